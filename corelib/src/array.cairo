@@ -1,28 +1,27 @@
 use traits::IndexView;
 
 use box::BoxTrait;
-use gas::withdraw_gas;
 use option::OptionTrait;
 use serde::Serde;
 
-extern type Array<T>;
-extern fn array_new<T>() -> Array<T> nopanic;
-extern fn array_append<T>(ref arr: Array<T>, value: T) nopanic;
-extern fn array_pop_front<T>(ref arr: Array<T>) -> Option<Box<T>> nopanic;
-extern fn array_pop_front_consume<T>(arr: Array<T>) -> Option<(Array<T>, Box<T>)> nopanic;
-extern fn array_snapshot_pop_front<T>(ref arr: @Array<T>) -> Option<Box<@T>> nopanic;
-extern fn array_snapshot_pop_back<T>(ref arr: @Array<T>) -> Option<Box<@T>> nopanic;
+pub extern type Array<T>;
+pub extern fn array_new<T>() -> Array<T> nopanic;
+pub extern fn array_append<T>(ref arr: Array<T>, value: T) nopanic;
+pub extern fn array_pop_front<T>(ref arr: Array<T>) -> Option<Box<T>> nopanic;
+pub extern fn array_pop_front_consume<T>(arr: Array<T>) -> Option<(Array<T>, Box<T>)> nopanic;
+pub extern fn array_snapshot_pop_front<T>(ref arr: @Array<T>) -> Option<Box<@T>> nopanic;
+pub extern fn array_snapshot_pop_back<T>(ref arr: @Array<T>) -> Option<Box<@T>> nopanic;
 #[panic_with('Index out of bounds', array_at)]
-extern fn array_get<T>(
+pub extern fn array_get<T>(
     arr: @Array<T>, index: usize
 ) -> Option<Box<@T>> implicits(RangeCheck) nopanic;
-extern fn array_slice<T>(
+pub extern fn array_slice<T>(
     arr: @Array<T>, start: usize, length: usize
 ) -> Option<@Array<T>> implicits(RangeCheck) nopanic;
-extern fn array_len<T>(arr: @Array<T>) -> usize nopanic;
+pub extern fn array_len<T>(arr: @Array<T>) -> usize nopanic;
 
 #[generate_trait]
-impl ArrayImpl<T> of ArrayTrait<T> {
+pub impl ArrayImpl<T> of ArrayTrait<T> {
     #[inline(always)]
     fn new() -> Array<T> {
         array_new()
@@ -66,20 +65,20 @@ impl ArrayImpl<T> of ArrayTrait<T> {
     }
 }
 
-impl ArrayDefault<T> of Default<Array<T>> {
+pub impl ArrayDefault<T> of Default<Array<T>> {
     #[inline(always)]
     fn default() -> Array<T> {
         ArrayTrait::new()
     }
 }
 
-impl ArrayIndex<T> of IndexView<Array<T>, usize, @T> {
+pub impl ArrayIndex<T> of IndexView<Array<T>, usize, @T> {
     fn index(self: @Array<T>, index: usize) -> @T {
         array_at(self, index).unbox()
     }
 }
 
-impl ArraySerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Array<T>> {
+pub impl ArraySerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Array<T>> {
     fn serialize(self: @Array<T>, ref output: Array<felt252>) {
         self.len().serialize(ref output);
         serialize_array_helper(self.span(), ref output);
@@ -114,17 +113,17 @@ fn deserialize_array_helper<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>>(
 }
 
 // Impls for common generic types
-impl ArrayDrop<T, impl TDrop: Drop<T>> of Drop<Array<T>>;
+pub impl ArrayDrop<T, impl TDrop: Drop<T>> of Drop<Array<T>>;
 
 // Span.
-struct Span<T> {
-    snapshot: @Array<T>
+pub struct Span<T> {
+    pub snapshot: @Array<T>
 }
 
-impl SpanCopy<T> of Copy<Span<T>>;
-impl SpanDrop<T> of Drop<Span<T>>;
+pub impl SpanCopy<T> of Copy<Span<T>>;
+pub impl SpanDrop<T> of Drop<Span<T>>;
 
-impl SpanSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Span<T>> {
+pub impl SpanSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Span<T>> {
     fn serialize(self: @Span<T>, ref output: Array<felt252>) {
         (*self).len().serialize(ref output);
         serialize_array_helper(*self, ref output)
@@ -138,7 +137,7 @@ impl SpanSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Span<T>>
 }
 
 #[generate_trait]
-impl SpanImpl<T> of SpanTrait<T> {
+pub impl SpanImpl<T> of SpanTrait<T> {
     #[inline(always)]
     fn pop_front(ref self: Span<T>) -> Option<@T> {
         let mut snapshot = self.snapshot;
@@ -179,9 +178,48 @@ impl SpanImpl<T> of SpanTrait<T> {
     fn is_empty(self: Span<T>) -> bool {
         self.len() == 0_usize
     }
+    /// Returns an array of the results of applying `f` to each item of the span.
+    fn map<U, F, impl FFn: Fn<F, (@T, ), U>, impl FDrop: Drop<F>, impl UDrop: Drop<U>>(
+        self: Span<T>, f: F
+    ) -> Array<U> {
+        let mut result = array_new();
+        for item in self {
+            result.append(f(item));
+        };
+        result
+    }
+    /// Returns an array of the items of the span for which `f` returns true.
+    fn filter<
+        F,
+        impl FFn: Fn<F, (@T, ), bool>,
+        impl FDrop: Drop<F>,
+        impl TCopy: Copy<T>,
+        impl TDrop: Drop<T>
+    >(
+        self: Span<T>, f: F
+    ) -> Array<T> {
+        let mut result = array_new();
+        for item in self {
+            if f(item) {
+                result.append(*item);
+            };
+        };
+        result
+    }
+    /// Folds the items of the span into an accumulator, starting from `init` and applying `f` to
+    /// the accumulator and each item.
+    fn fold<A, F, impl FFn: Fn<F, (A, @T), A>, impl FDrop: Drop<F>, impl ADrop: Drop<A>>(
+        self: Span<T>, init: A, f: F
+    ) -> A {
+        let mut acc = init;
+        for item in self {
+            acc = f(acc, item);
+        };
+        acc
+    }
 }
 
-impl SpanIndex<T> of IndexView<Span<T>, usize, @T> {
+pub impl SpanIndex<T> of IndexView<Span<T>, usize, @T> {
     #[inline(always)]
     fn index(self: @Span<T>, index: usize) -> @T {
         array_at(*self.snapshot, index).unbox()
@@ -189,7 +227,7 @@ impl SpanIndex<T> of IndexView<Span<T>, usize, @T> {
 }
 
 // TODO(spapini): Remove TDrop. It is necessary to get rid of response in case of panic.
-impl ArrayTCloneImpl<T, impl TClone: Clone<T>, impl TDrop: Drop<T>> of Clone<Array<T>> {
+pub impl ArrayTCloneImpl<T, impl TClone: Clone<T>, impl TDrop: Drop<T>> of Clone<Array<T>> {
     fn clone(self: @Array<T>) -> Array<T> {
         let mut response = array_new();
         let mut span = self.span();
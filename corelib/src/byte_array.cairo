@@ -0,0 +1,194 @@
+use array::{ArrayTrait, SpanTrait};
+use bytes_31::{
+    BYTES_IN_BYTES31, Bytes31Trait, one_shift_left_bytes_felt252, split_bytes_felt252, u8_at_felt252
+};
+use clone::Clone;
+use option::OptionTrait;
+use serde::Serde;
+use traits::{Into, TryInto};
+
+/// A magic constant for identifying serialization of ByteArrays. An array of felt252s with this
+/// magic as one of the felt252s indicates that right after it you should expect a serialized
+/// ByteArray. This is currently used mainly for prints and panics.
+const BYTE_ARRAY_MAGIC: felt252 = 0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3;
+
+/// A byte array of arbitrary length. The bytes are stored in full 31-byte words, followed by a
+/// pending word holding the remaining bytes.
+#[derive(Clone, Drop, Serde)]
+pub struct ByteArray {
+    // Full "words" of 31 bytes each. The first byte of each word in the byte array is the most
+    // significant byte in the word.
+    pub(crate) data: Array<bytes31>,
+    // This felt252 actually represents a bytes31, with < 31 bytes. It is represented as a felt252
+    // to improve performance of building the byte array.
+    // The first byte is the most significant byte among the `pending_word_len` bytes in the word.
+    pub(crate) pending_word: felt252,
+    // Should be in range [0, 30].
+    pub(crate) pending_word_len: usize,
+}
+
+pub impl ByteArrayDefault of Default<ByteArray> {
+    fn default() -> ByteArray {
+        ByteArray { data: Default::default(), pending_word: 0, pending_word_len: 0 }
+    }
+}
+
+pub trait ByteArrayTrait {
+    /// Appends a single word of `len` bytes to the end of the ByteArray.
+    /// Assumes `len < 31` and that `word` has no more than `len` bytes of data.
+    fn append_word(ref self: ByteArray, word: felt252, len: usize);
+    /// Appends a byte array to the end of `self`.
+    fn append(ref self: ByteArray, other: @ByteArray);
+    /// Concatenates two byte arrays and returns the result.
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray;
+    /// Appends a single byte to the end of `self`.
+    fn append_byte(ref self: ByteArray, byte: u8);
+    /// Returns the length of the byte array, in bytes.
+    fn len(self: @ByteArray) -> usize;
+    /// Returns the byte at the given index, or None if the index is out of bounds.
+    fn at(self: @ByteArray, index: usize) -> Option<u8>;
+}
+
+pub impl ByteArrayImpl of ByteArrayTrait {
+    fn append_word(ref self: ByteArray, word: felt252, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let total_pending_bytes = self.pending_word_len + len;
+
+        if total_pending_bytes < BYTES_IN_BYTES31 {
+            self.pending_word = word + self.pending_word * one_shift_left_bytes_felt252(len);
+            self.pending_word_len = total_pending_bytes;
+            return;
+        }
+
+        // The pending word is completed by the high bytes of `word`, and the remaining low bytes
+        // become the new pending word.
+        let n_low_bytes = total_pending_bytes - BYTES_IN_BYTES31;
+        let (high, low) = split_bytes_felt252(word, n_low_bytes);
+        let full_word = high + self.pending_word * one_shift_left_bytes_felt252(len - n_low_bytes);
+        self.data.append(full_word.try_into().unwrap());
+        self.pending_word = low;
+        self.pending_word_len = n_low_bytes;
+    }
+
+    fn append(ref self: ByteArray, other: @ByteArray) {
+        let mut other_data = other.data.span();
+        loop {
+            match other_data.pop_front() {
+                Option::Some(word) => {
+                    self.append_full_word(*word);
+                },
+                Option::None(_) => {
+                    break ();
+                },
+            };
+        };
+        self.append_word(*other.pending_word, *other.pending_word_len);
+    }
+
+    fn concat(left: @ByteArray, right: @ByteArray) -> ByteArray {
+        let mut result = left.clone();
+        result.append(right);
+        result
+    }
+
+    fn append_byte(ref self: ByteArray, byte: u8) {
+        self.append_word(byte.into(), 1);
+    }
+
+    fn len(self: @ByteArray) -> usize {
+        self.data.len() * BYTES_IN_BYTES31 + *self.pending_word_len
+    }
+
+    fn at(self: @ByteArray, index: usize) -> Option<u8> {
+        let word_index = index / BYTES_IN_BYTES31;
+        let index_in_word = index % BYTES_IN_BYTES31;
+        let data_len = self.data.len();
+        if word_index == data_len {
+            // The byte is in the pending word.
+            if index_in_word >= *self.pending_word_len {
+                return Option::None(());
+            }
+            return Option::Some(
+                u8_at_felt252(*self.pending_word, *self.pending_word_len - 1 - index_in_word)
+            );
+        }
+        if word_index > data_len {
+            return Option::None(());
+        }
+        Option::Some(self.data.at(word_index).at(BYTES_IN_BYTES31 - 1 - index_in_word))
+    }
+}
+
+#[generate_trait]
+impl InternalByteArrayImpl of InternalByteArrayTrait {
+    /// Appends a full word of 31 bytes to the end of `self`.
+    fn append_full_word(ref self: ByteArray, word: bytes31) {
+        if self.pending_word_len == 0 {
+            self.data.append(word);
+            return;
+        }
+        let (high, low) = split_bytes_felt252(word.into(), self.pending_word_len);
+        let full_word = high
+            + self.pending_word
+                * one_shift_left_bytes_felt252(BYTES_IN_BYTES31 - self.pending_word_len);
+        self.data.append(full_word.try_into().unwrap());
+        self.pending_word = low;
+    }
+}
+
+pub impl ByteArrayPartialEq of PartialEq<ByteArray> {
+    fn eq(lhs: @ByteArray, rhs: @ByteArray) -> bool {
+        if lhs.len() != rhs.len() {
+            return false;
+        }
+        if *lhs.pending_word != *rhs.pending_word {
+            return false;
+        }
+        let mut lhs_data = lhs.data.span();
+        let mut rhs_data = rhs.data.span();
+        loop {
+            match lhs_data.pop_front() {
+                Option::Some(lhs_word) => {
+                    if lhs_word != rhs_data.pop_front().unwrap() {
+                        break false;
+                    }
+                },
+                Option::None(_) => {
+                    break true;
+                },
+            };
+        }
+    }
+    fn ne(lhs: @ByteArray, rhs: @ByteArray) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+pub impl ByteArrayAdd of Add<ByteArray> {
+    fn add(lhs: ByteArray, rhs: ByteArray) -> ByteArray {
+        ByteArrayTrait::concat(@lhs, @rhs)
+    }
+}
+
+pub impl ByteArrayAddEq of AddEq<ByteArray> {
+    fn add_eq(ref self: ByteArray, other: ByteArray) {
+        self.append(@other);
+    }
+}
+
+pub impl ByteArrayIndexView of IndexView<ByteArray, usize, u8> {
+    fn index(self: @ByteArray, index: usize) -> u8 {
+        self.at(index).expect('Index out of bounds')
+    }
+}
+
+/// Panics with the given ByteArray as the panic message.
+#[inline(always)]
+pub fn panic_with_byte_array(err: @ByteArray) -> never {
+    let mut serialized = ArrayTrait::new();
+    serialized.append(BYTE_ARRAY_MAGIC);
+    err.serialize(ref serialized);
+    panic(serialized)
+}
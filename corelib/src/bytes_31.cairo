@@ -0,0 +1,88 @@
+use array::{ArrayTrait, SpanTrait};
+use traits::{Into, TryInto};
+use option::OptionTrait;
+use serde::Serde;
+
+pub(crate) const BYTES_IN_BYTES31: usize = 31;
+
+// A value of up to 31 bytes, in the range [0, 2 ** 248).
+#[derive(Copy, Drop)]
+pub extern type bytes31;
+
+pub extern fn bytes31_const<const value: felt252>() -> bytes31 nopanic;
+pub extern fn bytes31_try_from_felt252(
+    value: felt252
+) -> Option<bytes31> implicits(RangeCheck) nopanic;
+pub extern fn bytes31_to_felt252(value: bytes31) -> felt252 nopanic;
+
+#[generate_trait]
+pub impl Bytes31Impl of Bytes31Trait {
+    /// Gets the byte at the given index (LSB's index is 0), assuming that
+    /// `index < BYTES_IN_BYTES31`. If the assumption is not met, the behavior is undefined.
+    fn at(self: @bytes31, index: usize) -> u8 {
+        u8_at_felt252((*self).into(), index)
+    }
+}
+
+pub impl Bytes31IntoFelt252 of Into<bytes31, felt252> {
+    fn into(self: bytes31) -> felt252 {
+        bytes31_to_felt252(self)
+    }
+}
+
+pub impl Felt252TryIntoBytes31 of TryInto<felt252, bytes31> {
+    fn try_into(self: felt252) -> Option<bytes31> {
+        bytes31_try_from_felt252(self)
+    }
+}
+
+pub impl Bytes31Serde of Serde<bytes31> {
+    fn serialize(self: @bytes31, ref output: Array<felt252>) {
+        output.append((*self).into());
+    }
+    fn deserialize(ref serialized: Span<felt252>) -> Option<bytes31> {
+        (*serialized.pop_front()?).try_into()
+    }
+}
+
+pub impl Bytes31PartialEq of PartialEq<bytes31> {
+    #[inline(always)]
+    fn eq(lhs: @bytes31, rhs: @bytes31) -> bool {
+        let lhs_as_felt252: felt252 = (*lhs).into();
+        let rhs_as_felt252: felt252 = (*rhs).into();
+        lhs_as_felt252 == rhs_as_felt252
+    }
+    #[inline(always)]
+    fn ne(lhs: @bytes31, rhs: @bytes31) -> bool {
+        !(lhs == rhs)
+    }
+}
+
+/// Returns `2 ** (8 * n_bytes)` as a felt252.
+pub(crate) fn one_shift_left_bytes_felt252(n_bytes: usize) -> felt252 {
+    let mut result = 1;
+    let mut i = 0_usize;
+    loop {
+        if i == n_bytes {
+            break result;
+        }
+        result *= 0x100;
+        i += 1;
+    }
+}
+
+/// Splits a word, assumed to be smaller than `2 ** 248`, into its high and low parts, where the
+/// low part consists of the `n_low_bytes` least significant bytes.
+pub(crate) fn split_bytes_felt252(word: felt252, n_low_bytes: usize) -> (felt252, felt252) {
+    let word: u256 = word.into();
+    let divisor: u256 = one_shift_left_bytes_felt252(n_low_bytes).into();
+    let (high, low) = DivRem::div_rem(word, divisor.try_into().unwrap());
+    (high.try_into().unwrap(), low.try_into().unwrap())
+}
+
+/// Gets the byte at the given index of a felt252 (LSB's index is 0).
+pub(crate) fn u8_at_felt252(word: felt252, index: usize) -> u8 {
+    let (high, _) = split_bytes_felt252(word, index);
+    let (_, byte) = split_bytes_felt252(high, 1);
+    byte.try_into().unwrap()
+}
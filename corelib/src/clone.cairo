@@ -1,8 +1,8 @@
-trait Clone<T> {
+pub trait Clone<T> {
     fn clone(self: @T) -> T;
 }
 
-impl TCopyClone<T, impl TCopy: Copy<T>> of Clone<T> {
+pub impl TCopyClone<T, impl TCopy: Copy<T>> of Clone<T> {
     fn clone(self: @T) -> T {
         *self
     }
@@ -1,6 +1,5 @@
 use array::ArrayTrait;
 use traits::Into;
-use option::Option;
 
 // Usage:
 //
@@ -18,25 +17,25 @@ use option::Option;
 // arr.append('SomeVeryLongMessage');
 // arr.print();
 
-extern fn print(message: Array<felt252>) nopanic;
+pub extern fn print(message: Array<felt252>) nopanic;
 
-fn print_felt252(message: felt252) {
+pub fn print_felt252(message: felt252) {
     let mut arr = Default::default();
     arr.append(message);
     print(arr);
 }
 
-trait PrintTrait<T> {
+pub trait PrintTrait<T> {
     fn print(self: T);
 }
 
-impl Felt252PrintImpl of PrintTrait<felt252> {
+pub impl Felt252PrintImpl of PrintTrait<felt252> {
     fn print(self: felt252) {
         print_felt252(self);
     }
 }
 
-impl BoolPrintImpl of PrintTrait<bool> {
+pub impl BoolPrintImpl of PrintTrait<bool> {
     fn print(self: bool) {
         if self {
             'true'.print();
@@ -46,50 +45,50 @@ impl BoolPrintImpl of PrintTrait<bool> {
     }
 }
 
-impl ContractAddressPrintImpl of PrintTrait<starknet::ContractAddress> {
+pub impl ContractAddressPrintImpl of PrintTrait<starknet::ContractAddress> {
     fn print(self: starknet::ContractAddress) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U8PrintImpl of PrintTrait<u8> {
+pub impl U8PrintImpl of PrintTrait<u8> {
     fn print(self: u8) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U16PrintImpl of PrintTrait<u16> {
+pub impl U16PrintImpl of PrintTrait<u16> {
     fn print(self: u16) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U32PrintImpl of PrintTrait<u32> {
+pub impl U32PrintImpl of PrintTrait<u32> {
     fn print(self: u32) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U64PrintImpl of PrintTrait<u64> {
+pub impl U64PrintImpl of PrintTrait<u64> {
     fn print(self: u64) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U128PrintImpl of PrintTrait<u128> {
+pub impl U128PrintImpl of PrintTrait<u128> {
     fn print(self: u128) {
         Into::<_, felt252>::into(self).print();
     }
 }
 
-impl U256PrintImpl of PrintTrait<u256> {
+pub impl U256PrintImpl of PrintTrait<u256> {
     fn print(self: u256) {
         Into::<u128, felt252>::into(self.low).print();
         Into::<u128, felt252>::into(self.high).print();
     }
 }
 
-impl ArrayGenericPrintImpl of PrintTrait<Array<felt252>> {
+pub impl ArrayGenericPrintImpl of PrintTrait<Array<felt252>> {
     fn print(mut self: Array<felt252>) {
         print(self);
     }
@@ -1,17 +1,17 @@
 use traits::{Index, Default};
 
-extern type Felt252Dict<T>;
-extern type SquashedFelt252Dict<T>;
-extern type Felt252DictEntry<T>;
-impl SquashedFelt252DictDrop<T, impl TDrop: Drop<T>> of Drop<SquashedFelt252Dict<T>>;
+pub extern type Felt252Dict<T>;
+pub extern type SquashedFelt252Dict<T>;
+pub extern type Felt252DictEntry<T>;
+pub impl SquashedFelt252DictDrop<T, impl TDrop: Drop<T>> of Drop<SquashedFelt252Dict<T>>;
 
-extern fn felt252_dict_new<T>() -> Felt252Dict<T> implicits(SegmentArena) nopanic;
+pub extern fn felt252_dict_new<T>() -> Felt252Dict<T> implicits(SegmentArena) nopanic;
 
-extern fn felt252_dict_entry_get<T>(
+pub extern fn felt252_dict_entry_get<T>(
     dict: Felt252Dict<T>, key: felt252
 ) -> (Felt252DictEntry<T>, T) nopanic;
 
-extern fn felt252_dict_entry_finalize<T>(
+pub extern fn felt252_dict_entry_finalize<T>(
     dict_entry: Felt252DictEntry<T>, new_value: T
 ) -> Felt252Dict<T> nopanic;
 
@@ -20,11 +20,11 @@ extern fn felt252_dict_entry_finalize<T>(
 /// NOTE: Never use this libfunc directly. Use Felt252DictTrait::squash() instead. Using this
 /// libfunc directly will result in multiple unnecessary copies of the libfunc in the compiled CASM
 /// code.
-extern fn felt252_dict_squash<T>(
+pub extern fn felt252_dict_squash<T>(
     dict: Felt252Dict<T>
 ) -> SquashedFelt252Dict<T> implicits(RangeCheck, GasBuiltin, SegmentArena) nopanic;
 
-trait Felt252DictTrait<T> {
+pub trait Felt252DictTrait<T> {
     /// Inserts the given value for the given key.
     ///
     /// Requires the `Destruct` trait, as the previous value is dropped.
@@ -36,7 +36,7 @@ trait Felt252DictTrait<T> {
     fn squash(self: Felt252Dict<T>) -> SquashedFelt252Dict<T> nopanic;
     fn entry(self: Felt252Dict<T>, key: felt252) -> (Felt252DictEntry<T>, T) nopanic;
 }
-impl Felt252DictImpl<T, impl TDefault: Felt252DictValue<T>> of Felt252DictTrait<T> {
+pub impl Felt252DictImpl<T, impl TDefault: Felt252DictValue<T>> of Felt252DictTrait<T> {
     #[inline]
     fn insert<impl TDestruct: Destruct<T>>(ref self: Felt252Dict<T>, key: felt252, value: T) {
         let (entry, _prev_value) = felt252_dict_entry_get(self, key);
@@ -62,25 +62,25 @@ impl Felt252DictImpl<T, impl TDefault: Felt252DictValue<T>> of Felt252DictTrait<
     }
 }
 
-trait Felt252DictEntryTrait<T> {
+pub trait Felt252DictEntryTrait<T> {
     fn finalize(self: Felt252DictEntry<T>, new_value: T) -> Felt252Dict<T>;
 }
 
-impl Felt252DictEntryImpl<T, impl TDefault: Felt252DictValue<T>> of Felt252DictEntryTrait<T> {
+pub impl Felt252DictEntryImpl<T, impl TDefault: Felt252DictValue<T>> of Felt252DictEntryTrait<T> {
     #[inline(always)]
     fn finalize(self: Felt252DictEntry<T>, new_value: T) -> Felt252Dict<T> {
         felt252_dict_entry_finalize(self, new_value)
     }
 }
 
-impl Felt252DictDefault<T> of Default<Felt252Dict<T>> {
+pub impl Felt252DictDefault<T> of Default<Felt252Dict<T>> {
     #[inline(always)]
     fn default() -> Felt252Dict<T> {
         felt252_dict_new()
     }
 }
 
-impl Felt252DictDestruct<
+pub impl Felt252DictDestruct<
     T, impl TDrop: Drop<T>, impl TDefault: Felt252DictValue<T>
 > of Destruct<Felt252Dict<T>> {
     #[inline(always)]
@@ -89,7 +89,7 @@ impl Felt252DictDestruct<
     }
 }
 
-impl Felt252DictEntryDestruct<
+pub impl Felt252DictEntryDestruct<
     T, impl TDrop: Drop<T>, impl TDefault: Felt252DictValue<T>
 > of Destruct<Felt252DictEntry<T>> {
     #[inline(always)]
@@ -98,7 +98,7 @@ impl Felt252DictEntryDestruct<
     }
 }
 
-impl Felt252DictIndex<
+pub impl Felt252DictIndex<
     T,
     impl TDictImpl: Felt252DictTrait<T>,
     impl TCopy: Copy<T>,
@@ -1,10 +1,5 @@
 use zeroable::IsZeroResult;
-use ec::{
-    ec_mul, ec_neg, ec_point_from_x, ec_point_from_x_nz, ec_point_is_zero, ec_point_new,
-    ec_point_new_nz, ec_point_non_zero, ec_point_try_new, ec_point_try_new_nz, ec_point_unwrap,
-    ec_point_zero, ec_state_add_mul, ec_state_add, ec_state_finalize, ec_state_init,
-    ec_state_try_finalize_nz
-};
+use ec::{ec_mul, ec_point_is_zero, ec_point_try_new, ec_point_unwrap};
 
 // Checks if (`signature_r`, `signature_s`) is a valid ECDSA signature for the given `public_key`
 // on the given `message`.
@@ -24,7 +19,7 @@ use ec::{
 // Returns:
 //   `true` if the signature is valid and `false` otherwise.
 // TODO(lior): Make this function nopanic once possible.
-fn check_ecdsa_signature(
+pub fn check_ecdsa_signature(
     message_hash: felt252, public_key: felt252, signature_r: felt252, signature_s: felt252
 ) -> bool {
     // TODO(lior): Change to || once short circuiting is supported.
@@ -79,7 +74,7 @@ fn check_ecdsa_signature(
             return false;
         },
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             x
         },
     };
@@ -89,7 +84,7 @@ fn check_ecdsa_signature(
     match ec_point_is_zero(zG + rQ) {
         IsZeroResult::Zero(()) => {},
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             if (x == sR_x) {
                 return true;
             }
@@ -99,7 +94,7 @@ fn check_ecdsa_signature(
     match ec_point_is_zero(zG - rQ) {
         IsZeroResult::Zero(()) => {},
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             if (x == sR_x) {
                 return true;
             }
@@ -1,21 +1,20 @@
 use traits::Into;
-use starknet::ContractAddress;
 
-extern type Pedersen;
+pub extern type Pedersen;
 
-extern fn pedersen(a: felt252, b: felt252) -> felt252 implicits(Pedersen) nopanic;
+pub extern fn pedersen(a: felt252, b: felt252) -> felt252 implicits(Pedersen) nopanic;
 
-trait LegacyHash<T> {
+pub trait LegacyHash<T> {
     fn hash(state: felt252, value: T) -> felt252;
 }
 
-impl LegacyHashFelt252 of LegacyHash<felt252> {
+pub impl LegacyHashFelt252 of LegacyHash<felt252> {
     fn hash(state: felt252, value: felt252) -> felt252 {
         pedersen(state, value)
     }
 }
 
-impl LegacyHashBool of LegacyHash<bool> {
+pub impl LegacyHashBool of LegacyHash<bool> {
     fn hash(state: felt252, value: bool) -> felt252 {
         LegacyHash::<felt252>::hash(state, if value {
             1
@@ -25,63 +24,63 @@ impl LegacyHashBool of LegacyHash<bool> {
     }
 }
 
-impl LegacyHashU8 of LegacyHash<u8> {
+pub impl LegacyHashU8 of LegacyHash<u8> {
     fn hash(state: felt252, value: u8) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl LegacyHashU16 of LegacyHash<u16> {
+pub impl LegacyHashU16 of LegacyHash<u16> {
     fn hash(state: felt252, value: u16) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl LegacyHashU32 of LegacyHash<u32> {
+pub impl LegacyHashU32 of LegacyHash<u32> {
     fn hash(state: felt252, value: u32) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl LegacyHashU64 of LegacyHash<u64> {
+pub impl LegacyHashU64 of LegacyHash<u64> {
     fn hash(state: felt252, value: u64) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl LegacyHashU128 of LegacyHash<u128> {
+pub impl LegacyHashU128 of LegacyHash<u128> {
     fn hash(state: felt252, value: u128) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl LegacyHashU256 of LegacyHash<u256> {
+pub impl LegacyHashU256 of LegacyHash<u256> {
     fn hash(state: felt252, value: u256) -> felt252 {
         let state = LegacyHash::<u128>::hash(state, value.low);
         LegacyHash::<u128>::hash(state, value.high)
     }
 }
 
-impl LegacyHashContractAddress of LegacyHash<starknet::ContractAddress> {
+pub impl LegacyHashContractAddress of LegacyHash<starknet::ContractAddress> {
     fn hash(state: felt252, value: starknet::ContractAddress) -> felt252 {
         LegacyHash::<felt252>::hash(state, value.into())
     }
 }
 
-impl TupleSize0LegacyHash of LegacyHash<()> {
+pub impl TupleSize0LegacyHash of LegacyHash<()> {
     fn hash(state: felt252, value: ()) -> felt252 {
         state
     }
 }
 
-impl TupleSize1LegacyHash<E0, impl E0LegacyHash: LegacyHash<E0>> of LegacyHash<(E0, )> {
+pub impl TupleSize1LegacyHash<E0, impl E0LegacyHash: LegacyHash<E0>> of LegacyHash<(E0, )> {
     fn hash(state: felt252, value: (E0, )) -> felt252 {
         let (e0, ) = value;
         E0LegacyHash::hash(state, e0)
     }
 }
 
-impl TupleSize2LegacyHash<
+pub impl TupleSize2LegacyHash<
     E0,
     E1,
     impl E0LegacyHash: LegacyHash<E0>,
@@ -96,7 +95,7 @@ impl TupleSize2LegacyHash<
     }
 }
 
-impl TupleSize3LegacyHash<
+pub impl TupleSize3LegacyHash<
     E0,
     E1,
     E2,
@@ -115,7 +114,7 @@ impl TupleSize3LegacyHash<
     }
 }
 
-impl TupleSize4LegacyHash<
+pub impl TupleSize4LegacyHash<
     E0,
     E1,
     E2,
@@ -137,7 +136,3 @@ impl TupleSize4LegacyHash<
         E3LegacyHash::hash(state, e3)
     }
 }
-
-fn foo(input: Span<u64>) -> starknet::SyscallResult<u256> {
-    starknet::syscalls::keccak_syscall(input)
-}
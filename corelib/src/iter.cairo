@@ -0,0 +1,102 @@
+use array::{ArrayTrait, SpanTrait};
+use math::Oneable;
+
+/// A type that yields a sequence of items, one at a time.
+pub trait Iterator<T, Item> {
+    /// Advances the iterator and returns the next item, or None if the iteration is finished.
+    fn next(ref self: T) -> Option<Item>;
+}
+
+/// A type that can be converted into an iterator. Used by `for` loops.
+pub trait IntoIterator<T, I> {
+    /// Converts `self` into an iterator.
+    fn into_iter(self: T) -> I;
+}
+
+// Array.
+/// An iterator over the items of an array, by value.
+pub struct ArrayIter<T> {
+    array: Array<T>
+}
+
+pub impl ArrayIterDrop<T, impl TDrop: Drop<T>> of Drop<ArrayIter<T>>;
+
+pub impl ArrayIterator<T> of Iterator<ArrayIter<T>, T> {
+    #[inline(always)]
+    fn next(ref self: ArrayIter<T>) -> Option<T> {
+        self.array.pop_front()
+    }
+}
+
+pub impl ArrayIntoIterator<T> of IntoIterator<Array<T>, ArrayIter<T>> {
+    #[inline(always)]
+    fn into_iter(self: Array<T>) -> ArrayIter<T> {
+        ArrayIter { array: self }
+    }
+}
+
+// Span.
+/// An iterator over the items of a span, by snapshot.
+pub struct SpanIter<T> {
+    span: Span<T>
+}
+
+pub impl SpanIterCopy<T> of Copy<SpanIter<T>>;
+pub impl SpanIterDrop<T> of Drop<SpanIter<T>>;
+
+pub impl SpanIterator<T> of Iterator<SpanIter<T>, @T> {
+    #[inline(always)]
+    fn next(ref self: SpanIter<T>) -> Option<@T> {
+        self.span.pop_front()
+    }
+}
+
+pub impl SpanIntoIterator<T> of IntoIterator<Span<T>, SpanIter<T>> {
+    #[inline(always)]
+    fn into_iter(self: Span<T>) -> SpanIter<T> {
+        SpanIter { span: self }
+    }
+}
+
+// Range.
+/// The half-open range `[start, end)`. Iterating over it yields `start`, `start + 1`, ..., up to
+/// but not including `end`.
+pub struct Range<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// Returns the half-open range `[start, end)`.
+#[inline(always)]
+pub fn range<T>(start: T, end: T) -> Range<T> {
+    Range { start, end }
+}
+
+pub impl RangeCopy<T, impl TCopy: Copy<T>> of Copy<Range<T>>;
+pub impl RangeDrop<T, impl TDrop: Drop<T>> of Drop<Range<T>>;
+
+pub impl RangeIterator<
+    T,
+    impl TCopy: Copy<T>,
+    impl TDrop: Drop<T>,
+    impl TPartialOrd: PartialOrd<T>,
+    impl TAdd: Add<T>,
+    impl TOneable: Oneable<T>
+> of Iterator<Range<T>, T> {
+    fn next(ref self: Range<T>) -> Option<T> {
+        if self.start < self.end {
+            let value = self.start;
+            self.start = value + TOneable::one();
+            Option::Some(value)
+        } else {
+            Option::None(())
+        }
+    }
+}
+
+pub impl RangeIntoIterator<T> of IntoIterator<Range<T>, Range<T>> {
+    #[inline(always)]
+    fn into_iter(self: Range<T>) -> Range<T> {
+        self
+    }
+}
@@ -1,5 +1,5 @@
-use array::{Span, ArrayTrait, SpanTrait, ArrayDrop};
-use integer::TryInto;
+use array::{Span, ArrayTrait, SpanTrait};
+use traits::TryInto;
 use option::OptionTrait;
 use starknet::SyscallResultTrait;
 
@@ -31,7 +31,7 @@ fn keccak_add_u256_le(ref keccak_input: Array::<u64>, v: u256) {
 // Computes the keccak256 of multiple u256 values.
 // The input values are interpreted as little-endian.
 // The 32-byte result is represented as a little-endian u256.
-fn keccak_u256s_le_inputs(mut input: Span<u256>) -> u256 {
+pub fn keccak_u256s_le_inputs(mut input: Span<u256>) -> u256 {
     let mut keccak_input: Array::<u64> = Default::default();
 
     loop {
@@ -61,7 +61,7 @@ fn keccak_add_u256_be(ref keccak_input: Array::<u64>, v: u256) {
 // Computes the keccak256 of multiple u256 values.
 // The input values are interpreted as big-endian.
 // The 32-byte result is represented as a little-endian u256.
-fn keccak_u256s_be_inputs(mut input: Span<u256>) -> u256 {
+pub fn keccak_u256s_be_inputs(mut input: Span<u256>) -> u256 {
     let mut keccak_input: Array::<u64> = Default::default();
 
     loop {
@@ -83,7 +83,7 @@ fn keccak_u256s_be_inputs(mut input: Span<u256>) -> u256 {
 // The padding in keccak256 is 10*1;
 fn add_padding(ref input: Array<u64>) {
     let divisor = KECCAK_FULL_RATE_IN_U64S.try_into().unwrap();
-    let (q, r) = integer::u32_safe_divmod(input.len(), divisor);
+    let (_q, r) = integer::u32_safe_divmod(input.len(), divisor);
     let padding_len = KECCAK_FULL_RATE_IN_U64S - r;
     // padding_len is in the range [1, KECCAK_FULL_RATE_IN_U64S].
 
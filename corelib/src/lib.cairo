@@ -1,21 +1,21 @@
-mod traits;
-use traits::{
+pub mod traits;
+pub use traits::{
     Add, AddEq, BitAnd, BitNot, BitOr, BitXor, Copy, Div, DivEq, DivRem, Drop, Mul, MulEq,
     PartialEq, PartialOrd, Rem, RemEq, Sub, SubEq, TupleSize0Copy, TupleSize0Drop,
     TupleSize0PartialEq, TupleSize1Copy, TupleSize1Drop, TupleSize1PartialEq, TupleSize2Copy,
     TupleSize2Drop, TupleSize3Copy, TupleSize3Drop, TupleSize4Copy, TupleSize4Drop, Not, Neg, Into,
-    TryInto, Index, IndexView, Destruct, Default, Felt252DictValue, PanicDestruct
+    TryInto, Index, IndexView, Destruct, Default, Felt252DictValue, PanicDestruct, FnOnce, Fn
 };
-use serde::Serde;
-use array::SpanTrait;
+pub use serde::Serde;
+pub use array::SpanTrait;
 
 #[derive(Copy, Drop)]
-enum bool {
+pub enum bool {
     False: (),
     True: (),
 }
 
-impl BoolSerde of Serde<bool> {
+pub impl BoolSerde of Serde<bool> {
     fn serialize(self: @bool, ref output: Array<felt252>) {
         if *self {
             1
@@ -28,8 +28,8 @@ impl BoolSerde of Serde<bool> {
     }
 }
 
-extern fn bool_and_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
-impl BoolBitAnd of BitAnd<bool> {
+pub extern fn bool_and_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
+pub impl BoolBitAnd of BitAnd<bool> {
     #[inline(always)]
     fn bitand(lhs: bool, rhs: bool) -> bool {
         let (r, ) = bool_and_impl(lhs, rhs);
@@ -37,8 +37,8 @@ impl BoolBitAnd of BitAnd<bool> {
     }
 }
 
-extern fn bool_or_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
-impl BoolBitOr of BitOr<bool> {
+pub extern fn bool_or_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
+pub impl BoolBitOr of BitOr<bool> {
     #[inline(always)]
     fn bitor(lhs: bool, rhs: bool) -> bool {
         let (r, ) = bool_or_impl(lhs, rhs);
@@ -46,9 +46,9 @@ impl BoolBitOr of BitOr<bool> {
     }
 }
 
-extern fn bool_not_impl(a: bool) -> (bool, ) implicits() nopanic;
+pub extern fn bool_not_impl(a: bool) -> (bool, ) implicits() nopanic;
 #[inline(always)]
-impl BoolNot of Not<bool> {
+pub impl BoolNot of Not<bool> {
     #[inline(always)]
     fn not(a: bool) -> bool implicits() nopanic {
         let (r, ) = bool_not_impl(a);
@@ -56,8 +56,8 @@ impl BoolNot of Not<bool> {
     }
 }
 
-extern fn bool_xor_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
-impl BoolBitXor of BitXor<bool> {
+pub extern fn bool_xor_impl(lhs: bool, rhs: bool) -> (bool, ) implicits() nopanic;
+pub impl BoolBitXor of BitXor<bool> {
     #[inline(always)]
     fn bitxor(lhs: bool, rhs: bool) -> bool {
         let (r, ) = bool_xor_impl(lhs, rhs);
@@ -65,7 +65,7 @@ impl BoolBitXor of BitXor<bool> {
     }
 }
 
-impl BoolPartialEq of PartialEq<bool> {
+pub impl BoolPartialEq of PartialEq<bool> {
     #[inline(always)]
     fn eq(lhs: @bool, rhs: @bool) -> bool {
         match lhs {
@@ -83,25 +83,25 @@ impl BoolPartialEq of PartialEq<bool> {
 }
 
 /// Default values for felt252_dict values.
-impl BoolFelt252DictValue of Felt252DictValue<bool> {
+pub impl BoolFelt252DictValue of Felt252DictValue<bool> {
     #[inline(always)]
     fn zero_default() -> bool nopanic {
         false
     }
 }
 
-extern fn bool_to_felt252(a: bool) -> felt252 implicits() nopanic;
+pub extern fn bool_to_felt252(a: bool) -> felt252 implicits() nopanic;
 
 // General purpose implicits.
-extern type RangeCheck;
-extern type SegmentArena;
+pub extern type RangeCheck;
+pub extern type SegmentArena;
 
 // felt252.
 #[derive(Copy, Drop)]
-extern type felt252;
-extern fn felt252_const<const value: felt252>() -> felt252 nopanic;
+pub extern type felt252;
+pub extern fn felt252_const<const value: felt252>() -> felt252 nopanic;
 
-impl Felt252Serde of Serde<felt252> {
+pub impl Felt252Serde of Serde<felt252> {
     fn serialize(self: @felt252, ref output: Array<felt252>) {
         output.append(*self);
     }
@@ -110,59 +110,59 @@ impl Felt252Serde of Serde<felt252> {
     }
 }
 
-impl Felt252Add of Add<felt252> {
+pub impl Felt252Add of Add<felt252> {
     #[inline(always)]
     fn add(lhs: felt252, rhs: felt252) -> felt252 {
         felt252_add(lhs, rhs)
     }
 }
-impl Felt252AddEq of AddEq<felt252> {
+pub impl Felt252AddEq of AddEq<felt252> {
     #[inline(always)]
     fn add_eq(ref self: felt252, other: felt252) {
         self = Add::add(self, other);
     }
 }
 
-extern fn felt252_add(lhs: felt252, rhs: felt252) -> felt252 nopanic;
-impl Felt252Sub of Sub<felt252> {
+pub extern fn felt252_add(lhs: felt252, rhs: felt252) -> felt252 nopanic;
+pub impl Felt252Sub of Sub<felt252> {
     #[inline(always)]
     fn sub(lhs: felt252, rhs: felt252) -> felt252 {
         felt252_sub(lhs, rhs)
     }
 }
-impl Felt252SubEq of SubEq<felt252> {
+pub impl Felt252SubEq of SubEq<felt252> {
     #[inline(always)]
     fn sub_eq(ref self: felt252, other: felt252) {
         self = Sub::sub(self, other);
     }
 }
 
-extern fn felt252_sub(lhs: felt252, rhs: felt252) -> felt252 nopanic;
-impl Felt252Mul of Mul<felt252> {
+pub extern fn felt252_sub(lhs: felt252, rhs: felt252) -> felt252 nopanic;
+pub impl Felt252Mul of Mul<felt252> {
     #[inline(always)]
     fn mul(lhs: felt252, rhs: felt252) -> felt252 {
         felt252_mul(lhs, rhs)
     }
 }
-impl Felt252MulEq of MulEq<felt252> {
+pub impl Felt252MulEq of MulEq<felt252> {
     #[inline(always)]
     fn mul_eq(ref self: felt252, other: felt252) {
         self = Mul::mul(self, other);
     }
 }
 
-extern fn felt252_mul(lhs: felt252, rhs: felt252) -> felt252 nopanic;
+pub extern fn felt252_mul(lhs: felt252, rhs: felt252) -> felt252 nopanic;
 
-impl Felt252Neg of Neg<felt252> {
+pub impl Felt252Neg of Neg<felt252> {
     #[inline(always)]
     fn neg(a: felt252) -> felt252 {
         a * felt252_const::<-1>()
     }
 }
 
-extern fn felt252_div(lhs: felt252, rhs: NonZero<felt252>) -> felt252 nopanic;
+pub extern fn felt252_div(lhs: felt252, rhs: NonZero<felt252>) -> felt252 nopanic;
 
-impl Felt252PartialEq of PartialEq<felt252> {
+pub impl Felt252PartialEq of PartialEq<felt252> {
     #[inline(always)]
     fn eq(lhs: @felt252, rhs: @felt252) -> bool {
         match *lhs - *rhs {
@@ -176,9 +176,9 @@ impl Felt252PartialEq of PartialEq<felt252> {
     }
 }
 
-extern fn felt252_is_zero(lhs: felt252) -> zeroable::IsZeroResult<felt252> nopanic;
+pub extern fn felt252_is_zero(lhs: felt252) -> zeroable::IsZeroResult<felt252> nopanic;
 
-impl Felt252TryIntoNonZero of TryInto<felt252, NonZero<felt252>> {
+pub impl Felt252TryIntoNonZero of TryInto<felt252, NonZero<felt252>> {
     fn try_into(self: felt252) -> Option<NonZero<felt252>> {
         match felt252_is_zero(self) {
             zeroable::IsZeroResult::Zero(()) => Option::None(()),
@@ -187,14 +187,14 @@ impl Felt252TryIntoNonZero of TryInto<felt252, NonZero<felt252>> {
     }
 }
 
-impl Felt252Default of Default<felt252> {
+pub impl Felt252Default of Default<felt252> {
     #[inline(always)]
     fn default() -> felt252 nopanic {
         0
     }
 }
 
-impl Felt252Felt252DictValue of Felt252DictValue<felt252> {
+pub impl Felt252Felt252DictValue of Felt252DictValue<felt252> {
     #[inline(always)]
     fn zero_default() -> felt252 nopanic {
         0
@@ -202,120 +202,135 @@ impl Felt252Felt252DictValue of Felt252DictValue<felt252> {
 }
 
 // TODO(spapini): Constraint using Copy and Drop traits.
-extern fn dup<T>(obj: T) -> (T, T) nopanic;
-extern fn drop<T>(obj: T) nopanic;
+pub extern fn dup<T>(obj: T) -> (T, T) nopanic;
+pub extern fn drop<T>(obj: T) nopanic;
 
 // Boxes.
-mod box;
-use box::{Box, BoxTrait};
+pub mod box;
+pub use box::{Box, BoxTrait};
 
 // Nullable
-mod nullable;
-use nullable::{Nullable, match_nullable, null, nullable_from_box};
+pub mod nullable;
+pub use nullable::{Nullable, match_nullable, null, nullable_from_box};
 
 // Arrays.
-mod array;
-use array::{Array, ArrayTrait};
-type usize = u32;
+pub mod array;
+pub use array::{Array, ArrayTrait};
+pub type usize = u32;
 
 // Span.
-use array::Span;
+pub use array::Span;
 
 
 // Dictionary.
-mod dict;
-use dict::{
+pub mod dict;
+pub use dict::{
     Felt252Dict, SquashedFelt252Dict, felt252_dict_new, felt252_dict_squash, Felt252DictTrait
 };
 
 // Result.
-mod result;
-use result::Result;
+pub mod result;
+pub use result::Result;
 
 // Option.
-mod option;
-use option::Option;
+pub mod option;
+pub use option::Option;
 
 // Clone.
-mod clone;
-use clone::Clone;
+pub mod clone;
+pub use clone::Clone;
 
 // EC.
-mod ec;
-use ec::{EcOp, EcPoint, EcState};
+pub mod ec;
+pub use ec::{EcOp, EcPoint, EcState};
 
-mod ecdsa;
+pub mod ecdsa;
 
 // Integer.
-mod integer;
-use integer::{
+pub mod integer;
+pub use integer::{
     NumericLiteral, u128, u128_const, u128_sqrt, u128_is_zero, u8, u8_const, u16, u16_const, u32,
     u32_const, u64, u64_const, u256, u256_sqrt, Felt252TryIntoU8, U8IntoFelt252, Felt252TryIntoU16,
     U16IntoFelt252, Felt252TryIntoU32, U32IntoFelt252, Felt252TryIntoU64, U64IntoFelt252,
     Felt252TryIntoU128, U128IntoFelt252, U16TryIntoU8, U32TryIntoU16, U64TryIntoU32, U128TryIntoU64,
-    Felt252IntoU256, Bitwise
+    Felt252IntoU256, Bitwise, i8, i8_const, i16, i16_const, i32, i32_const, i64, i64_const, i128,
+    i128_const, Felt252TryIntoI8, I8IntoFelt252, Felt252TryIntoI16, I16IntoFelt252,
+    Felt252TryIntoI32, I32IntoFelt252, Felt252TryIntoI64, I64IntoFelt252, Felt252TryIntoI128,
+    I128IntoFelt252
 };
 
 // Math.
-mod math;
+pub mod math;
+
+// Bytes31.
+pub mod bytes_31;
+pub use bytes_31::{bytes31, bytes31_const, Bytes31IntoFelt252, Felt252TryIntoBytes31};
+
+// ByteArray.
+pub mod byte_array;
+pub use byte_array::{ByteArray, ByteArrayImpl, ByteArrayTrait, panic_with_byte_array};
 
 // Cmp.
-mod cmp;
+pub mod cmp;
+
+// Iterators.
+pub mod iter;
+pub use iter::{IntoIterator, Iterator};
 
 // Gas.
-mod gas;
-use gas::{BuiltinCosts, GasBuiltin, get_builtin_costs};
+pub mod gas;
+pub use gas::{BuiltinCosts, GasBuiltin, get_builtin_costs};
 
 
 // Panics.
-mod panics;
-use panics::{panic, Panic, PanicResult};
+pub mod panics;
+pub use panics::{panic, Panic, PanicResult};
 
-enum never {}
+pub enum never {}
 
 #[inline(always)]
-fn panic_with_felt252(err_code: felt252) -> never {
-    let mut data = Default::default();
+pub fn panic_with_felt252(err_code: felt252) -> never {
+    let mut data: Array<felt252> = Default::default();
     data.append(err_code);
     panic(data)
 }
 
 #[inline(always)]
-fn assert(cond: bool, err_code: felt252) {
+pub fn assert(cond: bool, err_code: felt252) {
     if !cond {
         panic_with_felt252(err_code)
     }
 }
 
 // Serialization and Deserialization.
-mod serde;
+pub mod serde;
 
 // Hash functions.
-mod hash;
-use hash::{pedersen, Pedersen};
+pub mod hash;
+pub use hash::{pedersen, Pedersen};
 
-mod keccak;
+pub mod keccak;
 
 // Poseidon
-mod poseidon;
-use poseidon::Poseidon;
+pub mod poseidon;
+pub use poseidon::Poseidon;
 
 // Debug.
-mod debug;
+pub mod debug;
 
 // Starknet
-mod starknet;
-use starknet::System;
+pub mod starknet;
+pub use starknet::System;
 
 // Internals.
-mod internal;
+pub mod internal;
 
 // Zeroable.
-mod zeroable;
-use zeroable::{Zeroable, NonZero};
+pub mod zeroable;
+pub use zeroable::{Zeroable, NonZero};
 
 #[cfg(test)]
 mod test;
 
 // Module for testing only.
-mod testing;
+pub mod testing;
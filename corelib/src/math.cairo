@@ -1,4 +1,4 @@
-use zeroable::{IsZeroResult, NonZeroIntoImpl, Zeroable};
+use zeroable::{NonZeroIntoImpl, Zeroable};
 use traits::{Into, TryInto};
 use option::OptionTrait;
 use integer::{u256_wide_mul, u512_safe_div_rem_by_u256};
@@ -12,7 +12,7 @@ use integer::{u256_wide_mul, u512_safe_div_rem_by_u256};
 /// `(s, -t)` or `(-s, t)` are the Bezout coefficients (according to `sub_direction`).
 ///
 /// Uses the Extended Euclidean algorithm.
-fn egcd<
+pub fn egcd<
     T,
     impl TCopyImpl: Copy<T>,
     impl TDropImpl: Drop<T>,
@@ -42,7 +42,7 @@ fn egcd<
 
 // TODO(yuval): use signed integers once supported.
 /// Returns the inverse of `a` modulo `n`, or None if `gcd(a, n) > 1`.
-fn inv_mod<
+pub fn inv_mod<
     T,
     impl TCopyImpl: Copy<T>,
     impl TDropImpl: Drop<T>,
@@ -78,7 +78,7 @@ fn inv_mod<
 }
 
 /// Returns `a / b (mod n)`, or None if `b` is not invertible modulo `n`.
-fn u256_div_mod_n(a: u256, b: NonZero<u256>, n: NonZero<u256>) -> Option<u256> {
+pub fn u256_div_mod_n(a: u256, b: NonZero<u256>, n: NonZero<u256>) -> Option<u256> {
     let inv_b = inv_mod(b, n)?;
     let quotient = u256_wide_mul(a, inv_b);
     let (_, quotient_mod_n) = u512_safe_div_rem_by_u256(quotient, n);
@@ -87,7 +87,7 @@ fn u256_div_mod_n(a: u256, b: NonZero<u256>, n: NonZero<u256>) -> Option<u256> {
 
 // === Oneable ===
 
-trait Oneable<T> {
+pub trait Oneable<T> {
     /// Returns the multiplicative identity element of Self, 1.
     fn one() -> T;
     /// Returns whether self is equal to 1, the multiplicative identity element.
@@ -96,7 +96,7 @@ trait Oneable<T> {
     fn is_non_one(self: T) -> bool;
 }
 
-impl U8Oneable of Oneable<u8> {
+pub impl U8Oneable of Oneable<u8> {
     fn one() -> u8 {
         1
     }
@@ -110,7 +110,7 @@ impl U8Oneable of Oneable<u8> {
     }
 }
 
-impl U16Oneable of Oneable<u16> {
+pub impl U16Oneable of Oneable<u16> {
     fn one() -> u16 {
         1
     }
@@ -124,7 +124,7 @@ impl U16Oneable of Oneable<u16> {
     }
 }
 
-impl U32Oneable of Oneable<u32> {
+pub impl U32Oneable of Oneable<u32> {
     fn one() -> u32 {
         1
     }
@@ -138,7 +138,7 @@ impl U32Oneable of Oneable<u32> {
     }
 }
 
-impl U64Oneable of Oneable<u64> {
+pub impl U64Oneable of Oneable<u64> {
     fn one() -> u64 {
         1
     }
@@ -152,7 +152,7 @@ impl U64Oneable of Oneable<u64> {
     }
 }
 
-impl U128Oneable of Oneable<u128> {
+pub impl U128Oneable of Oneable<u128> {
     fn one() -> u128 {
         1
     }
@@ -166,7 +166,7 @@ impl U128Oneable of Oneable<u128> {
     }
 }
 
-impl U256Oneable of Oneable<u256> {
+pub impl U256Oneable of Oneable<u256> {
     fn one() -> u256 {
         1
     }
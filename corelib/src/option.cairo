@@ -1,13 +1,12 @@
-use array::ArrayTrait;
 use serde::Serde;
 use array::SpanTrait;
 
-enum Option<T> {
+pub enum Option<T> {
     Some: T,
     None: (),
 }
 
-impl OptionSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Option<T>> {
+pub impl OptionSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Option<T>> {
     fn serialize(self: @Option<T>, ref output: Array<felt252>) {
         match self {
             Option::Some(x) => {
@@ -29,7 +28,7 @@ impl OptionSerde<T, impl TSerde: Serde<T>, impl TDrop: Drop<T>> of Serde<Option<
     }
 }
 
-trait OptionTrait<T> {
+pub trait OptionTrait<T> {
     /// If `val` is `Option::Some(x)`, returns `x`. Otherwise, panics with `err`.
     fn expect(self: Option<T>, err: felt252) -> T;
     /// If `val` is `Option::Some(x)`, returns `x`. Otherwise, panics.
@@ -39,7 +38,7 @@ trait OptionTrait<T> {
     /// Returns `true` if the `Option` is `Option::None`.
     fn is_none(self: @Option<T>) -> bool;
 }
-impl OptionTraitImpl<T> of OptionTrait<T> {
+pub impl OptionTraitImpl<T> of OptionTrait<T> {
     #[inline(always)]
     fn expect(self: Option<T>, err: felt252) -> T {
         match self {
@@ -68,5 +67,5 @@ impl OptionTraitImpl<T> of OptionTrait<T> {
 }
 
 // Impls for generic types.
-impl OptionCopy<T, impl TCopy: Copy<T>> of Copy<Option<T>>;
-impl OptionDrop<T, impl TDrop: Drop<T>> of Drop<Option<T>>;
+pub impl OptionCopy<T, impl TCopy: Copy<T>> of Copy<Option<T>>;
+pub impl OptionDrop<T, impl TDrop: Drop<T>> of Drop<Option<T>>;
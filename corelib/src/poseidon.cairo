@@ -2,19 +2,19 @@ use array::Span;
 use array::SpanTrait;
 use option::OptionTrait;
 
-extern type Poseidon;
+pub extern type Poseidon;
 
-extern fn hades_permutation(
+pub extern fn hades_permutation(
     s0: felt252, s1: felt252, s2: felt252
 ) -> (felt252, felt252, felt252) implicits(Poseidon) nopanic;
 
 
 // Represents a Poseidon state.
 #[derive(Copy, Drop)]
-struct PoseidonBuiltinState {
-    s0: felt252,
-    s1: felt252,
-    s2: felt252,
+pub struct PoseidonBuiltinState {
+    pub s0: felt252,
+    pub s1: felt252,
+    pub s2: felt252,
 }
 
 
@@ -24,7 +24,7 @@ struct PoseidonBuiltinState {
 /// To distinguish between use cases, the capacity element is initialized to 0.
 /// To distinguish between different input sizes always pads with 1, and possibly with another 0 to
 /// complete to an even-sized input.
-fn poseidon_hash_span(mut span: Span<felt252>) -> felt252 {
+pub fn poseidon_hash_span(mut span: Span<felt252>) -> felt252 {
     let builtin_costs = get_builtin_costs();
     _poseidon_hash_span_inner(builtin_costs, PoseidonBuiltinState { s0: 0, s1: 0, s2: 0 }, ref span)
 }
@@ -37,7 +37,7 @@ fn _poseidon_hash_span_inner(
         Option::Some(x) => x,
         Option::None(()) => {
             // Pad input with [1, 0].
-            let (s0, s1, s2) = hades_permutation(state.s0 + 1, state.s1, state.s2);
+            let (s0, _s1, _s2) = hades_permutation(state.s0 + 1, state.s1, state.s2);
             return s0;
         },
     };
@@ -45,7 +45,7 @@ fn _poseidon_hash_span_inner(
         Option::Some(y) => y,
         Option::None(()) => {
             // Add x and pad with [0].
-            let (s0, s1, s2) = hades_permutation(state.s0 + *x, state.s1 + 1, state.s2);
+            let (s0, _s1, _s2) = hades_permutation(state.s0 + *x, state.s1 + 1, state.s2);
             return s0;
         },
     };
@@ -1,9 +1,8 @@
-use array::ArrayTrait;
-enum Result<T, E> {
+pub enum Result<T, E> {
     Ok: T,
     Err: E,
 }
-trait ResultTrait<T, E> {
+pub trait ResultTrait<T, E> {
     /// If `val` is `Result::Ok(x)`, returns `x`. Otherwise, panics with `err`.
     fn expect<impl EDrop: Drop<E>>(self: Result<T, E>, err: felt252) -> T;
     /// If `val` is `Result::Ok(x)`, returns `x`. Otherwise, panics.
@@ -21,7 +20,7 @@ trait ResultTrait<T, E> {
     /// Returns `true` if the `Result` is `Result::Err`, and consumes the value.
     fn into_is_ok<impl TDrop: Drop<T>, impl EDrop: Drop<E>>(self: Result<T, E>) -> bool;
 }
-impl ResultTraitImpl<T, E> of ResultTrait<T, E> {
+pub impl ResultTraitImpl<T, E> of ResultTrait<T, E> {
     fn expect<impl EDrop: Drop<E>>(self: Result<T, E>, err: felt252) -> T {
         match self {
             Result::Ok(x) => x,
@@ -71,5 +70,5 @@ impl ResultTraitImpl<T, E> of ResultTrait<T, E> {
 }
 
 // Impls for generic types.
-impl ResultCopy<T, E, impl TCopy: Copy<T>, impl ECopy: Copy<E>> of Copy<Result<T, E>>;
-impl ResultDrop<T, E, impl TDrop: Drop<T>, impl EDrop: Drop<E>> of Drop<Result<T, E>>;
+pub impl ResultCopy<T, E, impl TCopy: Copy<T>, impl ECopy: Copy<E>> of Copy<Result<T, E>>;
+pub impl ResultDrop<T, E, impl TDrop: Drop<T>, impl EDrop: Drop<E>> of Drop<Result<T, E>>;
@@ -1,21 +1,16 @@
-use array::ArrayTrait;
-use array::SpanTrait;
-use traits::Into;
-use traits::TryInto;
-
-trait Serde<T> {
+pub trait Serde<T> {
     fn serialize(self: @T, ref output: Array<felt252>);
     fn deserialize(ref serialized: Span<felt252>) -> Option<T>;
 }
 
-impl TupleSize0Serde of Serde<()> {
+pub impl TupleSize0Serde of Serde<()> {
     fn serialize(self: @(), ref output: Array<felt252>) {}
     fn deserialize(ref serialized: Span<felt252>) -> Option<()> {
         Option::Some(())
     }
 }
 
-impl TupleSize1Serde<E0, impl E0Serde: Serde<E0>> of Serde<(E0, )> {
+pub impl TupleSize1Serde<E0, impl E0Serde: Serde<E0>> of Serde<(E0, )> {
     fn serialize(self: @(E0, ), ref output: Array<felt252>) {
         let (e0, ) = self;
         e0.serialize(ref output)
@@ -25,7 +20,7 @@ impl TupleSize1Serde<E0, impl E0Serde: Serde<E0>> of Serde<(E0, )> {
     }
 }
 
-impl TupleSize2Serde<
+pub impl TupleSize2Serde<
     E0,
     E1,
     impl E0Serde: Serde<E0>,
@@ -43,7 +38,7 @@ impl TupleSize2Serde<
     }
 }
 
-impl TupleSize3Serde<
+pub impl TupleSize3Serde<
     E0,
     E1,
     E2,
@@ -71,7 +66,7 @@ impl TupleSize3Serde<
     }
 }
 
-impl TupleSize4Serde<
+pub impl TupleSize4Serde<
     E0,
     E1,
     E2,
@@ -1,13 +1,13 @@
 use starknet::ContractAddress;
 
 #[derive(Drop, Serde)]
-struct Call {
-    to: ContractAddress,
-    selector: felt252,
-    calldata: Array<felt252>
+pub struct Call {
+    pub to: ContractAddress,
+    pub selector: felt252,
+    pub calldata: Array<felt252>
 }
 
-trait AccountContract<TContractState> {
+pub trait AccountContract<TContractState> {
     fn __validate_declare__(self: @TContractState, class_hash: felt252) -> felt252;
     fn __validate__(ref self: TContractState, calls: Array<Call>) -> felt252;
     fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
@@ -2,28 +2,28 @@ use zeroable::Zeroable;
 use serde::Serde;
 
 #[derive(Copy, Drop)]
-extern type ClassHash;
+pub extern type ClassHash;
 
 
-extern fn class_hash_const<const address: felt252>() -> ClassHash nopanic;
-extern fn class_hash_to_felt252(address: ClassHash) -> felt252 nopanic;
+pub extern fn class_hash_const<const address: felt252>() -> ClassHash nopanic;
+pub extern fn class_hash_to_felt252(address: ClassHash) -> felt252 nopanic;
 
-extern fn class_hash_try_from_felt252(
+pub extern fn class_hash_try_from_felt252(
     address: felt252
 ) -> Option<ClassHash> implicits(RangeCheck) nopanic;
 
-impl Felt252TryIntoClassHash of TryInto<felt252, ClassHash> {
+pub impl Felt252TryIntoClassHash of TryInto<felt252, ClassHash> {
     fn try_into(self: felt252) -> Option<ClassHash> {
         class_hash_try_from_felt252(self)
     }
 }
-impl ClassHashIntoFelt252 of Into<ClassHash, felt252> {
+pub impl ClassHashIntoFelt252 of Into<ClassHash, felt252> {
     fn into(self: ClassHash) -> felt252 {
         class_hash_to_felt252(self)
     }
 }
 
-impl ClassHashZeroable of Zeroable<ClassHash> {
+pub impl ClassHashZeroable of Zeroable<ClassHash> {
     fn zero() -> ClassHash {
         class_hash_const::<0>()
     }
@@ -37,7 +37,7 @@ impl ClassHashZeroable of Zeroable<ClassHash> {
     }
 }
 
-impl ClassHashSerde of serde::Serde<ClassHash> {
+pub impl ClassHashSerde of serde::Serde<ClassHash> {
     fn serialize(self: @ClassHash, ref output: Array<felt252>) {
         class_hash_to_felt252(*self).serialize(ref output);
     }
@@ -48,7 +48,7 @@ impl ClassHashSerde of serde::Serde<ClassHash> {
     }
 }
 
-impl ClassHashPartialEq of PartialEq<ClassHash> {
+pub impl ClassHashPartialEq of PartialEq<ClassHash> {
     #[inline(always)]
     fn eq(lhs: @ClassHash, rhs: @ClassHash) -> bool {
         class_hash_to_felt252(*lhs) == class_hash_to_felt252(*rhs)
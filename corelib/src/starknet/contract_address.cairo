@@ -2,28 +2,28 @@ use zeroable::Zeroable;
 use serde::Serde;
 
 #[derive(Copy, Drop)]
-extern type ContractAddress;
+pub extern type ContractAddress;
 
 
-extern fn contract_address_const<const address: felt252>() -> ContractAddress nopanic;
-extern fn contract_address_to_felt252(address: ContractAddress) -> felt252 nopanic;
+pub extern fn contract_address_const<const address: felt252>() -> ContractAddress nopanic;
+pub extern fn contract_address_to_felt252(address: ContractAddress) -> felt252 nopanic;
 
-extern fn contract_address_try_from_felt252(
+pub extern fn contract_address_try_from_felt252(
     address: felt252
 ) -> Option<ContractAddress> implicits(RangeCheck) nopanic;
 
-impl Felt252TryIntoContractAddress of TryInto<felt252, ContractAddress> {
+pub impl Felt252TryIntoContractAddress of TryInto<felt252, ContractAddress> {
     fn try_into(self: felt252) -> Option<ContractAddress> {
         contract_address_try_from_felt252(self)
     }
 }
-impl ContractAddressIntoFelt252 of Into<ContractAddress, felt252> {
+pub impl ContractAddressIntoFelt252 of Into<ContractAddress, felt252> {
     fn into(self: ContractAddress) -> felt252 {
         contract_address_to_felt252(self)
     }
 }
 
-impl ContractAddressZeroable of Zeroable<ContractAddress> {
+pub impl ContractAddressZeroable of Zeroable<ContractAddress> {
     fn zero() -> ContractAddress {
         contract_address_const::<0>()
     }
@@ -37,7 +37,7 @@ impl ContractAddressZeroable of Zeroable<ContractAddress> {
     }
 }
 
-impl ContractAddressSerde of serde::Serde<ContractAddress> {
+pub impl ContractAddressSerde of serde::Serde<ContractAddress> {
     fn serialize(self: @ContractAddress, ref output: Array<felt252>) {
         contract_address_to_felt252(*self).serialize(ref output);
     }
@@ -50,7 +50,7 @@ impl ContractAddressSerde of serde::Serde<ContractAddress> {
     }
 }
 
-impl ContractAddressPartialEq of PartialEq<ContractAddress> {
+pub impl ContractAddressPartialEq of PartialEq<ContractAddress> {
     #[inline(always)]
     fn eq(lhs: @ContractAddress, rhs: @ContractAddress) -> bool {
         contract_address_to_felt252(*lhs) == contract_address_to_felt252(*rhs)
@@ -1,5 +1,4 @@
 use debug::PrintTrait;
-use integer::{u128_safe_divmod, U128TryIntoNonZero, U256TryIntoFelt252};
 use option::{Option, OptionTrait};
 use serde::Serde;
 use traits::{Into, TryInto};
@@ -7,10 +6,10 @@ use zeroable::Zeroable;
 
 // An Ethereum address (160 bits).
 #[derive(Copy, Drop, storage_access::StorageAccess)]
-struct EthAddress {
-    address: felt252, 
+pub struct EthAddress {
+    pub address: felt252, 
 }
-impl Felt252TryIntoEthAddress of TryInto<felt252, EthAddress> {
+pub impl Felt252TryIntoEthAddress of TryInto<felt252, EthAddress> {
     fn try_into(self: felt252) -> Option<EthAddress> {
         let ETH_ADDRESS_BOUND = 0x10000000000000000000000000000000000000000_u256; // 2 ** 160
 
@@ -21,12 +20,12 @@ impl Felt252TryIntoEthAddress of TryInto<felt252, EthAddress> {
         }
     }
 }
-impl EthAddressIntoFelt252 of Into<EthAddress, felt252> {
+pub impl EthAddressIntoFelt252 of Into<EthAddress, felt252> {
     fn into(self: EthAddress) -> felt252 {
         self.address
     }
 }
-impl U256IntoEthAddress of Into<u256, EthAddress> {
+pub impl U256IntoEthAddress of Into<u256, EthAddress> {
     fn into(self: u256) -> EthAddress {
         // The Ethereum address is the 20 least significant bytes (=160=128+32 bits) of the value.
         let high_32_bits = self.high % 0x100000000_u128;
@@ -36,7 +35,7 @@ impl U256IntoEthAddress of Into<u256, EthAddress> {
         }
     }
 }
-impl EthAddressSerde of Serde<EthAddress> {
+pub impl EthAddressSerde of Serde<EthAddress> {
     fn serialize(self: @EthAddress, ref output: Array<felt252>) {
         self.address.serialize(ref output);
     }
@@ -44,7 +43,7 @@ impl EthAddressSerde of Serde<EthAddress> {
         Serde::<felt252>::deserialize(ref serialized)?.try_into()
     }
 }
-impl EthAddressZeroable of Zeroable<EthAddress> {
+pub impl EthAddressZeroable of Zeroable<EthAddress> {
     fn zero() -> EthAddress {
         0.try_into().unwrap()
     }
@@ -57,7 +56,7 @@ impl EthAddressZeroable of Zeroable<EthAddress> {
         !self.is_zero()
     }
 }
-impl EthAddressPartialEq of PartialEq<EthAddress> {
+pub impl EthAddressPartialEq of PartialEq<EthAddress> {
     #[inline(always)]
     fn eq(lhs: @EthAddress, rhs: @EthAddress) -> bool {
         *lhs.address == *rhs.address
@@ -68,7 +67,7 @@ impl EthAddressPartialEq of PartialEq<EthAddress> {
     }
 }
 
-impl EthAddressPrintImpl of PrintTrait<EthAddress> {
+pub impl EthAddressPrintImpl of PrintTrait<EthAddress> {
     fn print(self: EthAddress) {
         self.address.print();
     }
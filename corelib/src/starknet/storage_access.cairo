@@ -8,40 +8,40 @@ use starknet::{
 use serde::Serde;
 
 #[derive(Copy, Drop)]
-extern type StorageAddress;
+pub extern type StorageAddress;
 
 #[derive(Copy, Drop)]
-extern type StorageBaseAddress;
+pub extern type StorageBaseAddress;
 
 // Storage.
-extern fn storage_base_address_const<const address: felt252>() -> StorageBaseAddress nopanic;
-extern fn storage_base_address_from_felt252(
+pub extern fn storage_base_address_const<const address: felt252>() -> StorageBaseAddress nopanic;
+pub extern fn storage_base_address_from_felt252(
     addr: felt252
 ) -> StorageBaseAddress implicits(RangeCheck) nopanic;
 
-extern fn storage_address_to_felt252(address: StorageAddress) -> felt252 nopanic;
-extern fn storage_address_from_base_and_offset(
+pub extern fn storage_address_to_felt252(address: StorageAddress) -> felt252 nopanic;
+pub extern fn storage_address_from_base_and_offset(
     base: StorageBaseAddress, offset: u8
 ) -> StorageAddress nopanic;
 
-extern fn storage_address_from_base(base: StorageBaseAddress) -> StorageAddress nopanic;
+pub extern fn storage_address_from_base(base: StorageBaseAddress) -> StorageAddress nopanic;
 
-extern fn storage_address_try_from_felt252(
+pub extern fn storage_address_try_from_felt252(
     address: felt252
 ) -> Option<StorageAddress> implicits(RangeCheck) nopanic;
 
-impl Felt252TryIntoStorageAddress of TryInto<felt252, StorageAddress> {
+pub impl Felt252TryIntoStorageAddress of TryInto<felt252, StorageAddress> {
     fn try_into(self: felt252) -> Option<StorageAddress> {
         storage_address_try_from_felt252(self)
     }
 }
-impl StorageAddressIntoFelt252 of Into<StorageAddress, felt252> {
+pub impl StorageAddressIntoFelt252 of Into<StorageAddress, felt252> {
     fn into(self: StorageAddress) -> felt252 {
         storage_address_to_felt252(self)
     }
 }
 
-impl StorageAddressSerde of serde::Serde<StorageAddress> {
+pub impl StorageAddressSerde of serde::Serde<StorageAddress> {
     fn serialize(self: @StorageAddress, ref output: Array<felt252>) {
         storage_address_to_felt252(*self).serialize(ref output);
     }
@@ -52,7 +52,7 @@ impl StorageAddressSerde of serde::Serde<StorageAddress> {
     }
 }
 
-trait StorageAccess<T> {
+pub trait StorageAccess<T> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<T>;
     fn write(address_domain: u32, base: StorageBaseAddress, value: T) -> SyscallResult<()>;
     fn read_at_offset_internal(
@@ -64,7 +64,7 @@ trait StorageAccess<T> {
     fn size_internal(value: T) -> u8;
 }
 
-impl StorageAccessFelt252 of StorageAccess<felt252> {
+pub impl StorageAccessFelt252 of StorageAccess<felt252> {
     #[inline(always)]
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<felt252> {
         storage_read_syscall(address_domain, storage_address_from_base(base))
@@ -93,7 +93,7 @@ impl StorageAccessFelt252 of StorageAccess<felt252> {
     }
 }
 
-impl StorageAccessBool of StorageAccess<bool> {
+pub impl StorageAccessBool of StorageAccess<bool> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<bool> {
         Result::Ok(StorageAccess::<felt252>::read(address_domain, base)? != 0)
     }
@@ -131,7 +131,7 @@ impl StorageAccessBool of StorageAccess<bool> {
     }
 }
 
-impl StorageAccessU8 of StorageAccess<u8> {
+pub impl StorageAccessU8 of StorageAccess<u8> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u8> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -167,7 +167,7 @@ impl StorageAccessU8 of StorageAccess<u8> {
     }
 }
 
-impl StorageAccessU16 of StorageAccess<u16> {
+pub impl StorageAccessU16 of StorageAccess<u16> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u16> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -203,7 +203,7 @@ impl StorageAccessU16 of StorageAccess<u16> {
     }
 }
 
-impl StorageAccessU32 of StorageAccess<u32> {
+pub impl StorageAccessU32 of StorageAccess<u32> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u32> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -239,7 +239,7 @@ impl StorageAccessU32 of StorageAccess<u32> {
     }
 }
 
-impl StorageAccessU64 of StorageAccess<u64> {
+pub impl StorageAccessU64 of StorageAccess<u64> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u64> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -275,7 +275,7 @@ impl StorageAccessU64 of StorageAccess<u64> {
     }
 }
 
-impl StorageAccessU128 of StorageAccess<u128> {
+pub impl StorageAccessU128 of StorageAccess<u128> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<u128> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -311,7 +311,7 @@ impl StorageAccessU128 of StorageAccess<u128> {
     }
 }
 
-impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
+pub impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<StorageAddress> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -349,7 +349,7 @@ impl StorageAccessStorageAddress of StorageAccess<StorageAddress> {
     }
 }
 
-impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
+pub impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<ContractAddress> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?
@@ -387,7 +387,7 @@ impl StorageAccessContractAddress of StorageAccess<ContractAddress> {
     }
 }
 
-impl StorageAccessClassHash of StorageAccess<ClassHash> {
+pub impl StorageAccessClassHash of StorageAccess<ClassHash> {
     fn read(address_domain: u32, base: StorageBaseAddress) -> SyscallResult<ClassHash> {
         Result::Ok(
             StorageAccess::<felt252>::read(address_domain, base)?.try_into().expect('Non ClassHash')
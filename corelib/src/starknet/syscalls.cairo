@@ -7,7 +7,7 @@ use starknet::{
 // `address` - The address of the called contract.
 // `entry_point_selector` - A selector for a function within that contract.
 // `calldata` - Call arguments.
-extern fn call_contract_syscall(
+pub extern fn call_contract_syscall(
     address: ContractAddress, entry_point_selector: felt252, calldata: Span<felt252>
 ) -> SyscallResult<Span<felt252>> implicits(GasBuiltin, System) nopanic;
 
@@ -17,7 +17,7 @@ extern fn call_contract_syscall(
 //     computation of the contract's address.
 // `calldata` - Call arguments for the constructor.
 // `deploy_from_zero` - Deploy the contract from the zero address.
-extern fn deploy_syscall(
+pub extern fn deploy_syscall(
     class_hash: ClassHash,
     contract_address_salt: felt252,
     calldata: Span<felt252>,
@@ -27,17 +27,17 @@ extern fn deploy_syscall(
 // Emits an event.
 // `keys` - The keys of the event.
 // `data` - The data of the event.
-extern fn emit_event_syscall(
+pub extern fn emit_event_syscall(
     keys: Span<felt252>, data: Span<felt252>
 ) -> SyscallResult<()> implicits(GasBuiltin, System) nopanic;
 
 // Gets the block hash of the block with the given number.
-extern fn get_block_hash_syscall(
+pub extern fn get_block_hash_syscall(
     block_number: u64
 ) -> SyscallResult<felt252> implicits(GasBuiltin, System) nopanic;
 
 // Gets information about the current execution.
-extern fn get_execution_info_syscall() -> SyscallResult<Box<starknet::info::ExecutionInfo>> implicits(
+pub extern fn get_execution_info_syscall() -> SyscallResult<Box<starknet::info::ExecutionInfo>> implicits(
     GasBuiltin, System
 ) nopanic;
 
@@ -45,7 +45,7 @@ extern fn get_execution_info_syscall() -> SyscallResult<Box<starknet::info::Exec
 // `class_hash` - The hash of the class you want to use.
 // `function_selector` - A selector for a function within that class.
 // `calldata` - Call arguments.
-extern fn library_call_syscall(
+pub extern fn library_call_syscall(
     class_hash: ClassHash, function_selector: felt252, calldata: Span<felt252>
 ) -> SyscallResult<Span<felt252>> implicits(GasBuiltin, System) nopanic;
 
@@ -53,7 +53,7 @@ extern fn library_call_syscall(
 // Sends a message to L1.
 // `to_address` - The recipient's L1 address.
 // `payload` - The content of the message.
-extern fn send_message_to_l1_syscall(
+pub extern fn send_message_to_l1_syscall(
     to_address: felt252, payload: Span<felt252>
 ) -> SyscallResult<()> implicits(GasBuiltin, System) nopanic;
 
@@ -62,7 +62,7 @@ extern fn send_message_to_l1_syscall(
 //     in the future it will enable access to address spaces with different data availability
 //     guarantees.
 // `address` - The address of the storage key to read.
-extern fn storage_read_syscall(
+pub extern fn storage_read_syscall(
     address_domain: u32, address: StorageAddress, 
 ) -> SyscallResult<felt252> implicits(GasBuiltin, System) nopanic;
 
@@ -72,14 +72,14 @@ extern fn storage_read_syscall(
 //     guarantees.
 // `address` - The address of the storage key to write.
 // `value` - The value to write to the key.
-extern fn storage_write_syscall(
+pub extern fn storage_write_syscall(
     address_domain: u32, address: StorageAddress, value: felt252
 ) -> SyscallResult<()> implicits(GasBuiltin, System) nopanic;
 
 
 // Replaces the class hash of the current contract.
 // `class_hash` - The class hash that should replace the current one.
-extern fn replace_class_syscall(
+pub extern fn replace_class_syscall(
     class_hash: ClassHash
 ) -> SyscallResult<()> implicits(GasBuiltin, System) nopanic;
 
@@ -87,6 +87,6 @@ extern fn replace_class_syscall(
 // Computes the keccak of the input.
 // The system call does not add any padding and the input needs to be a multiple of 1088 bits
 // (== 17 u64 word).
-extern fn keccak_syscall(
+pub extern fn keccak_syscall(
     input: Span<u64>
 ) -> SyscallResult<u256> implicits(GasBuiltin, System) nopanic;
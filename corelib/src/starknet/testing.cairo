@@ -1,17 +1,31 @@
 use starknet::ContractAddress;
 
-extern fn set_caller_address(address: ContractAddress) implicits() nopanic;
-extern fn set_contract_address(address: ContractAddress) implicits() nopanic;
-extern fn set_sequencer_address(address: ContractAddress) implicits() nopanic;
-extern fn set_block_number(block_number: u64) implicits() nopanic;
-extern fn set_block_timestamp(block_timestamp: u64) implicits() nopanic;
-extern fn set_version(version: felt252) implicits() nopanic;
-extern fn set_account_contract_address(address: ContractAddress) implicits() nopanic;
-extern fn set_max_fee(fee: u128) implicits() nopanic;
-extern fn set_transaction_hash(hash: felt252) implicits() nopanic;
-extern fn set_chain_id(chain_id: felt252) implicits() nopanic;
-extern fn set_nonce(nonce: felt252) implicits() nopanic;
-extern fn set_signature(signature: Span<felt252>) implicits() nopanic;
-extern fn pop_log(
+pub extern fn set_caller_address(address: ContractAddress) implicits() nopanic;
+pub extern fn set_contract_address(address: ContractAddress) implicits() nopanic;
+pub extern fn set_sequencer_address(address: ContractAddress) implicits() nopanic;
+pub extern fn set_block_number(block_number: u64) implicits() nopanic;
+pub extern fn set_block_timestamp(block_timestamp: u64) implicits() nopanic;
+pub extern fn set_version(version: felt252) implicits() nopanic;
+pub extern fn set_account_contract_address(address: ContractAddress) implicits() nopanic;
+pub extern fn set_max_fee(fee: u128) implicits() nopanic;
+pub extern fn set_transaction_hash(hash: felt252) implicits() nopanic;
+pub extern fn set_chain_id(chain_id: felt252) implicits() nopanic;
+pub extern fn set_nonce(nonce: felt252) implicits() nopanic;
+pub extern fn set_signature(signature: Span<felt252>) implicits() nopanic;
+pub extern fn set_block_hash(block_number: u64, value: felt252) implicits() nopanic;
+pub extern fn pop_log(
     address: ContractAddress
 ) -> Option<(Span<felt252>, Span<felt252>)> implicits() nopanic;
+/// Makes calls to the entry point `selector` of the contract at `address` return `ret_data`,
+/// without calling the contract.
+pub extern fn mock_call(
+    address: ContractAddress, selector: felt252, ret_data: Span<felt252>
+) implicits() nopanic;
+/// Writes `value` to the storage of the contract at `address`, at the storage address `key`.
+pub extern fn store(address: ContractAddress, key: felt252, value: felt252) implicits() nopanic;
+/// Reads the storage of the contract at `address`, at the storage address `key`.
+pub extern fn load(address: ContractAddress, key: felt252) -> felt252 implicits() nopanic;
+/// Expects the next call to a contract to revert with `error`, in which case the call succeeds
+/// with no return data. A call that doesn't revert fails with `'Call did not revert'`, and a call
+/// that reverts with another error fails as usual.
+pub extern fn expect_revert(error: Span<felt252>) implicits() nopanic;
@@ -1,13 +1,16 @@
 mod array_test;
 mod bool_test;
 mod box_test;
+mod byte_array_test;
 mod dict_test;
 mod ec_test;
 mod felt_test;
 mod cmp_test;
 mod hash_test;
 mod integer_test;
+mod iter_test;
 mod keccak_test;
+mod match_test;
 mod secp256k1_test;
 mod plugins_test;
 mod testing_test;
@@ -2,7 +2,7 @@ use array::{ArrayTrait, SpanTrait};
 use box::BoxTrait;
 use clone::Clone;
 use option::OptionTrait;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 fn test_array_helper() -> Array<felt252> {
     let mut arr = Default::default();
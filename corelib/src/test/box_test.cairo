@@ -1,5 +1,5 @@
 use box::BoxTrait;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 fn test_box_unbox_felt252s() {
@@ -0,0 +1,142 @@
+use array::ArrayTrait;
+use byte_array::{ByteArray, ByteArrayTrait};
+use bytes_31::Bytes31Trait;
+use option::OptionTrait;
+use serde::Serde;
+use traits::{Into, TryInto};
+use test::test_utils::{assert_eq, assert_ne};
+
+#[test]
+fn test_string_literal_short() {
+    let ba: ByteArray = "ABC";
+    assert(ba.data.len() == 0, 'data should be empty');
+    assert_eq(@ba.pending_word, @'ABC', 'wrong pending word');
+    assert_eq(@ba.pending_word_len, @3, 'wrong pending word len');
+}
+
+#[test]
+fn test_string_literal_empty() {
+    let ba: ByteArray = "";
+    assert(ba.data.len() == 0, 'data should be empty');
+    assert_eq(@ba.pending_word, @0, 'wrong pending word');
+    assert_eq(@ba.pending_word_len, @0, 'wrong pending word len');
+}
+
+#[test]
+fn test_string_literal_long() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    assert_eq(@ba.data.len(), @2, 'wrong data len');
+    let first_word: felt252 = (*ba.data[0]).into();
+    let second_word: felt252 = (*ba.data[1]).into();
+    assert_eq(@first_word, @'ABCDEFGHIJKLMNOPQRSTUVWXYZabcde', 'wrong first word');
+    assert_eq(@second_word, @'fghijklmnopqrstuvwxyz0123456789', 'wrong second word');
+    assert_eq(@ba.pending_word, @0, 'wrong pending word');
+    assert_eq(@ba.pending_word_len, @0, 'wrong pending word len');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_append_word() {
+    let mut ba: ByteArray = Default::default();
+    ba.append_word('ABCDEFGHIJKLMNOPQRSTUVWXYZ', 26);
+    ba.append_word('abcdefghij', 10);
+    assert_eq(@ba.len(), @36, 'wrong len');
+    assert_eq(@ba, @"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghij", 'wrong byte array');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_append_byte() {
+    let mut ba: ByteArray = "AB";
+    ba.append_byte(0x43);
+    assert_eq(@ba, @"ABC", 'wrong byte array');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_concat() {
+    let ba1: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let ba2: ByteArray = "abcdefghijklmnopqrstuvwxyz0123456789";
+    let ba3 = ByteArrayTrait::concat(@ba1, @ba2);
+    assert_eq(@ba3.len(), @62, 'wrong len');
+    assert_eq(
+        @ba3, @"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", 'wrong concat'
+    );
+    assert_eq(@(ba1 + ba2), @ba3, 'wrong add');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_add_eq() {
+    let mut ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    ba += "0123456789";
+    assert_eq(
+        @ba, @"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", 'wrong add_eq'
+    );
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_at() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!";
+    assert_eq(@ba.at(0).unwrap(), @0x41, 'wrong byte at 0');
+    assert_eq(@ba.at(30).unwrap(), @0x65, 'wrong byte at 30');
+    assert_eq(@ba.at(31).unwrap(), @0x66, 'wrong byte at 31');
+    assert_eq(@ba.at(62).unwrap(), @0x21, 'wrong byte at 62');
+    assert(ba.at(63).is_none(), 'index 63 out of bounds');
+    assert(ba.at(100).is_none(), 'index 100 out of bounds');
+    assert_eq(@ba[61], @0x39, 'wrong byte at 61');
+}
+
+#[test]
+#[available_gas(10000000)]
+#[should_panic(expected: ('Index out of bounds', ))]
+fn test_index_out_of_bounds() {
+    let ba: ByteArray = "ABC";
+    ba[3];
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_eq() {
+    let ba1: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let ba2: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let ba3: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyZ";
+    let ba4: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxy";
+    assert_eq(@ba1, @ba2, 'ba1 != ba2');
+    assert_ne(@ba1, @ba3, 'ba1 == ba3');
+    assert_ne(@ba1, @ba4, 'ba1 == ba4');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_serde() {
+    let ba: ByteArray = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let mut serialized = ArrayTrait::new();
+    ba.serialize(ref serialized);
+    assert_eq(@serialized.len(), @4, 'wrong serialized len');
+    assert_eq(@*serialized[0], @1, 'wrong number of words');
+    assert_eq(@*serialized[2], @'fghijklmnopqrstuvwxyz', 'wrong pending word');
+    assert_eq(@*serialized[3], @21, 'wrong pending word len');
+    let mut serialized = serialized.span();
+    let deserialized: ByteArray = Serde::deserialize(ref serialized).unwrap();
+    assert_eq(@deserialized, @ba, 'wrong deserialization');
+}
+
+#[test]
+#[available_gas(10000000)]
+fn test_bytes31_at() {
+    let word: bytes31 = 'ABC'.try_into().unwrap();
+    assert_eq(@word.at(0), @0x43, 'wrong byte at 0');
+    assert_eq(@word.at(2), @0x41, 'wrong byte at 2');
+    assert_eq(@word.at(3), @0, 'wrong byte at 3');
+}
+
+#[test]
+#[available_gas(10000000)]
+#[should_panic(
+    expected: (0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3, 0, 'error', 5, )
+)]
+fn test_panic_with_byte_array() {
+    panic_with_byte_array(@"error");
+}
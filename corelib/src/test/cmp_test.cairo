@@ -1,6 +1,6 @@
 use cmp::min;
 use cmp::max;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 // Integer tests
 
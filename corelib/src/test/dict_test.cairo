@@ -2,7 +2,7 @@ use box::BoxTrait;
 use dict::{Felt252DictTrait, Felt252DictEntryTrait};
 use nullable::NullableTrait;
 use traits::Index;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 fn test_dict_new() -> Felt252Dict<felt252> {
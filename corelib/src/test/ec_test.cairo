@@ -2,11 +2,10 @@ use core::traits::Into;
 use option::OptionTrait;
 use ec::{
     ec_mul, ec_neg, ec_point_from_x, ec_point_from_x_nz, ec_point_is_zero, ec_point_new,
-    ec_point_new_nz, ec_point_non_zero, ec_point_try_new, ec_point_try_new_nz, ec_point_unwrap,
-    ec_point_zero, ec_state_add_mul, ec_state_add, ec_state_finalize, ec_state_init,
+    ec_point_non_zero, ec_point_unwrap, ec_state_add_mul, ec_state_add, ec_state_init,
     ec_state_try_finalize_nz
 };
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 #[should_panic]
@@ -1,5 +1,5 @@
 use clone::Clone;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 fn test_felt252_operators() {
@@ -1,5 +1,5 @@
 use array::ArrayTrait;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 fn test_pedersen_hash() {
@@ -3,7 +3,7 @@ use core::traits::Default;
 use option::OptionTrait;
 use integer::{
     BoundedInt, u128_wrapping_sub, u16_sqrt, u32_sqrt, u64_sqrt, u8_sqrt, u512, u256_wide_mul,
-    u256_as_non_zero, u512_safe_div_rem_by_u256, u128_as_non_zero
+    u512_safe_div_rem_by_u256
 };
 use test::test_utils::{assert_eq, assert_ne, assert_le, assert_lt, assert_gt, assert_ge};
 
@@ -1092,3 +1092,379 @@ fn test_u128_byte_reverse() {
         'Wrong byte reverse'
     );
 }
+
+#[test]
+fn test_i8_operators() {
+    assert_eq(@1_i8, @1_i8, '1 == 1');
+    assert_ne(@1_i8, @2_i8, '1 != 2');
+    assert_eq(@-3_i8, @-3_i8, '-3 == -3');
+    assert_ne(@-3_i8, @3_i8, '-3 != 3');
+    assert_eq(@(1_i8 + 3_i8), @4_i8, '1 + 3 == 4');
+    assert_eq(@(3_i8 + -6_i8), @-3_i8, '3 + -6 == -3');
+    assert_eq(@(-3_i8 + -6_i8), @-9_i8, '-3 + -6 == -9');
+    assert_eq(@(3_i8 - 1_i8), @2_i8, '3 - 1 == 2');
+    assert_eq(@(1_i8 - 3_i8), @-2_i8, '1 - 3 == -2');
+    assert_eq(@(-3_i8 - -6_i8), @3_i8, '-3 - -6 == 3');
+    assert_eq(@(2_i8 * 4_i8), @8_i8, '2 * 4 == 8');
+    assert_eq(@(-2_i8 * 4_i8), @-8_i8, '-2 * 4 == -8');
+    assert_eq(@(-2_i8 * -4_i8), @8_i8, '-2 * -4 == 8');
+    assert_eq(@-(-5_i8), @5_i8, '-(-5) == 5');
+    assert_lt(-4_i8, 1_i8, '-4 < 1');
+    assert_le(-4_i8, -4_i8, '-4 <= -4');
+    assert(!(4_i8 < -4_i8), '!(4 < -4)');
+    assert_gt(2_i8, -5_i8, '2 > -5');
+    assert_ge(-2_i8, -5_i8, '-2 >= -5');
+    assert(!(-3_i8 > 3_i8), '!(-3 > 3)');
+    let min: i8 = BoundedInt::min();
+    let max: i8 = BoundedInt::max();
+    assert_eq(@min, @-0x80, 'i8 min');
+    assert_eq(@max, @0x7f, 'i8 max');
+    assert_eq(@(min + max), @-1, 'min + max == -1');
+    let minus_one: felt252 = (-1_i8).into();
+    assert_eq(@minus_one, @-1, '-1 into felt252');
+    let minus_one: i8 = (-1_felt252).try_into().unwrap();
+    assert_eq(@minus_one, @-1, '-1 try_into i8');
+    let below_min: Option<i8> = (-0x80_felt252 - 1).try_into();
+    assert(below_min.is_none(), 'min - 1 not i8');
+    let above_max: Option<i8> = 0x80_felt252.try_into();
+    assert(above_max.is_none(), 'max + 1 not i8');
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_overflow() {
+    0x7f_i8 + 1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_add_underflow() {
+    -0x80_i8 + -1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_sub_overflow() {
+    0x7f_i8 - -1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_sub_underflow() {
+    -0x80_i8 - 1_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_mul_overflow() {
+    0x10_i8 * 0x10_i8;
+}
+
+#[test]
+#[should_panic]
+fn test_i8_neg_overflow() {
+    let min: i8 = BoundedInt::min();
+    -min;
+}
+
+#[test]
+fn test_i16_operators() {
+    assert_eq(@1_i16, @1_i16, '1 == 1');
+    assert_ne(@1_i16, @2_i16, '1 != 2');
+    assert_eq(@-3_i16, @-3_i16, '-3 == -3');
+    assert_ne(@-3_i16, @3_i16, '-3 != 3');
+    assert_eq(@(1_i16 + 3_i16), @4_i16, '1 + 3 == 4');
+    assert_eq(@(3_i16 + -6_i16), @-3_i16, '3 + -6 == -3');
+    assert_eq(@(-3_i16 + -6_i16), @-9_i16, '-3 + -6 == -9');
+    assert_eq(@(3_i16 - 1_i16), @2_i16, '3 - 1 == 2');
+    assert_eq(@(1_i16 - 3_i16), @-2_i16, '1 - 3 == -2');
+    assert_eq(@(-3_i16 - -6_i16), @3_i16, '-3 - -6 == 3');
+    assert_eq(@(2_i16 * 4_i16), @8_i16, '2 * 4 == 8');
+    assert_eq(@(-2_i16 * 4_i16), @-8_i16, '-2 * 4 == -8');
+    assert_eq(@(-2_i16 * -4_i16), @8_i16, '-2 * -4 == 8');
+    assert_eq(@-(-5_i16), @5_i16, '-(-5) == 5');
+    assert_lt(-4_i16, 1_i16, '-4 < 1');
+    assert_le(-4_i16, -4_i16, '-4 <= -4');
+    assert(!(4_i16 < -4_i16), '!(4 < -4)');
+    assert_gt(2_i16, -5_i16, '2 > -5');
+    assert_ge(-2_i16, -5_i16, '-2 >= -5');
+    assert(!(-3_i16 > 3_i16), '!(-3 > 3)');
+    let min: i16 = BoundedInt::min();
+    let max: i16 = BoundedInt::max();
+    assert_eq(@min, @-0x8000, 'i16 min');
+    assert_eq(@max, @0x7fff, 'i16 max');
+    assert_eq(@(min + max), @-1, 'min + max == -1');
+    let minus_one: felt252 = (-1_i16).into();
+    assert_eq(@minus_one, @-1, '-1 into felt252');
+    let minus_one: i16 = (-1_felt252).try_into().unwrap();
+    assert_eq(@minus_one, @-1, '-1 try_into i16');
+    let below_min: Option<i16> = (-0x8000_felt252 - 1).try_into();
+    assert(below_min.is_none(), 'min - 1 not i16');
+    let above_max: Option<i16> = 0x8000_felt252.try_into();
+    assert(above_max.is_none(), 'max + 1 not i16');
+}
+
+#[test]
+#[should_panic]
+fn test_i16_add_overflow() {
+    0x7fff_i16 + 1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_add_underflow() {
+    -0x8000_i16 + -1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_sub_overflow() {
+    0x7fff_i16 - -1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_sub_underflow() {
+    -0x8000_i16 - 1_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_mul_overflow() {
+    0x100_i16 * 0x100_i16;
+}
+
+#[test]
+#[should_panic]
+fn test_i16_neg_overflow() {
+    let min: i16 = BoundedInt::min();
+    -min;
+}
+
+#[test]
+fn test_i32_operators() {
+    assert_eq(@1_i32, @1_i32, '1 == 1');
+    assert_ne(@1_i32, @2_i32, '1 != 2');
+    assert_eq(@-3_i32, @-3_i32, '-3 == -3');
+    assert_ne(@-3_i32, @3_i32, '-3 != 3');
+    assert_eq(@(1_i32 + 3_i32), @4_i32, '1 + 3 == 4');
+    assert_eq(@(3_i32 + -6_i32), @-3_i32, '3 + -6 == -3');
+    assert_eq(@(-3_i32 + -6_i32), @-9_i32, '-3 + -6 == -9');
+    assert_eq(@(3_i32 - 1_i32), @2_i32, '3 - 1 == 2');
+    assert_eq(@(1_i32 - 3_i32), @-2_i32, '1 - 3 == -2');
+    assert_eq(@(-3_i32 - -6_i32), @3_i32, '-3 - -6 == 3');
+    assert_eq(@(2_i32 * 4_i32), @8_i32, '2 * 4 == 8');
+    assert_eq(@(-2_i32 * 4_i32), @-8_i32, '-2 * 4 == -8');
+    assert_eq(@(-2_i32 * -4_i32), @8_i32, '-2 * -4 == 8');
+    assert_eq(@-(-5_i32), @5_i32, '-(-5) == 5');
+    assert_lt(-4_i32, 1_i32, '-4 < 1');
+    assert_le(-4_i32, -4_i32, '-4 <= -4');
+    assert(!(4_i32 < -4_i32), '!(4 < -4)');
+    assert_gt(2_i32, -5_i32, '2 > -5');
+    assert_ge(-2_i32, -5_i32, '-2 >= -5');
+    assert(!(-3_i32 > 3_i32), '!(-3 > 3)');
+    let min: i32 = BoundedInt::min();
+    let max: i32 = BoundedInt::max();
+    assert_eq(@min, @-0x80000000, 'i32 min');
+    assert_eq(@max, @0x7fffffff, 'i32 max');
+    assert_eq(@(min + max), @-1, 'min + max == -1');
+    let minus_one: felt252 = (-1_i32).into();
+    assert_eq(@minus_one, @-1, '-1 into felt252');
+    let minus_one: i32 = (-1_felt252).try_into().unwrap();
+    assert_eq(@minus_one, @-1, '-1 try_into i32');
+    let below_min: Option<i32> = (-0x80000000_felt252 - 1).try_into();
+    assert(below_min.is_none(), 'min - 1 not i32');
+    let above_max: Option<i32> = 0x80000000_felt252.try_into();
+    assert(above_max.is_none(), 'max + 1 not i32');
+}
+
+#[test]
+#[should_panic]
+fn test_i32_add_overflow() {
+    0x7fffffff_i32 + 1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_add_underflow() {
+    -0x80000000_i32 + -1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_sub_overflow() {
+    0x7fffffff_i32 - -1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_sub_underflow() {
+    -0x80000000_i32 - 1_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_mul_overflow() {
+    0x10000_i32 * 0x10000_i32;
+}
+
+#[test]
+#[should_panic]
+fn test_i32_neg_overflow() {
+    let min: i32 = BoundedInt::min();
+    -min;
+}
+
+#[test]
+fn test_i64_operators() {
+    assert_eq(@1_i64, @1_i64, '1 == 1');
+    assert_ne(@1_i64, @2_i64, '1 != 2');
+    assert_eq(@-3_i64, @-3_i64, '-3 == -3');
+    assert_ne(@-3_i64, @3_i64, '-3 != 3');
+    assert_eq(@(1_i64 + 3_i64), @4_i64, '1 + 3 == 4');
+    assert_eq(@(3_i64 + -6_i64), @-3_i64, '3 + -6 == -3');
+    assert_eq(@(-3_i64 + -6_i64), @-9_i64, '-3 + -6 == -9');
+    assert_eq(@(3_i64 - 1_i64), @2_i64, '3 - 1 == 2');
+    assert_eq(@(1_i64 - 3_i64), @-2_i64, '1 - 3 == -2');
+    assert_eq(@(-3_i64 - -6_i64), @3_i64, '-3 - -6 == 3');
+    assert_eq(@(2_i64 * 4_i64), @8_i64, '2 * 4 == 8');
+    assert_eq(@(-2_i64 * 4_i64), @-8_i64, '-2 * 4 == -8');
+    assert_eq(@(-2_i64 * -4_i64), @8_i64, '-2 * -4 == 8');
+    assert_eq(@-(-5_i64), @5_i64, '-(-5) == 5');
+    assert_lt(-4_i64, 1_i64, '-4 < 1');
+    assert_le(-4_i64, -4_i64, '-4 <= -4');
+    assert(!(4_i64 < -4_i64), '!(4 < -4)');
+    assert_gt(2_i64, -5_i64, '2 > -5');
+    assert_ge(-2_i64, -5_i64, '-2 >= -5');
+    assert(!(-3_i64 > 3_i64), '!(-3 > 3)');
+    let min: i64 = BoundedInt::min();
+    let max: i64 = BoundedInt::max();
+    assert_eq(@min, @-0x8000000000000000, 'i64 min');
+    assert_eq(@max, @0x7fffffffffffffff, 'i64 max');
+    assert_eq(@(min + max), @-1, 'min + max == -1');
+    let minus_one: felt252 = (-1_i64).into();
+    assert_eq(@minus_one, @-1, '-1 into felt252');
+    let minus_one: i64 = (-1_felt252).try_into().unwrap();
+    assert_eq(@minus_one, @-1, '-1 try_into i64');
+    let below_min: Option<i64> = (-0x8000000000000000_felt252 - 1).try_into();
+    assert(below_min.is_none(), 'min - 1 not i64');
+    let above_max: Option<i64> = 0x8000000000000000_felt252.try_into();
+    assert(above_max.is_none(), 'max + 1 not i64');
+}
+
+#[test]
+#[should_panic]
+fn test_i64_add_overflow() {
+    0x7fffffffffffffff_i64 + 1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_add_underflow() {
+    -0x8000000000000000_i64 + -1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_sub_overflow() {
+    0x7fffffffffffffff_i64 - -1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_sub_underflow() {
+    -0x8000000000000000_i64 - 1_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_mul_overflow() {
+    0x100000000_i64 * 0x100000000_i64;
+}
+
+#[test]
+#[should_panic]
+fn test_i64_neg_overflow() {
+    let min: i64 = BoundedInt::min();
+    -min;
+}
+
+#[test]
+fn test_i128_operators() {
+    assert_eq(@1_i128, @1_i128, '1 == 1');
+    assert_ne(@1_i128, @2_i128, '1 != 2');
+    assert_eq(@-3_i128, @-3_i128, '-3 == -3');
+    assert_ne(@-3_i128, @3_i128, '-3 != 3');
+    assert_eq(@(1_i128 + 3_i128), @4_i128, '1 + 3 == 4');
+    assert_eq(@(3_i128 + -6_i128), @-3_i128, '3 + -6 == -3');
+    assert_eq(@(-3_i128 + -6_i128), @-9_i128, '-3 + -6 == -9');
+    assert_eq(@(3_i128 - 1_i128), @2_i128, '3 - 1 == 2');
+    assert_eq(@(1_i128 - 3_i128), @-2_i128, '1 - 3 == -2');
+    assert_eq(@(-3_i128 - -6_i128), @3_i128, '-3 - -6 == 3');
+    assert_eq(@(2_i128 * 4_i128), @8_i128, '2 * 4 == 8');
+    assert_eq(@(-2_i128 * 4_i128), @-8_i128, '-2 * 4 == -8');
+    assert_eq(@(-2_i128 * -4_i128), @8_i128, '-2 * -4 == 8');
+    assert_eq(@-(-5_i128), @5_i128, '-(-5) == 5');
+    assert_lt(-4_i128, 1_i128, '-4 < 1');
+    assert_le(-4_i128, -4_i128, '-4 <= -4');
+    assert(!(4_i128 < -4_i128), '!(4 < -4)');
+    assert_gt(2_i128, -5_i128, '2 > -5');
+    assert_ge(-2_i128, -5_i128, '-2 >= -5');
+    assert(!(-3_i128 > 3_i128), '!(-3 > 3)');
+    let min: i128 = BoundedInt::min();
+    let max: i128 = BoundedInt::max();
+    assert_eq(@min, @-0x80000000000000000000000000000000, 'i128 min');
+    assert_eq(@max, @0x7fffffffffffffffffffffffffffffff, 'i128 max');
+    assert_eq(@(min + max), @-1, 'min + max == -1');
+    let minus_one: felt252 = (-1_i128).into();
+    assert_eq(@minus_one, @-1, '-1 into felt252');
+    let minus_one: i128 = (-1_felt252).try_into().unwrap();
+    assert_eq(@minus_one, @-1, '-1 try_into i128');
+    let below_min: Option<i128> = (-0x80000000000000000000000000000000_felt252 - 1).try_into();
+    assert(below_min.is_none(), 'min - 1 not i128');
+    let above_max: Option<i128> = 0x80000000000000000000000000000000_felt252.try_into();
+    assert(above_max.is_none(), 'max + 1 not i128');
+}
+
+#[test]
+#[should_panic]
+fn test_i128_add_overflow() {
+    0x7fffffffffffffffffffffffffffffff_i128 + 1_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_add_underflow() {
+    -0x80000000000000000000000000000000_i128 + -1_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_sub_overflow() {
+    0x7fffffffffffffffffffffffffffffff_i128 - -1_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_sub_underflow() {
+    -0x80000000000000000000000000000000_i128 - 1_i128;
+}
+
+#[test]
+#[should_panic(expected: ('i128_mul Overflow', ))]
+fn test_i128_mul_overflow() {
+    0x10000000000000000_i128 * 0x10000000000000000_i128;
+}
+
+#[test]
+#[should_panic(expected: ('i128_mul Overflow', ))]
+fn test_i128_mul_overflow_in_u128_range() {
+    0x10000000000000000_i128 * 0x8000000000000000_i128;
+}
+
+#[test]
+#[should_panic]
+fn test_i128_neg_overflow() {
+    let min: i128 = BoundedInt::min();
+    -min;
+}
@@ -0,0 +1,124 @@
+use array::ArrayTrait;
+use iter::range;
+use test::test_utils::assert_eq;
+
+#[test]
+#[available_gas(1000000)]
+fn test_while() {
+    let mut i = 0_u32;
+    let mut sum = 0_u32;
+    while i < 5 {
+        sum += i;
+        i += 1;
+    };
+    assert_eq(@i, @5, 'wrong i');
+    assert_eq(@sum, @10, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_while_break_continue() {
+    let mut i = 0_u32;
+    let mut sum = 0_u32;
+    while true {
+        i += 1;
+        if i == 3 {
+            continue;
+        }
+        if i > 5 {
+            break;
+        }
+        sum += i;
+    };
+    assert_eq(@sum, @12, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_array() {
+    let mut arr = ArrayTrait::new();
+    arr.append(1_u32);
+    arr.append(2_u32);
+    arr.append(3_u32);
+    let mut sum = 0_u32;
+    for x in arr {
+        sum += x;
+    };
+    assert_eq(@sum, @6, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_span() {
+    let mut arr = ArrayTrait::new();
+    arr.append(1_u32);
+    arr.append(2_u32);
+    arr.append(3_u32);
+    let mut sum = 0_u32;
+    for x in arr.span() {
+        sum += *x;
+    };
+    assert_eq(@sum, @6, 'wrong sum');
+    assert_eq(@arr.len(), @3, 'array should be untouched');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_range() {
+    let mut sum = 0_u32;
+    for i in range(2_u32, 6_u32) {
+        sum += i;
+    };
+    assert_eq(@sum, @14, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_empty_range() {
+    let mut n = 0_u8;
+    for _i in range(3_u8, 3_u8) {
+        n += 1;
+    };
+    assert_eq(@n, @0, 'range should be empty');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_break_continue() {
+    let mut sum = 0_u32;
+    for i in range(0_u32, 100_u32) {
+        if i % 2 == 0 {
+            continue;
+        }
+        if i > 7 {
+            break;
+        }
+        sum += i;
+    };
+    assert_eq(@sum, @16, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_for_tuple_pattern() {
+    let mut arr = ArrayTrait::new();
+    arr.append((1_u32, 10_u32));
+    arr.append((2_u32, 20_u32));
+    let mut sum = 0_u32;
+    for (a, b) in arr {
+        sum += a * b;
+    };
+    assert_eq(@sum, @50, 'wrong sum');
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_nested_for() {
+    let mut count = 0_u32;
+    for i in range(0_u32, 3_u32) {
+        for _j in range(0_u32, i) {
+            count += 1;
+        };
+    };
+    assert_eq(@count, @3, 'wrong count');
+}
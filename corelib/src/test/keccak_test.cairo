@@ -1,7 +1,6 @@
 use array::ArrayTrait;
-use core::traits::Into;
 use starknet::SyscallResultTrait;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[test]
 #[available_gas(100000)]
@@ -0,0 +1,83 @@
+use test::test_utils::assert_eq;
+
+fn classify(x: u8) -> felt252 {
+    match x {
+        0 => 'zero',
+        1..10 => 'small',
+        10..100 => 'medium',
+        _ => 'large',
+    }
+}
+
+#[test]
+fn test_match_integer_ranges() {
+    assert_eq(@classify(0), @'zero', 'wrong 0');
+    assert_eq(@classify(1), @'small', 'wrong 1');
+    assert_eq(@classify(9), @'small', 'wrong 9');
+    assert_eq(@classify(10), @'medium', 'wrong 10');
+    assert_eq(@classify(99), @'medium', 'wrong 99');
+    assert_eq(@classify(100), @'large', 'wrong 100');
+    assert_eq(@classify(255), @'large', 'wrong 255');
+}
+
+fn signed(x: i8) -> felt252 {
+    match x {
+        0 => 'zero',
+        1..128 => 'positive',
+        _ => 'negative',
+    }
+}
+
+#[test]
+fn test_match_signed_integers() {
+    assert_eq(@signed(-128), @'negative', 'wrong -128');
+    assert_eq(@signed(-1), @'negative', 'wrong -1');
+    assert_eq(@signed(0), @'zero', 'wrong 0');
+    assert_eq(@signed(127), @'positive', 'wrong 127');
+}
+
+fn both(a: Option<u32>, b: Option<u32>) -> u32 {
+    match (a, b) {
+        (Option::Some(x), Option::Some(y)) => x + y,
+        (Option::Some(x), _) => x,
+        (_, Option::Some(5)) => 50,
+        (_, _) => 0,
+    }
+}
+
+#[test]
+#[available_gas(1000000)]
+fn test_match_tuple_of_enums() {
+    assert_eq(@both(Option::Some(1), Option::Some(2)), @3, 'wrong some/some');
+    assert_eq(@both(Option::Some(1), Option::None(())), @1, 'wrong some/none');
+    assert_eq(@both(Option::None(()), Option::Some(5)), @50, 'wrong none/5');
+    assert_eq(@both(Option::None(()), Option::Some(6)), @0, 'wrong none/6');
+    assert_eq(@both(Option::None(()), Option::None(())), @0, 'wrong none/none');
+}
+
+fn nested(a: Option<Option<felt252>>) -> felt252 {
+    match a {
+        Option::None(_) => 0,
+        Option::Some(Option::None(_)) => 1,
+        Option::Some(Option::Some(7)) => 7,
+        Option::Some(Option::Some(x)) => x + 100,
+    }
+}
+
+#[test]
+fn test_match_nested_enums() {
+    assert_eq(@nested(Option::None(())), @0, 'wrong none');
+    assert_eq(@nested(Option::Some(Option::None(()))), @1, 'wrong some/none');
+    assert_eq(@nested(Option::Some(Option::Some(7))), @7, 'wrong 7');
+    assert_eq(@nested(Option::Some(Option::Some(8))), @108, 'wrong 8');
+}
+
+#[test]
+fn test_match_out_of_order_arms() {
+    let x: Option<felt252> = Option::Some(3);
+    let y = match x {
+        Option::None(_) => 0,
+        Option::Some(v) => v,
+    };
+    assert_eq(@y, @3, 'wrong value');
+}
@@ -2,7 +2,7 @@ use array::ArrayTrait;
 use array::SpanTrait;
 use serde::Serde;
 use option::OptionTrait;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 #[derive(Copy, Drop, Serde, PartialEq)]
 enum EnumForSerde {
@@ -1 +1 @@
-extern fn get_available_gas() -> u128 implicits(GasBuiltin) nopanic;
+pub extern fn get_available_gas() -> u128 implicits(GasBuiltin) nopanic;
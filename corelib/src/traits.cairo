@@ -1,62 +1,62 @@
 use core::panics::Panic;
 
-trait Copy<T>;
-trait Drop<T>;
+pub trait Copy<T>;
+pub trait Drop<T>;
 
-impl SnapshotCopy<T> of Copy<@T>;
-impl SnapshotDrop<T> of Drop<@T>;
+pub impl SnapshotCopy<T> of Copy<@T>;
+pub impl SnapshotDrop<T> of Drop<@T>;
 
 // TODO(spapini): When associated types are supported, support the general trait Add<X, Y>.
-trait Add<T> {
+pub trait Add<T> {
     fn add(lhs: T, rhs: T) -> T;
 }
-trait AddEq<T> {
+pub trait AddEq<T> {
     fn add_eq(ref self: T, other: T);
 }
 
 // TODO(spapini): When associated types are supported, support the general trait Sub<X, Y>.
-trait Sub<T> {
+pub trait Sub<T> {
     fn sub(lhs: T, rhs: T) -> T;
 }
-trait SubEq<T> {
+pub trait SubEq<T> {
     fn sub_eq(ref self: T, other: T);
 }
 
 // TODO(spapini): When associated types are supported, support the general trait Mul<X, Y>.
-trait Mul<T> {
+pub trait Mul<T> {
     fn mul(lhs: T, rhs: T) -> T;
 }
-trait MulEq<T> {
+pub trait MulEq<T> {
     fn mul_eq(ref self: T, other: T);
 }
 
 // TODO(spapini): When associated types are supported, support the general trait Div<X, Y>.
-trait Div<T> {
+pub trait Div<T> {
     fn div(lhs: T, rhs: T) -> T;
 }
-trait DivEq<T> {
+pub trait DivEq<T> {
     fn div_eq(ref self: T, other: T);
 }
 
 // TODO(spapini): When associated types are supported, support the general trait Rem<X, Y>.
-trait Rem<T> {
+pub trait Rem<T> {
     fn rem(lhs: T, rhs: T) -> T;
 }
-trait RemEq<T> {
+pub trait RemEq<T> {
     fn rem_eq(ref self: T, other: T);
 }
 
 // TODO(spapini): When associated types are supported, support the general trait DivRem<X, Y>.
 /// Division with remainder.
-trait DivRem<T> {
+pub trait DivRem<T> {
     fn div_rem(lhs: T, rhs: NonZero<T>) -> (T, T);
 }
 
-trait PartialEq<T> {
+pub trait PartialEq<T> {
     fn eq(lhs: @T, rhs: @T) -> bool;
     fn ne(lhs: @T, rhs: @T) -> bool;
 }
-impl PartialEqSnap<T, impl TEq: PartialEq<T>> of PartialEq<@T> {
+pub impl PartialEqSnap<T, impl TEq: PartialEq<T>> of PartialEq<@T> {
     fn eq(lhs: @@T, rhs: @@T) -> bool {
         TEq::eq(*lhs, *rhs)
     }
@@ -66,25 +66,25 @@ impl PartialEqSnap<T, impl TEq: PartialEq<T>> of PartialEq<@T> {
 }
 
 // TODO(spapini): When associated types are supported, support the general trait BitAnd<X, Y>.
-trait BitAnd<T> {
+pub trait BitAnd<T> {
     fn bitand(lhs: T, rhs: T) -> T;
 }
 
 // TODO(spapini): When associated types are supported, support the general trait BitOr<X, Y>.
-trait BitOr<T> {
+pub trait BitOr<T> {
     fn bitor(lhs: T, rhs: T) -> T;
 }
 
 // TODO(spapini): When associated types are supported, support the general trait BitXor<X, Y>.
-trait BitXor<T> {
+pub trait BitXor<T> {
     fn bitxor(lhs: T, rhs: T) -> T;
 }
 
-trait BitNot<T> {
+pub trait BitNot<T> {
     fn bitnot(a: T) -> T;
 }
 
-trait PartialOrd<T> {
+pub trait PartialOrd<T> {
     fn le(lhs: T, rhs: T) -> bool;
     fn ge(lhs: T, rhs: T) -> bool;
     fn lt(lhs: T, rhs: T) -> bool;
@@ -92,64 +92,64 @@ trait PartialOrd<T> {
 }
 
 /// Trait for conversion between types.
-trait Into<T, S> {
+pub trait Into<T, S> {
     fn into(self: T) -> S;
 }
 
-impl TIntoT<T> of Into<T, T> {
+pub impl TIntoT<T> of Into<T, T> {
     fn into(self: T) -> T {
         self
     }
 }
 
 /// Trait for fallible conversion between types.
-trait TryInto<T, S> {
+pub trait TryInto<T, S> {
     fn try_into(self: T) -> Option<S>;
 }
 
-trait Neg<T> {
+pub trait Neg<T> {
     fn neg(a: T) -> T;
 }
 
-trait Not<T> {
+pub trait Not<T> {
     fn not(a: T) -> T;
 }
 
 /// The following two traits are for implementing the [] operator. Only one should be implemented
 /// for each type. Both are not consuming of self, the first gets a snapshot of the object and
 /// the second gets ref.
-trait IndexView<C, I, V> {
+pub trait IndexView<C, I, V> {
     fn index(self: @C, index: I) -> V;
 }
 
-trait Index<C, I, V> {
+pub trait Index<C, I, V> {
     fn index(ref self: C, index: I) -> V;
 }
 
-trait Destruct<T> {
+pub trait Destruct<T> {
     fn destruct(self: T) nopanic;
 }
 // TODO(spapini): Remove this, it can lead to multiple impls and unwanted Destruct implementation.
-impl DestructFromDrop<T, impl TDrop: Drop<T>> of Destruct<T> {
+pub impl DestructFromDrop<T, impl TDrop: Drop<T>> of Destruct<T> {
     #[inline(always)]
     fn destruct(self: T) nopanic {}
 }
 
-trait PanicDestruct<T> {
+pub trait PanicDestruct<T> {
     fn panic_destruct(self: T, ref panic: Panic) nopanic;
 }
-impl PanicDestructForDestruct<T, impl TDestruct: Destruct<T>> of PanicDestruct<T> {
+pub impl PanicDestructForDestruct<T, impl TDestruct: Destruct<T>> of PanicDestruct<T> {
     #[inline(always)]
     fn panic_destruct(self: T, ref panic: Panic) nopanic {
         TDestruct::destruct(self);
     }
 }
 
-trait Default<T> {
+pub trait Default<T> {
     fn default() -> T;
 }
 
-impl SnapshotDefault<T, impl TDefault: Default<T>, impl TDrop: Drop<T>> of Default<@T> {
+pub impl SnapshotDefault<T, impl TDefault: Default<T>, impl TDrop: Drop<T>> of Default<@T> {
     #[inline(always)]
     fn default() -> @T {
         @Default::default()
@@ -157,24 +157,24 @@ impl SnapshotDefault<T, impl TDefault: Default<T>, impl TDrop: Drop<T>> of Defau
 }
 
 /// Trait for types allowed as values in a Felt252Dict.
-trait Felt252DictValue<T> {
+pub trait Felt252DictValue<T> {
     /// Returns the default value for this type as a value in a Felt252Dict.
     /// Should be logically equivalent to 0.
     fn zero_default() -> T nopanic;
 }
 
 // Tuple Copy impls.
-impl TupleSize0Copy of Copy<()>;
+pub impl TupleSize0Copy of Copy<()>;
 
-impl TupleSize1Copy<E0, impl E0Copy: Copy<E0>> of Copy<(E0, )>;
+pub impl TupleSize1Copy<E0, impl E0Copy: Copy<E0>> of Copy<(E0, )>;
 
-impl TupleSize2Copy<E0, E1, impl E0Copy: Copy<E0>, impl E1Copy: Copy<E1>> of Copy<(E0, E1)>;
+pub impl TupleSize2Copy<E0, E1, impl E0Copy: Copy<E0>, impl E1Copy: Copy<E1>> of Copy<(E0, E1)>;
 
-impl TupleSize3Copy<
+pub impl TupleSize3Copy<
     E0, E1, E2, impl E0Copy: Copy<E0>, impl E1Copy: Copy<E1>, impl E2Copy: Copy<E2>
 > of Copy<(E0, E1, E2)>;
 
-impl TupleSize4Copy<
+pub impl TupleSize4Copy<
     E0,
     E1,
     E2,
@@ -186,17 +186,17 @@ impl TupleSize4Copy<
 > of Copy<(E0, E1, E2, E3)>;
 
 // Tuple Drop impls.
-impl TupleSize0Drop of Drop<()>;
+pub impl TupleSize0Drop of Drop<()>;
 
-impl TupleSize1Drop<E0, impl E0Drop: Drop<E0>> of Drop<(E0, )>;
+pub impl TupleSize1Drop<E0, impl E0Drop: Drop<E0>> of Drop<(E0, )>;
 
-impl TupleSize2Drop<E0, E1, impl E0Drop: Drop<E0>, impl E1Drop: Drop<E1>> of Drop<(E0, E1)>;
+pub impl TupleSize2Drop<E0, E1, impl E0Drop: Drop<E0>, impl E1Drop: Drop<E1>> of Drop<(E0, E1)>;
 
-impl TupleSize3Drop<
+pub impl TupleSize3Drop<
     E0, E1, E2, impl E0Drop: Drop<E0>, impl E1Drop: Drop<E1>, impl E2Drop: Drop<E2>
 > of Drop<(E0, E1, E2)>;
 
-impl TupleSize4Drop<
+pub impl TupleSize4Drop<
     E0,
     E1,
     E2,
@@ -208,7 +208,7 @@ impl TupleSize4Drop<
 > of Drop<(E0, E1, E2, E3)>;
 
 // Tuple PartialEq impls.
-impl TupleSize0PartialEq of PartialEq<()> {
+pub impl TupleSize0PartialEq of PartialEq<()> {
     #[inline(always)]
     fn eq(lhs: @(), rhs: @()) -> bool {
         true
@@ -219,7 +219,7 @@ impl TupleSize0PartialEq of PartialEq<()> {
     }
 }
 
-impl TupleSize1PartialEq<E0, impl E0PartialEq: PartialEq<E0>> of PartialEq<(E0, )> {
+pub impl TupleSize1PartialEq<E0, impl E0PartialEq: PartialEq<E0>> of PartialEq<(E0, )> {
     #[inline(always)]
     fn eq(lhs: @(E0, ), rhs: @(E0, )) -> bool {
         let (lhs, ) = lhs;
@@ -231,3 +231,14 @@ impl TupleSize1PartialEq<E0, impl E0PartialEq: PartialEq<E0>> of PartialEq<(E0,
         !(rhs == lhs)
     }
 }
+
+/// A trait that can be used to call a closure, consuming it.
+pub trait FnOnce<T, Args, R> {
+    fn call_once(self: T, args: Args) -> R;
+}
+
+/// A trait that can be used to call a closure by snapshot, without consuming it.
+/// Implemented by closures that capture only snapshots or `Copy` values.
+pub trait Fn<T, Args, R> {
+    fn call(self: @T, args: Args) -> R;
+}
@@ -1,6 +1,6 @@
 // === Zeroable ===
 
-trait Zeroable<T> {
+pub trait Zeroable<T> {
     /// Returns the additive identity element of Self, 0.
     fn zero() -> T;
     /// Returns whether self is equal to 0, the additive identity element.
@@ -9,7 +9,7 @@ trait Zeroable<T> {
     fn is_non_zero(self: T) -> bool;
 }
 
-impl Felt252Zeroable of Zeroable<felt252> {
+pub impl Felt252Zeroable of Zeroable<felt252> {
     fn zero() -> felt252 {
         0
     }
@@ -25,22 +25,22 @@ impl Felt252Zeroable of Zeroable<felt252> {
 
 // === NonZero ===
 
-extern type NonZero<T>;
-impl NonZeroTCopy<T, impl TCopy: Copy<T>> of Copy<NonZero<T>>;
-impl NonZeroTDrop<T, impl TDrop: Drop<T>> of Drop<NonZero<T>>;
-enum IsZeroResult<T> {
+pub extern type NonZero<T>;
+pub impl NonZeroTCopy<T, impl TCopy: Copy<T>> of Copy<NonZero<T>>;
+pub impl NonZeroTDrop<T, impl TDrop: Drop<T>> of Drop<NonZero<T>>;
+pub enum IsZeroResult<T> {
     Zero: (),
     NonZero: NonZero<T>,
 }
-extern fn unwrap_non_zero<T>(a: NonZero<T>) -> T nopanic;
+pub extern fn unwrap_non_zero<T>(a: NonZero<T>) -> T nopanic;
 
-impl NonZeroIntoImpl<T> of Into<NonZero<T>, T> {
+pub impl NonZeroIntoImpl<T> of Into<NonZero<T>, T> {
     fn into(self: NonZero<T>) -> T nopanic {
         unwrap_non_zero(self)
     }
 }
 
-impl IsZeroResultIntoBool<T, impl TDrop: Drop<T>> of Into<IsZeroResult<T>, bool> {
+pub impl IsZeroResultIntoBool<T, impl TDrop: Drop<T>> of Into<IsZeroResult<T>, bool> {
     fn into(self: IsZeroResult<T>) -> bool {
         match self {
             IsZeroResult::Zero(()) => true,
@@ -2,6 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
@@ -18,6 +19,9 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Fails the compilation on warnings, as if they were errors.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -28,7 +32,12 @@ fn main() -> anyhow::Result<()> {
 
     let sierra_program = compile_cairo_project_at_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::stderr()
+                .with_warnings_as_errors(args.deny_warnings),
+            replace_ids: args.replace_ids,
+            ..CompilerConfig::default()
+        },
     )?;
 
     match args.output {
@@ -0,0 +1,657 @@
+//! A debug adapter for Cairo programs, serving the Debug Adapter Protocol over stdio.
+//!
+//! The debugged function is run to its end when launched, and the recorded run is then stepped
+//! through - forwards and backwards - by the requests of the client.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::setup_project;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_runner::debugging::{RecordedRun, StepKind};
+use cairo_lang_runner::short_string::format_for_panic;
+use cairo_lang_runner::values::{parse_args, DecodedValue};
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner, StarknetState};
+use cairo_lang_sierra::debug_info::SourceSpan;
+use cairo_lang_sierra::extensions::gas::{
+    BuiltinCostWithdrawGasLibfunc, RedepositGasLibfunc, WithdrawGasLibfunc,
+};
+use cairo_lang_sierra::extensions::NamedLibfunc;
+use cairo_lang_sierra::ids::FunctionId;
+use cairo_lang_sierra_generator::db::{SierraGenGroup, StatementsLocations};
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use cairo_lang_starknet::contract::get_contracts_info;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use clap::Parser;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Command line args parser.
+/// The debug adapter communicates with its client over stdio, and the debugged program is given by
+/// the `launch` request of the client.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {}
+
+/// The arguments of the `launch` request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LaunchArguments {
+    /// The file or project to compile and run.
+    program: String,
+    /// The path of the function to run, or a suffix of it following `::`.
+    #[serde(default = "default_function")]
+    function: String,
+    /// The arguments of the function, as given to `cairo-run --args`.
+    #[serde(default)]
+    args: String,
+    /// In cases where gas is available, the amount of provided gas.
+    available_gas: Option<usize>,
+    /// Whether to stop at the first step of the run, rather than at the first breakpoint.
+    #[serde(default)]
+    stop_on_entry: bool,
+}
+
+fn default_function() -> String {
+    "main".into()
+}
+
+/// A connection to the client, sending and receiving messages over stdio.
+struct Connection {
+    reader: io::StdinLock<'static>,
+    writer: io::Stdout,
+    /// The sequence number of the last message sent.
+    seq: u64,
+}
+
+impl Connection {
+    fn new() -> Self {
+        Self { reader: io::stdin().lock(), writer: io::stdout(), seq: 0 }
+    }
+
+    /// Reads the next message from the client, or returns `None` if the input is closed.
+    fn read_message(&mut self) -> anyhow::Result<Option<Value>> {
+        let mut content_length = None;
+        loop {
+            let mut line = String::new();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                content_length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+        let content_length = content_length.context("Missing the `Content-Length` header.")?;
+        let mut content = vec![0; content_length];
+        self.reader.read_exact(&mut content)?;
+        Ok(Some(serde_json::from_slice(&content)?))
+    }
+
+    /// Sends a message to the client, numbering it by its sequence number.
+    fn send(&mut self, mut message: Value) -> anyhow::Result<()> {
+        self.seq += 1;
+        message["seq"] = self.seq.into();
+        let content = message.to_string();
+        write!(self.writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Sends a successful response to a request.
+    fn respond(&mut self, request: &Value, body: Value) -> anyhow::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": true,
+            "command": request["command"],
+            "body": body,
+        }))
+    }
+
+    /// Sends a failed response to a request.
+    fn respond_error(&mut self, request: &Value, message: &str) -> anyhow::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "success": false,
+            "command": request["command"],
+            "message": message,
+        }))
+    }
+
+    /// Sends an event to the client.
+    fn send_event(&mut self, event: &str, body: Value) -> anyhow::Result<()> {
+        self.send(json!({"type": "event", "event": event, "body": body}))
+    }
+
+    /// Sends text to be shown in the output of the client.
+    fn send_output(&mut self, output: String) -> anyhow::Result<()> {
+        self.send_event("output", json!({"category": "console", "output": output}))
+    }
+}
+
+/// A compiled program, ready to be run.
+struct CompiledProgram {
+    runner: SierraCasmRunner,
+    statements_locations: StatementsLocations,
+    /// The names of the parameters (excluding the implicits) of the functions, by their index in
+    /// the Sierra program, if known.
+    parameter_names: HashMap<usize, Vec<String>>,
+}
+
+/// Compiles the program of a `launch` request.
+fn compile(args: &LaunchArguments, connection: &mut Connection) -> anyhow::Result<CompiledProgram> {
+    let db = &mut RootDatabase::builder().detect_corelib().build()?;
+    let path = Path::new(&args.program)
+        .canonicalize()
+        .with_context(|| format!("Program `{}` not found.", args.program))?;
+    let main_crate_ids = setup_project(db, &path)?;
+
+    let mut diagnostics = String::new();
+    let has_errors = DiagnosticsReporter::write_to_string(&mut diagnostics).check(db);
+    if !diagnostics.is_empty() {
+        connection.send_output(diagnostics)?;
+    }
+    if has_errors {
+        bail!("failed to compile: {}", args.program);
+    }
+
+    let mut function_ids = vec![];
+    for crate_id in main_crate_ids.iter().copied() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let free_functions = db
+                .module_free_functions(*module_id)
+                .to_option()
+                .with_context(|| "Compilation failed without any diagnostics.")?;
+            for (free_func_id, _) in free_functions.iter() {
+                function_ids
+                    .extend(ConcreteFunctionWithBodyId::from_no_generics_free(db, *free_func_id));
+            }
+        }
+    }
+    let sierra_program = db
+        .get_sierra_program_for_functions(function_ids.clone())
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let statements_locations = db
+        .get_sierra_program_statements_locations(function_ids)
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    if args.available_gas.is_none()
+        && sierra_program.type_declarations.iter().any(|decl| {
+            matches!(
+                decl.long_id.generic_id.0.as_str(),
+                WithdrawGasLibfunc::STR_ID
+                    | BuiltinCostWithdrawGasLibfunc::STR_ID
+                    | RedepositGasLibfunc::STR_ID
+            )
+        })
+    {
+        bail!("Program requires gas counter, please provide the `availableGas` launch argument.");
+    }
+    let parameter_names = sierra_program
+        .funcs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, function)| Some((idx, parameter_names(db, &function.id)?)))
+        .collect();
+
+    let replacer = DebugReplacer { db };
+    let contracts_info = get_contracts_info(db, main_crate_ids, &replacer)?;
+    let runner = SierraCasmRunner::new(
+        replacer.apply(&sierra_program),
+        if args.available_gas.is_some() { Some(Default::default()) } else { None },
+        contracts_info,
+    )
+    .with_context(|| "Failed setting up runner.")?;
+    Ok(CompiledProgram {
+        runner,
+        statements_locations: statements_locations.as_ref().clone(),
+        parameter_names,
+    })
+}
+
+/// Returns the names of the parameters of a Sierra function, as written in its code.
+fn parameter_names(db: &RootDatabase, function_id: &FunctionId) -> Option<Vec<String>> {
+    let body = db.lookup_intern_sierra_function(function_id.clone()).body(db).ok()??;
+    let signature = body.signature(db).ok()?;
+    let file_id = db.module_file(body.stable_location(db).ok()?.module_file_id).ok()?;
+    let root = db.file_syntax(file_id).ok()?.as_syntax_node();
+    Some(
+        signature
+            .params
+            .iter()
+            .map(|param| {
+                root.lookup_ptr(db, param.stable_ptr().untyped()).get_text_without_trivia(db)
+            })
+            .collect(),
+    )
+}
+
+/// A variable shown by the client.
+#[derive(Clone)]
+struct Variable {
+    name: String,
+    /// The type of the variable, if known.
+    ty: Option<String>,
+    value: DecodedValue,
+}
+
+/// A debugging session of a recorded run.
+struct Debugger<'a> {
+    connection: Connection,
+    run: RecordedRun<'a>,
+    parameter_names: HashMap<usize, Vec<String>>,
+    /// The lines (starting from 0) of the breakpoints, by the paths of their source files.
+    breakpoints: HashMap<String, HashSet<usize>>,
+    stop_on_entry: bool,
+    /// The step the run is stopped at.
+    step: usize,
+    /// The message describing the result of the run.
+    result_message: String,
+    /// Whether the run panicked.
+    panicked: bool,
+    /// The variables shown by the client since the run stopped, by their variables reference
+    /// (starting from 1).
+    variables: Vec<Vec<Variable>>,
+}
+
+impl<'a> Debugger<'a> {
+    /// Serves the requests of the client until it disconnects.
+    fn serve(&mut self) -> anyhow::Result<()> {
+        while let Some(request) = self.connection.read_message()? {
+            let args = &request["arguments"];
+            match request["command"].as_str().unwrap_or_default() {
+                "setBreakpoints" => match self.set_breakpoints(args) {
+                    Ok(body) => self.connection.respond(&request, body)?,
+                    Err(err) => self.connection.respond_error(&request, &format!("{err:#}"))?,
+                },
+                "setExceptionBreakpoints" => self.connection.respond(&request, json!({}))?,
+                "configurationDone" => {
+                    self.connection.respond(&request, Value::Null)?;
+                    if self.stop_on_entry {
+                        self.stop_at(Some(0), true, "entry")?;
+                    } else {
+                        let breakpoint = self.find_breakpoint(0, true);
+                        self.stop_at(breakpoint, true, "breakpoint")?;
+                    }
+                }
+                "threads" => self
+                    .connection
+                    .respond(&request, json!({"threads": [{"id": 1, "name": "main"}]}))?,
+                "stackTrace" => {
+                    let body = self.stack_trace();
+                    self.connection.respond(&request, body)?;
+                }
+                "scopes" => {
+                    let frame_idx = args["frameId"].as_u64().unwrap_or_default() as usize;
+                    let body = self.scopes(frame_idx);
+                    self.connection.respond(&request, body)?;
+                }
+                "variables" => {
+                    let reference = args["variablesReference"].as_u64().unwrap_or_default();
+                    let body = self.variables(reference as usize);
+                    self.connection.respond(&request, body)?;
+                }
+                "continue" => {
+                    self.connection.respond(&request, json!({"allThreadsContinued": true}))?;
+                    let breakpoint = self.find_breakpoint(self.step + 1, true);
+                    self.stop_at(breakpoint, true, "breakpoint")?;
+                }
+                "reverseContinue" => {
+                    self.connection.respond(&request, Value::Null)?;
+                    let breakpoint = match self.step.checked_sub(1) {
+                        Some(step) => self.find_breakpoint(step, false),
+                        None => None,
+                    };
+                    self.stop_at(breakpoint, false, "breakpoint")?;
+                }
+                command @ ("next" | "stepIn" | "stepOut" | "stepBack") => {
+                    self.connection.respond(&request, Value::Null)?;
+                    let by_statement = args["granularity"].as_str() == Some("instruction");
+                    let kind = match command {
+                        "stepOut" => StepKind::Out,
+                        _ if by_statement => StepKind::Statement,
+                        "stepIn" => StepKind::Into,
+                        _ => StepKind::Over,
+                    };
+                    let forward = command != "stepBack";
+                    let step = self.run.step(self.step, kind, forward);
+                    self.stop_at(step, forward, "step")?;
+                }
+                // The run is always stopped when requests are served.
+                "pause" => self.connection.respond(&request, Value::Null)?,
+                "disconnect" | "terminate" => {
+                    self.connection.respond(&request, Value::Null)?;
+                    return Ok(());
+                }
+                command => self
+                    .connection
+                    .respond_error(&request, &format!("Unsupported request `{command}`."))?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a `setBreakpoints` request, replacing the breakpoints of a source file.
+    fn set_breakpoints(&mut self, args: &Value) -> anyhow::Result<Value> {
+        let path = args["source"]["path"].as_str().unwrap_or_default().to_string();
+        // The lines of the client start from 1.
+        let lines = args["breakpoints"]
+            .as_array()
+            .map(|breakpoints| {
+                breakpoints
+                    .iter()
+                    .filter_map(|breakpoint| breakpoint["line"].as_u64())
+                    .map(|line| {
+                        (line as usize)
+                            .checked_sub(1)
+                            .with_context(|| format!("Invalid breakpoint line `{line}`."))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let breakpoints = lines
+            .iter()
+            .map(|line| {
+                json!({"verified": self.run.is_line_of_code(&path, *line), "line": line + 1})
+            })
+            .collect_vec();
+        self.breakpoints.insert(path, lines.into_iter().collect());
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    /// Returns the first step starting from `step`, forwards or backwards, that hits a breakpoint.
+    fn find_breakpoint(&self, step: usize, forward: bool) -> Option<usize> {
+        self.run.find_breakpoint(step, forward, |span| {
+            self.breakpoints.get(&span.file).is_some_and(|lines| lines.contains(&span.start.line))
+        })
+    }
+
+    /// Stops the run at a step, notifying the client by the given reason. If there is no such step,
+    /// the run stops at its start when going backwards, and ends when going forwards.
+    fn stop_at(&mut self, step: Option<usize>, forward: bool, reason: &str) -> anyhow::Result<()> {
+        let step = match step {
+            Some(step) => step,
+            None if !forward => 0,
+            None => return self.end(),
+        };
+        self.step = step;
+        self.variables.clear();
+        self.connection.send_event(
+            "stopped",
+            json!({"reason": reason, "threadId": 1, "allThreadsStopped": true}),
+        )
+    }
+
+    /// Ends the session, reporting the result of the run.
+    fn end(&mut self) -> anyhow::Result<()> {
+        let output = format!("{}\n", self.result_message);
+        self.connection.send_output(output)?;
+        self.connection.send_event("exited", json!({"exitCode": i32::from(self.panicked)}))?;
+        self.connection.send_event("terminated", json!({}))
+    }
+
+    /// Handles a `stackTrace` request, by the call stack of the current step.
+    fn stack_trace(&self) -> Value {
+        let frames = self
+            .run
+            .call_stack(self.step)
+            .into_iter()
+            .map(|(frame_idx, step)| {
+                let name = self.run.function(frame_idx).id.to_string();
+                match self.run.location(step) {
+                    Some(span) => json!({
+                        "id": frame_idx,
+                        "name": name,
+                        "source": source(span),
+                        "line": span.start.line + 1,
+                        "column": span.start.col + 1,
+                        "endLine": span.end.line + 1,
+                        "endColumn": span.end.col + 1,
+                    }),
+                    None => json!({"id": frame_idx, "name": name, "line": 0, "column": 0}),
+                }
+            })
+            .collect_vec();
+        json!({"totalFrames": frames.len(), "stackFrames": frames})
+    }
+
+    /// Handles a `scopes` request, showing the arguments of the call of a frame, and the variables
+    /// of the Sierra statement the frame is at.
+    fn scopes(&mut self, frame_idx: usize) -> Value {
+        let arguments = self.run.arguments(frame_idx);
+        let names = self
+            .parameter_names
+            .get(&self.run.frame(frame_idx).function_idx)
+            .filter(|names| names.len() == arguments.len());
+        let variables = arguments
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (ty, value))| Variable {
+                name: match names {
+                    Some(names) => names[idx].clone(),
+                    None => format!("arg{idx}"),
+                },
+                ty: Some(ty.to_string()),
+                value,
+            })
+            .collect();
+        let arguments_reference = self.add_variables(variables);
+        let step = self
+            .run
+            .call_stack(self.step)
+            .into_iter()
+            .find_map(|(idx, step)| (idx == frame_idx).then_some(step))
+            .unwrap_or(self.step);
+        let locals = self
+            .run
+            .locals(step)
+            .into_iter()
+            .map(|(var_id, ty, value)| Variable {
+                name: var_id.to_string(),
+                ty: Some(ty.to_string()),
+                value,
+            })
+            .collect();
+        let locals_reference = self.add_variables(locals);
+        json!({"scopes": [
+            {
+                "name": "Arguments",
+                "presentationHint": "arguments",
+                "variablesReference": arguments_reference,
+                "expensive": false,
+            },
+            {
+                "name": "Locals",
+                "presentationHint": "locals",
+                "variablesReference": locals_reference,
+                "expensive": false,
+            },
+        ]})
+    }
+
+    /// Handles a `variables` request, showing the variables of a reference.
+    fn variables(&mut self, reference: usize) -> Value {
+        let Some(variables) =
+            reference.checked_sub(1).and_then(|idx| self.variables.get(idx)).cloned()
+        else {
+            return json!({"variables": []});
+        };
+        let variables = variables
+            .into_iter()
+            .map(|Variable { name, ty, value }| {
+                let mut variable = json!({
+                    "name": name,
+                    "value": value.to_string(),
+                    "variablesReference": self.add_members(&value),
+                });
+                if let Some(ty) = ty {
+                    variable["type"] = ty.into();
+                }
+                variable
+            })
+            .collect_vec();
+        json!({ "variables": variables })
+    }
+
+    /// Adds the members of a value as variables, returning their reference, or 0 if the value has
+    /// no members.
+    fn add_members(&mut self, value: &DecodedValue) -> usize {
+        let members = match value {
+            DecodedValue::Array(elements) => elements
+                .iter()
+                .enumerate()
+                .map(|(idx, element)| Variable {
+                    name: format!("[{idx}]"),
+                    ty: None,
+                    value: element.clone(),
+                })
+                .collect_vec(),
+            DecodedValue::Struct { members, .. } => members
+                .iter()
+                .enumerate()
+                .map(|(idx, member)| Variable {
+                    name: idx.to_string(),
+                    ty: None,
+                    value: member.clone(),
+                })
+                .collect_vec(),
+            DecodedValue::Enum { variant, value, .. } => {
+                vec![Variable { name: variant.to_string(), ty: None, value: *value.clone() }]
+            }
+            _ => vec![],
+        };
+        if members.is_empty() { 0 } else { self.add_variables(members) }
+    }
+
+    /// Adds variables shown by the client, returning their reference.
+    fn add_variables(&mut self, variables: Vec<Variable>) -> usize {
+        self.variables.push(variables);
+        self.variables.len()
+    }
+}
+
+/// Returns the DAP source of a span.
+fn source(span: &SourceSpan) -> Value {
+    let name = Path::new(&span.file).file_name().map(|name| name.to_string_lossy().to_string());
+    json!({"name": name, "path": span.file})
+}
+
+fn main() -> anyhow::Result<()> {
+    Args::parse();
+    let mut connection = Connection::new();
+
+    // Handling the requests preceding the launch of the program.
+    let (launch_request, launch_args) = loop {
+        let Some(request) = connection.read_message()? else {
+            return Ok(());
+        };
+        match request["command"].as_str().unwrap_or_default() {
+            "initialize" => connection.respond(
+                &request,
+                json!({
+                    "supportsConfigurationDoneRequest": true,
+                    "supportsStepBack": true,
+                    "supportsSteppingGranularity": true,
+                    "supportsTerminateRequest": true,
+                }),
+            )?,
+            "launch" => {
+                match serde_json::from_value::<LaunchArguments>(request["arguments"].clone()) {
+                    Ok(args) => break (request, args),
+                    Err(err) => connection
+                        .respond_error(&request, &format!("Invalid launch arguments: {err}"))?,
+                }
+            }
+            "disconnect" => {
+                connection.respond(&request, Value::Null)?;
+                return Ok(());
+            }
+            command => connection.respond_error(
+                &request,
+                &format!("Unexpected request `{command}` before launch."),
+            )?,
+        }
+    };
+
+    let program = match compile(&launch_args, &mut connection) {
+        Ok(program) => program,
+        Err(err) => {
+            connection.respond_error(&launch_request, &format!("{err:#}"))?;
+            return Ok(());
+        }
+    };
+    let runner = &program.runner;
+    let run = || -> anyhow::Result<_> {
+        let func = runner.find_function_by_path(&launch_args.function)?;
+        let func_args =
+            parse_args(&launch_args.args).with_context(|| "Failed parsing the arguments.")?;
+        let func_args = runner.prepare_args(func, func_args)?;
+        let result = runner
+            .run_function_with_starknet_context(
+                func,
+                &func_args,
+                launch_args.available_gas,
+                StarknetState::default(),
+            )
+            .with_context(|| "Failed to run the function.")?;
+        Ok((func, result))
+    };
+    let (func, result) = match run() {
+        Ok(run) => run,
+        Err(err) => {
+            connection.respond_error(&launch_request, &format!("{err:#}"))?;
+            return Ok(());
+        }
+    };
+    let mut result_message = match &result.value {
+        RunResultValue::Success(values) => {
+            match runner.decode_return_value(func, values, &result.memory) {
+                Some(value) => format!("Run completed successfully, returning {value}"),
+                None => "Run completed successfully.".into(),
+            }
+        }
+        RunResultValue::Panic(values) => format!("Run panicked with {}.", format_for_panic(values)),
+    };
+    if let Some(gas) = result.gas_counter {
+        result_message.push_str(&format!("\nRemaining gas: {gas}"));
+    }
+    let panicked = matches!(result.value, RunResultValue::Panic(_));
+    let run =
+        match RecordedRun::new(runner, result.trace, result.memory, program.statements_locations) {
+            Ok(run) => run,
+            Err(err) => {
+                connection.respond_error(&launch_request, &format!("{err:#}"))?;
+                return Ok(());
+            }
+        };
+
+    connection.respond(&launch_request, Value::Null)?;
+    connection.send_event("initialized", json!({}))?;
+    let mut debugger = Debugger {
+        connection,
+        run,
+        parameter_names: program.parameter_names,
+        breakpoints: HashMap::new(),
+        stop_on_entry: launch_args.stop_on_entry,
+        step: 0,
+        result_message,
+        panicked,
+        variables: vec![],
+    };
+    debugger.serve()
+}
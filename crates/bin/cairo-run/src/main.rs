@@ -1,14 +1,16 @@
 //! Compiles and runs a Cairo program.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Ok};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{SierraCasmRunner, StarknetState};
+use cairo_lang_runner::profiling::{ProfilingConfig, COLLAPSED_STACKS_FILE};
+use cairo_lang_runner::short_string::format_for_panic;
+use cairo_lang_runner::values::parse_args;
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner, StarknetState};
 use cairo_lang_sierra::extensions::gas::{
     BuiltinCostWithdrawGasLibfunc, RedepositGasLibfunc, WithdrawGasLibfunc,
 };
@@ -25,12 +27,39 @@ use clap::Parser;
 struct Args {
     /// The file to compile and run.
     path: String,
+    /// The path of the function to run, or a suffix of it following `::`.
+    #[arg(long, default_value = "main")]
+    function: String,
+    /// The arguments of the function, separated by whitespace: integers (decimal or `0x` hex),
+    /// short strings in single quotes, and arrays in square brackets, e.g. `1 'abc' [2 [3 4]]`.
+    #[arg(long, default_value = "", allow_hyphen_values = true)]
+    args: String,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// Whether to print the resources used by the run - the VM steps, the memory holes, and the
+    /// memory cells used by each builtin.
+    #[arg(long, default_value_t = false)]
+    print_resources: bool,
+    /// Should we profile the run, printing the functions with the most steps and writing the call
+    /// stacks in the collapsed stacks format (for flamegraph tools).
+    #[arg(long, default_value_t = false)]
+    profile: bool,
+    /// The path of the collapsed stacks file of the profile.
+    #[arg(long, default_value = COLLAPSED_STACKS_FILE, requires = "profile")]
+    profile_output: PathBuf,
+    /// The number of functions in the table of the profile.
+    #[arg(long, default_value_t = 10, requires = "profile")]
+    profile_top: usize,
+    /// Whether to print the result of the run in JSON.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["print_full_memory", "profile"])]
+    json: bool,
+    /// Fails the compilation on warnings, as if they were errors.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -40,7 +69,7 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
 
-    if DiagnosticsReporter::stderr().check(db) {
+    if DiagnosticsReporter::stderr().with_warnings_as_errors(args.deny_warnings).check(db) {
         anyhow::bail!("failed to compile: {}", args.path);
     }
 
@@ -70,32 +99,87 @@ fn main() -> anyhow::Result<()> {
         contracts_info,
     )
     .with_context(|| "Failed setting up runner.")?;
+    let func = runner.find_function_by_path(&args.function)?;
+    let func_args = parse_args(&args.args).with_context(|| "Failed parsing the arguments.")?;
+    let func_args = runner.prepare_args(func, func_args)?;
     let result = runner
         .run_function_with_starknet_context(
-            runner.find_function("::main")?,
-            &[],
+            func,
+            &func_args,
             args.available_gas,
             StarknetState::default(),
         )
         .with_context(|| "Failed to run the function.")?;
-    match result.value {
-        cairo_lang_runner::RunResultValue::Success(values) => {
-            println!("Run completed successfully, returning {values:?}")
+    if args.json {
+        let mut output = serde_json::Map::new();
+        match &result.value {
+            RunResultValue::Success(values) => {
+                output.insert("status".into(), "success".into());
+                let value = runner.decode_return_value(func, values, &result.memory);
+                output
+                    .insert("value".into(), value.map(|value| value.to_json()).unwrap_or_default());
+            }
+            RunResultValue::Panic(values) => {
+                output.insert("status".into(), "panic".into());
+                output.insert(
+                    "panic_data".into(),
+                    values.iter().map(|value| value.to_string()).collect(),
+                );
+            }
+        }
+        output.insert("gas_counter".into(), result.gas_counter.map(|gas| gas.to_string()).into());
+        if args.print_resources {
+            let resources = &result.resources;
+            output.insert(
+                "resources".into(),
+                serde_json::json!({
+                    "steps": resources.steps,
+                    "memory_holes": resources.memory_holes,
+                    "builtins": resources
+                        .builtins
+                        .iter()
+                        .map(|(name, cells)| (name.clone(), (*cells).into()))
+                        .collect::<serde_json::Map<_, _>>(),
+                }),
+            );
         }
-        cairo_lang_runner::RunResultValue::Panic(values) => {
-            print!("Run panicked with [");
-            for value in &values {
-                match as_cairo_short_string(value) {
-                    Some(as_string) => print!("{value} ('{as_string}'), "),
-                    None => print!("{value}, "),
-                }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+    match &result.value {
+        RunResultValue::Success(values) => {
+            match runner.decode_return_value(func, values, &result.memory) {
+                Some(value) => println!("Run completed successfully, returning {value}"),
+                None => println!("Run completed successfully."),
             }
-            println!("].")
+        }
+        RunResultValue::Panic(values) => {
+            println!("Run panicked with {}.", format_for_panic(values))
         }
     }
     if let Some(gas) = result.gas_counter {
         println!("Remaining gas: {gas}");
     }
+    if args.print_resources {
+        let resources = &result.resources;
+        println!("Resources:");
+        println!("  steps: {}", resources.steps);
+        println!("  memory holes: {}", resources.memory_holes);
+        println!("  builtins:");
+        for (name, cells) in resources.builtins.iter() {
+            println!("    {name}: {cells}");
+        }
+    }
+    if args.profile {
+        let config = ProfilingConfig {
+            collapsed_stacks_path: args.profile_output,
+            top_functions: args.profile_top,
+        };
+        let profile = runner.profile(&result.trace, &result.memory);
+        profile.write_collapsed_stacks(&config)?;
+        print!("{}", profile.format_top_functions(config.top_functions));
+        println!("Call stacks written to `{}`.", config.collapsed_stacks_path.display());
+    }
     if args.print_full_memory {
         print!("Full memory: [");
         for cell in &result.memory {
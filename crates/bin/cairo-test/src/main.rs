@@ -1,6 +1,12 @@
 //! Compiles and runs a Cairo program.
 
+use std::path::PathBuf;
+
 use anyhow::Ok;
+use cairo_lang_runner::profiling::{ProfilingConfig, COLLAPSED_STACKS_FILE};
+use cairo_lang_test_runner::coverage::CoverageConfig;
+use cairo_lang_test_runner::gas_snapshot::GasSnapshotConfig;
+use cairo_lang_test_runner::reporter::OutputFormat;
 use cairo_lang_test_runner::TestRunner;
 use clap::Parser;
 
@@ -23,18 +29,61 @@ struct Args {
     /// Should we add the starknet plugin to run the tests.
     #[arg(long, default_value_t = false)]
     starknet: bool,
+    /// The format of the test results: `human`, `json` (a JSON object per line for each test
+    /// event) or `junit` (a JUnit XML report).
+    #[arg(long, default_value = "human")]
+    format: OutputFormat,
+    /// Should we compare the gas used by the tests to the `.gas-snapshot` file, and update it.
+    #[arg(long, default_value_t = false)]
+    gas_snapshot: bool,
+    /// The allowed growth, in percents, of the gas used by a test over its gas snapshot.
+    #[arg(long, default_value_t = 0.0, requires = "gas_snapshot")]
+    gas_snapshot_threshold: f64,
+    /// Should we write an LCOV coverage report of the lines executed by the tests to `lcov.info`.
+    #[arg(long, default_value_t = false)]
+    coverage: bool,
+    /// Should we profile the tests, printing the functions with the most steps and writing the
+    /// call stacks in the collapsed stacks format (for flamegraph tools).
+    #[arg(long, default_value_t = false)]
+    profile: bool,
+    /// The path of the collapsed stacks file of the profile.
+    #[arg(long, default_value = COLLAPSED_STACKS_FILE, requires = "profile")]
+    profile_output: PathBuf,
+    /// The number of functions in the table of the profile.
+    #[arg(long, default_value_t = 10, requires = "profile")]
+    profile_top: usize,
+    /// Fails the compilation on warnings, as if they were errors.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    let runner = TestRunner::new(
+    let mut runner = TestRunner::new(
         &args.path,
         &args.filter,
         args.include_ignored,
         args.ignored,
         args.starknet,
-    )?;
+    )?
+    .with_format(args.format)
+    .with_deny_warnings(args.deny_warnings);
+    if args.gas_snapshot {
+        runner = runner.with_gas_snapshot(GasSnapshotConfig {
+            threshold_percent: args.gas_snapshot_threshold,
+            ..GasSnapshotConfig::default()
+        });
+    }
+    if args.coverage {
+        runner = runner.with_coverage(CoverageConfig::default());
+    }
+    if args.profile {
+        runner = runner.with_profiling(ProfilingConfig {
+            collapsed_stacks_path: args.profile_output,
+            top_functions: args.profile_top,
+        });
+    }
     runner.run()?;
 
     Ok(())
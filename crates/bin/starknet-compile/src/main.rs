@@ -2,6 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_starknet::allowed_libfuncs::ListSelector;
 use cairo_lang_starknet::contract_class::starknet_compile;
@@ -28,6 +29,12 @@ struct Args {
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// Adds the source locations of the Sierra statements to the debug info.
+    #[arg(long, default_value_t = false)]
+    add_statements_locations: bool,
+    /// Fails the compilation on warnings, as if they were errors.
+    #[arg(long, default_value_t = false)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -38,7 +45,13 @@ fn main() -> anyhow::Result<()> {
     let res = starknet_compile(
         args.path,
         args.contract_path,
-        Some(CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() }),
+        Some(CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::stderr()
+                .with_warnings_as_errors(args.deny_warnings),
+            replace_ids: args.replace_ids,
+            add_statements_locations: args.add_statements_locations,
+            ..CompilerConfig::default()
+        }),
         Some(list_selector),
     )?;
     match args.output {
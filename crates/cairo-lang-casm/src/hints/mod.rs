@@ -80,6 +80,21 @@ pub enum StarknetHint {
         data_start: CellRef,
         data_end: CellRef,
     },
+    #[codec(index = 14)]
+    MockCall {
+        contract_address: ResOperand,
+        selector: ResOperand,
+        data_start: ResOperand,
+        data_end: ResOperand,
+    },
+    #[codec(index = 15)]
+    Store { contract_address: ResOperand, key: ResOperand, value: ResOperand },
+    #[codec(index = 16)]
+    Load { contract_address: ResOperand, key: ResOperand, dst: CellRef },
+    #[codec(index = 17)]
+    ExpectRevert { start: ResOperand, end: ResOperand },
+    #[codec(index = 18)]
+    SetBlockHash { block_number: ResOperand, value: ResOperand },
 }
 
 // Represents a cairo core hint.
@@ -758,6 +773,49 @@ impl Display for StarknetHint {
             } => {
                 write!(f, "raise NotImplemented")
             }
+            StarknetHint::MockCall { contract_address, selector, data_start, data_end } => {
+                write!(
+                    f,
+                    "syscall_handler.mocked_calls[({}, {})] = [memory[i] for i in range({}, {})]",
+                    ResOperandFormatter(contract_address),
+                    ResOperandFormatter(selector),
+                    ResOperandFormatter(data_start),
+                    ResOperandFormatter(data_end)
+                )
+            }
+            StarknetHint::Store { contract_address, key, value } => {
+                write!(
+                    f,
+                    "syscall_handler.storage[{}][{}] = {}",
+                    ResOperandFormatter(contract_address),
+                    ResOperandFormatter(key),
+                    ResOperandFormatter(value)
+                )
+            }
+            StarknetHint::Load { contract_address, key, dst } => {
+                write!(
+                    f,
+                    "memory{dst} = syscall_handler.storage[{}][{}]",
+                    ResOperandFormatter(contract_address),
+                    ResOperandFormatter(key)
+                )
+            }
+            StarknetHint::ExpectRevert { start, end } => {
+                write!(
+                    f,
+                    "syscall_handler.expected_revert = [memory[i] for i in range({}, {})]",
+                    ResOperandFormatter(start),
+                    ResOperandFormatter(end)
+                )
+            }
+            StarknetHint::SetBlockHash { block_number, value } => {
+                write!(
+                    f,
+                    "syscall_handler.block_hashes[{}] = {}",
+                    ResOperandFormatter(block_number),
+                    ResOperandFormatter(value)
+                )
+            }
         }
     }
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
 use cairo_lang_defs::db::{DefsDatabase, DefsGroup, HasMacroPlugins};
-use cairo_lang_defs::plugin::MacroPlugin;
+use cairo_lang_defs::plugin::{InlineMacroPlugin, MacroPlugin};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::{
     init_dev_corelib, init_files_group, AsFilesGroupMut, FilesDatabase, FilesGroup, FilesGroupEx,
@@ -12,16 +12,28 @@ use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::CrateLongId;
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup};
 use cairo_lang_parser::db::ParserDatabase;
-use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_plugins::{get_default_inline_macro_plugins, get_default_plugins};
 use cairo_lang_project::ProjectConfig;
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use cairo_lang_semantic::plugin::SemanticPlugin;
 use cairo_lang_sierra_generator::db::SierraGenDatabase;
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
 
 use crate::project::update_crate_roots_from_project_config;
 
+/// The payload of the unwinding of a query of a database snapshot, cancelled since the database is
+/// being modified.
+#[derive(Debug)]
+pub struct Cancelled;
+impl Cancelled {
+    /// Unwinds the current query, without invoking the panic hook.
+    pub fn throw() -> ! {
+        std::panic::resume_unwind(Box::new(Cancelled))
+    }
+}
+
 #[salsa::database(
     DefsDatabase,
     FilesDatabase,
@@ -34,22 +46,48 @@ use crate::project::update_crate_roots_from_project_config;
 pub struct RootDatabase {
     storage: salsa::Storage<RootDatabase>,
 }
-impl salsa::Database for RootDatabase {}
+impl salsa::Database for RootDatabase {
+    fn salsa_event(&self, event: salsa::Event) {
+        // Queries of snapshots stop as soon as the database is about to be modified, as their
+        // results would be outdated.
+        if matches!(
+            event.kind,
+            salsa::EventKind::WillExecute { .. }
+                | salsa::EventKind::DidValidateMemoizedValue { .. }
+        ) && self.salsa_runtime().is_current_revision_canceled()
+        {
+            Cancelled::throw();
+        }
+    }
+
+    fn on_propagated_panic(&self) -> ! {
+        // The query this one waited for was computed by another snapshot, which either was
+        // cancelled, since the database is about to be modified, or panicked.
+        if self.salsa_runtime().is_current_revision_canceled() {
+            Cancelled::throw();
+        }
+        panic!("concurrent salsa query panicked")
+    }
+}
 impl salsa::ParallelDatabase for RootDatabase {
     fn snapshot(&self) -> salsa::Snapshot<RootDatabase> {
         salsa::Snapshot::new(RootDatabase { storage: self.storage.snapshot() })
     }
 }
 impl RootDatabase {
-    fn new(plugins: Vec<Arc<dyn SemanticPlugin>>) -> Self {
+    fn new(
+        plugins: Vec<Arc<dyn SemanticPlugin>>,
+        inline_macro_plugins: OrderedHashMap<String, Arc<dyn InlineMacroPlugin>>,
+    ) -> Self {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         res.set_semantic_plugins(plugins);
+        res.set_inline_macro_plugins(Arc::new(inline_macro_plugins));
         res
     }
 
     pub fn empty() -> Self {
-        Self::builder().clear_plugins().build().unwrap()
+        Self::builder().clear_plugins().clear_inline_macro_plugins().build().unwrap()
     }
 
     pub fn builder() -> RootDatabaseBuilder {
@@ -71,6 +109,7 @@ impl Default for RootDatabase {
 #[derive(Clone, Debug)]
 pub struct RootDatabaseBuilder {
     plugins: Vec<Arc<dyn SemanticPlugin>>,
+    inline_macro_plugins: OrderedHashMap<String, Arc<dyn InlineMacroPlugin>>,
     detect_corelib: bool,
     project_config: Option<Box<ProjectConfig>>,
     cfg_set: Option<CfgSet>,
@@ -80,6 +119,7 @@ impl RootDatabaseBuilder {
     fn new() -> Self {
         Self {
             plugins: get_default_plugins(),
+            inline_macro_plugins: get_default_inline_macro_plugins(),
             detect_corelib: false,
             project_config: None,
             cfg_set: None,
@@ -91,11 +131,27 @@ impl RootDatabaseBuilder {
         self
     }
 
+    /// Registers an inline macro plugin, to expand the calls to the macro `name!`.
+    pub fn with_inline_macro_plugin(
+        &mut self,
+        name: &str,
+        plugin: Arc<dyn InlineMacroPlugin>,
+    ) -> &mut Self {
+        self.inline_macro_plugins.insert(name.into(), plugin);
+        self
+    }
+
     pub fn clear_plugins(&mut self) -> &mut Self {
         self.plugins.clear();
         self
     }
 
+    /// Removes the inline macro plugins, including the default ones.
+    pub fn clear_inline_macro_plugins(&mut self) -> &mut Self {
+        self.inline_macro_plugins.clear();
+        self
+    }
+
     pub fn detect_corelib(&mut self) -> &mut Self {
         self.detect_corelib = true;
         self
@@ -116,7 +172,7 @@ impl RootDatabaseBuilder {
         //   Errors if something is not OK are very subtle, mostly this results in missing
         //   identifier diagnostics, or panics regarding lack of corelib items.
 
-        let mut db = RootDatabase::new(self.plugins.clone());
+        let mut db = RootDatabase::new(self.plugins.clone(), self.inline_macro_plugins.clone());
 
         if let Some(cfg_set) = &self.cfg_set {
             db.use_cfg(cfg_set);
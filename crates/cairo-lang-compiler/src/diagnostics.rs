@@ -1,5 +1,6 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileLongId;
 use cairo_lang_lowering::db::LoweringGroup;
@@ -32,12 +33,14 @@ impl<'a> DiagnosticCallback for Option<Box<dyn DiagnosticCallback + 'a>> {
 /// Collects compilation diagnostics and presents them in preconfigured way.
 pub struct DiagnosticsReporter<'a> {
     callback: Option<Box<dyn DiagnosticCallback + 'a>>,
+    /// If true, warnings fail the compilation as if they were errors.
+    warnings_as_errors: bool,
 }
 
 impl DiagnosticsReporter<'static> {
     /// Create a reporter which does not print or collect diagnostics at all.
     pub fn ignoring() -> Self {
-        Self { callback: None }
+        Self { callback: None, warnings_as_errors: false }
     }
 
     /// Create a reporter which prints all diagnostics to [`std::io::Stderr`].
@@ -77,11 +80,31 @@ impl<'a> DiagnosticsReporter<'a> {
 
     /// Create a reporter which calls [`DiagnosticCallback::on_diagnostic`].
     fn new(callback: impl DiagnosticCallback + 'a) -> Self {
-        Self { callback: Some(Box::new(callback)) }
+        Self { callback: Some(Box::new(callback)), warnings_as_errors: false }
+    }
+
+    /// Makes the reporter treat warnings as errors.
+    pub fn with_warnings_as_errors(mut self, warnings_as_errors: bool) -> Self {
+        self.warnings_as_errors = warnings_as_errors;
+        self
+    }
+
+    /// Reports the given diagnostics to the callback, if there are any.
+    /// Returns `true` if the diagnostics should fail the compilation.
+    fn report<TEntry: DiagnosticEntry>(
+        &mut self,
+        diagnostics: &Diagnostics<TEntry>,
+        db: &TEntry::DbType,
+    ) -> bool {
+        if diagnostics.is_empty() {
+            return false;
+        }
+        self.callback.on_diagnostic(diagnostics.format(db));
+        diagnostics.has_errors() || self.warnings_as_errors
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
-    /// Returns `true` if diagnostics were found.
+    /// Returns `true` if errors were found, or warnings if they are treated as errors.
     pub fn check(&mut self, db: &RootDatabase) -> bool {
         let mut found_diagnostics = false;
         for crate_id in db.crates() {
@@ -103,25 +126,15 @@ impl<'a> DiagnosticsReporter<'a> {
 
             for module_id in &*db.crate_modules(crate_id) {
                 for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    let diag = db.file_syntax_diagnostics(file_id);
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_diagnostics |= self.report(&db.file_syntax_diagnostics(file_id), db);
                 }
 
                 if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_diagnostics |= self.report(&diag, db);
                 }
 
                 if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_diagnostics |= self.report(&diag, db);
                 }
             }
         }
@@ -129,7 +142,7 @@ impl<'a> DiagnosticsReporter<'a> {
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
-    /// Returns `Err` if diagnostics were found.
+    /// Returns `Err` if errors were found, or warnings if they are treated as errors.
     pub fn ensure(&mut self, db: &RootDatabase) -> Result<(), DiagnosticsError> {
         if self.check(db) { Err(DiagnosticsError) } else { Ok(()) }
     }
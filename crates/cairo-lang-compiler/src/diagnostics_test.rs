@@ -1,8 +1,11 @@
+use std::sync::Arc;
+
 use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
-use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
+use indoc::indoc;
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{get_diagnostics_as_string, DiagnosticsReporter};
 
 #[test]
 fn test_diagnostics() {
@@ -13,3 +16,130 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&mut db), "no/such/path/lib.cairo not found\n");
 }
+
+#[test]
+fn test_corelib_prelude_from_user_crate() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+
+    let crate_id = db.intern_crate(CrateLongId("user_crate".into()));
+    db.set_crate_root(crate_id, Some(Directory("user_src".into())));
+    let file_id = db.intern_file(FileLongId::OnDisk("user_src/lib.cairo".into()));
+    db.override_file_content(
+        file_id,
+        Some(Arc::new(
+            r#"
+use array::{ArrayTrait, SpanTrait};
+use option::OptionTrait;
+use serde::Serde;
+use traits::{Into, TryInto};
+
+pub fn main() -> Array<felt252> {
+    let x = 3_u8;
+    let y: felt252 = x.into();
+    let z: u8 = y.try_into().unwrap();
+    let mut out = ArrayTrait::new();
+    y.serialize(ref out);
+    z.serialize(ref out);
+    out.append(felt252_sub(y, 1) * felt252_mul(y, 2));
+    if x < 5_u8 && out.span().len() > 2 {
+        out.append(y + 1 - y * 2);
+    }
+    out
+}
+"#
+            .into(),
+        )),
+    );
+
+    // Items of the corelib public API are visible from any crate, through the prelude.
+    assert_eq!(get_diagnostics_as_string(&mut db), "");
+}
+
+#[test]
+fn test_byte_array_from_user_crate() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+
+    let crate_id = db.intern_crate(CrateLongId("user_crate".into()));
+    db.set_crate_root(crate_id, Some(Directory("user_src".into())));
+    let file_id = db.intern_file(FileLongId::OnDisk("user_src/lib.cairo".into()));
+    db.override_file_content(
+        file_id,
+        Some(Arc::new(
+            r#"
+use byte_array::ByteArrayTrait;
+use option::OptionTrait;
+
+pub fn main() -> usize {
+    let mut s: ByteArray = "hello";
+    s.append_byte(0x21);
+    s.append(@" world");
+    if s.at(0).is_none() {
+        panic_with_byte_array(@"empty");
+    }
+    s.len()
+}
+"#
+            .into(),
+        )),
+    );
+
+    // The ByteArray API is usable from crates other than the corelib.
+    assert_eq!(get_diagnostics_as_string(&mut db), "");
+}
+
+#[test]
+fn test_inline_macro_argument_diagnostics() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+
+    let crate_id = db.intern_crate(CrateLongId("user_crate".into()));
+    db.set_crate_root(crate_id, Some(Directory("user_src".into())));
+    let file_id = db.intern_file(FileLongId::OnDisk("user_src/lib.cairo".into()));
+    db.override_file_content(
+        file_id,
+        Some(Arc::new(
+            indoc! {"
+                pub fn main() -> Array<felt252> {
+                    assert!(1_u8 == 1_u16, 'wrong types');
+                    array![1, undefined]
+                }
+            "}
+            .into(),
+        )),
+    );
+
+    // Diagnostics in the arguments of inline macros are reported on the offending arguments.
+    assert_eq!(
+        get_diagnostics_as_string(&mut db),
+        indoc! {r#"
+            error: Plugin diagnostic: Unexpected argument type. Expected: "@core::integer::u8", found: "@core::integer::u16".
+             --> lib.cairo:2:21
+                assert!(1_u8 == 1_u16, 'wrong types');
+                                ^***^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:15
+                array![1, undefined]
+                          ^*******^
+
+            "#}
+    );
+}
+
+#[test]
+fn test_warnings_as_errors() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+
+    let crate_id = db.intern_crate(CrateLongId("user_crate".into()));
+    db.set_crate_root(crate_id, Some(Directory("user_src".into())));
+    let file_id = db.intern_file(FileLongId::OnDisk("user_src/lib.cairo".into()));
+    db.override_file_content(
+        file_id,
+        Some(Arc::new("pub fn main() {\n    let x = 1;\n}\n".into())),
+    );
+
+    // Warnings are reported, but fail the compilation only if they are treated as errors.
+    let mut diagnostics = String::new();
+    assert!(!DiagnosticsReporter::write_to_string(&mut diagnostics).check(&db));
+    assert!(diagnostics.starts_with("warning: Unused variable."), "{diagnostics}");
+    assert!(DiagnosticsReporter::ignoring().with_warnings_as_errors(true).check(&db));
+}
@@ -30,6 +30,10 @@ pub struct CompilerConfig<'c> {
     /// The name of the allowed libfuncs list to use in compilation.
     /// If None the default list of audited libfuncs will be used.
     pub allowed_libfuncs_list_name: Option<String>,
+
+    /// Adds the source locations of the Sierra statements to the debug info of the compiled
+    /// program, where it is generated.
+    pub add_statements_locations: bool,
 }
 
 /// The default compiler configuration.
@@ -39,6 +43,7 @@ impl Default for CompilerConfig<'static> {
             diagnostics_reporter: DiagnosticsReporter::default(),
             replace_ids: false,
             allowed_libfuncs_list_name: None,
+            add_statements_locations: false,
         }
     }
 }
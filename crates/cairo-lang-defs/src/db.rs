@@ -4,6 +4,7 @@ use std::sync::Arc;
 use cairo_lang_diagnostics::{Maybe, ToMaybe};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, Directory, FileId, FileLongId, VirtualFile};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -240,6 +241,8 @@ pub struct GeneratedFileInfo {
     pub aux_data: DynGeneratedFileAuxData,
     /// The module and file index from which the current file was generated.
     pub origin: ModuleFileId,
+    /// The span of the item in the origin file from which the current file was generated.
+    pub origin_item_span: TextSpan,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -328,6 +331,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                     res.generated_file_infos.push(Some(GeneratedFileInfo {
                         aux_data: generated.aux_data,
                         origin: module_file_id,
+                        origin_item_span: item_ast.as_syntax_node().span_without_trivia(syntax_db),
                     }));
                     module_queue.push_back((new_file, db.file_syntax(new_file)?.items(syntax_db)));
                 }
@@ -2,9 +2,11 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::SyntaxNode;
 use smol_str::SmolStr;
 
 /// A trait for arbitrary data that a macro generates along with a generated file.
@@ -60,6 +62,17 @@ pub struct PluginResult {
 pub struct PluginDiagnostic {
     pub stable_ptr: SyntaxStablePtrId,
     pub message: String,
+    pub severity: Severity,
+}
+impl PluginDiagnostic {
+    /// Creates a diagnostic of [Severity::Error].
+    pub fn error(stable_ptr: SyntaxStablePtrId, message: String) -> Self {
+        Self { stable_ptr, message, severity: Severity::Error }
+    }
+    /// Creates a diagnostic of [Severity::Warning].
+    pub fn warning(stable_ptr: SyntaxStablePtrId, message: String) -> Self {
+        Self { stable_ptr, message, severity: Severity::Warning }
+    }
 }
 
 // TOD(spapini): Move to another place.
@@ -69,4 +82,58 @@ pub trait MacroPlugin: std::fmt::Debug + Sync + Send {
     /// Otherwise, returns (virtual_module_name, module_content), and a virtual submodule
     /// with that name and content should be created.
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult;
+
+    /// Attributes of the functions that are entry points handled by this plugin, called from
+    /// outside of the crate (e.g. tests). Such functions are not reported as unused.
+    fn executable_attributes(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// A piece of the code generated by an inline macro plugin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InlineMacroCodePiece {
+    /// Generated code. Diagnostics in it are mapped to the whole macro call.
+    Text(String),
+    /// A node of the macro call (e.g. one of its arguments), copied as is. Diagnostics in it are
+    /// mapped to the node itself.
+    Node(SyntaxNode),
+}
+impl From<&str> for InlineMacroCodePiece {
+    fn from(text: &str) -> Self {
+        Self::Text(text.into())
+    }
+}
+impl From<String> for InlineMacroCodePiece {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+impl From<SyntaxNode> for InlineMacroCodePiece {
+    fn from(node: SyntaxNode) -> Self {
+        Self::Node(node)
+    }
+}
+
+/// Result of inline macro code generation.
+#[derive(Default)]
+pub struct InlinePluginResult {
+    /// The pieces of the code that replaces the macro call. If None, the macro call is left as is.
+    pub code: Option<Vec<InlineMacroCodePiece>>,
+    /// Diagnostics.
+    pub diagnostics: Vec<PluginDiagnostic>,
+}
+
+/// A trait for an inline macro plugin: an external plugin that generates code for an inline macro
+/// call expression, e.g. `array![1, 2, 3]`.
+pub trait InlineMacroPlugin: std::fmt::Debug + Sync + Send {
+    /// Generates the code that replaces the given macro call.
+    /// The generated code should be a single self-contained expression (e.g. a block), as it is
+    /// spliced into the surrounding code as is. The arguments of the macro call should be added as
+    /// [InlineMacroCodePiece::Node], so that diagnostics in them point to the original code.
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        syntax: &ast::ExprInlineMacro,
+    ) -> InlinePluginResult;
 }
@@ -242,10 +242,10 @@ impl MacroPlugin for DummyPlugin {
                     content: "extern type B;".into(),
                     aux_data: DynGeneratedFileAuxData::new(DummyAuxData),
                 }),
-                diagnostics: vec![PluginDiagnostic {
-                    stable_ptr: free_function_ast.stable_ptr().untyped(),
-                    message: "bla".into(),
-                }],
+                diagnostics: vec![PluginDiagnostic::error(
+                    free_function_ast.stable_ptr().untyped(),
+                    "bla".into(),
+                )],
                 remove_original_item: false,
             },
             _ => PluginResult::default(),
@@ -12,12 +12,37 @@ use itertools::Itertools;
 
 use crate::location_marks::get_location_marks;
 
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Severity {
+    /// An informational message, usually attached to another diagnostic.
+    Note,
+    /// A potential problem that does not prevent compilation.
+    Warning,
+    /// A problem that prevents compilation.
+    #[default]
+    Error,
+}
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Note => write!(f, "note"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
 /// A trait for diagnostics (i.e., errors and warnings) across the compiler.
 /// Meant to be implemented by each module that may produce diagnostics.
 pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
+    /// The severity of the diagnostic. Only diagnostics of [Severity::Error] fail the compilation.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
 pub struct DiagnosticLocation {
@@ -78,20 +103,26 @@ impl<T> ToOption<T> for Maybe<T> {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct DiagnosticsBuilder<TEntry: DiagnosticEntry> {
     pub count: usize,
+    /// The number of diagnostics of [Severity::Error] in the tree.
+    pub error_count: usize,
     pub leaves: Vec<TEntry>,
     pub subtrees: Vec<Diagnostics<TEntry>>,
 }
 impl<TEntry: DiagnosticEntry> DiagnosticsBuilder<TEntry> {
     pub fn new() -> Self {
-        Self { leaves: Default::default(), subtrees: Default::default(), count: 0 }
+        Self { leaves: Default::default(), subtrees: Default::default(), count: 0, error_count: 0 }
     }
     pub fn add(&mut self, diagnostic: TEntry) -> DiagnosticAdded {
+        if diagnostic.severity() == Severity::Error {
+            self.error_count += 1;
+        }
         self.leaves.push(diagnostic);
         self.count += 1;
         DiagnosticAdded::default()
     }
     pub fn extend(&mut self, diagnostics: Diagnostics<TEntry>) {
         self.count += diagnostics.len();
+        self.error_count += diagnostics.error_count();
         self.subtrees.push(diagnostics);
     }
     pub fn build(self) -> Diagnostics<TEntry> {
@@ -109,6 +140,16 @@ pub fn format_diagnostics(
     db: &dyn FilesGroup,
     message: &str,
     location: DiagnosticLocation,
+) -> String {
+    format_diagnostics_with_severity(db, Severity::Error, message, location)
+}
+
+/// Formats a diagnostic message with its severity and location marks.
+pub fn format_diagnostics_with_severity(
+    db: &dyn FilesGroup,
+    severity: Severity,
+    message: &str,
+    location: DiagnosticLocation,
 ) -> String {
     let file_name = location.file_id.file_name(db);
     let marks = get_location_marks(db, &location);
@@ -116,7 +157,7 @@ pub fn format_diagnostics(
         Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
         None => "?".into(),
     };
-    format!("error: {message}\n --> {file_name}:{pos}\n{marks}\n")
+    format!("{severity}: {message}\n --> {file_name}:{pos}\n{marks}\n")
 }
 
 /// A set of diagnostic entries that arose during a computation.
@@ -135,16 +176,37 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         self.0.count == 0
     }
 
+    /// Returns the number of diagnostics of [Severity::Error].
+    pub fn error_count(&self) -> usize {
+        self.0.error_count
+    }
+
+    /// Returns true if there is at least one diagnostic of [Severity::Error].
+    pub fn has_errors(&self) -> bool {
+        self.0.error_count > 0
+    }
+
     pub fn is_diagnostic_free(&self) -> Maybe<()> {
         if self.is_empty() { Ok(()) } else { Err(DiagnosticAdded) }
     }
 
+    /// Returns `Err` if there is at least one diagnostic of [Severity::Error]. Warnings and notes
+    /// are ignored.
+    pub fn check_error_free(&self) -> Maybe<()> {
+        if self.has_errors() { Err(DiagnosticAdded) } else { Ok(()) }
+    }
+
     pub fn format(&self, db: &TEntry::DbType) -> String {
         let mut res = String::new();
         // Format leaves.
         for entry in &self.0.leaves {
             let message = entry.format(db);
-            res += &format_diagnostics(db.upcast(), &message, entry.location(db));
+            res += &format_diagnostics_with_severity(
+                db.upcast(),
+                entry.severity(),
+                &message,
+                entry.location(db),
+            );
             res += "\n";
         }
         // Format subtrees.
@@ -168,6 +230,12 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         }
     }
 
+    /// Same as [Self::expect_with_db], except that only diagnostics of [Severity::Error] cause a
+    /// panic.
+    pub fn expect_no_errors(&self, db: &TEntry::DbType, error_message: &str) {
+        assert!(!self.has_errors(), "{}\n{}", error_message, self.format(db));
+    }
+
     // TODO(spapini): This is temporary. Remove once the logic in language server doesn't use this.
     pub fn get_all(&self) -> Vec<TEntry> {
         let mut res = self.0.leaves.clone();
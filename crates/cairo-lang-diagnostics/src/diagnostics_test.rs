@@ -7,7 +7,7 @@ use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, Severity};
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -61,3 +61,53 @@ fn test_diagnostics() {
         " }
     );
 }
+
+// Test diagnostic with a warning severity.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct WarningDiag {
+    file_id: FileId,
+}
+impl DiagnosticEntry for WarningDiag {
+    type DbType = dyn FilesGroup;
+
+    fn format(&self, _db: &dyn cairo_lang_filesystem::db::FilesGroup) -> String {
+        "Warning diagnostic.".into()
+    }
+
+    fn location(&self, _db: &dyn cairo_lang_filesystem::db::FilesGroup) -> DiagnosticLocation {
+        DiagnosticLocation {
+            file_id: self.file_id,
+            span: TextSpan {
+                start: TextOffset::default().add_width(TextWidth::new_for_testing(5)),
+                end: TextOffset::default().add_width(TextWidth::new_for_testing(8)),
+            },
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
+
+#[test]
+fn test_warnings() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<WarningDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(WarningDiag { file_id });
+    let diagnostics = diagnostics.build();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(!diagnostics.has_errors());
+    assert_eq!(diagnostics.check_error_free(), Ok(()));
+    assert_eq!(
+        diagnostics.format(&db_val),
+        indoc! { "
+            warning: Warning diagnostic.
+             --> dummy_file.sierra:2:1
+            efg.
+            ^*^
+
+        " }
+    );
+}
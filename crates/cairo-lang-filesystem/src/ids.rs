@@ -62,6 +62,13 @@ impl FileId {
             FileLongId::Virtual(vf) => vf.name.to_string(),
         }
     }
+
+    pub fn full_path(self, db: &dyn FilesGroup) -> String {
+        match db.lookup_intern_file(self) {
+            FileLongId::OnDisk(path) => path.display().to_string(),
+            FileLongId::Virtual(vf) => vf.name.to_string(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
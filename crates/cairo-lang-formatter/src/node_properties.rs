@@ -25,7 +25,10 @@ impl SyntaxNodeFormat for SyntaxNode {
                 true
             }
             SyntaxKind::TokenLParen
-                if matches!(grandparent_kind(db, self), Some(SyntaxKind::FunctionSignature)) =>
+                if matches!(
+                    grandparent_kind(db, self),
+                    Some(SyntaxKind::FunctionSignature | SyntaxKind::VisibilityPubArgumentClause)
+                ) =>
             {
                 true
             }
@@ -39,6 +42,19 @@ impl SyntaxNodeFormat for SyntaxNode {
             {
                 true
             }
+            SyntaxKind::TokenDotDot
+                if matches!(grandparent_kind(db, self), Some(SyntaxKind::PatternRange)) =>
+            {
+                true
+            }
+            SyntaxKind::TokenOr
+                if matches!(
+                    grandparent_kind(db, self),
+                    Some(SyntaxKind::ClosureParamWrapperNAry)
+                ) =>
+            {
+                !is_closure_leftor(self)
+            }
             SyntaxKind::TokenLT | SyntaxKind::TokenGT
                 if matches!(
                     grandparent_kind(db, self),
@@ -101,6 +117,19 @@ impl SyntaxNodeFormat for SyntaxNode {
             {
                 true
             }
+            SyntaxKind::TokenDotDot
+                if matches!(grandparent_kind(db, self), Some(SyntaxKind::PatternRange)) =>
+            {
+                true
+            }
+            SyntaxKind::TokenOr
+                if matches!(
+                    grandparent_kind(db, self),
+                    Some(SyntaxKind::ClosureParamWrapperNAry)
+                ) =>
+            {
+                is_closure_leftor(self)
+            }
             _ => false,
         }
     }
@@ -200,6 +229,23 @@ impl SyntaxNodeFormat for SyntaxNode {
                 SyntaxKind::ElseClause => Some(3),
                 _ => None,
             },
+            Some(SyntaxKind::ExprWhile | SyntaxKind::ExprFor) => match self.kind(db) {
+                SyntaxKind::ExprBlock => Some(1),
+                SyntaxKind::ExprBinary
+                | SyntaxKind::ExprErrorPropagate
+                | SyntaxKind::ExprFieldInitShorthand
+                | SyntaxKind::ExprFunctionCall
+                | SyntaxKind::ExprIf
+                | SyntaxKind::ExprList
+                | SyntaxKind::ExprMatch
+                | SyntaxKind::ExprMissing
+                | SyntaxKind::ExprParenthesized
+                | SyntaxKind::ExprPath
+                | SyntaxKind::ExprStructCtorCall
+                | SyntaxKind::ExprTuple
+                | SyntaxKind::ExprUnary => Some(2),
+                _ => None,
+            },
             Some(SyntaxKind::ExprMatch) => match self.kind(db) {
                 SyntaxKind::MatchArms => Some(1),
                 SyntaxKind::ExprBinary
@@ -564,15 +610,19 @@ impl SyntaxNodeFormat for SyntaxNode {
                     )),
                     trailing: None,
                 },
-                SyntaxKind::TerminalOrOr => WrappingBreakLinePoints {
-                    leading: Some(BreakLinePointProperties::new(
-                        11,
-                        BreakLinePointIndentation::Indented,
-                        true,
-                        true,
-                    )),
-                    trailing: None,
-                },
+                SyntaxKind::TerminalOrOr
+                    if parent_kind(db, self) != Some(SyntaxKind::ExprClosure) =>
+                {
+                    WrappingBreakLinePoints {
+                        leading: Some(BreakLinePointProperties::new(
+                            11,
+                            BreakLinePointIndentation::Indented,
+                            true,
+                            true,
+                        )),
+                        trailing: None,
+                    }
+                }
                 SyntaxKind::TerminalAnd => WrappingBreakLinePoints {
                     leading: Some(BreakLinePointProperties::new(
                         12,
@@ -582,15 +632,19 @@ impl SyntaxNodeFormat for SyntaxNode {
                     )),
                     trailing: None,
                 },
-                SyntaxKind::TerminalOr => WrappingBreakLinePoints {
-                    leading: Some(BreakLinePointProperties::new(
-                        13,
-                        BreakLinePointIndentation::Indented,
-                        true,
-                        true,
-                    )),
-                    trailing: None,
-                },
+                SyntaxKind::TerminalOr
+                    if parent_kind(db, self) != Some(SyntaxKind::ClosureParamWrapperNAry) =>
+                {
+                    WrappingBreakLinePoints {
+                        leading: Some(BreakLinePointProperties::new(
+                            13,
+                            BreakLinePointIndentation::Indented,
+                            true,
+                            true,
+                        )),
+                        trailing: None,
+                    }
+                }
                 SyntaxKind::TerminalXor => WrappingBreakLinePoints {
                     leading: Some(BreakLinePointProperties::new(
                         14,
@@ -644,3 +698,9 @@ impl SyntaxNodeFormat for SyntaxNode {
         }
     }
 }
+
+/// Returns whether the given `|` token is the opening one of a closure parameter list.
+fn is_closure_leftor(node: &SyntaxNode) -> bool {
+    let terminal = node.parent().unwrap();
+    terminal.offset() == terminal.parent().unwrap().offset()
+}
@@ -29,6 +29,12 @@ impl Upcast<dyn FilesGroup> for DatabaseImpl {
     "test_data/expected_results/linebreaking.cairo"
 )]
 #[test_case("test_data/cairo_files/attrs.cairo", "test_data/expected_results/attrs.cairo")]
+#[test_case("test_data/cairo_files/patterns.cairo", "test_data/expected_results/patterns.cairo")]
+#[test_case("test_data/cairo_files/closures.cairo", "test_data/expected_results/closures.cairo")]
+#[test_case(
+    "test_data/cairo_files/visibility.cairo",
+    "test_data/expected_results/visibility.cairo"
+)]
 fn format_and_compare_file(unformatted_filename: &str, expected_filename: &str) {
     let db_val = SimpleParserDatabase::default();
     let db = &db_val;
@@ -0,0 +1,13 @@
+fn foo(x: u8, y: (u8, u8)) -> u8 {
+    let a = match x {
+        0 => { 0 },
+        1 .. 10=>{ 1 },
+        10  ..0x20 => 2,
+        _ => 3,
+    };
+    match y {
+        (0, 1) => a,
+        (_, 0)=>0,
+        (_,_) => 1,
+    }
+}
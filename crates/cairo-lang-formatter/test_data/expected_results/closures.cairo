@@ -0,0 +1,7 @@
+fn closures() {
+    let a = |x: felt252, y| -> felt252 {
+        x + y
+    };
+    let b = || 5;
+    let c = |x| x * 2;
+}
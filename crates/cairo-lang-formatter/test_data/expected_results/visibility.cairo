@@ -0,0 +1,7 @@
+pub struct Visible {
+    pub a: felt252,
+    pub(crate) b: felt252,
+    c: felt252,
+}
+pub(crate) fn crate_visible() {}
+pub use crate_visible as public_crate_visible;
@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{ImplDefLongId, LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_lowering::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnosticKind};
+use cairo_lang_semantic::items::visibility::peek_visible_in;
+use cairo_lang_semantic::{SemanticDiagnostic, TypeId, TypeLongId};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use lsp::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Location, TextEdit, Url,
+    WorkspaceEdit,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::get_location;
+use crate::references::{origin_location, CodeLocation};
+
+#[cfg(test)]
+#[path = "code_actions_test.rs"]
+mod test;
+
+/// The body of the code generated by fixes, until it is implemented.
+const STUB_BODY: &str = "panic_with_felt252('Not implemented')";
+
+/// A fix of a diagnostic. Fixes are computed with the diagnostics, and sent to the client as the
+/// data of the diagnostic, so that code actions can apply them later.
+#[derive(Serialize, Deserialize)]
+pub struct Fix {
+    pub title: String,
+    pub edit: WorkspaceEdit,
+}
+
+/// Returns the quick fixes of the given diagnostics, as sent back by the client.
+pub fn code_actions(diagnostics: Vec<Diagnostic>) -> Vec<CodeActionOrCommand> {
+    let mut actions = vec![];
+    for diagnostic in diagnostics {
+        let Some(data) = diagnostic.data.clone() else { continue; };
+        let Ok(fixes) = serde_json::from_value::<Vec<Fix>>(data) else { continue; };
+        let is_preferred = fixes.len() == 1;
+        actions.extend(fixes.into_iter().map(|fix| {
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(fix.edit),
+                is_preferred: Some(is_preferred),
+                ..CodeAction::default()
+            })
+        }));
+    }
+    actions
+}
+
+/// Returns the fixes of a semantic diagnostic.
+pub fn semantic_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    diagnostic: &SemanticDiagnostic,
+) -> Vec<Fix> {
+    let Some((file, node)) = location_node(db, diagnostic.stable_location) else { return vec![]; };
+    match &diagnostic.kind {
+        SemanticDiagnosticKind::PathNotFound(item_type) => {
+            import_fixes(db, diagnostic.stable_location.module_file_id.0, file, node, *item_type)
+        }
+        SemanticDiagnosticKind::WrongType { expected_ty, actual_ty }
+        | SemanticDiagnosticKind::WrongArgumentType { expected_ty, actual_ty }
+        | SemanticDiagnosticKind::WrongReturnType { expected_ty, actual_ty } => {
+            snapshot_fix(db, file, node, *expected_ty, *actual_ty).into_iter().collect()
+        }
+        SemanticDiagnosticKind::RefArgNotExplicit => {
+            prefix_fix(db, file, node, "ref", "Pass by reference").into_iter().collect()
+        }
+        SemanticDiagnosticKind::MissingItemsInImpl { item_names } => {
+            missing_functions_fix(db, diagnostic.stable_location, file, node, item_names)
+                .into_iter()
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the fixes of a lowering diagnostic.
+pub fn lowering_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    diagnostic: &LoweringDiagnostic,
+) -> Vec<Fix> {
+    let Some((file, node)) = location_node(db, diagnostic.stable_location) else { return vec![]; };
+    match &diagnostic.kind {
+        LoweringDiagnosticKind::MissingMatchArm(pattern) => {
+            match_arm_fix(db, file, node, pattern).into_iter().collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the fixes importing a name that was not found, one for each visible item with that
+/// name in the loaded crates.
+fn import_fixes(
+    db: &(dyn SemanticGroup + 'static),
+    user_module_id: ModuleId,
+    file: FileId,
+    node: SyntaxNode,
+    item_type: NotFoundItemType,
+) -> Vec<Fix> {
+    let syntax_db = db.upcast();
+    // Only the first segment of a path can be imported.
+    let Some(segment) = node.parent() else { return vec![]; };
+    let Some(path) = segment.parent() else { return vec![]; };
+    if path.kind(syntax_db) != SyntaxKind::ExprPath {
+        return vec![];
+    }
+    let segments = ast::ExprPath::from_syntax_node(syntax_db, path.clone()).elements(syntax_db);
+    if segments.first().map(|first| first.as_syntax_node()) != Some(segment) {
+        return vec![];
+    }
+    let is_last = segments.len() == 1;
+    let Some(location) = import_location(db, file, path) else { return vec![]; };
+    let Some(indentation) = indentation(db, location) else { return vec![]; };
+
+    let name = node.get_text_without_trivia(syntax_db);
+    let mut paths = OrderedHashSet::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let Ok(Some(item_info)) = db.module_item_info_by_name(*module_id, name.clone().into())
+            else {
+                continue;
+            };
+            if !is_importable(item_info.item_id, item_type, is_last)
+                || !peek_visible_in(db.upcast(), item_info.visibility, *module_id, user_module_id)
+                || !is_module_visible(db, *module_id, user_module_id)
+            {
+                continue;
+            }
+            paths.insert(format!("{}::{name}", module_id.full_path(db.upcast())));
+        }
+    }
+    paths
+        .into_iter()
+        .map(|path| Fix {
+            title: format!("Import `{path}`"),
+            edit: workspace_edit(db, vec![(location, format!("use {path};\n{indentation}"))]),
+        })
+        .collect()
+}
+
+/// Returns the location of new `use` items of the module of a path - before its first item.
+fn import_location(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    mut node: SyntaxNode,
+) -> Option<CodeLocation> {
+    let syntax_db = db.upcast();
+    let items = loop {
+        match node.kind(syntax_db) {
+            SyntaxKind::ItemUse => return None,
+            SyntaxKind::ItemModule => {
+                if let ast::MaybeModuleBody::Some(body) =
+                    ast::ItemModule::from_syntax_node(syntax_db, node.clone()).body(syntax_db)
+                {
+                    break body.items(syntax_db);
+                }
+            }
+            SyntaxKind::SyntaxFile => {
+                break ast::SyntaxFile::from_syntax_node(syntax_db, node).items(syntax_db);
+            }
+            _ => {}
+        }
+        node = node.parent()?;
+    };
+    let first_item = items.elements(syntax_db).into_iter().next()?;
+    origin(db, file, first_item.as_syntax_node().span_without_trivia(syntax_db).start)
+}
+
+/// Returns whether a module item can be imported in place of a name that was not found.
+fn is_importable(item_id: ModuleItemId, item_type: NotFoundItemType, is_last: bool) -> bool {
+    if !is_last {
+        // A prefix of a path.
+        return matches!(
+            item_id,
+            ModuleItemId::Submodule(_) | ModuleItemId::Enum(_) | ModuleItemId::Trait(_)
+        );
+    }
+    match item_type {
+        NotFoundItemType::Identifier => !matches!(item_id, ModuleItemId::Use(_)),
+        NotFoundItemType::Function => {
+            matches!(item_id, ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_))
+        }
+        NotFoundItemType::Type => matches!(
+            item_id,
+            ModuleItemId::Struct(_)
+                | ModuleItemId::Enum(_)
+                | ModuleItemId::ExternType(_)
+                | ModuleItemId::TypeAlias(_)
+        ),
+        NotFoundItemType::Trait => matches!(item_id, ModuleItemId::Trait(_)),
+        NotFoundItemType::Impl => {
+            matches!(item_id, ModuleItemId::Impl(_) | ModuleItemId::ImplAlias(_))
+        }
+    }
+}
+
+/// Returns whether a module and all the modules containing it are visible from another module.
+fn is_module_visible(
+    db: &(dyn SemanticGroup + 'static),
+    mut module_id: ModuleId,
+    user_module_id: ModuleId,
+) -> bool {
+    while let ModuleId::Submodule(submodule_id) = module_id {
+        let parent_module_id = submodule_id.parent_module(db.upcast());
+        let Ok(Some(item_info)) =
+            db.module_item_info_by_name(parent_module_id, submodule_id.name(db.upcast()))
+        else {
+            return false;
+        };
+        if !peek_visible_in(db.upcast(), item_info.visibility, parent_module_id, user_module_id) {
+            return false;
+        }
+        module_id = parent_module_id;
+    }
+    true
+}
+
+/// Returns the fix taking a snapshot of an expression, or desnapping it, if that is the difference
+/// between its type and the expected one.
+fn snapshot_fix(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    expected_ty: TypeId,
+    actual_ty: TypeId,
+) -> Option<Fix> {
+    if db.lookup_intern_type(expected_ty) == TypeLongId::Snapshot(actual_ty) {
+        prefix_fix(db, file, node, "@", "Take a snapshot")
+    } else if db.lookup_intern_type(actual_ty) == TypeLongId::Snapshot(expected_ty) {
+        prefix_fix(db, file, node, "*", "Desnap")
+    } else {
+        None
+    }
+}
+
+/// Returns the fix adding a prefix operator, or `ref`, to an expression.
+fn prefix_fix(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    prefix: &str,
+    title: &str,
+) -> Option<Fix> {
+    let syntax_db = db.upcast();
+    let span = node.span_without_trivia(syntax_db);
+    let start = origin(db, file, span.start)?;
+    // Binary operators, except for member access, bind weaker than prefix operators.
+    let is_binary = node.kind(syntax_db) == SyntaxKind::ExprBinary
+        && !matches!(
+            ast::ExprBinary::from_syntax_node(syntax_db, node).op(syntax_db),
+            ast::BinaryOperator::Dot(_)
+        );
+    let insertions = if prefix == "ref" {
+        vec![(start, "ref ".to_string())]
+    } else if is_binary {
+        vec![(start, format!("{prefix}(")), (origin(db, file, span.end)?, ")".to_string())]
+    } else {
+        vec![(start, prefix.to_string())]
+    };
+    Some(Fix { title: title.to_string(), edit: workspace_edit(db, insertions) })
+}
+
+/// Returns the fix adding stubs of the functions of a trait missing in an impl.
+fn missing_functions_fix(
+    db: &(dyn SemanticGroup + 'static),
+    stable_location: StableLocation,
+    file: FileId,
+    node: SyntaxNode,
+    item_names: &[smol_str::SmolStr],
+) -> Option<Fix> {
+    let syntax_db = db.upcast();
+    // The diagnostic is reported on the name of the impl.
+    let impl_node = node.parent()?;
+    if impl_node.kind(syntax_db) != SyntaxKind::ItemImpl {
+        return None;
+    }
+    let item_impl = ast::ItemImpl::from_syntax_node(syntax_db, impl_node.clone());
+    let ast::MaybeImplBody::Some(body) = item_impl.body(syntax_db) else { return None; };
+    let impl_def_id =
+        db.intern_impl(ImplDefLongId(stable_location.module_file_id, item_impl.stable_ptr()));
+    let trait_id = db.impl_def_trait(impl_def_id).ok()?;
+    let trait_functions = db.trait_functions(trait_id).ok()?;
+
+    // The generic parameters of the trait are replaced by the generic arguments of the impl, as
+    // written.
+    let generic_args = item_impl
+        .trait_path(syntax_db)
+        .elements(syntax_db)
+        .last()?
+        .generic_args(syntax_db)
+        .unwrap_or_default();
+    let substitution: HashMap<String, String> = db
+        .trait_generic_params(trait_id)
+        .ok()?
+        .into_iter()
+        .zip(generic_args)
+        .map(|(param, arg)| {
+            (
+                param.id().name(db.upcast()).to_string(),
+                arg.as_syntax_node().get_text_without_trivia(syntax_db),
+            )
+        })
+        .collect();
+
+    let items = body.items(syntax_db);
+    let (offset, is_empty) = if items.elements(syntax_db).is_empty() {
+        (body.lbrace(syntax_db).as_syntax_node().span_without_trivia(syntax_db).end, true)
+    } else {
+        (items.as_syntax_node().span_without_trivia(syntax_db).end, false)
+    };
+    let location = origin(db, file, offset)?;
+    let indentation =
+        indentation(db, origin(db, file, impl_node.span_without_trivia(syntax_db).start)?)?;
+    let mut text = String::new();
+    for name in item_names {
+        let trait_function = trait_functions.get(name)?;
+        let trait_file = db.module_file(trait_function.module_file_id(db.upcast())).ok()?;
+        let declaration = ast::TraitItemFunction::from_syntax_node(
+            syntax_db,
+            db.file_syntax(trait_file)
+                .ok()?
+                .as_syntax_node()
+                .lookup_ptr(syntax_db, trait_function.untyped_stable_ptr(db.upcast())),
+        )
+        .declaration(syntax_db)
+        .as_syntax_node();
+        if !(is_empty && text.is_empty()) {
+            text.push('\n');
+        }
+        text += &format!(
+            "\n{indentation}    {} {{\n{indentation}        {STUB_BODY}\n{indentation}    }}",
+            substituted_text(syntax_db, &declaration, &substitution)
+        );
+    }
+    if is_empty {
+        text += &format!("\n{indentation}");
+    }
+    Some(Fix {
+        title: "Implement missing functions".into(),
+        edit: workspace_edit(db, vec![(location, text)]),
+    })
+}
+
+/// Returns the code of a syntax node, without its outer trivia, with some identifiers replaced.
+fn substituted_text(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+    substitution: &HashMap<String, String>,
+) -> String {
+    fn collect(
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+        bounds: TextSpan,
+        substitution: &HashMap<String, String>,
+        text: &mut String,
+    ) {
+        let span = node.span(db);
+        if node.kind(db) == SyntaxKind::Trivia
+            && (span.end <= bounds.start || bounds.end <= span.start)
+        {
+            return;
+        }
+        let mut children = node.children(db).peekable();
+        if children.peek().is_some() {
+            for child in children {
+                collect(db, child, bounds, substitution, text);
+            }
+            return;
+        }
+        let token = node.get_text(db);
+        match substitution.get(&token) {
+            Some(replacement) if node.kind(db) == SyntaxKind::TokenIdentifier => {
+                *text += replacement
+            }
+            _ => *text += &token,
+        }
+    }
+    let mut text = String::new();
+    collect(db, node.clone(), node.span_without_trivia(db), substitution, &mut text);
+    text
+}
+
+/// Returns the fix adding an arm for a pattern not covered by a match.
+fn match_arm_fix(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    pattern: &str,
+) -> Option<Fix> {
+    let syntax_db = db.upcast();
+    if node.kind(syntax_db) != SyntaxKind::ExprMatch {
+        return None;
+    }
+    let expr_match = ast::ExprMatch::from_syntax_node(syntax_db, node.clone());
+    let arms = expr_match.arms(syntax_db);
+    let arm = format!("{pattern} => {STUB_BODY},");
+    let insertion = match arms.elements(syntax_db).first() {
+        None => {
+            let indentation =
+                indentation(db, origin(db, file, node.span_without_trivia(syntax_db).start)?)?;
+            (
+                origin(
+                    db,
+                    file,
+                    expr_match.lbrace(syntax_db).as_syntax_node().span(syntax_db).end,
+                )?,
+                format!("\n{indentation}    {arm}\n{indentation}"),
+            )
+        }
+        Some(first_arm) => {
+            let indentation = indentation(
+                db,
+                origin(db, file, first_arm.as_syntax_node().span_without_trivia(syntax_db).start)?,
+            )?;
+            let last = arms.as_syntax_node().children(syntax_db).last()?;
+            let separator =
+                if last.kind(syntax_db) == SyntaxKind::TerminalComma { "" } else { "," };
+            (
+                origin(db, file, last.span_without_trivia(syntax_db).end)?,
+                format!("{separator}\n{indentation}{arm}"),
+            )
+        }
+    };
+    Some(Fix {
+        title: format!("Add match arm `{pattern}`"),
+        edit: workspace_edit(db, vec![insertion]),
+    })
+}
+
+/// Returns the file and the syntax node of a diagnostic location.
+fn location_node(
+    db: &(dyn SemanticGroup + 'static),
+    location: StableLocation,
+) -> Option<(FileId, SyntaxNode)> {
+    let file = db.module_file(location.module_file_id).ok()?;
+    let node =
+        db.file_syntax(file).ok()?.as_syntax_node().lookup_ptr(db.upcast(), location.stable_ptr);
+    Some((file, node))
+}
+
+/// Returns the location in the code of an offset, possibly in code generated by plugins.
+fn origin(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    offset: TextOffset,
+) -> Option<CodeLocation> {
+    origin_location(db, file, TextSpan { start: offset, end: offset })
+}
+
+/// Returns the indentation of the line of a location.
+fn indentation(db: &(dyn SemanticGroup + 'static), location: CodeLocation) -> Option<String> {
+    let line = location.span.start.position_in_file(db.upcast(), location.file)?.line;
+    let line_start = *db.file_summary(location.file)?.line_offsets.get(line)?;
+    let content = db.file_content(location.file)?;
+    Some(line_start.take_from(&content).chars().take_while(|c| *c == ' ' || *c == '\t').collect())
+}
+
+/// Returns the edit inserting texts at locations in the code.
+fn workspace_edit(
+    db: &(dyn SemanticGroup + 'static),
+    insertions: Vec<(CodeLocation, String)>,
+) -> WorkspaceEdit {
+    let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+    for (location, new_text) in insertions {
+        let Location { uri, range } = get_location(db, location);
+        changes.entry(uri).or_default().push(TextEdit { range, new_text });
+    }
+    WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }
+}
@@ -0,0 +1,148 @@
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::{lowering_fixes, semantic_fixes};
+use crate::test_utils::{fixture_file, format_lines_range, setup_fixture};
+
+/// Returns the fixes of the diagnostics of a crate of a single file, each formatted as its title
+/// followed by its edits.
+fn fixes(content: &str) -> Vec<String> {
+    let db = setup_fixture(&[("lib.cairo", content)]);
+    let file = fixture_file(&db, "lib.cairo");
+    let semantic_diagnostics = db.file_semantic_diagnostics(file).unwrap();
+    let lowering_diagnostics = db.file_lowering_diagnostics(file).unwrap();
+    semantic_diagnostics
+        .get_all()
+        .iter()
+        .flat_map(|diagnostic| semantic_fixes(&db, diagnostic))
+        .chain(
+            lowering_diagnostics
+                .get_all()
+                .iter()
+                .flat_map(|diagnostic| lowering_fixes(&db, diagnostic)),
+        )
+        .map(|fix| {
+            let edits = fix
+                .edit
+                .changes
+                .unwrap_or_default()
+                .into_values()
+                .flatten()
+                .map(|edit| format!("{} {:?}", format_lines_range(edit.range), edit.new_text))
+                .sorted()
+                .join(", ");
+            format!("{}: {edits}", fix.title)
+        })
+        .collect()
+}
+
+#[test]
+fn test_import_fixes() {
+    let fixes = fixes(indoc! {"
+        mod shapes {
+            #[derive(Copy, Drop)]
+            pub struct Square {
+                pub side: u32,
+            }
+        }
+
+        mod tiles {
+            struct Square {}
+        }
+
+        mod board {
+            pub struct Square {}
+        }
+
+        mod area {
+            fn area(square: Square) -> u32 {
+                square.side * square.side
+            }
+        }
+    "});
+    // Each visible item of the name can be imported. The new `use` item is inserted before the
+    // first item of the module of the path.
+    assert_eq!(
+        fixes,
+        [
+            "Import `fixture::shapes::Square`: 16:4-16:4 \"use fixture::shapes::Square;\\n    \"",
+            "Import `fixture::board::Square`: 16:4-16:4 \"use fixture::board::Square;\\n    \"",
+        ]
+    );
+}
+
+#[test]
+fn test_snapshot_and_ref_fixes() {
+    let fixes = fixes(indoc! {"
+        fn by_snapshot(a: @u32) {}
+
+        fn by_value(a: u32) {}
+
+        fn by_ref(ref a: u32) {}
+
+        fn main() {
+            let mut x = 1_u32;
+            by_snapshot(x);
+            by_snapshot(x + 1);
+            by_value(@x);
+            by_ref(x);
+        }
+    "});
+    // Binary expressions are wrapped in parentheses, except for member access.
+    assert_eq!(
+        fixes,
+        [
+            "Take a snapshot: 8:16-8:16 \"@\"",
+            "Take a snapshot: 9:16-9:16 \"@(\", 9:21-9:21 \")\"",
+            "Desnap: 10:13-10:13 \"*\"",
+            "Pass by reference: 11:11-11:11 \"ref \"",
+        ]
+    );
+}
+
+#[test]
+fn test_missing_functions_fix() {
+    let fixes = fixes(indoc! {"
+        trait Shape<T> {
+            fn area(self: @T) -> u32;
+            fn scale(ref self: T, factor: u32);
+            fn name(self: @T) -> felt252;
+        }
+
+        impl U32Shape of Shape<u32> {
+            fn name(self: @u32) -> felt252 {
+                'u32'
+            }
+        }
+    "});
+    // The generic parameters of the trait are substituted by the generic arguments of the impl.
+    let expected = "Implement missing functions: 9:5-9:5 \"\\n\\n    fn area(self: @u32) -> u32 {\
+                    \\n        panic_with_felt252('Not implemented')\\n    }\\n\\n    fn \
+                    scale(ref self: u32, factor: u32) {\\n        \
+                    panic_with_felt252('Not implemented')\\n    }\"";
+    assert_eq!(fixes, [expected]);
+}
+
+#[test]
+fn test_match_arm_fix() {
+    let fixes = fixes(indoc! {"
+        enum Shape {
+            Circle: u32,
+            Square: u32,
+            Triangle: u32,
+        }
+
+        fn area(shape: Shape) -> u32 {
+            match shape {
+                Shape::Circle(r) => 3 * r * r,
+            }
+        }
+    "});
+    // Only the first missing arm is reported.
+    let expected = "Add match arm `Shape::Square(_)`: 8:38-8:38 \"\\n        Shape::Square(_) => \
+                    panic_with_felt252('Not implemented'),\"";
+    assert_eq!(fixes, [expected]);
+}
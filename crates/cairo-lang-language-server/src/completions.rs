@@ -1,5 +1,5 @@
 use cairo_lang_defs::ids::{
-    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId,
+    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
 };
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::db::SemanticGroup;
@@ -8,6 +8,7 @@ use cairo_lang_semantic::expr::inference::solver::SolutionSet;
 use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
 use cairo_lang_semantic::items::structure::SemanticStructEx;
 use cairo_lang_semantic::items::us::SemanticUseEx;
+use cairo_lang_semantic::items::visibility::peek_visible_in;
 use cairo_lang_semantic::lookup_item::{HasResolverData, LookupItemEx};
 use cairo_lang_semantic::lsp_helpers::TypeFilter;
 use cairo_lang_semantic::resolve::{ResolvedGenericItem, Resolver};
@@ -60,8 +61,13 @@ pub fn dot_completions(
     if let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
         db.lookup_intern_type(ty)
     {
+        let struct_module_id = concrete_struct_id.struct_id(db).parent_module(db.upcast());
         db.concrete_struct_members(concrete_struct_id).ok()?.into_iter().for_each(
             |(name, member)| {
+                // Skip members that are not visible from the current module.
+                if !peek_visible_in(db.upcast(), member.visibility, struct_module_id, module_id) {
+                    return;
+                }
                 let completion = CompletionItem {
                     label: name.to_string(),
                     detail: Some(member.ty.format(db.upcast())),
@@ -84,6 +90,9 @@ fn completion_for_method(
     let trait_id = trait_function.trait_id(db.upcast());
     let name = trait_function.name(db.upcast());
     db.trait_function_signature(trait_function).ok()?;
+    if !is_trait_visible(db, module_id, trait_id)? {
+        return None;
+    }
 
     // TODO(spapini): Add signature.
     let detail = trait_id.full_path(db.upcast());
@@ -112,11 +121,23 @@ fn completion_for_method(
     Some(completion)
 }
 
+/// Checks if a trait is visible from a module.
+fn is_trait_visible(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    trait_id: TraitId,
+) -> Option<bool> {
+    let trait_module_id = trait_id.parent_module(db.upcast());
+    let item_info =
+        db.module_item_info_by_name(trait_module_id, trait_id.name(db.upcast())).ok()??;
+    Some(peek_visible_in(db.upcast(), item_info.visibility, trait_module_id, module_id))
+}
+
 /// Checks if a module has a trait in scope.
 fn module_has_trait(
     db: &dyn SemanticGroup,
     module_id: ModuleId,
-    trait_id: cairo_lang_defs::ids::TraitId,
+    trait_id: TraitId,
 ) -> Option<bool> {
     if db.module_traits_ids(module_id).ok()?.contains(&trait_id) {
         return Some(true);
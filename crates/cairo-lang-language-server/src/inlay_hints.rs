@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Expr, ExprFunctionCall, Statement};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+
+use crate::get_location;
+use crate::references::origin_location;
+use crate::signature_help::method_call;
+
+#[cfg(test)]
+#[path = "inlay_hints_test.rs"]
+mod test;
+
+/// Returns the inlay hints of a range of a file - the inferred types of variables declared by `let`
+/// statements without a type, and the names of the parameters of function calls.
+pub fn inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+) -> Vec<InlayHint> {
+    let Some(module_id) = db.file_modules(file).ok().and_then(|modules| modules.first().copied())
+    else {
+        return vec![];
+    };
+    // Functions may be generated by plugins from the code of the file, in other modules of its
+    // crate.
+    let mut hints = vec![];
+    for function_id in crate_functions(db, ModuleId::CrateRoot(module_id.owning_crate(db.upcast())))
+    {
+        function_hints(db, function_id, &mut hints);
+    }
+
+    let mut seen = HashSet::new();
+    let mut res = vec![];
+    for (hint_file, offset, label, kind) in hints {
+        let span = TextSpan { start: offset, end: offset };
+        let Some(location) = origin_location(db, hint_file, span) else { continue; };
+        if location.file != file {
+            continue;
+        }
+        let position = get_location(db, location).range.start;
+        if position < range.start
+            || range.end < position
+            || !seen.insert((position.line, position.character, label.clone()))
+        {
+            continue;
+        }
+        res.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind: Some(kind),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right: Some(kind == InlayHintKind::PARAMETER),
+            data: None,
+        });
+    }
+    res.sort_by_key(|hint| hint.position);
+    res
+}
+
+/// Returns the functions with a body defined in a module and its submodules.
+fn crate_functions(
+    db: &(dyn SemanticGroup + 'static),
+    module_id: ModuleId,
+) -> Vec<FunctionWithBodyId> {
+    let Ok(items) = db.module_items(module_id) else { return vec![]; };
+    let mut functions = vec![];
+    for item in items.iter() {
+        match item {
+            ModuleItemId::FreeFunction(id) => functions.push(FunctionWithBodyId::Free(*id)),
+            ModuleItemId::Impl(id) => {
+                let Ok(impl_functions) = db.impl_functions(*id) else { continue; };
+                functions.extend(impl_functions.values().map(|id| FunctionWithBodyId::Impl(*id)));
+            }
+            ModuleItemId::Submodule(id) => {
+                functions.extend(crate_functions(db, ModuleId::Submodule(*id)))
+            }
+            _ => {}
+        }
+    }
+    functions
+}
+
+/// Collects the inlay hints of a function, in the file it is defined in.
+fn function_hints(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    hints: &mut Vec<(FileId, TextOffset, String, InlayHintKind)>,
+) {
+    let syntax_db = db.upcast();
+    let Ok(file) = db.module_file(function_id.module_file_id(db.upcast())) else { return; };
+    let Ok(syntax) = db.file_syntax(file) else { return; };
+    let Ok(body) = db.function_body(function_id) else { return; };
+    let root = syntax.as_syntax_node();
+
+    for (_, statement) in body.statements.iter() {
+        let Statement::Let(statement) = statement else { continue; };
+        let node = root.lookup_ptr(syntax_db, statement.stable_ptr.untyped());
+        if node.kind(syntax_db) != SyntaxKind::StatementLet {
+            continue;
+        }
+        let let_statement = ast::StatementLet::from_syntax_node(syntax_db, node);
+        if !matches!(let_statement.type_clause(syntax_db), ast::OptionTypeClause::Empty(_)) {
+            continue;
+        }
+        for variable in statement.pattern.variables() {
+            // Types that failed to be inferred are already reported as diagnostics.
+            if variable.var.ty.check_not_missing(db).is_err() {
+                continue;
+            }
+            let node = root.lookup_ptr(syntax_db, variable.stable_ptr.untyped());
+            hints.push((
+                file,
+                node.span_without_trivia(syntax_db).end,
+                format!(": {}", variable.var.ty.format(db)),
+                InlayHintKind::TYPE,
+            ));
+        }
+    }
+
+    for (_, expr) in body.exprs.iter() {
+        let Expr::FunctionCall(call) = expr else { continue; };
+        let node = root.lookup_ptr(syntax_db, call.stable_ptr.untyped());
+        parameter_hints(db, file, node, call, hints);
+    }
+}
+
+/// Collects the names of the parameters before the unnamed arguments of a function call.
+fn parameter_hints(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    call: &ExprFunctionCall,
+    hints: &mut Vec<(FileId, TextOffset, String, InlayHintKind)>,
+) {
+    let syntax_db = db.upcast();
+    // Operators are function calls too, but have no arguments list. The receiver of a method call
+    // is its first argument.
+    let (call_syntax, skipped) = match node.kind(syntax_db) {
+        SyntaxKind::ExprFunctionCall => {
+            (ast::ExprFunctionCall::from_syntax_node(syntax_db, node), 0)
+        }
+        SyntaxKind::ExprBinary => {
+            let ast::Expr::FunctionCall(call_syntax) =
+                ast::ExprBinary::from_syntax_node(syntax_db, node).rhs(syntax_db)
+            else {
+                return;
+            };
+            if method_call(db, &call_syntax).is_none() {
+                return;
+            }
+            (call_syntax, 1)
+        }
+        _ => return,
+    };
+    let Some(signature) = db.concrete_function_signature(call.function).to_option() else {
+        return;
+    };
+    let args = call_syntax.arguments(syntax_db).args(syntax_db).elements(syntax_db);
+    for (arg, parameter) in args.into_iter().zip(signature.params.iter().skip(skipped)) {
+        let ast::ArgClause::Unnamed(clause) = arg.arg_clause(syntax_db) else { continue; };
+        // Hints repeating the argument are noise.
+        if clause.value(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db)
+            == parameter.name
+        {
+            continue;
+        }
+        hints.push((
+            file,
+            arg.as_syntax_node().span_without_trivia(syntax_db).start,
+            format!("{}:", parameter.name),
+            InlayHintKind::PARAMETER,
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+use indoc::indoc;
+use lsp::{InlayHintLabel, Position, Range};
+use pretty_assertions::assert_eq;
+
+use super::inlay_hints;
+use crate::test_utils::{fixture_file, setup_fixture};
+
+#[test]
+fn test_inlay_hints() {
+    let db = setup_fixture(&[(
+        "lib.cairo",
+        indoc! {"
+            fn scale(x: u32, factor: u32) -> u32 {
+                x * factor
+            }
+
+            #[derive(Copy, Drop)]
+            struct Pair {
+                first: u32,
+                second: u32,
+            }
+
+            #[generate_trait]
+            impl PairImpl of PairTrait {
+                fn sum(self: Pair, factor: u32) -> u32 {
+                    scale(self.first + self.second, factor)
+                }
+            }
+
+            fn main() {
+                let x = 5_u8;
+                let factor: u32 = x.into();
+                let (a, b) = (scale(1, factor), array::ArrayTrait::<felt252>::new());
+                let pair = Pair { first: a, second: 2 };
+                pair.sum(3);
+            }
+        "},
+    )]);
+    let file = fixture_file(&db, "lib.cairo");
+
+    let hints = |range| -> Vec<_> {
+        inlay_hints(&db, file, range)
+            .into_iter()
+            .map(|hint| {
+                let InlayHintLabel::String(label) = hint.label else { panic!("Unexpected label.") };
+                format!("{}:{} {label}", hint.position.line, hint.position.character)
+            })
+            .collect()
+    };
+    // Variables with a type, and arguments named like their parameters, have no hints. The hints
+    // in the code generated by plugins are shown in the code it was copied from.
+    assert_eq!(
+        hints(Range { start: Position::new(0, 0), end: Position::new(100, 0) }),
+        [
+            "13:14 x:",
+            "18:9 : core::integer::u8",
+            "20:10 : core::integer::u32",
+            "20:13 : core::array::Array::<core::felt252>",
+            "20:24 x:",
+            "21:12 : fixture::Pair",
+            "22:13 factor:",
+        ]
+    );
+    // Only the hints in the range are returned.
+    assert_eq!(
+        hints(Range { start: Position::new(20, 12), end: Position::new(20, 24) }),
+        ["20:13 : core::array::Array::<core::felt252>", "20:24 x:"]
+    );
+}
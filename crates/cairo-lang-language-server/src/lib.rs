@@ -3,12 +3,13 @@
 //! Implements the LSP protocol over stdin/out.
 
 use std::collections::{HashMap, HashSet};
+use std::iter::zip;
 use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::{bail, Error};
-use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::db::{Cancelled, RootDatabase};
 use cairo_lang_compiler::project::{setup_project, update_crate_roots_from_project_config};
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{
@@ -17,14 +18,14 @@ use cairo_lang_defs::ids::{
     LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId,
     TraitLongId, UseLongId,
 };
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity, ToOption};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::db::{
     init_dev_corelib, AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
 };
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileId, FileLongId};
-use cairo_lang_filesystem::span::{TextPosition, TextWidth};
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
 use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::diagnostic::LoweringDiagnostic;
@@ -37,8 +38,8 @@ use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_semantic::resolve::ResolvedGenericItem;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_starknet::plugin::StarkNetPlugin;
-use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_syntax::node::utils::is_grandparent_of_kind;
@@ -47,7 +48,7 @@ use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper, Upcast};
 use log::warn;
 use lsp::notification::Notification;
-use salsa::InternKey;
+use salsa::{InternKey, ParallelDatabase};
 use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
 use serde::{Deserialize, Serialize};
@@ -57,11 +58,29 @@ use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 use vfs::{ProvideVirtualFileRequest, ProvideVirtualFileResponse};
 
+use crate::code_actions::{code_actions, lowering_fixes, semantic_fixes, Fix};
 use crate::completions::dot_completions;
+use crate::inlay_hints::inlay_hints;
+use crate::references::{
+    find_references, is_identifier, is_renamable, origin_location, rename_locations, CodeLocation,
+};
 use crate::scarb_service::{is_scarb_manifest_path, ScarbService};
+use crate::signature_help::signature_help;
+use crate::symbols::{document_symbols, workspace_symbols};
+
+#[cfg(test)]
+#[path = "lib_test.rs"]
+mod test;
 
+mod code_actions;
+mod inlay_hints;
+mod references;
 mod scarb_service;
 mod semantic_highlighting;
+mod signature_help;
+mod symbols;
+#[cfg(test)]
+mod test_utils;
 
 pub mod completions;
 pub mod vfs;
@@ -84,6 +103,7 @@ pub async fn serve_language_service() {
 
     let (service, socket) = LspService::build(|client| Backend::new(client, db))
         .custom_method("vfs/provide", Backend::vfs_provide)
+        .custom_method("textDocument/inlayHint", Backend::inlay_hint)
         .finish();
     Server::new(stdin, stdout, socket).serve(service).await;
 }
@@ -122,7 +142,8 @@ impl NotificationService {
 }
 pub struct Backend {
     pub client: Client,
-    // TODO(spapini): Remove this once we support ParallelDatabase.
+    // The db mutex is only held to modify the database and to take snapshots of it, which requests
+    // run on.
     // State mutex should only be taken after db mutex is taken, to avoid deadlocks.
     pub db_mutex: tokio::sync::Mutex<RootDatabase>,
     pub state_mutex: tokio::sync::Mutex<State>,
@@ -144,16 +165,27 @@ impl Backend {
         }
     }
 
-    /// Runs a function with a database snapshot.
-    /// Catches panics and returns Err.
+    /// Runs a function with a database snapshot, on a blocking thread, so that requests run in
+    /// parallel.
+    /// Catches panics and returns Err. The function is cancelled, returning Err, when the database
+    /// is modified.
     async fn with_db<F, T>(&self, f: F) -> LSPResult<T>
     where
-        F: FnOnce(&RootDatabase) -> T + std::panic::UnwindSafe,
+        F: FnOnce(&RootDatabase) -> T + std::panic::UnwindSafe + Send + 'static,
+        T: Send + 'static,
     {
         let db_mut = self.db_mutex.lock().await;
-        let db = db_mut.snapshot();
+        let db = ParallelDatabase::snapshot(&*db_mut);
         drop(db_mut);
-        std::panic::catch_unwind(AssertUnwindSafe(|| f(&db))).map_err(|_| {
+        let res = tokio::task::spawn_blocking(move || {
+            std::panic::catch_unwind(AssertUnwindSafe(|| f(&db)))
+        })
+        .await
+        .map_err(|_| LSPError::internal_error())?;
+        res.map_err(|err| {
+            if err.is::<Cancelled>() {
+                return LSPError::content_modified();
+            }
             eprintln!("Caught panic in LSP worker thread.");
             LSPError::internal_error()
         })
@@ -173,7 +205,7 @@ impl Backend {
         let state = real_state.clone();
         drop(real_state);
         let (state, res) = self
-            .with_db(|db| {
+            .with_db(move |db| {
                 let mut state = state;
                 let mut res = vec![];
                 // Get all files. Try to go over open files first.
@@ -201,9 +233,24 @@ impl Backend {
                         }
                     }
                     let mut diags = Vec::new();
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.parser);
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.semantic);
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.lowering);
+                    get_diagnostics(
+                        db.upcast(),
+                        &mut diags,
+                        &new_file_diagnostics.parser,
+                        |_| vec![],
+                    );
+                    get_diagnostics(
+                        db.upcast(),
+                        &mut diags,
+                        &new_file_diagnostics.semantic,
+                        |diagnostic| semantic_fixes(db, diagnostic),
+                    );
+                    get_diagnostics(
+                        db.upcast(),
+                        &mut diags,
+                        &new_file_diagnostics.lowering,
+                        |diagnostic| lowering_fixes(db, diagnostic),
+                    );
                     state.file_diagnostics.insert(file_id, new_file_diagnostics);
 
                     res.push((uri, diags));
@@ -238,13 +285,23 @@ impl Backend {
         &self,
         params: ProvideVirtualFileRequest,
     ) -> LSPResult<ProvideVirtualFileResponse> {
-        self.with_db(|db| {
+        self.with_db(move |db| {
             let file_id = file(db, params.uri);
             ProvideVirtualFileResponse { content: db.file_content(file_id).map(|s| (*s).clone()) }
         })
         .await
     }
 
+    /// Handles `textDocument/inlayHint`, which is not a method of [LanguageServer] in this version
+    /// of tower-lsp.
+    pub async fn inlay_hint(&self, params: InlayHintParams) -> LSPResult<Option<Vec<InlayHint>>> {
+        self.with_db(move |db| {
+            let file = file(db, params.text_document.uri);
+            Some(inlay_hints(db, file, params.range))
+        })
+        .await
+    }
+
     /// Get corelib path fallback from the client configuration.
     ///
     /// The value is set by the user under the `cairo1.corelibPath` key in client configuration.
@@ -421,15 +478,17 @@ impl LanguageServer for Backend {
     async fn initialize(&self, _: InitializeParams) -> LSPResult<InitializeResult> {
         Ok(InitializeResult {
             server_info: None,
+            offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
                     trigger_characters: Some(vec![".".to_string()]),
                     work_done_progress_options: Default::default(),
                     all_commit_characters: None,
+                    completion_item: None,
                 }),
                 execute_command_provider: Some(ExecuteCommandOptions {
                     commands: vec!["cairo1.reload".to_string()],
@@ -456,6 +515,20 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -541,23 +614,26 @@ impl LanguageServer for Backend {
         }
 
         let file = file(&db, uri.clone());
+        // Changes are applied to the text of the document, which may differ from the file.
+        db.override_file_content(file, Some(Arc::new(params.text_document.text)));
         self.state_mutex.lock().await.open_files.insert(file);
         drop(db);
         self.refresh_diagnostics().await.ok();
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let text =
-            if let [TextDocumentContentChangeEvent { text, .. }] = &params.content_changes[..] {
-                text
-            } else {
-                eprintln!("Unexpected format of document change.");
-                return;
-            };
         let mut db = self.db_mut().await;
         let uri = params.text_document.uri;
         let file = file(&db, uri.clone());
-        db.override_file_content(file, Some(Arc::new(text.into())));
+        let Some(text) = db.file_content(file).map(|text| (*text).clone()) else {
+            eprintln!("Change failed. File '{uri}' does not exist.");
+            return;
+        };
+        let Some(text) = apply_content_changes(text, params.content_changes) else {
+            eprintln!("Change failed. Range out of bounds in file '{uri}'.");
+            return;
+        };
+        db.override_file_content(file, Some(Arc::new(text)));
         drop(db);
         self.refresh_diagnostics().await.ok();
     }
@@ -579,7 +655,7 @@ impl LanguageServer for Backend {
     }
 
     async fn completion(&self, params: CompletionParams) -> LSPResult<Option<CompletionResponse>> {
-        self.with_db(|db| {
+        self.with_db(move |db| {
             let text_document_position = params.text_document_position;
             let file_uri = text_document_position.text_document.uri;
             eprintln!("Complete {file_uri}");
@@ -602,7 +678,7 @@ impl LanguageServer for Backend {
         &self,
         params: SemanticTokensParams,
     ) -> LSPResult<Option<SemanticTokensResult>> {
-        self.with_db(|db| {
+        self.with_db(move |db| {
             let file_uri = params.text_document.uri;
             let file = file(db, file_uri.clone());
             let syntax = if let Ok(syntax) = db.file_syntax(file) {
@@ -624,7 +700,7 @@ impl LanguageServer for Backend {
         &self,
         params: DocumentFormattingParams,
     ) -> LSPResult<Option<Vec<TextEdit>>> {
-        self.with_db(|db| {
+        self.with_db(move |db| {
             let file_uri = params.text_document.uri;
             let file = file(db, file_uri.clone());
             let syntax = if let Ok(syntax) = db.file_syntax(file) {
@@ -668,7 +744,7 @@ impl LanguageServer for Backend {
     }
 
     async fn hover(&self, params: HoverParams) -> LSPResult<Option<Hover>> {
-        self.with_db(|db| {
+        self.with_db(move |db| {
             let file_uri = params.text_document_position_params.text_document.uri;
             eprintln!("Hover {file_uri}");
             let file = file(db, file_uri);
@@ -708,110 +784,114 @@ impl LanguageServer for Backend {
         &self,
         params: GotoDefinitionParams,
     ) -> LSPResult<Option<GotoDefinitionResponse>> {
-        self.with_db(|db| {
-            let syntax_db = db.upcast();
+        self.with_db(move |db| {
             let file_uri = params.text_document_position_params.text_document.uri;
-            let file = file(db, file_uri.clone());
+            let file = file(db, file_uri);
             let position = params.text_document_position_params.position;
-            let Some((node, lookup_items)) = get_node_and_lookup_items(db, file, position) else {return None};
-            for lookup_item_id in lookup_items {
-                if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
-                    continue;
-                }
-                let identifier =
-                    ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
-                let Some(item) = db.lookup_resolved_generic_item_by_ptr(
-                    lookup_item_id, identifier.stable_ptr())
-                else { continue; };
-
-                let defs_db = db.upcast();
-                let (module_id, file_index, stable_ptr) = match item {
-                    ResolvedGenericItem::Constant(item) => (
-                        item.parent_module(defs_db),
-                        item.file_index(defs_db),
-                        item.untyped_stable_ptr(defs_db),
-                    ),
-                    ResolvedGenericItem::Module(item) => {
-                        (item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
-                    }
-                    ResolvedGenericItem::GenericFunction(item) => {
-                        let title = match item {
-                            GenericFunctionId::Free(id) => FunctionTitleId::Free(id),
-                            GenericFunctionId::Extern(id) => FunctionTitleId::Extern(id),
-                            GenericFunctionId::Impl(id) => {
-                                // Note: Only the trait title is returned.
-                                FunctionTitleId::Trait(id.function)
-                            }
-                        };
-                        (
-                            title.parent_module(defs_db),
-                            title.file_index(defs_db),
-                            title.untyped_stable_ptr(defs_db),
-                        )
-                    }
-                    ResolvedGenericItem::GenericType(generic_type) => (
-                        generic_type.parent_module(defs_db),
-                        generic_type.file_index(defs_db),
-                        generic_type.untyped_stable_ptr(defs_db),
-                    ),
-                    ResolvedGenericItem::GenericTypeAlias(type_alias) => (
-                        type_alias.parent_module(defs_db),
-                        type_alias.file_index(defs_db),
-                        type_alias.untyped_stable_ptr(defs_db),
-                    ),
-                    ResolvedGenericItem::GenericImplAlias(impl_alias) => (
-                        impl_alias.parent_module(defs_db),
-                        impl_alias.file_index(defs_db),
-                        impl_alias.untyped_stable_ptr(defs_db),
-                    ),
-                    ResolvedGenericItem::Variant(variant) => (
-                        variant.id.parent_module(defs_db),
-                        variant.id.file_index(defs_db),
-                        variant.id.stable_ptr(defs_db).untyped(),
-                    ),
-                    ResolvedGenericItem::Trait(trt) => (
-                        trt.parent_module(defs_db),
-                        trt.file_index(defs_db),
-                        trt.stable_ptr(defs_db).untyped(),
-                    ),
-                    ResolvedGenericItem::Impl(imp) => (
-                        imp.parent_module(defs_db),
-                        imp.file_index(defs_db),
-                        imp.stable_ptr(defs_db).untyped(),
-                    ),
-                    ResolvedGenericItem::TraitFunction(trait_function) => (
-                        trait_function.parent_module(defs_db),
-                        trait_function.file_index(defs_db),
-                        trait_function.stable_ptr(defs_db).untyped(),
-                    ),
-                };
-
-                let file = if let Ok(files) = db.module_files(module_id) {
-                    files[file_index.0]
-                } else {
-                    return None;
-                };
+            let (file, span) = find_definition(db, file, position)?;
+            let start = from_pos(span.start.position_in_file(db.upcast(), file).unwrap());
+            let end = from_pos(span.end.position_in_file(db.upcast(), file).unwrap());
+            Some(GotoDefinitionResponse::Scalar(Location {
+                uri: get_uri(db, file),
+                range: Range { start, end },
+            }))
+        }).await
+    }
 
-                let uri = get_uri(db, file);
-                let syntax = if let Ok(syntax) = db.file_syntax(file) {
-                    syntax
-                } else {
-                    eprintln!("Formatting failed. File '{file_uri}' does not exist.");
-                    return None;
-                };
-                let node = syntax.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
-                let span = node.span_without_trivia(syntax_db);
+    async fn references(&self, params: ReferenceParams) -> LSPResult<Option<Vec<Location>>> {
+        self.with_db(move |db| {
+            let file_uri = params.text_document_position.text_document.uri;
+            eprintln!("References {file_uri}");
+            let file = file(db, file_uri);
+            let position = params.text_document_position.position;
+            let references = find_references(db, file, position)?;
+            Some(
+                references
+                    .locations
+                    .into_iter()
+                    .filter(|location| {
+                        params.context.include_declaration || *location != references.definition
+                    })
+                    .map(|location| get_location(db, location))
+                    .collect(),
+            )
+        })
+        .await
+    }
 
-                let start = from_pos(span.start.position_in_file(db.upcast(), file).unwrap());
-                let end = from_pos(span.end.position_in_file(db.upcast(), file).unwrap());
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LSPResult<Option<PrepareRenameResponse>> {
+        self.with_db(move |db| {
+            let file = file(db, params.text_document.uri);
+            let references = find_references(db, file, params.position)?;
+            if !is_renamable(db, references.definition) {
+                return None;
+            }
+            Some(PrepareRenameResponse::Range(get_location(db, references.identifier).range))
+        })
+        .await
+    }
 
-                return Some(GotoDefinitionResponse::Scalar(Location {
-                    uri,
-                    range: Range { start, end },
-                }));
+    async fn rename(&self, params: RenameParams) -> LSPResult<Option<WorkspaceEdit>> {
+        let new_name = params.new_name;
+        if !is_identifier(&new_name) {
+            return Err(LSPError::invalid_params(format!("`{new_name}` is not an identifier.")));
+        }
+        self.with_db(move |db| {
+            let file_uri = params.text_document_position.text_document.uri;
+            eprintln!("Rename {file_uri}");
+            let file = file(db, file_uri);
+            let position = params.text_document_position.position;
+            let Some(locations) = rename_locations(db, file, position) else {
+                eprintln!("Rename failed. The symbol at the position cannot be renamed.");
+                return None;
+            };
+            let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+            for location in locations {
+                let Location { uri, range } = get_location(db, location);
+                changes
+                    .entry(uri)
+                    .or_default()
+                    .push(TextEdit { range, new_text: new_name.clone() });
             }
-            None
-        }).await
+            Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() })
+        })
+        .await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LSPResult<Option<DocumentSymbolResponse>> {
+        self.with_db(move |db| {
+            let file = file(db, params.text_document.uri);
+            Some(DocumentSymbolResponse::Nested(document_symbols(db, file)))
+        })
+        .await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LSPResult<Option<Vec<SymbolInformation>>> {
+        self.with_db(move |db| Some(workspace_symbols(db, &params.query))).await
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> LSPResult<Option<CodeActionResponse>> {
+        Ok(Some(code_actions(params.context.diagnostics)))
+    }
+
+    async fn signature_help(
+        &self,
+        params: SignatureHelpParams,
+    ) -> LSPResult<Option<SignatureHelp>> {
+        self.with_db(move |db| {
+            let file = file(db, params.text_document_position_params.text_document.uri);
+            signature_help(db, file, params.text_document_position_params.position)
+        })
+        .await
     }
 }
 
@@ -899,7 +979,67 @@ fn get_node_and_lookup_items(
     file: FileId,
     position: Position,
 ) -> Option<(SyntaxNode, Vec<LookupItemId>)> {
-    let mut res = Vec::new();
+    let node = get_node(db, file, position)?;
+    let lookup_items = get_lookup_items(db, file, node.clone())?;
+    // The item may have been replaced by code generated from it, e.g. by the expansion of the
+    // inline macros in it. The node is then looked up in its copy in the generated code.
+    if lookup_items.last().is_some_and(|item| !is_existing_item(db, *item)) {
+        if let Some(generated) = get_generated_node_and_lookup_items(db, file, node.clone()) {
+            return Some(generated);
+        }
+    }
+    Some((node, lookup_items))
+}
+
+/// Returns false if the given lookup item is a module item that is not one of the items of its
+/// module, e.g. because a plugin removed it.
+fn is_existing_item(db: &(dyn SemanticGroup + 'static), item: LookupItemId) -> bool {
+    let LookupItemId::ModuleItem(module_item) = item else {
+        return true;
+    };
+    db.module_items(module_item.parent_module(db.upcast()))
+        .is_ok_and(|module_items| module_items.contains(&module_item))
+}
+
+/// Given a syntax node of a file, returns the node copied from it as is to a file generated by a
+/// plugin, and all the lookup items above the copied node.
+fn get_generated_node_and_lookup_items(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<(SyntaxNode, Vec<LookupItemId>)> {
+    let syntax_db = db.upcast();
+    let module_id = find_node_module(db, file, node.clone())?;
+    let location =
+        CodeLocation { file, span: node.span_without_trivia(syntax_db), generated: false };
+    let generated_file_infos = db.module_generated_file_infos(module_id).ok()?;
+    for (generated_file, generated_file_info) in
+        zip(db.module_files(module_id).ok()?.iter(), generated_file_infos.iter())
+    {
+        let Some(generated_file_info) = generated_file_info else { continue; };
+        if db.module_file(generated_file_info.origin).ok() != Some(file) {
+            continue;
+        }
+        let Ok(syntax) = db.file_syntax(*generated_file) else { continue; };
+        let generated_node = syntax.as_syntax_node().descendants(syntax_db).find(|descendant| {
+            descendant.kind(syntax_db) == node.kind(syntax_db)
+                && origin_location(db, *generated_file, descendant.span_without_trivia(syntax_db))
+                    == Some(location)
+        });
+        if let Some(generated_node) = generated_node {
+            let lookup_items = get_lookup_items(db, *generated_file, generated_node.clone())?;
+            return Some((generated_node, lookup_items));
+        }
+    }
+    None
+}
+
+/// Given a position in a file, return the syntax node for the token at that position.
+fn get_node(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<SyntaxNode> {
     let syntax_db = db.upcast();
     let filename = file.file_name(db.upcast());
 
@@ -907,6 +1047,17 @@ fn get_node_and_lookup_items(
     let syntax = db.file_syntax(file).to_option().on_none(|| {
         eprintln!("Formatting failed. File '{filename}' does not exist.");
     })?;
+    let offset = get_offset(db, file, position)?;
+    Some(syntax.as_syntax_node().lookup_offset(syntax_db, offset))
+}
+
+/// Given a position in a file, return its offset.
+fn get_offset(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<TextOffset> {
+    let filename = file.file_name(db.upcast());
 
     // Get file summary and content.
     let file_summary = db.file_summary(file).on_none(|| {
@@ -920,24 +1071,78 @@ fn get_node_and_lookup_items(
     let mut offset = *file_summary.line_offsets.get(position.line as usize).on_none(|| {
         eprintln!("Hover failed. Position out of bounds.");
     })?;
+    // The character of the position is counted in UTF-16 code units.
     let mut chars_it = offset.take_from(&content).chars();
-    for _ in 0..position.character {
+    let mut utf16_column = 0;
+    while utf16_column < position.character as usize {
         let c = chars_it.next().on_none(|| {
             eprintln!("Position does not exist.");
         })?;
         offset = offset.add_width(TextWidth::from_char(c));
+        utf16_column += c.len_utf16();
+    }
+    Some(offset)
+}
+
+/// Given a position in a text, return its byte offset. The character of the position is counted in
+/// UTF-16 code units, the position encoding of the protocol. Characters past the end of the line
+/// are clamped to it, as required by the protocol. The end of a line excludes its line ending,
+/// either `\n` or `\r\n`.
+fn get_text_offset(text: &str, position: Position) -> Option<usize> {
+    let line_start = match position.line {
+        0 => 0,
+        line => text.match_indices('\n').nth(line as usize - 1)?.0 + 1,
+    };
+    let line = text[line_start..].split('\n').next().unwrap_or_default().trim_end_matches('\r');
+    let column = line
+        .char_indices()
+        .scan(0, |utf16_column, (column, c)| {
+            let char_utf16_column = *utf16_column;
+            *utf16_column += c.len_utf16();
+            Some((column, char_utf16_column))
+        })
+        .find(|(_, utf16_column)| *utf16_column >= position.character as usize)
+        .map_or(line.len(), |(column, _)| column);
+    Some(line_start + column)
+}
+
+/// Applies the changes of a `didChange` notification to a text, in order. Returns None if the range
+/// of a change is out of bounds.
+fn apply_content_changes(
+    mut text: String,
+    changes: Vec<TextDocumentContentChangeEvent>,
+) -> Option<String> {
+    for change in changes {
+        let Some(range) = change.range else {
+            text = change.text;
+            continue;
+        };
+        let (start, end) = get_text_offset(&text, range.start)
+            .zip(get_text_offset(&text, range.end))
+            .filter(|(start, end)| start <= end)?;
+        text.replace_range(start..end, &change.text);
     }
-    let node = syntax.as_syntax_node().lookup_offset(syntax_db, offset);
+    Some(text)
+}
+
+/// Returns all the lookup items above a syntax node of a file.
+fn get_lookup_items(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<Vec<LookupItemId>> {
+    let mut res = Vec::new();
 
     // Find module.
     let module_id = find_node_module(db, file, node.clone()).on_none(|| {
         eprintln!("Hover failed. Failed to find module.");
     })?;
-    let file_index = FileIndex(0);
+    // The file is not one of the files of the module if its code was replaced by a plugin.
+    let file_index = FileIndex(db.module_files(module_id).ok()?.iter().position(|f| *f == file)?);
     let module_file_id = ModuleFileId(module_id, file_index);
 
     // Find containing function.
-    let mut item_node = node.clone();
+    let mut item_node = node;
     loop {
         if let Some(item) = lookup_item_from_ast(db, module_file_id, item_node.clone()) {
             res.push(item);
@@ -946,11 +1151,101 @@ fn get_node_and_lookup_items(
             Some(next_node) => {
                 item_node = next_node;
             }
-            None => return Some((node, res)),
+            None => return Some(res),
         }
     }
 }
 
+/// Returns the module file and the stable pointer of the definition of a resolved item.
+fn resolved_generic_item_location(
+    db: &(dyn SemanticGroup + 'static),
+    item: ResolvedGenericItem,
+) -> (ModuleFileId, SyntaxStablePtrId) {
+    let defs_db = db.upcast();
+    let (module_id, file_index, stable_ptr) = match item {
+        ResolvedGenericItem::Constant(item) => (
+            item.parent_module(defs_db),
+            item.file_index(defs_db),
+            item.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::Module(item) => {
+            (item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
+        }
+        ResolvedGenericItem::GenericFunction(item) => {
+            let title = match item {
+                GenericFunctionId::Free(id) => FunctionTitleId::Free(id),
+                GenericFunctionId::Extern(id) => FunctionTitleId::Extern(id),
+                GenericFunctionId::Impl(id) => {
+                    // Note: Only the trait title is returned.
+                    FunctionTitleId::Trait(id.function)
+                }
+            };
+            (
+                title.parent_module(defs_db),
+                title.file_index(defs_db),
+                title.untyped_stable_ptr(defs_db),
+            )
+        }
+        ResolvedGenericItem::GenericType(generic_type) => (
+            generic_type.parent_module(defs_db),
+            generic_type.file_index(defs_db),
+            generic_type.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::GenericTypeAlias(type_alias) => (
+            type_alias.parent_module(defs_db),
+            type_alias.file_index(defs_db),
+            type_alias.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::GenericImplAlias(impl_alias) => (
+            impl_alias.parent_module(defs_db),
+            impl_alias.file_index(defs_db),
+            impl_alias.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::Variant(variant) => (
+            variant.id.parent_module(defs_db),
+            variant.id.file_index(defs_db),
+            variant.id.stable_ptr(defs_db).untyped(),
+        ),
+        ResolvedGenericItem::Trait(trt) => {
+            (trt.parent_module(defs_db), trt.file_index(defs_db), trt.stable_ptr(defs_db).untyped())
+        }
+        ResolvedGenericItem::Impl(imp) => {
+            (imp.parent_module(defs_db), imp.file_index(defs_db), imp.stable_ptr(defs_db).untyped())
+        }
+        ResolvedGenericItem::TraitFunction(trait_function) => (
+            trait_function.parent_module(defs_db),
+            trait_function.file_index(defs_db),
+            trait_function.stable_ptr(defs_db).untyped(),
+        ),
+    };
+    (ModuleFileId(module_id, file_index), stable_ptr)
+}
+
+/// Given a position in a file, returns the file and the span of the definition of the identifier
+/// at that position.
+fn find_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<(FileId, TextSpan)> {
+    let syntax_db = db.upcast();
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
+    let item = lookup_items.into_iter().find_map(|lookup_item_id| {
+        db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+    })?;
+    let (module_file_id, stable_ptr) = resolved_generic_item_location(db, item);
+    let file = db.module_file(module_file_id).ok()?;
+    let syntax = db.file_syntax(file).to_option().on_none(|| {
+        eprintln!("Goto definition failed. File '{}' does not exist.", file.file_name(db.upcast()));
+    })?;
+    let node = syntax.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
+    Some((file, node.span_without_trivia(syntax_db)))
+}
+
 fn find_node_module(
     db: &(dyn SemanticGroup + 'static),
     main_file: FileId,
@@ -1059,7 +1354,7 @@ fn file(db: &RootDatabase, uri: Url) -> FileId {
 }
 
 /// Gets the canonical URI for a file.
-fn get_uri(db: &RootDatabase, file_id: FileId) -> Url {
+fn get_uri(db: &(dyn SemanticGroup + 'static), file_id: FileId) -> Url {
     let virtual_file = match db.lookup_intern_file(file_id) {
         FileLongId::OnDisk(path) => return Url::from_file_path(path).unwrap(),
         FileLongId::Virtual(virtual_file) => virtual_file,
@@ -1071,11 +1366,21 @@ fn get_uri(db: &RootDatabase, file_id: FileId) -> Url {
     uri
 }
 
-/// Converts internal format diagnostics to LSP format.
+/// Converts a location in the code to LSP format.
+fn get_location(db: &(dyn SemanticGroup + 'static), location: CodeLocation) -> Location {
+    let CodeLocation { file, span, .. } = location;
+    let start = from_pos(span.start.position_in_file(db.upcast(), file).unwrap());
+    let end = from_pos(span.end.position_in_file(db.upcast(), file).unwrap());
+    Location { uri: get_uri(db, file), range: Range { start, end } }
+}
+
+/// Converts internal format diagnostics to LSP format, with the fixes of each diagnostic as its
+/// data.
 fn get_diagnostics<T: DiagnosticEntry>(
     db: &T::DbType,
     diags: &mut Vec<Diagnostic>,
     diagnostics: &Diagnostics<T>,
+    fixes: impl Fn(&T) -> Vec<Fix>,
 ) {
     for diagnostic in diagnostics.get_all() {
         let location = diagnostic.location(db);
@@ -1083,7 +1388,19 @@ fn get_diagnostics<T: DiagnosticEntry>(
         let start =
             from_pos(location.span.start.position_in_file(db.upcast(), location.file_id).unwrap());
         let end =
-            from_pos(location.span.start.position_in_file(db.upcast(), location.file_id).unwrap());
-        diags.push(Diagnostic { range: Range { start, end }, message, ..Diagnostic::default() });
+            from_pos(location.span.end.position_in_file(db.upcast(), location.file_id).unwrap());
+        let severity = match diagnostic.severity() {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Note => DiagnosticSeverity::INFORMATION,
+        };
+        let fixes = fixes(&diagnostic);
+        diags.push(Diagnostic {
+            range: Range { start, end },
+            message,
+            severity: Some(severity),
+            data: if fixes.is_empty() { None } else { serde_json::to_value(fixes).ok() },
+            ..Diagnostic::default()
+        });
     }
 }
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::{Cancelled, RootDatabase};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_parser::db::ParserGroup;
+use indoc::indoc;
+use lsp::{
+    DidChangeTextDocumentParams, Position, Range, TextDocumentContentChangeEvent, Url,
+    VersionedTextDocumentIdentifier,
+};
+use pretty_assertions::assert_eq;
+use salsa::Database;
+use test_case::test_case;
+use tower_lsp::jsonrpc::ErrorCode;
+use tower_lsp::{LanguageServer, LspService};
+
+use super::{apply_content_changes, find_definition, get_text_offset, Backend};
+use crate::test_utils::{fixture_file, format_span, position_of, setup_fixture};
+
+/// A text with a 2-byte character (`é`, 1 UTF-16 code unit) and a 4-byte character (`😀`, 2 UTF-16
+/// code units - a surrogate pair).
+const TEXT: &str = "let a = 'é';\nlet b = '😀';\nb";
+
+#[test_case(0, 0, Some(0); "start")]
+#[test_case(0, 9, Some(9); "before a 2-byte character")]
+#[test_case(0, 10, Some(11); "after a 2-byte character")]
+#[test_case(1, 9, Some(23); "before a surrogate pair")]
+#[test_case(1, 11, Some(27); "after a surrogate pair")]
+#[test_case(0, 100, Some(13); "past the end of a line")]
+#[test_case(2, 1, Some(31); "end of the text")]
+#[test_case(2, 100, Some(31); "past the end of the last line")]
+#[test_case(3, 0, None; "past the last line")]
+fn test_get_text_offset(line: u32, character: u32, expected: Option<usize>) {
+    assert_eq!(get_text_offset(TEXT, Position { line, character }), expected);
+}
+
+#[test_case(0, 6, Some(6); "end of a line")]
+#[test_case(0, 100, Some(6); "past the end of a line")]
+#[test_case(1, 0, Some(8); "start of a line")]
+#[test_case(1, 100, Some(9); "past the end of the last line")]
+fn test_get_text_offset_crlf(line: u32, character: u32, expected: Option<usize>) {
+    // A position past the end of a line is clamped before its `\r\n` line ending.
+    assert_eq!(get_text_offset("let a;\r\nb", Position { line, character }), expected);
+}
+
+/// Returns an incremental change replacing the given range with `text`.
+fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent {
+        range: Some(Range {
+            start: Position { line: start.0, character: start.1 },
+            end: Position { line: end.0, character: end.1 },
+        }),
+        range_length: None,
+        text: text.into(),
+    }
+}
+
+#[test]
+fn test_incremental_changes() {
+    // Each change applies to the text resulting from the previous changes.
+    let changes = vec![
+        change((1, 9), (1, 11), "x"),
+        change((0, 4), (0, 5), "alpha"),
+        change((2, 0), (2, 0), "a + "),
+        change((1, 12), (1, 12), "\nlet c = 0;"),
+    ];
+    assert_eq!(
+        apply_content_changes(TEXT.into(), changes).unwrap(),
+        "let alpha = 'é';\nlet b = 'x';\nlet c = 0;\na + b"
+    );
+}
+
+#[test]
+fn test_full_text_change() {
+    // A change without a range replaces the whole text, and the changes after it apply to the new
+    // text.
+    let changes = vec![
+        change((0, 0), (0, 3), "const"),
+        TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "fn a() {}".into(),
+        },
+        change((0, 3), (0, 4), "b"),
+    ];
+    assert_eq!(apply_content_changes(TEXT.into(), changes).unwrap(), "fn b() {}");
+}
+
+#[test]
+fn test_invalid_changes() {
+    assert_eq!(apply_content_changes(TEXT.into(), vec![change((5, 0), (5, 1), "x")]), None);
+    assert_eq!(apply_content_changes(TEXT.into(), vec![change((1, 2), (0, 2), "x")]), None);
+}
+
+#[tokio::test]
+async fn test_with_db_errors() {
+    let mut client = None;
+    let _service = LspService::new(|service_client| {
+        client = Some(service_client.clone());
+        Backend::new(service_client, RootDatabase::default())
+    });
+    let backend = Backend::new(client.unwrap(), RootDatabase::default());
+
+    assert_eq!(backend.with_db(|_| 1).await.unwrap(), 1);
+    // A cancelled query means that the content was modified while handling the request.
+    let err = backend.with_db(|_| -> usize { Cancelled::throw() }).await.unwrap_err();
+    assert_eq!(err.code, ErrorCode::ContentModified);
+    let err = backend.with_db(|_| -> usize { panic!("A bug.") }).await.unwrap_err();
+    assert_eq!(err.code, ErrorCode::InternalError);
+}
+
+#[tokio::test]
+async fn test_with_db_cancelled_by_change() {
+    let mut db = RootDatabase::default();
+    let crate_id = db.intern_crate(CrateLongId("fixture".into()));
+    db.set_crate_root(crate_id, Some(Directory("/fixture/src".into())));
+    let file = fixture_file(&db, "lib.cairo");
+    db.override_file_content(file, Some(Arc::new("fn foo() {}".into())));
+    let mut client = None;
+    let _service = LspService::new(|service_client| {
+        client = Some(service_client.clone());
+        Backend::new(service_client, RootDatabase::default())
+    });
+    let backend = Backend::new(client.unwrap(), db);
+
+    // The query waits for the change to start modifying the database, and then computes a value
+    // missing from its snapshot, which is cancelled since it would be outdated.
+    let query = backend.with_db(move |db| {
+        while !db.salsa_runtime().is_current_revision_canceled() {
+            std::thread::yield_now();
+        }
+        db.file_syntax_diagnostics(file).len()
+    });
+    let change = backend.did_change(DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier {
+            uri: Url::from_file_path("/fixture/src/lib.cairo").unwrap(),
+            version: 1,
+        },
+        content_changes: vec![change((0, 3), (0, 6), "bar")],
+    });
+    let (result, ()) = tokio::join!(query, change);
+    assert_eq!(result.unwrap_err().code, ErrorCode::ContentModified);
+    assert_eq!(backend.db_mut().await.file_content(file).unwrap().as_str(), "fn bar() {}");
+}
+
+#[test]
+fn test_goto_definition_in_function_with_inline_macros() {
+    let db = setup_fixture(&[(
+        "lib.cairo",
+        indoc! {"
+            fn double(x: felt252) -> felt252 {
+                x * 2
+            }
+
+            fn foo(x: felt252) -> Array<felt252> {
+                let y = double(x);
+                array![double(y), y]
+            }
+        "},
+    )]);
+    let file = fixture_file(&db, "lib.cairo");
+
+    // The function is replaced by the expansion of its inline macros, so the identifiers are looked
+    // up in the expanded code, both outside of the macros and in their arguments.
+    for pattern in ["double(x)", "double(y)"] {
+        let (definition_file, span) =
+            find_definition(&db, file, position_of(&db, file, pattern)).unwrap();
+        assert_eq!(format_span(&db, definition_file, span), "lib.cairo:0:0-2:1");
+    }
+}
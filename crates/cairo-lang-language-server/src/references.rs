@@ -0,0 +1,356 @@
+use cairo_lang_defs::ids::{LanguageElementId, LookupItemId, ModuleId};
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::plugin::DynPluginAuxData;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::Expr;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use lsp::Position;
+
+use crate::{get_lookup_items, get_node, nearest_semantic_expr, resolved_generic_item_location};
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
+/// A span of code in a file. Code generated by plugins is located by the code it was copied from.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CodeLocation {
+    pub file: FileId,
+    pub span: TextSpan,
+    /// Whether the location is of code that a plugin generated the code in place of, rather than
+    /// of code the plugin copied - e.g. the call of an inline macro, or the item of a plugin.
+    pub generated: bool,
+}
+
+/// The references to a symbol.
+pub struct References {
+    /// The identifier the symbol was looked up by.
+    pub identifier: CodeLocation,
+    /// The name of the definition of the symbol.
+    pub definition: CodeLocation,
+    /// The identifiers referring to the symbol, including the name of its definition.
+    pub locations: Vec<CodeLocation>,
+}
+
+/// An identifier, and the definition it refers to.
+struct Occurrence {
+    location: CodeLocation,
+    definition: CodeLocation,
+}
+
+/// Finds the references to the symbol of the identifier at the given position, in all the crates.
+pub fn find_references(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<References> {
+    let syntax_db = db.upcast();
+    let node = get_node(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let identifier = origin_location(db, file, node.span_without_trivia(syntax_db))?;
+    let occurrences = find_occurrences(db, &node.get_text_without_trivia(syntax_db));
+    // The identifier may be found in a few files, e.g. when it is copied to code generated by a
+    // plugin, so its definition is taken from any of them.
+    let definition =
+        occurrences.iter().find(|occurrence| occurrence.location == identifier)?.definition;
+    let locations: OrderedHashSet<_> = occurrences
+        .into_iter()
+        .filter(|occurrence| occurrence.definition == definition)
+        .map(|occurrence| occurrence.location)
+        .collect();
+    Some(References { identifier, definition, locations: locations.into_iter().collect() })
+}
+
+/// Returns whether a symbol, given by the name of its definition, can be renamed - i.e. it is not
+/// defined in the core library, nor generated by a plugin.
+pub fn is_renamable(db: &(dyn SemanticGroup + 'static), definition: CodeLocation) -> bool {
+    if definition.generated {
+        return false;
+    }
+    let Ok(modules) = db.file_modules(definition.file) else { return false; };
+    modules.first().is_some_and(|module_id| module_id.owning_crate(db.upcast()) != db.core_crate())
+}
+
+/// Returns the identifiers to replace for renaming the symbol of the identifier at the given
+/// position, or `None` if it can not be renamed. References in code generated by plugins are
+/// renamed by renaming the code they were generated from.
+pub fn rename_locations(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<Vec<CodeLocation>> {
+    let references = find_references(db, file, position)?;
+    if !is_renamable(db, references.definition) {
+        return None;
+    }
+    Some(references.locations.into_iter().filter(|location| !location.generated).collect())
+}
+
+/// Returns whether a name is a valid identifier.
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Finds the identifiers of the given name in the files of all the crates, with the definitions
+/// they refer to.
+fn find_occurrences(db: &(dyn SemanticGroup + 'static), name: &str) -> Vec<Occurrence> {
+    let syntax_db = db.upcast();
+    let mut files = OrderedHashSet::<FileId>::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            files.extend(db.module_files(*module_id).unwrap_or_default());
+        }
+    }
+    let mut occurrences = vec![];
+    for file in files {
+        let Ok(syntax) = db.file_syntax(file) else { continue; };
+        for node in syntax.as_syntax_node().descendants(syntax_db) {
+            if node.kind(syntax_db) != SyntaxKind::TokenIdentifier
+                || node.text(syntax_db).as_deref() != Some(name)
+            {
+                continue;
+            }
+            let Some(location) = call_site_location(db, file, node.span_without_trivia(syntax_db))
+            else {
+                continue;
+            };
+            let Some(definition) = identifier_definition(db, file, node) else { continue; };
+            occurrences.push(Occurrence { location, definition });
+        }
+    }
+    occurrences
+}
+
+/// Returns the name of the definition that an identifier token refers to. The name of a definition
+/// refers to itself, except for the name of an impl function, which refers to the function of the
+/// trait it implements.
+fn identifier_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<CodeLocation> {
+    let syntax_db = db.upcast();
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent()?);
+    let name = identifier.text(syntax_db);
+    let lookup_items = get_lookup_items(db, file, node.clone())?;
+    match identifier.as_syntax_node().parent()?.kind(syntax_db) {
+        SyntaxKind::FunctionDeclaration => {
+            if let Some(LookupItemId::ImplFunction(impl_function_id)) = lookup_items.first() {
+                if let Ok(trait_function_id) = db.impl_function_trait_function(*impl_function_id) {
+                    return element_name_location(db, trait_function_id);
+                }
+            }
+            return call_site_location(db, file, node.span_without_trivia(syntax_db));
+        }
+        SyntaxKind::ItemConstant
+        | SyntaxKind::ItemModule
+        | SyntaxKind::ItemStruct
+        | SyntaxKind::ItemEnum
+        | SyntaxKind::ItemTrait
+        | SyntaxKind::ItemImpl
+        | SyntaxKind::ItemTypeAlias
+        | SyntaxKind::ItemImplAlias
+        | SyntaxKind::ItemExternType
+        | SyntaxKind::Member
+        | SyntaxKind::Param
+        | SyntaxKind::PatternIdentifier => {
+            return call_site_location(db, file, node.span_without_trivia(syntax_db));
+        }
+        SyntaxKind::PathSegmentSimple if is_variable_pattern(syntax_db, &identifier) => {
+            return call_site_location(db, file, node.span_without_trivia(syntax_db));
+        }
+        _ => {}
+    }
+
+    // Items resolved by paths.
+    for lookup_item_id in &lookup_items {
+        if let Some(item) =
+            db.lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())
+        {
+            return match item {
+                ResolvedGenericItem::Module(ModuleId::Submodule(submodule_id)) => {
+                    element_name_location(db, submodule_id)
+                }
+                ResolvedGenericItem::Module(_) => None,
+                item => {
+                    let (module_file_id, stable_ptr) = resolved_generic_item_location(db, item);
+                    let file = db.module_file(module_file_id).ok()?;
+                    name_location(db, file, stable_ptr)
+                }
+            };
+        }
+    }
+
+    // Variables, members and methods, resolved by the semantic model of a function.
+    let function_id = lookup_items.first()?.function_with_body()?;
+    // The name of a member in a struct constructor is looked up by the constructor expression,
+    // rather than by the argument expression of the member.
+    let struct_arg = identifier
+        .as_syntax_node()
+        .parent()
+        .filter(|parent| parent.kind(syntax_db) == SyntaxKind::StructArgSingle);
+    let expr = nearest_semantic_expr(db, struct_arg.clone().unwrap_or(node), function_id)?;
+    match expr {
+        Expr::Var(expr) => element_name_location(db, expr.var),
+        Expr::MemberAccess(expr) if expr.member.name(db.upcast()) == name => {
+            element_name_location(db, expr.member)
+        }
+        Expr::StructCtor(expr) if struct_arg.is_some() => {
+            let members = db.struct_members(expr.concrete_struct_id.struct_id(db)).ok()?;
+            element_name_location(db, members.get(&name)?.id)
+        }
+        Expr::FunctionCall(expr) => match expr.function.get_concrete(db).generic_function {
+            GenericFunctionId::Free(id) if id.name(db.upcast()) == name => {
+                element_name_location(db, id)
+            }
+            GenericFunctionId::Extern(id) if id.name(db.upcast()) == name => {
+                element_name_location(db, id)
+            }
+            GenericFunctionId::Impl(id) if id.function.name(db.upcast()) == name => {
+                element_name_location(db, id.function)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether an identifier is a pattern binding a new variable. Such patterns are parsed as
+/// paths of a single segment.
+fn is_variable_pattern(db: &dyn SyntaxGroup, identifier: &ast::TerminalIdentifier) -> bool {
+    let Some(path) = identifier.as_syntax_node().parent().and_then(|segment| segment.parent())
+    else {
+        return false;
+    };
+    if path.kind(db) != SyntaxKind::ExprPath
+        || ast::ExprPath::from_syntax_node(db, path.clone()).elements(db).len() != 1
+    {
+        return false;
+    }
+    let Some(parent) = path.parent() else { return false; };
+    let pattern = match parent.kind(db) {
+        SyntaxKind::StatementLet => ast::StatementLet::from_syntax_node(db, parent).pattern(db),
+        SyntaxKind::MatchArm => ast::MatchArm::from_syntax_node(db, parent).pattern(db),
+        SyntaxKind::ExprFor => ast::ExprFor::from_syntax_node(db, parent).pattern(db),
+        SyntaxKind::PatternEnum => ast::PatternEnum::from_syntax_node(db, parent).pattern(db),
+        SyntaxKind::PatternStructParamWithExpr => {
+            ast::PatternStructParamWithExpr::from_syntax_node(db, parent).pattern(db)
+        }
+        SyntaxKind::PatternList => return true,
+        _ => return false,
+    };
+    pattern.as_syntax_node() == path
+}
+
+/// Returns the location of the name of a language element.
+fn element_name_location(
+    db: &(dyn SemanticGroup + 'static),
+    element_id: impl LanguageElementId,
+) -> Option<CodeLocation> {
+    let file = db.module_file(element_id.module_file_id(db.upcast())).ok()?;
+    name_location(db, file, element_id.untyped_stable_ptr(db.upcast()))
+}
+
+/// Returns the location of the name of the definition at the given pointer of a file.
+fn name_location(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<CodeLocation> {
+    let syntax_db = db.upcast();
+    let node = db.file_syntax(file).ok()?.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
+    let name = definition_name(syntax_db, node)?;
+    call_site_location(db, file, name.span_without_trivia(syntax_db))
+}
+
+/// Returns the name of a definition.
+pub fn definition_name(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<SyntaxNode> {
+    let name = match node.kind(db) {
+        SyntaxKind::TerminalIdentifier => return Some(node),
+        SyntaxKind::ItemConstant => ast::ItemConstant::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemModule => ast::ItemModule::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemStruct => ast::ItemStruct::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemEnum => ast::ItemEnum::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemTrait => ast::ItemTrait::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemImpl => ast::ItemImpl::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemTypeAlias => ast::ItemTypeAlias::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemImplAlias => ast::ItemImplAlias::from_syntax_node(db, node).name(db),
+        SyntaxKind::ItemExternType => ast::ItemExternType::from_syntax_node(db, node).name(db),
+        SyntaxKind::FunctionWithBody => {
+            ast::FunctionWithBody::from_syntax_node(db, node).declaration(db).name(db)
+        }
+        SyntaxKind::ItemExternFunction => {
+            ast::ItemExternFunction::from_syntax_node(db, node).declaration(db).name(db)
+        }
+        SyntaxKind::TraitItemFunction => {
+            ast::TraitItemFunction::from_syntax_node(db, node).declaration(db).name(db)
+        }
+        SyntaxKind::Member => ast::Member::from_syntax_node(db, node).name(db),
+        SyntaxKind::Param => ast::Param::from_syntax_node(db, node).name(db),
+        _ => return None,
+    };
+    Some(name.as_syntax_node())
+}
+
+/// Returns the location of a span of a file. Spans of files generated by plugins are mapped to the
+/// code they were copied from, or `None` if they were not copied as is.
+pub fn origin_location(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    span: TextSpan,
+) -> Option<CodeLocation> {
+    call_site_location(db, file, span).filter(|location| !location.generated)
+}
+
+/// Returns the location of a span of a file, like [origin_location]. Spans of code that plugins
+/// generated in place of other code are mapped to that code, e.g. to the call of an inline macro,
+/// or to the item the code was generated from if the plugin does not map them.
+fn call_site_location(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    span: TextSpan,
+) -> Option<CodeLocation> {
+    let mut location = CodeLocation { file, span, generated: false };
+    while let FileLongId::Virtual(_) = db.lookup_intern_file(location.file) {
+        let module_id = *db.file_modules(location.file).ok()?.first()?;
+        let file_index =
+            db.module_files(module_id).ok()?.iter().position(|file| *file == location.file)?;
+        let generated_file_info =
+            db.module_generated_file_infos(module_id).ok()?.get(file_index)?.clone()?;
+        let file = db.module_file(generated_file_info.origin).ok()?;
+        // Plugins without a mapping of their code, e.g. derive, generate it from the whole item.
+        let span = generated_file_info
+            .aux_data
+            .0
+            .as_any()
+            .downcast_ref::<DynPluginAuxData>()
+            .and_then(|aux_data| aux_data.map_span(db, location.span));
+        location = match span {
+            Some(span) => CodeLocation {
+                file,
+                span,
+                // Generated in place of the original code, rather than copied from it.
+                generated: location.generated
+                    || span.end - span.start != location.span.end - location.span.start,
+            },
+            None => CodeLocation {
+                file,
+                span: generated_file_info.origin_item_span,
+                generated: true,
+            },
+        };
+    }
+    Some(location)
+}
@@ -0,0 +1,161 @@
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::ids::FileId;
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+
+use super::{find_references, rename_locations, CodeLocation};
+use crate::test_utils::{fixture_file, format_span, position_of, setup_fixture};
+
+/// Sets up a crate of a few modules, using a struct of one of them through `use` items, and
+/// deriving implementations for it with a plugin.
+fn setup_shapes() -> RootDatabase {
+    setup_fixture(&[
+        (
+            "lib.cairo",
+            indoc! {"
+                mod shapes;
+                mod area;
+
+                use shapes::Square;
+
+                fn main() -> u32 {
+                    let square = Square { side: 3 };
+                    area::area(square)
+                }
+            "},
+        ),
+        (
+            "shapes.cairo",
+            indoc! {"
+                #[derive(Copy, Drop)]
+                pub struct Square {
+                    pub side: u32,
+                }
+
+                pub fn unit_square() -> Square {
+                    Square { side: 1 }
+                }
+            "},
+        ),
+        (
+            "area.cairo",
+            indoc! {"
+                use super::shapes::Square;
+
+                pub fn area(square: Square) -> u32 {
+                    square.side * square.side
+                }
+            "},
+        ),
+    ])
+}
+
+/// Formats locations in sorted order, marking the locations of code generated in place by plugins.
+fn format_locations(db: &RootDatabase, locations: Vec<CodeLocation>) -> Vec<String> {
+    locations
+        .into_iter()
+        .map(|location| {
+            let span = format_span(db, location.file, location.span);
+            if location.generated { format!("{span} (generated)") } else { span }
+        })
+        .sorted()
+        .collect()
+}
+
+/// Returns the file and the position of the first occurrence of `pattern` in a file of a fixture.
+fn find_references_at(
+    db: &RootDatabase,
+    path: &str,
+    pattern: &str,
+) -> (FileId, lsp::Position, Vec<String>) {
+    let file = fixture_file(db, path);
+    let position = position_of(db, file, pattern);
+    let references = find_references(db, file, position).unwrap();
+    (file, position, format_locations(db, references.locations))
+}
+
+#[test]
+fn test_find_references() {
+    let db = setup_shapes();
+    let (_, _, locations) = find_references_at(&db, "area.cairo", "Square) ->");
+    // The implementations derived for `Square` refer to it from the code they were generated from.
+    assert_eq!(
+        locations,
+        [
+            "area.cairo:0:19-0:25",
+            "area.cairo:2:20-2:26",
+            "lib.cairo:3:12-3:18",
+            "lib.cairo:6:17-6:23",
+            "shapes.cairo:0:0-3:1 (generated)",
+            "shapes.cairo:1:11-1:17",
+            "shapes.cairo:5:24-5:30",
+            "shapes.cairo:6:4-6:10",
+        ]
+    );
+}
+
+#[test]
+fn test_rename() {
+    let db = setup_shapes();
+    let (file, position, _) = find_references_at(&db, "area.cairo", "Square) ->");
+    // Every reference is renamed, including the `use` items, except for the derived code, which is
+    // generated again from the renamed struct.
+    let locations = format_locations(&db, rename_locations(&db, file, position).unwrap());
+    assert_eq!(
+        locations,
+        [
+            "area.cairo:0:19-0:25",
+            "area.cairo:2:20-2:26",
+            "lib.cairo:3:12-3:18",
+            "lib.cairo:6:17-6:23",
+            "shapes.cairo:1:11-1:17",
+            "shapes.cairo:5:24-5:30",
+            "shapes.cairo:6:4-6:10",
+        ]
+    );
+
+    // Renaming a function called through the path of its module.
+    let (file, position, _) = find_references_at(&db, "lib.cairo", "area(square)");
+    let locations = format_locations(&db, rename_locations(&db, file, position).unwrap());
+    assert_eq!(locations, ["area.cairo:2:7-2:11", "lib.cairo:7:10-7:14"]);
+}
+
+#[test]
+fn test_references_to_generated_items() {
+    let db = setup_fixture(&[(
+        "lib.cairo",
+        indoc! {"
+            #[starknet::interface]
+            trait ICounter<T> {
+                fn get(self: @T) -> u32;
+            }
+
+            fn get_count(contract_address: starknet::ContractAddress) -> u32 {
+                ICounterDispatcher { contract_address }.get()
+            }
+        "},
+    )]);
+    let file = fixture_file(&db, "lib.cairo");
+
+    // The dispatcher is defined by the code generated from the interface.
+    let position = position_of(&db, file, "ICounterDispatcher {");
+    let references = find_references(&db, file, position).unwrap();
+    assert_eq!(
+        format_locations(&db, vec![references.definition]),
+        ["lib.cairo:0:0-3:1 (generated)"]
+    );
+    assert_eq!(
+        format_locations(&db, references.locations),
+        ["lib.cairo:0:0-3:1 (generated)", "lib.cairo:6:4-6:22"]
+    );
+    // A generated item can only be renamed by the code it was generated from.
+    assert_eq!(rename_locations(&db, file, position), None);
+
+    // The method of the dispatcher is copied from the interface.
+    let position = position_of(&db, file, "get()");
+    assert_eq!(
+        format_locations(&db, rename_locations(&db, file, position).unwrap()),
+        ["lib.cairo:2:7-2:10", "lib.cairo:6:44-6:47"]
+    );
+}
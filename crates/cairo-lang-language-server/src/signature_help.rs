@@ -0,0 +1,197 @@
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::corelib::unit_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::{Expr, Mutability, Parameter, Signature};
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::{ParameterInformation, ParameterLabel, Position, SignatureHelp, SignatureInformation};
+use smol_str::SmolStr;
+
+use crate::{get_lookup_items, get_node, get_offset};
+
+#[cfg(test)]
+#[path = "signature_help_test.rs"]
+mod test;
+
+/// Returns the signature of the innermost function call whose arguments contain a position, with
+/// the parameter at the position active.
+pub fn signature_help(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let syntax_db = db.upcast();
+    let offset = get_offset(db, file, position)?;
+    let node = get_node(db, file, position)?;
+    let call = enclosing_call(db, node, offset)?;
+    let lookup_items = get_lookup_items(db, file, call.as_syntax_node())?;
+
+    // The receiver of a method call is its first argument.
+    let receiver = method_call(db, &call);
+    let mut active_parameter = call
+        .arguments(syntax_db)
+        .args(syntax_db)
+        .as_syntax_node()
+        .children(syntax_db)
+        .filter(|child| {
+            child.kind(syntax_db) == SyntaxKind::TerminalComma
+                && child.span_without_trivia(syntax_db).end <= offset
+        })
+        .count();
+    if receiver.is_some() {
+        active_parameter += 1;
+    }
+
+    let (name, signature) = lookup_items
+        .iter()
+        .find_map(|lookup_item| {
+            let function_id = lookup_item.function_with_body()?;
+            let expr = receiver.clone().unwrap_or_else(|| ast::Expr::FunctionCall(call.clone()));
+            called_function_signature(db, function_id, expr)
+        })
+        .or_else(|| {
+            // The call could not be inferred, e.g. since it is incomplete. Fall back to the
+            // signature of the called item, as declared.
+            if receiver.is_some() {
+                return None;
+            }
+            let segment = call.path(syntax_db).elements(syntax_db).pop()?;
+            let identifier = segment.identifier_ast(syntax_db);
+            lookup_items.iter().find_map(|lookup_item| {
+                declared_signature(
+                    db,
+                    db.lookup_resolved_generic_item_by_ptr(*lookup_item, identifier.stable_ptr())?,
+                )
+            })
+        })?;
+
+    Some(SignatureHelp {
+        signatures: vec![signature_information(db, &name, &signature)],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter as u32),
+    })
+}
+
+/// Returns the label of a parameter, as in a function declaration.
+pub fn parameter_label(db: &(dyn SemanticGroup + 'static), parameter: &Parameter) -> String {
+    let modifier = match parameter.mutability {
+        Mutability::Immutable => "",
+        Mutability::Mutable => "mut ",
+        Mutability::Reference => "ref ",
+    };
+    format!("{modifier}{}: {}", parameter.name, parameter.ty.format(db))
+}
+
+/// Returns the name of a function, without its path.
+pub fn function_name(db: &(dyn SemanticGroup + 'static), function: GenericFunctionId) -> SmolStr {
+    match function {
+        GenericFunctionId::Free(id) => id.name(db.upcast()),
+        GenericFunctionId::Extern(id) => id.name(db.upcast()),
+        GenericFunctionId::Impl(id) => id.function.name(db.upcast()),
+    }
+}
+
+/// Returns the innermost function call above a node, whose arguments contain an offset.
+fn enclosing_call(
+    db: &(dyn SemanticGroup + 'static),
+    mut node: SyntaxNode,
+    offset: TextOffset,
+) -> Option<ast::ExprFunctionCall> {
+    let syntax_db = db.upcast();
+    loop {
+        if node.kind(syntax_db) == SyntaxKind::ExprFunctionCall {
+            let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, node.clone());
+            let arguments = call.arguments(syntax_db);
+            if arguments.lparen(syntax_db).as_syntax_node().span(syntax_db).end <= offset
+                && offset <= arguments.rparen(syntax_db).as_syntax_node().span(syntax_db).start
+            {
+                return Some(call);
+            }
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Returns the whole method call expression, e.g. `x.foo(y)`, if a call is the method part of it.
+pub fn method_call(
+    db: &(dyn SemanticGroup + 'static),
+    call: &ast::ExprFunctionCall,
+) -> Option<ast::Expr> {
+    let syntax_db = db.upcast();
+    let parent = call.as_syntax_node().parent()?;
+    if parent.kind(syntax_db) != SyntaxKind::ExprBinary {
+        return None;
+    }
+    let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent);
+    if !matches!(binary.op(syntax_db), ast::BinaryOperator::Dot(_))
+        || binary.rhs(syntax_db).as_syntax_node() != call.as_syntax_node()
+    {
+        return None;
+    }
+    Some(ast::Expr::Binary(binary))
+}
+
+/// Returns the name and the concrete signature of the function called by an expression, with the
+/// generic arguments inferred.
+fn called_function_signature(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    expr: ast::Expr,
+) -> Option<(SmolStr, Signature)> {
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr.stable_ptr()).to_option()?;
+    let Expr::FunctionCall(call) = db.expr_semantic(function_id, expr_id) else { return None; };
+    let signature = db.concrete_function_signature(call.function).to_option()?;
+    Some((function_name(db, call.function.get_concrete(db).generic_function), signature))
+}
+
+/// Returns the name and the declared signature of a function.
+fn declared_signature(
+    db: &(dyn SemanticGroup + 'static),
+    item: ResolvedGenericItem,
+) -> Option<(SmolStr, Signature)> {
+    match item {
+        ResolvedGenericItem::GenericFunction(function) => {
+            Some((function_name(db, function), function.generic_signature(db).to_option()?))
+        }
+        ResolvedGenericItem::TraitFunction(function) => {
+            Some((function.name(db.upcast()), db.trait_function_signature(function).to_option()?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the LSP information of a signature, e.g. `fn into(self: u8) -> u16`.
+fn signature_information(
+    db: &(dyn SemanticGroup + 'static),
+    name: &str,
+    signature: &Signature,
+) -> SignatureInformation {
+    let parameters: Vec<_> =
+        signature.params.iter().map(|parameter| parameter_label(db, parameter)).collect();
+    let mut label = format!("fn {name}({})", parameters.join(", "));
+    if signature.return_type != unit_ty(db) {
+        label += &format!(" -> {}", signature.return_type.format(db));
+    }
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(
+            parameters
+                .into_iter()
+                .map(|parameter| ParameterInformation {
+                    label: ParameterLabel::Simple(parameter),
+                    documentation: None,
+                })
+                .collect(),
+        ),
+        active_parameter: None,
+    }
+}
@@ -0,0 +1,69 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::signature_help;
+use crate::test_utils::{fixture_file, position_of, setup_fixture};
+
+const CONTENT: &str = indoc! {"
+    use traits::Into;
+
+    fn scale(x: u32, factor: u32, ref total: u32) -> u32 {
+        x * factor
+    }
+
+    trait Shift<T> {
+        fn shift(self: T, by: T) -> T;
+    }
+
+    impl U64Shift of Shift<u64> {
+        fn shift(self: u64, by: u64) -> u64 {
+            self + by
+        }
+    }
+
+    fn main() {
+        let mut total = 0;
+        scale(1, scale(2, 3, ref total), ref total);
+        let x: u64 = 1;
+        x.shift(2);
+        let y: felt252 = 5_u8.into();
+    }
+"};
+
+/// The label of the signature of `scale`.
+const SCALE: &str = "fn scale(x: core::integer::u32, factor: core::integer::u32, ref total: \
+                     core::integer::u32) -> core::integer::u32";
+
+/// The label of the signature of `shift`, with the receiver as its first parameter.
+const SHIFT: &str =
+    "fn shift(self: core::integer::u64, by: core::integer::u64) -> core::integer::u64";
+
+/// The label of the signature of `into`, with the generic arguments of the trait inferred.
+const INTO: &str = "fn into(self: core::integer::u8) -> core::felt252";
+
+#[test_case("1, scale", SCALE, 0; "first argument")]
+#[test_case(", ref total);", SCALE, 1; "after a comma")]
+#[test_case("ref total);", SCALE, 2; "last argument")]
+#[test_case("3, ref", SCALE, 1; "nested call")]
+#[test_case("2);", SHIFT, 1; "method call")]
+#[test_case(");\n    let y", SHIFT, 1; "end of arguments")]
+// The receiver is the only parameter, so none of the parameters is active.
+#[test_case(");\n}", INTO, 1; "inferred generic arguments")]
+fn test_signature_help(pattern: &str, expected_label: &str, expected_active_parameter: u32) {
+    let db = setup_fixture(&[("lib.cairo", CONTENT)]);
+    let file = fixture_file(&db, "lib.cairo");
+    let help = signature_help(&db, file, position_of(&db, file, pattern)).unwrap();
+    assert_eq!(help.signatures.len(), 1);
+    assert_eq!(help.signatures[0].label, expected_label);
+    assert_eq!(help.active_parameter, Some(expected_active_parameter));
+}
+
+#[test]
+fn test_signature_help_outside_of_calls() {
+    let db = setup_fixture(&[("lib.cairo", CONTENT)]);
+    let file = fixture_file(&db, "lib.cairo");
+    assert_eq!(signature_help(&db, file, position_of(&db, file, "total = 0")), None);
+    // The path of the called function is not part of its arguments.
+    assert_eq!(signature_help(&db, file, position_of(&db, file, "scale(1")), None);
+}
@@ -0,0 +1,281 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use lsp::{DocumentSymbol, SymbolInformation, SymbolKind};
+
+use crate::get_location;
+use crate::references::{definition_name, origin_location, CodeLocation};
+
+#[cfg(test)]
+#[path = "symbols_test.rs"]
+mod test;
+
+/// A symbol of the code, with the symbols nested in it.
+struct Symbol {
+    name: String,
+    detail: Option<String>,
+    kind: SymbolKind,
+    /// The code of the symbol.
+    location: CodeLocation,
+    /// The name of the symbol.
+    name_location: CodeLocation,
+    children: Vec<Symbol>,
+}
+
+/// Returns the outline of a file - the symbols defined in it, nested in their modules, structs,
+/// enums, traits and impls.
+pub fn document_symbols(db: &(dyn SemanticGroup + 'static), file: FileId) -> Vec<DocumentSymbol> {
+    let Some(module_id) = db.file_modules(file).ok().and_then(|modules| modules.first().copied())
+    else {
+        return vec![];
+    };
+    to_document_symbols(db, file, module_symbols(db, module_id), &mut HashSet::new())
+}
+
+/// Returns the symbols of all the crates whose names match a query, fuzzily.
+pub fn workspace_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let mut symbols = vec![];
+    let mut seen = HashSet::new();
+    for crate_id in db.crates() {
+        let module_id = ModuleId::CrateRoot(crate_id);
+        collect_matching_symbols(
+            db,
+            query,
+            module_symbols(db, module_id),
+            &module_id.full_path(db.upcast()),
+            &mut seen,
+            &mut symbols,
+        );
+    }
+    // Better matches first, and otherwise in the order of the code.
+    symbols.sort_by_key(|(rank, _)| *rank);
+    symbols.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+/// Converts the symbols defined in a file to LSP format. Symbols whose name was already seen, e.g.
+/// code generated by plugins from the same code, are skipped.
+fn to_document_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    symbols: Vec<Symbol>,
+    seen: &mut HashSet<CodeLocation>,
+) -> Vec<DocumentSymbol> {
+    let mut document_symbols = vec![];
+    for symbol in symbols {
+        if symbol.name_location.file != file || !seen.insert(symbol.name_location) {
+            continue;
+        }
+        #[allow(deprecated)]
+        document_symbols.push(DocumentSymbol {
+            name: symbol.name,
+            detail: symbol.detail,
+            kind: symbol.kind,
+            tags: None,
+            deprecated: None,
+            range: get_location(db, symbol.location).range,
+            selection_range: get_location(db, symbol.name_location).range,
+            children: Some(to_document_symbols(db, file, symbol.children, seen)),
+        });
+    }
+    document_symbols
+}
+
+/// Collects the symbols matching a query in LSP format, including the nested symbols.
+fn collect_matching_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    query: &str,
+    symbols: Vec<Symbol>,
+    container: &str,
+    seen: &mut HashSet<CodeLocation>,
+    matching_symbols: &mut Vec<(usize, SymbolInformation)>,
+) {
+    for symbol in symbols {
+        if !seen.insert(symbol.name_location) {
+            continue;
+        }
+        let path = format!("{container}::{}", symbol.name);
+        if let Some(rank) = fuzzy_match_rank(query, &symbol.name) {
+            #[allow(deprecated)]
+            matching_symbols.push((
+                rank,
+                SymbolInformation {
+                    name: symbol.name,
+                    kind: symbol.kind,
+                    tags: None,
+                    deprecated: None,
+                    location: get_location(db, symbol.location),
+                    container_name: Some(container.to_string()),
+                },
+            ));
+        }
+        collect_matching_symbols(db, query, symbol.children, &path, seen, matching_symbols);
+    }
+}
+
+/// Returns how well a name matches a query, ignoring case - lower is better - or `None` if the
+/// characters of the query do not appear in the name in order.
+fn fuzzy_match_rank(query: &str, name: &str) -> Option<usize> {
+    let query = query.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(&query) {
+        return Some(1);
+    }
+    if name.contains(&query) {
+        return Some(2);
+    }
+    let mut name_chars = name.chars();
+    query.chars().all(|c| name_chars.any(|name_c| name_c == c)).then_some(3)
+}
+
+/// Returns the symbols of the items of a module.
+fn module_symbols(db: &(dyn SemanticGroup + 'static), module_id: ModuleId) -> Vec<Symbol> {
+    let Ok(items) = db.module_items(module_id) else { return vec![]; };
+    items.iter().filter_map(|item| item_symbol(db, *item)).collect()
+}
+
+/// Returns the symbol of a module item, or `None` for `use` items.
+fn item_symbol(db: &(dyn SemanticGroup + 'static), item: ModuleItemId) -> Option<Symbol> {
+    let syntax_db = db.upcast();
+    let file = db.module_file(item.module_file_id(db.upcast())).ok()?;
+    let node = db
+        .file_syntax(file)
+        .ok()?
+        .as_syntax_node()
+        .lookup_ptr(syntax_db, item.untyped_stable_ptr(db.upcast()));
+    let (kind, detail, children) = match item {
+        ModuleItemId::Constant(_) => (SymbolKind::CONSTANT, None, vec![]),
+        ModuleItemId::Submodule(id) => {
+            (SymbolKind::MODULE, None, module_symbols(db, ModuleId::Submodule(id)))
+        }
+        ModuleItemId::Use(_) => return None,
+        ModuleItemId::FreeFunction(_) => {
+            let function = ast::FunctionWithBody::from_syntax_node(syntax_db, node.clone());
+            (SymbolKind::FUNCTION, Some(signature(db, function.declaration(syntax_db))), vec![])
+        }
+        ModuleItemId::ExternFunction(_) => {
+            let function = ast::ItemExternFunction::from_syntax_node(syntax_db, node.clone());
+            (SymbolKind::FUNCTION, Some(signature(db, function.declaration(syntax_db))), vec![])
+        }
+        ModuleItemId::Struct(_) => {
+            let members = ast::ItemStruct::from_syntax_node(syntax_db, node.clone())
+                .members(syntax_db)
+                .elements(syntax_db);
+            (SymbolKind::STRUCT, None, member_symbols(db, file, members, SymbolKind::FIELD))
+        }
+        ModuleItemId::Enum(_) => {
+            let variants = ast::ItemEnum::from_syntax_node(syntax_db, node.clone())
+                .variants(syntax_db)
+                .elements(syntax_db);
+            (SymbolKind::ENUM, None, member_symbols(db, file, variants, SymbolKind::ENUM_MEMBER))
+        }
+        ModuleItemId::TypeAlias(_) => (SymbolKind::TYPE_PARAMETER, None, vec![]),
+        ModuleItemId::ImplAlias(_) => (SymbolKind::OBJECT, None, vec![]),
+        ModuleItemId::Trait(_) => {
+            let body = ast::ItemTrait::from_syntax_node(syntax_db, node.clone()).body(syntax_db);
+            let functions = match body {
+                ast::MaybeTraitBody::Some(body) => body.items(syntax_db).elements(syntax_db),
+                ast::MaybeTraitBody::None(_) => vec![],
+            };
+            let children = functions
+                .into_iter()
+                .filter_map(|item| match item {
+                    ast::TraitItem::Function(function) => method_symbol(
+                        db,
+                        file,
+                        function.as_syntax_node(),
+                        function.declaration(syntax_db),
+                    ),
+                    ast::TraitItem::Missing(_) => None,
+                })
+                .collect();
+            (SymbolKind::INTERFACE, None, children)
+        }
+        ModuleItemId::Impl(_) => {
+            let body = ast::ItemImpl::from_syntax_node(syntax_db, node.clone()).body(syntax_db);
+            let items = match body {
+                ast::MaybeImplBody::Some(body) => body.items(syntax_db).elements(syntax_db),
+                ast::MaybeImplBody::None(_) => vec![],
+            };
+            let children = items
+                .into_iter()
+                .filter_map(|item| match item {
+                    ast::ImplItem::Function(function) => method_symbol(
+                        db,
+                        file,
+                        function.as_syntax_node(),
+                        function.declaration(syntax_db),
+                    ),
+                    _ => None,
+                })
+                .collect();
+            (SymbolKind::OBJECT, None, children)
+        }
+        ModuleItemId::ExternType(_) => (SymbolKind::STRUCT, None, vec![]),
+    };
+    symbol(db, file, node, kind, detail, children)
+}
+
+/// Returns the symbols of the members of a struct or the variants of an enum.
+fn member_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    members: Vec<ast::Member>,
+    kind: SymbolKind,
+) -> Vec<Symbol> {
+    members
+        .into_iter()
+        .filter_map(|member| symbol(db, file, member.as_syntax_node(), kind, None, vec![]))
+        .collect()
+}
+
+/// Returns the symbol of a function of a trait or an impl.
+fn method_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    declaration: ast::FunctionDeclaration,
+) -> Option<Symbol> {
+    symbol(db, file, node, SymbolKind::METHOD, Some(signature(db, declaration)), vec![])
+}
+
+/// Returns the signature of a function, as written in its declaration.
+fn signature(db: &(dyn SemanticGroup + 'static), declaration: ast::FunctionDeclaration) -> String {
+    let syntax_db = db.upcast();
+    declaration.signature(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db)
+}
+
+/// Returns the symbol of a definition, or `None` if it is not in the code, e.g. if it was generated
+/// by a plugin.
+fn symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+    kind: SymbolKind,
+    detail: Option<String>,
+    children: Vec<Symbol>,
+) -> Option<Symbol> {
+    let syntax_db = db.upcast();
+    let name = definition_name(syntax_db, node.clone())?;
+    let name_location = origin_location(db, file, name.span_without_trivia(syntax_db))?;
+    // Definitions generated by plugins are only symbols of the code if they were copied from it as
+    // a whole.
+    let location = origin_location(db, file, node.span_without_trivia(syntax_db))
+        .filter(|location| location.file == name_location.file)?;
+    Some(Symbol {
+        name: name.get_text_without_trivia(syntax_db),
+        detail,
+        kind,
+        location,
+        name_location,
+        children,
+    })
+}
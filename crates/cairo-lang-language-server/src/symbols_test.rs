@@ -0,0 +1,85 @@
+use indoc::indoc;
+use lsp::DocumentSymbol;
+use pretty_assertions::assert_eq;
+
+use super::document_symbols;
+use crate::test_utils::{fixture_file, format_lines_range, setup_fixture};
+
+/// Formats the tree of document symbols, one symbol per line, indented by its depth.
+fn format_symbols(symbols: &[DocumentSymbol], depth: usize, lines: &mut Vec<String>) {
+    for symbol in symbols {
+        let detail =
+            symbol.detail.as_ref().map(|detail| format!(" `{detail}`")).unwrap_or_default();
+        lines.push(format!(
+            "{}{:?} {}{detail} at {}, named at {}",
+            "    ".repeat(depth),
+            symbol.kind,
+            symbol.name,
+            format_lines_range(symbol.range),
+            format_lines_range(symbol.selection_range),
+        ));
+        format_symbols(symbol.children.as_deref().unwrap_or_default(), depth + 1, lines);
+    }
+}
+
+#[test]
+fn test_document_symbols() {
+    let db = setup_fixture(&[(
+        "lib.cairo",
+        indoc! {"
+            use array::ArrayTrait;
+
+            const LIMIT: u32 = 10;
+
+            mod shapes {
+                #[derive(Copy, Drop)]
+                struct Point {
+                    x: u32,
+                    y: u32,
+                }
+
+                enum Shape {
+                    Circle: (Point, u32),
+                    Square: Point,
+                }
+
+                fn origin() -> Point {
+                    Point { x: 0, y: 0 }
+                }
+            }
+
+            trait Area<T> {
+                fn area(self: @T) -> u32;
+            }
+
+            impl U32Area of Area<u32> {
+                fn area(self: @u32) -> u32 {
+                    *self * *self
+                }
+            }
+        "},
+    )]);
+    let file = fixture_file(&db, "lib.cairo");
+
+    let mut lines = vec![];
+    format_symbols(&document_symbols(&db, file), 0, &mut lines);
+    // `use` items are not symbols, and neither are the impls derived for `Point`.
+    assert_eq!(
+        lines,
+        [
+            "Constant LIMIT at 2:0-2:22, named at 2:6-2:11",
+            "Module shapes at 4:0-19:1, named at 4:4-4:10",
+            "    Struct Point at 5:4-9:5, named at 6:11-6:16",
+            "        Field x at 7:8-7:14, named at 7:8-7:9",
+            "        Field y at 8:8-8:14, named at 8:8-8:9",
+            "    Enum Shape at 11:4-14:5, named at 11:9-11:14",
+            "        EnumMember Circle at 12:8-12:28, named at 12:8-12:14",
+            "        EnumMember Square at 13:8-13:21, named at 13:8-13:14",
+            "    Function origin `() -> Point` at 16:4-18:5, named at 16:7-16:13",
+            "Interface Area at 21:0-23:1, named at 21:6-21:10",
+            "    Method area `(self: @T) -> u32` at 22:4-22:29, named at 22:7-22:11",
+            "Object U32Area at 25:0-29:1, named at 25:5-25:12",
+            "    Method area `(self: @u32) -> u32` at 26:4-28:5, named at 26:7-26:11",
+        ]
+    );
+}
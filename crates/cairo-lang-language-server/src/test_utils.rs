@@ -0,0 +1,73 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileId, FileLongId};
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_starknet::plugin::StarkNetPlugin;
+use lsp::{Position, Range};
+
+use crate::from_pos;
+
+/// The root directory of the crate of a fixture.
+const FIXTURE_ROOT: &str = "/fixture/src";
+
+/// Sets up a database with a crate of the given files, by their paths relative to the root of the
+/// crate. The crate is named `fixture`, and is compiled with the plugins of the language server.
+pub fn setup_fixture(files: &[(&str, &str)]) -> RootDatabase {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_semantic_plugin(Arc::new(StarkNetPlugin::default()))
+        .build()
+        .unwrap();
+    let crate_id = db.intern_crate(CrateLongId("fixture".into()));
+    db.set_crate_root(crate_id, Some(Directory(FIXTURE_ROOT.into())));
+    for (path, content) in files {
+        let file = fixture_file(&db, path);
+        db.as_files_group_mut().override_file_content(file, Some(Arc::new(content.to_string())));
+    }
+    db
+}
+
+/// Returns a file of a fixture, by its path relative to the root of the crate.
+pub fn fixture_file(db: &RootDatabase, path: &str) -> FileId {
+    FileId::new(db, Path::new(FIXTURE_ROOT).join(path))
+}
+
+/// Returns the position of the first occurrence of `pattern` in a file.
+pub fn position_of(db: &RootDatabase, file: FileId, pattern: &str) -> Position {
+    let content = db.file_content(file).unwrap();
+    let offset = content.find(pattern).unwrap_or_else(|| panic!("`{pattern}` not found."));
+    let offset = TextOffset::default().add_width(TextWidth::from_str(&content[..offset]));
+    from_pos(offset.position_in_file(db, file).unwrap())
+}
+
+/// Formats a span of a file as `path:line:col-line:col`, with the path relative to the root of the
+/// fixture crate, and the lines and columns starting from 0.
+pub fn format_span(db: &RootDatabase, file: FileId, span: TextSpan) -> String {
+    let start = from_pos(span.start.position_in_file(db, file).unwrap());
+    let end = from_pos(span.end.position_in_file(db, file).unwrap());
+    format_range(&file_name(db, file), Range { start, end })
+}
+
+/// Formats a range of a file as `path:line:col-line:col`.
+pub fn format_range(path: &str, range: Range) -> String {
+    format!("{path}:{}", format_lines_range(range))
+}
+
+/// Formats a range as `line:col-line:col`.
+pub fn format_lines_range(range: Range) -> String {
+    let Range { start, end } = range;
+    format!("{}:{}-{}:{}", start.line, start.character, end.line, end.character)
+}
+
+/// Returns the path of a file relative to the root of the fixture crate.
+pub fn file_name(db: &RootDatabase, file: FileId) -> String {
+    match db.lookup_intern_file(file) {
+        FileLongId::OnDisk(path) => {
+            path.strip_prefix(FIXTURE_ROOT).unwrap_or(&path).display().to_string()
+        }
+        FileLongId::Virtual(virtual_file) => virtual_file.name.to_string(),
+    }
+}
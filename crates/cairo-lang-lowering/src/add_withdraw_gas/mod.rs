@@ -71,7 +71,7 @@ fn add_withdraw_gas_to_function(
                 .lowered(db),
                 inputs: vec![],
                 outputs: vec![builtin_costs_var],
-                location,
+                location: location.into(),
             }),
         ],
         end: FlatBlockEnd::Match {
@@ -102,7 +102,7 @@ fn add_withdraw_gas_to_function(
                         var_ids: vec![],
                     },
                 ],
-                location,
+                location: location.into(),
             }),
         },
     };
@@ -159,7 +159,7 @@ fn create_panic_block(
                 .lowered(db),
                 inputs: vec![],
                 outputs: vec![new_array_var],
-                location,
+                location: location.into(),
             }),
             Statement::Literal(StatementLiteral {
                 value: BigInt::from_bytes_be(Sign::Plus, "Out of gas".as_bytes()),
@@ -175,7 +175,7 @@ fn create_panic_block(
                 .lowered(db),
                 inputs: vec![new_array_var, out_of_gas_err_var],
                 outputs: vec![panic_data_var],
-                location,
+                location: location.into(),
             }),
             Statement::StructConstruct(StatementStructConstruct {
                 inputs: vec![],
@@ -4,7 +4,7 @@ use cairo_lang_semantic::substitution::{
 };
 
 use crate::db::LoweringGroup;
-use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction};
+use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction, SemanticFunctionIdEx};
 use crate::{FlatBlockEnd, FlatLowered, MatchArm, Statement};
 
 /// Rewrites a [FunctionId] with a [SubstitutionRewriter].
@@ -14,12 +14,10 @@ fn concretize_function(
     function: FunctionId,
 ) -> Maybe<FunctionId> {
     let long_id = match db.lookup_intern_lowering_function(function) {
-        FunctionLongId::Semantic(id) => FunctionLongId::Semantic(rewriter.rewrite(id)?),
-        FunctionLongId::Generated(GeneratedFunction { parent, element }) => {
-            FunctionLongId::Generated(GeneratedFunction {
-                parent: rewriter.rewrite(parent)?,
-                element,
-            })
+        // A generic function may become a call of a closure once concretized.
+        FunctionLongId::Semantic(id) => return Ok(rewriter.rewrite(id)?.lowered(db)),
+        FunctionLongId::Generated(GeneratedFunction { parent, key }) => {
+            FunctionLongId::Generated(GeneratedFunction { parent: rewriter.rewrite(parent)?, key })
         }
     };
     Ok(db.intern_lowering_function(long_id))
@@ -285,8 +285,8 @@ fn priv_function_with_body_lowering(
     let multi_lowering = db.priv_function_with_body_multi_lowering(semantic_function_id)?;
     let lowered = match db.lookup_intern_lowering_function_with_body(function_id) {
         ids::FunctionWithBodyLongId::Semantic(_) => multi_lowering.main_lowering.clone(),
-        ids::FunctionWithBodyLongId::Generated { element, .. } => {
-            multi_lowering.generated_lowerings[element].clone()
+        ids::FunctionWithBodyLongId::Generated { key, .. } => {
+            multi_lowering.generated_lowerings[key].clone()
         }
     };
     Ok(Arc::new(lowered))
@@ -451,11 +451,11 @@ fn semantic_function_with_body_lowering_diagnostics(
         );
         diagnostics
             .extend(db.function_with_body_lowering_diagnostics(function_id).unwrap_or_default());
-        for (element, _) in multi_lowering.generated_lowerings.iter() {
+        for (key, _) in multi_lowering.generated_lowerings.iter() {
             let function_id =
                 db.intern_lowering_function_with_body(ids::FunctionWithBodyLongId::Generated {
                     parent: semantic_function_id,
-                    element: *element,
+                    key: *key,
                 });
             diagnostics.extend(
                 db.function_with_body_lowering_diagnostics(function_id).unwrap_or_default(),
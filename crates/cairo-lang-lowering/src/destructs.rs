@@ -297,7 +297,7 @@ pub fn add_destructs(
                             function: semantic_function.lowered(db),
                             inputs: vec![var_id],
                             outputs: vec![output_var],
-                            location: lowered.variables[var_id].location,
+                            location: lowered.variables[var_id].location.into(),
                         }),
                     )
                 }
@@ -325,7 +325,7 @@ pub fn add_destructs(
                                 function: semantic_function.lowered(db),
                                 inputs: vec![panic_var, var_id],
                                 outputs: vec![panic_var, output_var],
-                                location: lowered.variables[panic_var].location,
+                                location: lowered.variables[panic_var].location.into(),
                             }),
                         ),
                         PanicLocation::PanicTuple {
@@ -357,7 +357,7 @@ pub fn add_destructs(
                                     function: semantic_function.lowered(db),
                                     inputs: vec![vars[0], var_id],
                                     outputs: vec![vars[0], output_var],
-                                    location: lowered.variables[tuple_var].location,
+                                    location: lowered.variables[tuple_var].location.into(),
                                 }),
                                 Statement::StructConstruct(StatementStructConstruct {
                                     inputs: vars,
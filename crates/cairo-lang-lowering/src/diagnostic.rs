@@ -2,6 +2,7 @@ use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::ModuleFileId;
 use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    Severity,
 };
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::expr::inference::InferenceError;
@@ -45,12 +46,6 @@ impl DiagnosticEntry for LoweringDiagnostic {
     fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
-            LoweringDiagnosticKind::NonZeroValueInMatch => {
-                "Match with a non-zero value is not supported.".into()
-            }
-            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => {
-                "Only match zero (match ... { 0 => ..., _ => ... }) is currently supported.".into()
-            }
             LoweringDiagnosticKind::VariableMoved { inference_error } => {
                 format!("Variable was previously moved. {}", inference_error.format(db))
             }
@@ -65,20 +60,18 @@ impl DiagnosticEntry for LoweringDiagnostic {
                 format!("Cannot desnap a non copyable type. {}", inference_error.format(db))
             }
             LoweringDiagnosticKind::UnsupportedMatchedValue => "Unsupported matched value. \
-                                                                Currently, only matches on enums \
-                                                                and felt252s are supported."
+                                                                Currently, literal patterns are \
+                                                                only supported for felt252 and \
+                                                                integers of up to 128 bits."
                 .into(),
             LoweringDiagnosticKind::UnsupportedMatchArms => "Unsupported match. Currently, \
                                                              matches require one arm per variant, \
                                                              in the order of variant definition."
                 .into(),
-            LoweringDiagnosticKind::UnsupportedMatchArmNotAVariant => {
-                "Unsupported match arm - not a variant.".into()
-            }
-            LoweringDiagnosticKind::UnsupportedMatchArmOutOfOrder => {
-                "Unsupported match arm - variants must be the same order as enum declaration."
-                    .into()
+            LoweringDiagnosticKind::MissingMatchArm(missing) => {
+                format!("Missing match arm: `{missing}` not covered.")
             }
+            LoweringDiagnosticKind::UnreachableMatchArm => "Unreachable match arm.".into(),
             LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself => {
                 "Cannot inline a function that might call itself.".into()
             }
@@ -88,6 +81,13 @@ impl DiagnosticEntry for LoweringDiagnostic {
         }
     }
 
+    fn severity(&self) -> Severity {
+        match &self.kind {
+            LoweringDiagnosticKind::UnreachableMatchArm => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
     #[allow(unreachable_patterns, clippy::single_match)]
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         match &self.kind {
@@ -105,17 +105,13 @@ impl DiagnosticEntry for LoweringDiagnostic {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum LoweringDiagnosticKind {
     Unreachable { last_statement_ptr: SyntaxStablePtrId },
-    // TODO(lior): Remove once supported.
-    NonZeroValueInMatch,
-    // TODO(lior): Remove once supported.
-    OnlyMatchZeroIsSupported,
     VariableMoved { inference_error: InferenceError },
     VariableNotDropped { drop_err: InferenceError, destruct_err: InferenceError },
     DesnappingANonCopyableType { inference_error: InferenceError },
     UnsupportedMatchedValue,
     UnsupportedMatchArms,
-    UnsupportedMatchArmNotAVariant,
-    UnsupportedMatchArmOutOfOrder,
+    MissingMatchArm(String),
+    UnreachableMatchArm,
     CannotInlineFunctionThatMightCallItself,
     MemberPathLoop,
 }
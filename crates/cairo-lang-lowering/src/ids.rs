@@ -6,8 +6,11 @@ use cairo_lang_syntax::node::ast;
 use cairo_lang_utils::define_short_id;
 use defs::diagnostic_utils::StableLocation;
 use defs::ids::{FreeFunctionId, LanguageElementId};
+use semantic::corelib::{fn_once_trait, fn_trait};
+use semantic::items::functions::GenericFunctionId;
+use semantic::items::imp::ImplId;
 use semantic::substitution::{GenericSubstitution, SubstitutionRewriter};
-use semantic::{ExprVar, Mutability};
+use semantic::{ExprVar, GenericArgumentId, Mutability, TypeLongId};
 use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
 
 use crate::db::LoweringGroup;
@@ -16,7 +19,7 @@ use crate::ids::semantic::substitution::SemanticRewriter;
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum FunctionWithBodyLongId {
     Semantic(defs::ids::FunctionWithBodyId),
-    Generated { parent: defs::ids::FunctionWithBodyId, element: semantic::ExprId },
+    Generated { parent: defs::ids::FunctionWithBodyId, key: GeneratedFunctionKey },
 }
 define_short_id!(
     FunctionWithBodyId,
@@ -39,13 +42,13 @@ impl FunctionWithBodyLongId {
             FunctionWithBodyLongId::Semantic(semantic) => ConcreteFunctionWithBodyLongId::Semantic(
                 semantic::ConcreteFunctionWithBodyId::from_generic(db.upcast(), semantic)?,
             ),
-            FunctionWithBodyLongId::Generated { parent, element } => {
+            FunctionWithBodyLongId::Generated { parent, key } => {
                 ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction {
                     parent: semantic::ConcreteFunctionWithBodyId::from_generic(
                         db.upcast(),
                         parent,
                     )?,
-                    element,
+                    key,
                 })
             }
         })
@@ -100,10 +103,10 @@ impl ConcreteFunctionWithBodyLongId {
             ConcreteFunctionWithBodyLongId::Semantic(id) => {
                 FunctionWithBodyLongId::Semantic(id.function_with_body_id(semantic_db))
             }
-            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, element }) => {
+            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, key }) => {
                 FunctionWithBodyLongId::Generated {
                     parent: parent.function_with_body_id(semantic_db),
-                    element,
+                    key,
                 }
             }
         };
@@ -193,7 +196,7 @@ impl ConcreteFunctionWithBodyId {
                 let parent_id = generated.parent.function_with_body_id(semantic_db);
                 StableLocation {
                     module_file_id: parent_id.module_file_id(semantic_db.upcast()),
-                    stable_ptr: db.function_body(parent_id)?.exprs[generated.element]
+                    stable_ptr: db.function_body(parent_id)?.exprs[generated.key.expr()]
                         .stable_ptr()
                         .untyped(),
                 }
@@ -250,9 +253,46 @@ pub trait SemanticFunctionIdEx {
 }
 impl SemanticFunctionIdEx for semantic::FunctionId {
     fn lowered(&self, db: &dyn LoweringGroup) -> FunctionId {
-        db.intern_lowering_function(FunctionLongId::Semantic(*self))
+        let long_id = match closure_generated_function(db, *self) {
+            Some(generated) => FunctionLongId::Generated(generated),
+            None => FunctionLongId::Semantic(*self),
+        };
+        db.intern_lowering_function(long_id)
     }
 }
+
+/// If `function` is the `call_once` or the `call` function of a closure, returns the matching
+/// generated function: the body of the closure, or the function calling it by snapshot.
+fn closure_generated_function(
+    db: &dyn LoweringGroup,
+    function: semantic::FunctionId,
+) -> Option<GeneratedFunction> {
+    let semantic_db = db.upcast();
+    let GenericFunctionId::Impl(impl_function) =
+        db.lookup_intern_function(function).function.generic_function
+    else {
+        return None;
+    };
+    let ImplId::GeneratedImpl(generated_impl) = impl_function.impl_id else {
+        return None;
+    };
+    let concrete_trait =
+        db.lookup_intern_concrete_trait(generated_impl.concrete_trait(semantic_db));
+    let GenericArgumentId::Type(closure_ty) = concrete_trait.generic_args[0] else {
+        return None;
+    };
+    let TypeLongId::Closure(closure) = db.lookup_intern_type(closure_ty) else {
+        return None;
+    };
+    let key = if concrete_trait.trait_id == fn_once_trait(semantic_db) {
+        GeneratedFunctionKey::Expr(closure.body)
+    } else if concrete_trait.trait_id == fn_trait(semantic_db) {
+        GeneratedFunctionKey::ClosureCall(closure.body)
+    } else {
+        return None;
+    };
+    Some(GeneratedFunction { parent: closure.parent_function, key })
+}
 impl<'a> DebugWithDb<dyn LoweringGroup + 'a> for FunctionLongId {
     fn fmt(
         &self,
@@ -261,11 +301,31 @@ impl<'a> DebugWithDb<dyn LoweringGroup + 'a> for FunctionLongId {
     ) -> std::fmt::Result {
         match self {
             FunctionLongId::Semantic(semantic) => semantic.fmt(f, db),
-            FunctionLongId::Generated(generated) => {
-                // TODO(spapini): Differentiate between the generated functions according to
-                // `element`.
-                write!(f, "{:?}[expr{}]", generated.parent.debug(db), generated.element.index())
-            }
+            FunctionLongId::Generated(generated) => match generated.key {
+                GeneratedFunctionKey::Expr(expr) => {
+                    write!(f, "{:?}[expr{}]", generated.parent.debug(db), expr.index())
+                }
+                GeneratedFunctionKey::ClosureCall(expr) => {
+                    write!(f, "{:?}[expr{}]::call", generated.parent.debug(db), expr.index())
+                }
+            },
+        }
+    }
+}
+
+/// The key of a generated function, identifying it within the function it was generated from.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum GeneratedFunctionKey {
+    /// The body of a loop or of a closure, lowered as a function.
+    Expr(semantic::ExprId),
+    /// The `Fn::call` function of a closure, calling its body by snapshot. Holds the closure body.
+    ClosureCall(semantic::ExprId),
+}
+impl GeneratedFunctionKey {
+    /// Returns the expression the function was generated for.
+    pub fn expr(&self) -> semantic::ExprId {
+        match *self {
+            GeneratedFunctionKey::Expr(expr) | GeneratedFunctionKey::ClosureCall(expr) => expr,
         }
     }
 }
@@ -274,13 +334,12 @@ impl<'a> DebugWithDb<dyn LoweringGroup + 'a> for FunctionLongId {
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct GeneratedFunction {
     pub parent: semantic::ConcreteFunctionWithBodyId,
-    pub element: semantic::ExprId,
+    pub key: GeneratedFunctionKey,
 }
 impl GeneratedFunction {
     pub fn body(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyId {
-        let GeneratedFunction { parent, element } = *self;
-        let long_id =
-            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, element });
+        let GeneratedFunction { parent, key } = *self;
+        let long_id = ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, key });
         db.intern_lowering_concrete_function_with_body(long_id)
     }
 }
@@ -112,7 +112,7 @@ fn lower_block_implicits(ctx: &mut Context<'_>, block_id: BlockId) -> Maybe<()>
             let implicit_output_vars = callee_implicits
                 .iter()
                 .copied()
-                .map(|ty| ctx.variables.new_var(VarRequest { ty, location: stmt.location }))
+                .map(|ty| ctx.variables.new_var(VarRequest { ty, location: stmt.location.stable_location }))
                 .collect_vec();
             for (i, var) in zip_eq(indices, implicit_output_vars.iter()) {
                 implicits[i] = *var;
@@ -156,7 +156,7 @@ fn lower_block_implicits(ctx: &mut Context<'_>, block_id: BlockId) -> Maybe<()>
                     callee_implicits.iter().map(|ty| ctx.implicit_index[ty]).collect_vec();
                 let implicit_input_vars = indices.iter().map(|i| implicits[*i]);
                 stmt.inputs.splice(0..0, implicit_input_vars);
-                let location = stmt.location;
+                let location = stmt.location.stable_location;
 
                 for MatchArm { variant_id: _, block_id, var_ids } in stmt.arms.iter_mut() {
                     let mut arm_implicits = implicits.clone();
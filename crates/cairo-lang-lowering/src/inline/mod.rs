@@ -16,7 +16,10 @@ use crate::diagnostic::{LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiag
 use crate::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyId};
 use crate::lower::context::{VarRequest, VariableAllocator};
 use crate::utils::{Rebuilder, RebuilderEx};
-use crate::{BlockId, FlatBlock, FlatBlockEnd, FlatLowered, Statement, VarRemapping, VariableId};
+use crate::{
+    BlockId, FlatBlock, FlatBlockEnd, FlatLowered, Location, MatchInfo, Statement, VarRemapping,
+    VariableId,
+};
 
 /// data about inlining.
 #[derive(Debug, PartialEq, Eq)]
@@ -185,6 +188,9 @@ pub struct Mapper<'a, 'b> {
     renamed_vars: HashMap<VariableId, VariableId>,
     return_block_id: BlockId,
     outputs: &'a [id_arena::Id<crate::Variable>],
+    /// The location of the call that is inlined, added to the locations of the inlined
+    /// statements.
+    inlining_location: &'a Location,
 
     /// An offset that is added to all the block IDs in order to translate them into the new
     /// lowering representation.
@@ -210,6 +216,12 @@ impl<'a, 'b> Rebuilder for Mapper<'a, 'b> {
         BlockId(self.block_id_offset.0 + orig_block_id.0)
     }
 
+    fn transform_statement(&mut self, statement: &mut Statement) {
+        if let Statement::Call(stmt) = statement {
+            stmt.location = stmt.location.inlined_at(self.inlining_location);
+        }
+    }
+
     fn transform_end(&mut self, end: &mut FlatBlockEnd) {
         match end {
             FlatBlockEnd::Return(returns) => {
@@ -221,7 +233,14 @@ impl<'a, 'b> Rebuilder for Mapper<'a, 'b> {
                 };
                 *end = FlatBlockEnd::Goto(self.return_block_id, remapping);
             }
-            FlatBlockEnd::Panic(_) | FlatBlockEnd::Goto(_, _) | FlatBlockEnd::Match { .. } => {}
+            FlatBlockEnd::Match { info } => {
+                let location = match info {
+                    MatchInfo::Enum(info) => &mut info.location,
+                    MatchInfo::Extern(info) => &mut info.location,
+                };
+                *location = location.inlined_at(self.inlining_location);
+            }
+            FlatBlockEnd::Panic(_) | FlatBlockEnd::Goto(_, _) => {}
             FlatBlockEnd::NotSet => unreachable!(),
         }
     }
@@ -292,9 +311,8 @@ impl<'db> FunctionInlinerRewriter<'db> {
                     .db
                     .priv_inline_data(function_id.function_with_body_id(semantic_db))?;
 
-                self.inlining_success = self
-                    .inlining_success
-                    .and_then(|()| inline_data.diagnostics.is_diagnostic_free());
+                self.inlining_success =
+                    self.inlining_success.and_then(|()| inline_data.diagnostics.check_error_free());
 
                 if inline_data.info.is_inlinable
                     && (inline_data.info.should_inline
@@ -302,10 +320,20 @@ impl<'db> FunctionInlinerRewriter<'db> {
                 {
                     if matches!(inline_data.config, InlineConfiguration::Should(_)) {
                         if !self.is_function_in_call_stack(function_id) {
-                            return self.inline_function(function_id, &stmt.inputs, &stmt.outputs);
+                            return self.inline_function(
+                                function_id,
+                                &stmt.inputs,
+                                &stmt.outputs,
+                                &stmt.location,
+                            );
                         }
                     } else {
-                        return self.inline_function(function_id, &stmt.inputs, &stmt.outputs);
+                        return self.inline_function(
+                            function_id,
+                            &stmt.inputs,
+                            &stmt.outputs,
+                            &stmt.location,
+                        );
                     }
                 }
             }
@@ -334,11 +362,13 @@ impl<'db> FunctionInlinerRewriter<'db> {
     /// are pushed into the `statement_rewrite_stack`.
     /// May also push additional blocks to the block queue.
     /// The function takes an optional return block id to handle early returns.
+    /// The locations of the inlined statements are marked as inlined at `location`.
     pub fn inline_function(
         &mut self,
         function_id: ConcreteFunctionWithBodyId,
         inputs: &[VariableId],
         outputs: &[VariableId],
+        location: &Location,
     ) -> Maybe<()> {
         let lowered =
             self.variables.db.priv_concrete_function_with_body_lowered_flat(function_id)?;
@@ -373,6 +403,7 @@ impl<'db> FunctionInlinerRewriter<'db> {
             block_id_offset: BlockId(return_block_id.0 + 1),
             return_block_id,
             outputs,
+            inlining_location: location,
         };
 
         // The current block should Goto to the root block of the inlined function.
@@ -118,6 +118,16 @@ impl BlockBuilder {
         self.finalize(ctx, FlatBlockEnd::Match { info: match_info });
     }
 
+    /// Ends a block with a match, whose arms are already lowered.
+    pub fn end_with_match(self, ctx: &mut LoweringContext<'_, '_>, match_info: MatchInfo) {
+        self.finalize(ctx, FlatBlockEnd::Match { info: match_info });
+    }
+
+    /// Ends a block with a goto to a block shared with other predecessors.
+    pub fn goto(self, ctx: &mut LoweringContext<'_, '_>, target: BlockId, remapping: VarRemapping) {
+        self.finalize(ctx, FlatBlockEnd::Goto(target, remapping));
+    }
+
     /// Ends a block with Panic.
     pub fn panic(self, ctx: &mut LoweringContext<'_, '_>, data: VariableId) -> Maybe<()> {
         self.finalize(ctx, FlatBlockEnd::Panic(data));
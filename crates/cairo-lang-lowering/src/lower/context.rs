@@ -24,7 +24,10 @@ use super::usage::BlockUsages;
 use crate::blocks::FlatBlocksBuilder;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnostics;
-use crate::ids::{ConcreteFunctionWithBodyId, FunctionWithBodyId, SemanticFunctionIdEx, Signature};
+use crate::ids::{
+    ConcreteFunctionWithBodyId, FunctionWithBodyId, GeneratedFunctionKey, SemanticFunctionIdEx,
+    Signature,
+};
 use crate::lower::external::{extern_facade_expr, extern_facade_return_tys};
 use crate::objects::Variable;
 use crate::{FlatLowered, MatchArm, MatchExternInfo, MatchInfo, VariableId};
@@ -110,7 +113,7 @@ pub struct EncapsulatingLoweringContext<'db> {
     /// Block usages for the entire encapsulating function.
     pub block_usages: BlockUsages,
     /// Lowerings of generated functions.
-    pub lowerings: OrderedHashMap<semantic::ExprId, FlatLowered>,
+    pub lowerings: OrderedHashMap<GeneratedFunctionKey, FlatLowered>,
 }
 impl<'db> EncapsulatingLoweringContext<'db> {
     pub fn new(
@@ -142,8 +145,8 @@ pub struct LoweringContext<'a, 'db> {
     /// Id for the current concrete function to be used when generating recursive calls.
     /// This it the generic function specialized with its own generic parameters.
     pub concrete_function_id: ConcreteFunctionWithBodyId,
-    /// Current loop expression needed for recursive calls in `continue`
-    pub current_loop_expr: Option<semantic::ExprLoop>,
+    /// Id of the current loop expression needed for recursive calls in `continue`
+    pub current_loop_expr_id: Option<semantic::ExprId>,
     /// Current emitted diagnostics.
     pub diagnostics: LoweringDiagnostics,
     /// Lowered blocks of the function.
@@ -168,7 +171,7 @@ impl<'a, 'db> LoweringContext<'a, 'db> {
             signature,
             function_id,
             concrete_function_id,
-            current_loop_expr: Option::None,
+            current_loop_expr_id: Option::None,
             diagnostics: LoweringDiagnostics::new(module_file_id),
             blocks: Default::default(),
         })
@@ -349,7 +352,7 @@ impl LoweredExprExternEnum {
             arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids)
                 .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
                 .collect(),
-            location: self.location,
+            location: self.location.into(),
         });
         builder
             .merge_and_end_with_match(ctx, match_info, sealed_blocks, self.location)?
@@ -17,6 +17,7 @@ cairo_lang_test_utils::test_file_test!(
     "src/lower/test_data",
     {
         loop_ :"loop",
+        closure: "closure",
     },
     test_generated_function
 );
@@ -48,11 +49,11 @@ fn test_generated_function(
         )
         .unwrap();
 
-        for (expr_id, lowering) in multi_lowering.generated_lowerings.iter() {
+        for (key, lowering) in multi_lowering.generated_lowerings.iter() {
             let generated_id = db.intern_lowering_concrete_function_with_body(
                 ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction {
                     parent: test_function.concrete_function_id,
-                    element: *expr_id,
+                    key: *key,
                 }),
             );
 
@@ -83,7 +83,7 @@ impl Call {
             function: self.function,
             inputs: self.inputs,
             outputs,
-            location: self.location,
+            location: self.location.into(),
         }));
 
         CallResult { returns, extra_outputs }
@@ -117,7 +117,7 @@ pub fn lower_expr_if_bool(
                 var_ids: vec![main_block_var_id],
             },
         ],
-        location: if_location,
+        location: if_location.into(),
     });
     builder.merge_and_end_with_match(ctx, match_info, vec![block_main, block_else], if_location)
 }
@@ -191,7 +191,7 @@ pub fn lower_expr_if_eq(
                 var_ids: vec![else_block_input_var_id],
             },
         ],
-        location: if_location,
+        location: if_location.into(),
     });
     builder.merge_and_end_with_match(ctx, match_info, vec![block_main, block_else], if_location)
 }
@@ -0,0 +1,1062 @@
+//! Lowering of match expressions.
+//!
+//! The arms of a match are compiled into a decision tree, where each node examines a single part of
+//! the matched value - a "place". An enum place is matched on its variants, a struct or tuple place
+//! is destructured into its members, and an integer place is tested against a literal or a range.
+//! The leaves of the tree select the arm to run.
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use itertools::{zip_eq, Itertools};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use semantic::items::enm::SemanticEnumEx;
+use semantic::items::structure::SemanticStructEx;
+use semantic::types::{peel_snapshots, wrap_in_snapshots};
+use semantic::{ConcreteTypeId, TypeLongId};
+use smol_str::SmolStr;
+
+use super::block_builder::{BlockBuilder, SealedBlockBuilder};
+use super::context::{
+    lowering_flow_error_to_sealed_block, LoweredExpr, LoweringContext, LoweringFlowError,
+    LoweringResult, VarRequest,
+};
+use super::logical_op::create_bool;
+use super::{
+    create_subscope_with_bound_refs, generators, lower_expr, lower_expr_literal,
+    lower_single_pattern, lower_tail_expr,
+};
+use crate::diagnostic::LoweringDiagnosticKind::*;
+use crate::ids::SemanticFunctionIdEx;
+use crate::{
+    BlockId, MatchArm, MatchEnumInfo, MatchExternInfo, MatchInfo, VarRemapping, VariableId,
+};
+
+/// Lowers the arms of a match expression on the value held by `matched_var`.
+pub fn lower_match_arms(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    expr: &semantic::ExprMatch,
+    matched_var: VariableId,
+) -> LoweringResult<LoweredExpr> {
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let mut lowering = MatchLowering::new(&expr.arms, location);
+    let root = lowering.add_place(ctx.variables[matched_var].ty, location);
+
+    let tree = if expr.arms.is_empty() {
+        lowering.compile_empty_match(ctx, root)?
+    } else {
+        let rows = expr
+            .arms
+            .iter()
+            .enumerate()
+            .map(|(arm_index, arm)| Row::new(arm_index, root, &arm.pattern))
+            .collect();
+        lowering.compile(ctx, rows, Domains::default())?
+    };
+
+    let mut leaf_counts = vec![0; expr.arms.len()];
+    tree.count_leaves(&mut leaf_counts);
+    for (arm, leaf_count) in zip_eq(&expr.arms, &leaf_counts) {
+        if *leaf_count == 0 {
+            ctx.diagnostics.report(arm.pattern.stable_ptr().untyped(), UnreachableMatchArm);
+        }
+    }
+    lowering.shared_arms =
+        leaf_counts.into_iter().map(|leaf_count| (leaf_count > 1).then(Default::default)).collect();
+
+    let mut vars = PlaceVars::default();
+    vars.insert(root, PlaceValue::Var(matched_var));
+    lowering.lower_root(ctx, builder, tree, vars)
+}
+
+/// Returns whether a pattern matches any value of its type.
+pub fn is_irrefutable(pattern: &semantic::Pattern) -> bool {
+    match pattern {
+        semantic::Pattern::Variable(_) | semantic::Pattern::Otherwise(_) => true,
+        semantic::Pattern::Struct(pattern) => {
+            pattern.field_patterns.iter().all(|(_, pattern)| is_irrefutable(pattern))
+        }
+        semantic::Pattern::Tuple(pattern) => {
+            pattern.field_patterns.iter().all(|pattern| is_irrefutable(pattern))
+        }
+        semantic::Pattern::Literal(_)
+        | semantic::Pattern::Range(_)
+        | semantic::Pattern::EnumVariant(_) => false,
+    }
+}
+
+/// Index of a place in [MatchLowering::places].
+type PlaceId = usize;
+
+/// A part of the matched value, examined by the decision tree.
+struct Place {
+    ty: semantic::TypeId,
+    location: StableLocation,
+}
+
+/// A row of the pattern matrix: the patterns of an arm that are left to check, with the places
+/// they apply to.
+#[derive(Clone)]
+struct Row<'a> {
+    arm_index: usize,
+    /// Refutable patterns, yet to be checked.
+    cells: Vec<(PlaceId, &'a semantic::Pattern)>,
+    /// Irrefutable patterns, bound once the arm is selected.
+    bindings: Vec<(PlaceId, &'a semantic::Pattern)>,
+}
+impl<'a> Row<'a> {
+    fn new(arm_index: usize, place: PlaceId, pattern: &'a semantic::Pattern) -> Self {
+        let mut row = Self { arm_index, cells: vec![], bindings: vec![] };
+        row.insert_cells(0, [(place, pattern)]);
+        row
+    }
+
+    /// Returns the pattern of the cell of the given place, if any.
+    fn cell(&self, place: PlaceId) -> Option<&'a semantic::Pattern> {
+        self.cells.iter().find(|(cell_place, _)| *cell_place == place).map(|(_, pattern)| *pattern)
+    }
+
+    /// Removes the cell of the given place, returning its index and pattern.
+    fn take_cell(&mut self, place: PlaceId) -> Option<(usize, &'a semantic::Pattern)> {
+        let index = self.cells.iter().position(|(cell_place, _)| *cell_place == place)?;
+        Some((index, self.cells.remove(index).1))
+    }
+
+    /// Inserts cells at the given index. Irrefutable patterns are kept as bindings instead.
+    fn insert_cells(
+        &mut self,
+        mut index: usize,
+        cells: impl IntoIterator<Item = (PlaceId, &'a semantic::Pattern)>,
+    ) {
+        for (place, pattern) in cells {
+            if matches!(pattern, semantic::Pattern::Otherwise(_)) {
+                continue;
+            }
+            if is_irrefutable(pattern) {
+                self.bindings.push((place, pattern));
+            } else {
+                self.cells.insert(index, (place, pattern));
+                index += 1;
+            }
+        }
+    }
+}
+
+/// A decision tree, compiled from the arms of a match expression.
+enum DecisionTree<'a> {
+    /// Selects an arm, after binding its irrefutable patterns.
+    Leaf { arm_index: usize, bindings: Vec<(PlaceId, &'a semantic::Pattern)> },
+    /// Destructures a struct or tuple place into its members.
+    Destructure { place: PlaceId, members: Vec<PlaceId>, next: Box<DecisionTree<'a>> },
+    /// Matches an enum place on its variants. The inner value of each variant is a new place.
+    Switch {
+        place: PlaceId,
+        concrete_enum_id: semantic::ConcreteEnumId,
+        arms: Vec<(semantic::ConcreteVariant, PlaceId, DecisionTree<'a>)>,
+    },
+    /// Tests an integer place against a literal or a range.
+    Test {
+        place: PlaceId,
+        test: ValueTest<'a>,
+        matched: Box<DecisionTree<'a>>,
+        unmatched: Box<DecisionTree<'a>>,
+    },
+}
+impl DecisionTree<'_> {
+    /// Counts the leaves selecting each arm.
+    fn count_leaves(&self, leaf_counts: &mut [usize]) {
+        match self {
+            DecisionTree::Leaf { arm_index, .. } => leaf_counts[*arm_index] += 1,
+            DecisionTree::Destructure { next, .. } => next.count_leaves(leaf_counts),
+            DecisionTree::Switch { arms, .. } => {
+                for (_, _, tree) in arms {
+                    tree.count_leaves(leaf_counts);
+                }
+            }
+            DecisionTree::Test { matched, unmatched, .. } => {
+                matched.count_leaves(leaf_counts);
+                unmatched.count_leaves(leaf_counts);
+            }
+        }
+    }
+}
+
+/// A test of an integer place.
+enum ValueTest<'a> {
+    Literal(&'a semantic::PatternLiteral),
+    /// A range test. Bounds already implied by previous tests are not checked.
+    Range {
+        pattern: &'a semantic::PatternRange,
+        check_start: bool,
+        check_end: bool,
+    },
+}
+
+/// Returns the half-open range of values matched by a literal or a range pattern.
+fn pattern_value_range(pattern: &semantic::Pattern) -> Option<(BigInt, BigInt)> {
+    match pattern {
+        semantic::Pattern::Literal(pattern) => {
+            Some((pattern.literal.value.clone(), &pattern.literal.value + 1))
+        }
+        semantic::Pattern::Range(pattern) => {
+            Some((pattern.start.value.clone(), pattern.end.value.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// The values an integer place may hold in a branch of the decision tree, as sorted disjoint
+/// half-open ranges.
+#[derive(Clone)]
+struct Domain(Vec<(BigInt, BigInt)>);
+impl Domain {
+    /// Returns the domain of all the values of the given type.
+    fn full(ctx: &LoweringContext<'_, '_>, ty: semantic::TypeId) -> Self {
+        Self(vec![match corelib::core_integer_range(ctx.db.upcast(), ty) {
+            Some((min, max)) => (min, max + 1),
+            // Values of other types, such as felt252, are not ordered, so any literal may match.
+            None => {
+                let bound: BigInt = BigInt::one() << 256;
+                (-bound.clone(), bound)
+            }
+        }])
+    }
+
+    fn intersect(&self, start: &BigInt, end: &BigInt) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|(s, e)| (s.max(start).clone(), e.min(end).clone()))
+                .filter(|(s, e)| s < e)
+                .collect(),
+        )
+    }
+
+    fn subtract(&self, start: &BigInt, end: &BigInt) -> Self {
+        Self(
+            self.0
+                .iter()
+                .flat_map(|(s, e)| {
+                    [(s.clone(), e.min(start).clone()), (s.max(end).clone(), e.clone())]
+                })
+                .filter(|(s, e)| s < e)
+                .collect(),
+        )
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The domains of the places tested so far.
+type Domains = UnorderedHashMap<PlaceId, Domain>;
+
+/// A decision on the path to a node of the decision tree, used to describe values no arm covers.
+enum Decision {
+    Variant(semantic::ConcreteVariant, PlaceId),
+    Tuple(Vec<PlaceId>),
+    Struct(SmolStr, Vec<(SmolStr, PlaceId)>),
+}
+
+/// The lowered value of a place in a branch of the decision tree.
+#[derive(Clone)]
+enum PlaceValue {
+    /// The place is held by a variable.
+    Var(VariableId),
+    /// The variable of the place was consumed by destructuring it into its members.
+    Destructured(Vec<PlaceId>),
+    /// The variable of the place was consumed by matching it to a variant.
+    Variant(semantic::ConcreteVariant, PlaceId),
+}
+
+/// The lowered values of the places in a branch of the decision tree.
+type PlaceVars = UnorderedHashMap<PlaceId, PlaceValue>;
+
+/// An arm reached from several leaves of the decision tree. It is lowered once, into a block the
+/// leaves jump to.
+#[derive(Default)]
+struct SharedArm {
+    /// The block of the arm, allocated when the first leaf jumping to it is lowered.
+    block_id: Option<BlockId>,
+    /// The variables bound by the arm pattern, with the variables holding them in the arm block.
+    vars: Vec<(semantic::LocalVariable, VariableId)>,
+}
+
+/// State of the lowering of a single match expression.
+struct MatchLowering<'a> {
+    arms: &'a [semantic::MatchArm],
+    location: StableLocation,
+    places: Vec<Place>,
+    decisions: Vec<(PlaceId, Decision)>,
+    /// For each arm, whether it is shared by several leaves.
+    shared_arms: Vec<Option<SharedArm>>,
+    /// The sealed blocks of the lowered arms.
+    sealed_blocks: Vec<SealedBlockBuilder>,
+}
+impl<'a> MatchLowering<'a> {
+    fn new(arms: &'a [semantic::MatchArm], location: StableLocation) -> Self {
+        Self {
+            arms,
+            location,
+            places: vec![],
+            decisions: vec![],
+            shared_arms: vec![],
+            sealed_blocks: vec![],
+        }
+    }
+
+    fn add_place(&mut self, ty: semantic::TypeId, location: StableLocation) -> PlaceId {
+        self.places.push(Place { ty, location });
+        self.places.len() - 1
+    }
+
+    /// Compiles a match without arms, which is only exhaustive for an empty enum.
+    fn compile_empty_match(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        root: PlaceId,
+    ) -> LoweringResult<DecisionTree<'a>> {
+        let (_, long_ty) = peel_snapshots(ctx.db.upcast(), self.places[root].ty);
+        if let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) = long_ty {
+            let variants = ctx
+                .db
+                .concrete_enum_variants(concrete_enum_id)
+                .map_err(LoweringFlowError::Failed)?;
+            if variants.is_empty() {
+                return Ok(DecisionTree::Switch { place: root, concrete_enum_id, arms: vec![] });
+            }
+        }
+        self.compile(ctx, vec![], Domains::default())
+    }
+
+    /// Compiles the rows of a pattern matrix into a decision tree.
+    fn compile(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        rows: Vec<Row<'a>>,
+        domains: Domains,
+    ) -> LoweringResult<DecisionTree<'a>> {
+        let Some(first_row) = rows.first() else {
+            let missing = self.describe_place(ctx, 0);
+            return Err(LoweringFlowError::Failed(
+                ctx.diagnostics.report_by_location(self.location, MissingMatchArm(missing)),
+            ));
+        };
+        let Some(&(place, pattern)) = first_row.cells.first() else {
+            return Ok(DecisionTree::Leaf {
+                arm_index: first_row.arm_index,
+                bindings: first_row.bindings.clone(),
+            });
+        };
+        match pattern {
+            semantic::Pattern::Struct(_) | semantic::Pattern::Tuple(_) => {
+                self.compile_destructure(ctx, rows, domains, place)
+            }
+            semantic::Pattern::EnumVariant(pattern) => {
+                self.compile_switch(ctx, rows, domains, place, pattern.variant.concrete_enum_id)
+            }
+            semantic::Pattern::Literal(_) | semantic::Pattern::Range(_) => {
+                self.compile_test(ctx, rows, domains, place, pattern)
+            }
+            semantic::Pattern::Variable(_) | semantic::Pattern::Otherwise(_) => {
+                unreachable!("Irrefutable patterns are bindings.")
+            }
+        }
+    }
+
+    /// Compiles a destructure of a struct or tuple place.
+    fn compile_destructure(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        rows: Vec<Row<'a>>,
+        domains: Domains,
+        place: PlaceId,
+    ) -> LoweringResult<DecisionTree<'a>> {
+        let (n_snapshots, long_ty) = peel_snapshots(ctx.db.upcast(), self.places[place].ty);
+        let (member_tys, struct_members) = match long_ty {
+            TypeLongId::Tuple(tys) => (tys, None),
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                let members = ctx
+                    .db
+                    .concrete_struct_members(concrete_struct_id)
+                    .map_err(LoweringFlowError::Failed)?;
+                let name = concrete_struct_id.struct_id(ctx.db.upcast()).name(ctx.db.upcast());
+                let tys = members.values().map(|member| member.ty).collect();
+                (tys, Some((name, members)))
+            }
+            _ => {
+                unreachable!("Semantic model should have made sure the type is a struct or tuple.")
+            }
+        };
+        let members = member_tys
+            .into_iter()
+            .map(|ty| {
+                self.add_place(wrap_in_snapshots(ctx.db.upcast(), ty, n_snapshots), self.location)
+            })
+            .collect_vec();
+
+        let rows = rows
+            .into_iter()
+            .map(|mut row| {
+                match row.take_cell(place) {
+                    Some((index, semantic::Pattern::Tuple(pattern))) => row.insert_cells(
+                        index,
+                        zip_eq(
+                            members.iter().copied(),
+                            pattern.field_patterns.iter().map(|p| &**p),
+                        ),
+                    ),
+                    Some((index, semantic::Pattern::Struct(pattern))) => {
+                        let (_, struct_members) = struct_members.as_ref().unwrap();
+                        row.insert_cells(
+                            index,
+                            pattern.field_patterns.iter().map(|(member, pattern)| {
+                                let member_index = struct_members
+                                    .values()
+                                    .position(|struct_member| struct_member.id == member.id)
+                                    .unwrap();
+                                (members[member_index], &**pattern)
+                            }),
+                        )
+                    }
+                    Some(_) => unreachable!("Only struct and tuple patterns match this place."),
+                    None => {}
+                }
+                row
+            })
+            .collect();
+
+        let decision = match &struct_members {
+            Some((name, struct_members)) => Decision::Struct(
+                name.clone(),
+                zip_eq(struct_members.keys().cloned(), members.iter().copied()).collect(),
+            ),
+            None => Decision::Tuple(members.clone()),
+        };
+        self.decisions.push((place, decision));
+        let next = self.compile(ctx, rows, domains);
+        self.decisions.pop();
+        Ok(DecisionTree::Destructure { place, members, next: Box::new(next?) })
+    }
+
+    /// Compiles a match of an enum place on its variants.
+    fn compile_switch(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        rows: Vec<Row<'a>>,
+        domains: Domains,
+        place: PlaceId,
+        concrete_enum_id: semantic::ConcreteEnumId,
+    ) -> LoweringResult<DecisionTree<'a>> {
+        let (n_snapshots, _) = peel_snapshots(ctx.db.upcast(), self.places[place].ty);
+        let variants =
+            ctx.db.concrete_enum_variants(concrete_enum_id).map_err(LoweringFlowError::Failed)?;
+        let mut arms = vec![];
+        for variant in variants {
+            let variant_patterns = rows.iter().filter_map(|row| match row.cell(place) {
+                Some(semantic::Pattern::EnumVariant(pattern)) if pattern.variant == variant => {
+                    Some(pattern)
+                }
+                _ => None,
+            });
+            // The inner value is located at the inner pattern of the first arm of the variant.
+            let location = variant_patterns
+                .map(|pattern| ctx.get_location(pattern.inner_pattern.stable_ptr().untyped()))
+                .next()
+                .unwrap_or(self.location);
+            let inner = self
+                .add_place(wrap_in_snapshots(ctx.db.upcast(), variant.ty, n_snapshots), location);
+
+            let variant_rows = rows
+                .iter()
+                .filter_map(|row| {
+                    let mut row = row.clone();
+                    match row.take_cell(place) {
+                        Some((index, semantic::Pattern::EnumVariant(pattern)))
+                            if pattern.variant == variant =>
+                        {
+                            row.insert_cells(index, [(inner, &*pattern.inner_pattern)]);
+                        }
+                        Some(_) => return None,
+                        None => {}
+                    }
+                    Some(row)
+                })
+                .collect();
+
+            self.decisions.push((place, Decision::Variant(variant.clone(), inner)));
+            let tree = self.compile(ctx, variant_rows, domains.clone());
+            self.decisions.pop();
+            arms.push((variant, inner, tree?));
+        }
+        Ok(DecisionTree::Switch { place, concrete_enum_id, arms })
+    }
+
+    /// Compiles a test of an integer place against a literal or a range pattern. Branches that
+    /// previous tests have ruled out are omitted.
+    fn compile_test(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        rows: Vec<Row<'a>>,
+        domains: Domains,
+        place: PlaceId,
+        pattern: &'a semantic::Pattern,
+    ) -> LoweringResult<DecisionTree<'a>> {
+        let ty = self.places[place].ty;
+        let (start, end) = pattern_value_range(pattern).unwrap();
+        let domain = domains.get(&place).cloned().unwrap_or_else(|| Domain::full(ctx, ty));
+
+        let mut branches = vec![];
+        for branch_domain in [domain.intersect(&start, &end), domain.subtract(&start, &end)] {
+            if branch_domain.is_empty() {
+                branches.push(None);
+                continue;
+            }
+            let branch_rows = rows
+                .iter()
+                .filter_map(|row| {
+                    let mut row = row.clone();
+                    let Some((index, pattern)) = row.take_cell(place) else {
+                        return Some(row);
+                    };
+                    let (start, end) = pattern_value_range(pattern).unwrap();
+                    if branch_domain.intersect(&start, &end).is_empty() {
+                        // The pattern matches no possible value.
+                        return None;
+                    }
+                    if !branch_domain.subtract(&start, &end).is_empty() {
+                        // The pattern is yet to be decided.
+                        row.cells.insert(index, (place, pattern));
+                    }
+                    Some(row)
+                })
+                .collect();
+            let mut branch_domains = domains.clone();
+            branch_domains.insert(place, branch_domain);
+            branches.push(Some(self.compile(ctx, branch_rows, branch_domains)?));
+        }
+
+        let [matched, unmatched]: [_; 2] = branches.try_into().ok().unwrap();
+        let (matched, unmatched) = match (matched, unmatched) {
+            (Some(matched), Some(unmatched)) => (matched, unmatched),
+            (Some(tree), None) | (None, Some(tree)) => return Ok(tree),
+            (None, None) => unreachable!("The domain of a place is never empty."),
+        };
+        let (min, _) = domain.0.first().unwrap();
+        let (_, max) = domain.0.last().unwrap();
+        let test = match pattern {
+            semantic::Pattern::Literal(pattern) => {
+                if ty != corelib::core_felt252_ty(ctx.db.upcast())
+                    && corelib::core_integer_to_felt252(ctx.db.upcast(), ty).is_none()
+                {
+                    return Err(LoweringFlowError::Failed(
+                        ctx.diagnostics
+                            .report(pattern.stable_ptr.untyped(), UnsupportedMatchedValue),
+                    ));
+                }
+                ValueTest::Literal(pattern)
+            }
+            semantic::Pattern::Range(pattern) => {
+                ValueTest::Range { pattern, check_start: *min < start, check_end: *max > end }
+            }
+            _ => unreachable!(),
+        };
+        Ok(DecisionTree::Test {
+            place,
+            test,
+            matched: Box::new(matched),
+            unmatched: Box::new(unmatched),
+        })
+    }
+
+    /// Describes the values of a place on the current path of the decision tree, as a pattern.
+    fn describe_place(&self, ctx: &LoweringContext<'_, '_>, place: PlaceId) -> String {
+        let Some((_, decision)) =
+            self.decisions.iter().rev().find(|(decision_place, _)| *decision_place == place)
+        else {
+            return "_".into();
+        };
+        match decision {
+            Decision::Variant(variant, inner) => {
+                let enum_id = variant.concrete_enum_id.enum_id(ctx.db.upcast());
+                format!(
+                    "{}::{}({})",
+                    enum_id.name(ctx.db.upcast()),
+                    variant.id.name(ctx.db.upcast()),
+                    self.describe_place(ctx, *inner)
+                )
+            }
+            Decision::Tuple(members) => format!(
+                "({})",
+                members.iter().map(|member| self.describe_place(ctx, *member)).join(", ")
+            ),
+            Decision::Struct(name, members) => format!(
+                "{name} {{ {} }}",
+                members
+                    .iter()
+                    .map(|(member_name, member)| format!(
+                        "{member_name}: {}",
+                        self.describe_place(ctx, *member)
+                    ))
+                    .join(", ")
+            ),
+        }
+    }
+
+    /// Lowers the decision tree into the block of the match expression.
+    fn lower_root(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        tree: DecisionTree<'a>,
+        mut vars: PlaceVars,
+    ) -> LoweringResult<LoweredExpr> {
+        match tree {
+            DecisionTree::Leaf { arm_index, bindings } => {
+                self.bind(ctx, builder, &vars, &bindings)?;
+                lower_expr(ctx, builder, self.arms[arm_index].expression)
+            }
+            DecisionTree::Destructure { place, members, next } => {
+                self.destructure(ctx, builder, &mut vars, place, members);
+                self.lower_root(ctx, builder, *next, vars)
+            }
+            DecisionTree::Switch { .. } | DecisionTree::Test { .. } => {
+                let match_info = self.lower_branches(ctx, builder, tree, vars)?;
+                self.lower_shared_arms(ctx, builder)?;
+                let sealed_blocks = std::mem::take(&mut self.sealed_blocks);
+                builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, self.location)
+            }
+        }
+    }
+
+    /// Lowers a subtree of the decision tree into the block of `builder`.
+    fn lower_subtree(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        mut builder: BlockBuilder,
+        tree: DecisionTree<'a>,
+        mut vars: PlaceVars,
+    ) -> Maybe<()> {
+        match tree {
+            DecisionTree::Leaf { arm_index, bindings } => {
+                if let Err(err) = self.bind(ctx, &mut builder, &vars, &bindings) {
+                    let sealed_block = lowering_flow_error_to_sealed_block(ctx, builder, err)?;
+                    self.sealed_blocks.push(sealed_block);
+                } else if self.shared_arms[arm_index].is_some() {
+                    self.goto_shared_arm(ctx, builder, arm_index);
+                } else {
+                    let sealed_block =
+                        lower_tail_expr(ctx, builder, self.arms[arm_index].expression)?;
+                    self.sealed_blocks.push(sealed_block);
+                }
+            }
+            DecisionTree::Destructure { place, members, next } => {
+                self.destructure(ctx, &mut builder, &mut vars, place, members);
+                self.lower_subtree(ctx, builder, *next, vars)?;
+            }
+            DecisionTree::Switch { .. } | DecisionTree::Test { .. } => {
+                match self.lower_branches(ctx, &mut builder, tree, vars) {
+                    Ok(match_info) => builder.end_with_match(ctx, match_info),
+                    Err(err) => {
+                        let sealed_block = lowering_flow_error_to_sealed_block(ctx, builder, err)?;
+                        self.sealed_blocks.push(sealed_block);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Lowers the branches of a switch or a test node, and returns the match ending the block of
+    /// `builder`.
+    fn lower_branches(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        tree: DecisionTree<'a>,
+        vars: PlaceVars,
+    ) -> LoweringResult<MatchInfo> {
+        match tree {
+            DecisionTree::Switch { place, concrete_enum_id, arms } => {
+                let input = self.place_var(ctx, builder, &vars, place);
+                let consumed = ctx.variables[input].duplicatable.is_err();
+                let mut match_arms = vec![];
+                for (variant, inner, tree) in arms {
+                    let subscope = create_subscope_with_bound_refs(ctx, builder);
+                    let block_id = subscope.block_id;
+                    let Place { ty, location } = self.places[inner];
+                    let var = ctx.new_var(VarRequest { ty, location });
+                    let mut vars = vars.clone();
+                    vars.insert(inner, PlaceValue::Var(var));
+                    if consumed {
+                        vars.insert(place, PlaceValue::Variant(variant.clone(), inner));
+                    }
+                    self.lower_subtree(ctx, subscope, tree, vars)
+                        .map_err(LoweringFlowError::Failed)?;
+                    match_arms.push(MatchArm { variant_id: variant, block_id, var_ids: vec![var] });
+                }
+                Ok(MatchInfo::Enum(MatchEnumInfo {
+                    concrete_enum_id,
+                    input,
+                    arms: match_arms,
+                    location: self.location.into(),
+                }))
+            }
+            DecisionTree::Test { place, test: ValueTest::Literal(pattern), matched, unmatched } => {
+                let semantic_db = ctx.db.upcast();
+                let felt252_ty = corelib::core_felt252_ty(semantic_db);
+                let location = ctx.get_location(pattern.stable_ptr.untyped());
+                let mut value = self.place_var(ctx, builder, &vars, place);
+                let ty = self.places[place].ty;
+                if ty != felt252_ty {
+                    let function = corelib::core_integer_to_felt252(semantic_db, ty).unwrap();
+                    value =
+                        self.lower_call(ctx, builder, function, vec![value], felt252_ty, location);
+                }
+                // Compares the value to the literal by checking whether their difference is zero.
+                if !pattern.literal.value.is_zero() {
+                    let literal = generators::Literal {
+                        value: pattern.literal.value.clone(),
+                        ty: felt252_ty,
+                        location,
+                    }
+                    .add(ctx, &mut builder.statements);
+                    value = self.lower_call(
+                        ctx,
+                        builder,
+                        corelib::felt252_sub(semantic_db),
+                        vec![value, literal],
+                        felt252_ty,
+                        location,
+                    );
+                }
+
+                let matched_subscope = create_subscope_with_bound_refs(ctx, builder);
+                let matched_block_id = matched_subscope.block_id;
+                let unmatched_subscope = create_subscope_with_bound_refs(ctx, builder);
+                let unmatched_block_id = unmatched_subscope.block_id;
+                let non_zero_var = ctx.new_var(VarRequest {
+                    ty: corelib::core_nonzero_ty(semantic_db, felt252_ty),
+                    location: self.location,
+                });
+                self.lower_subtree(ctx, matched_subscope, *matched, vars.clone())
+                    .map_err(LoweringFlowError::Failed)?;
+                self.lower_subtree(ctx, unmatched_subscope, *unmatched, vars)
+                    .map_err(LoweringFlowError::Failed)?;
+
+                Ok(MatchInfo::Extern(MatchExternInfo {
+                    function: corelib::core_felt252_is_zero(semantic_db).lowered(ctx.db),
+                    inputs: vec![value],
+                    arms: vec![
+                        MatchArm {
+                            variant_id: corelib::jump_nz_zero_variant(semantic_db),
+                            block_id: matched_block_id,
+                            var_ids: vec![],
+                        },
+                        MatchArm {
+                            variant_id: corelib::jump_nz_nonzero_variant(semantic_db),
+                            block_id: unmatched_block_id,
+                            var_ids: vec![non_zero_var],
+                        },
+                    ],
+                    location: self.location.into(),
+                }))
+            }
+            DecisionTree::Test {
+                place,
+                test: ValueTest::Range { pattern, check_start, check_end },
+                matched,
+                unmatched,
+            } => {
+                let semantic_db = ctx.db.upcast();
+                let location = ctx.get_location(pattern.stable_ptr.untyped());
+                let value = self.place_var(ctx, builder, &vars, place);
+                // A bool that is `in_range_variant` iff the value is in the range.
+                let (condition, in_range_variant) = match (check_start, check_end) {
+                    (true, false) => (
+                        self.lower_lt(ctx, builder, pattern, value, &pattern.start, location)?,
+                        corelib::false_variant(semantic_db),
+                    ),
+                    (false, true) => (
+                        self.lower_lt(ctx, builder, pattern, value, &pattern.end, location)?,
+                        corelib::true_variant(semantic_db),
+                    ),
+                    (true, true) => {
+                        // Lowers the condition to
+                        // `if value < start { false } else { value < end }`.
+                        let below_start =
+                            self.lower_lt(ctx, builder, pattern, value, &pattern.start, location)?;
+                        let mut subscope_below = create_subscope_with_bound_refs(ctx, builder);
+                        let below_block_id = subscope_below.block_id;
+                        let false_var = create_bool(
+                            ctx,
+                            &mut subscope_below,
+                            corelib::false_variant(semantic_db),
+                            location,
+                        );
+                        let mut subscope_not_below = create_subscope_with_bound_refs(ctx, builder);
+                        let not_below_block_id = subscope_not_below.block_id;
+                        let below_end = self.lower_lt(
+                            ctx,
+                            &mut subscope_not_below,
+                            pattern,
+                            value,
+                            &pattern.end,
+                            location,
+                        )?;
+                        let match_info = bool_match_info(
+                            ctx,
+                            below_start,
+                            not_below_block_id,
+                            below_block_id,
+                            location,
+                        );
+                        let in_range = builder
+                            .merge_and_end_with_match(
+                                ctx,
+                                match_info,
+                                vec![
+                                    subscope_below.goto_callsite(Some(false_var)),
+                                    subscope_not_below.goto_callsite(Some(below_end)),
+                                ],
+                                location,
+                            )?
+                            .var(ctx, builder)?;
+                        (in_range, corelib::true_variant(semantic_db))
+                    }
+                    (false, false) => unreachable!("Decided tests are omitted."),
+                };
+
+                let matched_subscope = create_subscope_with_bound_refs(ctx, builder);
+                let matched_block_id = matched_subscope.block_id;
+                let unmatched_subscope = create_subscope_with_bound_refs(ctx, builder);
+                let unmatched_block_id = unmatched_subscope.block_id;
+                self.lower_subtree(ctx, matched_subscope, *matched, vars.clone())
+                    .map_err(LoweringFlowError::Failed)?;
+                self.lower_subtree(ctx, unmatched_subscope, *unmatched, vars)
+                    .map_err(LoweringFlowError::Failed)?;
+
+                let (false_block_id, true_block_id) =
+                    if in_range_variant == corelib::true_variant(semantic_db) {
+                        (unmatched_block_id, matched_block_id)
+                    } else {
+                        (matched_block_id, unmatched_block_id)
+                    };
+                Ok(bool_match_info(ctx, condition, false_block_id, true_block_id, self.location))
+            }
+            DecisionTree::Leaf { .. } | DecisionTree::Destructure { .. } => {
+                unreachable!("Only switch and test nodes have branches.")
+            }
+        }
+    }
+
+    /// Lowers a call to `PartialOrd::lt` of a range pattern, comparing the value to a bound.
+    fn lower_lt(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        pattern: &semantic::PatternRange,
+        value: VariableId,
+        bound: &semantic::ExprLiteral,
+        location: StableLocation,
+    ) -> LoweringResult<VariableId> {
+        let bound = lower_expr_literal(ctx, bound, builder)?.var(ctx, builder)?;
+        let bool_ty = corelib::core_bool_ty(ctx.db.upcast());
+        Ok(self.lower_call(
+            ctx,
+            builder,
+            pattern.lt_function,
+            vec![value, bound],
+            bool_ty,
+            location,
+        ))
+    }
+
+    /// Lowers a call to a function with a single return value.
+    fn lower_call(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        function: semantic::FunctionId,
+        inputs: Vec<VariableId>,
+        ret_ty: semantic::TypeId,
+        location: StableLocation,
+    ) -> VariableId {
+        generators::Call {
+            function: function.lowered(ctx.db),
+            inputs,
+            extra_ret_tys: vec![],
+            ret_tys: vec![ret_ty],
+            location,
+        }
+        .add(ctx, &mut builder.statements)
+        .returns
+        .into_iter()
+        .next()
+        .unwrap()
+    }
+
+    /// Destructures a struct or tuple place into its members.
+    fn destructure(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        vars: &mut PlaceVars,
+        place: PlaceId,
+        members: Vec<PlaceId>,
+    ) {
+        let input = self.place_var(ctx, builder, vars, place);
+        let var_reqs = members
+            .iter()
+            .map(|member| VarRequest { ty: self.places[*member].ty, location: self.location })
+            .collect();
+        let outputs =
+            generators::StructDestructure { input, var_reqs }.add(ctx, &mut builder.statements);
+        for (member, output) in zip_eq(&members, outputs) {
+            vars.insert(*member, PlaceValue::Var(output));
+        }
+        if ctx.variables[input].duplicatable.is_err() {
+            vars.insert(place, PlaceValue::Destructured(members));
+        }
+    }
+
+    /// Returns a variable holding the value of a place, reconstructing the value if its variable
+    /// was consumed.
+    fn place_var(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        vars: &PlaceVars,
+        place: PlaceId,
+    ) -> VariableId {
+        match &vars[&place] {
+            PlaceValue::Var(var) => *var,
+            PlaceValue::Destructured(members) => {
+                let inputs = members
+                    .iter()
+                    .map(|member| self.place_var(ctx, builder, vars, *member))
+                    .collect();
+                generators::StructConstruct {
+                    inputs,
+                    ty: self.places[place].ty,
+                    location: self.location,
+                }
+                .add(ctx, &mut builder.statements)
+            }
+            PlaceValue::Variant(variant, inner) => {
+                let input = self.place_var(ctx, builder, vars, *inner);
+                generators::EnumConstruct {
+                    input,
+                    variant: variant.clone(),
+                    location: self.location,
+                }
+                .add(ctx, &mut builder.statements)
+            }
+        }
+    }
+
+    /// Binds the irrefutable patterns of a selected arm.
+    fn bind(
+        &self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut BlockBuilder,
+        vars: &PlaceVars,
+        bindings: &[(PlaceId, &semantic::Pattern)],
+    ) -> LoweringResult<()> {
+        for (place, pattern) in bindings {
+            let var = self.place_var(ctx, builder, vars, *place);
+            lower_single_pattern(ctx, builder, pattern, LoweredExpr::AtVariable(var))?;
+        }
+        Ok(())
+    }
+
+    /// Ends the block of a leaf with a jump to the block of its shared arm.
+    fn goto_shared_arm(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        mut builder: BlockBuilder,
+        arm_index: usize,
+    ) {
+        let arm = &self.arms[arm_index];
+        let shared_arm = self.shared_arms[arm_index].as_mut().unwrap();
+        let block_id = *shared_arm.block_id.get_or_insert_with(|| {
+            shared_arm.vars = arm
+                .pattern
+                .variables()
+                .into_iter()
+                .map(|pattern_variable| {
+                    let var = ctx.new_var(VarRequest {
+                        ty: pattern_variable.var.ty,
+                        location: ctx.get_location(pattern_variable.stable_ptr.untyped()),
+                    });
+                    (pattern_variable.var.clone(), var)
+                })
+                .collect();
+            ctx.blocks.alloc_empty()
+        });
+        let mut remapping = VarRemapping::default();
+        for (semantic_var, var) in &shared_arm.vars {
+            let bound_var =
+                builder.get_semantic(ctx, semantic::VarId::Local(semantic_var.id), self.location);
+            remapping.insert(*var, bound_var);
+        }
+        builder.goto(ctx, block_id, remapping);
+    }
+
+    /// Lowers the arms shared by several leaves.
+    fn lower_shared_arms(
+        &mut self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &BlockBuilder,
+    ) -> LoweringResult<()> {
+        for (arm, shared_arm) in zip_eq(self.arms, std::mem::take(&mut self.shared_arms)) {
+            let Some(SharedArm { block_id: Some(block_id), vars }) = shared_arm else {
+                continue;
+            };
+            let mut subscope = builder.child_block_builder(block_id);
+            for (semantic_var, var) in vars {
+                let semantic_var = semantic::Variable::Local(semantic_var);
+                subscope.put_semantic(semantic_var.id(), var);
+                ctx.semantic_defs.insert(semantic_var.id(), semantic_var);
+            }
+            let sealed_block = lower_tail_expr(ctx, subscope, arm.expression)
+                .map_err(LoweringFlowError::Failed)?;
+            self.sealed_blocks.push(sealed_block);
+        }
+        Ok(())
+    }
+}
+
+/// Returns a match on a bool variable.
+fn bool_match_info(
+    ctx: &mut LoweringContext<'_, '_>,
+    input: VariableId,
+    false_block_id: BlockId,
+    true_block_id: BlockId,
+    location: StableLocation,
+) -> MatchInfo {
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = corelib::unit_ty(semantic_db);
+    MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id: corelib::core_bool_enum(semantic_db),
+        input,
+        arms: vec![
+            MatchArm {
+                variant_id: corelib::false_variant(semantic_db),
+                block_id: false_block_id,
+                var_ids: vec![ctx.new_var(VarRequest { ty: unit_ty, location })],
+            },
+            MatchArm {
+                variant_id: corelib::true_variant(semantic_db),
+                block_id: true_block_id,
+                var_ids: vec![ctx.new_var(VarRequest { ty: unit_ty, location })],
+            },
+        ],
+        location: location.into(),
+    })
+}
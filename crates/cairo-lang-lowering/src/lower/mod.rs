@@ -6,17 +6,16 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::{chain, zip_eq, Itertools};
-use num_bigint::BigInt;
-use num_traits::Zero;
+use num_bigint::{BigInt, Sign};
 use semantic::corelib::{
-    core_felt252_is_zero, core_felt252_ty, core_nonzero_ty, get_core_function_id,
-    get_core_ty_by_name, jump_nz_nonzero_variant, jump_nz_zero_variant, never_ty, unit_ty,
+    closure_call_function, core_bool_enum, core_felt252_ty, core_submodule, false_variant,
+    get_core_function_id, get_core_ty_by_name, get_function_id, never_ty, true_variant, unit_ty,
 };
 use semantic::items::enm::SemanticEnumEx;
 use semantic::items::structure::SemanticStructEx;
 use semantic::types::{peel_snapshots, wrap_in_snapshots};
 use semantic::{
-    ConcreteTypeId, ExprFunctionCallArg, ExprPropagateError, ExprVarMemberPath, TypeLongId,
+    ExprFunctionCallArg, ExprPropagateError, ExprVarMemberPath, GenericArgumentId, TypeLongId,
 };
 use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
 
@@ -28,12 +27,13 @@ use self::context::{
 use self::external::{extern_facade_expr, extern_facade_return_tys};
 use self::logical_op::lower_logical_op;
 use self::lower_if::lower_expr_if;
+use self::lower_match::{is_irrefutable, lower_match_arms};
 use crate::blocks::FlatBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::*;
 use crate::ids::{
     FunctionLongId, FunctionWithBodyId, FunctionWithBodyLongId, GeneratedFunction,
-    SemanticFunctionIdEx, Signature,
+    GeneratedFunctionKey, SemanticFunctionIdEx, Signature,
 };
 use crate::lower::context::{LoweringResult, VarRequest};
 use crate::{
@@ -46,6 +46,7 @@ mod external;
 pub mod generators;
 mod logical_op;
 mod lower_if;
+mod lower_match;
 pub mod refs;
 pub mod usage;
 
@@ -56,7 +57,7 @@ mod generated_test;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MultiLowering {
     pub main_lowering: FlatLowered,
-    pub generated_lowerings: OrderedHashMap<semantic::ExprId, FlatLowered>,
+    pub generated_lowerings: OrderedHashMap<GeneratedFunctionKey, FlatLowered>,
 }
 
 /// Lowers a semantic free function.
@@ -65,8 +66,8 @@ pub fn lower_semantic_function(
     semantic_function_id: defs::ids::FunctionWithBodyId,
 ) -> Maybe<MultiLowering> {
     db.function_declaration_diagnostics(semantic_function_id)
-        .is_diagnostic_free()
-        .and_then(|()| db.function_body_diagnostics(semantic_function_id).is_diagnostic_free())?;
+        .check_error_free()
+        .and_then(|()| db.function_body_diagnostics(semantic_function_id).check_error_free())?;
     let mut encapsulating_ctx = EncapsulatingLoweringContext::new(db, semantic_function_id)?;
     let function_id = db
         .intern_lowering_function_with_body(FunctionWithBodyLongId::Semantic(semantic_function_id));
@@ -160,14 +161,16 @@ pub fn lower_loop_function(
     encapsulating_ctx: &mut EncapsulatingLoweringContext<'_>,
     function_id: FunctionWithBodyId,
     signature: Signature,
-    expr: &semantic::ExprLoop,
+    loop_expr_id: semantic::ExprId,
 ) -> Maybe<FlatLowered> {
     let mut ctx = LoweringContext::new(encapsulating_ctx, function_id, signature)?;
-    ctx.current_loop_expr = Some(expr.clone());
+    ctx.current_loop_expr_id = Some(loop_expr_id);
+    let loop_expr = ctx.function_body.exprs[loop_expr_id].clone();
 
     // Fetch body block expr.
     let semantic_block =
-        extract_matches!(&ctx.function_body.exprs[expr.body], semantic::Expr::Block).clone();
+        extract_matches!(&ctx.function_body.exprs[loop_body(&loop_expr)], semantic::Expr::Block)
+            .clone();
 
     // Initialize builder.
     let root_block_id = alloc_empty_block(&mut ctx);
@@ -187,12 +190,20 @@ pub fn lower_loop_function(
         .collect_vec();
 
     let root_ok = (|| {
-        let block_expr = (|| {
-            lower_expr_block(&mut ctx, &mut builder, &semantic_block)?;
-            // Add recursive call.
-            let signature = ctx.signature.clone();
-            call_loop_func(&mut ctx, signature, &mut builder, expr)
-        })();
+        let block_expr = match &loop_expr {
+            semantic::Expr::While(expr) => {
+                lower_while_loop_iteration(&mut ctx, &mut builder, expr, loop_expr_id)
+            }
+            semantic::Expr::For(expr) => {
+                lower_for_loop_iteration(&mut ctx, &mut builder, expr, loop_expr_id)
+            }
+            _ => (|| {
+                lower_expr_block(&mut ctx, &mut builder, &semantic_block)?;
+                // Add recursive call.
+                let signature = ctx.signature.clone();
+                call_loop_func(&mut ctx, signature, &mut builder, loop_expr_id)
+            })(),
+        };
         let block_sealed = lowered_expr_to_block_scope_end(&mut ctx, builder, block_expr)?;
         match block_sealed {
             SealedBlockBuilder::GotoCallsite { mut builder, expr } => {
@@ -224,6 +235,159 @@ pub fn lower_loop_function(
     })
 }
 
+/// Lowers the body of a closure into [FlatLowered].
+/// The generated function gets the closure, i.e. the struct of its captured variables, and a tuple
+/// of the closure parameters.
+pub fn lower_closure_function(
+    encapsulating_ctx: &mut EncapsulatingLoweringContext<'_>,
+    function_id: FunctionWithBodyId,
+    closure_expr: &semantic::ExprClosure,
+) -> Maybe<FlatLowered> {
+    let db = encapsulating_ctx.db;
+    let signature = db
+        .concrete_function_signature(closure_call_function(db.upcast(), closure_expr.ty, true))?
+        .into();
+    let mut ctx = LoweringContext::new(encapsulating_ctx, function_id, signature)?;
+    let location = ctx.get_location(closure_expr.stable_ptr.untyped());
+
+    // Initialize builder.
+    let root_block_id = alloc_empty_block(&mut ctx);
+    let mut builder = BlockBuilder::root(&mut ctx, root_block_id);
+
+    let parameters = ctx
+        .signature
+        .params
+        .clone()
+        .into_iter()
+        .map(|param| ctx.new_var(VarRequest { ty: param.ty(), location }))
+        .collect_vec();
+    let [closure_var, args_var] = parameters[..] else {
+        unreachable!("`FnOnce::call_once` has exactly two parameters.");
+    };
+
+    // Bind the captured variables.
+    let captured_vars = generators::StructDestructure {
+        input: closure_var,
+        var_reqs: closure_expr
+            .captured_vars
+            .iter()
+            .map(|var| VarRequest { ty: var.ty, location })
+            .collect(),
+    }
+    .add(&mut ctx, &mut builder.statements);
+    for (semantic_var, var) in zip_eq(&closure_expr.captured_vars, captured_vars) {
+        builder.put_semantic(semantic_var.var, var);
+    }
+
+    // Bind the closure parameters.
+    let param_vars = generators::StructDestructure {
+        input: args_var,
+        var_reqs: closure_expr
+            .params
+            .iter()
+            .map(|param| VarRequest {
+                ty: param.var.ty,
+                location: ctx.get_location(param.var.id.stable_ptr(db.upcast()).untyped()),
+            })
+            .collect(),
+    }
+    .add(&mut ctx, &mut builder.statements);
+    for (param, var) in zip_eq(&closure_expr.params, param_vars) {
+        let semantic_var = semantic::Variable::Local(param.var.clone());
+        builder.put_semantic(semantic_var.id(), var);
+        ctx.semantic_defs.insert(semantic_var.id(), semantic_var);
+    }
+
+    let root_ok =
+        lower_tail_expr(&mut ctx, builder, closure_expr.body).and_then(|block_sealed| {
+            match block_sealed {
+                SealedBlockBuilder::GotoCallsite { mut builder, expr } => {
+                    // Convert to a return.
+                    let var = expr.unwrap_or_else(|| {
+                        generators::StructConstruct {
+                            inputs: vec![],
+                            ty: unit_ty(ctx.db.upcast()),
+                            location,
+                        }
+                        .add(&mut ctx, &mut builder.statements)
+                    });
+                    builder.ret(&mut ctx, var, location)?;
+                }
+                SealedBlockBuilder::Ends(_) => {}
+            }
+            Ok(root_block_id)
+        });
+    let blocks = root_ok
+        .map(|_| ctx.blocks.build().expect("Root block must exist."))
+        .unwrap_or_else(FlatBlocks::new_errored);
+    Ok(FlatLowered {
+        diagnostics: ctx.diagnostics.build(),
+        variables: ctx.variables.variables,
+        blocks,
+        signature: ctx.signature.clone(),
+        parameters,
+    })
+}
+
+/// Lowers the `Fn::call` function of a copyable closure into [FlatLowered].
+/// The generated function gets a snapshot of the closure and a tuple of the closure parameters, and
+/// calls the function of the closure body with a copy of the closure.
+pub fn lower_closure_call_function(
+    encapsulating_ctx: &mut EncapsulatingLoweringContext<'_>,
+    function_id: FunctionWithBodyId,
+    closure_expr: &semantic::ExprClosure,
+) -> Maybe<FlatLowered> {
+    let db = encapsulating_ctx.db;
+    let signature = db
+        .concrete_function_signature(closure_call_function(db.upcast(), closure_expr.ty, false))?
+        .into();
+    let mut ctx = LoweringContext::new(encapsulating_ctx, function_id, signature)?;
+    let location = ctx.get_location(closure_expr.stable_ptr.untyped());
+
+    // Initialize builder.
+    let root_block_id = alloc_empty_block(&mut ctx);
+    let mut builder = BlockBuilder::root(&mut ctx, root_block_id);
+
+    let parameters = ctx
+        .signature
+        .params
+        .clone()
+        .into_iter()
+        .map(|param| ctx.new_var(VarRequest { ty: param.ty(), location }))
+        .collect_vec();
+    let [closure_snapshot_var, args_var] = parameters[..] else {
+        unreachable!("`Fn::call` has exactly two parameters.");
+    };
+
+    // Call the function of the closure body with a copy of the closure.
+    let closure_var = generators::Desnap { input: closure_snapshot_var, location }
+        .add(&mut ctx, &mut builder.statements);
+    let function = ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
+        parent: ctx.concrete_function_id.base_semantic_function(ctx.db),
+        key: GeneratedFunctionKey::Expr(closure_expr.body),
+    }));
+    let call_result = generators::Call {
+        function,
+        inputs: vec![closure_var, args_var],
+        extra_ret_tys: vec![],
+        ret_tys: vec![ctx.signature.return_type],
+        location,
+    }
+    .add(&mut ctx, &mut builder.statements);
+
+    let root_ok = builder.ret(&mut ctx, call_result.returns[0], location).map(|()| root_block_id);
+    let blocks = root_ok
+        .map(|_| ctx.blocks.build().expect("Root block must exist."))
+        .unwrap_or_else(FlatBlocks::new_errored);
+    Ok(FlatLowered {
+        diagnostics: ctx.diagnostics.build(),
+        variables: ctx.variables.variables,
+        blocks,
+        signature: ctx.signature.clone(),
+        parameters,
+    })
+}
+
 /// Lowers a semantic block.
 fn lower_block(
     ctx: &mut LoweringContext<'_, '_>,
@@ -320,8 +484,8 @@ pub fn lower_statement(
         }
         semantic::Statement::Continue(semantic::StatementContinue { stable_ptr }) => {
             log::trace!("Lowering a continue statement.");
-            let loop_expr = ctx.current_loop_expr.clone().unwrap();
-            let lowered_expr = call_loop_func(ctx, ctx.signature.clone(), builder, &loop_expr)?;
+            let loop_expr_id = ctx.current_loop_expr_id.unwrap();
+            let lowered_expr = call_loop_func(ctx, ctx.signature.clone(), builder, loop_expr_id)?;
             let ret_var = lowered_expr.var(ctx, builder)?;
             return Err(LoweringFlowError::Return(ret_var, ctx.get_location(stable_ptr.untyped())));
         }
@@ -356,7 +520,7 @@ fn lower_single_pattern(
 ) -> Result<(), LoweringFlowError> {
     log::trace!("Lowering a single pattern.");
     match pattern {
-        semantic::Pattern::Literal(_) => unreachable!(),
+        semantic::Pattern::Literal(_) | semantic::Pattern::Range(_) => unreachable!(),
         semantic::Pattern::Variable(semantic::PatternVariable {
             name: _,
             var: sem_var,
@@ -457,17 +621,21 @@ fn lower_expr(
         semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, builder),
         semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, builder),
         semantic::Expr::If(expr) => lower_expr_if(ctx, builder, expr),
-        semantic::Expr::Loop(expr) => lower_expr_loop(ctx, expr, builder),
+        semantic::Expr::Loop(_) | semantic::Expr::While(_) | semantic::Expr::For(_) => {
+            lower_expr_loop(ctx, builder, expr_id)
+        }
         semantic::Expr::Var(expr) => {
             let member_path = ExprVarMemberPath::Var(expr.clone());
             log::trace!("Lowering a variable: {:?}", expr.debug(&ctx.expr_formatter));
             Ok(LoweredExpr::Member(member_path, ctx.get_location(expr.stable_ptr.untyped())))
         }
         semantic::Expr::Literal(expr) => lower_expr_literal(ctx, expr, builder),
+        semantic::Expr::StringLiteral(expr) => lower_expr_string_literal(ctx, expr, builder),
         semantic::Expr::MemberAccess(expr) => lower_expr_member_access(ctx, expr, builder),
         semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, builder),
         semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, builder),
         semantic::Expr::PropagateError(expr) => lower_expr_error_propagate(ctx, expr, builder),
+        semantic::Expr::Closure(expr) => lower_expr_closure(ctx, expr, builder),
         semantic::Expr::Missing(semantic::ExprMissing { diag_added, .. }) => {
             Err(LoweringFlowError::Failed(*diag_added))
         }
@@ -513,6 +681,87 @@ fn lower_expr_literal(
     ))
 }
 
+/// Lowers a string literal into a `ByteArray` struct, built from the full 31-byte words of the
+/// string and a pending word holding the remaining bytes.
+fn lower_expr_string_literal(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprStringLiteral,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a string literal: {:?}", expr.debug(&ctx.expr_formatter));
+    let semantic_db = ctx.db.upcast();
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+
+    let bytes31_ty = get_core_ty_by_name(semantic_db, "bytes31".into(), vec![]);
+    let data_array_ty =
+        get_core_ty_by_name(semantic_db, "Array".into(), vec![GenericArgumentId::Type(bytes31_ty)]);
+    let array_module = core_submodule(semantic_db, "array");
+    let array_function = |name: &str| {
+        get_function_id(
+            semantic_db,
+            array_module,
+            name.into(),
+            vec![GenericArgumentId::Type(bytes31_ty)],
+        )
+        .lowered(ctx.db)
+    };
+    let array_new_function = array_function("array_new");
+    let array_append_function = array_function("array_append");
+
+    let mut data_array = generators::Call {
+        function: array_new_function,
+        inputs: vec![],
+        extra_ret_tys: vec![],
+        ret_tys: vec![data_array_ty],
+        location,
+    }
+    .add(ctx, &mut builder.statements)
+    .returns[0];
+
+    let bytes = expr.value.as_bytes();
+    let full_words_len = bytes.len() - bytes.len() % 31;
+    let (full_words, pending_bytes) = bytes.split_at(full_words_len);
+    for word in full_words.chunks(31) {
+        let word = generators::Literal {
+            value: BigInt::from_bytes_be(Sign::Plus, word),
+            ty: bytes31_ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements);
+        data_array = generators::Call {
+            function: array_append_function,
+            inputs: vec![data_array, word],
+            extra_ret_tys: vec![data_array_ty],
+            ret_tys: vec![],
+            location,
+        }
+        .add(ctx, &mut builder.statements)
+        .extra_outputs[0];
+    }
+
+    let pending_word = generators::Literal {
+        value: BigInt::from_bytes_be(Sign::Plus, pending_bytes),
+        ty: core_felt252_ty(semantic_db),
+        location,
+    }
+    .add(ctx, &mut builder.statements);
+    let pending_word_len = generators::Literal {
+        value: pending_bytes.len().into(),
+        ty: get_core_ty_by_name(semantic_db, "u32".into(), vec![]),
+        location,
+    }
+    .add(ctx, &mut builder.statements);
+
+    Ok(LoweredExpr::AtVariable(
+        generators::StructConstruct {
+            inputs: vec![data_array, pending_word, pending_word_len],
+            ty: expr.ty,
+            location,
+        }
+        .add(ctx, &mut builder.statements),
+    ))
+}
+
 fn lower_expr_constant(
     ctx: &mut LoweringContext<'_, '_>,
     expr: &semantic::ExprConstant,
@@ -674,7 +923,7 @@ fn perform_function_call(
                 ),
                 input: call_result.returns[0],
                 arms: vec![],
-                location,
+                location: location.into(),
             })));
         }
 
@@ -696,13 +945,21 @@ fn perform_function_call(
     Ok((call_result.extra_outputs, extern_facade_expr(ctx, ret_ty, call_result.returns, location)))
 }
 
-/// Lowers an expression of type [semantic::ExprLoop].
+/// Lowers a loop expression, i.e. an expression of type [semantic::ExprLoop],
+/// [semantic::ExprWhile] or [semantic::ExprFor].
 fn lower_expr_loop(
     ctx: &mut LoweringContext<'_, '_>,
-    expr: &semantic::ExprLoop,
     builder: &mut BlockBuilder,
+    loop_expr_id: semantic::ExprId,
 ) -> LoweringResult<LoweredExpr> {
-    let usage = &ctx.block_usages.block_usages[expr.body];
+    let loop_expr = ctx.function_body.exprs[loop_expr_id].clone();
+    if let semantic::Expr::For(expr) = &loop_expr {
+        // The iterator is created once, before the loop function is called.
+        let into_iter_var = lower_expr(ctx, builder, expr.into_iter)?.var(ctx, builder)?;
+        builder.put_semantic(expr.into_iter_member_path.base_var(), into_iter_var);
+    }
+    let body = loop_body(&loop_expr);
+    let usage = &ctx.block_usages.block_usages[body];
 
     // Determine signature.
     let params = usage.usage.iter().map(|(_, expr)| expr.clone()).collect_vec();
@@ -711,7 +968,7 @@ fn lower_expr_loop(
     let signature = Signature {
         params,
         extra_rets,
-        return_type: expr.ty,
+        return_type: loop_expr.ty(),
         implicits: vec![],
         panicable: ctx.signature.panicable,
     };
@@ -719,19 +976,204 @@ fn lower_expr_loop(
     // Get the function id.
     let function = ctx.db.intern_lowering_function_with_body(FunctionWithBodyLongId::Generated {
         parent: ctx.semantic_function_id,
-        element: expr.body,
+        key: GeneratedFunctionKey::Expr(body),
     });
 
     // Generate the function.
     let encapsulating_ctx = std::mem::take(&mut ctx.encapsulating_ctx).unwrap();
-    let lowered = lower_loop_function(encapsulating_ctx, function, signature.clone(), expr)
+    let lowered = lower_loop_function(encapsulating_ctx, function, signature.clone(), loop_expr_id)
         .map_err(LoweringFlowError::Failed)?;
     // TODO(spapini): Recursive call.
-    encapsulating_ctx.lowerings.insert(expr.body, lowered);
+    encapsulating_ctx.lowerings.insert(GeneratedFunctionKey::Expr(body), lowered);
     ctx.encapsulating_ctx = Some(encapsulating_ctx);
-    ctx.current_loop_expr = Some(expr.clone());
+    ctx.current_loop_expr_id = Some(loop_expr_id);
+
+    call_loop_func(ctx, signature, builder, loop_expr_id)
+}
+
+/// Lowers an expression of type [semantic::ExprClosure].
+/// The body of the closure is lowered as a generated function, and the closure itself is a struct
+/// of its captured variables. If the closure is copyable, i.e. it captures only snapshots or `Copy`
+/// values, its `Fn::call` function is generated as well.
+fn lower_expr_closure(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprClosure,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a closure expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let inputs = expr
+        .captured_vars
+        .iter()
+        .map(|var| {
+            LoweredExpr::Member(ExprVarMemberPath::Var(var.clone()), location).var(ctx, builder)
+        })
+        .collect::<LoweringResult<Vec<_>>>()?;
+
+    let closure_var = generators::StructConstruct { inputs, ty: expr.ty, location }
+        .add(ctx, &mut builder.statements);
+
+    // Generate the functions.
+    let mut keys = vec![GeneratedFunctionKey::Expr(expr.body)];
+    if ctx.variables[closure_var].duplicatable.is_ok() {
+        keys.push(GeneratedFunctionKey::ClosureCall(expr.body));
+    }
+    for key in keys {
+        let function = ctx.db.intern_lowering_function_with_body(FunctionWithBodyLongId::Generated {
+            parent: ctx.semantic_function_id,
+            key,
+        });
+        let encapsulating_ctx = std::mem::take(&mut ctx.encapsulating_ctx).unwrap();
+        let lowered = match key {
+            GeneratedFunctionKey::Expr(_) => {
+                lower_closure_function(encapsulating_ctx, function, expr)
+            }
+            GeneratedFunctionKey::ClosureCall(_) => {
+                lower_closure_call_function(encapsulating_ctx, function, expr)
+            }
+        };
+        ctx.encapsulating_ctx = Some(encapsulating_ctx);
+        ctx.lowerings.insert(key, lowered.map_err(LoweringFlowError::Failed)?);
+    }
+
+    Ok(LoweredExpr::AtVariable(closure_var))
+}
 
-    call_loop_func(ctx, signature, builder, expr)
+/// Returns the body block of a loop expression.
+fn loop_body(loop_expr: &semantic::Expr) -> semantic::ExprId {
+    match loop_expr {
+        semantic::Expr::Loop(expr) => expr.body,
+        semantic::Expr::While(expr) => expr.body,
+        semantic::Expr::For(expr) => expr.body,
+        _ => unreachable!("Only loop expressions have a loop body."),
+    }
+}
+
+/// Lowers a single iteration of a `while` loop, inside its loop function: if the condition holds,
+/// runs the body and calls the loop function recursively.
+fn lower_while_loop_iteration(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    expr: &semantic::ExprWhile,
+    loop_expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a while loop iteration: {:?}", expr.debug(&ctx.expr_formatter));
+    let condition_var = lower_expr(ctx, builder, expr.condition)?.var(ctx, builder)?;
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = unit_ty(semantic_db);
+    let while_location = ctx.get_location(expr.stable_ptr.untyped());
+    let semantic_block =
+        extract_matches!(&ctx.function_body.exprs[expr.body], semantic::Expr::Block).clone();
+
+    // Main block.
+    let mut subscope_main = create_subscope_with_bound_refs(ctx, builder);
+    let block_main_id = subscope_main.block_id;
+    let main_block_var_id = ctx.new_var(VarRequest {
+        ty: unit_ty,
+        location: ctx.get_location(semantic_block.stable_ptr.untyped()),
+    });
+    let block_main_expr = (|| {
+        lower_expr_block(ctx, &mut subscope_main, &semantic_block)?;
+        // Add recursive call.
+        let signature = ctx.signature.clone();
+        call_loop_func(ctx, signature, &mut subscope_main, loop_expr_id)
+    })();
+    let block_main = lowered_expr_to_block_scope_end(ctx, subscope_main, block_main_expr)
+        .map_err(LoweringFlowError::Failed)?;
+
+    // Else block.
+    let subscope_else = create_subscope_with_bound_refs(ctx, builder);
+    let block_else_id = subscope_else.block_id;
+    let else_block_input_var_id = ctx.new_var(VarRequest { ty: unit_ty, location: while_location });
+    let block_else = lowered_expr_to_block_scope_end(
+        ctx,
+        subscope_else,
+        Ok(LoweredExpr::Tuple { exprs: vec![], location: while_location }),
+    )
+    .map_err(LoweringFlowError::Failed)?;
+
+    let match_info = MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id: core_bool_enum(semantic_db),
+        input: condition_var,
+        arms: vec![
+            MatchArm {
+                variant_id: false_variant(semantic_db),
+                block_id: block_else_id,
+                var_ids: vec![else_block_input_var_id],
+            },
+            MatchArm {
+                variant_id: true_variant(semantic_db),
+                block_id: block_main_id,
+                var_ids: vec![main_block_var_id],
+            },
+        ],
+        location: while_location.into(),
+    });
+    builder.merge_and_end_with_match(ctx, match_info, vec![block_main, block_else], while_location)
+}
+
+/// Lowers a single iteration of a `for` loop, inside its loop function: if the iterator returns an
+/// item, binds it to the pattern, runs the body and calls the loop function recursively.
+fn lower_for_loop_iteration(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    expr: &semantic::ExprFor,
+    loop_expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a for loop iteration: {:?}", expr.debug(&ctx.expr_formatter));
+    let next_var = lower_expr(ctx, builder, expr.next_call)?.var(ctx, builder)?;
+    let for_location = ctx.get_location(expr.stable_ptr.untyped());
+    let semantic_block =
+        extract_matches!(&ctx.function_body.exprs[expr.body], semantic::Expr::Block).clone();
+
+    // Some block.
+    let mut subscope_some = create_subscope_with_bound_refs(ctx, builder);
+    let block_some_id = subscope_some.block_id;
+    let some_var_id = ctx.new_var(VarRequest { ty: expr.some_variant.ty, location: for_location });
+    let block_some_expr = (|| {
+        lower_single_pattern(
+            ctx,
+            &mut subscope_some,
+            &expr.pattern,
+            LoweredExpr::AtVariable(some_var_id),
+        )?;
+        lower_expr_block(ctx, &mut subscope_some, &semantic_block)?;
+        // Add recursive call.
+        let signature = ctx.signature.clone();
+        call_loop_func(ctx, signature, &mut subscope_some, loop_expr_id)
+    })();
+    let block_some = lowered_expr_to_block_scope_end(ctx, subscope_some, block_some_expr)
+        .map_err(LoweringFlowError::Failed)?;
+
+    // None block.
+    let subscope_none = create_subscope_with_bound_refs(ctx, builder);
+    let block_none_id = subscope_none.block_id;
+    let none_var_id = ctx.new_var(VarRequest { ty: expr.none_variant.ty, location: for_location });
+    let block_none = lowered_expr_to_block_scope_end(
+        ctx,
+        subscope_none,
+        Ok(LoweredExpr::Tuple { exprs: vec![], location: for_location }),
+    )
+    .map_err(LoweringFlowError::Failed)?;
+
+    let match_info = MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id: expr.some_variant.concrete_enum_id,
+        input: next_var,
+        arms: vec![
+            MatchArm {
+                variant_id: expr.some_variant.clone(),
+                block_id: block_some_id,
+                var_ids: vec![some_var_id],
+            },
+            MatchArm {
+                variant_id: expr.none_variant.clone(),
+                block_id: block_none_id,
+                var_ids: vec![none_var_id],
+            },
+        ],
+        location: for_location.into(),
+    });
+    builder.merge_and_end_with_match(ctx, match_info, vec![block_some, block_none], for_location)
 }
 
 /// Adds a call to an inner loop-generated function.
@@ -739,15 +1181,18 @@ fn call_loop_func(
     ctx: &mut LoweringContext<'_, '_>,
     signature: Signature,
     builder: &mut BlockBuilder,
-    expr: &semantic::ExprLoop,
+    loop_expr_id: semantic::ExprId,
 ) -> LoweringResult<LoweredExpr> {
-    let stable_ptr = expr.stable_ptr.untyped();
-    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let loop_expr = &ctx.function_body.exprs[loop_expr_id];
+    let stable_ptr = loop_expr.stable_ptr().untyped();
+    let ty = loop_expr.ty();
+    let body = loop_body(loop_expr);
+    let location = ctx.get_location(stable_ptr);
 
     // Call it.
     let function = ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
         parent: ctx.concrete_function_id.base_semantic_function(ctx.db),
-        element: expr.body,
+        key: GeneratedFunctionKey::Expr(body),
     }));
     let inputs = signature
         .params
@@ -760,7 +1205,7 @@ fn call_loop_func(
         .collect::<LoweringResult<Vec<_>>>()?;
     let extra_ret_tys = signature.extra_rets.iter().map(|path| path.ty()).collect_vec();
     let call_result =
-        generators::Call { function, inputs, extra_ret_tys, ret_tys: vec![expr.ty], location }
+        generators::Call { function, inputs, extra_ret_tys, ret_tys: vec![ty], location }
             .add(ctx, &mut builder.statements);
 
     // Rebind the ref variables.
@@ -778,87 +1223,41 @@ fn lower_expr_match(
     builder: &mut BlockBuilder,
 ) -> LoweringResult<LoweredExpr> {
     log::trace!("Lowering a match expression: {:?}", expr.debug(&ctx.expr_formatter));
-    let location = ctx.get_location(expr.stable_ptr.untyped());
     let lowered_expr = lower_expr(ctx, builder, expr.matched_expr)?;
 
-    if ctx.function_body.exprs[expr.matched_expr].ty() == ctx.db.core_felt252_ty() {
-        let var = lowered_expr.var(ctx, builder)?;
-        return lower_expr_match_felt252(ctx, expr, var, builder);
-    }
-
-    // TODO(spapini): Use diagnostics.
-    // TODO(spapini): Handle more than just enums.
-    if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
-        return lower_optimized_extern_match(ctx, builder, extern_enum, &expr.arms);
+    if let LoweredExpr::ExternEnum(extern_enum) = &lowered_expr {
+        if is_variant_per_arm_match(ctx, extern_enum.concrete_enum_id, &expr.arms)? {
+            let LoweredExpr::ExternEnum(extern_enum) = lowered_expr else { unreachable!() };
+            return lower_optimized_extern_match(ctx, builder, extern_enum, &expr.arms);
+        }
     }
 
-    let ExtractedEnumDetails { concrete_enum_id, concrete_variants, n_snapshots } =
-        extract_concrete_enum(ctx, expr)?;
-    let expr_var = lowered_expr.var(ctx, builder)?;
-
-    // Merge arm blocks.
-
-    let mut arm_var_ids = vec![];
-    let (sealed_blocks, block_ids): (Vec<_>, Vec<_>) = zip_eq(&concrete_variants, &expr.arms)
-        .map(|(concrete_variant, arm)| {
-            let mut subscope = create_subscope_with_bound_refs(ctx, builder);
-            let block_id = subscope.block_id;
-
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmNotAVariant),
-                )
-            })?;
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmOutOfOrder),
-                ));
-            }
-
-            let pattern_location =
-                ctx.get_location(enum_pattern.inner_pattern.stable_ptr().untyped());
-
-            let var_id = ctx.new_var(VarRequest {
-                ty: wrap_in_snapshots(ctx.db.upcast(), concrete_variant.ty, n_snapshots),
-                location: pattern_location,
-            });
-            arm_var_ids.push(vec![var_id]);
-            let variant_expr = LoweredExpr::AtVariable(var_id);
-
-            match lower_single_pattern(
-                ctx,
-                &mut subscope,
-                &enum_pattern.inner_pattern,
-                variant_expr,
-            ) {
-                Ok(_) => {
-                    // Lower the arm expression.
-                    lower_tail_expr(ctx, subscope, arm.expression)
-                }
-                Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
-            }
-            .map_err(LoweringFlowError::Failed)
-            .map(|sb| (sb, block_id))
-        })
-        .collect::<LoweringResult<Vec<_>>>()?
-        .into_iter()
-        .unzip();
+    let matched_var = lowered_expr.var(ctx, builder)?;
+    lower_match_arms(ctx, builder, expr, matched_var)
+}
 
-    let match_info = MatchInfo::Enum(MatchEnumInfo {
-        concrete_enum_id,
-        input: expr_var,
-        arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
-            .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
-            .collect(),
-        location,
-    });
-    builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
+/// Returns whether the arms of a match on an enum are exactly its variants, in order, with
+/// irrefutable inner patterns.
+fn is_variant_per_arm_match(
+    ctx: &mut LoweringContext<'_, '_>,
+    concrete_enum_id: semantic::ConcreteEnumId,
+    arms: &[semantic::MatchArm],
+) -> LoweringResult<bool> {
+    let concrete_variants =
+        ctx.db.concrete_enum_variants(concrete_enum_id).map_err(LoweringFlowError::Failed)?;
+    Ok(arms.len() == concrete_variants.len()
+        && zip_eq(arms, &concrete_variants).all(|(arm, concrete_variant)| {
+            matches!(
+                &arm.pattern,
+                semantic::Pattern::EnumVariant(enum_pattern)
+                    if &enum_pattern.variant == concrete_variant
+                        && is_irrefutable(&enum_pattern.inner_pattern)
+            )
+        }))
 }
 
-/// Lowers a match expression on a LoweredExpr::ExternEnum lowered expression.
+/// Lowers a match expression on a LoweredExpr::ExternEnum lowered expression, whose arms are the
+/// variants of the enum, in order (see [is_variant_per_arm_match]).
 fn lower_optimized_extern_match(
     ctx: &mut LoweringContext<'_, '_>,
     builder: &mut BlockBuilder,
@@ -871,11 +1270,6 @@ fn lower_optimized_extern_match(
         .db
         .concrete_enum_variants(extern_enum.concrete_enum_id)
         .map_err(LoweringFlowError::Failed)?;
-    if match_arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report_by_location(location, UnsupportedMatchArms),
-        ));
-    }
     // Merge arm blocks.
     let mut arm_var_ids = vec![];
 
@@ -892,19 +1286,7 @@ fn lower_optimized_extern_match(
                 .collect_vec();
             arm_var_ids.push(input_vars.clone());
 
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmNotAVariant),
-                )
-            })?;
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics
-                        .report(arm.pattern.stable_ptr().untyped(), UnsupportedMatchArmOutOfOrder),
-                ));
-            }
+            let enum_pattern = extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant);
 
             // Bind the arm inputs to implicits and semantic variables.
             match_extern_arm_ref_args_bind(ctx, &mut input_vars, &extern_enum, &mut subscope);
@@ -935,131 +1317,11 @@ fn lower_optimized_extern_match(
         arms: zip_eq(zip_eq(concrete_variants, block_ids), arm_var_ids.into_iter())
             .map(|((variant_id, block_id), var_ids)| MatchArm { variant_id, block_id, var_ids })
             .collect(),
-        location,
+        location: location.into(),
     });
     builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
 }
 
-/// Lowers an expression of type [semantic::ExprMatch] where the matched expression is a felt252.
-/// Currently only a simple match-zero is supported.
-fn lower_expr_match_felt252(
-    ctx: &mut LoweringContext<'_, '_>,
-    expr: &semantic::ExprMatch,
-    expr_var: VariableId,
-    builder: &mut BlockBuilder,
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Lowering a match-felt252 expression.");
-    let location = ctx.get_location(expr.stable_ptr.untyped());
-    // Check that the match has the expected form.
-    let (literal, block0, block_otherwise) = if let [
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Literal(semantic::PatternLiteral { literal, .. }),
-            expression: block0,
-        },
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Otherwise(_),
-            expression: block_otherwise,
-        },
-    ] = &expr.arms[..]
-    {
-        (literal, block0, block_otherwise)
-    } else {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), OnlyMatchZeroIsSupported),
-        ));
-    };
-
-    // Make sure literal is 0.
-    if !literal.value.is_zero() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(literal.stable_ptr.untyped(), NonZeroValueInMatch),
-        ));
-    }
-
-    let semantic_db = ctx.db.upcast();
-
-    // Lower both blocks.
-    let zero_block_id = alloc_empty_block(ctx);
-    let nonzero_block_id = alloc_empty_block(ctx);
-
-    let subscope_nz = builder.child_block_builder(nonzero_block_id);
-    let var_nz = ctx.new_var(VarRequest {
-        ty: core_nonzero_ty(semantic_db, core_felt252_ty(semantic_db)),
-        location,
-    });
-
-    let sealed_blocks = vec![
-        lower_tail_expr(ctx, builder.child_block_builder(zero_block_id), *block0)
-            .map_err(LoweringFlowError::Failed)?,
-        lower_tail_expr(ctx, subscope_nz, *block_otherwise).map_err(LoweringFlowError::Failed)?,
-    ];
-
-    let match_info = MatchInfo::Extern(MatchExternInfo {
-        function: core_felt252_is_zero(semantic_db).lowered(ctx.db),
-        inputs: vec![expr_var],
-        arms: vec![
-            MatchArm {
-                variant_id: jump_nz_zero_variant(semantic_db),
-                block_id: zero_block_id,
-                var_ids: vec![],
-            },
-            MatchArm {
-                variant_id: jump_nz_nonzero_variant(semantic_db),
-                block_id: nonzero_block_id,
-                var_ids: vec![var_nz],
-            },
-        ],
-        location,
-    });
-    builder.merge_and_end_with_match(ctx, match_info, sealed_blocks, location)
-}
-
-/// Information about the enum of a match statement. See [extract_concrete_enum].
-struct ExtractedEnumDetails {
-    concrete_enum_id: semantic::ConcreteEnumId,
-    concrete_variants: Vec<semantic::ConcreteVariant>,
-    n_snapshots: usize,
-}
-
-/// Extracts concrete enum and variants from a match expression. Assumes it is indeed a concrete
-/// enum.
-fn extract_concrete_enum(
-    ctx: &mut LoweringContext<'_, '_>,
-    expr: &semantic::ExprMatch,
-) -> Result<ExtractedEnumDetails, LoweringFlowError> {
-    let ty = ctx.function_body.exprs[expr.matched_expr].ty();
-    let (n_snapshots, long_ty) = peel_snapshots(ctx.db.upcast(), ty);
-
-    // Semantic model should have made sure the type is an enum.
-    let concrete_ty = extract_matches!(long_ty, TypeLongId::Concrete);
-    let Some(concrete_enum_id) = try_extract_matches!(concrete_ty, ConcreteTypeId::Enum) else {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatchedValue),
-        ));
-    };
-    let enum_id = concrete_enum_id.enum_id(ctx.db.upcast());
-    let variants = ctx.db.enum_variants(enum_id).map_err(LoweringFlowError::Failed)?;
-    let concrete_variants = variants
-        .values()
-        .map(|variant_id| {
-            let variant =
-                ctx.db.variant_semantic(enum_id, *variant_id).map_err(LoweringFlowError::Failed)?;
-
-            ctx.db
-                .concrete_enum_variant(concrete_enum_id, &variant)
-                .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    if expr.arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatchArms),
-        ));
-    }
-
-    Ok(ExtractedEnumDetails { concrete_enum_id, concrete_variants, n_snapshots })
-}
-
 /// Lowers a sequence of expressions and return them all. If the flow ended in the middle,
 /// propagates that flow error without returning any variable.
 fn lower_exprs_as_vars(
@@ -1234,7 +1496,7 @@ fn lower_expr_error_propagate(
                 var_ids: vec![err_value],
             },
         ],
-        location,
+        location: location.into(),
     });
     builder.merge_and_end_with_match(
         ctx,
@@ -1300,7 +1562,7 @@ fn lower_optimized_extern_error_propagate(
                 var_ids: block_err_input_vars,
             },
         ],
-        location,
+        location: location.into(),
     });
     builder.merge_and_end_with_match(
         ctx,
@@ -118,50 +118,28 @@ impl BlockUsages {
                 if let Some(expr_id) = expr.tail {
                     self.handle_expr(function_body, expr_id, &mut usage)
                 }
-                for (member_path, _) in usage.usage.clone() {
-                    // Prune introductions from usages.
-                    if usage.introductions.contains(&member_path.base_var()) {
-                        usage.usage.swap_remove(&member_path);
-                        continue;
-                    }
-
-                    // Prune usages that are members of other usages.
-                    let mut current_path = member_path.clone();
-                    while let MemberPath::Member { parent, .. } = current_path {
-                        current_path = *parent.clone();
-                        if usage.usage.contains_key(&current_path) {
-                            usage.usage.swap_remove(&member_path);
-                            break;
-                        }
-                    }
-                }
-                for (member_path, _) in usage.changes.clone() {
-                    // Prune introductions from changes.
-                    if usage.introductions.contains(&member_path.base_var()) {
-                        usage.changes.swap_remove(&member_path);
-                    }
-
-                    // Prune changes that are members of other changes.
-                    let mut current_path = member_path.clone();
-                    while let MemberPath::Member { parent, .. } = current_path {
-                        current_path = *parent.clone();
-                        if usage.changes.contains_key(&current_path) {
-                            usage.changes.swap_remove(&member_path);
-                            break;
-                        }
-                    }
-                }
-
-                for (path, expr) in usage.usage.iter() {
-                    current.usage.insert(path.clone(), expr.clone());
-                }
-                for (path, expr) in usage.changes.iter() {
-                    current.changes.insert(path.clone(), expr.clone());
-                }
-
-                self.block_usages.insert(expr_id, usage);
+                self.finalize_as_scope(expr_id, usage, current);
             }
             Expr::Loop(expr) => self.handle_expr(function_body, expr.body, current),
+            Expr::While(expr) => {
+                let mut usage = Default::default();
+                self.handle_expr(function_body, expr.condition, &mut usage);
+                self.handle_expr(function_body, expr.body, &mut usage);
+                // The loop function evaluates the condition as well, so the usage of the whole
+                // loop overrides the usage of its body.
+                self.finalize_as_scope(expr.body, usage, current);
+            }
+            Expr::For(expr) => {
+                self.handle_expr(function_body, expr.into_iter, current);
+                current.introductions.insert(expr.into_iter_member_path.base_var());
+                let mut usage = Default::default();
+                self.handle_expr(function_body, expr.next_call, &mut usage);
+                Self::handle_pattern(&expr.pattern, &mut usage);
+                self.handle_expr(function_body, expr.body, &mut usage);
+                // The loop function calls `next` and binds the pattern as well, so the usage of
+                // the whole loop overrides the usage of its body.
+                self.finalize_as_scope(expr.body, usage, current);
+            }
             Expr::FunctionCall(expr) => {
                 for arg in &expr.args {
                     match arg {
@@ -194,7 +172,7 @@ impl BlockUsages {
                     .usage
                     .insert(MemberPath::Var(expr.var), ExprVarMemberPath::Var(expr.clone()));
             }
-            Expr::Literal(_) => {}
+            Expr::Literal(_) | Expr::StringLiteral(_) => {}
             Expr::MemberAccess(expr) => {
                 if let Some(member_path) = &expr.member_path {
                     current.usage.insert(member_path.into(), member_path.clone());
@@ -211,14 +189,71 @@ impl BlockUsages {
                 self.handle_expr(function_body, expr.value_expr, current)
             }
             Expr::PropagateError(expr) => self.handle_expr(function_body, expr.inner, current),
+            Expr::Closure(expr) => {
+                // The closure body is lowered as a separate function, so only the captured
+                // variables are used by the enclosing scope.
+                self.handle_expr(function_body, expr.body, &mut Default::default());
+                for var in &expr.captured_vars {
+                    current
+                        .usage
+                        .insert(MemberPath::Var(var.var), ExprVarMemberPath::Var(var.clone()));
+                }
+            }
             Expr::Constant(_) => {}
             Expr::Missing(_) => {}
         }
     }
 
+    /// Prunes the usage of a scope, records it as the usage of the given expression and merges it
+    /// into the usage of the enclosing scope.
+    fn finalize_as_scope(&mut self, expr_id: ExprId, mut usage: Usage, current: &mut Usage) {
+        for (member_path, _) in usage.usage.clone() {
+            // Prune introductions from usages.
+            if usage.introductions.contains(&member_path.base_var()) {
+                usage.usage.swap_remove(&member_path);
+                continue;
+            }
+
+            // Prune usages that are members of other usages.
+            let mut current_path = member_path.clone();
+            while let MemberPath::Member { parent, .. } = current_path {
+                current_path = *parent.clone();
+                if usage.usage.contains_key(&current_path) {
+                    usage.usage.swap_remove(&member_path);
+                    break;
+                }
+            }
+        }
+        for (member_path, _) in usage.changes.clone() {
+            // Prune introductions from changes.
+            if usage.introductions.contains(&member_path.base_var()) {
+                usage.changes.swap_remove(&member_path);
+            }
+
+            // Prune changes that are members of other changes.
+            let mut current_path = member_path.clone();
+            while let MemberPath::Member { parent, .. } = current_path {
+                current_path = *parent.clone();
+                if usage.changes.contains_key(&current_path) {
+                    usage.changes.swap_remove(&member_path);
+                    break;
+                }
+            }
+        }
+
+        for (path, expr) in usage.usage.iter() {
+            current.usage.insert(path.clone(), expr.clone());
+        }
+        for (path, expr) in usage.changes.iter() {
+            current.changes.insert(path.clone(), expr.clone());
+        }
+
+        self.block_usages.insert(expr_id, usage);
+    }
+
     fn handle_pattern(pat: &Pattern, current: &mut Usage) {
         match pat {
-            Pattern::Literal(_) => {}
+            Pattern::Literal(_) | Pattern::Range(_) => {}
             Pattern::Variable(pat) => {
                 current.introductions.insert(VarId::Local(pat.var.id));
             }
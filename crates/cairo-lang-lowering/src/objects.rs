@@ -11,6 +11,7 @@ use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::{ConcreteEnumId, ConcreteVariant};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use id_arena::{Arena, Id};
+use itertools::chain;
 use num_bigint::BigInt;
 pub mod blocks;
 pub use blocks::BlockId;
@@ -138,6 +139,35 @@ pub struct Variable {
     pub location: StableLocation,
 }
 
+/// The location of a lowered statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// The location of the statement in the code.
+    pub stable_location: StableLocation,
+    /// The locations of the calls the statement was inlined into, from the innermost call to the
+    /// outermost one.
+    pub inline_locations: Vec<StableLocation>,
+}
+impl Location {
+    /// Returns the location of the statement after inlining it into a call at `call_location`.
+    pub fn inlined_at(&self, call_location: &Location) -> Self {
+        Self {
+            stable_location: self.stable_location,
+            inline_locations: chain!(
+                self.inline_locations.iter().copied(),
+                [call_location.stable_location],
+                call_location.inline_locations.iter().copied()
+            )
+            .collect(),
+        }
+    }
+}
+impl From<StableLocation> for Location {
+    fn from(stable_location: StableLocation) -> Self {
+        Self { stable_location, inline_locations: vec![] }
+    }
+}
+
 /// Lowered statement.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Statement {
@@ -181,6 +211,17 @@ impl Statement {
             Statement::Desnap(stmt) => vec![stmt.output],
         }
     }
+    pub fn location(&self) -> Option<&Location> {
+        match &self {
+            Statement::Call(stmt) => Some(&stmt.location),
+            Statement::Literal(_)
+            | Statement::StructConstruct(_)
+            | Statement::StructDestructure(_)
+            | Statement::EnumConstruct(_)
+            | Statement::Snapshot(_)
+            | Statement::Desnap(_) => None,
+        }
+    }
 }
 
 /// A statement that binds a literal value to a variable.
@@ -202,7 +243,7 @@ pub struct StatementCall {
     /// New variables to be introduced into the current scope from the function outputs.
     pub outputs: Vec<VariableId>,
     /// Location for the call.
-    pub location: StableLocation,
+    pub location: Location,
 }
 
 /// A statement that construct a variant of an enum with a single argument, and binds it to a
@@ -276,7 +317,7 @@ pub struct MatchExternInfo {
     /// Order must be identical to the order in the definition of the enum.
     pub arms: Vec<MatchArm>,
     /// Location for the call.
-    pub location: StableLocation,
+    pub location: Location,
 }
 
 /// A statement that matches an enum, and "calls" a possibly different block for each branch.
@@ -289,7 +330,7 @@ pub struct MatchEnumInfo {
     /// Order must be identical to the order in the definition of the enum.
     pub arms: Vec<MatchArm>,
     /// Location for the match.
-    pub location: StableLocation,
+    pub location: Location,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -310,4 +351,10 @@ impl MatchInfo {
             MatchInfo::Extern(s) => &s.arms,
         }
     }
+    pub fn location(&self) -> &Location {
+        match self {
+            MatchInfo::Enum(s) => &s.location,
+            MatchInfo::Extern(s) => &s.location,
+        }
+    }
 }
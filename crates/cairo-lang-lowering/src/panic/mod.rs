@@ -210,7 +210,7 @@ impl<'a> PanicBlockLoweringContext<'a> {
             function: call.function,
             inputs: call.inputs.clone(),
             outputs: call_outputs,
-            location,
+            location: location.into(),
         }));
 
         // Start constructing a match on the result.
@@ -253,7 +253,7 @@ impl<'a> PanicBlockLoweringContext<'a> {
                         var_ids: vec![err_var],
                     },
                 ],
-                location,
+                location: location.into(),
             }),
         };
 
@@ -7,7 +7,7 @@ use cairo_lang_filesystem::db::{
 };
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_parser::db::ParserDatabase;
-use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_plugins::{get_default_inline_macro_plugins, get_default_plugins};
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
 use cairo_lang_utils::Upcast;
@@ -31,6 +31,7 @@ impl Default for LoweringDatabaseForTesting {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         res.set_semantic_plugins(get_default_plugins());
+        res.set_inline_macro_plugins(Arc::new(get_default_inline_macro_plugins()));
         let corelib_path = detect_corelib().expect("Corelib not found in default location.");
         init_dev_corelib(&mut res, corelib_path);
         res
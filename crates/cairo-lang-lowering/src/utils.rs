@@ -29,7 +29,7 @@ pub trait RebuilderEx: Rebuilder {
                 function: stmt.function,
                 inputs: stmt.inputs.iter().map(|v| self.map_var_id(*v)).collect(),
                 outputs: stmt.outputs.iter().map(|v| self.map_var_id(*v)).collect(),
-                location: stmt.location,
+                location: stmt.location.clone(),
             }),
             Statement::StructConstruct(stmt) => {
                 Statement::StructConstruct(StatementStructConstruct {
@@ -102,7 +102,7 @@ pub trait RebuilderEx: Rebuilder {
                                     .collect(),
                             })
                             .collect(),
-                        location: stmt.location,
+                        location: stmt.location.clone(),
                     }),
                     MatchInfo::Enum(stmt) => MatchInfo::Enum(MatchEnumInfo {
                         concrete_enum_id: stmt.concrete_enum_id,
@@ -120,7 +120,7 @@ pub trait RebuilderEx: Rebuilder {
                                     .collect(),
                             })
                             .collect(),
-                        location: stmt.location,
+                        location: stmt.location.clone(),
                     }),
                 },
             },
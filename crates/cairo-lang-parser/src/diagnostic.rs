@@ -20,16 +20,21 @@ pub enum ParserDiagnosticKind {
     MissingPathSegment,
     MissingTypeClause,
     MissingTypeExpression,
+    MissingWrappedArgList,
+    MissingInKeyword,
     ReservedIdentifier { identifier: SmolStr },
     UnderscoreNotAllowedAsIdentifier,
     MissingLiteralSuffix,
     InvalidNumericLiteralValue,
     IllegalStringEscaping,
     ShortStringMustBeAscii,
+    StringMustBeAscii,
     UnterminatedString,
     AttributesWithoutItem,
     AttributesWithoutTraitItem,
     AttributesWithoutImplItem,
+    VisibilityWithoutItem,
+    VisibilityWithoutImplItem,
 }
 impl DiagnosticEntry for ParserDiagnostic {
     type DbType = dyn FilesGroup;
@@ -54,6 +59,14 @@ impl DiagnosticEntry for ParserDiagnostic {
             ParserDiagnosticKind::MissingTypeExpression => {
                 "Missing tokens. Expected a type expression.".to_string()
             }
+            ParserDiagnosticKind::MissingWrappedArgList => {
+                "Missing tokens. Expected an argument list wrapped in either parentheses or \
+                 brackets."
+                    .to_string()
+            }
+            ParserDiagnosticKind::MissingInKeyword => {
+                "Missing tokens. Expected 'in' after the pattern of a `for` loop.".to_string()
+            }
             ParserDiagnosticKind::ReservedIdentifier { identifier } => {
                 format!("'{identifier}' is a reserved identifier.")
             }
@@ -68,6 +81,9 @@ impl DiagnosticEntry for ParserDiagnostic {
             ParserDiagnosticKind::ShortStringMustBeAscii => {
                 "Short strings can only include ASCII characters.".into()
             }
+            ParserDiagnosticKind::StringMustBeAscii => {
+                "String literals can only include ASCII characters.".into()
+            }
             ParserDiagnosticKind::UnterminatedString => "Unterminated string literal.".into(),
             ParserDiagnosticKind::AttributesWithoutItem => {
                 "Missing tokens. Expected an item after attributes.".to_string()
@@ -78,6 +94,12 @@ impl DiagnosticEntry for ParserDiagnostic {
             ParserDiagnosticKind::AttributesWithoutImplItem => {
                 "Missing tokens. Expected an impl item after attributes.".to_string()
             }
+            ParserDiagnosticKind::VisibilityWithoutItem => {
+                "Missing tokens. Expected an item after visibility.".to_string()
+            }
+            ParserDiagnosticKind::VisibilityWithoutImplItem => {
+                "Missing tokens. Expected an impl item after visibility.".to_string()
+            }
         }
     }
 
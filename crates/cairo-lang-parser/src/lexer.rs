@@ -165,6 +165,24 @@ impl<'a> Lexer<'a> {
         TokenKind::ShortString
     }
 
+    /// Takes a string.
+    fn take_token_string(&mut self) -> TokenKind {
+        self.take();
+        let mut escaped = false;
+        while let Some(token) = self.peek() {
+            self.take();
+            match token {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => {
+                    break;
+                }
+                _ => {}
+            };
+        }
+        TokenKind::String
+    }
+
     /// Assumes the next character is [a-zA-Z_].
     fn take_token_identifier(&mut self) -> TokenKind {
         // TODO(spapini): Support or explicitly report general unicode characters.
@@ -181,6 +199,7 @@ impl<'a> Lexer<'a> {
             "trait" => TokenKind::Trait,
             "impl" => TokenKind::Impl,
             "of" => TokenKind::Of,
+            "pub" => TokenKind::Pub,
             "mod" => TokenKind::Module,
             "struct" => TokenKind::Struct,
             "enum" => TokenKind::Enum,
@@ -189,6 +208,8 @@ impl<'a> Lexer<'a> {
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
             "loop" => TokenKind::Loop,
+            "while" => TokenKind::While,
+            "for" => TokenKind::For,
             "continue" => TokenKind::Continue,
             "break" => TokenKind::Break,
             "else" => TokenKind::Else,
@@ -231,6 +252,7 @@ impl<'a> Lexer<'a> {
             match current {
                 '0'..='9' => self.take_token_literal_number(),
                 '\'' => self.take_token_short_string(),
+                '"' => self.take_token_string(),
                 ',' => self.take_token_of_kind(TokenKind::Comma),
                 ';' => self.take_token_of_kind(TokenKind::Semicolon),
                 '?' => self.take_token_of_kind(TokenKind::QuestionMark),
@@ -328,6 +350,7 @@ enum TokenKind {
     // Literals.
     LiteralNumber,
     ShortString,
+    String,
 
     // Keywords.
     As,
@@ -340,6 +363,7 @@ enum TokenKind {
     Trait,
     Impl,
     Of,
+    Pub,
     Module,
     Struct,
     Enum,
@@ -348,6 +372,8 @@ enum TokenKind {
     Match,
     If,
     Loop,
+    While,
+    For,
     Continue,
     Break,
     Else,
@@ -416,6 +442,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Identifier => SyntaxKind::TerminalIdentifier,
         TokenKind::LiteralNumber => SyntaxKind::TerminalLiteralNumber,
         TokenKind::ShortString => SyntaxKind::TerminalShortString,
+        TokenKind::String => SyntaxKind::TerminalString,
         TokenKind::False => SyntaxKind::TerminalFalse,
         TokenKind::True => SyntaxKind::TerminalTrue,
         TokenKind::Extern => SyntaxKind::TerminalExtern,
@@ -424,6 +451,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Trait => SyntaxKind::TerminalTrait,
         TokenKind::Impl => SyntaxKind::TerminalImpl,
         TokenKind::Of => SyntaxKind::TerminalOf,
+        TokenKind::Pub => SyntaxKind::TerminalPub,
         TokenKind::Module => SyntaxKind::TerminalModule,
         TokenKind::Struct => SyntaxKind::TerminalStruct,
         TokenKind::Enum => SyntaxKind::TerminalEnum,
@@ -432,6 +460,8 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Match => SyntaxKind::TerminalMatch,
         TokenKind::If => SyntaxKind::TerminalIf,
         TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::While => SyntaxKind::TerminalWhile,
+        TokenKind::For => SyntaxKind::TerminalFor,
         TokenKind::Continue => SyntaxKind::TerminalContinue,
         TokenKind::Break => SyntaxKind::TerminalBreak,
         TokenKind::Else => SyntaxKind::TerminalElse,
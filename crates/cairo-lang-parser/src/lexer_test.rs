@@ -34,6 +34,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalTrait => vec!["trait"],
         SyntaxKind::TerminalImpl => vec!["impl"],
         SyntaxKind::TerminalOf => vec!["of"],
+        SyntaxKind::TerminalPub => vec!["pub"],
         SyntaxKind::TerminalLet => vec!["let"],
         SyntaxKind::TerminalMut => vec!["mut"],
         SyntaxKind::TerminalRef => vec!["ref"],
@@ -46,6 +47,8 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
         SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalWhile => vec!["while"],
+        SyntaxKind::TerminalFor => vec!["for"],
         SyntaxKind::TerminalBreak => vec!["break"],
         SyntaxKind::TerminalElse => vec!["else"],
         SyntaxKind::TerminalUse => vec!["use"],
@@ -110,6 +113,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalTrait,
         SyntaxKind::TerminalImpl,
         SyntaxKind::TerminalOf,
+        SyntaxKind::TerminalPub,
         SyntaxKind::TerminalModule,
         SyntaxKind::TerminalStruct,
         SyntaxKind::TerminalEnum,
@@ -121,6 +125,8 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
         SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalWhile,
+        SyntaxKind::TerminalFor,
         SyntaxKind::TerminalBreak,
         SyntaxKind::TerminalElse,
         SyntaxKind::TerminalUse,
@@ -143,23 +143,31 @@ impl<'a> Parser<'a> {
             Some(attributes) => (true, attributes),
             None => (false, AttributeList::new_green(self.db, vec![])),
         };
+        let has_visibility = self.peek().kind == SyntaxKind::TerminalPub;
+        let visibility = self.parse_visibility();
 
         match self.peek().kind {
-            SyntaxKind::TerminalConst => Some(self.expect_const(attributes).into()),
-            SyntaxKind::TerminalModule => Some(self.expect_module(attributes).into()),
-            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes).into()),
-            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes).into()),
-            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes).into()),
-            SyntaxKind::TerminalExtern => Some(self.expect_extern_item(attributes)),
-            SyntaxKind::TerminalFunction => Some(self.expect_function_with_body(attributes).into()),
-            SyntaxKind::TerminalUse => Some(self.expect_use(attributes).into()),
-            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes).into()),
-            SyntaxKind::TerminalImpl => Some(self.expect_item_impl(attributes)),
+            SyntaxKind::TerminalConst => Some(self.expect_const(attributes, visibility).into()),
+            SyntaxKind::TerminalModule => Some(self.expect_module(attributes, visibility).into()),
+            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes, visibility).into()),
+            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes, visibility).into()),
+            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes, visibility).into()),
+            SyntaxKind::TerminalExtern => Some(self.expect_extern_item(attributes, visibility)),
+            SyntaxKind::TerminalFunction => {
+                Some(self.expect_function_with_body(attributes, visibility).into())
+            }
+            SyntaxKind::TerminalUse => Some(self.expect_use(attributes, visibility).into()),
+            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes, visibility).into()),
+            SyntaxKind::TerminalImpl => Some(self.expect_item_impl(attributes, visibility)),
             _ => {
                 if has_attrs {
                     Some(self.create_and_report_missing::<Item>(
                         ParserDiagnosticKind::AttributesWithoutItem,
                     ))
+                } else if has_visibility {
+                    Some(self.create_and_report_missing::<Item>(
+                        ParserDiagnosticKind::VisibilityWithoutItem,
+                    ))
                 } else {
                     None
                 }
@@ -169,7 +177,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Module.
     /// Expected pattern: `mod <Identifier> \{<ItemList>\}` or `mod <Identifier>;`.
-    fn expect_module(&mut self, attributes: AttributeListGreen) -> ItemModuleGreen {
+    fn expect_module(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemModuleGreen {
         let module_kw = self.take::<TerminalModule>();
         let name = self.parse_identifier();
 
@@ -191,12 +203,16 @@ impl<'a> Parser<'a> {
             _ => self.parse_token::<TerminalSemicolon>().into(),
         };
 
-        ItemModule::new_green(self.db, attributes, module_kw, name, body)
+        ItemModule::new_green(self.db, attributes, visibility, module_kw, name, body)
     }
 
     /// Assumes the current token is Struct.
     /// Expected pattern: `struct<Identifier>{<ParamList>}`
-    fn expect_struct(&mut self, attributes: AttributeListGreen) -> ItemStructGreen {
+    fn expect_struct(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemStructGreen {
         let struct_kw = self.take::<TerminalStruct>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -206,6 +222,7 @@ impl<'a> Parser<'a> {
         ItemStruct::new_green(
             self.db,
             attributes,
+            visibility,
             struct_kw,
             name,
             generic_params,
@@ -217,7 +234,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Enum.
     /// Expected pattern: `enum<Identifier>{<ParamList>}`
-    fn expect_enum(&mut self, attributes: AttributeListGreen) -> ItemEnumGreen {
+    fn expect_enum(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemEnumGreen {
         let enum_kw = self.take::<TerminalEnum>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -227,6 +248,7 @@ impl<'a> Parser<'a> {
         ItemEnum::new_green(
             self.db,
             attributes,
+            visibility,
             enum_kw,
             name,
             generic_params,
@@ -238,7 +260,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is type.
     /// Expected pattern: `type <Identifier>{<ParamList>} = <TypeExpression>`
-    fn expect_type_alias(&mut self, attributes: AttributeListGreen) -> ItemTypeAliasGreen {
+    fn expect_type_alias(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemTypeAliasGreen {
         let type_kw = self.take::<TerminalType>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -248,6 +274,7 @@ impl<'a> Parser<'a> {
         ItemTypeAlias::new_green(
             self.db,
             attributes,
+            visibility,
             type_kw,
             name,
             generic_params,
@@ -283,7 +310,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is [TerminalConst].
     /// Expected pattern: `const <Identifier> = <Expr>;`
-    fn expect_const(&mut self, attributes: AttributeListGreen) -> ItemConstantGreen {
+    fn expect_const(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemConstantGreen {
         let const_kw = self.take::<TerminalConst>();
         let name = self.parse_identifier();
         let type_clause = self.parse_type_clause(ErrorRecovery {
@@ -296,6 +327,7 @@ impl<'a> Parser<'a> {
         ItemConstant::new_green(
             self.db,
             attributes,
+            visibility,
             const_kw,
             name,
             type_clause,
@@ -307,8 +339,12 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Extern.
     /// Expected pattern: `extern(<FunctionDeclaration>|type<Identifier>);`
-    fn expect_extern_item(&mut self, attributes: AttributeListGreen) -> ItemGreen {
-        match self.expect_extern_item_inner(attributes) {
+    fn expect_extern_item(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemGreen {
+        match self.expect_extern_item_inner(attributes, visibility) {
             ExternItem::Function(x) => x.into(),
             ExternItem::Type(x) => x.into(),
         }
@@ -316,8 +352,12 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Extern.
     /// Expected pattern: `extern(<FunctionDeclaration>|type<Identifier>);`
-    fn expect_extern_impl_item(&mut self, attributes: AttributeListGreen) -> ImplItemGreen {
-        match self.expect_extern_item_inner(attributes) {
+    fn expect_extern_impl_item(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ImplItemGreen {
+        match self.expect_extern_item_inner(attributes, visibility) {
             ExternItem::Function(x) => x.into(),
             ExternItem::Type(x) => x.into(),
         }
@@ -325,7 +365,11 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Extern.
     /// Expected pattern: `extern(<FunctionDeclaration>|type<Identifier>);`
-    fn expect_extern_item_inner(&mut self, attributes: AttributeListGreen) -> ExternItem {
+    fn expect_extern_item_inner(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ExternItem {
         let extern_kw = self.take::<TerminalExtern>();
         match self.peek().kind {
             SyntaxKind::TerminalFunction => {
@@ -334,6 +378,7 @@ impl<'a> Parser<'a> {
                 ExternItem::Function(ItemExternFunction::new_green(
                     self.db,
                     attributes,
+                    visibility,
                     extern_kw,
                     declaration,
                     semicolon,
@@ -350,6 +395,7 @@ impl<'a> Parser<'a> {
                 ExternItem::Type(ItemExternType::new_green(
                     self.db,
                     attributes,
+                    visibility,
                     extern_kw,
                     type_kw,
                     name,
@@ -362,11 +408,15 @@ impl<'a> Parser<'a> {
 
     /// Assumes the current token is Use.
     /// Expected pattern: `use<Path>;`
-    fn expect_use(&mut self, attributes: AttributeListGreen) -> ItemUseGreen {
+    fn expect_use(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemUseGreen {
         let use_kw = self.take::<TerminalUse>();
         let use_path = self.parse_use_path();
         let semicolon = self.parse_token::<TerminalSemicolon>();
-        ItemUse::new_green(self.db, attributes, use_kw, use_path, semicolon)
+        ItemUse::new_green(self.db, attributes, visibility, use_kw, use_path, semicolon)
     }
 
     /// Returns a GreenId of a node with a UsePath kind or None if can't parse a UsePath.
@@ -512,6 +562,24 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the visibility of an item or a struct member.
+    /// Expected pattern: `pub(<Identifier>)?`, or nothing for the default visibility.
+    fn parse_visibility(&mut self) -> VisibilityGreen {
+        if self.peek().kind != SyntaxKind::TerminalPub {
+            return VisibilityDefault::new_green(self.db).into();
+        }
+        let pub_kw = self.take::<TerminalPub>();
+        let argument_clause = if self.peek().kind == SyntaxKind::TerminalLParen {
+            let lparen = self.take::<TerminalLParen>();
+            let argument = self.parse_identifier();
+            let rparen = self.parse_token::<TerminalRParen>();
+            VisibilityPubArgumentClause::new_green(self.db, lparen, argument, rparen).into()
+        } else {
+            OptionVisibilityPubArgumentClauseEmpty::new_green(self.db).into()
+        };
+        VisibilityPub::new_green(self.db, pub_kw, argument_clause).into()
+    }
+
     /// Assumes the current token is Function.
     /// Expected pattern: `<FunctionDeclaration>`
     fn expect_function_declaration(&mut self) -> FunctionDeclarationGreen {
@@ -528,14 +596,19 @@ impl<'a> Parser<'a> {
     fn expect_function_with_body(
         &mut self,
         attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
     ) -> FunctionWithBodyGreen {
         let declaration = self.expect_function_declaration();
         let function_body = self.parse_block();
-        FunctionWithBody::new_green(self.db, attributes, declaration, function_body)
+        FunctionWithBody::new_green(self.db, attributes, visibility, declaration, function_body)
     }
 
     /// Assumes the current token is Trait.
-    fn expect_trait(&mut self, attributes: AttributeListGreen) -> ItemTraitGreen {
+    fn expect_trait(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemTraitGreen {
         let trait_kw = self.take::<TerminalTrait>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -555,7 +628,7 @@ impl<'a> Parser<'a> {
             self.parse_token::<TerminalSemicolon>().into()
         };
 
-        ItemTrait::new_green(self.db, attributes, trait_kw, name, generic_params, body)
+        ItemTrait::new_green(self.db, attributes, visibility, trait_kw, name, generic_params, body)
     }
 
     /// Returns a GreenId of a node with a TraitItem.* kind (see
@@ -596,23 +669,35 @@ impl<'a> Parser<'a> {
     }
 
     /// Assumes the current token is Impl.
-    fn expect_item_impl(&mut self, attributes: AttributeListGreen) -> ItemGreen {
-        match self.expect_impl_inner(attributes) {
+    fn expect_item_impl(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ItemGreen {
+        match self.expect_impl_inner(attributes, visibility) {
             ImplItemOrAlias::Item(green) => green.into(),
             ImplItemOrAlias::Alias(green) => green.into(),
         }
     }
 
     /// Assumes the current token is Impl.
-    fn expect_impl_item_impl(&mut self, attributes: AttributeListGreen) -> ImplItemGreen {
-        match self.expect_impl_inner(attributes) {
+    fn expect_impl_item_impl(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ImplItemGreen {
+        match self.expect_impl_inner(attributes, visibility) {
             ImplItemOrAlias::Item(green) => green.into(),
             ImplItemOrAlias::Alias(green) => green.into(),
         }
     }
 
     /// Assumes the current token is Impl.
-    fn expect_impl_inner(&mut self, attributes: AttributeListGreen) -> ImplItemOrAlias {
+    fn expect_impl_inner(
+        &mut self,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+    ) -> ImplItemOrAlias {
         let impl_kw = self.take::<TerminalImpl>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
@@ -625,6 +710,7 @@ impl<'a> Parser<'a> {
             return ImplItemOrAlias::Alias(ItemImplAlias::new_green(
                 self.db,
                 attributes,
+                visibility,
                 impl_kw,
                 name,
                 generic_params,
@@ -655,6 +741,7 @@ impl<'a> Parser<'a> {
         ImplItemOrAlias::Item(ItemImpl::new_green(
             self.db,
             attributes,
+            visibility,
             impl_kw,
             name,
             generic_params,
@@ -674,24 +761,34 @@ impl<'a> Parser<'a> {
             Some(attributes) => (true, attributes),
             None => (false, AttributeList::new_green(self.db, vec![])),
         };
+        let has_visibility = self.peek().kind == SyntaxKind::TerminalPub;
+        let visibility = self.parse_visibility();
 
         match self.peek().kind {
-            SyntaxKind::TerminalFunction => Some(self.expect_function_with_body(attributes).into()),
+            SyntaxKind::TerminalFunction => {
+                Some(self.expect_function_with_body(attributes, visibility).into())
+            }
             // These are not supported semantically.
-            SyntaxKind::TerminalConst => Some(self.expect_const(attributes).into()),
-            SyntaxKind::TerminalModule => Some(self.expect_module(attributes).into()),
-            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes).into()),
-            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes).into()),
-            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes).into()),
-            SyntaxKind::TerminalExtern => Some(self.expect_extern_impl_item(attributes)),
-            SyntaxKind::TerminalUse => Some(self.expect_use(attributes).into()),
-            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes).into()),
-            SyntaxKind::TerminalImpl => Some(self.expect_impl_item_impl(attributes)),
+            SyntaxKind::TerminalConst => Some(self.expect_const(attributes, visibility).into()),
+            SyntaxKind::TerminalModule => Some(self.expect_module(attributes, visibility).into()),
+            SyntaxKind::TerminalStruct => Some(self.expect_struct(attributes, visibility).into()),
+            SyntaxKind::TerminalEnum => Some(self.expect_enum(attributes, visibility).into()),
+            SyntaxKind::TerminalType => Some(self.expect_type_alias(attributes, visibility).into()),
+            SyntaxKind::TerminalExtern => {
+                Some(self.expect_extern_impl_item(attributes, visibility))
+            }
+            SyntaxKind::TerminalUse => Some(self.expect_use(attributes, visibility).into()),
+            SyntaxKind::TerminalTrait => Some(self.expect_trait(attributes, visibility).into()),
+            SyntaxKind::TerminalImpl => Some(self.expect_impl_item_impl(attributes, visibility)),
             _ => {
                 if has_attrs {
                     Some(self.create_and_report_missing::<ImplItem>(
                         ParserDiagnosticKind::AttributesWithoutImplItem,
                     ))
+                } else if has_visibility {
+                    Some(self.create_and_report_missing::<ImplItem>(
+                        ParserDiagnosticKind::VisibilityWithoutImplItem,
+                    ))
                 } else {
                     None
                 }
@@ -846,6 +943,7 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalTrue => Some(self.take::<TerminalTrue>().into()),
             SyntaxKind::TerminalLiteralNumber => Some(self.take::<TerminalLiteralNumber>().into()),
             SyntaxKind::TerminalShortString => Some(self.take::<TerminalShortString>().into()),
+            SyntaxKind::TerminalString => Some(self.take::<TerminalString>().into()),
             SyntaxKind::TerminalLParen => {
                 // Note that LBrace is allowed inside parenthesis, even if `lbrace_allowed` is
                 // [LbraceAllowed::Forbid].
@@ -863,6 +961,15 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalLoop if lbrace_allowed == LbraceAllowed::Allow => {
                 Some(self.expect_loop_expr().into())
             }
+            SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_while_expr().into())
+            }
+            SyntaxKind::TerminalFor if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_for_expr().into())
+            }
+            SyntaxKind::TerminalOr | SyntaxKind::TerminalOrOr => {
+                Some(self.expect_closure_expr(lbrace_allowed).into())
+            }
 
             _ => {
                 // TODO(yuval): report to diagnostics.
@@ -871,6 +978,59 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Assumes the current token is `|` or `||`.
+    /// Expected pattern: `|<ClosureParamList>| <ReturnTypeClause>? <Expr>` or
+    /// `|| <ReturnTypeClause>? <Expr>`.
+    fn expect_closure_expr(&mut self, lbrace_allowed: LbraceAllowed) -> ExprClosureGreen {
+        let wrapper = if self.peek().kind == SyntaxKind::TerminalOrOr {
+            self.take::<TerminalOrOr>().into()
+        } else {
+            let leftor = self.take::<TerminalOr>();
+            let params = ClosureParamList::new_green(
+                self.db,
+                self.parse_separated_list::<
+                    ClosureParam,
+                    TerminalComma,
+                    ClosureParamListElementOrSeparatorGreen,
+                >(
+                    Self::try_parse_closure_param,
+                    is_of_kind!(or, rparen, block, lbrace, rbrace, top_level),
+                    "closure parameter",
+                ),
+            );
+            let rightor = self.parse_token::<TerminalOr>();
+            ClosureParamWrapperNAry::new_green(self.db, leftor, params, rightor).into()
+        };
+        // With an explicit return type, the body must be a block.
+        let has_ret_ty = self.peek().kind == SyntaxKind::TerminalArrow;
+        let ret_ty = self.parse_option_return_type_clause();
+        let expr = if has_ret_ty {
+            self.parse_block().into()
+        } else {
+            self.parse_expr_limited(MAX_PRECEDENCE, lbrace_allowed)
+        };
+        ExprClosure::new_green(self.db, wrapper, ret_ty, expr)
+    }
+
+    /// Returns a GreenId of a node with kind ClosureParam or None if a closure parameter can't be
+    /// parsed.
+    fn try_parse_closure_param(&mut self) -> Option<ClosureParamGreen> {
+        let modifier_list = self.parse_modifier_list();
+        let name = if modifier_list.is_empty() {
+            self.try_parse_identifier()?
+        } else {
+            // If we had modifiers then the identifier is not optional and can't be '_'.
+            self.parse_identifier()
+        };
+        let type_clause = self.parse_option_type_clause();
+        Some(ClosureParam::new_green(
+            self.db,
+            ModifierList::new_green(self.db, modifier_list),
+            name,
+            type_clause,
+        ))
+    }
+
     /// Returns a GreenId of a node with an ExprPath|ExprParenthesized|ExprTuple kind, or None if
     /// such an expression can't be parsed.
     fn try_parse_type_expr(&mut self) -> Option<ExprGreen> {
@@ -927,13 +1087,25 @@ impl<'a> Parser<'a> {
         ExprFunctionCall::new_green(self.db, func_name, parenthesized_args)
     }
 
-    /// Assumes the current token is LParen.
-    /// Expected pattern: `<ArgListParenthesized>`
+    /// Assumes the current token is Not.
+    /// Expected pattern: `!<WrappedArgList>`
     fn expect_macro_call(&mut self, path: ExprPathGreen) -> ExprInlineMacroGreen {
         let bang = self.take::<TerminalNot>();
         let macro_name = path;
-        let parenthesized_args = self.expect_parenthesized_argument_list();
-        ExprInlineMacro::new_green(self.db, macro_name, bang, parenthesized_args)
+        let wrapped_args = self.parse_wrapped_argument_list();
+        ExprInlineMacro::new_green(self.db, macro_name, bang, wrapped_args)
+    }
+
+    /// Returns a GreenId of a node with an ArgListParenthesized|ArgListBracketed|
+    /// WrappedArgListMissing kind.
+    fn parse_wrapped_argument_list(&mut self) -> WrappedArgListGreen {
+        match self.peek().kind {
+            SyntaxKind::TerminalLParen => self.expect_parenthesized_argument_list().into(),
+            SyntaxKind::TerminalLBrack => self.expect_bracketed_argument_list().into(),
+            _ => self.create_and_report_missing::<WrappedArgList>(
+                ParserDiagnosticKind::MissingWrappedArgList,
+            ),
+        }
     }
 
     /// Assumes the current token is LParen.
@@ -952,6 +1124,22 @@ impl<'a> Parser<'a> {
         ArgListParenthesized::new_green(self.db, lparen, arg_list, rparen)
     }
 
+    /// Assumes the current token is LBrack.
+    /// Expected pattern: `\[<ArgList>\]`
+    fn expect_bracketed_argument_list(&mut self) -> ArgListBracketedGreen {
+        let lbrack = self.take::<TerminalLBrack>();
+        let arg_list = ArgList::new_green(
+            self.db,
+            self.parse_separated_list::<Arg, TerminalComma, ArgListElementOrSeparatorGreen>(
+                Self::try_parse_function_argument,
+                is_of_kind!(rbrack, block, rbrace, top_level),
+                "argument",
+            ),
+        );
+        let rbrack = self.parse_token::<TerminalRBrack>();
+        ArgListBracketed::new_green(self.db, lbrack, arg_list, rbrack)
+    }
+
     /// Tries to parse parenthesized function call arguments.
     /// Expected pattern: `\(<ArgList>\)`
     fn try_parse_parenthesized_argument_list(&mut self) -> OptionArgListParenthesizedGreen {
@@ -1223,6 +1411,36 @@ impl<'a> Parser<'a> {
         ExprLoop::new_green(self.db, loop_kw, body)
     }
 
+    /// Assumes the current token is `While`.
+    /// Expected pattern: `while <expr> <block>`.
+    fn expect_while_expr(&mut self) -> ExprWhileGreen {
+        let while_kw = self.take::<TerminalWhile>();
+        let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprWhile::new_green(self.db, while_kw, condition, body)
+    }
+
+    /// Assumes the current token is `For`.
+    /// Expected pattern: `for <pattern> in <expr> <block>`.
+    /// Note that `in` is not a keyword, and is parsed as an identifier.
+    fn expect_for_expr(&mut self) -> ExprForGreen {
+        let for_kw = self.take::<TerminalFor>();
+        let pattern = self.parse_pattern();
+        let in_identifier =
+            if self.peek().kind == SyntaxKind::TerminalIdentifier && self.peek().text == "in" {
+                self.take::<TerminalIdentifier>()
+            } else {
+                self.create_and_report_missing::<TerminalIdentifier>(
+                    ParserDiagnosticKind::MissingInKeyword,
+                )
+            };
+        let expr = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprFor::new_green(self.db, for_kw, pattern, in_identifier, expr, body)
+    }
+
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
         let pattern = self.try_parse_pattern()?;
@@ -1243,7 +1461,17 @@ impl<'a> Parser<'a> {
 
         // TODO(yuval): Support "Or" patterns.
         Some(match self.peek().kind {
-            SyntaxKind::TerminalLiteralNumber => self.take::<TerminalLiteralNumber>().into(),
+            SyntaxKind::TerminalLiteralNumber => {
+                let literal = self.take::<TerminalLiteralNumber>();
+                match self.peek().kind {
+                    SyntaxKind::TerminalDotDot => {
+                        let dotdot = self.take::<TerminalDotDot>();
+                        let end = self.parse_token::<TerminalLiteralNumber>();
+                        PatternRange::new_green(self.db, literal, dotdot, end).into()
+                    }
+                    _ => literal.into(),
+                }
+            }
             SyntaxKind::TerminalShortString => self.take::<TerminalShortString>().into(),
             SyntaxKind::TerminalUnderscore => self.take::<TerminalUnderscore>().into(),
             SyntaxKind::TerminalIdentifier => {
@@ -1521,7 +1749,9 @@ impl<'a> Parser<'a> {
     fn try_parse_member(&mut self) -> Option<MemberGreen> {
         let attributes =
             self.try_parse_attribute_list("Struct member", |x| x != SyntaxKind::TerminalHash);
-        let name = if attributes.is_some() {
+        let has_visibility = self.peek().kind == SyntaxKind::TerminalPub;
+        let visibility = self.parse_visibility();
+        let name = if attributes.is_some() || has_visibility {
             self.parse_identifier()
         } else {
             self.try_parse_identifier()?
@@ -1531,7 +1761,7 @@ impl<'a> Parser<'a> {
         let type_clause = self.parse_type_clause(ErrorRecovery {
             should_stop: is_of_kind!(comma, rbrace, top_level),
         });
-        Some(Member::new_green(self.db, attributes, name, type_clause))
+        Some(Member::new_green(self.db, attributes, visibility, name, type_clause))
     }
 
     /// Expected pattern: `<PathSegment>(::<PathSegment>)*`
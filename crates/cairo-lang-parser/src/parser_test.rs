@@ -345,9 +345,12 @@ cairo_lang_test_utils::test_file_test!(
         attribute_errors: "attribute_errors",
         module: "module",
         op_eq: "op_eq",
+        range_pattern: "range_pattern",
         array: "array",
         attrs: "attrs",
         inline_macro: "inline_macro",
+        closure: "closure",
+        visibility: "visibility",
     },
     test_partial_parser_tree
 );
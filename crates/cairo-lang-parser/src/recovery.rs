@@ -34,6 +34,13 @@ macro_rules! rparen {
 }
 pub(crate) use rparen;
 
+macro_rules! rbrack {
+    () => {
+        SyntaxKind::TerminalRBrack
+    };
+}
+pub(crate) use rbrack;
+
 macro_rules! rangle {
     () => {
         SyntaxKind::TerminalGT
@@ -41,6 +48,13 @@ macro_rules! rangle {
 }
 pub(crate) use rangle;
 
+macro_rules! or {
+    () => {
+        SyntaxKind::TerminalOr
+    };
+}
+pub(crate) use or;
+
 macro_rules! comma {
     () => {
         SyntaxKind::TerminalComma
@@ -70,6 +84,7 @@ macro_rules! top_level {
             | SyntaxKind::TerminalFunction
             | SyntaxKind::TerminalImpl
             | SyntaxKind::TerminalModule
+            | SyntaxKind::TerminalPub
             | SyntaxKind::TerminalStruct
             | SyntaxKind::TerminalTrait
             | SyntaxKind::TerminalType
@@ -40,6 +40,11 @@ pub fn validate(
                 validate_short_string(node, db, diagnostics, file_id)
             }
 
+            SyntaxKind::TerminalString => {
+                let node = ast::TerminalString::from_syntax_node(db, node);
+                validate_string(node, db, diagnostics, file_id)
+            }
+
             _ => Ok(()),
         })
     })
@@ -171,3 +176,45 @@ fn validate_short_string(
 
     result
 }
+
+/// Validate that the string literal is valid, after it is consumed by the parser.
+///
+/// Cairo parser tries to consume even not proper tokens in order to support code editions in IDEs.
+/// This means that it omits some crucial details in the literals that make the code uncompilable.
+/// This function validates that the literal:
+/// 1. Has double quotes on both sides (parser accepts unterminated literals).
+/// 2. Has all escape sequences valid.
+/// 3. Is entirely ASCII.
+fn validate_string(
+    node: ast::TerminalString,
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut DiagnosticsBuilder<ParserDiagnostic>,
+    file_id: FileId,
+) -> Maybe<()> {
+    let text = node.text(db);
+    let Some(body) = text.strip_prefix('"').and_then(|text| text.strip_suffix('"')) else {
+        return Err(diagnostics.add(ParserDiagnostic {
+            file_id,
+            span: node.as_syntax_node().span(db),
+            kind: ParserDiagnosticKind::UnterminatedString,
+        }));
+    };
+
+    let Ok(body) = unescape(body) else {
+        return Err(diagnostics.add(ParserDiagnostic {
+            file_id,
+            span: node.as_syntax_node().span(db),
+            kind: ParserDiagnosticKind::IllegalStringEscaping,
+        }));
+    };
+
+    if !body.is_ascii() {
+        return Err(diagnostics.add(ParserDiagnostic {
+            file_id,
+            span: node.as_syntax_node().span(db),
+            kind: ParserDiagnosticKind::StringMustBeAscii,
+        }));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use cairo_lang_defs::plugin::{
+    InlineMacroCodePiece, InlineMacroPlugin, InlinePluginResult, PluginDiagnostic,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+
+/// The `array!` inline macro, creating an array of the given elements,
+/// e.g. `array![1, 2, 3]`.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct ArrayMacro;
+impl InlineMacroPlugin for ArrayMacro {
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        syntax: &ast::ExprInlineMacro,
+    ) -> InlinePluginResult {
+        // A missing argument list is reported by the parser.
+        let Some(args) = syntax.arguments(db).arg_list(db) else {
+            return InlinePluginResult::default();
+        };
+        let mut code = vec![InlineMacroCodePiece::from(
+            "{\n    let mut __array_builder_macro_result__ = core::array::array_new();\n",
+        )];
+        for arg in args.elements(db) {
+            let ast::ArgClause::Unnamed(arg_clause) = arg.arg_clause(db) else {
+                return unsupported_argument(&arg);
+            };
+            if !arg.modifiers(db).elements(db).is_empty() {
+                return unsupported_argument(&arg);
+            }
+            code.push("    core::array::array_append(ref __array_builder_macro_result__, ".into());
+            code.push(arg_clause.value(db).as_syntax_node().into());
+            code.push(");\n".into());
+        }
+        code.push("    __array_builder_macro_result__\n}".into());
+        InlinePluginResult { code: Some(code), diagnostics: vec![] }
+    }
+}
+
+/// Returns a result reporting that the given argument is not supported by the `array!` macro.
+fn unsupported_argument(arg: &ast::Arg) -> InlinePluginResult {
+    InlinePluginResult {
+        code: None,
+        diagnostics: vec![PluginDiagnostic::error(
+            arg.stable_ptr().untyped(),
+            "`array!` arguments must be unnamed, with no modifiers.".into(),
+        )],
+    }
+}
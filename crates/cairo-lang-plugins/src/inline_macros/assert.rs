@@ -0,0 +1,63 @@
+use cairo_lang_defs::plugin::{
+    InlineMacroCodePiece, InlineMacroPlugin, InlinePluginResult, PluginDiagnostic,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+
+/// The `assert!` inline macro, panicking if the given condition does not hold,
+/// e.g. `assert!(x == 1)` or `assert!(x == 1, 'x is not 1')`.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct AssertMacro;
+impl InlineMacroPlugin for AssertMacro {
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        syntax: &ast::ExprInlineMacro,
+    ) -> InlinePluginResult {
+        // A missing argument list is reported by the parser.
+        let Some(args) = syntax.arguments(db).arg_list(db) else {
+            return InlinePluginResult::default();
+        };
+        let mut values = vec![];
+        for arg in args.elements(db) {
+            match arg.arg_clause(db) {
+                ast::ArgClause::Unnamed(arg_clause)
+                    if arg.modifiers(db).elements(db).is_empty() =>
+                {
+                    values.push(InlineMacroCodePiece::from(arg_clause.value(db).as_syntax_node()))
+                }
+                _ => return failure(arg.stable_ptr().untyped()),
+            }
+        }
+        let (condition, err_code) = match &values[..] {
+            [condition] => (condition.clone(), "'assertion failed'".into()),
+            [condition, err_code] => (condition.clone(), err_code.clone()),
+            _ => return failure(syntax.stable_ptr().untyped()),
+        };
+        InlinePluginResult {
+            code: Some(vec![
+                "if !(".into(),
+                condition,
+                ") { core::panic_with_felt252(".into(),
+                err_code,
+                ") }".into(),
+            ]),
+            diagnostics: vec![],
+        }
+    }
+}
+
+/// Returns a result reporting that the arguments of the `assert!` macro are invalid.
+fn failure(stable_ptr: SyntaxStablePtrId) -> InlinePluginResult {
+    InlinePluginResult {
+        code: None,
+        diagnostics: vec![PluginDiagnostic::error(
+            stable_ptr,
+            "`assert!` expects a condition and an optional error code, as unnamed arguments."
+                .into(),
+        )],
+    }
+}
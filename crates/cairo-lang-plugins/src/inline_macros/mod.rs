@@ -0,0 +1,5 @@
+pub use array::*;
+pub use assert::*;
+
+mod array;
+mod assert;
@@ -1,12 +1,16 @@
 //! Cairo core plugin implementations.
 use std::sync::Arc;
 
+use cairo_lang_defs::plugin::InlineMacroPlugin;
 use cairo_lang_semantic::plugin::SemanticPlugin;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
+use crate::inline_macros::{ArrayMacro, AssertMacro};
 use crate::plugins::{
     ConfigPlugin, ConstevalIntMacroPlugin, DerivePlugin, GenerateTraitPlugin, PanicablePlugin,
 };
 
+pub mod inline_macros;
 pub mod plugins;
 
 #[cfg(test)]
@@ -22,3 +26,12 @@ pub fn get_default_plugins() -> Vec<Arc<dyn SemanticPlugin>> {
         Arc::new(ConfigPlugin::default()),
     ]
 }
+
+/// Gets the default inline macro plugins to load into the Cairo compiler, by the name of the macro
+/// they implement.
+pub fn get_default_inline_macro_plugins() -> OrderedHashMap<String, Arc<dyn InlineMacroPlugin>> {
+    let mut res = OrderedHashMap::<String, Arc<dyn InlineMacroPlugin>>::default();
+    res.insert("array".into(), Arc::new(ArrayMacro));
+    res.insert("assert".into(), Arc::new(AssertMacro));
+    res
+}
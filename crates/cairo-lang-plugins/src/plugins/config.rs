@@ -69,18 +69,18 @@ fn parse_predicate_item(
 ) -> Option<Cfg> {
     match arg.variant {
         AttributeArgVariant::FieldInitShorthand { .. } => {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: arg.arg_stable_ptr.untyped(),
-                message: "This attribute does not support field initialization shorthands.".into(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                arg.arg_stable_ptr.untyped(),
+                "This attribute does not support field initialization shorthands.".into(),
+            ));
             None
         }
         AttributeArgVariant::Named { name, value, value_stable_ptr, .. } => {
             let ast::Expr::ShortString(terminal) = value else {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: value_stable_ptr.untyped(),
-                    message: "Expected short string.".into(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    value_stable_ptr.untyped(),
+                    "Expected short string.".into(),
+                ));
                 return None;
             };
 
@@ -90,17 +90,17 @@ fn parse_predicate_item(
         }
         AttributeArgVariant::Unnamed { value, value_stable_ptr, .. } => {
             let ast::Expr::Path(path) = value else {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: value_stable_ptr.untyped(),
-                    message: "Expected identifier.".into(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    value_stable_ptr.untyped(),
+                    "Expected identifier.".into(),
+                ));
                 return None;
             };
             let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] else {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: value_stable_ptr.untyped(),
-                    message: "Expected simple path.".into(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    value_stable_ptr.untyped(),
+                    "Expected simple path.".into(),
+                ));
                 return None;
             };
             let key = segment.ident(db).text(db);
@@ -5,6 +5,7 @@ use cairo_lang_defs::plugin::{
 };
 use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPluginAuxData};
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use num_bigint::BigInt;
 
@@ -74,21 +75,22 @@ fn extract_consteval_macro_expression(
     macro_ast: &ast::ExprInlineMacro,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) -> Option<ast::Expr> {
-    let args = macro_ast.arguments(db).args(db).elements(db);
+    // A missing argument list is reported by the parser.
+    let args = macro_ast.arguments(db).arg_list(db)?.elements(db);
     if args.len() != 1 {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: macro_ast.stable_ptr().untyped(),
-            message: "consteval_int macro must have a single unnamed argument.".to_string(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            macro_ast.stable_ptr().untyped(),
+            "consteval_int macro must have a single unnamed argument.".to_string(),
+        ));
         return None;
     }
     match args[0].arg_clause(db) {
         ast::ArgClause::Unnamed(arg) => Some(arg.value(db)),
         _ => {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: macro_ast.stable_ptr().untyped(),
-                message: "consteval_int macro must have a single unnamed argument.".to_string(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                macro_ast.stable_ptr().untyped(),
+                "consteval_int macro must have a single unnamed argument.".to_string(),
+            ));
             None
         }
     }
@@ -137,10 +139,10 @@ fn compute_constant_expr(
                     ^ compute_constant_expr(db, &bin_expr.rhs(db), diagnostics)?,
             ),
             _ => {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: bin_expr.stable_ptr().untyped(),
-                    message: "Unsupported binary operator in consteval_int macro".to_string(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    bin_expr.stable_ptr().untyped(),
+                    "Unsupported binary operator in consteval_int macro".to_string(),
+                ));
                 None
             }
         },
@@ -149,10 +151,10 @@ fn compute_constant_expr(
                 Some(-compute_constant_expr(db, &un_expr.expr(db), diagnostics)?)
             }
             _ => {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: un_expr.stable_ptr().untyped(),
-                    message: "Unsupported unary operator in consteval_int macro".to_string(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    un_expr.stable_ptr().untyped(),
+                    "Unsupported unary operator in consteval_int macro".to_string(),
+                ));
                 None
             }
         },
@@ -160,10 +162,10 @@ fn compute_constant_expr(
             compute_constant_expr(db, &paren_expr.expr(db), diagnostics)
         }
         _ => {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: value.stable_ptr().untyped(),
-                message: "Unsupported expression in consteval_int macro".to_string(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                value.stable_ptr().untyped(),
+                "Unsupported expression in consteval_int macro".to_string(),
+            ));
             None
         }
     }
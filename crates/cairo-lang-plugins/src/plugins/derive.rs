@@ -28,18 +28,21 @@ impl MacroPlugin for DerivePlugin {
                 db,
                 struct_ast.name(db),
                 struct_ast.attributes(db),
+                struct_ast.visibility(db),
                 extract_struct_extra_info(db, &struct_ast),
             ),
             ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
                 db,
                 enum_ast.name(db),
                 enum_ast.attributes(db),
+                enum_ast.visibility(db),
                 ExtraInfo::Enum(member_names(db, enum_ast.variants(db))),
             ),
             ast::Item::ExternType(extern_type_ast) => generate_derive_code_for_type(
                 db,
                 extern_type_ast.name(db),
                 extern_type_ast.attributes(db),
+                extern_type_ast.visibility(db),
                 ExtraInfo::Extern,
             ),
             _ => PluginResult::default(),
@@ -118,6 +121,7 @@ fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
     ident: ast::TerminalIdentifier,
     attributes: AttributeList,
+    visibility: ast::Visibility,
     extra_info: ExtraInfo,
 ) -> PluginResult {
     let mut diagnostics = vec![];
@@ -126,10 +130,10 @@ fn generate_derive_code_for_type(
         let attr = attr.structurize(db);
 
         if attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: attr.args_stable_ptr.untyped(),
-                message: "Expected args.".into(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                "Expected args.".into(),
+            ));
             continue;
         }
 
@@ -142,10 +146,10 @@ fn generate_derive_code_for_type(
                 },
                 ..
             } = arg else {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: arg.arg_stable_ptr.untyped(),
-                    message: "Expected path.".into(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    arg.arg_stable_ptr.untyped(),
+                    "Expected path.".into(),
+                ));
                 continue;
             };
 
@@ -173,10 +177,10 @@ fn generate_derive_code_for_type(
                     impls.push(get_serde_impl(&name, &extra_info))
                 }
                 "Clone" | "Destruct" | "PartialEq" | "Serde" => {
-                    diagnostics.push(PluginDiagnostic {
-                        stable_ptr: value_stable_ptr.untyped(),
-                        message: "Unsupported trait for derive for extern types.".into(),
-                    })
+                    diagnostics.push(PluginDiagnostic::error(
+                        value_stable_ptr.untyped(),
+                        "Unsupported trait for derive for extern types.".into(),
+                    ))
                 }
                 _ => {
                     // TODO(spapini): How to allow downstream derives while also
@@ -191,7 +195,11 @@ fn generate_derive_code_for_type(
         } else {
             Some(PluginGeneratedFile {
                 name: "impls".into(),
-                content: impls.join(""),
+                // The generated impls have the same visibility as the type.
+                content: impls
+                    .into_iter()
+                    .map(|impl_code| format!("{}{impl_code}", visibility.as_item_prefix(db)))
+                    .join(""),
                 aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
             })
         },
@@ -45,11 +45,10 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
     let [trait_ast_segment] = &trait_ast.elements(db)[..] else {
         return PluginResult {
             code: None,
-            diagnostics: vec![
-                PluginDiagnostic {
-                    stable_ptr: trait_ast.stable_ptr().untyped(),
-                    message: "Generated trait must have a single element path.".to_string(),
-                }],
+            diagnostics: vec![PluginDiagnostic::error(
+                trait_ast.stable_ptr().untyped(),
+                "Generated trait must have a single element path.".to_string(),
+            )],
             remove_original_item: false,
         };
     };
@@ -73,10 +72,10 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
                     .collect()
             }
             _ => {
-                diagnostics.push(PluginDiagnostic {
-                    stable_ptr: attr_arg.arg_stable_ptr.untyped(),
-                    message: "Expected an argument with the name `trait_attrs`.".to_string(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    attr_arg.arg_stable_ptr.untyped(),
+                    "Expected an argument with the name `trait_attrs`.".to_string(),
+                ));
                 vec![]
             }
         })
@@ -120,11 +119,11 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
     };
     let trait_identifier = trait_identifier.text(db);
     if !generic_params_match {
-        diagnostics.push(PluginDiagnostic {
-            stable_ptr: trait_ast.stable_ptr().untyped(),
-            message: "Generated trait must have generic args matching the impl's generic params."
+        diagnostics.push(PluginDiagnostic::error(
+            trait_ast.stable_ptr().untyped(),
+            "Generated trait must have generic args matching the impl's generic params."
                 .to_string(),
-        });
+        ));
     }
     let signatures = match impl_ast.body(db) {
         ast::MaybeImplBody::Some(body) => body.items(db).elements(db),
@@ -158,11 +157,13 @@ fn generate_trait_for_impl(db: &dyn SyntaxGroup, impl_ast: ItemImpl) -> PluginRe
         }
     }).join("\n");
     let impl_generic_params = impl_generic_params.as_syntax_node().get_text(db);
+    // The generated trait has the same visibility as the impl.
+    let visibility = impl_ast.visibility(db).as_item_prefix(db);
     PluginResult {
         code: Some(PluginGeneratedFile {
             name: "generate_trait".into(),
             content: formatdoc! {"
-            {trait_attrs}trait {trait_identifier}{impl_generic_params} {{
+            {trait_attrs}{visibility}trait {trait_identifier}{impl_generic_params} {{
             {signatures}}}
         "},
             aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
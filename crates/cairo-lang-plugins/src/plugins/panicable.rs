@@ -20,17 +20,21 @@ pub struct PanicablePlugin;
 
 impl MacroPlugin for PanicablePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
-        let (declaration, attributes) = match item_ast {
-            ast::Item::ExternFunction(extern_func_ast) => {
-                (extern_func_ast.declaration(db), extern_func_ast.attributes(db))
-            }
-            ast::Item::FreeFunction(free_func_ast) => {
-                (free_func_ast.declaration(db), free_func_ast.attributes(db))
-            }
+        let (declaration, attributes, visibility) = match item_ast {
+            ast::Item::ExternFunction(extern_func_ast) => (
+                extern_func_ast.declaration(db),
+                extern_func_ast.attributes(db),
+                extern_func_ast.visibility(db),
+            ),
+            ast::Item::FreeFunction(free_func_ast) => (
+                free_func_ast.declaration(db),
+                free_func_ast.attributes(db),
+                free_func_ast.visibility(db),
+            ),
             _ => return PluginResult::default(),
         };
 
-        generate_panicable_code(db, declaration, attributes)
+        generate_panicable_code(db, declaration, attributes, visibility)
     }
 }
 impl AsDynMacroPlugin for PanicablePlugin {
@@ -48,6 +52,7 @@ fn generate_panicable_code(
     db: &dyn SyntaxGroup,
     declaration: ast::FunctionDeclaration,
     attributes: ast::AttributeList,
+    visibility: ast::Visibility,
 ) -> PluginResult {
     let mut attrs = attributes.query_attr(db, "panic_with");
     if attrs.is_empty() {
@@ -57,11 +62,10 @@ fn generate_panicable_code(
         let extra_attr = attrs.swap_remove(1);
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                stable_ptr: extra_attr.stable_ptr().untyped(),
-                message: "`#[panic_with]` cannot be applied multiple times to the same item."
-                    .into(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                extra_attr.stable_ptr().untyped(),
+                "`#[panic_with]` cannot be applied multiple times to the same item.".into(),
+            )],
             remove_original_item: false,
         };
     }
@@ -72,11 +76,10 @@ fn generate_panicable_code(
         extract_success_ty_and_variants(db, &signature) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                stable_ptr: signature.ret_ty(db).stable_ptr().untyped(),
-                message: "Currently only wrapping functions returning an Option<T> or \
-                    Result<T, E>".into(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                signature.ret_ty(db).stable_ptr().untyped(),
+                "Currently only wrapping functions returning an Option<T> or Result<T, E>".into(),
+            )],
             remove_original_item: false,
         };
     };
@@ -86,10 +89,10 @@ fn generate_panicable_code(
     let Some((err_value, panicable_name)) = parse_arguments(db, &attr) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                stable_ptr: attr.stable_ptr.untyped(),
-                message: "Failed to extract panic data attribute".into(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                attr.stable_ptr.untyped(),
+                "Failed to extract panic data attribute".into(),
+            )],
             remove_original_item: false,
         };
     };
@@ -109,13 +112,15 @@ fn generate_panicable_code(
             format!("{}{}", ref_kw, param.name(db).as_syntax_node().get_text(db))
         })
         .join(", ");
+    // The panicable variant has the same visibility as the original function.
+    let visibility = visibility.as_item_prefix(db);
 
     PluginResult {
         code: Some(PluginGeneratedFile {
             name: "panicable".into(),
             content: indoc::formatdoc!(
                 r#"
-                    fn {panicable_name}{generics_params}({params}) -> {inner_ty_text} {{
+                    {visibility}fn {panicable_name}{generics_params}({params}) -> {inner_ty_text} {{
                         match {function_name}({args}) {{
                             {success_variant} (v) => {{
                                 v
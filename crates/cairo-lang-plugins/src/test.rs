@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::plugin::PluginGeneratedFile;
-use cairo_lang_diagnostics::{format_diagnostics, DiagnosticLocation};
+use cairo_lang_defs::plugin::{MacroPlugin, PluginGeneratedFile};
+use cairo_lang_diagnostics::{format_diagnostics_with_severity, DiagnosticLocation};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_parser::test_utils::create_virtual_file;
 use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
+use cairo_lang_semantic::inline_macros::InlineMacroExpanderPlugin;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
-use crate::get_default_plugins;
+use crate::{get_default_inline_macro_plugins, get_default_plugins};
 
 cairo_lang_test_utils::test_file_test!(
     expand_plugin,
@@ -19,6 +20,7 @@ cairo_lang_test_utils::test_file_test!(
         config: "config",
         derive: "derive",
         generate_trait: "generate_trait",
+        inline_macros: "inline_macros",
         panicable: "panicable",
     },
     test_expand_plugin
@@ -41,21 +43,25 @@ pub fn test_expand_plugin(
     let (syntax_file, diagnostics) = get_syntax_file_and_diagnostics(db, file_id, cairo_code);
     assert!(diagnostics.is_empty(), "Unexpected diagnostics:\n{}", diagnostics.format(db));
     let file_syntax_node = syntax_file.as_syntax_node();
-    let plugins = get_default_plugins();
+    let inline_macro_expander: Arc<dyn MacroPlugin> =
+        Arc::new(InlineMacroExpanderPlugin::new(Arc::new(get_default_inline_macro_plugins())));
+    let plugins: Vec<Arc<dyn MacroPlugin>> = std::iter::once(inline_macro_expander)
+        .chain(get_default_plugins().into_iter().map(|plugin| plugin.as_dyn_macro_plugin()))
+        .collect();
     let mut generated_items: Vec<String> = Vec::new();
     let mut diagnostic_items: Vec<String> = Vec::new();
     for item in syntax_file.items(db).elements(db).into_iter() {
         let mut remove_original_item = false;
         let mut local_generated_items = Vec::<String>::new();
         for plugin in &plugins {
-            let result = plugin.clone().as_dyn_macro_plugin().generate_code(db, item.clone());
+            let result = plugin.generate_code(db, item.clone());
 
             diagnostic_items.extend(result.diagnostics.iter().map(|diag| {
                 let syntax_node = file_syntax_node.lookup_ptr(db, diag.stable_ptr);
 
                 let location =
                     DiagnosticLocation { file_id, span: syntax_node.span_without_trivia(db) };
-                format_diagnostics(db, &diag.message, location)
+                format_diagnostics_with_severity(db, diag.severity, &diag.message, location)
             }));
 
             if result.remove_original_item {
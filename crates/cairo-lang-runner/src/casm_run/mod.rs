@@ -14,6 +14,7 @@ use cairo_lang_casm::operand::{
 };
 use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_utils::extract_matches;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_vm::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
 use cairo_vm::serde::deserialize_program::{
     ApTracking, BuiltinName, FlowTrackingData, HintParams, ReferenceManager,
@@ -118,12 +119,17 @@ pub struct StarknetState {
     /// The values of addresses in the simulated storage per contract.
     storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
     /// A mapping from contract address to class hash.
-    #[allow(dead_code)]
     deployed_contracts: HashMap<Felt252, Felt252>,
+    /// A mapping from block number to block hash.
+    block_hashes: HashMap<u64, Felt252>,
     /// A mapping from contract address to logs.
     logs: HashMap<Felt252, VecDeque<Log>>,
     /// The simulated execution info.
     exec_info: ExecutionInfo,
+    /// The mocked return data of calls, per contract address and entry point selector.
+    mocked_calls: HashMap<(Felt252, Felt252), Vec<Felt252>>,
+    /// The revert reason expected from the next call to a contract, if any.
+    expected_revert: Option<Vec<Felt252>>,
     next_id: Felt252,
 }
 impl StarknetState {
@@ -388,6 +394,45 @@ impl HintProcessor for CairoHintProcessor<'_> {
                     insert_value_to_cellref!(vm, opt_variant, 1)?;
                 }
             }
+            StarknetHint::MockCall { contract_address, selector, data_start, data_end } => {
+                let contract_address = get_val(vm, contract_address)?;
+                let selector = get_val(vm, selector)?;
+                let (cell, offset) = extract_buffer(data_start);
+                let data_start = get_ptr(vm, cell, &offset)?;
+                let (cell, offset) = extract_buffer(data_end);
+                let data_end = get_ptr(vm, cell, &offset)?;
+                let ret_data = vm_get_range(vm, data_start, data_end)?;
+                self.starknet_state.mocked_calls.insert((contract_address, selector), ret_data);
+            }
+            StarknetHint::Store { contract_address, key, value } => {
+                let contract_address = get_val(vm, contract_address)?;
+                let key = get_val(vm, key)?;
+                let value = get_val(vm, value)?;
+                self.starknet_state.storage.entry(contract_address).or_default().insert(key, value);
+            }
+            StarknetHint::Load { contract_address, key, dst } => {
+                let contract_address = get_val(vm, contract_address)?;
+                let key = get_val(vm, key)?;
+                let value = self
+                    .starknet_state
+                    .storage
+                    .get(&contract_address)
+                    .and_then(|contract_storage| contract_storage.get(&key))
+                    .cloned()
+                    .unwrap_or_else(|| Felt252::from(0));
+                insert_value_to_cellref!(vm, dst, value)?;
+            }
+            StarknetHint::SetBlockHash { block_number, value } => {
+                let block_number = get_val(vm, block_number)?.to_u64().unwrap();
+                self.starknet_state.block_hashes.insert(block_number, get_val(vm, value)?);
+            }
+            StarknetHint::ExpectRevert { start, end } => {
+                let (cell, offset) = extract_buffer(start);
+                let start = get_ptr(vm, cell, &offset)?;
+                let (cell, offset) = extract_buffer(end);
+                let end = get_ptr(vm, cell, &offset)?;
+                self.starknet_state.expected_revert = Some(vm_get_range(vm, start, end)?);
+            }
         };
         Ok(())
     }
@@ -762,13 +807,13 @@ impl<'a> CairoHintProcessor<'a> {
     fn get_block_hash(
         &mut self,
         gas_counter: &mut usize,
-        _block_number: u64,
+        block_number: u64,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, 100);
-        // TODO(Arni, 28/5/2023): Replace the temporary return value with the required value.
-        //      One design suggestion - to preform a storage read. Have an arbitrary, hardcoded
-        //      (For example, addr=1) contain the mapping from block number to block hash.
-        fail_syscall!(b"GET_BLOCK_HASH_UNIMPLEMENTED");
+        let Some(block_hash) = self.starknet_state.block_hashes.get(&block_number) else {
+            fail_syscall!(b"GET_BLOCK_HASH_NOT_SET");
+        };
+        Ok(SyscallResult::Success(vec![block_hash.clone().into()]))
     }
 
     /// Executes the `get_execution_info_syscall` syscall.
@@ -885,6 +930,20 @@ impl<'a> CairoHintProcessor<'a> {
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, 50);
 
+        // The expected revert only applies to this call, and not to the calls made by it.
+        let expected_revert = self.starknet_state.expected_revert.take();
+
+        // Mocked calls return their mocked data, whether the contract is deployed or not.
+        if let Some(ret_data) =
+            self.starknet_state.mocked_calls.get(&(contract_address.clone(), selector.clone()))
+        {
+            if expected_revert.is_some() {
+                fail_syscall!(b"Call did not revert");
+            }
+            let (res_data_start, res_data_end) = segment_with_data(vm, ret_data.iter().cloned())?;
+            return Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]));
+        }
+
         // Get the class hash of the contract.
         let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
             fail_syscall!(b"CONTRACT_NOT_DEPLOYED");
@@ -918,11 +977,20 @@ impl<'a> CairoHintProcessor<'a> {
         self.starknet_state.exec_info.caller_address = old_caller_address;
         self.starknet_state.exec_info.contract_address = old_contract_address;
 
-        match res {
-            Ok((res_data_start, res_data_end)) => {
+        match (res, expected_revert) {
+            (Ok((res_data_start, res_data_end)), None) => {
                 Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
             }
-            Err(mut revert_reason) => {
+            (Ok(_), Some(_)) => {
+                fail_syscall!(b"Call did not revert");
+            }
+            // An expected revert is treated as a successful call, with no return data.
+            (Err(revert_reason), Some(expected_revert)) if revert_reason == expected_revert => {
+                let (res_data_start, res_data_end) =
+                    segment_with_data(vm, std::iter::empty::<Felt252>())?;
+                Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
+            }
+            (Err(mut revert_reason), _) => {
                 fail_syscall!(revert_reason, b"ENTRYPOINT_FAILED");
             }
         }
@@ -967,7 +1035,11 @@ impl<'a> CairoHintProcessor<'a> {
         _vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, 50);
-        // Prepare runner for running the call.
+        let runner = self.runner.expect("Runner is needed for starknet.");
+        if !runner.starknet_contracts_info.contains_key(&new_class) {
+            fail_syscall!(b"CLASS_HASH_NOT_FOUND");
+        }
+        // Later calls to the contract are dispatched to the new class.
         let address = self.starknet_state.exec_info.contract_address.clone();
         self.starknet_state.deployed_contracts.insert(address, new_class);
         Ok(SyscallResult::Success(vec![]))
@@ -989,7 +1061,7 @@ impl<'a> CairoHintProcessor<'a> {
         let mut res = runner
             .run_function_with_starknet_context(
                 function,
-                &[Arg::Array(calldata)],
+                &[Arg::Array(calldata.into_iter().map(Arg::Value).collect())],
                 Some(*gas_counter),
                 self.starknet_state.clone(),
             )
@@ -1810,10 +1882,34 @@ pub struct RunFunctionContext<'a> {
     pub data_len: usize,
 }
 
-type RunFunctionRes = (Vec<Option<Felt252>>, usize);
-type RunFunctionResStarknet = (Vec<Option<Felt252>>, usize, StarknetState);
+/// A step of a run - the values of the registers before an instruction is executed.
+///
+/// The values are relocated - the program starts at `pc` 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    pub pc: usize,
+    pub ap: usize,
+    pub fp: usize,
+}
+
+/// The resources used by a run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExecutionResources {
+    /// The number of VM steps.
+    pub steps: usize,
+    /// The number of memory holes - memory cells that were allocated but never accessed.
+    pub memory_holes: usize,
+    /// The number of memory cells used by each builtin, by its name.
+    pub builtins: OrderedHashMap<String, usize>,
+}
+
+/// The memory layout, the final ap value, the trace and the used resources of a run.
+type RunFunctionRes = (Vec<Option<Felt252>>, usize, Vec<TraceEntry>, ExecutionResources);
+type RunFunctionResStarknet =
+    (Vec<Option<Felt252>>, usize, Vec<TraceEntry>, ExecutionResources, StarknetState);
 
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+/// Runs `program` on layout with prime, and returns the memory layout, ap value, the trace and the
+/// used resources of the run.
 /// Run used CairoHintProcessor and StarknetState to emulate Starknet behaviour.
 pub fn run_function_with_starknet_context<'a, 'b: 'a, Instructions>(
     instructions: Instructions,
@@ -1832,11 +1928,13 @@ where
         starknet_state: StarknetState::default(),
     };
     run_function(instructions, builtins, additional_initialization, &mut hint_processor, hints_dict)
-        .map(|(mem, val)| (mem, val, hint_processor.starknet_state))
+        .map(|(mem, val, trace, resources)| {
+            (mem, val, trace, resources, hint_processor.starknet_state)
+        })
 }
 
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
-/// Allows injecting custom HintProcessor.
+/// Runs `program` on layout with prime, and returns the memory layout, ap value, the trace and the
+/// used resources of the run. Allows injecting custom HintProcessor.
 pub fn run_function<'a, 'b: 'a, Instructions>(
     instructions: Instructions,
     builtins: Vec<BuiltinName>,
@@ -1883,5 +1981,23 @@ where
         .end_run(true, false, &mut vm, hint_processor as &mut dyn HintProcessor)
         .map_err(CairoRunError::from)?;
     runner.relocate(&mut vm, true).map_err(CairoRunError::from)?;
-    Ok((runner.relocated_memory, vm.get_relocated_trace().unwrap().last().unwrap().ap))
+    let trace: Vec<TraceEntry> = vm
+        .get_relocated_trace()
+        .unwrap()
+        .iter()
+        .map(|entry| TraceEntry { pc: entry.pc, ap: entry.ap, fp: entry.fp })
+        .collect();
+    let resources = ExecutionResources {
+        steps: trace.len(),
+        memory_holes: runner.get_memory_holes(&vm).map_err(CairoRunError::from)?,
+        builtins: vm
+            .get_builtin_runners()
+            .iter()
+            .map(|builtin| {
+                let name = builtin.name().trim_end_matches("_builtin").to_string();
+                (name, vm.get_segment_used_size(builtin.base()).unwrap_or_default())
+            })
+            .collect(),
+    };
+    Ok((runner.relocated_memory, trace.last().unwrap().ap, trace, resources))
 }
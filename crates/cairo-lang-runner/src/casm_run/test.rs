@@ -101,7 +101,7 @@ use crate::casm_run::run_function_with_starknet_context;
     "simple_division"
 )]
 fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
-    let (cells, ap, _) =
+    let (cells, ap, _, _, _) =
         run_function_with_starknet_context(function.instructions.iter(), vec![], |_| Ok(()))
             .expect("Running code failed.");
     let cells = cells.into_iter().skip(ap - n_returns);
@@ -113,7 +113,7 @@ fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
 
 #[test]
 fn test_allocate_segment() {
-    let (memory, ap, _) = run_function_with_starknet_context(
+    let (memory, ap, _, _, _) = run_function_with_starknet_context(
         casm! {
             [ap] = 1337, ap++;
             %{ memory[ap] = segments.add() %}
@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use cairo_felt::Felt252;
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate, Register};
+use cairo_lang_sierra::debug_info::SourceSpan;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
+use cairo_lang_sierra::program::{Function, StatementIdx};
+use cairo_lang_sierra_to_casm::compiler::{compile_with_var_refs, CairoProgramDebugInfo};
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+
+use crate::values::DecodedValue;
+use crate::{RunnerError, SierraCasmRunner, TraceEntry};
+
+#[cfg(test)]
+#[path = "debugging_test.rs"]
+mod test;
+
+/// A call of a Sierra function in a recorded run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallFrame {
+    /// The index of the called function in the Sierra program.
+    pub function_idx: usize,
+    /// The `fp` of the call.
+    pub fp: usize,
+    /// The index of the frame of the caller, if any.
+    pub caller: Option<usize>,
+    /// The number of calls on the call stack below this call.
+    pub depth: usize,
+    /// The first step of the call.
+    pub start_step: usize,
+}
+
+/// The units of stepping through a recorded run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StepKind {
+    /// Steps to the next Sierra statement.
+    Statement,
+    /// Steps to the next line of code, stepping into calls.
+    Into,
+    /// Steps to the next line of code, stepping over calls.
+    Over,
+    /// Steps out of the current call.
+    Out,
+}
+
+/// A run, recorded by its trace and memory, that is stepped through by a debugger.
+///
+/// Since the memory of a run is written once, the final memory holds the value of every variable
+/// from the step it is written onwards, so the run can be stepped through in both directions.
+pub struct RecordedRun<'a> {
+    runner: &'a SierraCasmRunner,
+    /// The trace of the run (see [crate::RunResultStarknet::trace]).
+    trace: Vec<TraceEntry>,
+    /// The relocated memory of the run.
+    memory: Vec<Option<Felt252>>,
+    /// The source spans of the Sierra statements (see
+    /// [cairo_lang_sierra::debug_info::DebugInfo::statements_locations]).
+    statements_locations: HashMap<StatementIdx, Vec<SourceSpan>>,
+    /// The debug information of the compiled program, with the references of its variables.
+    debug_info: CairoProgramDebugInfo,
+    /// The calls made in the run.
+    frames: Vec<CallFrame>,
+    /// The index of the frame of each step.
+    step_frames: Vec<usize>,
+}
+
+impl<'a> RecordedRun<'a> {
+    /// Records a run of the program of `runner`, given its trace and memory. The calls of the run
+    /// are found by the changes of `fp`, as in [SierraCasmRunner::profile].
+    pub fn new(
+        runner: &'a SierraCasmRunner,
+        trace: Vec<TraceEntry>,
+        memory: Vec<Option<Felt252>>,
+        statements_locations: HashMap<StatementIdx, Vec<SourceSpan>>,
+    ) -> Result<Self, RunnerError> {
+        // The references of the variables are not recorded by the runner, so the program is
+        // compiled again to record them. The gas usage was already checked by the runner.
+        let debug_info =
+            compile_with_var_refs(&runner.sierra_program, &runner.metadata, false)?.debug_info;
+        // The code offset of the entry point of each function, sorted by offset.
+        let entry_offsets = runner
+            .sierra_program
+            .funcs
+            .iter()
+            .enumerate()
+            .map(|(idx, function)| {
+                (
+                    runner.casm_program.debug_info.sierra_statement_info[function.entry_point.0]
+                        .code_offset,
+                    idx,
+                )
+            })
+            .sorted()
+            .collect_vec();
+        let mut frames: Vec<CallFrame> = vec![];
+        let mut step_frames = Vec::with_capacity(trace.len());
+        // The indices of the frames of the current call stack.
+        let mut stack: Vec<usize> = vec![];
+        for (step, entry) in trace.iter().enumerate() {
+            if let Some(depth) = stack.iter().rposition(|idx| frames[*idx].fp == entry.fp) {
+                // Returning to the call of an outer frame.
+                stack.truncate(depth + 1);
+            } else {
+                let position = entry_offsets.partition_point(|(offset, _)| *offset <= entry.pc);
+                let (_, function_idx) = entry_offsets[position.max(1) - 1];
+                stack.push(frames.len());
+                frames.push(CallFrame {
+                    function_idx,
+                    fp: entry.fp,
+                    caller: stack.iter().rev().nth(1).copied(),
+                    depth: stack.len() - 1,
+                    start_step: step,
+                });
+            }
+            step_frames.push(*stack.last().unwrap());
+        }
+        Ok(Self { runner, trace, memory, statements_locations, debug_info, frames, step_frames })
+    }
+
+    /// Returns the number of steps of the run.
+    pub fn steps_count(&self) -> usize {
+        self.trace.len()
+    }
+
+    /// Returns the memory of the run.
+    pub fn memory(&self) -> &[Option<Felt252>] {
+        &self.memory
+    }
+
+    /// Returns the Sierra statement executed at a step.
+    pub fn statement(&self, step: usize) -> StatementIdx {
+        self.runner.sierra_statement_index_by_pc(self.trace[step].pc)
+    }
+
+    /// Returns the source span of the code executed at a step, if known. For inlined code, this is
+    /// the span of the inlined code itself.
+    pub fn location(&self, step: usize) -> Option<&SourceSpan> {
+        self.statements_locations.get(&self.statement(step))?.first()
+    }
+
+    /// Returns whether the given source file and line (starting from 0) are the location of any
+    /// statement of the program.
+    pub fn is_line_of_code(&self, file: &str, line: usize) -> bool {
+        self.statements_locations
+            .values()
+            .filter_map(|spans| spans.first())
+            .any(|span| span.file == file && span.start.line == line)
+    }
+
+    /// Returns the frame of a call.
+    pub fn frame(&self, frame_idx: usize) -> &CallFrame {
+        &self.frames[frame_idx]
+    }
+
+    /// Returns the function called in a frame.
+    pub fn function(&self, frame_idx: usize) -> &Function {
+        &self.runner.sierra_program.funcs[self.frames[frame_idx].function_idx]
+    }
+
+    /// Returns the call stack at a step, from the innermost call outwards, by the index of the
+    /// frame of each call and the step it is at - the step itself for the innermost call, and the
+    /// step of the call instruction for the outer calls.
+    pub fn call_stack(&self, step: usize) -> Vec<(usize, usize)> {
+        let mut call_stack = vec![(self.step_frames[step], step)];
+        while let Some((frame_idx, _)) = call_stack.last() {
+            let frame = &self.frames[*frame_idx];
+            let Some(caller) = frame.caller else { break };
+            call_stack.push((caller, frame.start_step - 1));
+        }
+        call_stack
+    }
+
+    /// Returns the arguments of a call (excluding the implicits), by their types and values.
+    pub fn arguments(&self, frame_idx: usize) -> Vec<(ConcreteTypeId, DecodedValue)> {
+        let param_types = &self.function(frame_idx).signature.param_types;
+        let args_size: usize =
+            param_types.iter().map(|ty| self.runner.type_sizes[ty] as usize).sum();
+        // The arguments are right before the return `fp` and `pc` pushed by the call.
+        let mut address = self.frames[frame_idx].fp - 2 - args_size;
+        let mut arguments = vec![];
+        for ty in param_types {
+            let size = self.runner.type_sizes[ty] as usize;
+            if !self.runner.is_implicit(ty) {
+                let cells = self.memory[address..address + size]
+                    .iter()
+                    .map(|cell| cell.clone().unwrap_or_default())
+                    .collect_vec();
+                arguments.push((ty.clone(), self.runner.decode_value(ty, &cells, &self.memory)));
+            }
+            address += size;
+        }
+        arguments
+    }
+
+    /// Returns the variables of the Sierra statement executed at a step (excluding the implicits),
+    /// by their ids, types and values at the start of the statement.
+    pub fn locals(&self, step: usize) -> Vec<(VarId, ConcreteTypeId, DecodedValue)> {
+        let statement_info = &self.debug_info.sierra_statement_info[self.statement(step).0];
+        let Some(var_refs) = &statement_info.var_refs else { return vec![] };
+        // The references are relative to the registers at the first step of the statement.
+        let frame_idx = self.step_frames[step];
+        let Some(start) = (0..=step).rev().find(|other| {
+            self.step_frames[*other] == frame_idx
+                && self.trace[*other].pc == statement_info.code_offset
+        }) else {
+            return vec![];
+        };
+        let entry = &self.trace[start];
+        var_refs
+            .iter()
+            .filter(|var_ref| !self.runner.is_implicit(&var_ref.ty))
+            .map(|var_ref| {
+                let cells = var_ref
+                    .expression
+                    .cells
+                    .iter()
+                    .map(|cell| self.cell_value(cell, entry).unwrap_or_default())
+                    .collect_vec();
+                let value = self.runner.decode_value(&var_ref.ty, &cells, &self.memory);
+                (var_ref.var_id.clone(), var_ref.ty.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Returns the step reached by stepping from `step`, forwards or backwards, or `None` if the
+    /// run ends (or starts) before it is reached.
+    ///
+    /// Stepping by lines stops at the first step of a line of code. Steps of code without a known
+    /// location are stepped over, unless stepping out reaches no other step of the caller.
+    pub fn step(&self, step: usize, kind: StepKind, forward: bool) -> Option<usize> {
+        let depth = self.frames[self.step_frames[step]].depth;
+        if kind == StepKind::Out {
+            if forward {
+                let returned =
+                    (step + 1..self.steps_count()).find(|other| self.depth(*other) < depth)?;
+                // Stopping at the first step of the caller with a known location, if there is one.
+                let caller = self.step_frames[returned];
+                return Some(
+                    (returned..self.steps_count())
+                        .take_while(|other| self.step_frames[*other] == caller)
+                        .find(|other| self.location(*other).is_some())
+                        .unwrap_or(returned),
+                );
+            }
+            let caller_step = self.frames[self.step_frames[step]].start_step.checked_sub(1)?;
+            return Some(self.line_start(caller_step));
+        }
+        let is_stop = |other: usize| match kind {
+            StepKind::Statement => self.statement(other) != self.statement(step),
+            StepKind::Into => self.line(other).is_some() && self.line(other) != self.line(step),
+            StepKind::Over => {
+                self.line(other).is_some()
+                    && self.line(other) != self.line(step)
+                    && self.depth(other) <= depth
+            }
+            StepKind::Out => unreachable!(),
+        };
+        if forward {
+            return (step + 1..self.steps_count()).find(|other| is_stop(*other));
+        }
+        let stop = (0..step).rev().find(|other| is_stop(*other))?;
+        Some(match kind {
+            StepKind::Statement => {
+                let statement = self.statement(stop);
+                (0..stop)
+                    .rev()
+                    .take_while(|other| self.statement(*other) == statement)
+                    .last()
+                    .unwrap_or(stop)
+            }
+            _ => self.line_start(stop),
+        })
+    }
+
+    /// Returns the first step starting from `step`, forwards or backwards, that starts a line of
+    /// code whose location is a breakpoint.
+    pub fn find_breakpoint(
+        &self,
+        step: usize,
+        forward: bool,
+        is_breakpoint: impl Fn(&SourceSpan) -> bool,
+    ) -> Option<usize> {
+        let is_hit = |other: &usize| {
+            self.location(*other).is_some_and(&is_breakpoint)
+                && (*other == 0 || self.line(*other - 1) != self.line(*other))
+        };
+        if forward {
+            (step..self.steps_count()).find(is_hit)
+        } else {
+            (0..=step).rev().find(is_hit)
+        }
+    }
+
+    /// Returns the value of a cell expression, evaluated by the registers of a trace entry, or
+    /// `None` if it refers to unknown memory.
+    fn cell_value(&self, cell: &CellExpression, entry: &TraceEntry) -> Option<Felt252> {
+        let address = |cell_ref: &CellRef| {
+            let base = match cell_ref.register {
+                Register::AP => entry.ap,
+                Register::FP => entry.fp,
+            };
+            base.checked_add_signed(cell_ref.offset.into())
+        };
+        let deref = |cell_ref: &CellRef| self.memory.get(address(cell_ref)?)?.clone();
+        match cell {
+            CellExpression::Deref(cell_ref) => deref(cell_ref),
+            CellExpression::DoubleDeref(cell_ref, offset) => {
+                let address = deref(cell_ref)?.to_usize()?.checked_add_signed((*offset).into())?;
+                self.memory.get(address)?.clone()
+            }
+            CellExpression::Immediate(value) => Some(Felt252::from(value.clone())),
+            CellExpression::BinOp { op, a, b } => {
+                let a = deref(a)?;
+                let b = match b {
+                    DerefOrImmediate::Deref(cell_ref) => deref(cell_ref)?,
+                    DerefOrImmediate::Immediate(value) => Felt252::from(value.value.clone()),
+                };
+                Some(match op {
+                    CellOperator::Add => a + b,
+                    CellOperator::Sub => a - b,
+                    CellOperator::Mul => a * b,
+                    CellOperator::Div => a / b,
+                })
+            }
+        }
+    }
+
+    /// Returns the depth of the call of a step.
+    fn depth(&self, step: usize) -> usize {
+        self.frames[self.step_frames[step]].depth
+    }
+
+    /// Returns the line of code of a step, as the frame of the step and the file and line of its
+    /// location.
+    fn line(&self, step: usize) -> Option<(usize, &str, usize)> {
+        let span = self.location(step)?;
+        Some((self.step_frames[step], span.file.as_str(), span.start.line))
+    }
+
+    /// Returns the first step of the line of code of `step`.
+    fn line_start(&self, step: usize) -> usize {
+        let line = self.line(step);
+        (0..step).rev().take_while(|other| self.line(*other) == line).last().unwrap_or(step)
+    }
+}
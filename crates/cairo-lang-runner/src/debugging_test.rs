@@ -0,0 +1,162 @@
+use cairo_felt::Felt252;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::debug_info::SourceSpan;
+use cairo_lang_sierra_generator::db::{SierraGenGroup, StatementsLocations};
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_sierra_generator::test_utils::setup_db_and_get_crate_id;
+use cairo_lang_utils::try_extract_matches;
+use indoc::indoc;
+use itertools::Itertools;
+
+use super::{RecordedRun, StepKind};
+use crate::{Arg, RunResultStarknet, SierraCasmRunner};
+
+/// The file of the compiled program.
+const FILE: &str = "src/lib.cairo";
+
+/// Compiles a program calling `double` from `main`, and runs `main` with the argument 3.
+fn run_main() -> (SierraCasmRunner, RunResultStarknet, StatementsLocations) {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn main(n: felt252) -> felt252 {
+            let x = double(n);
+            let y = double(x);
+            y
+        }
+
+        #[inline(never)]
+        fn double(a: felt252) -> felt252 {
+            match a {
+                0 => 0,
+                _ => a + a,
+            }
+        }
+    "});
+    let function_ids = ["main", "double"]
+        .map(|name| {
+            let free_function_id = db
+                .crate_modules(crate_id)
+                .iter()
+                .find_map(|module_id| {
+                    try_extract_matches!(
+                        db.module_item_by_name(*module_id, name.into()).unwrap()?,
+                        ModuleItemId::FreeFunction
+                    )
+                })
+                .unwrap();
+            ConcreteFunctionWithBodyId::from_no_generics_free(&db, free_function_id).unwrap()
+        })
+        .to_vec();
+    let program = db.get_sierra_program_for_functions(function_ids.clone()).unwrap();
+    let statements_locations = db.get_sierra_program_statements_locations(function_ids).unwrap();
+    let runner = SierraCasmRunner::new(
+        replace_sierra_ids_in_program(&db, &program),
+        None,
+        Default::default(),
+    )
+    .unwrap();
+    let func = runner.find_function_by_path("test::main").unwrap();
+    let result = runner
+        .run_function_with_starknet_context(
+            func,
+            &[Arg::Value(Felt252::from(3))],
+            None,
+            Default::default(),
+        )
+        .unwrap();
+    (runner, result, statements_locations.as_ref().clone())
+}
+
+/// Returns whether a span starts at a line of the compiled program.
+fn is_line(span: &SourceSpan, line: usize) -> bool {
+    span.file == FILE && span.start.line == line
+}
+
+#[test]
+fn test_breakpoints() {
+    let (runner, result, statements_locations) = run_main();
+    let run = RecordedRun::new(&runner, result.trace, result.memory, statements_locations).unwrap();
+    let is_breakpoint = |span: &SourceSpan| is_line(span, 8);
+
+    assert!(run.is_line_of_code(FILE, 1));
+    assert!(run.is_line_of_code(FILE, 8));
+    // An empty line, and a line of another file.
+    assert!(!run.is_line_of_code(FILE, 5));
+    assert!(!run.is_line_of_code("src/other.cairo", 1));
+
+    // The line of `match a {` is hit once per call of `double`, for `a` in 3, 6.
+    let first_hit = run.find_breakpoint(0, true, is_breakpoint).unwrap();
+    let second_hit = run.find_breakpoint(first_hit + 1, true, is_breakpoint).unwrap();
+    assert_eq!(run.find_breakpoint(second_hit + 1, true, is_breakpoint), None);
+    for (hit, argument) in [(first_hit, "3"), (second_hit, "6")] {
+        assert!(is_breakpoint(run.location(hit).unwrap()));
+        let call_stack = run.call_stack(hit);
+        assert_eq!(call_stack.len(), 2);
+        let arguments =
+            run.arguments(call_stack[0].0).into_iter().map(|(_, value)| value).join(", ");
+        assert_eq!(arguments, argument);
+    }
+    // Searching backwards finds the hits in reverse order.
+    let last_step = run.steps_count() - 1;
+    assert_eq!(run.find_breakpoint(last_step, false, is_breakpoint), Some(second_hit));
+    assert_eq!(run.find_breakpoint(second_hit - 1, false, is_breakpoint), Some(first_hit));
+    // A breakpoint on a line that is not a line of code is never hit.
+    assert_eq!(run.find_breakpoint(0, true, |span| is_line(span, 5)), None);
+}
+
+#[test]
+fn test_stepping() {
+    let (runner, result, statements_locations) = run_main();
+    let run = RecordedRun::new(&runner, result.trace, result.memory, statements_locations).unwrap();
+    let line = |step: usize| run.location(step).unwrap().start.line;
+    let depth = |step: usize| run.call_stack(step).len();
+
+    // The line of `let x = double(n);`.
+    let call_line = run.find_breakpoint(0, true, |span| is_line(span, 1)).unwrap();
+
+    // Stepping over the call reaches the next line of `main`, and stepping back returns.
+    let next_line = run.step(call_line, StepKind::Over, true).unwrap();
+    assert_eq!((line(next_line), depth(next_line)), (2, 1));
+    assert_eq!(run.step(next_line, StepKind::Over, false), Some(call_line));
+
+    // Stepping into the call reaches the line of `match a {`.
+    let inner_line = run.step(call_line, StepKind::Into, true).unwrap();
+    assert_eq!((line(inner_line), depth(inner_line)), (8, 2));
+
+    // Stepping out of `double` reaches the next line of `main`, or the line of the call when
+    // stepping backwards.
+    assert_eq!(run.step(inner_line, StepKind::Out, true), Some(next_line));
+    assert_eq!(run.step(inner_line, StepKind::Out, false), Some(call_line));
+
+    // Stepping by statements moves to the next statement, and back.
+    let next_statement = run.step(inner_line, StepKind::Statement, true).unwrap();
+    assert_ne!(run.statement(next_statement), run.statement(inner_line));
+    assert_eq!(run.step(next_statement, StepKind::Statement, false), Some(inner_line));
+
+    // Stepping past the end of the run, or before its start.
+    assert_eq!(run.step(run.steps_count() - 1, StepKind::Statement, true), None);
+    assert_eq!(run.step(0, StepKind::Over, false), None);
+}
+
+#[test]
+fn test_locals() {
+    let (runner, result, statements_locations) = run_main();
+    let run = RecordedRun::new(&runner, result.trace, result.memory, statements_locations).unwrap();
+    let locals = |step: usize| {
+        run.locals(step)
+            .into_iter()
+            .map(|(var_id, ty, value)| format!("{var_id}: {ty} = {value}"))
+            .collect_vec()
+    };
+
+    // Within `double`, its argument and the copy of it that is matched on.
+    let first_hit = run.find_breakpoint(0, true, |span| is_line(span, 8)).unwrap();
+    assert_eq!(locals(first_hit), ["[0]: felt252 = 3", "[1]: felt252 = 3"]);
+    let second_hit = run.find_breakpoint(first_hit + 1, true, |span| is_line(span, 8)).unwrap();
+    assert_eq!(locals(second_hit), ["[0]: felt252 = 6", "[1]: felt252 = 6"]);
+    // On the line after the first call, the result of the call.
+    let next_line = run.find_breakpoint(0, true, |span| is_line(span, 2)).unwrap();
+    assert_eq!(locals(next_line), ["[4]: felt252 = 6"]);
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 use cairo_felt::Felt252;
 use cairo_lang_casm::hints::Hint;
+use cairo_lang_casm::inline::CasmContext;
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_casm::{casm, casm_extend};
 use cairo_lang_sierra::extensions::bitwise::BitwiseType;
@@ -16,7 +17,7 @@ use cairo_lang_sierra::extensions::range_check::RangeCheckType;
 use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
 use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
 use cairo_lang_sierra::extensions::{ConcreteType, NamedType};
-use cairo_lang_sierra::program::{Function, GenericArg};
+use cairo_lang_sierra::program::{Function, GenericArg, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_ap_change::{calc_ap_changes, ApChangeError};
 use cairo_lang_sierra_gas::gas_info::GasInfo;
@@ -32,13 +33,16 @@ use cairo_vm::hint_processor::hint_processor_definition::HintProcessor;
 use cairo_vm::serde::deserialize_program::{BuiltinName, HintParams};
 use cairo_vm::vm::errors::cairo_run_errors::CairoRunError;
 use casm_run::hint_to_hint_params;
-pub use casm_run::{CairoHintProcessor, StarknetState};
+pub use casm_run::{CairoHintProcessor, ExecutionResources, StarknetState, TraceEntry};
 use itertools::chain;
 use num_traits::ToPrimitive;
 use thiserror::Error;
 
 pub mod casm_run;
+pub mod debugging;
+pub mod profiling;
 pub mod short_string;
+pub mod values;
 
 #[derive(Debug, Error)]
 pub enum RunnerError {
@@ -54,6 +58,8 @@ pub enum RunnerError {
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("{0}")]
+    InvalidArguments(String),
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
@@ -70,6 +76,11 @@ pub struct RunResultStarknet {
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
     pub starknet_state: StarknetState,
+    /// The resources used by the run.
+    pub resources: ExecutionResources,
+    /// The steps of the run within the CASM code of the Sierra program, with their `pc` given as
+    /// an offset in that code (see [SierraCasmRunner::sierra_statement_index_by_pc]).
+    pub trace: Vec<TraceEntry>,
 }
 
 /// The full result of a run.
@@ -77,6 +88,10 @@ pub struct RunResult {
     pub gas_counter: Option<Felt252>,
     pub memory: Vec<Option<Felt252>>,
     pub value: RunResultValue,
+    /// The resources used by the run.
+    pub resources: ExecutionResources,
+    /// The trace of the run.
+    pub trace: Vec<TraceEntry>,
 }
 
 /// The ran function return value.
@@ -92,10 +107,12 @@ pub enum RunResultValue {
 pub const DUMMY_BUILTIN_GAS_COST: usize = 10000;
 
 /// An argument to a sierra function run,
-#[derive(Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Arg {
     Value(Felt252),
-    Array(Vec<Felt252>),
+    /// An array, given by its elements - the cells of the values in it, where nested arrays are
+    /// given as arrays.
+    Array(Vec<Arg>),
 }
 impl From<Felt252> for Arg {
     fn from(value: Felt252) -> Self {
@@ -187,12 +204,24 @@ impl SierraCasmRunner {
         let (hints_dict, string_to_hint) = build_hints_dict(instructions.clone());
         let mut hint_processor =
             CairoHintProcessor { runner: Some(self), starknet_state, string_to_hint };
+        // The relocated `pc` of the start of the CASM code of the Sierra program.
+        let casm_program_start =
+            1 + entry_code.iter().map(|instruction| instruction.body.op_size()).sum::<usize>();
+        let casm_program_end = casm_program_start
+            + self.casm_program.instructions.iter().map(|inst| inst.body.op_size()).sum::<usize>();
         self.run_function(func, &mut hint_processor, hints_dict, instructions, builtins).map(|v| {
             RunResultStarknet {
                 gas_counter: v.gas_counter,
                 memory: v.memory,
                 value: v.value,
                 starknet_state: hint_processor.starknet_state,
+                resources: v.resources,
+                trace: v
+                    .trace
+                    .into_iter()
+                    .filter(|entry| (casm_program_start..casm_program_end).contains(&entry.pc))
+                    .map(|entry| TraceEntry { pc: entry.pc - casm_program_start, ..entry })
+                    .collect(),
             }
         })
     }
@@ -211,7 +240,7 @@ impl SierraCasmRunner {
     where
         Instructions: Iterator<Item = &'a Instruction> + Clone,
     {
-        let (cells, ap) = casm_run::run_function(
+        let (cells, ap, trace, mut resources) = casm_run::run_function(
             instructions,
             builtins,
             |context| {
@@ -263,7 +292,33 @@ impl SierraCasmRunner {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
             self.handle_main_return_value(ty, values, &cells)?
         };
-        Ok(RunResult { gas_counter, memory: cells, value })
+        resources.builtins.insert(
+            "segment_arena".into(),
+            self.segment_arena_used_cells(func, &trace, &cells, ap),
+        );
+        Ok(RunResult { gas_counter, memory: cells, value, resources, trace })
+    }
+
+    /// Returns the number of memory cells of the segment arena used by a run of `func`, given the
+    /// trace, the memory and the final `ap` of the run.
+    fn segment_arena_used_cells(
+        &self,
+        func: &Function,
+        trace: &[TraceEntry],
+        memory: &[Option<Felt252>],
+        ap: usize,
+    ) -> usize {
+        let function_idx = self.sierra_program.funcs.iter().position(|f| f.id == func.id).unwrap();
+        // The first step of the function - the entry code runs in the initial frame.
+        let Some(entry) = trace.iter().find(|entry| entry.fp != trace[0].fp) else {
+            return 0;
+        };
+        let arguments = self.implicit_arguments(function_idx, entry.fp, memory);
+        let return_values = self.implicit_return_values(function_idx, ap, memory);
+        match (arguments.get(&SegmentArenaType::ID), return_values.get(&SegmentArenaType::ID)) {
+            (Some(argument), Some(return_value)) => return_value.saturating_sub(*argument),
+            _ => 0,
+        }
     }
 
     /// Handling the main return value to create a `RunResultValue`.
@@ -324,6 +379,22 @@ impl SierraCasmRunner {
         Ok(results_data)
     }
 
+    /// Returns the index of the Sierra statement whose CASM code includes the given `pc`, given as
+    /// an offset in the CASM code of the Sierra program.
+    pub fn sierra_statement_index_by_pc(&self, pc: usize) -> StatementIdx {
+        self.casm_program.debug_info.sierra_statement_index_by_code_offset(pc)
+    }
+
+    /// Returns the indices of the Sierra statements executed when the given `pc` (given as an
+    /// offset in the CASM code of the Sierra program) is executed first in their code.
+    /// This includes the preceding statements without code.
+    pub fn sierra_statements_starting_at_pc(
+        &self,
+        pc: usize,
+    ) -> impl Iterator<Item = StatementIdx> {
+        self.casm_program.debug_info.sierra_statements_starting_at(pc).map(StatementIdx)
+    }
+
     /// Finds first function ending with `name_suffix`.
     pub fn find_function(&self, name_suffix: &str) -> Result<&Function, RunnerError> {
         self.sierra_program
@@ -350,7 +421,7 @@ impl SierraCasmRunner {
         args: &[Arg],
         initial_gas: usize,
     ) -> Result<(Vec<Instruction>, Vec<BuiltinName>), RunnerError> {
-        let mut arg_iter = args.iter().peekable();
+        let mut arg_iter = args.iter();
         let mut expected_arguments_size = 0;
         let mut ctx = casm! {};
         // The builtins in the formatting expected by the runner.
@@ -374,20 +445,11 @@ impl SierraCasmRunner {
         let mut ap_offset: i16 = 0;
         for arg in args {
             let Arg::Array(values) = arg else { continue };
-            vecs.push(ap_offset);
-            casm_extend! {ctx,
-                %{ memory[ap + 0] = segments.add() %}
-                ap += 1;
-            }
-            for (i, v) in values.iter().enumerate() {
-                let arr_at = (i + 1) as i16;
-                casm_extend! {ctx,
-                    [ap + 0] = (v.to_bigint());
-                    [ap + 0] = [[ap - arr_at] + (i as i16)], ap++;
-                };
-            }
-            ap_offset += (1 + values.len()) as i16;
+            let (ap_change, array_size) = load_array(&mut ctx, values);
+            vecs.push((ap_offset, array_size));
+            ap_offset += ap_change;
         }
+        let mut vecs = vecs.into_iter();
         let after_vecs_offset = ap_offset;
         if func
             .signature
@@ -431,32 +493,39 @@ impl SierraCasmRunner {
                 casm_extend! {ctx,
                     [ap + 0] = [ap + offset] + 3, ap++;
                 }
-            } else if let Some(Arg::Array(_)) = arg_iter.peek() {
-                let values = extract_matches!(arg_iter.next().unwrap(), Arg::Array);
-                let offset = -ap_offset + vecs.pop().unwrap();
-                expected_arguments_size += 1;
-                casm_extend! {ctx,
-                    [ap + 0] = [ap + (offset)], ap++;
-                    [ap + 0] = [ap - 1] + (values.len()), ap++;
-                }
             } else {
-                let arg_size = ty_size;
-                expected_arguments_size += arg_size as usize;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        let value = extract_matches!(value, Arg::Value);
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.to_bigint()), ap++;
+                // The cells of the argument - a cell per value, and the start and end pointers of
+                // each array.
+                expected_arguments_size += ty_size as usize;
+                let mut arg_offset: i16 = 0;
+                while arg_offset < ty_size {
+                    match arg_iter.next() {
+                        Some(Arg::Value(value)) => {
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.to_bigint()), ap++;
+                            }
+                            arg_offset += 1;
+                        }
+                        Some(Arg::Array(_)) => {
+                            let (vec_offset, array_size) = vecs.next().unwrap();
+                            let offset = vec_offset - ap_offset - arg_offset;
+                            casm_extend! {ctx,
+                                [ap + 0] = [ap + (offset)], ap++;
+                                [ap + 0] = [ap - 1] + (array_size), ap++;
+                            }
+                            arg_offset += 2;
                         }
+                        None => break,
                     }
                 }
             };
             ap_offset += ty_size;
         }
-        if expected_arguments_size != args.len() {
+        let actual_arguments_size = args.iter().map(arg_size).sum();
+        if expected_arguments_size != actual_arguments_size {
             return Err(RunnerError::ArgumentsSizeMismatch {
                 expected: expected_arguments_size,
-                actual: args.len(),
+                actual: actual_arguments_size,
             });
         }
         let before_final_call = ctx.current_code_offset;
@@ -514,6 +583,64 @@ impl SierraCasmRunner {
 }
 
 /// Creates the metadata required for a Sierra program lowering to casm.
+/// Returns the number of cells an argument takes on the stack - a cell for a value, or the start
+/// and end pointers of an array.
+fn arg_size(arg: &Arg) -> usize {
+    match arg {
+        Arg::Value(_) => 1,
+        Arg::Array(_) => 2,
+    }
+}
+
+/// Adds the code loading an array argument to a new segment, including the arrays nested in it.
+///
+/// Returns the number of cells the code pushes to the stack, the first of which is the pointer to
+/// the start of the array, and the number of cells of the array.
+fn load_array(ctx: &mut CasmContext, values: &[Arg]) -> (i16, usize) {
+    casm_extend! {ctx,
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+    }
+    // The number of cells pushed to the stack so far, which is also the offset of the pointer to
+    // the start of the array from `ap`.
+    let mut ap_change: i16 = 1;
+    let mut array_size: usize = 0;
+    for value in values {
+        match value {
+            Arg::Value(value) => {
+                let arr_at = ap_change;
+                let cell = array_size as i16;
+                casm_extend! {ctx,
+                    [ap + 0] = (value.to_bigint());
+                    [ap + 0] = [[ap - arr_at] + cell], ap++;
+                }
+                ap_change += 1;
+                array_size += 1;
+            }
+            Arg::Array(inner_values) => {
+                let inner_start = ap_change;
+                let (inner_ap_change, inner_array_size) = load_array(ctx, inner_values);
+                ap_change += inner_ap_change;
+                let inner_at = ap_change - inner_start;
+                casm_extend! {ctx,
+                    [ap + 0] = [ap - inner_at] + (inner_array_size), ap++;
+                }
+                ap_change += 1;
+                let arr_at = ap_change;
+                let inner_at = inner_at + 1;
+                let start_cell = array_size as i16;
+                let end_cell = start_cell + 1;
+                casm_extend! {ctx,
+                    [ap - inner_at] = [[ap - arr_at] + start_cell];
+                    [ap - 1] = [[ap - arr_at] + end_cell];
+                }
+                array_size += 2;
+            }
+        }
+    }
+    (ap_change, array_size)
+}
+
 fn create_metadata(
     sierra_program: &cairo_lang_sierra::program::Program,
     metadata_config: Option<MetadataComputationConfig>,
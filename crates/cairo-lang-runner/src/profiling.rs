@@ -0,0 +1,371 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::bitwise::BitwiseType;
+use cairo_lang_sierra::extensions::ec::EcOpType;
+use cairo_lang_sierra::extensions::gas::GasBuiltinType;
+use cairo_lang_sierra::extensions::pedersen::PedersenType;
+use cairo_lang_sierra::extensions::poseidon::PoseidonType;
+use cairo_lang_sierra::extensions::range_check::RangeCheckType;
+use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::{ConcreteTypeId, GenericTypeId};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::{chain, Itertools};
+use num_traits::ToPrimitive;
+
+use crate::{SierraCasmRunner, TraceEntry};
+
+/// The builtins tracked by the profiler, with the number of memory cells of each of their
+/// instances.
+pub(crate) const PROFILED_BUILTINS: [(GenericTypeId, usize); 5] = [
+    (PedersenType::ID, 3),
+    (RangeCheckType::ID, 1),
+    (BitwiseType::ID, 5),
+    (EcOpType::ID, 7),
+    (PoseidonType::ID, 6),
+];
+
+/// The default path of the collapsed stacks file of a profile.
+pub const COLLAPSED_STACKS_FILE: &str = "profile.folded";
+
+/// Configuration of the profiling mode.
+#[derive(Clone, Debug)]
+pub struct ProfilingConfig {
+    /// The path of the file the call stacks of the profile are written to, in the collapsed
+    /// stacks format.
+    pub collapsed_stacks_path: PathBuf,
+    /// The number of functions shown in the table of the functions with the most steps.
+    pub top_functions: usize,
+}
+impl Default for ProfilingConfig {
+    fn default() -> Self {
+        Self { collapsed_stacks_path: COLLAPSED_STACKS_FILE.into(), top_functions: 10 }
+    }
+}
+
+/// The resources used by the code of a Sierra function.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfileResources {
+    /// The number of VM steps.
+    pub steps: usize,
+    /// The number of memory holes - cells of the execution segment that were allocated but never
+    /// written.
+    pub memory_holes: usize,
+    /// The number of instances of each used builtin, by the name of its Sierra type.
+    pub builtins: OrderedHashMap<String, usize>,
+    /// The gas deducted from the gas counter.
+    pub gas: usize,
+}
+impl ProfileResources {
+    /// Adds the resources of `other` to these resources.
+    pub fn add(&mut self, other: &ProfileResources) {
+        self.steps += other.steps;
+        self.memory_holes += other.memory_holes;
+        for (name, count) in other.builtins.iter() {
+            *self.builtins.entry(name.clone()).or_default() += count;
+        }
+        self.gas += other.gas;
+    }
+}
+
+/// The profile of a Sierra function over a run.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionProfile {
+    /// The number of calls of the function.
+    pub calls: usize,
+    /// The resources used by the function, including the functions it called.
+    pub inclusive: ProfileResources,
+    /// The resources used by the function itself, excluding the functions it called.
+    pub exclusive: ProfileResources,
+}
+
+/// The profile of a run, collected from its trace.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfilingInfo {
+    /// The profile of every called Sierra function, by its name.
+    pub functions: OrderedHashMap<String, FunctionProfile>,
+    /// The number of VM steps executed in each call stack, keyed by the names of the functions in
+    /// the stack, from the outermost, separated by `;`.
+    pub stacks: OrderedHashMap<String, usize>,
+}
+impl ProfilingInfo {
+    /// Adds the profile of another run to this profile.
+    pub fn add(&mut self, other: &ProfilingInfo) {
+        for (name, profile) in other.functions.iter() {
+            let entry = self.functions.entry(name.clone()).or_default();
+            entry.calls += profile.calls;
+            entry.inclusive.add(&profile.inclusive);
+            entry.exclusive.add(&profile.exclusive);
+        }
+        for (stack, steps) in other.stacks.iter() {
+            *self.stacks.entry(stack.clone()).or_default() += steps;
+        }
+    }
+
+    /// Formats the call stacks in the collapsed stacks format, as consumed by flamegraph tools -
+    /// a line per call stack, with the number of steps executed in it.
+    pub fn format_collapsed_stacks(&self) -> String {
+        self.stacks.iter().map(|(stack, steps)| format!("{stack} {steps}\n")).join("")
+    }
+
+    /// Writes the call stacks of the profile to the collapsed stacks file of `config`.
+    pub fn write_collapsed_stacks(&self, config: &ProfilingConfig) -> Result<()> {
+        fs::write(&config.collapsed_stacks_path, self.format_collapsed_stacks()).with_context(
+            || format!("Failed to write `{}`.", config.collapsed_stacks_path.display()),
+        )
+    }
+
+    /// Formats a table of the `n` functions with the most steps, including their callees.
+    pub fn format_top_functions(&self, n: usize) -> String {
+        let header = [
+            "function",
+            "calls",
+            "steps",
+            "self steps",
+            "holes",
+            "self holes",
+            "gas",
+            "self gas",
+            "builtins",
+        ]
+        .map(String::from);
+        let rows = self
+            .functions
+            .iter()
+            .sorted_by(|(name_a, a), (name_b, b)| {
+                b.inclusive.steps.cmp(&a.inclusive.steps).then(name_a.cmp(name_b))
+            })
+            .take(n)
+            .map(|(name, profile)| {
+                [
+                    name.clone(),
+                    profile.calls.to_string(),
+                    profile.inclusive.steps.to_string(),
+                    profile.exclusive.steps.to_string(),
+                    profile.inclusive.memory_holes.to_string(),
+                    profile.exclusive.memory_holes.to_string(),
+                    profile.inclusive.gas.to_string(),
+                    profile.exclusive.gas.to_string(),
+                    profile
+                        .inclusive
+                        .builtins
+                        .iter()
+                        .map(|(name, count)| format!("{name}: {count}"))
+                        .join(", "),
+                ]
+            })
+            .collect_vec();
+        let widths = (0..header.len())
+            .map(|i| rows.iter().map(|row| row[i].len()).chain([header[i].len()]).max().unwrap())
+            .collect_vec();
+        let format_row = |row: &[String]| {
+            row.iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(i, (cell, width))| match i {
+                    // The function name is aligned to the left, the numbers to the right.
+                    0 => format!("{cell:<width$}"),
+                    _ => format!("{cell:>width$}"),
+                })
+                .join("  ")
+                .trim_end()
+                .to_string()
+                + "\n"
+        };
+        chain!([header], rows).map(|row| format_row(&row)).join("")
+    }
+}
+
+/// A call of a Sierra function, on the call stack of the profiled run.
+struct Frame {
+    /// The index of the called function in the Sierra program.
+    function_idx: usize,
+    /// The `fp` of the call.
+    fp: usize,
+    /// The call stack up to and including this call, in the collapsed stacks format.
+    stack: String,
+    /// The values of the builtins pointers and the gas counter at the call, if it entered the
+    /// function at its entry point.
+    arguments: Option<OrderedHashMap<GenericTypeId, usize>>,
+    /// The steps and memory holes of the function itself.
+    exclusive: ProfileResources,
+    /// The inclusive resources of the calls made by the function.
+    callees: ProfileResources,
+}
+
+impl SierraCasmRunner {
+    /// Profiles a run by the Sierra functions called in its trace.
+    ///
+    /// `trace` is the trace of the run within the CASM code of the Sierra program (see
+    /// [crate::RunResultStarknet::trace]), and `memory` is the relocated memory of the run.
+    /// The calls are found by the changes of `fp` during the run. The steps and memory holes are
+    /// attributed per step, while the builtins and gas used by a call are computed from the values
+    /// of its implicit arguments and return values.
+    pub fn profile(&self, trace: &[TraceEntry], memory: &[Option<Felt252>]) -> ProfilingInfo {
+        // The code offset of the entry point of each function, sorted by offset.
+        let entry_offsets = self
+            .sierra_program
+            .funcs
+            .iter()
+            .enumerate()
+            .map(|(idx, function)| {
+                (
+                    self.casm_program.debug_info.sierra_statement_info[function.entry_point.0]
+                        .code_offset,
+                    idx,
+                )
+            })
+            .sorted()
+            .collect_vec();
+        let mut info = ProfilingInfo::default();
+        let mut frames: Vec<Frame> = vec![];
+        for (step_idx, entry) in trace.iter().enumerate() {
+            if let Some(depth) = frames.iter().rposition(|frame| frame.fp == entry.fp) {
+                // Returning to the call of an outer frame.
+                while frames.len() > depth + 1 {
+                    self.end_call(&mut info, &mut frames, trace[step_idx - 1].ap, memory);
+                }
+            } else {
+                let position = entry_offsets.partition_point(|(offset, _)| *offset <= entry.pc);
+                let (entry_offset, function_idx) = entry_offsets[position.max(1) - 1];
+                let name = self.sierra_program.funcs[function_idx].id.to_string();
+                let stack = match frames.last() {
+                    Some(caller) => format!("{};{name}", caller.stack),
+                    None => name,
+                };
+                let arguments = (entry.pc == entry_offset)
+                    .then(|| self.implicit_arguments(function_idx, entry.fp, memory));
+                frames.push(Frame {
+                    function_idx,
+                    fp: entry.fp,
+                    stack,
+                    arguments,
+                    exclusive: ProfileResources::default(),
+                    callees: ProfileResources::default(),
+                });
+            }
+            let frame = frames.last_mut().unwrap();
+            frame.exclusive.steps += 1;
+            *info.stacks.entry(frame.stack.clone()).or_default() += 1;
+            if let Some(next_entry) = trace.get(step_idx + 1) {
+                frame.exclusive.memory_holes += (entry.ap..next_entry.ap)
+                    .filter(|address| !matches!(memory.get(*address), Some(Some(_))))
+                    .count();
+            }
+        }
+        if let Some(last_entry) = trace.last() {
+            while !frames.is_empty() {
+                self.end_call(&mut info, &mut frames, last_entry.ap, memory);
+            }
+        }
+        info
+    }
+
+    /// Pops the innermost call from `frames`, and adds its resources to the profile.
+    /// `ap` is the value of `ap` after the return of the call.
+    fn end_call(
+        &self,
+        info: &mut ProfilingInfo,
+        frames: &mut Vec<Frame>,
+        ap: usize,
+        memory: &[Option<Felt252>],
+    ) {
+        let frame = frames.pop().unwrap();
+        let mut inclusive = frame.exclusive.clone();
+        inclusive.add(&frame.callees);
+        let mut exclusive = frame.exclusive;
+        if let Some(arguments) = &frame.arguments {
+            // The builtins and gas are only known at the boundaries of the call.
+            let return_values = self.implicit_return_values(frame.function_idx, ap, memory);
+            inclusive.builtins = Default::default();
+            inclusive.gas = 0;
+            for (generic_id, argument) in arguments.iter() {
+                let Some(return_value) = return_values.get(generic_id) else { continue };
+                if *generic_id == GasBuiltinType::ID {
+                    inclusive.gas = argument.saturating_sub(*return_value);
+                } else if let Some((_, cells)) =
+                    PROFILED_BUILTINS.iter().find(|(id, _)| id == generic_id)
+                {
+                    let count = return_value.saturating_sub(*argument) / cells;
+                    if count > 0 {
+                        inclusive.builtins.insert(generic_id.0.to_string(), count);
+                    }
+                }
+            }
+            for (name, count) in inclusive.builtins.iter() {
+                let callees_count = frame.callees.builtins.get(name).copied().unwrap_or_default();
+                if *count > callees_count {
+                    exclusive.builtins.insert(name.clone(), count - callees_count);
+                }
+            }
+            exclusive.gas = inclusive.gas.saturating_sub(frame.callees.gas);
+        }
+        if let Some(caller) = frames.last_mut() {
+            caller.callees.add(&inclusive);
+        }
+        let function = &self.sierra_program.funcs[frame.function_idx];
+        let profile = info.functions.entry(function.id.to_string()).or_default();
+        profile.calls += 1;
+        profile.exclusive.add(&exclusive);
+        // A recursive call is already included in the outer call of the same function.
+        if !frames.iter().any(|caller| caller.function_idx == frame.function_idx) {
+            profile.inclusive.add(&inclusive);
+        }
+    }
+
+    /// Returns the values of the builtins pointers and the gas counter passed to a function, by
+    /// the generic ids of their types, given the `fp` of the call.
+    pub(crate) fn implicit_arguments(
+        &self,
+        function_idx: usize,
+        fp: usize,
+        memory: &[Option<Felt252>],
+    ) -> OrderedHashMap<GenericTypeId, usize> {
+        let param_types = &self.sierra_program.funcs[function_idx].signature.param_types;
+        let args_size: usize = param_types.iter().map(|ty| self.type_sizes[ty] as usize).sum();
+        // The arguments are right before the return `fp` and `pc` pushed by the call.
+        self.implicit_values(param_types, fp - 2 - args_size, memory)
+    }
+
+    /// Returns the values of the builtins pointers and the gas counter returned by a function, by
+    /// the generic ids of their types, given the `ap` after the return.
+    pub(crate) fn implicit_return_values(
+        &self,
+        function_idx: usize,
+        ap: usize,
+        memory: &[Option<Felt252>],
+    ) -> OrderedHashMap<GenericTypeId, usize> {
+        let ret_types = &self.sierra_program.funcs[function_idx].signature.ret_types;
+        let rets_size: usize = ret_types.iter().map(|ty| self.type_sizes[ty] as usize).sum();
+        self.implicit_values(ret_types, ap - rets_size, memory)
+    }
+
+    /// Returns the values of the builtins pointers (including the segment arena) and the gas
+    /// counter in a sequence of values of the given types, starting at `address`.
+    fn implicit_values(
+        &self,
+        types: &[ConcreteTypeId],
+        mut address: usize,
+        memory: &[Option<Felt252>],
+    ) -> OrderedHashMap<GenericTypeId, usize> {
+        let mut values = OrderedHashMap::default();
+        for ty in types {
+            let generic_id = &self.get_info(ty).long_id.generic_id;
+            if *generic_id == GasBuiltinType::ID
+                || *generic_id == SegmentArenaType::ID
+                || PROFILED_BUILTINS.iter().any(|(id, _)| id == generic_id)
+            {
+                if let Some(value) =
+                    memory.get(address).cloned().flatten().and_then(|value| value.to_usize())
+                {
+                    values.insert(generic_id.clone(), value);
+                }
+            }
+            address += self.type_sizes[ty] as usize;
+        }
+        values
+    }
+}
@@ -1,4 +1,5 @@
 use cairo_felt::Felt252;
+use num_traits::{Num, ToPrimitive};
 
 /// Converts a bigint representing a felt252 to a Cairo short-string.
 pub fn as_cairo_short_string(value: &Felt252) -> Option<String> {
@@ -15,3 +16,59 @@ pub fn as_cairo_short_string(value: &Felt252) -> Option<String> {
     }
     Some(as_string)
 }
+
+/// The magic felt252 that precedes a serialized `ByteArray` in panic data.
+const BYTE_ARRAY_MAGIC: &str = "46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3";
+/// The number of bytes in a full word of a `ByteArray`.
+const BYTES_IN_WORD: usize = 31;
+
+/// Formats the given panic data, decoding felt252s as short strings where possible, and
+/// serialized `ByteArray`s (preceded by the `ByteArray` magic) as full strings.
+pub fn format_for_panic(felts: &[Felt252]) -> String {
+    let magic = Felt252::from_str_radix(BYTE_ARRAY_MAGIC, 16).unwrap();
+    let mut formatted = String::default();
+    let mut felts = felts.iter();
+    while let Some(value) = felts.next() {
+        if *value == magic {
+            let mut byte_array_felts = felts.clone();
+            if let Some(string) = try_decode_byte_array(&mut byte_array_felts) {
+                formatted.push_str(&format!("{string:?}, "));
+                felts = byte_array_felts;
+                continue;
+            }
+        }
+        match as_cairo_short_string(value) {
+            Some(as_string) => formatted.push_str(&format!("{value} ('{as_string}'), ")),
+            None => formatted.push_str(&format!("{value}, ")),
+        }
+    }
+    format!("[{formatted}]")
+}
+
+/// Tries to decode a serialized `ByteArray` from the given felts, consuming them.
+fn try_decode_byte_array<'a>(felts: &mut impl Iterator<Item = &'a Felt252>) -> Option<String> {
+    let n_full_words = felts.next()?.to_usize()?;
+    let mut bytes = Vec::new();
+    for _ in 0..n_full_words {
+        bytes.extend(felt252_to_bytes(felts.next()?, BYTES_IN_WORD)?);
+    }
+    let pending_word = felts.next()?;
+    let pending_word_len = felts.next()?.to_usize()?;
+    if pending_word_len >= BYTES_IN_WORD {
+        return None;
+    }
+    bytes.extend(felt252_to_bytes(pending_word, pending_word_len)?);
+    String::from_utf8(bytes).ok()
+}
+
+/// Converts a felt252 to exactly `len` big-endian bytes, if it fits.
+fn felt252_to_bytes(value: &Felt252, len: usize) -> Option<Vec<u8>> {
+    let bytes = value.to_biguint().to_bytes_be();
+    let significant_bytes = bytes.iter().skip_while(|byte| **byte == 0).count();
+    if significant_bytes > len {
+        return None;
+    }
+    let mut padded = vec![0; len - significant_bytes];
+    padded.extend(&bytes[bytes.len() - significant_bytes..]);
+    Some(padded)
+}
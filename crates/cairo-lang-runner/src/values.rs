@@ -0,0 +1,464 @@
+use std::fmt::Display;
+
+use cairo_felt::Felt252;
+use cairo_lang_sierra::extensions::core::CoreTypeConcrete;
+use cairo_lang_sierra::extensions::enm::EnumType;
+use cairo_lang_sierra::extensions::gas::GasBuiltinType;
+use cairo_lang_sierra::extensions::segment_arena::SegmentArenaType;
+use cairo_lang_sierra::extensions::starknet::syscalls::SystemType;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Function, GenericArg};
+use cairo_lang_utils::extract_matches;
+use itertools::Itertools;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Num, One, ToPrimitive, Zero};
+use thiserror::Error;
+
+use crate::profiling::PROFILED_BUILTINS;
+use crate::{Arg, RunnerError, SierraCasmRunner};
+
+#[cfg(test)]
+#[path = "values_test.rs"]
+mod test;
+
+/// The maximal number of characters in a short string.
+const MAX_SHORT_STRING_LEN: usize = 31;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ParseArgsError {
+    #[error("Unexpected `]`.")]
+    UnexpectedClosingBracket,
+    #[error("Missing `]`.")]
+    MissingClosingBracket,
+    #[error("Missing closing `'` of a short string.")]
+    UnterminatedShortString,
+    #[error("The short string `{0}` is longer than 31 characters.")]
+    ShortStringTooLong(String),
+    #[error("Invalid value `{0}`.")]
+    InvalidValue(String),
+    #[error("The value `{0}` is out of the range of felt252.")]
+    ValueOutOfRange(String),
+}
+
+/// Parses the arguments of a function run from text.
+///
+/// The arguments are separated by whitespace (or commas). Each argument is either a value - a
+/// decimal or `0x`-prefixed hexadecimal integer, possibly negative, or a short string in single
+/// quotes - or an array of arguments in square brackets, e.g. `1 -2 0x3 'hello' [4 [5 6]]`.
+pub fn parse_args(text: &str) -> Result<Vec<Arg>, ParseArgsError> {
+    let mut chars = text.chars().peekable();
+    // The arguments of the arrays being parsed, from the outermost.
+    let mut arrays: Vec<Vec<Arg>> = vec![vec![]];
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() || c == ',' => {}
+            '[' => arrays.push(vec![]),
+            ']' => {
+                if arrays.len() == 1 {
+                    return Err(ParseArgsError::UnexpectedClosingBracket);
+                }
+                let array = arrays.pop().unwrap();
+                arrays.last_mut().unwrap().push(Arg::Array(array));
+            }
+            '\'' => {
+                let mut string = String::default();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => string.push(c),
+                        None => return Err(ParseArgsError::UnterminatedShortString),
+                    }
+                }
+                if string.len() > MAX_SHORT_STRING_LEN {
+                    return Err(ParseArgsError::ShortStringTooLong(string));
+                }
+                let value = Felt252::from_bytes_be(string.as_bytes());
+                arrays.last_mut().unwrap().push(Arg::Value(value));
+            }
+            c => {
+                let mut token = c.to_string();
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace() && !",[]'".contains(*c)) {
+                    token.push(c);
+                }
+                arrays.last_mut().unwrap().push(Arg::Value(parse_value(&token)?));
+            }
+        }
+    }
+    if arrays.len() != 1 {
+        return Err(ParseArgsError::MissingClosingBracket);
+    }
+    Ok(arrays.pop().unwrap())
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal integer, possibly negative, to a felt252.
+///
+/// A non-negative value must be below the prime, and a negative value must be in the range of a
+/// signed felt252 (see [Felt252::to_signed_felt]), so that no two values map to the same felt252
+/// once read back as signed.
+fn parse_value(token: &str) -> Result<Felt252, ParseArgsError> {
+    let (is_negative, abs) = match token.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, token),
+    };
+    let abs = match abs.strip_prefix("0x") {
+        Some(hex) => BigInt::from_str_radix(hex, 16),
+        None => BigInt::from_str_radix(abs, 10),
+    }
+    .map_err(|_| ParseArgsError::InvalidValue(token.to_string()))?;
+    let prime = BigInt::from(Felt252::prime());
+    let max_abs = if is_negative { (prime - 1) / 2 } else { prime - 1 };
+    if abs > max_abs {
+        return Err(ParseArgsError::ValueOutOfRange(token.to_string()));
+    }
+    Ok(Felt252::from(if is_negative { -abs } else { abs }))
+}
+
+/// A value of a Sierra type, decoded from the memory of a run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodedValue {
+    /// A felt252 or an integer.
+    Int(BigInt),
+    Bool(bool),
+    /// An array or a span, by its elements.
+    Array(Vec<DecodedValue>),
+    /// A struct, by the name of its type and its members.
+    Struct {
+        name: String,
+        members: Vec<DecodedValue>,
+    },
+    /// A variant of an enum, by the name of the enum type and the index of the variant.
+    Enum {
+        name: String,
+        variant: usize,
+        value: Box<DecodedValue>,
+    },
+    /// A value of a type that is not decoded, by its cells.
+    Raw(Vec<Felt252>),
+}
+impl DecodedValue {
+    /// Returns the value in JSON. Integers are given as decimal strings, as they may exceed the
+    /// range of JSON numbers.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            DecodedValue::Int(value) => value.to_string().into(),
+            DecodedValue::Bool(value) => (*value).into(),
+            DecodedValue::Array(values) => values.iter().map(Self::to_json).collect(),
+            DecodedValue::Struct { name, members } => serde_json::json!({
+                "type": name,
+                "members": members.iter().map(Self::to_json).collect_vec(),
+            }),
+            DecodedValue::Enum { name, variant, value } => serde_json::json!({
+                "type": name,
+                "variant": variant,
+                "value": value.to_json(),
+            }),
+            DecodedValue::Raw(cells) => cells.iter().map(|cell| cell.to_string()).collect(),
+        }
+    }
+}
+impl Display for DecodedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodedValue::Int(value) => write!(f, "{value}"),
+            DecodedValue::Bool(value) => write!(f, "{value}"),
+            DecodedValue::Array(values) => write!(f, "[{}]", values.iter().join(", ")),
+            DecodedValue::Struct { name, members } if name == "Tuple" => match &members[..] {
+                [member] => write!(f, "({member},)"),
+                _ => write!(f, "({})", members.iter().join(", ")),
+            },
+            DecodedValue::Struct { name, members } => {
+                write!(f, "{name}({})", members.iter().join(", "))
+            }
+            DecodedValue::Enum { name, variant, value } => write!(f, "{name}::{variant}({value})"),
+            DecodedValue::Raw(cells) => write!(f, "<{}>", cells.iter().join(", ")),
+        }
+    }
+}
+
+impl SierraCasmRunner {
+    /// Finds the function with the given path, or whose path ends with `::` followed by the given
+    /// path.
+    pub fn find_function_by_path(&self, path: &str) -> Result<&Function, RunnerError> {
+        let suffix = format!("::{path}");
+        self.sierra_program
+            .funcs
+            .iter()
+            .find(|f| {
+                f.id.debug_name.as_ref().is_some_and(|name| name == path || name.ends_with(&suffix))
+            })
+            .ok_or_else(|| RunnerError::MissingFunction { suffix: path.to_owned() })
+    }
+
+    /// Prepares `args` for a run of `func`, by the types of its parameters (excluding the
+    /// implicits).
+    ///
+    /// A parameter of an array or span type takes an array of arguments for its elements. An
+    /// integer given for a `u256` is split into its low and high words. The members of a struct or
+    /// a tuple are taken one after the other. Any other parameter takes a value per cell.
+    ///
+    /// An argument of an integer type (including `u256`) is read as a signed felt252, and must be
+    /// in the range of the type.
+    pub fn prepare_args(&self, func: &Function, args: Vec<Arg>) -> Result<Vec<Arg>, RunnerError> {
+        let mut args = args.into_iter().peekable();
+        let mut prepared_args = vec![];
+        for ty in &func.signature.param_types {
+            if !self.is_implicit(ty) {
+                self.prepare_arg(ty, &mut args, &mut prepared_args)?;
+            }
+        }
+        if args.next().is_some() {
+            return Err(RunnerError::InvalidArguments(format!(
+                "Function `{}` received too many arguments.",
+                func.id
+            )));
+        }
+        Ok(prepared_args)
+    }
+
+    /// Prepares the arguments for a value of type `ty`, taken from `args`, adding them to
+    /// `prepared_args`.
+    fn prepare_arg(
+        &self,
+        ty: &ConcreteTypeId,
+        args: &mut std::iter::Peekable<impl Iterator<Item = Arg>>,
+        prepared_args: &mut Vec<Arg>,
+    ) -> Result<(), RunnerError> {
+        let Some(arg) = args.peek() else {
+            return Err(RunnerError::InvalidArguments(format!(
+                "Missing an argument of type `{ty}`."
+            )));
+        };
+        match self.sierra_program_registry.get_type(ty)? {
+            CoreTypeConcrete::Array(info) | CoreTypeConcrete::Span(info) => {
+                let Some(Arg::Array(values)) = args.next() else {
+                    return Err(RunnerError::InvalidArguments(format!(
+                        "Expected an array for an argument of type `{ty}`."
+                    )));
+                };
+                let mut values = values.into_iter().peekable();
+                let mut elements = vec![];
+                while values.peek().is_some() {
+                    self.prepare_arg(&info.ty, &mut values, &mut elements)?;
+                }
+                prepared_args.push(Arg::Array(elements));
+            }
+            CoreTypeConcrete::Snapshot(info) => self.prepare_arg(&info.ty, args, prepared_args)?,
+            CoreTypeConcrete::Struct(info)
+                if is_u256(&info.info.long_id.generic_args) && matches!(arg, Arg::Value(_)) =>
+            {
+                let value = extract_matches!(args.next().unwrap(), Arg::Value);
+                let bounds = (BigInt::zero(), (BigInt::one() << 256) - 1);
+                let value = check_int_arg(&value, &bounds, ty)?.to_biguint().unwrap();
+                let mask = (BigUint::one() << 128) - BigUint::one();
+                prepared_args.push(Arg::Value(Felt252::from(&value & mask)));
+                prepared_args.push(Arg::Value(Felt252::from(value >> 128)));
+            }
+            CoreTypeConcrete::Struct(info) => {
+                for member in &info.members {
+                    self.prepare_arg(member, args, prepared_args)?;
+                }
+            }
+            concrete_ty => {
+                let bounds = int_bounds(concrete_ty);
+                for _ in 0..self.type_sizes[ty] {
+                    match args.next() {
+                        Some(Arg::Value(value)) => {
+                            if let Some(bounds) = &bounds {
+                                check_int_arg(&value, bounds, ty)?;
+                            }
+                            prepared_args.push(Arg::Value(value));
+                        }
+                        Some(Arg::Array(_)) => {
+                            return Err(RunnerError::InvalidArguments(format!(
+                                "Expected a value for an argument of type `{ty}`, found an array."
+                            )));
+                        }
+                        None => {
+                            return Err(RunnerError::InvalidArguments(format!(
+                                "Missing an argument of type `{ty}`."
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether `ty` is the type of an implicit parameter, initialized by the runner.
+    pub(crate) fn is_implicit(&self, ty: &ConcreteTypeId) -> bool {
+        let generic_id = &self.get_info(ty).long_id.generic_id;
+        [GasBuiltinType::ID, SystemType::ID, SegmentArenaType::ID].contains(generic_id)
+            || PROFILED_BUILTINS.iter().any(|(id, _)| id == generic_id)
+    }
+
+    /// Decodes the value returned by a successful run of `func`, given the cells of the value (see
+    /// [crate::RunResultValue::Success]) and the memory of the run. Returns `None` if the function
+    /// does not return a value.
+    pub fn decode_return_value(
+        &self,
+        func: &Function,
+        values: &[Felt252],
+        memory: &[Option<Felt252>],
+    ) -> Option<DecodedValue> {
+        let ty = func.signature.ret_types.iter().find(|ty| !self.is_implicit(ty))?;
+        let long_id = &self.get_info(ty).long_id;
+        let ty = if long_id.generic_id == EnumType::ID
+            && matches!(&long_id.generic_args[0], GenericArg::UserType(ut)
+                if ut.debug_name.as_ref().unwrap().starts_with("core::panics::PanicResult::"))
+        {
+            // The function includes a panic wrapper, and the values are of the inner value - a
+            // tuple of the returned value and the values of the `ref` parameters.
+            let inner_ty = extract_matches!(&long_id.generic_args[1], GenericArg::Type);
+            match self.sierra_program_registry.get_type(inner_ty).unwrap() {
+                CoreTypeConcrete::Struct(info) if info.members.len() == 1 => &info.members[0],
+                _ => inner_ty,
+            }
+        } else {
+            ty
+        };
+        Some(self.decode_value(ty, values, memory))
+    }
+
+    /// Decodes a value of type `ty` from its cells.
+    pub(crate) fn decode_value(
+        &self,
+        ty: &ConcreteTypeId,
+        cells: &[Felt252],
+        memory: &[Option<Felt252>],
+    ) -> DecodedValue {
+        match self.sierra_program_registry.get_type(ty).unwrap() {
+            CoreTypeConcrete::Felt252(_)
+            | CoreTypeConcrete::Uint8(_)
+            | CoreTypeConcrete::Uint16(_)
+            | CoreTypeConcrete::Uint32(_)
+            | CoreTypeConcrete::Uint64(_)
+            | CoreTypeConcrete::Uint128(_)
+            | CoreTypeConcrete::Bytes31(_) => DecodedValue::Int(cells[0].to_bigint()),
+            CoreTypeConcrete::Sint8(_)
+            | CoreTypeConcrete::Sint16(_)
+            | CoreTypeConcrete::Sint32(_)
+            | CoreTypeConcrete::Sint64(_)
+            | CoreTypeConcrete::Sint128(_) => DecodedValue::Int(cells[0].to_signed_felt()),
+            CoreTypeConcrete::Array(info) | CoreTypeConcrete::Span(info) => {
+                let start = cells[0].to_usize().unwrap();
+                let end = cells[1].to_usize().unwrap();
+                let element_cells =
+                    memory[start..end].iter().map(|cell| cell.clone().unwrap()).collect_vec();
+                let element_size = self.type_sizes[&info.ty] as usize;
+                DecodedValue::Array(if element_size == 0 {
+                    vec![]
+                } else {
+                    element_cells
+                        .chunks(element_size)
+                        .map(|element| self.decode_value(&info.ty, element, memory))
+                        .collect()
+                })
+            }
+            CoreTypeConcrete::Snapshot(info) | CoreTypeConcrete::NonZero(info) => {
+                self.decode_value(&info.ty, cells, memory)
+            }
+            CoreTypeConcrete::Box(info) => {
+                let address = cells[0].to_usize().unwrap();
+                let size = self.type_sizes[&info.ty] as usize;
+                let inner_cells = memory[address..address + size]
+                    .iter()
+                    .map(|cell| cell.clone().unwrap())
+                    .collect_vec();
+                self.decode_value(&info.ty, &inner_cells, memory)
+            }
+            CoreTypeConcrete::Struct(info) => {
+                let name = user_type_name(&info.info.long_id.generic_args);
+                if is_u256(&info.info.long_id.generic_args) {
+                    return DecodedValue::Int(cells[0].to_bigint() + (cells[1].to_bigint() << 128));
+                }
+                let mut offset = 0;
+                let members = info
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let size = self.type_sizes[member] as usize;
+                        let value =
+                            self.decode_value(member, &cells[offset..offset + size], memory);
+                        offset += size;
+                        value
+                    })
+                    .collect();
+                DecodedValue::Struct { name, members }
+            }
+            CoreTypeConcrete::Enum(info) => {
+                let name = user_type_name(&info.info.long_id.generic_args);
+                let num_variants = info.variants.len();
+                let selector = cells[0].to_usize().unwrap();
+                // See the variant selector of `enum_init` in the Sierra to CASM compilation. With
+                // more than 2 variants, the selector of variant `k` is `2 * (n - k) - 1`.
+                let variant = if num_variants <= 2 {
+                    selector
+                } else {
+                    (2 * num_variants - 1 - selector) / 2
+                };
+                if name == "core::bool" {
+                    return DecodedValue::Bool(variant == 1);
+                }
+                let variant_ty = &info.variants[variant];
+                let variant_size = self.type_sizes[variant_ty] as usize;
+                // The value of the variant is padded from the start to the size of the enum.
+                let value =
+                    self.decode_value(variant_ty, &cells[cells.len() - variant_size..], memory);
+                DecodedValue::Enum { name, variant, value: Box::new(value) }
+            }
+            _ => DecodedValue::Raw(cells.to_vec()),
+        }
+    }
+}
+
+/// Returns the name of a struct or an enum type, given its generic arguments.
+fn user_type_name(generic_args: &[GenericArg]) -> String {
+    match generic_args.first() {
+        Some(GenericArg::UserType(user_type)) => match &user_type.debug_name {
+            Some(name) => name.to_string(),
+            None => user_type.id.to_string(),
+        },
+        _ => String::default(),
+    }
+}
+
+/// Returns whether the generic arguments of a struct type are of `u256`.
+fn is_u256(generic_args: &[GenericArg]) -> bool {
+    user_type_name(generic_args) == "core::integer::u256"
+}
+
+/// Returns the minimal and maximal values of an integer type, or `None` if `ty` is not an integer
+/// type.
+fn int_bounds(ty: &CoreTypeConcrete) -> Option<(BigInt, BigInt)> {
+    Some(match ty {
+        CoreTypeConcrete::Uint8(_) => (u8::MIN.into(), u8::MAX.into()),
+        CoreTypeConcrete::Uint16(_) => (u16::MIN.into(), u16::MAX.into()),
+        CoreTypeConcrete::Uint32(_) => (u32::MIN.into(), u32::MAX.into()),
+        CoreTypeConcrete::Uint64(_) => (u64::MIN.into(), u64::MAX.into()),
+        CoreTypeConcrete::Uint128(_) => (u128::MIN.into(), u128::MAX.into()),
+        CoreTypeConcrete::Sint8(_) => (i8::MIN.into(), i8::MAX.into()),
+        CoreTypeConcrete::Sint16(_) => (i16::MIN.into(), i16::MAX.into()),
+        CoreTypeConcrete::Sint32(_) => (i32::MIN.into(), i32::MAX.into()),
+        CoreTypeConcrete::Sint64(_) => (i64::MIN.into(), i64::MAX.into()),
+        CoreTypeConcrete::Sint128(_) => (i128::MIN.into(), i128::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// Returns the value of an argument of the integer type `ty`, read as a signed felt252, or an
+/// error if it is out of the `(min, max)` bounds of the type.
+fn check_int_arg(
+    value: &Felt252,
+    (min, max): &(BigInt, BigInt),
+    ty: &ConcreteTypeId,
+) -> Result<BigInt, RunnerError> {
+    let value = value.to_signed_felt();
+    if value < *min || value > *max {
+        return Err(RunnerError::InvalidArguments(format!(
+            "Argument `{value}` is out of the range of type `{ty}`."
+        )));
+    }
+    Ok(value)
+}
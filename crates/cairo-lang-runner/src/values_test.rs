@@ -0,0 +1,145 @@
+use cairo_felt::Felt252;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_sierra_generator::test_utils::setup_db_and_get_crate_id;
+use indoc::indoc;
+use num_bigint::BigInt;
+use serde_json::json;
+use test_case::test_case;
+
+use super::{parse_args, DecodedValue, ParseArgsError};
+use crate::{Arg, SierraCasmRunner};
+
+fn value(value: i128) -> Arg {
+    Arg::Value(Felt252::from(value))
+}
+
+#[test_case("", vec![]; "empty")]
+#[test_case(" 1  -2,0x1f ", vec![value(1), value(-2), value(31)]; "values")]
+#[test_case("'abc' ''", vec![value(0x616263), value(0)]; "short strings")]
+#[test_case(
+    "[1 [2, 3] []] 4",
+    vec![
+        Arg::Array(vec![value(1), Arg::Array(vec![value(2), value(3)]), Arg::Array(vec![])]),
+        value(4),
+    ];
+    "nested arrays"
+)]
+fn test_parse_args(text: &str, expected: Vec<Arg>) {
+    assert_eq!(parse_args(text), Ok(expected));
+}
+
+#[test_case("1 ]", ParseArgsError::UnexpectedClosingBracket; "unexpected closing bracket")]
+#[test_case("[1 [2]", ParseArgsError::MissingClosingBracket; "missing closing bracket")]
+#[test_case("'abc", ParseArgsError::UnterminatedShortString; "unterminated short string")]
+#[test_case(
+    "'abcdefghijabcdefghijabcdefghijab'",
+    ParseArgsError::ShortStringTooLong("abcdefghijabcdefghijabcdefghijab".into());
+    "short string too long"
+)]
+#[test_case("1x2", ParseArgsError::InvalidValue("1x2".into()); "invalid value")]
+#[test_case(
+    "0x800000000000011000000000000000000000000000000000000000000000001",
+    ParseArgsError::ValueOutOfRange(
+        "0x800000000000011000000000000000000000000000000000000000000000001".into()
+    );
+    "value out of range"
+)]
+#[test_case(
+    "-0x400000000000008800000000000000000000000000000000000000000000001",
+    ParseArgsError::ValueOutOfRange(
+        "-0x400000000000008800000000000000000000000000000000000000000000001".into()
+    );
+    "negative value out of range"
+)]
+fn test_parse_args_failure(text: &str, expected: ParseArgsError) {
+    assert_eq!(parse_args(text), Err(expected));
+}
+
+#[test]
+fn test_display() {
+    let int = |value: i64| DecodedValue::Int(BigInt::from(value));
+    let value = DecodedValue::Struct {
+        name: "Tuple".into(),
+        members: vec![
+            DecodedValue::Array(vec![int(1), int(-2)]),
+            DecodedValue::Struct { name: "test::Point".into(), members: vec![int(3), int(4)] },
+            DecodedValue::Enum {
+                name: "core::option::Option::<core::integer::u8>".into(),
+                variant: 0,
+                value: Box::new(int(5)),
+            },
+            DecodedValue::Bool(true),
+            DecodedValue::Struct { name: "Tuple".into(), members: vec![int(6)] },
+        ],
+    };
+    assert_eq!(
+        value.to_string(),
+        "([1, -2], test::Point(3, 4), core::option::Option::<core::integer::u8>::0(5), true, (6,))"
+    );
+    assert_eq!(
+        value.to_json(),
+        json!({
+            "type": "Tuple",
+            "members": [
+                ["1", "-2"],
+                {"type": "test::Point", "members": ["3", "4"]},
+                {"type": "core::option::Option::<core::integer::u8>", "variant": 0, "value": "5"},
+                true,
+                {"type": "Tuple", "members": ["6"]},
+            ],
+        })
+    );
+}
+
+/// Returns a runner of a program with functions taking an argument of each integer type.
+fn int_args_runner() -> SierraCasmRunner {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+        fn take_u8(a: u8) -> u8 { a }
+        fn take_i8(a: i8) -> i8 { a }
+        fn take_u128(a: u128) -> u128 { a }
+        fn take_u256(a: u256) -> u256 { a }
+        fn take_felt252(a: felt252) -> felt252 { a }
+    "});
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &program), None, Default::default())
+        .unwrap()
+}
+
+#[test_case("take_u8", "255", vec![value(255)]; "u8 max")]
+#[test_case("take_i8", "-128", vec![value(-128)]; "i8 min")]
+#[test_case(
+    "take_u128",
+    "0xffffffffffffffffffffffffffffffff",
+    vec![Arg::Value(Felt252::from(u128::MAX))];
+    "u128 max"
+)]
+#[test_case("take_u256", "0x100000000000000000000000000000002", vec![value(2), value(1)]; "u256")]
+#[test_case("take_felt252", "-1", vec![value(-1)]; "negative felt252")]
+fn test_prepare_int_args(function: &str, args: &str, expected: Vec<Arg>) {
+    let runner = int_args_runner();
+    let func = runner.find_function_by_path(function).unwrap();
+    let prepared_args = runner.prepare_args(func, parse_args(args).unwrap()).unwrap();
+    assert_eq!(prepared_args, expected);
+}
+
+#[test_case("take_u8", "-1", "-1", "u8"; "negative unsigned argument")]
+#[test_case("take_u8", "256", "256", "u8"; "argument out of range")]
+#[test_case("take_i8", "128", "128", "i8"; "signed argument out of range")]
+#[test_case("take_i8", "-129", "-129", "i8"; "negative signed argument out of range")]
+#[test_case(
+    "take_u128",
+    "0x100000000000000000000000000000000",
+    "340282366920938463463374607431768211456",
+    "u128";
+    "u128 out of range"
+)]
+#[test_case("take_u256", "-1", "-1", "core::integer::u256"; "negative u256 argument")]
+fn test_prepare_int_args_out_of_range(function: &str, args: &str, value: &str, ty: &str) {
+    let runner = int_args_runner();
+    let func = runner.find_function_by_path(function).unwrap();
+    assert_eq!(
+        runner.prepare_args(func, parse_args(args).unwrap()).unwrap_err().to_string(),
+        format!("Argument `{value}` is out of the range of type `{ty}`.")
+    );
+}
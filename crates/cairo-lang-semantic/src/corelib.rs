@@ -8,7 +8,7 @@ use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::Terminal;
 use cairo_lang_utils::{extract_matches, try_extract_matches, OptionFrom};
 use num_bigint::BigInt;
-use num_traits::{Num, Signed};
+use num_traits::{Num, One, Signed, Zero};
 use smol_str::SmolStr;
 
 use crate::db::SemanticGroup;
@@ -17,7 +17,7 @@ use crate::expr::compute::ComputationContext;
 use crate::expr::inference::Inference;
 use crate::items::enm::SemanticEnumEx;
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
-use crate::items::imp::{can_infer_impl_by_self, infer_impl_by_self, ImplId};
+use crate::items::imp::{can_infer_impl_by_self, infer_impl_by_self, GeneratedImplLongId, ImplId};
 use crate::items::trt::{
     ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId, ConcreteTraitId,
 };
@@ -206,6 +206,29 @@ pub fn option_none_variant(
     get_enum_concrete_variant(db, core_submodule(db, "option"), "Option", vec![generic_arg], "None")
 }
 
+/// Returns the `Some` and `None` variants of `ty`, if it is the core `Option` enum.
+pub fn unwrap_option_type(
+    db: &dyn SemanticGroup,
+    ty: TypeId,
+) -> Option<(ConcreteVariant, ConcreteVariant)> {
+    let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) = db.lookup_intern_type(ty)
+    else {
+        return None;
+    };
+    let option_enum_id = db
+        .module_item_by_name(core_submodule(db, "option"), "Option".into())
+        .expect("Failed to load core lib.")
+        .and_then(EnumId::option_from)
+        .expect("Type Option was not found in core lib.");
+    if concrete_enum_id.enum_id(db) != option_enum_id {
+        return None;
+    }
+    match db.concrete_enum_variants(concrete_enum_id).ok()?.as_slice() {
+        [some_variant, none_variant] => Some((some_variant.clone(), none_variant.clone())),
+        _ => None,
+    }
+}
+
 /// Gets a semantic expression of the literal `false`. Uses the given `stable_ptr` in the returned
 /// semantic expression.
 pub fn false_literal_expr(
@@ -323,6 +346,7 @@ pub fn unwrap_error_propagation_type(
         | TypeLongId::Tuple(_)
         | TypeLongId::Snapshot(_)
         | TypeLongId::Var(_)
+        | TypeLongId::Closure(_)
         | TypeLongId::Missing(_) => None,
     }
 }
@@ -432,6 +456,24 @@ pub fn get_index_operator_impl(
     }
 }
 
+/// Returns the function of an infered impl of a core library trait, for the given self_ty.
+/// Returns None if no such impl is found.
+pub fn get_core_trait_function_by_self(
+    ctx: &mut ComputationContext<'_>,
+    trait_name: SmolStr,
+    function_name: SmolStr,
+    self_ty: TypeId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<FunctionId> {
+    let trait_function_id =
+        get_core_trait_function(ctx.db, trait_name, function_name).unwrap().unwrap();
+    if !can_infer_impl_by_self(ctx, trait_function_id, self_ty, stable_ptr) {
+        return None;
+    }
+    infer_impl_by_self(ctx, trait_function_id, self_ty, stable_ptr)
+        .map(|(function_id, _)| function_id)
+}
+
 pub fn felt252_eq(db: &dyn SemanticGroup) -> FunctionId {
     get_core_function_impl_method(db, "Felt252PartialEq".into(), "eq".into())
 }
@@ -564,6 +606,70 @@ pub fn destruct_trait(db: &dyn SemanticGroup) -> TraitId {
     get_core_trait(db, "Destruct".into())
 }
 
+pub fn fn_once_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "FnOnce".into())
+}
+
+pub fn fn_once_call_once_function(db: &dyn SemanticGroup) -> TraitFunctionId {
+    get_core_trait_function(db, "FnOnce".into(), "call_once".into()).unwrap().unwrap()
+}
+
+pub fn fn_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Fn".into())
+}
+
+/// Returns the function called by a call expression of a closure: `FnOnce::call_once` if
+/// `call_once` is true, and `Fn::call` otherwise. The generic arguments are type variables, to be
+/// inferred later.
+pub fn core_closure_call_function(
+    db: &dyn SemanticGroup,
+    inference: &mut Inference<'_>,
+    call_once: bool,
+    stable_ptr: SyntaxStablePtrId,
+) -> ConcreteTraitGenericFunctionId {
+    let (trait_name, function_name) =
+        if call_once { ("FnOnce", "call_once") } else { ("Fn", "call") };
+    get_core_trait_function_infer(
+        db,
+        inference,
+        trait_name.into(),
+        function_name.into(),
+        stable_ptr,
+    )
+}
+
+/// Returns the function of the generated impl of a closure type called by a call expression:
+/// `FnOnce::call_once` if `call_once` is true, and `Fn::call` otherwise.
+pub fn closure_call_function(
+    db: &dyn SemanticGroup,
+    closure_ty: TypeId,
+    call_once: bool,
+) -> FunctionId {
+    let (trait_name, function_name) =
+        if call_once { ("FnOnce", "call_once") } else { ("Fn", "call") };
+    let closure = extract_matches!(db.lookup_intern_type(closure_ty), TypeLongId::Closure);
+    let args_ty = db.intern_type(TypeLongId::Tuple(closure.param_tys));
+    let concrete_trait = get_core_concrete_trait(
+        db,
+        trait_name.into(),
+        vec![
+            GenericArgumentId::Type(closure_ty),
+            GenericArgumentId::Type(args_ty),
+            GenericArgumentId::Type(closure.ret_ty),
+        ],
+    );
+    let impl_id =
+        ImplId::GeneratedImpl(db.intern_generated_impl(GeneratedImplLongId { concrete_trait }));
+    let function =
+        get_core_trait_function(db, trait_name.into(), function_name.into()).unwrap().unwrap();
+    db.intern_function(FunctionLongId {
+        function: ConcreteFunction {
+            generic_function: GenericFunctionId::Impl(ImplGenericFunctionId { impl_id, function }),
+            generic_args: vec![],
+        },
+    })
+}
+
 /// Given a core library trait name and its generic arguments, returns [ConcreteTraitId].
 fn get_core_concrete_trait(
     db: &dyn SemanticGroup,
@@ -625,6 +731,64 @@ pub fn get_panic_ty(db: &dyn SemanticGroup, inner_ty: TypeId) -> TypeId {
     get_core_ty_by_name(db.upcast(), "PanicResult".into(), vec![GenericArgumentId::Type(inner_ty)])
 }
 
+/// The core integer types, with their signedness and bit width.
+const CORE_INTEGER_TYPES: [(&str, bool, usize); 11] = [
+    ("u8", false, 8),
+    ("u16", false, 16),
+    ("u32", false, 32),
+    ("u64", false, 64),
+    ("u128", false, 128),
+    ("u256", false, 256),
+    ("i8", true, 8),
+    ("i16", true, 16),
+    ("i32", true, 32),
+    ("i64", true, 64),
+    ("i128", true, 128),
+];
+
+/// Returns the name of the core integer type `ty`, its signedness and its bit width, or None if
+/// `ty` is not a core integer type.
+fn core_integer_type_info(
+    db: &dyn SemanticGroup,
+    ty: TypeId,
+) -> Option<(&'static str, bool, usize)> {
+    CORE_INTEGER_TYPES
+        .into_iter()
+        .find(|(name, _, _)| ty == get_core_ty_by_name(db, (*name).into(), vec![]))
+}
+
+/// Returns true if `ty` is a core signed integer type, e.g. `i8`.
+pub fn is_core_signed_integer_ty(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    matches!(core_integer_type_info(db, ty), Some((_, true, _)))
+}
+
+/// Returns the minimal and maximal values of the core integer type `ty`, or None if `ty` is not a
+/// core integer type.
+pub fn core_integer_range(db: &dyn SemanticGroup, ty: TypeId) -> Option<(BigInt, BigInt)> {
+    let (_, signed, bits) = core_integer_type_info(db, ty)?;
+    Some(if signed {
+        let bound = BigInt::one() << (bits - 1);
+        (-bound.clone(), bound - 1)
+    } else {
+        (BigInt::zero(), (BigInt::one() << bits) - 1)
+    })
+}
+
+/// Returns the libfunc converting a value of the core integer type `ty` to a felt252, or None if
+/// there is no such libfunc (e.g. for u256).
+pub fn core_integer_to_felt252(db: &dyn SemanticGroup, ty: TypeId) -> Option<FunctionId> {
+    let (name, _, bits) = core_integer_type_info(db, ty)?;
+    if bits > 128 {
+        return None;
+    }
+    Some(get_function_id(
+        db,
+        core_submodule(db, "integer"),
+        format!("{name}_to_felt252").into(),
+        vec![],
+    ))
+}
+
 /// Returns the name of the libfunc that creates a constant of type `ty`;
 pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> String {
     if ty == core_felt252_ty(db) {
@@ -639,6 +803,18 @@ pub fn get_const_libfunc_name_by_type(db: &dyn SemanticGroup, ty: TypeId) -> Str
         "u64_const".into()
     } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
         "u128_const".into()
+    } else if ty == get_core_ty_by_name(db, "i8".into(), vec![]) {
+        "i8_const".into()
+    } else if ty == get_core_ty_by_name(db, "i16".into(), vec![]) {
+        "i16_const".into()
+    } else if ty == get_core_ty_by_name(db, "i32".into(), vec![]) {
+        "i32_const".into()
+    } else if ty == get_core_ty_by_name(db, "i64".into(), vec![]) {
+        "i64_const".into()
+    } else if ty == get_core_ty_by_name(db, "i128".into(), vec![]) {
+        "i128_const".into()
+    } else if ty == get_core_ty_by_name(db, "bytes31".into(), vec![]) {
+        "bytes31_const".into()
     } else {
         panic!("No const libfunc for type {}.", ty.format(db))
     }
@@ -652,25 +828,14 @@ pub fn validate_literal(
     value: BigInt,
 ) -> Result<(), SemanticDiagnosticKind> {
     let is_out_of_range = if ty == core_felt252_ty(db) {
-        value.is_negative()
-            || value
-                > BigInt::from_str_radix(
-                    "800000000000011000000000000000000000000000000000000000000000000",
-                    16,
-                )
-                .unwrap()
-    } else if ty == get_core_ty_by_name(db, "u8".into(), vec![]) {
-        value.is_negative() || value.bits() > 8
-    } else if ty == get_core_ty_by_name(db, "u16".into(), vec![]) {
-        value.is_negative() || value.bits() > 16
-    } else if ty == get_core_ty_by_name(db, "u32".into(), vec![]) {
-        value.is_negative() || value.bits() > 32
-    } else if ty == get_core_ty_by_name(db, "u64".into(), vec![]) {
-        value.is_negative() || value.bits() > 64
-    } else if ty == get_core_ty_by_name(db, "u128".into(), vec![]) {
-        value.is_negative() || value.bits() > 128
-    } else if ty == get_core_ty_by_name(db, "u256".into(), vec![]) {
-        value.is_negative() || value.bits() > 256
+        value.abs()
+            > BigInt::from_str_radix(
+                "800000000000011000000000000000000000000000000000000000000000000",
+                16,
+            )
+            .unwrap()
+    } else if let Some((min, max)) = core_integer_range(db, ty) {
+        value < min || value > max
     } else {
         return Err(SemanticDiagnosticKind::NoLiteralFunctionFound);
     };
@@ -8,8 +8,8 @@ use cairo_lang_defs::ids::{
     LanguageElementId, LookupItemId, ModuleId, ModuleItemId, StructId, TraitFunctionId, TraitId,
     TypeAliasId, UseId, VariantId,
 };
-use cairo_lang_defs::plugin::MacroPlugin;
-use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_defs::plugin::{InlineMacroPlugin, MacroPlugin};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, DiagnosticsBuilder, Maybe, Severity};
 use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup};
 use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
 use cairo_lang_parser::db::ParserGroup;
@@ -22,12 +22,13 @@ use smol_str::SmolStr;
 
 use crate::diagnostic::SemanticDiagnosticKind;
 use crate::expr::inference::{self, ImplVar, ImplVarId};
+use crate::inline_macros::InlineMacroExpanderPlugin;
 use crate::items::constant::Constant;
 use crate::items::function_with_body::FunctionBody;
 use crate::items::functions::{ImplicitPrecedence, InlineConfiguration};
 use crate::items::generics::GenericParam;
 use crate::items::imp::{ImplGenericParamsData, ImplId, ImplLookupContext, UninferredImpl};
-use crate::items::module::ModuleSemanticData;
+use crate::items::module::{ModuleItemInfo, ModuleSemanticData, ModuleUsedItems};
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitId};
 use crate::plugin::{DynPluginAuxData, SemanticPlugin};
 use crate::resolve::scope::Scope;
@@ -90,6 +91,11 @@ pub trait SemanticGroup:
         id: items::imp::ConcreteImplLongId,
     ) -> items::imp::ConcreteImplId;
     #[salsa::interned]
+    fn intern_generated_impl(
+        &self,
+        id: items::imp::GeneratedImplLongId,
+    ) -> items::imp::GeneratedImplId;
+    #[salsa::interned]
     fn intern_type(&self, id: types::TypeLongId) -> semantic::TypeId;
     #[salsa::interned]
     fn intern_literal(&self, id: literals::LiteralLongId) -> literals::LiteralId;
@@ -127,6 +133,9 @@ pub trait SemanticGroup:
     fn use_semantic_diagnostics(&self, use_id: UseId) -> Diagnostics<SemanticDiagnostic>;
     #[salsa::invoke(items::us::use_resolver_data)]
     fn use_resolver_data(&self, use_id: UseId) -> Maybe<Arc<ResolverData>>;
+    /// Returns true if the use is not public and no path in its crate is resolved through it.
+    #[salsa::invoke(items::us::use_is_unused)]
+    fn use_is_unused(&self, use_id: UseId) -> bool;
 
     // Module.
     // ====
@@ -148,10 +157,23 @@ pub trait SemanticGroup:
         name: SmolStr,
     ) -> Maybe<Option<ModuleItemId>>;
 
+    /// Returns [Maybe::Err] if the module was not properly resolved.
+    /// Returns [Maybe::Ok(Option::None)] if the item does not exist.
+    #[salsa::invoke(items::module::module_item_info_by_name)]
+    fn module_item_info_by_name(
+        &self,
+        module_id: ModuleId,
+        name: SmolStr,
+    ) -> Maybe<Option<ModuleItemInfo>>;
+
     /// Returns the attributes of a module.
     #[salsa::invoke(items::module::module_attributes)]
     fn module_attributes(&self, module_id: ModuleId) -> Maybe<Vec<Attribute>>;
 
+    /// Private query to compute the items referenced by the lookup items of a module.
+    #[salsa::invoke(items::module::priv_module_used_items)]
+    fn priv_module_used_items(&self, module_id: ModuleId) -> Arc<ModuleUsedItems>;
+
     // Struct.
     // =======
     /// Private query to compute data about a struct declaration.
@@ -650,6 +672,10 @@ pub trait SemanticGroup:
         &self,
         free_function_id: FreeFunctionId,
     ) -> Maybe<items::function_with_body::FunctionBodyData>;
+    /// Returns true if the free function is private, is not an entry point, and is never
+    /// referenced in its crate outside of its own definition.
+    #[salsa::invoke(items::free_function::free_function_is_unused)]
+    fn free_function_is_unused(&self, free_function_id: FreeFunctionId) -> bool;
 
     // Function with body.
     // ===================
@@ -880,6 +906,9 @@ pub trait SemanticGroup:
     // ========
     #[salsa::input]
     fn semantic_plugins(&self) -> Vec<Arc<dyn SemanticPlugin>>;
+    /// The inline macro plugins, by the name of the macro they implement.
+    #[salsa::input]
+    fn inline_macro_plugins(&self) -> Arc<OrderedHashMap<String, Arc<dyn InlineMacroPlugin>>>;
 
     // Helpers for language server.
     // ============================
@@ -909,14 +938,21 @@ impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
 pub fn init_semantic_group(db: &mut (dyn SemanticGroup + 'static)) {
     // Initialize inputs.
     db.set_semantic_plugins(Vec::new());
+    db.set_inline_macro_plugins(Arc::new(OrderedHashMap::default()));
 }
 
 pub trait SemanticGroupEx<'a>: Upcast<dyn SemanticGroup + 'a> {
     fn get_macro_plugins(&self) -> Vec<Arc<dyn MacroPlugin>> {
-        self.upcast()
-            .semantic_plugins()
-            .into_iter()
-            .map(|plugin| plugin.as_dyn_macro_plugin())
+        // Inline macros are expanded first, so that other plugins act on the expanded code.
+        let inline_macro_expander: Arc<dyn MacroPlugin> =
+            Arc::new(InlineMacroExpanderPlugin::new(self.upcast().inline_macro_plugins()));
+        std::iter::once(inline_macro_expander)
+            .chain(
+                self.upcast()
+                    .semantic_plugins()
+                    .into_iter()
+                    .map(|plugin| plugin.as_dyn_macro_plugin()),
+            )
             .collect()
     }
 }
@@ -944,10 +980,28 @@ fn module_semantic_diagnostics(
             // Add signature diagnostics.
             ModuleItemId::Use(use_id) => {
                 diagnostics.extend(db.use_semantic_diagnostics(*use_id));
+                if db.use_is_unused(*use_id) {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::new(
+                            use_id.module_file_id(db.upcast()),
+                            use_id.stable_ptr(db.upcast()).untyped(),
+                        ),
+                        SemanticDiagnosticKind::UnusedImport(*use_id),
+                    ));
+                }
             }
             ModuleItemId::FreeFunction(free_function) => {
                 diagnostics.extend(db.free_function_declaration_diagnostics(*free_function));
                 diagnostics.extend(db.free_function_body_diagnostics(*free_function));
+                if db.free_function_is_unused(*free_function) {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::new(
+                            free_function.module_file_id(db.upcast()),
+                            db.module_item_name_stable_ptr(module_id, *item)?,
+                        ),
+                        SemanticDiagnosticKind::UnusedFunction(*free_function),
+                    ));
+                }
             }
             ModuleItemId::Struct(struct_id) => {
                 diagnostics.extend(db.struct_declaration_diagnostics(*struct_id));
@@ -1056,6 +1110,11 @@ fn map_diagnostics(
                 has_change = true;
                 continue;
             }
+            // Warnings in code written by a plugin are not actionable for the user.
+            if diag.severity() != Severity::Error {
+                has_change = true;
+                continue;
+            }
         }
         diagnostics.add(diag.clone());
     }
@@ -1100,7 +1159,12 @@ pub fn lookup_resolved_concrete_item_by_ptr(
         .find_map(|resolver_data| resolver_data.resolved_items.concrete.get(&ptr).cloned())
 }
 
-fn get_resolver_datas(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<ResolverData>> {
+/// Returns the resolver datas of a lookup item, including the ones of its body, and for traits, the
+/// ones of their functions.
+pub(crate) fn get_resolver_datas(
+    id: LookupItemId,
+    db: &dyn SemanticGroup,
+) -> Vec<Arc<ResolverData>> {
     match id {
         LookupItemId::ModuleItem(module_item) => match module_item {
             ModuleItemId::Constant(id) => vec![db.constant_resolver_data(id)],
@@ -1119,14 +1183,21 @@ fn get_resolver_datas(id: LookupItemId, db: &dyn SemanticGroup) -> Vec<Arc<Resol
             }
             ModuleItemId::TypeAlias(id) => vec![db.type_alias_resolver_data(id)],
             ModuleItemId::ImplAlias(id) => vec![db.impl_alias_resolver_data(id)],
-            ModuleItemId::Trait(_) => vec![],
+            ModuleItemId::Trait(id) => {
+                let trait_functions = db.trait_functions(id).unwrap_or_default();
+                std::iter::once(db.trait_resolver_data(id))
+                    .chain(trait_functions.values().map(|id| db.trait_function_resolver_data(*id)))
+                    .collect()
+            }
             ModuleItemId::Impl(id) => vec![db.impl_def_resolver_data(id)],
             ModuleItemId::ExternType(_) => vec![],
             ModuleItemId::ExternFunction(id) => {
                 vec![db.extern_function_declaration_resolver_data(id)]
             }
         },
-        LookupItemId::ImplFunction(id) => vec![db.impl_function_resolver_data(id)],
+        LookupItemId::ImplFunction(id) => {
+            vec![db.impl_function_resolver_data(id), db.impl_function_body_resolver_data(id)]
+        }
     }
     .into_iter()
     .flatten()
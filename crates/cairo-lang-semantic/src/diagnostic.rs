@@ -3,12 +3,13 @@ use std::fmt::Display;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    EnumId, FunctionTitleId, ImplDefId, ImplFunctionId, ModuleFileId, StructId,
-    TopLevelLanguageElementId, TraitFunctionId, TraitId,
+    EnumId, FreeFunctionId, FunctionTitleId, ImplDefId, ImplFunctionId, ModuleFileId, StructId,
+    TopLevelLanguageElementId, TraitFunctionId, TraitId, UseId,
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    Severity,
 };
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -368,6 +369,9 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::IfConditionNotBool { condition_ty } => {
                 format!(r#"If condition has type "{}", expected bool."#, condition_ty.format(db))
             }
+            SemanticDiagnosticKind::WhileConditionNotBool { condition_ty } => {
+                format!(r#"While condition has type "{}", expected bool."#, condition_ty.format(db))
+            }
             SemanticDiagnosticKind::IncompatibleMatchArms { match_ty, arm_ty } => format!(
                 r#"Match arms have incompatible types: "{}" and "{}""#,
                 match_ty.format(db),
@@ -414,6 +418,18 @@ impl DiagnosticEntry for SemanticDiagnostic {
             }
             SemanticDiagnosticKind::InvalidMemberExpression => "Invalid member expression.".into(),
             SemanticDiagnosticKind::InvalidPath => "Invalid path.".into(),
+            SemanticDiagnosticKind::ItemNotVisible { item_name } => {
+                format!(r#"Item "{item_name}" is not visible in this context."#)
+            }
+            SemanticDiagnosticKind::MemberNotVisible { struct_id, member_name } => {
+                format!(
+                    r#"Member "{member_name}" of struct "{}" is not visible in this context."#,
+                    struct_id.full_path(db.upcast())
+                )
+            }
+            SemanticDiagnosticKind::UnsupportedPubArgument => {
+                "Unsupported visibility argument. Only `pub(crate)` is supported.".into()
+            }
             SemanticDiagnosticKind::RefArgNotAVariable => "ref argument must be a variable.".into(),
             SemanticDiagnosticKind::RefArgNotMutable => {
                 "ref argument must be a mutable variable.".into()
@@ -452,6 +468,15 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnexpectedTuplePattern { ty } => {
                 format!(r#"Unexpected type for tuple pattern. "{}" is not a tuple."#, ty.format(db),)
             }
+            SemanticDiagnosticKind::UnexpectedRangePattern { ty } => {
+                format!(
+                    r#"Unexpected type for range pattern. "{}" does not implement the "PartialOrd" trait."#,
+                    ty.format(db),
+                )
+            }
+            SemanticDiagnosticKind::EmptyRangePattern => {
+                "Empty range pattern. The start of the range must be lower than its end.".into()
+            }
             SemanticDiagnosticKind::WrongEnum { expected_enum, actual_enum } => {
                 format!(
                     r#"Wrong enum in pattern. Expected: "{}". Got: "{}"."#,
@@ -522,6 +547,7 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     UnsupportedOutsideOfFunctionFeatureName::FunctionCall => "Function call",
                     UnsupportedOutsideOfFunctionFeatureName::ReturnStatement => "Return statement",
                     UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate => "The '?' operator",
+                    UnsupportedOutsideOfFunctionFeatureName::Closure => "Closure",
                 };
                 format!("{feature_name_str} is not supported outside of functions.")
             }
@@ -551,6 +577,18 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     ty.format(db)
                 )
             }
+            SemanticDiagnosticKind::NoImplementationOfIntoIterator(ty) => {
+                format!(r#"Type "{}" does not implement the "IntoIterator" trait."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::NoImplementationOfIterator(ty) => {
+                format!(r#"Type "{}" does not implement the "Iterator" trait."#, ty.format(db))
+            }
+            SemanticDiagnosticKind::IteratorNextNotOption(ty) => {
+                format!(
+                    r#""Iterator::next" must return an "Option", but returns "{}"."#,
+                    ty.format(db)
+                )
+            }
 
             SemanticDiagnosticKind::UnsupportedInlineArguments => {
                 "Unsupported `inline` arguments.".into()
@@ -585,6 +623,18 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::ReturnNotAllowedInsideALoop => {
                 "`return` not allowed inside a `loop`.".into()
             }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideAClosure => {
+                "`return` not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideAClosure => {
+                "The '?' operator is not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::CannotCaptureMutableVariable => {
+                "Capture of mutable variables in a closure is not supported.".into()
+            }
+            SemanticDiagnosticKind::RefClosureParam => {
+                "Closure parameters cannot be `ref`.".into()
+            }
             SemanticDiagnosticKind::ConstGenericParamSupported => {
                 "Const generic args are not allowed in this context.".into()
             }
@@ -597,6 +647,31 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::UnsupportedImplicitPrecedenceArguments => {
                 "Unsupported `implicit_precedence` arguments.".into()
             }
+            SemanticDiagnosticKind::UnusedVariable => {
+                "Unused variable. Consider ignoring by prefixing with `_`.".into()
+            }
+            SemanticDiagnosticKind::UnusedImport(use_id) => {
+                format!("Unused import: `{}`", use_id.full_path(db.upcast()))
+            }
+            SemanticDiagnosticKind::UnusedFunction(free_function_id) => {
+                format!("Unused function: `{}`", free_function_id.full_path(db.upcast()))
+            }
+            SemanticDiagnosticKind::InlineMacroNotFound { macro_name } => {
+                format!("Inline macro `{macro_name}` not found.")
+            }
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match &self.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => diagnostic.severity,
+            SemanticDiagnosticKind::WrappedPluginDiagnostic { original_diag, .. } => {
+                original_diag.severity()
+            }
+            SemanticDiagnosticKind::UnusedVariable
+            | SemanticDiagnosticKind::UnusedImport(_)
+            | SemanticDiagnosticKind::UnusedFunction(_) => Severity::Warning,
+            _ => Severity::Error,
         }
     }
 
@@ -759,6 +834,9 @@ pub enum SemanticDiagnosticKind {
     IfConditionNotBool {
         condition_ty: semantic::TypeId,
     },
+    WhileConditionNotBool {
+        condition_ty: semantic::TypeId,
+    },
     IncompatibleMatchArms {
         match_ty: semantic::TypeId,
         arm_ty: semantic::TypeId,
@@ -803,6 +881,14 @@ pub enum SemanticDiagnosticKind {
     InvalidLhsForAssignment,
     InvalidMemberExpression,
     InvalidPath,
+    ItemNotVisible {
+        item_name: SmolStr,
+    },
+    MemberNotVisible {
+        struct_id: StructId,
+        member_name: SmolStr,
+    },
+    UnsupportedPubArgument,
     PathNotFound(NotFoundItemType),
     SuperUsedInRootModule,
     RedundantModifier {
@@ -819,6 +905,10 @@ pub enum SemanticDiagnosticKind {
     UnexpectedTuplePattern {
         ty: semantic::TypeId,
     },
+    UnexpectedRangePattern {
+        ty: semantic::TypeId,
+    },
+    EmptyRangePattern,
     WrongEnum {
         expected_enum: EnumId,
         actual_enum: EnumId,
@@ -866,6 +956,9 @@ pub enum SemanticDiagnosticKind {
     InternalInferenceError(InferenceError),
     NoImplementationOfIndexOperator(semantic::TypeId),
     MultipleImplementationOfIndexOperator(semantic::TypeId),
+    NoImplementationOfIntoIterator(semantic::TypeId),
+    NoImplementationOfIterator(semantic::TypeId),
+    IteratorNextNotOption(semantic::TypeId),
     LogicalOperatorsNotSupported,
     UnsupportedInlineArguments,
     RedundantInlineAttribute,
@@ -876,9 +969,19 @@ pub enum SemanticDiagnosticKind {
     ContinueOnlyAllowedInsideALoop,
     BreakOnlyAllowedInsideALoop,
     ReturnNotAllowedInsideALoop,
+    ReturnNotAllowedInsideAClosure,
+    ErrorPropagateNotAllowedInsideAClosure,
+    CannotCaptureMutableVariable,
+    RefClosureParam,
     ImplicitPrecedenceAttrForExternFunctionNotAllowed,
     RedundantImplicitPrecedenceAttribute,
     UnsupportedImplicitPrecedenceArguments,
+    UnusedVariable,
+    UnusedImport(UseId),
+    UnusedFunction(FreeFunctionId),
+    InlineMacroNotFound {
+        macro_name: SmolStr,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -895,6 +998,7 @@ pub enum UnsupportedOutsideOfFunctionFeatureName {
     FunctionCall,
     ReturnStatement,
     ErrorPropagate,
+    Closure,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -3,12 +3,15 @@ use std::sync::Arc;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_defs::plugin::{
-    DynGeneratedFileAuxData, GeneratedFileAuxData, MacroPlugin, PluginGeneratedFile, PluginResult,
+    DynGeneratedFileAuxData, GeneratedFileAuxData, InlineMacroPlugin, InlinePluginResult,
+    MacroPlugin, PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_diagnostics::DiagnosticEntry;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::helpers::{QueryAttrs, WrappedArgListHelper};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 use test_log::test;
@@ -20,8 +23,8 @@ use crate::plugin::{
     PluginMappedDiagnostic, SemanticPlugin,
 };
 use crate::test_utils::{
-    get_crate_semantic_diagnostics, setup_test_crate, test_expr_diagnostics,
-    SemanticDatabaseForTesting,
+    get_crate_semantic_diagnostics, setup_test_crate, test_crate_diagnostics,
+    test_expr_diagnostics, SemanticDatabaseForTesting,
 };
 use crate::SemanticDiagnostic;
 
@@ -34,10 +37,20 @@ cairo_lang_test_utils::test_file_test!(
         missing: "missing",
         plus_eq: "plus_eq",
         inline: "inline",
+        unused: "unused",
     },
     test_expr_diagnostics
 );
 
+cairo_lang_test_utils::test_file_test!(
+    crate_diagnostics,
+    "src/diagnostic_test_data",
+    {
+        unused_items: "unused_items",
+    },
+    test_crate_diagnostics
+);
+
 #[test]
 fn test_missing_module_file() {
     let mut db_val = SemanticDatabaseForTesting::default();
@@ -177,7 +190,7 @@ fn test_inline_module_diagnostics() {
         indoc! {"
             mod a {
                 #[test_change_return_type]
-                fn bad() -> u128 {
+                pub fn bad() -> u128 {
                     return 5_felt252;
                 }
             }
@@ -210,22 +223,22 @@ fn test_inline_inline_module_diagnostics() {
         db,
         indoc! {"
             mod a {
-                fn bad_a() -> u128 {
+                pub fn bad_a() -> u128 {
                     return 1_felt252;
                 }
             }
             mod b {
-                mod c {
-                    fn bad_c() -> u128 {
+                pub mod c {
+                    pub fn bad_c() -> u128 {
                         return 2_felt252;
                     }
                 }
                 mod d {
-                    fn foo_d() {
+                    pub fn foo_d() {
                     }
                 }
             }
-            fn foo() {
+            pub fn foo() {
                 b::c::bad_c();
             }
        "},
@@ -246,3 +259,169 @@ fn test_inline_inline_module_diagnostics() {
     "#},
     );
 }
+
+/// An inline macro plugin that doubles its argument, for testing.
+#[derive(Debug)]
+struct DoubleMacroDummyPlugin;
+impl InlineMacroPlugin for DoubleMacroDummyPlugin {
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        syntax: &ast::ExprInlineMacro,
+    ) -> InlinePluginResult {
+        let arg = syntax.arguments(db).arg_list(db).unwrap().as_syntax_node();
+        InlinePluginResult {
+            code: Some(vec!["(".into(), arg.clone().into(), " + ".into(), arg.into(), ")".into()]),
+            diagnostics: vec![],
+        }
+    }
+}
+
+// A dummy plugin that declares the functions with the `entry_point` attribute as entry points.
+#[derive(Debug)]
+struct EntryPointDummyPlugin;
+
+impl MacroPlugin for EntryPointDummyPlugin {
+    fn generate_code(&self, _db: &dyn SyntaxGroup, _item_ast: ast::Item) -> PluginResult {
+        PluginResult::default()
+    }
+
+    fn executable_attributes(&self) -> Vec<String> {
+        vec!["entry_point".into()]
+    }
+}
+impl AsDynMacroPlugin for EntryPointDummyPlugin {
+    fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
+    where
+        Self: 'a,
+    {
+        self
+    }
+}
+impl SemanticPlugin for EntryPointDummyPlugin {}
+
+#[test]
+fn test_unused_entry_point_diagnostics() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    db.set_semantic_plugins(vec![Arc::new(EntryPointDummyPlugin)]);
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            #[entry_point]
+            fn entry() {}
+            #[other_attribute]
+            fn not_entry() {}
+            mod a {
+                #[entry_point]
+                fn inner_entry() {}
+            }
+        "},
+    );
+
+    // Only the functions marked as entry points by the plugin are not reported as unused.
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {"
+            warning: Unused function: `test::not_entry`
+             --> lib.cairo:4:4
+            fn not_entry() {}
+               ^*******^
+
+            "},
+    );
+}
+
+#[test]
+fn test_inline_macro_diagnostics() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    db.set_inline_macro_plugins(Arc::new(OrderedHashMap::from([(
+        "double".into(),
+        Arc::new(DoubleMacroDummyPlugin) as Arc<dyn InlineMacroPlugin>,
+    )])));
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            pub fn foo(x: u128) -> u128 {
+                let _y: felt252 = double!(x);
+                double!(x) + double![undefined]
+            }
+       "},
+    );
+
+    // Diagnostics in the generated code are mapped to the macro call, and diagnostics in the
+    // arguments are mapped to the arguments.
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {r#"
+            error: Plugin diagnostic: Unexpected argument type. Expected: "core::felt252", found: "core::integer::u128".
+             --> lib.cairo:2:23
+                let _y: felt252 = double!(x);
+                                  ^********^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:26
+                double!(x) + double![undefined]
+                                     ^*******^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:26
+                double!(x) + double![undefined]
+                                     ^*******^
+
+            "#},
+    );
+}
+
+#[test]
+fn test_nested_inline_macro_diagnostics() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    db.set_inline_macro_plugins(Arc::new(OrderedHashMap::from([(
+        "double".into(),
+        Arc::new(DoubleMacroDummyPlugin) as Arc<dyn InlineMacroPlugin>,
+    )])));
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            pub fn foo(x: u128) -> u128 {
+                let _y: felt252 = double!(double!(x));
+                double!(double![undefined])
+            }
+       "},
+    );
+
+    // Macros nested in the arguments of a macro are expanded, and their diagnostics are mapped
+    // the same way.
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {r#"
+            error: Plugin diagnostic: Unexpected argument type. Expected: "core::felt252", found: "core::integer::u128".
+             --> lib.cairo:2:23
+                let _y: felt252 = double!(double!(x));
+                                  ^*****************^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:21
+                double!(double![undefined])
+                                ^*******^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:21
+                double!(double![undefined])
+                                ^*******^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:21
+                double!(double![undefined])
+                                ^*******^
+
+            error: Plugin diagnostic: Identifier not found.
+             --> lib.cairo:3:21
+                double!(double![undefined])
+                                ^*******^
+
+            "#},
+    );
+}
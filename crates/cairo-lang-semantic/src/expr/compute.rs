@@ -2,12 +2,15 @@
 //! the code, while type checking.
 //! It is invoked by queries for function bodies and other code blocks.
 
-use std::collections::HashMap;
 use std::ops::Deref;
 
 use ast::PathSegment;
-use cairo_lang_defs::ids::{FunctionTitleId, LanguageElementId, LocalVarLongId, MemberId, TraitId};
-use cairo_lang_diagnostics::{Maybe, ToMaybe, ToOption};
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{
+    FunctionTitleId, FunctionWithBodyId, LanguageElementId, LocalVarLongId, MemberId, ModuleItemId,
+    StructId, TraitId, UseId,
+};
+use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe, ToOption};
 use cairo_lang_syntax::node::ast::{BlockOrIf, ExprPtr, PatternStructParam, UnaryOperator};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, PathSegmentEx};
@@ -18,8 +21,9 @@ use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper};
 use id_arena::Arena;
-use itertools::{chain, zip_eq};
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::BigInt;
+use num_traits::{One, Zero};
 use smol_str::SmolStr;
 
 use super::inference::canonic::ResultNoErrEx;
@@ -29,12 +33,15 @@ use super::inference::solver::SolutionSet;
 use super::inference::{Inference, InferenceError};
 use super::objects::*;
 use super::pattern::{
-    Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
+    Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternRange, PatternTuple,
+    PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_bool_ty, core_unary_operator, false_literal_expr, get_core_trait,
-    get_index_operator_impl, never_ty, true_literal_expr, try_get_core_ty_by_name, unit_ty,
-    unwrap_error_propagation_type, validate_literal,
+    core_binary_operator, core_bool_ty, core_closure_call_function, core_unary_operator,
+    false_literal_expr, fn_once_call_once_function, get_core_trait,
+    get_core_trait_function_by_self, get_index_operator_impl, is_core_signed_integer_ty, never_ty,
+    true_literal_expr, try_get_core_ty_by_name, unit_ty, unwrap_error_propagation_type,
+    unwrap_option_type, validate_literal,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
@@ -42,14 +49,19 @@ use crate::diagnostic::{
     ElementKind, NotFoundItemType, SemanticDiagnostics, UnsupportedOutsideOfFunctionFeatureName,
 };
 use crate::items::enm::SemanticEnumEx;
+use crate::items::functions::ConcreteFunctionWithBodyId;
+use crate::items::imp::{can_infer_impl_by_self, get_impl_at_context};
 use crate::items::modifiers::compute_mutability;
-use crate::items::structure::SemanticStructEx;
+use crate::items::structure::{Member, SemanticStructEx};
 use crate::items::trt::ConcreteTraitGenericFunctionLongId;
 use crate::items::us::SemanticUseEx;
+use crate::items::visibility::peek_visible_in;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
 use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
 use crate::substitution::SemanticRewriter;
-use crate::types::{peel_snapshots, resolve_type, wrap_in_snapshots, ConcreteTypeId};
+use crate::types::{
+    peel_snapshots, resolve_type, wrap_in_snapshots, ClosureTypeLongId, ConcreteTypeId,
+};
 use crate::{
     ConcreteFunction, FunctionLongId, GenericArgumentId, Mutability, Parameter, PatternStruct,
     Signature,
@@ -85,6 +97,14 @@ pub struct ComputationContext<'ctx> {
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
     loop_flow_merge: Option<FlowMergeTypeHelper>,
+    /// The function whose body is computed, if any. Closures are only supported inside functions.
+    function_id: Option<FunctionWithBodyId>,
+    /// The numeric literals negated by a minus sign. Once their type is inferred, the negations of
+    /// signed integer literals are folded into negative literals (see [fold_negated_literal]).
+    negated_literals: UnorderedHashSet<ExprId>,
+    /// The definitions of the variables found unused so far. They are reported once the whole
+    /// body is computed (see [Self::report_unused_variables]), in the order of their definitions.
+    unused_variables: Vec<SyntaxStablePtrId>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
@@ -93,6 +113,7 @@ impl<'ctx> ComputationContext<'ctx> {
         resolver: Resolver<'ctx>,
         signature: Option<&'ctx Signature>,
         environment: Environment,
+        function_id: Option<FunctionWithBodyId>,
     ) -> Self {
         let semantic_defs =
             environment.variables.values().by_ref().map(|var| (var.id(), var.clone())).collect();
@@ -106,6 +127,9 @@ impl<'ctx> ComputationContext<'ctx> {
             statements: Arena::default(),
             semantic_defs,
             loop_flow_merge: None,
+            function_id,
+            negated_literals: UnorderedHashSet::default(),
+            unused_variables: vec![],
         }
     }
 
@@ -114,20 +138,102 @@ impl<'ctx> ComputationContext<'ctx> {
     /// parent.
     /// Used for block expressions.
     fn run_in_subscope<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.run_in_environment(Environment::default(), f).0
+    }
+
+    /// Runs a function with a modified context, with a new environment for the scope of a
+    /// closure. Returns the variables of the enclosing scopes that were captured by the closure.
+    fn run_in_closure_subscope<T, F>(&mut self, f: F) -> (T, Vec<ExprVar>)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let closure_environment =
+            Environment { captured_variables: Some(Default::default()), ..Default::default() };
+        let (res, closed_environment) = self.run_in_environment(closure_environment, f);
+        let captured_variables = closed_environment.captured_variables.unwrap();
+        (res, captured_variables.into_iter().map(|(_, var)| var).collect())
+    }
+
+    /// Runs a function with a modified context, with the given environment pushed on top of the
+    /// current one. Returns the result, and the environment after it was popped.
+    fn run_in_environment<T, F>(&mut self, new_environment: Environment, f: F) -> (T, Environment)
     where
         F: FnOnce(&mut Self) -> T,
     {
         // Push an environment to the stack.
-        let new_environment = Box::<Environment>::default();
-        let old_environment = std::mem::replace(&mut self.environment, new_environment);
+        let old_environment = std::mem::replace(&mut self.environment, Box::new(new_environment));
         self.environment.parent = Some(old_environment);
 
         let res = f(self);
 
         // Pop the environment from the stack.
         let parent = self.environment.parent.take();
-        self.environment = parent.unwrap();
-        res
+        let closed_environment = std::mem::replace(&mut self.environment, parent.unwrap());
+        for (name, var) in closed_environment.variables.iter() {
+            self.report_if_unused(&closed_environment, name, var);
+        }
+        (res, *closed_environment)
+    }
+
+    /// Returns true if the current scope is inside the body of a closure.
+    fn is_inside_closure(&self) -> bool {
+        let mut maybe_env = Some(&*self.environment);
+        while let Some(env) = maybe_env {
+            if env.captured_variables.is_some() {
+                return true;
+            }
+            maybe_env = env.parent.as_deref();
+        }
+        false
+    }
+
+    /// Adds a local variable to the current environment, and registers its definition.
+    /// If it shadows an unused variable of the same environment, a warning is reported for the
+    /// shadowed variable.
+    fn add_local_variable(&mut self, name: SmolStr, var_def: Variable) {
+        if let Some(shadowed) = self.environment.variables.insert(name.clone(), var_def.clone()) {
+            let environment = std::mem::take(&mut self.environment);
+            self.report_if_unused(&environment, &name, &shadowed);
+            self.environment = environment;
+        }
+        self.semantic_defs.insert(var_def.id(), var_def);
+    }
+
+    /// Records the given variable of the given environment as unused if it was never used, to be
+    /// reported by [Self::report_unused_variables]. Variables whose name starts with `_`, and `self`
+    /// parameters, are ignored.
+    fn report_if_unused(&mut self, environment: &Environment, name: &SmolStr, var: &Variable) {
+        if name.starts_with('_') || environment.used_variables.contains(&var.id()) {
+            return;
+        }
+        let stable_ptr = match var {
+            Variable::Local(local) => local.stable_ptr(self.db.upcast()),
+            Variable::Param(param) => {
+                if name == "self" {
+                    return;
+                }
+                param.stable_ptr
+            }
+        };
+        self.unused_variables.push(stable_ptr.untyped());
+    }
+
+    /// Reports an [UnusedVariable] warning for each of the variables found unused, sorted by the
+    /// position of their definitions.
+    fn report_unused_variables(&mut self) {
+        let location = |stable_ptr| {
+            StableLocation::new(self.diagnostics.module_file_id, stable_ptr)
+                .diagnostic_location(self.db.upcast())
+                .span
+                .start
+        };
+        let unused_variables = std::mem::take(&mut self.unused_variables);
+        for stable_ptr in unused_variables.into_iter().sorted_by_cached_key(|ptr| location(*ptr)) {
+            self.diagnostics.report_by_ptr(stable_ptr, UnusedVariable);
+        }
     }
 
     /// Returns [Self::signature] if it exists. Otherwise, reports a diagnostic and returns `Err`.
@@ -152,7 +258,7 @@ impl<'ctx> ComputationContext<'ctx> {
 }
 
 // TODO(ilya): Change value to VarId.
-pub type EnvVariables = HashMap<SmolStr, Variable>;
+pub type EnvVariables = OrderedHashMap<SmolStr, Variable>;
 
 // TODO(spapini): Consider using identifiers instead of SmolStr everywhere in the code.
 /// A state which contains all the variables defined at the current resolver until now, and a
@@ -161,6 +267,11 @@ pub type EnvVariables = HashMap<SmolStr, Variable>;
 pub struct Environment {
     parent: Option<Box<Environment>>,
     variables: EnvVariables,
+    /// The variables of this environment that were used.
+    used_variables: UnorderedHashSet<semantic::VarId>,
+    /// For the environment of a closure, the variables of enclosing environments used by the
+    /// closure, by their id. `None` for other environments.
+    captured_variables: Option<OrderedHashMap<semantic::VarId, ExprVar>>,
 }
 impl Environment {
     /// Adds a parameter to the environment.
@@ -172,16 +283,14 @@ impl Environment {
         function_title_id: FunctionTitleId,
     ) -> Maybe<()> {
         let name = &semantic_param.name;
-        match self.variables.entry(name.clone()) {
-            std::collections::hash_map::Entry::Occupied(_) => Err(diagnostics.report(
+        if self.variables.contains_key(name) {
+            return Err(diagnostics.report(
                 ast_param,
                 ParamNameRedefinition { function_title_id, param_name: name.clone() },
-            )),
-            std::collections::hash_map::Entry::Vacant(entry) => {
-                entry.insert(Variable::Param(semantic_param));
-                Ok(())
-            }
+            ));
         }
+        self.variables.insert(name.clone(), Variable::Param(semantic_param));
+        Ok(())
     }
 }
 
@@ -226,6 +335,9 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::ShortString(literal_syntax) => {
             Ok(Expr::Literal(short_string_to_semantic(ctx, literal_syntax)?))
         }
+        ast::Expr::String(literal_syntax) => {
+            Ok(Expr::StringLiteral(string_literal_to_semantic(ctx, literal_syntax)?))
+        }
         ast::Expr::False(syntax) => Ok(false_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::True(syntax) => Ok(true_literal_expr(ctx, syntax.stable_ptr().into())),
         ast::Expr::Parenthesized(paren_syntax) => {
@@ -242,14 +354,33 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Match(expr_match) => compute_expr_match_semantic(ctx, expr_match),
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
         ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
+        ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
+        ast::Expr::For(expr_for) => compute_expr_for_semantic(ctx, expr_for),
+        ast::Expr::Closure(expr_closure) => compute_expr_closure_semantic(ctx, expr_closure),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
-        ast::Expr::Missing(_) | ast::Expr::FieldInitShorthand(_) | ast::Expr::InlineMacro(_) => {
+        ast::Expr::InlineMacro(expr) => compute_expr_inline_macro_semantic(ctx, expr),
+        ast::Expr::Missing(_) | ast::Expr::FieldInitShorthand(_) => {
             Err(ctx.diagnostics.report(syntax, Unsupported))
         }
         ast::Expr::Indexed(expr) => compute_expr_indexed_semantic(ctx, expr),
     }
 }
 
+/// Computes the semantic model of an inline macro call. Calls to registered inline macros are
+/// expanded by the plugins, so reaching here means the macro is unknown or failed to expand.
+fn compute_expr_inline_macro_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprInlineMacro,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+    let macro_name = syntax.path(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db);
+    if ctx.db.inline_macro_plugins().contains_key(&macro_name) {
+        // The failure was already reported by the expansion.
+        return Err(skip_diagnostic());
+    }
+    Err(ctx.diagnostics.report(syntax, InlineMacroNotFound { macro_name: macro_name.into() }))
+}
+
 fn compute_expr_unary_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprUnary,
@@ -257,7 +388,11 @@ fn compute_expr_unary_semantic(
     let syntax_db = ctx.db.upcast();
 
     let unary_op = syntax.op(syntax_db);
-    let expr = compute_expr_semantic(ctx, &syntax.expr(syntax_db));
+    let inner = syntax.expr(syntax_db);
+    let expr = compute_expr_semantic(ctx, &inner);
+    if let (UnaryOperator::Minus(_), ast::Expr::Literal(_)) = (&unary_op, &inner) {
+        ctx.negated_literals.insert(expr.id);
+    }
 
     let expr_ty = ctx.reduce_ty(expr.ty());
     if let UnaryOperator::At(_) = unary_op {
@@ -493,6 +628,15 @@ fn compute_expr_function_call_semantic(
     let syntax_db = db.upcast();
 
     let path = syntax.path(syntax_db);
+
+    // Check if this is a call of a closure held by a variable.
+    if let [PathSegment::Simple(ident_segment)] = &path.elements(syntax_db)[..] {
+        let variable_name = ident_segment.ident(syntax_db).text(syntax_db);
+        if let Some(var) = get_variable_by_name(ctx, &variable_name, path.stable_ptr().into()) {
+            return compute_expr_closure_call_semantic(ctx, syntax, var);
+        }
+    }
+
     let item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, &path, NotFoundItemType::Function)?;
     let args_syntax = syntax.arguments(syntax_db);
@@ -606,6 +750,27 @@ pub fn compute_root_expr(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprBlock,
     return_type: TypeId,
+) -> Maybe<ExprId> {
+    let res = compute_root_expr_inner(ctx, syntax, return_type);
+    // Report unused parameters. Uses are recorded when variables are resolved, so they are
+    // reported even if the inference of the body failed. The parameters of trait and impl
+    // functions are not reported, as their names must match the trait.
+    if res.is_ok() && matches!(ctx.function_id, Some(FunctionWithBodyId::Free(_))) {
+        let environment = std::mem::take(&mut ctx.environment);
+        for (name, var) in environment.variables.iter() {
+            ctx.report_if_unused(&environment, name, var);
+        }
+        ctx.environment = environment;
+    }
+    ctx.report_unused_variables();
+    res
+}
+
+/// Computes the semantic model of a root expression, see [compute_root_expr].
+fn compute_root_expr_inner(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprBlock,
+    return_type: TypeId,
 ) -> Maybe<ExprId> {
     let res = compute_expr_block_semantic(ctx, syntax)?;
     let res_ty = res.ty();
@@ -627,13 +792,60 @@ pub fn compute_root_expr(
     Ok(res)
 }
 
-fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
+/// Applies the inference to all the expressions and statements, and validates the literals.
+pub fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
     for (_id, expr) in ctx.exprs.iter_mut() {
         *expr = ctx.resolver.inference().rewrite(expr.clone()).no_err();
-        if let Expr::Literal(expr) = expr {
-            validate_literal(ctx.db, expr.ty, expr.value.clone())
-                .map_err(|err| ctx.diagnostics.report_by_ptr(expr.stable_ptr.untyped(), err))
-                .ok();
+    }
+    // The negated literals are validated as part of the negative literals they are folded into.
+    let mut folded_literals = UnorderedHashSet::<ExprId>::default();
+    let expr_ids = ctx.exprs.iter().map(|(id, _)| id).collect_vec();
+    for id in expr_ids {
+        if let Some((literal_id, literal)) = fold_negated_literal(ctx, &ctx.exprs[id]) {
+            ctx.exprs[id] = Expr::Literal(literal);
+            folded_literals.insert(literal_id);
+        }
+    }
+    // The negations which are not folded are function calls, which may panic.
+    let negation_calls = ctx
+        .exprs
+        .iter()
+        .filter_map(|(_, expr)| match expr {
+            Expr::FunctionCall(call) => match call.args[..] {
+                [ExprFunctionCallArg::Value(arg)] if ctx.negated_literals.contains(&arg) => {
+                    Some((call.function, call.stable_ptr))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect_vec();
+    for (function, stable_ptr) in negation_calls {
+        let Ok(signature) = ctx.db.concrete_function_signature(function) else { continue };
+        if signature.panicable
+            && ctx
+                .get_signature(
+                    stable_ptr.untyped(),
+                    UnsupportedOutsideOfFunctionFeatureName::FunctionCall,
+                )
+                .is_ok_and(|signature| !signature.panicable)
+        {
+            ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), PanicableFromNonPanicable);
+        }
+    }
+    for (id, expr) in ctx.exprs.iter() {
+        match expr {
+            Expr::Literal(expr) if !folded_literals.contains(&id) => {
+                validate_literal(ctx.db, expr.ty, expr.value.clone())
+                    .map_err(|err| ctx.diagnostics.report_by_ptr(expr.stable_ptr.untyped(), err))
+                    .ok();
+            }
+            Expr::Match(expr) => {
+                for arm in &expr.arms {
+                    validate_pattern_literals(ctx.db, ctx.diagnostics, &arm.pattern);
+                }
+            }
+            _ => {}
         }
     }
     for (_id, stmt) in ctx.statements.iter_mut() {
@@ -642,6 +854,60 @@ fn infer_all(ctx: &mut ComputationContext<'_>) -> Maybe<()> {
     Ok(())
 }
 
+/// If `expr` is a negation of a numeric literal of a core signed integer type, returns the id of
+/// the literal and the negative literal the negation is folded into, so that the minimal values of
+/// signed integers would be representable (e.g. `let x: i8 = -128;`).
+fn fold_negated_literal(
+    ctx: &ComputationContext<'_>,
+    expr: &Expr,
+) -> Option<(ExprId, ExprLiteral)> {
+    let Expr::FunctionCall(call) = expr else { return None };
+    let [ExprFunctionCallArg::Value(literal_id)] = call.args[..] else { return None };
+    if !ctx.negated_literals.contains(&literal_id) || !is_core_signed_integer_ty(ctx.db, call.ty) {
+        return None;
+    }
+    let Expr::Literal(literal) = &ctx.exprs[literal_id] else { return None };
+    let value = -literal.value.clone();
+    Some((literal_id, ExprLiteral { value, stable_ptr: call.stable_ptr, ..literal.clone() }))
+}
+
+/// Validates that the literals in a pattern fit their types.
+fn validate_pattern_literals(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    pattern: &Pattern,
+) {
+    // The end of a range is exclusive, so it may exceed the maximal value of the type by one.
+    let literals = match pattern {
+        Pattern::Literal(pattern) => vec![(&pattern.literal, BigInt::zero())],
+        Pattern::Range(pattern) => {
+            vec![(&pattern.start, BigInt::zero()), (&pattern.end, BigInt::one())]
+        }
+        Pattern::Struct(pattern) => {
+            for (_, field_pattern) in &pattern.field_patterns {
+                validate_pattern_literals(db, diagnostics, field_pattern);
+            }
+            vec![]
+        }
+        Pattern::Tuple(pattern) => {
+            for field_pattern in &pattern.field_patterns {
+                validate_pattern_literals(db, diagnostics, field_pattern);
+            }
+            vec![]
+        }
+        Pattern::EnumVariant(pattern) => {
+            validate_pattern_literals(db, diagnostics, &pattern.inner_pattern);
+            vec![]
+        }
+        Pattern::Variable(_) | Pattern::Otherwise(_) => vec![],
+    };
+    for (literal, offset) in literals {
+        validate_literal(db, literal.ty, &literal.value - offset)
+            .map_err(|err| diagnostics.report_by_ptr(literal.stable_ptr.untyped(), err))
+            .ok();
+    }
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprBlock].
 pub fn compute_expr_block_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -748,11 +1014,7 @@ fn compute_expr_match_semantic(
                     compute_pattern_semantic(new_ctx, syntax_arm.pattern(syntax_db), expr.ty())?;
                 let variables = pattern.variables();
                 for v in variables {
-                    let var_def = Variable::Local(v.var.clone());
-                    // TODO(spapini): Wrap this in a function to couple with semantic_defs
-                    // insertion.
-                    new_ctx.environment.variables.insert(v.name.clone(), var_def.clone());
-                    new_ctx.semantic_defs.insert(var_def.id(), var_def);
+                    new_ctx.add_local_variable(v.name.clone(), Variable::Local(v.var.clone()));
                 }
                 let arm_expr = compute_expr_semantic(new_ctx, &arm_expr_syntax);
                 Ok((pattern, arm_expr))
@@ -839,42 +1101,404 @@ fn compute_expr_loop_semantic(
     let syntax_db = db.upcast();
 
     let (body, new_flow_merge) = ctx.run_in_subscope(|new_ctx| {
-        let old_flow_merge = new_ctx.loop_flow_merge.replace(FlowMergeTypeHelper::new(db));
+        compute_loop_body_semantic(
+            new_ctx,
+            syntax.body(syntax_db),
+            FlowMergeTypeHelper::new(db),
+            syntax.stable_ptr().into(),
+        )
+    });
 
-        let mut statements = syntax.body(syntax_db).statements(syntax_db).elements(syntax_db);
-        // Remove the tail expression, if exists.
-        let tail = get_tail_expression(syntax_db, statements.as_slice());
-        if let Some(tail) = tail {
-            new_ctx.diagnostics.report(&tail, TailExpressionNotAllowedInLoop);
-            statements.pop();
+    Ok(Expr::Loop(ExprLoop {
+        body,
+        ty: new_flow_merge.get_final_type(),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprWhile].
+fn compute_expr_while_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprWhile,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let condition = compute_expr_semantic(ctx, &syntax.condition(syntax_db));
+    if ctx.resolver.inference().conform_ty(condition.ty(), core_bool_ty(db)).is_err() {
+        ctx.diagnostics.report_by_ptr(
+            condition.stable_ptr().untyped(),
+            WhileConditionNotBool { condition_ty: condition.ty() },
+        );
+    }
+
+    let (body, _) = ctx.run_in_subscope(|new_ctx| {
+        let flow_merge = unit_flow_merge(new_ctx);
+        compute_loop_body_semantic(
+            new_ctx,
+            syntax.body(syntax_db),
+            flow_merge,
+            syntax.stable_ptr().into(),
+        )
+    });
+
+    Ok(Expr::While(ExprWhile {
+        condition: condition.id,
+        body,
+        ty: unit_ty(db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprFor].
+/// The loop is desugared by calling `IntoIterator::into_iter` on the iterated expression, and
+/// `Iterator::next` on the resulting iterator at the start of each iteration.
+fn compute_expr_for_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprFor,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let expr_syntax = syntax.expr(syntax_db);
+    let expr_ptr = expr_syntax.stable_ptr();
+
+    let expr = compute_expr_semantic(ctx, &expr_syntax);
+    let expr_ty = ctx.reduce_ty(expr.ty());
+    expr_ty.check_not_missing(db)?;
+    let Some(into_iter_function) = get_core_trait_function_by_self(
+        ctx,
+        "IntoIterator".into(),
+        "into_iter".into(),
+        expr_ty,
+        expr_ptr.untyped(),
+    ) else {
+        return Err(ctx.diagnostics.report(&expr_syntax, NoImplementationOfIntoIterator(expr_ty)));
+    };
+    let into_iter = expr_function_call(
+        ctx,
+        into_iter_function,
+        vec![NamedArg(expr, None, Mutability::Immutable)],
+        expr_ptr,
+    )?;
+    ctx.resolver.inference().solve().ok();
+    let iterator_ty = ctx.reduce_ty(into_iter.ty());
+    let into_iter = ctx.exprs.alloc(into_iter);
+
+    // The iterator is held by a variable that has no name in the code, identified by the `in`
+    // identifier of the loop.
+    let iterator_var = LocalVariable {
+        id: db.intern_local_var(LocalVarLongId(
+            ctx.resolver.module_file_id,
+            syntax.identifier(syntax_db).stable_ptr(),
+        )),
+        ty: iterator_ty,
+        is_mut: true,
+    };
+    ctx.semantic_defs
+        .insert(semantic::VarId::Local(iterator_var.id), Variable::Local(iterator_var.clone()));
+    let iterator_expr_var = ExprVar {
+        var: semantic::VarId::Local(iterator_var.id),
+        ty: iterator_ty,
+        stable_ptr: expr_ptr,
+    };
+    let into_iter_member_path = ExprVarMemberPath::Var(iterator_expr_var.clone());
+    let iterator_expr = Expr::Var(iterator_expr_var);
+    let iterator_expr =
+        ExprAndId { expr: iterator_expr.clone(), id: ctx.exprs.alloc(iterator_expr) };
+
+    let Some(next_function) = get_core_trait_function_by_self(
+        ctx,
+        "Iterator".into(),
+        "next".into(),
+        iterator_ty,
+        expr_ptr.untyped(),
+    ) else {
+        return Err(ctx.diagnostics.report(&expr_syntax, NoImplementationOfIterator(iterator_ty)));
+    };
+    let next_call = expr_function_call(
+        ctx,
+        next_function,
+        vec![NamedArg(iterator_expr, None, Mutability::Reference)],
+        expr_ptr,
+    )?;
+    ctx.resolver.inference().solve().ok();
+    let next_ty = ctx.reduce_ty(next_call.ty());
+    let next_call = ctx.exprs.alloc(next_call);
+    // `Iterator::next` returns an `Option`.
+    let Some((some_variant, none_variant)) = unwrap_option_type(db, next_ty) else {
+        return Err(ctx.diagnostics.report(&expr_syntax, IteratorNextNotOption(next_ty)));
+    };
+
+    let (pattern, body) = ctx.run_in_subscope(|new_ctx| {
+        let item_ty = new_ctx.reduce_ty(some_variant.ty);
+        let pattern = compute_pattern_semantic(new_ctx, syntax.pattern(syntax_db), item_ty)?;
+        for v in pattern.variables() {
+            new_ctx.add_local_variable(v.name.clone(), Variable::Local(v.var.clone()));
         }
+        let flow_merge = unit_flow_merge(new_ctx);
+        let (body, _) = compute_loop_body_semantic(
+            new_ctx,
+            syntax.body(syntax_db),
+            flow_merge,
+            syntax.stable_ptr().into(),
+        );
+        Ok((pattern, body))
+    })?;
 
-        // Convert statements to semantic model.
-        let statements_semantic: Vec<_> = statements
+    Ok(Expr::For(ExprFor {
+        into_iter,
+        into_iter_member_path,
+        next_call,
+        some_variant,
+        none_variant,
+        pattern,
+        body,
+        ty: unit_ty(db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprClosure].
+fn compute_expr_closure_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprClosure,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let Some(function_id) = ctx.function_id else {
+        return Err(ctx.diagnostics.report(
+            syntax,
+            UnsupportedOutsideOfFunction {
+                feature_name: UnsupportedOutsideOfFunctionFeatureName::Closure,
+            },
+        ));
+    };
+    let parent_function = ConcreteFunctionWithBodyId::from_generic(db, function_id)?;
+    let wrapper_syntax = syntax.wrapper(syntax_db);
+    let params_syntax = match &wrapper_syntax {
+        ast::ClosureParamWrapper::Nullary(_) => vec![],
+        ast::ClosureParamWrapper::NAry(wrapper) => wrapper.params(syntax_db).elements(syntax_db),
+    };
+    let explicit_ret_ty = match syntax.ret_ty(syntax_db) {
+        ast::OptionReturnTypeClause::Empty(_) => None,
+        ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty_clause) => {
+            Some(resolve_type(db, ctx.diagnostics, &mut ctx.resolver, &ret_ty_clause.ty(syntax_db)))
+        }
+    };
+
+    // `break` and `continue` of an enclosing loop are not allowed inside the closure.
+    let old_flow_merge = ctx.loop_flow_merge.take();
+    let ((params, body), captured_vars) = ctx.run_in_closure_subscope(|new_ctx| {
+        let params = params_syntax
             .into_iter()
-            .filter_map(|statement_syntax| {
-                compute_statement_semantic(new_ctx, statement_syntax).to_option()
+            .map(|param_syntax| {
+                let param = compute_closure_param_semantic(new_ctx, param_syntax);
+                new_ctx.add_local_variable(param.name.clone(), Variable::Local(param.var.clone()));
+                param
             })
-            .collect();
+            .collect_vec();
+        let body = compute_expr_semantic(new_ctx, &syntax.expr(syntax_db));
+        (params, body)
+    });
+    ctx.loop_flow_merge = old_flow_merge;
 
-        let new_flow_merge =
-            std::mem::replace(&mut new_ctx.loop_flow_merge, old_flow_merge).unwrap();
+    let ret_ty = match explicit_ret_ty {
+        Some(expected_ty) => {
+            let actual_ty = ctx.reduce_ty(body.ty());
+            if !expected_ty.is_missing(db)
+                && !actual_ty.is_missing(db)
+                && ctx.resolver.inference().conform_ty(actual_ty, expected_ty).is_err()
+            {
+                ctx.diagnostics.report_by_ptr(
+                    body.stable_ptr().untyped(),
+                    WrongReturnType { expected_ty, actual_ty },
+                );
+            }
+            expected_ty
+        }
+        None => body.ty(),
+    };
+    let ty = db.intern_type(TypeLongId::Closure(ClosureTypeLongId {
+        param_tys: params.iter().map(|param| param.var.ty).collect(),
+        ret_ty,
+        captured_types: captured_vars.iter().map(|var| var.ty).collect(),
+        parent_function,
+        body: body.id,
+        wrapper_location: StableLocation::from_ast(ctx.resolver.module_file_id, &wrapper_syntax),
+    }));
+    Ok(Expr::Closure(ExprClosure {
+        params,
+        body: body.id,
+        captured_vars,
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
 
-        let body = new_ctx.exprs.alloc(Expr::Block(ExprBlock {
-            statements: statements_semantic,
-            tail: None,
-            ty: unit_ty(db),
-            stable_ptr: syntax.stable_ptr().into(),
-        }));
+/// Computes the semantic model of a closure parameter. Parameters without a type clause get a new
+/// type variable, to be inferred from the uses of the closure.
+fn compute_closure_param_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: ast::ClosureParam,
+) -> ExprClosureParam {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let identifier = syntax.name(syntax_db);
+    let ty = match syntax.type_clause(syntax_db) {
+        ast::OptionTypeClause::Empty(_) => {
+            ctx.resolver.inference().new_type_var(Some(identifier.stable_ptr().untyped()))
+        }
+        ast::OptionTypeClause::TypeClause(type_clause) => {
+            resolve_type(db, ctx.diagnostics, &mut ctx.resolver, &type_clause.ty(syntax_db))
+        }
+    };
+    let is_mut = match compute_mutability(
+        ctx.diagnostics,
+        syntax_db,
+        &syntax.modifiers(syntax_db).elements(syntax_db),
+    ) {
+        Mutability::Immutable => false,
+        Mutability::Mutable => true,
+        Mutability::Reference => {
+            ctx.diagnostics.report(&identifier, RefClosureParam);
+            false
+        }
+    };
+    let id =
+        db.intern_local_var(LocalVarLongId(ctx.resolver.module_file_id, identifier.stable_ptr()));
+    ExprClosureParam { name: identifier.text(syntax_db), var: LocalVariable { id, ty, is_mut } }
+}
 
-        (body, new_flow_merge)
+/// Computes the semantic model of a call of a closure held by a variable, `f(a, b)`.
+/// The call is desugared to `FnOnce::call_once(f, (a, b))` if the type of `f` is a closure type or
+/// has an `FnOnce` impl in the context, and to `Fn::call(@f, (a, b))` otherwise.
+fn compute_expr_closure_call_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprFunctionCall,
+    closure_expr: Expr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr: ast::ExprPtr = syntax.stable_ptr().into();
+    let closure_ty = ctx.reduce_ty(closure_expr.ty());
+    closure_ty.check_not_missing(db)?;
+
+    let mut items = vec![];
+    let mut item_tys = vec![];
+    for arg_syntax in syntax.arguments(syntax_db).args(syntax_db).elements(syntax_db) {
+        let NamedArg(arg, name_terminal, mutability) =
+            compute_named_argument_clause(ctx, arg_syntax);
+        if let Some(name_terminal) = name_terminal {
+            ctx.diagnostics.report(&name_terminal, NamedArgumentsAreNotSupported);
+        }
+        if mutability != Mutability::Immutable {
+            ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), ImmutableArgWithModifiers);
+        }
+        items.push(arg.id);
+        item_tys.push(arg.ty());
+    }
+    let args_expr = Expr::Tuple(ExprTuple {
+        items,
+        ty: db.intern_type(TypeLongId::Tuple(item_tys)),
+        stable_ptr,
     });
+    let args_expr = ExprAndId { expr: args_expr.clone(), id: ctx.exprs.alloc(args_expr) };
 
-    Ok(Expr::Loop(ExprLoop {
-        body,
-        ty: new_flow_merge.get_final_type(),
-        stable_ptr: syntax.stable_ptr().into(),
-    }))
+    let call_once = match db.lookup_intern_type(closure_ty) {
+        TypeLongId::Closure(_) | TypeLongId::Var(_) => true,
+        _ => can_infer_impl_by_self(
+            ctx,
+            fn_once_call_once_function(db),
+            closure_ty,
+            stable_ptr.untyped(),
+        ),
+    };
+    let mut closure_expr =
+        ExprAndId { expr: closure_expr.clone(), id: ctx.exprs.alloc(closure_expr) };
+    if !call_once {
+        let expr = Expr::Snapshot(ExprSnapshot {
+            inner: closure_expr.id,
+            ty: db.intern_type(TypeLongId::Snapshot(closure_ty)),
+            stable_ptr: closure_expr.stable_ptr(),
+        });
+        closure_expr = ExprAndId { expr: expr.clone(), id: ctx.exprs.alloc(expr) };
+    }
+    let concrete_trait_function = core_closure_call_function(
+        db,
+        &mut ctx.resolver.inference(),
+        call_once,
+        stable_ptr.untyped(),
+    );
+    let impl_lookup_context = ctx.resolver.impl_lookup_context();
+    let function = ctx
+        .resolver
+        .inference()
+        .infer_trait_function(
+            concrete_trait_function,
+            &impl_lookup_context,
+            Some(stable_ptr.untyped()),
+        )
+        .map_err(|err| err.report(ctx.diagnostics, stable_ptr.untyped()))?;
+    expr_function_call(
+        ctx,
+        function,
+        vec![
+            NamedArg(closure_expr, None, Mutability::Immutable),
+            NamedArg(args_expr, None, Mutability::Immutable),
+        ],
+        stable_ptr,
+    )
+}
+
+/// Returns a [FlowMergeTypeHelper] for the `break` statements of a loop that evaluates to unit,
+/// i.e. `while` and `for` loops.
+fn unit_flow_merge(ctx: &mut ComputationContext<'_>) -> FlowMergeTypeHelper {
+    let mut flow_merge = FlowMergeTypeHelper::new(ctx.db);
+    flow_merge.try_merge_types(&mut ctx.resolver.inference(), ctx.db, unit_ty(ctx.db)).unwrap();
+    flow_merge
+}
+
+/// Computes the semantic model of the body of a loop, given the [FlowMergeTypeHelper] for its
+/// `break` statements. Should be called in the subscope of the loop.
+/// Returns the body block and the flow merge helper, updated with the types of the `break`
+/// statements of the body.
+fn compute_loop_body_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: ast::ExprBlock,
+    flow_merge: FlowMergeTypeHelper,
+    stable_ptr: ast::ExprPtr,
+) -> (ExprId, FlowMergeTypeHelper) {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+
+    let old_flow_merge = ctx.loop_flow_merge.replace(flow_merge);
+
+    let mut statements = syntax.statements(syntax_db).elements(syntax_db);
+    // Remove the tail expression, if exists.
+    let tail = get_tail_expression(syntax_db, statements.as_slice());
+    if let Some(tail) = tail {
+        ctx.diagnostics.report(&tail, TailExpressionNotAllowedInLoop);
+        statements.pop();
+    }
+
+    // Convert statements to semantic model.
+    let statements_semantic: Vec<_> = statements
+        .into_iter()
+        .filter_map(|statement_syntax| {
+            compute_statement_semantic(ctx, statement_syntax).to_option()
+        })
+        .collect();
+
+    let new_flow_merge = std::mem::replace(&mut ctx.loop_flow_merge, old_flow_merge).unwrap();
+
+    let body = ctx.exprs.alloc(Expr::Block(ExprBlock {
+        statements: statements_semantic,
+        tail: None,
+        ty: unit_ty(db),
+        stable_ptr,
+    }));
+
+    (body, new_flow_merge)
 }
 
 /// Computes the semantic model of an expression of type [ast::ExprErrorPropagate].
@@ -890,6 +1514,9 @@ fn compute_expr_error_propagate_semantic(
         unwrap_error_propagation_type(ctx.db, inner_ty).ok_or_else(|| {
             ctx.diagnostics.report(syntax, ErrorPropagateOnNonErrorType { ty: inner_ty })
         })?;
+    if ctx.is_inside_closure() {
+        return Err(ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideAClosure));
+    }
     let func_signature = ctx.get_signature(
         syntax.stable_ptr().untyped(),
         UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate,
@@ -990,6 +1617,59 @@ fn compute_pattern_semantic(
                 stable_ptr: short_string_pattern.stable_ptr().into(),
             })
         }
+        ast::Pattern::Range(range_pattern) => {
+            let start = literal_to_semantic(ctx, &range_pattern.start(syntax_db))?;
+            let end = literal_to_semantic(ctx, &range_pattern.end(syntax_db))?;
+            for literal in [&start, &end] {
+                ctx.resolver
+                    .inference()
+                    .conform_ty(literal.ty, ty)
+                    .map_err(|err| err.report(ctx.diagnostics, literal.stable_ptr.untyped()))?;
+            }
+            if start.value >= end.value {
+                return Err(ctx.diagnostics.report(&range_pattern, EmptyRangePattern));
+            }
+            // `PartialOrd::lt` does not take `self`, so the impl is inferred from the concrete
+            // trait rather than by self type.
+            let trait_id = get_core_trait(ctx.db, "PartialOrd".into());
+            let concrete_trait_id = ctx.db.intern_concrete_trait(semantic::ConcreteTraitLongId {
+                trait_id,
+                generic_args: vec![GenericArgumentId::Type(ty)],
+            });
+            let Some(trait_function) = ctx.db.trait_function_by_name(trait_id, "lt".into())? else {
+                return Err(ctx.diagnostics.report(&range_pattern, UnexpectedRangePattern { ty }));
+            };
+            let concrete_trait_function = ctx.db.intern_concrete_trait_function(
+                ConcreteTraitGenericFunctionLongId::new(ctx.db, concrete_trait_id, trait_function),
+            );
+            let impl_lookup_context = ctx.resolver.impl_lookup_context();
+            if get_impl_at_context(
+                ctx.db,
+                impl_lookup_context.clone(),
+                concrete_trait_id,
+                stable_ptr,
+            )
+            .is_err()
+            {
+                return Err(ctx.diagnostics.report(&range_pattern, UnexpectedRangePattern { ty }));
+            }
+            let lt_function = ctx
+                .resolver
+                .inference()
+                .infer_trait_function(
+                    concrete_trait_function,
+                    &impl_lookup_context,
+                    Some(stable_ptr),
+                )
+                .map_err(|err| err.report(ctx.diagnostics, stable_ptr))?;
+            Pattern::Range(PatternRange {
+                start,
+                end,
+                lt_function,
+                ty,
+                stable_ptr: range_pattern.stable_ptr(),
+            })
+        }
         ast::Pattern::Enum(enum_pattern) => {
             // Peel all snapshot wrappers.
             let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
@@ -1088,6 +1768,13 @@ fn compute_pattern_semantic(
                         },
                     );
                 })?;
+                check_struct_member_is_visible(
+                    ctx,
+                    &member,
+                    pattern_struct.stable_ptr().untyped(),
+                    struct_id,
+                    &member_name,
+                );
                 used_members.insert(member_name);
                 Some(member)
             };
@@ -1243,6 +1930,13 @@ fn struct_ctor_expr(
             ctx.diagnostics.report(&arg_identifier, UnknownMember);
             continue;
         };
+        check_struct_member_is_visible(
+            ctx,
+            member,
+            arg_identifier.stable_ptr().untyped(),
+            concrete_struct_id.struct_id(db),
+            &arg_name,
+        );
 
         // Extract expression.
         let arg_expr = match arg.arg_expr(syntax_db) {
@@ -1360,6 +2054,22 @@ fn short_string_to_semantic(
     new_literal_expr(ctx, suffix, value, short_string_syntax.stable_ptr().into())
 }
 
+/// Creates the semantic model of a string literal from its AST.
+fn string_literal_to_semantic(
+    ctx: &mut ComputationContext<'_>,
+    string_syntax: &ast::TerminalString,
+) -> Maybe<ExprStringLiteral> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr = string_syntax.stable_ptr();
+
+    let value = string_syntax.string_value(syntax_db).unwrap_or_default();
+    let ty = try_get_core_ty_by_name(db, "ByteArray".into(), vec![])
+        .map_err(|err| ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), err))?;
+
+    Ok(ExprStringLiteral { value, ty, stable_ptr: stable_ptr.into() })
+}
+
 /// Given an expression syntax, if it's an identifier, returns it. Otherwise, returns the proper
 /// error.
 fn expr_as_identifier(
@@ -1390,12 +2100,18 @@ fn dot_expr(
     }
 }
 
-/// Finds all the trait ids usable in the current context.
-fn all_module_trait_ids(ctx: &mut ComputationContext<'_>) -> Maybe<Vec<TraitId>> {
-    let mut module_traits = ctx.db.module_traits_ids(ctx.resolver.module_file_id.0)?;
+/// Finds all the trait ids usable in the current context, along with the use that brings each of
+/// them into scope, if any.
+fn all_module_trait_ids(ctx: &mut ComputationContext<'_>) -> Maybe<Vec<(TraitId, Option<UseId>)>> {
+    let mut module_traits: Vec<_> = ctx
+        .db
+        .module_traits_ids(ctx.resolver.module_file_id.0)?
+        .into_iter()
+        .map(|trait_id| (trait_id, None))
+        .collect();
     for use_id in ctx.db.module_uses_ids(ctx.resolver.module_file_id.0)? {
         if let Ok(ResolvedGenericItem::Trait(trait_id)) = ctx.db.use_resolved_item(use_id) {
-            module_traits.push(trait_id);
+            module_traits.push((trait_id, Some(use_id)));
         }
     }
     Ok(module_traits)
@@ -1423,7 +2139,7 @@ fn method_call_expr(
     let ty = ctx.reduce_ty(lexpr.ty());
     // Save some work.
     ctx.resolver.inference().solve().ok();
-    for trait_id in all_module_trait_ids(ctx)? {
+    for (trait_id, use_id) in all_module_trait_ids(ctx)? {
         for (name, trait_function) in ctx.db.trait_functions(trait_id)? {
             if name != func_name {
                 continue;
@@ -1448,19 +2164,19 @@ fn method_call_expr(
                 continue;
             }
 
-            candidates.push(trait_function);
+            candidates.push((trait_function, use_id));
         }
     }
 
-    let trait_function = match candidates[..] {
+    let (trait_function, use_id) = match candidates[..] {
         [] => {
             return Err(ctx.diagnostics.report_by_ptr(
                 path.stable_ptr().untyped(),
                 NoSuchMethod { ty, method_name: func_name },
             ));
         }
-        [trait_function] => trait_function,
-        [trait_function_id0, trait_function_id1, ..] => {
+        [candidate] => candidate,
+        [(trait_function_id0, _), (trait_function_id1, _), ..] => {
             return Err(ctx.diagnostics.report_by_ptr(
                 stable_ptr.untyped(),
                 AmbiguousTrait { trait_function_id0, trait_function_id1 },
@@ -1473,6 +2189,10 @@ fn method_call_expr(
         &segment,
         ResolvedGenericItem::TraitFunction(trait_function),
     );
+    // Mark the use that brings the trait of the method into scope.
+    if let Some(use_id) = use_id {
+        ctx.resolver.data.used_items.insert(ModuleItemId::Use(use_id));
+    }
 
     let mut lookup_context = ctx.resolver.impl_lookup_context();
     lookup_context.insert_module(trait_function.module_file_id(ctx.db.upcast()).0);
@@ -1535,6 +2255,25 @@ fn method_call_expr(
     expr_function_call(ctx, function_id, named_args, stable_ptr)
 }
 
+/// Reports a diagnostic if a struct member is not visible from the current module.
+fn check_struct_member_is_visible(
+    ctx: &mut ComputationContext<'_>,
+    member: &Member,
+    stable_ptr: SyntaxStablePtrId,
+    struct_id: StructId,
+    member_name: &SmolStr,
+) {
+    let db = ctx.db.upcast();
+    let containing_module_id = member.id.module_file_id(db).0;
+    if !peek_visible_in(db, member.visibility, containing_module_id, ctx.resolver.module_file_id.0)
+    {
+        ctx.diagnostics.report_by_ptr(
+            stable_ptr,
+            MemberNotVisible { struct_id, member_name: member_name.clone() },
+        );
+    }
+}
+
 /// Computes the semantic model of a member access expression (e.g. "expr.member").
 fn member_access_expr(
     ctx: &mut ComputationContext<'_>,
@@ -1558,10 +2297,17 @@ fn member_access_expr(
                         &rhs_syntax,
                         NoSuchMember {
                             struct_id: concrete_struct_id.struct_id(ctx.db),
-                            member_name,
+                            member_name: member_name.clone(),
                         },
                     )
                 })?;
+                check_struct_member_is_visible(
+                    ctx,
+                    member,
+                    rhs_syntax.stable_ptr().untyped(),
+                    concrete_struct_id.struct_id(ctx.db),
+                    &member_name,
+                );
                 let member_path = if n_snapshots == 0 {
                     lexpr.as_member_path().map(|parent| ExprVarMemberPath::Member {
                         parent: Box::new(parent),
@@ -1596,7 +2342,7 @@ fn member_access_expr(
             // TODO(spapini): Handle snapshot members.
             Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
         }
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::Closure(_) => {
             Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name }))
         }
         TypeLongId::Var(_) => Err(ctx
@@ -1659,14 +2405,34 @@ pub fn get_variable_by_name(
     variable_name: &SmolStr,
     stable_ptr: ast::ExprPtr,
 ) -> Option<Expr> {
+    // Find the environment defining the variable, by its depth in the environment stack.
     let mut maybe_env = Some(&*ctx.environment);
-    while let Some(env) = maybe_env {
+    let mut depth = 0;
+    let var = loop {
+        let env = maybe_env?;
         if let Some(var) = env.variables.get(variable_name) {
-            return Some(Expr::Var(ExprVar { var: var.id(), ty: var.ty(), stable_ptr }));
+            break var.clone();
         }
         maybe_env = env.parent.as_deref();
+        depth += 1;
+    };
+    let expr_var = ExprVar { var: var.id(), ty: var.ty(), stable_ptr };
+
+    // Every closure between the current environment and the defining one captures the variable.
+    let mut env = &mut *ctx.environment;
+    let mut is_captured = false;
+    for _ in 0..depth {
+        if let Some(captured_variables) = &mut env.captured_variables {
+            captured_variables.entry(var.id()).or_insert_with(|| expr_var.clone());
+            is_captured = true;
+        }
+        env = env.parent.as_deref_mut().unwrap();
+    }
+    env.used_variables.insert(var.id());
+    if is_captured && var.is_mut() {
+        ctx.diagnostics.report_by_ptr(stable_ptr.untyped(), CannotCaptureMutableVariable);
     }
-    None
+    Some(Expr::Var(expr_var))
 }
 
 /// Typechecks a function call.
@@ -1687,8 +2453,14 @@ fn expr_function_call(
         ));
     }
 
-    // Check panicable.
+    // Check panicable. A negation of a literal is checked in [infer_all], once it is known whether
+    // it is folded into a negative literal.
+    let is_negated_literal = matches!(
+        &named_args[..],
+        [NamedArg(arg, _, _)] if ctx.negated_literals.contains(&arg.id)
+    );
     if signature.panicable
+        && !is_negated_literal
         && !ctx
             .get_signature(
                 stable_ptr.untyped(),
@@ -1838,9 +2610,7 @@ pub fn compute_statement_semantic(
             // TODO(yuval): allow unnamed variables. Add them here to
             // ctx.environment.unnamed_variables
             for v in variables {
-                let var_def = Variable::Local(v.var.clone());
-                ctx.environment.variables.insert(v.name.clone(), var_def.clone());
-                ctx.semantic_defs.insert(var_def.id(), var_def);
+                ctx.add_local_variable(v.name.clone(), Variable::Local(v.var.clone()));
             }
             semantic::Statement::Let(semantic::StatementLet {
                 pattern,
@@ -1856,7 +2626,11 @@ pub fn compute_statement_semantic(
                 ast::OptionTerminalSemicolon::Empty(_)
             ) && !matches!(
                 expr_syntax,
-                ast::Expr::Block(_) | ast::Expr::If(_) | ast::Expr::Match(_)
+                ast::Expr::Block(_)
+                    | ast::Expr::If(_)
+                    | ast::Expr::Match(_)
+                    | ast::Expr::While(_)
+                    | ast::Expr::For(_)
             ) {
                 // Point to after the expression, where the semicolon is missing.
                 ctx.diagnostics.report_after(&expr_syntax, MissingSemicolon);
@@ -1880,6 +2654,9 @@ pub fn compute_statement_semantic(
             if ctx.loop_flow_merge.is_some() {
                 return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideALoop));
             }
+            if ctx.is_inside_closure() {
+                return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideAClosure));
+            }
 
             let (expr_option, expr_ty, stable_ptr) = match return_syntax.expr_clause(syntax_db) {
                 ast::OptionExprClause::Empty(empty_clause) => {
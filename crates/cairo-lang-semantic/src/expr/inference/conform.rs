@@ -1,11 +1,11 @@
-use itertools::zip_eq;
+use itertools::{chain, zip_eq};
 
 use super::canonic::ResultNoErrEx;
 use super::{Inference, InferenceError, InferenceResult, InferenceVar};
 use crate::corelib::never_ty;
-use crate::items::imp::ImplId;
+use crate::items::imp::{GeneratedImplLongId, ImplId};
 use crate::substitution::SemanticRewriter;
-use crate::types::peel_snapshots;
+use crate::types::{peel_snapshots, ClosureTypeLongId};
 use crate::{
     ConcreteImplLongId, ConcreteTraitId, ConcreteTraitLongId, ConcreteTypeId, GenericArgumentId,
     TypeId, TypeLongId,
@@ -134,6 +134,33 @@ impl<'db> InferenceConform for Inference<'db> {
                 let (ty, n_snapshots) = self.conform_ty_ex(ty0, ty1, ty0_is_self)?;
                 Ok((self.db.intern_type(TypeLongId::Snapshot(ty)), n_snapshots))
             }
+            TypeLongId::Closure(closure0) => {
+                let (n_snapshots, long_ty1) = self.maybe_peel_snapshots(ty0_is_self, ty1);
+                let TypeLongId::Closure(closure1) = long_ty1 else {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                };
+                if (closure0.parent_function, closure0.body)
+                    != (closure1.parent_function, closure1.body)
+                    || closure0.param_tys.len() != closure1.param_tys.len()
+                    || closure0.captured_types.len() != closure1.captured_types.len()
+                {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                }
+                let param_tys = zip_eq(closure0.param_tys, closure1.param_tys)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let ret_ty = self.conform_ty(closure0.ret_ty, closure1.ret_ty)?;
+                let captured_types = zip_eq(closure0.captured_types, closure1.captured_types)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let long_ty = TypeLongId::Closure(ClosureTypeLongId {
+                    param_tys,
+                    ret_ty,
+                    captured_types,
+                    ..closure0
+                });
+                Ok((self.db.intern_type(long_ty), n_snapshots))
+            }
             TypeLongId::GenericParameter(_) => Err(InferenceError::TypeKindMismatch { ty0, ty1 }),
             TypeLongId::Var(var) => Ok((self.assign_ty(var, ty1)?, n_snapshots)),
             TypeLongId::Missing(_) => Ok((ty0, n_snapshots)),
@@ -230,6 +257,18 @@ impl<'db> InferenceConform for Inference<'db> {
                     generic_args,
                 })))
             }
+            ImplId::GeneratedImpl(generated0) => {
+                let ImplId::GeneratedImpl(generated1) = impl1 else {
+                    return Err(InferenceError::ImplKindMismatch { impl0, impl1 });
+                };
+                let concrete_trait = self.conform_traits(
+                    generated0.concrete_trait(self.db),
+                    generated1.concrete_trait(self.db),
+                )?;
+                Ok(ImplId::GeneratedImpl(
+                    self.db.intern_generated_impl(GeneratedImplLongId { concrete_trait }),
+                ))
+            }
             ImplId::GenericParameter(_) => Err(InferenceError::ImplKindMismatch { impl0, impl1 }),
         }
     }
@@ -266,6 +305,14 @@ impl<'db> InferenceConform for Inference<'db> {
                 .into_iter()
                 .any(|x| x),
             TypeLongId::Snapshot(ty) => self.ty_contains_var(ty, var)?,
+            TypeLongId::Closure(closure) => {
+                for ty in chain!(closure.param_tys, [closure.ret_ty], closure.captured_types) {
+                    if self.ty_contains_var(ty, var)? {
+                        return Ok(true);
+                    }
+                }
+                false
+            }
             TypeLongId::Var(new_var) => {
                 if InferenceVar::Type(new_var.id) == var {
                     return Ok(true);
@@ -311,6 +358,13 @@ impl<'db> InferenceConform for Inference<'db> {
                 var,
             )?,
             ImplId::GenericParameter(_) => false,
+            ImplId::GeneratedImpl(generated_impl) => {
+                let concrete_trait = generated_impl.concrete_trait(self.db);
+                self.generic_args_contain_var(
+                    &self.db.lookup_intern_concrete_trait(concrete_trait).generic_args,
+                    var,
+                )?
+            }
             ImplId::ImplVar(new_var) => {
                 if InferenceVar::Impl(new_var.get(self.db).id) == var {
                     return Ok(true);
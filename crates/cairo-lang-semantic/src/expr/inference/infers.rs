@@ -6,13 +6,17 @@ use itertools::Itertools;
 use super::canonic::ResultNoErrEx;
 use super::conform::InferenceConform;
 use super::{Inference, InferenceError, InferenceResult};
+use crate::corelib::{copy_trait, fn_once_trait, fn_trait};
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
-use crate::items::imp::{ImplId, ImplLookupContext, UninferredImpl};
+use crate::items::imp::{
+    closure_generated_impl_concrete_trait, GeneratedImplLongId, ImplId, ImplLookupContext,
+    UninferredImpl,
+};
 use crate::items::trt::ConcreteTraitGenericFunctionId;
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::{
     ConcreteFunction, ConcreteImplLongId, ConcreteTraitId, ConcreteTraitLongId, FunctionId,
-    FunctionLongId, GenericArgumentId, GenericParam, TypeId,
+    FunctionLongId, GenericArgumentId, GenericParam, TypeId, TypeLongId,
 };
 
 /// Functions for embedding generic semantic objects in an existing [Inference] object, by
@@ -32,6 +36,12 @@ pub trait InferenceEmbeddings {
         lookup_context: &ImplLookupContext,
         stable_ptr: Option<SyntaxStablePtrId>,
     ) -> InferenceResult<ImplId>;
+    fn infer_generated_impl(
+        &mut self,
+        concrete_trait_id: ConcreteTraitId,
+        lookup_context: &ImplLookupContext,
+        stable_ptr: Option<SyntaxStablePtrId>,
+    ) -> InferenceResult<ImplId>;
     fn infer_impl_alias(
         &mut self,
         impl_alias_id: ImplAliasId,
@@ -110,10 +120,50 @@ impl<'db> InferenceEmbeddings for Inference<'db> {
                 self.conform_traits(concrete_trait_id, imp_concrete_trait_id)?;
                 ImplId::GenericParameter(param_id)
             }
+            UninferredImpl::GeneratedImpl(_) => {
+                self.infer_generated_impl(concrete_trait_id, lookup_context, stable_ptr)?
+            }
         };
         Ok(impl_id)
     }
 
+    /// Infers all the variables required to make the impl generated for a closure type provide a
+    /// concrete trait. `Copy` and `Drop` are only provided if all the captured types provide them,
+    /// and `Fn` only if all the captured types are `Copy` - e.g. snapshots.
+    fn infer_generated_impl(
+        &mut self,
+        concrete_trait_id: ConcreteTraitId,
+        lookup_context: &ImplLookupContext,
+        stable_ptr: Option<SyntaxStablePtrId>,
+    ) -> InferenceResult<ImplId> {
+        let generated_concrete_trait =
+            closure_generated_impl_concrete_trait(self.db, concrete_trait_id)
+                .ok_or(InferenceError::NoImplsFound { concrete_trait_id })?;
+        self.conform_traits(concrete_trait_id, generated_concrete_trait)?;
+        let long_concrete_trait = self.db.lookup_intern_concrete_trait(generated_concrete_trait);
+        let trait_id = long_concrete_trait.trait_id;
+        if trait_id != fn_once_trait(self.db) {
+            let captured_trait_id =
+                if trait_id == fn_trait(self.db) { copy_trait(self.db) } else { trait_id };
+            let closure_ty =
+                *extract_matches!(&long_concrete_trait.generic_args[0], GenericArgumentId::Type);
+            let closure =
+                extract_matches!(self.db.lookup_intern_type(closure_ty), TypeLongId::Closure);
+            for captured_ty in closure.captured_types {
+                let captured_concrete_trait = self.db.intern_concrete_trait(ConcreteTraitLongId {
+                    trait_id: captured_trait_id,
+                    generic_args: vec![GenericArgumentId::Type(captured_ty)],
+                });
+                self.new_impl_var(captured_concrete_trait, stable_ptr, lookup_context.clone())?;
+            }
+        }
+        Ok(ImplId::GeneratedImpl(
+            self.db.intern_generated_impl(GeneratedImplLongId {
+                concrete_trait: generated_concrete_trait,
+            }),
+        ))
+    }
+
     /// Infers all the variables required to make an impl (possibly with free generic params)
     /// provide a concrete trait.
     fn infer_impl_def(
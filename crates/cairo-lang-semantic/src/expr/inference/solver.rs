@@ -7,7 +7,10 @@ use super::canonic::{CanonicalImpl, CanonicalMapping, CanonicalTrait, MapperErro
 use super::infers::InferenceEmbeddings;
 use super::{InferenceData, InferenceError, InferenceResult, InferenceVar, LocalImplVarId};
 use crate::db::SemanticGroup;
-use crate::items::imp::{find_candidates_at_context, ImplId, ImplLookupContext, UninferredImpl};
+use crate::items::imp::{
+    closure_generated_impl_concrete_trait, find_candidates_at_context, GeneratedImplLongId, ImplId,
+    ImplLookupContext, UninferredImpl,
+};
 use crate::substitution::SemanticRewriter;
 use crate::{ConcreteTraitId, GenericArgumentId, TypeLongId};
 
@@ -113,8 +116,14 @@ impl Solver {
         lookup_context: ImplLookupContext,
     ) -> Self {
         let filter = canonical_trait.0.filter(db);
-        let candidates =
+        let mut candidates =
             find_candidates_at_context(db, &lookup_context, filter).unwrap_or_default();
+        // Closures implement some of the core traits by compiler generated impls.
+        if let Some(concrete_trait) = closure_generated_impl_concrete_trait(db, canonical_trait.0) {
+            candidates.insert(UninferredImpl::GeneratedImpl(
+                db.intern_generated_impl(GeneratedImplLongId { concrete_trait }),
+            ));
+        }
         let candidate_solvers = candidates
             .into_iter()
             .filter_map(|candidate| {
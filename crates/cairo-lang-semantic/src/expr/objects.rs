@@ -5,11 +5,12 @@ use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
 use cairo_lang_syntax::node::ast;
 use id_arena::Id;
 use num_bigint::BigInt;
+use smol_str::SmolStr;
 
 use super::fmt::ExprFormatter;
 use super::pattern::Pattern;
 use crate::items::imp::ImplId;
-use crate::{semantic, ConcreteStructId, FunctionId, TypeId};
+use crate::{semantic, ConcreteStructId, FunctionId, LocalVariable, TypeId};
 
 pub type ExprId = Id<Expr>;
 pub type StatementId = Id<Statement>;
@@ -113,11 +114,15 @@ pub enum Expr {
     LogicalOperator(ExprLogicalOperator),
     Block(ExprBlock),
     Loop(ExprLoop),
+    While(ExprWhile),
+    For(ExprFor),
+    Closure(ExprClosure),
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
     Var(ExprVar),
     Literal(ExprLiteral),
+    StringLiteral(ExprStringLiteral),
     MemberAccess(ExprMemberAccess),
     StructCtor(ExprStructCtor),
     EnumVariantCtor(ExprEnumVariantCtor),
@@ -135,11 +140,15 @@ impl Expr {
             Expr::LogicalOperator(expr) => expr.ty,
             Expr::Block(expr) => expr.ty,
             Expr::Loop(expr) => expr.ty,
+            Expr::While(expr) => expr.ty,
+            Expr::For(expr) => expr.ty,
+            Expr::Closure(expr) => expr.ty,
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
+            Expr::StringLiteral(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
             Expr::StructCtor(expr) => expr.ty,
             Expr::EnumVariantCtor(expr) => expr.ty,
@@ -157,11 +166,15 @@ impl Expr {
             Expr::LogicalOperator(expr) => expr.stable_ptr,
             Expr::Block(expr) => expr.stable_ptr,
             Expr::Loop(expr) => expr.stable_ptr,
+            Expr::While(expr) => expr.stable_ptr,
+            Expr::For(expr) => expr.stable_ptr,
+            Expr::Closure(expr) => expr.stable_ptr,
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
+            Expr::StringLiteral(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
             Expr::StructCtor(expr) => expr.stable_ptr,
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
@@ -235,6 +248,67 @@ pub struct ExprLoop {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprWhile {
+    pub condition: ExprId,
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A `for` loop. Desugared into a loop over the iterator returned by `into_iter`, calling `next`
+/// on it at the start of each iteration.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprFor {
+    /// The call to `into_iter` on the iterated expression, evaluated once before the loop.
+    pub into_iter: ExprId,
+    /// The variable holding the iterator during the loop.
+    pub into_iter_member_path: ExprVarMemberPath,
+    /// The call to `next` on the iterator, evaluated at the start of each iteration.
+    pub next_call: ExprId,
+    pub some_variant: semantic::ConcreteVariant,
+    pub none_variant: semantic::ConcreteVariant,
+    /// The pattern binding the item returned by `next`.
+    pub pattern: Pattern,
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A closure expression. The body is computed in the scope of the closure, and may use variables
+/// of the enclosing function, which are captured by value when the closure is created.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprClosure {
+    pub params: Vec<ExprClosureParam>,
+    pub body: ExprId,
+    /// The variables of the enclosing scopes used by the body, in the order of their first use.
+    pub captured_vars: Vec<ExprVar>,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A parameter of a closure, bound to a local variable in the closure body.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ExprClosureParam {
+    #[dont_rewrite]
+    pub name: SmolStr,
+    pub var: LocalVariable,
+}
+impl DebugWithDb<ExprFormatter<'_>> for ExprClosureParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, _db: &ExprFormatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 /// A sequence of member accesses of a variable. For example: a, a.b, a.b.c, ...
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum ExprVarMemberPath {
@@ -386,6 +460,17 @@ pub struct ExprLiteral {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprStringLiteral {
+    #[dont_rewrite]
+    pub value: String,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMemberAccess {
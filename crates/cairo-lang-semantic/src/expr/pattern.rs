@@ -18,6 +18,7 @@ use crate::{semantic, ConcreteStructId, ExprLiteral, LocalVariable};
 #[debug_db(ExprFormatter<'a>)]
 pub enum Pattern {
     Literal(PatternLiteral),
+    Range(PatternRange),
     Variable(PatternVariable),
     Struct(PatternStruct),
     Tuple(PatternTuple),
@@ -28,6 +29,7 @@ impl Pattern {
     pub fn ty(&self, _db: &dyn SemanticGroup) -> semantic::TypeId {
         match self {
             Pattern::Literal(literal) => literal.literal.ty,
+            Pattern::Range(range) => range.ty,
             Pattern::Variable(variable) => variable.var.ty,
             Pattern::Struct(pattern_struct) => pattern_struct.ty,
             Pattern::Tuple(pattern_tuple) => pattern_tuple.ty,
@@ -52,13 +54,14 @@ impl Pattern {
             Pattern::EnumVariant(pattern_enum_variant) => {
                 pattern_enum_variant.inner_pattern.variables()
             }
-            Pattern::Literal(_) | Pattern::Otherwise(_) => vec![],
+            Pattern::Literal(_) | Pattern::Range(_) | Pattern::Otherwise(_) => vec![],
         }
     }
 
     pub fn stable_ptr(&self) -> ast::PatternPtr {
         match self {
             Pattern::Literal(pat) => pat.stable_ptr,
+            Pattern::Range(pat) => pat.stable_ptr.into(),
             Pattern::Variable(pat) => pat.stable_ptr,
             Pattern::Struct(pat) => pat.stable_ptr.into(),
             Pattern::Tuple(pat) => pat.stable_ptr.into(),
@@ -77,6 +80,20 @@ pub struct PatternLiteral {
     pub stable_ptr: ast::PatternPtr,
 }
 
+/// A pattern that matches the values in the half-open range `start..end`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct PatternRange {
+    pub start: ExprLiteral,
+    pub end: ExprLiteral,
+    /// The `PartialOrd::lt` function of the matched type, used to test the range bounds.
+    pub lt_function: semantic::FunctionId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::PatternRangePtr,
+}
+
 /// A pattern that binds the matched value to a variable.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub struct PatternVariable {
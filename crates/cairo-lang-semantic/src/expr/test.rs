@@ -21,6 +21,7 @@ cairo_lang_test_utils::test_file_test!(
     "src/expr/test_data",
     {
         assignment: "assignment",
+        closure: "closure",
         constant: "constant",
         enum_: "enum",
         error_propagate: "error_propagate",
@@ -209,7 +210,7 @@ fn test_member_access_failures() {
                 b: felt252,
                 c: felt252,
             }
-            fn foo(a: A){
+            pub fn foo(a: A){
                 a.f;
                 a.a::b;
                 a.4.4;
@@ -360,6 +361,11 @@ fn test_let_statement_failures() {
                 let a: () = 3_felt252;
                             ^*******^
 
+            warning: Unused variable. Consider ignoring by prefixing with `_`.
+             --> lib.cairo:2:9
+                let a: () = 3_felt252;
+                    ^
+
         "#}
     );
 }
@@ -654,7 +660,7 @@ fn test_expr_struct_ctor_failures() {
                 a: felt252,
                 b: ()
             }
-            fn foo(a: A) -> A {
+            pub fn foo(a: A) -> A {
                 A {
                     b: 1_felt252,
                     a: 2,
@@ -689,6 +695,11 @@ fn test_expr_struct_ctor_failures() {
                     ..d,
                     ^*^
 
+            warning: Unused variable. Consider ignoring by prefixing with `_`.
+             --> lib.cairo:5:12
+            pub fn foo(a: A) -> A {
+                       ^
+
         "#}
     );
 }
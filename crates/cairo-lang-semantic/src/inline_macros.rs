@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::plugin::{
+    DynGeneratedFileAuxData, GeneratedFileAuxData, InlineMacroPlugin, MacroPlugin,
+    PluginDiagnostic, PluginGeneratedFile, PluginResult,
+};
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::db::SemanticGroup;
+use crate::patcher::{PatchBuilder, Patches};
+use crate::plugin::{
+    AsDynGeneratedFileAuxData, DynPluginAuxData, PluginAuxData, PluginMappedDiagnostic,
+};
+use crate::SemanticDiagnostic;
+
+/// A macro plugin that expands the calls to the registered inline macros in an item.
+#[derive(Debug)]
+pub struct InlineMacroExpanderPlugin {
+    inline_macro_plugins: Arc<OrderedHashMap<String, Arc<dyn InlineMacroPlugin>>>,
+}
+impl InlineMacroExpanderPlugin {
+    pub fn new(
+        inline_macro_plugins: Arc<OrderedHashMap<String, Arc<dyn InlineMacroPlugin>>>,
+    ) -> Self {
+        Self { inline_macro_plugins }
+    }
+
+    /// Returns the plugin of the given inline macro, if it is registered.
+    fn get_plugin(
+        &self,
+        db: &dyn SyntaxGroup,
+        inline_macro: &ast::ExprInlineMacro,
+    ) -> Option<&Arc<dyn InlineMacroPlugin>> {
+        let macro_name = inline_macro.path(db).as_syntax_node().get_text_without_trivia(db);
+        self.inline_macro_plugins.get(&macro_name)
+    }
+
+    /// Adds the code of `node` to `builder`, where the calls to the registered inline macros
+    /// listed in `macro_spans` are replaced by their expansion, recursively. Returns false if any
+    /// of the macros failed to expand.
+    fn expand_node(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+        macro_spans: &[TextSpan],
+        builder: &mut PatchBuilder<'_>,
+        diagnostics: &mut Vec<PluginDiagnostic>,
+    ) -> bool {
+        let span = node.span(db);
+        if !macro_spans.iter().any(|macro_span| span.contains(*macro_span)) {
+            builder.add_node(node);
+            return true;
+        }
+        if node.kind(db) == SyntaxKind::ExprInlineMacro && macro_spans.contains(&span) {
+            let inline_macro = ast::ExprInlineMacro::from_syntax_node(db, node.clone());
+            let plugin = self.get_plugin(db, &inline_macro).unwrap();
+            let result = plugin.generate_code(db, &inline_macro);
+            let no_diagnostics = result.diagnostics.is_empty();
+            diagnostics.extend(result.diagnostics);
+            let Some(code) = result.code else {
+                if no_diagnostics {
+                    diagnostics.push(PluginDiagnostic::error(
+                        inline_macro.stable_ptr().untyped(),
+                        format!(
+                            "Failed to expand inline macro `{}`.",
+                            inline_macro.path(db).as_syntax_node().get_text_without_trivia(db)
+                        ),
+                    ));
+                }
+                return false;
+            };
+            // Keep the trivia around the macro call.
+            let span_without_trivia = node.span_without_trivia(db);
+            builder.add_str(&node.clone().get_text_of_span(
+                db,
+                TextSpan { start: span.start, end: span_without_trivia.start },
+            ));
+            // The macros nested in the arguments of the macro are expanded as well.
+            let mut success = true;
+            builder.add_inline_macro_code(&code, &node, |builder, node| {
+                success &= self.expand_node(db, node, macro_spans, builder, diagnostics);
+            });
+            builder.add_str(
+                &node.clone().get_text_of_span(
+                    db,
+                    TextSpan { start: span_without_trivia.end, end: span.end },
+                ),
+            );
+            return success;
+        }
+        let mut success = true;
+        for child in node.children(db) {
+            success &= self.expand_node(db, child, macro_spans, builder, diagnostics);
+        }
+        success
+    }
+}
+impl MacroPlugin for InlineMacroExpanderPlugin {
+    fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
+        // The items of a submodule are expanded separately.
+        if matches!(item_ast, ast::Item::Module(_)) {
+            return PluginResult::default();
+        }
+        let item_node = item_ast.as_syntax_node();
+        let macro_spans: Vec<TextSpan> = item_node
+            .descendants(db)
+            .filter(|node| node.kind(db) == SyntaxKind::ExprInlineMacro)
+            .filter(|node| {
+                let inline_macro = ast::ExprInlineMacro::from_syntax_node(db, node.clone());
+                self.get_plugin(db, &inline_macro).is_some()
+            })
+            .map(|node| node.span(db))
+            .collect();
+        if macro_spans.is_empty() {
+            return PluginResult::default();
+        }
+
+        let mut builder = PatchBuilder::new(db);
+        let mut diagnostics = vec![];
+        // If any of the macros fails to expand, the item is kept as is, and the failure is
+        // reported by the plugin diagnostics.
+        if !self.expand_node(db, item_node, &macro_spans, &mut builder, &mut diagnostics) {
+            return PluginResult { diagnostics, ..PluginResult::default() };
+        }
+        PluginResult {
+            code: Some(PluginGeneratedFile {
+                name: "inline_macros".into(),
+                content: builder.code,
+                aux_data: DynGeneratedFileAuxData::new(DynPluginAuxData::new(InlineMacroAuxData {
+                    patches: builder.patches,
+                })),
+            }),
+            diagnostics,
+            remove_original_item: true,
+        }
+    }
+}
+
+/// The auxiliary data of the inline macro expander, mapping diagnostics in the expanded code back
+/// to the original code.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InlineMacroAuxData {
+    /// Patches of code that need translation in case they have diagnostics.
+    pub patches: Patches,
+}
+impl GeneratedFileAuxData for InlineMacroAuxData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn eq(&self, other: &dyn GeneratedFileAuxData) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() { self == other } else { false }
+    }
+}
+impl AsDynGeneratedFileAuxData for InlineMacroAuxData {
+    fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static) {
+        self
+    }
+}
+impl PluginAuxData for InlineMacroAuxData {
+    fn map_diag(
+        &self,
+        db: &(dyn SemanticGroup + 'static),
+        diag: &dyn std::any::Any,
+    ) -> Option<PluginMappedDiagnostic> {
+        let diag = diag.downcast_ref::<SemanticDiagnostic>()?;
+        let span = self
+            .patches
+            .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
+        Some(PluginMappedDiagnostic { span, message: diag.format(db) })
+    }
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
+}
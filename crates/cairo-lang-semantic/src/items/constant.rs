@@ -7,7 +7,7 @@ use cairo_lang_syntax::node::TypedSyntaxNode;
 
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnostics;
-use crate::expr::compute::{compute_expr_semantic, ComputationContext, Environment};
+use crate::expr::compute::{compute_expr_semantic, infer_all, ComputationContext, Environment};
 use crate::expr::inference::canonic::ResultNoErrEx;
 use crate::expr::inference::conform::InferenceConform;
 use crate::resolve::{Resolver, ResolverData};
@@ -59,29 +59,39 @@ pub fn priv_constant_semantic_data(
         &const_ast.type_clause(syntax_db).ty(syntax_db),
     );
 
-    let mut ctx =
-        ComputationContext::new(db, &mut diagnostics, resolver, None, Environment::default());
+    let mut ctx = ComputationContext::new(
+        db,
+        &mut diagnostics,
+        resolver,
+        None,
+        Environment::default(),
+        None,
+    );
     let value = compute_expr_semantic(&mut ctx, &const_ast.value(syntax_db));
     if let Err(err) = ctx.resolver.inference().conform_ty(value.ty(), const_type) {
         err.report(ctx.diagnostics, const_ast.stable_ptr().untyped());
     }
 
+    // Check fully resolved.
+    let value = if let Some((stable_ptr, inference_err)) = ctx.resolver.inference().finalize() {
+        inference_err
+            .report(ctx.diagnostics, stable_ptr.unwrap_or(const_ast.stable_ptr().untyped()));
+        ctx.resolver.inference().rewrite(value.expr).no_err()
+    } else {
+        // Apply inference, which also folds negated signed integer literals.
+        infer_all(&mut ctx).ok();
+        ctx.exprs[value.id].clone()
+    };
+
     // Check that the expression is a literal.
-    if !matches!(value.expr, Expr::Literal(_)) {
+    if !matches!(value, Expr::Literal(_)) {
         ctx.diagnostics.report(
             &const_ast.value(syntax_db),
             crate::diagnostic::SemanticDiagnosticKind::OnlyLiteralConstants,
         );
     };
 
-    let constant = Constant { value: value.expr };
-
-    // Check fully resolved.
-    if let Some((stable_ptr, inference_err)) = ctx.resolver.inference().finalize() {
-        inference_err
-            .report(ctx.diagnostics, stable_ptr.unwrap_or(const_ast.stable_ptr().untyped()));
-    }
-    let constant = ctx.resolver.inference().rewrite(constant).no_err();
+    let constant = Constant { value };
 
     let resolver_data = Arc::new(ctx.resolver.data);
     Ok(ConstantData { diagnostics: diagnostics.build(), constant: Ok(constant), resolver_data })
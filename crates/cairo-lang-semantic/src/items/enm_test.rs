@@ -23,7 +23,7 @@ fn test_enum() {
                 a: ()
             }
 
-            fn foo(a: A) {
+            pub fn foo(a: A) {
                 5;
             }
         "},
@@ -42,6 +42,11 @@ fn test_enum() {
             a: ()
             ^***^
 
+        warning: Unused variable. Consider ignoring by prefixing with `_`.
+         --> lib.cairo:9:12
+        pub fn foo(a: A) {
+                   ^
+
         "#}
     );
     let module_id = test_module.module_id;
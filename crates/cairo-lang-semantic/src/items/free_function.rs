@@ -1,16 +1,21 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{FreeFunctionId, FunctionTitleId, LanguageElementId};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, FunctionTitleId, FunctionWithBodyId, LanguageElementId, ModuleItemId,
+};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use itertools::Itertools;
 
 use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
 use super::functions::{
     forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
 };
 use super::generics::semantic_generic_params;
+use super::module::{is_used_in_module_tree, is_user_item};
+use super::visibility::Visibility;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::{compute_root_expr, ComputationContext, Environment};
@@ -174,6 +179,40 @@ pub fn free_function_body_resolver_data(
     Ok(db.priv_free_function_body_data(free_function_id)?.resolver_data)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::free_function_is_unused].
+pub fn free_function_is_unused(db: &dyn SemanticGroup, free_function_id: FreeFunctionId) -> bool {
+    let module_id = free_function_id.module_file_id(db.upcast()).0;
+    let name = free_function_id.name(db.upcast());
+    if name.starts_with('_') {
+        return false;
+    }
+    let item = ModuleItemId::FreeFunction(free_function_id);
+    let Ok(Some(item_info)) = db.module_item_info_by_name(module_id, name) else {
+        return false;
+    };
+    // `pub` and `pub(crate)` functions are part of the interface of their module.
+    if item_info.item_id != item
+        || item_info.visibility != Visibility::Private
+        || !is_user_item(db, module_id, item)
+    {
+        return false;
+    }
+    let Ok(attributes) =
+        db.function_with_body_attributes(FunctionWithBodyId::Free(free_function_id))
+    else {
+        return false;
+    };
+    // Entry points are called from outside of the crate, e.g. by a test runner.
+    let executable_attributes =
+        db.macro_plugins().iter().flat_map(|plugin| plugin.executable_attributes()).collect_vec();
+    if attributes.iter().any(|attr| executable_attributes.iter().any(|name| *name == attr.id)) {
+        return false;
+    }
+    !is_used_in_module_tree(db, module_id, &|used_items| {
+        used_items.free_functions.contains(&free_function_id)
+    })
+}
+
 // --- Computation ---
 
 /// Query implementation of [crate::db::SemanticGroup::priv_free_function_body_data].
@@ -202,6 +241,7 @@ pub fn priv_free_function_body_data(
         resolver,
         Some(&declaration.signature),
         environment,
+        Some(FunctionWithBodyId::Free(free_function_id)),
     );
     let function_body = function_syntax.body(db.upcast());
     let return_type = declaration.signature.return_type;
@@ -44,7 +44,9 @@ impl ImplGenericFunctionId {
             ImplId::Concrete(concrete_impl_id) => {
                 concrete_impl_id.get_impl_function(db, self.function)
             }
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => Ok(None),
+            ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::GeneratedImpl(_) => {
+                Ok(None)
+            }
         }
     }
     /// Converts to ImplGenericFunctionWithBodyId if this is a function of a concrete impl.
@@ -4,8 +4,9 @@ use std::vec;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, GenericParamId, ImplAliasId, ImplDefId, ImplFunctionId, ImplFunctionLongId,
-    LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+    FunctionTitleId, FunctionWithBodyId, GenericParamId, ImplAliasId, ImplDefId, ImplFunctionId,
+    ImplFunctionLongId, LanguageElementId, ModuleId, TopLevelLanguageElementId, TraitFunctionId,
+    TraitId,
 };
 use cairo_lang_diagnostics::{
     skip_diagnostic, Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
@@ -33,7 +34,7 @@ use super::functions::{
 use super::generics::{semantic_generic_params, GenericArgumentHead};
 use super::structure::SemanticStructEx;
 use super::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
-use crate::corelib::{copy_trait, core_module, drop_trait};
+use crate::corelib::{copy_trait, core_module, drop_trait, fn_once_trait, fn_trait};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::{self, *};
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
@@ -111,13 +112,16 @@ pub enum ImplId {
     Concrete(ConcreteImplId),
     GenericParameter(GenericParamId),
     ImplVar(ImplVarId),
+    GeneratedImpl(GeneratedImplId),
 }
 impl ImplId {
     /// Returns the [ImplHead] of an impl if available.
     pub fn head(&self, db: &dyn SemanticGroup) -> Option<ImplHead> {
         Some(match self {
             ImplId::Concrete(concrete) => ImplHead::Concrete(concrete.impl_def_id(db)),
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+            ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::GeneratedImpl(_) => {
+                return None;
+            }
         })
     }
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
@@ -125,6 +129,7 @@ impl ImplId {
             ImplId::Concrete(concrete_impl) => concrete_impl.name(db),
             ImplId::GenericParameter(generic_param_impl) => generic_param_impl.name(db.upcast()),
             ImplId::ImplVar(var) => format!("{var:?}").into(),
+            ImplId::GeneratedImpl(generated_impl) => generated_impl.name(db),
         }
     }
     pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> Maybe<ConcreteTraitId> {
@@ -141,10 +146,35 @@ impl DebugWithDb<dyn SemanticGroup> for ImplId {
             ImplId::Concrete(concrete_impl_id) => write!(f, "{:?}", concrete_impl_id.debug(db)),
             ImplId::GenericParameter(param) => write!(f, "{:?}", param.debug(db)),
             ImplId::ImplVar(var) => write!(f, "?{}", var.get(db).id.0),
+            ImplId::GeneratedImpl(generated_impl) => {
+                write!(f, "{:?}", generated_impl.concrete_trait(db).debug(db))
+            }
         }
     }
 }
 
+/// An impl generated by the compiler, rather than defined in code. Used for the traits that
+/// closures implement.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct GeneratedImplLongId {
+    pub concrete_trait: ConcreteTraitId,
+}
+define_short_id!(GeneratedImplId, GeneratedImplLongId, SemanticGroup, lookup_intern_generated_impl);
+semantic_object_for_id!(
+    GeneratedImplId,
+    lookup_intern_generated_impl,
+    intern_generated_impl,
+    GeneratedImplLongId
+);
+impl GeneratedImplId {
+    pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> ConcreteTraitId {
+        db.lookup_intern_generated_impl(*self).concrete_trait
+    }
+    pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
+        self.concrete_trait(db).name(db)
+    }
+}
+
 /// Head of an impl. A non-param non-variable impl has a head, which represents the kind of the root
 /// node in its tree representation. This is used for caching queries for fast lookups when the impl
 /// is not completely inferred yet.
@@ -286,6 +316,7 @@ pub fn impl_concrete_trait(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<Con
             param_impl.concrete_trait
         }
         ImplId::ImplVar(var) => Ok(var.get(db).concrete_trait_id),
+        ImplId::GeneratedImpl(generated_impl) => Ok(generated_impl.concrete_trait(db)),
     }
 }
 
@@ -631,6 +662,7 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<Vec<TypeId>> {
             }
         }
         TypeLongId::Tuple(tys) => tys,
+        TypeLongId::Closure(closure) => closure.captured_types,
         TypeLongId::Snapshot(_) => vec![],
         TypeLongId::GenericParameter(_) => {
             return Err(skip_diagnostic());
@@ -777,6 +809,7 @@ pub enum UninferredImpl {
     Def(ImplDefId),
     ImplAlias(ImplAliasId),
     GenericParam(GenericParamId),
+    GeneratedImpl(GeneratedImplId),
 }
 impl UninferredImpl {
     pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> Maybe<ConcreteTraitId> {
@@ -791,6 +824,7 @@ impl UninferredImpl {
                     extract_matches!(db.generic_param_semantic(*param)?, GenericParam::Impl);
                 param.concrete_trait
             }
+            UninferredImpl::GeneratedImpl(generated_impl) => Ok(generated_impl.concrete_trait(db)),
         }
     }
 
@@ -806,6 +840,9 @@ impl UninferredImpl {
                     extract_matches!(db.generic_param_semantic(*param)?, GenericParam::Impl);
                 param.concrete_trait.map(|concrete_trait| concrete_trait.trait_id(db))
             }
+            UninferredImpl::GeneratedImpl(generated_impl) => {
+                Ok(generated_impl.concrete_trait(db).trait_id(db))
+            }
         }
     }
 
@@ -815,6 +852,9 @@ impl UninferredImpl {
             UninferredImpl::Def(impl_def_id) => impl_def_id.module_file_id(defs_db).0,
             UninferredImpl::ImplAlias(impl_alias_id) => impl_alias_id.module_file_id(defs_db).0,
             UninferredImpl::GenericParam(param) => param.module_file_id(defs_db).0,
+            UninferredImpl::GeneratedImpl(generated_impl) => {
+                generated_impl.concrete_trait(db).trait_id(db).module_file_id(defs_db).0
+            }
         }
     }
 }
@@ -828,10 +868,45 @@ impl DebugWithDb<dyn SemanticGroup> for UninferredImpl {
             UninferredImpl::GenericParam(param) => {
                 write!(f, "generic param {}", param.name(db.upcast()))
             }
+            UninferredImpl::GeneratedImpl(generated_impl) => {
+                write!(f, "generated impl {}", generated_impl.name(db))
+            }
         }
     }
 }
 
+/// Returns the concrete trait provided by the impl generated for a closure type, if the first
+/// generic argument of `concrete_trait_id` is a closure type and its trait is one of the traits
+/// closures implement (`FnOnce`, `Fn`, `Copy` and `Drop`).
+pub fn closure_generated_impl_concrete_trait(
+    db: &dyn SemanticGroup,
+    concrete_trait_id: ConcreteTraitId,
+) -> Option<ConcreteTraitId> {
+    let long_concrete_trait = db.lookup_intern_concrete_trait(concrete_trait_id);
+    let Some(GenericArgumentId::Type(closure_ty)) = long_concrete_trait.generic_args.first() else {
+        return None;
+    };
+    let TypeLongId::Closure(closure) = db.lookup_intern_type(*closure_ty) else {
+        return None;
+    };
+    let trait_id = long_concrete_trait.trait_id;
+    if trait_id == fn_once_trait(db) || trait_id == fn_trait(db) {
+        let args_ty = db.intern_type(TypeLongId::Tuple(closure.param_tys));
+        Some(db.intern_concrete_trait(ConcreteTraitLongId {
+            trait_id,
+            generic_args: vec![
+                GenericArgumentId::Type(*closure_ty),
+                GenericArgumentId::Type(args_ty),
+                GenericArgumentId::Type(closure.ret_ty),
+            ],
+        }))
+    } else if trait_id == copy_trait(db) || trait_id == drop_trait(db) {
+        Some(concrete_trait_id)
+    } else {
+        None
+    }
+}
+
 /// Finds all the implementations of a concrete trait, in a specific lookup context.
 pub fn find_candidates_at_context(
     db: &dyn SemanticGroup,
@@ -1335,6 +1410,7 @@ pub fn priv_impl_function_body_data(
         resolver,
         Some(&declaration.function_declaration_data.signature),
         environment,
+        Some(FunctionWithBodyId::Impl(impl_function_id)),
     );
     let function_body = function_syntax.body(db.upcast());
     let return_type = declaration.function_declaration_data.signature.return_type;
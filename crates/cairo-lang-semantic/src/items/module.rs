@@ -1,23 +1,44 @@
 use std::sync::Arc;
 
 use cairo_lang_defs::diagnostic_utils::StableLocation;
-use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, ImplDefId, LanguageElementId, LookupItemId, ModuleFileId, ModuleId,
+    ModuleItemId, UseId,
+};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeListStructurize};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use smol_str::SmolStr;
 
+use super::functions::GenericFunctionId;
+use super::generics::GenericArgumentId;
+use super::imp::ImplId;
+use super::visibility::Visibility;
 use crate::corelib::core_module;
-use crate::db::SemanticGroup;
-use crate::diagnostic::SemanticDiagnosticKind;
+use crate::db::{get_resolver_datas, SemanticGroup};
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
+use crate::items::us::SemanticUseEx;
+use crate::plugin::DynPluginAuxData;
 use crate::resolve::scope::Scope;
-use crate::resolve::ResolvedGenericItem;
+use crate::resolve::{ResolvedGenericItem, ResolverData};
 use crate::SemanticDiagnostic;
 
+/// Information about an item in a module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleItemInfo {
+    pub item_id: ModuleItemId,
+    pub visibility: Visibility,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ModuleSemanticData {
     // The items in the module without duplicates.
-    pub items: OrderedHashMap<SmolStr, ModuleItemId>,
+    pub items: OrderedHashMap<SmolStr, ModuleItemInfo>,
     pub diagnostics: Diagnostics<SemanticDiagnostic>,
 }
 
@@ -26,9 +47,11 @@ pub fn priv_module_semantic_data(
     module_id: ModuleId,
 ) -> Maybe<Arc<ModuleSemanticData>> {
     let def_db = db.upcast();
+    let syntax_db = db.upcast();
     // We use the builder here since the items can come from different file_ids.
     let mut diagnostics = DiagnosticsBuilder::default();
     let mut items = OrderedHashMap::default();
+    let mut validated_visibilities = OrderedHashSet::default();
     for item in db.module_items(module_id)?.iter() {
         let name = match item {
             ModuleItemId::Constant(item_id) => item_id.name(def_db),
@@ -45,7 +68,16 @@ pub fn priv_module_semantic_data(
             ModuleItemId::ExternFunction(item_id) => item_id.name(def_db),
         };
 
-        if items.insert(name.clone(), *item).is_some() {
+        let visibility_ast = module_item_visibility_ast(db, *item)?;
+        let mut item_diagnostics = SemanticDiagnostics::new(item.module_file_id(def_db));
+        let visibility = Visibility::from_ast(syntax_db, &mut item_diagnostics, &visibility_ast);
+        // The leaves of a `use` item share its visibility, so it is only reported once.
+        if validated_visibilities.insert(visibility_ast.stable_ptr()) {
+            diagnostics.extend(item_diagnostics.build());
+        }
+
+        let info = ModuleItemInfo { item_id: *item, visibility };
+        if items.insert(name.clone(), info).is_some() {
             let stable_location = StableLocation::new(
                 item.module_file_id(def_db),
                 db.module_item_name_stable_ptr(module_id, *item)?,
@@ -57,13 +89,83 @@ pub fn priv_module_semantic_data(
     Ok(Arc::new(ModuleSemanticData { items, diagnostics: diagnostics.build() }))
 }
 
+/// Returns the syntax of the visibility of a module item.
+fn module_item_visibility_ast(
+    db: &dyn SemanticGroup,
+    item: ModuleItemId,
+) -> Maybe<ast::Visibility> {
+    let def_db = db.upcast();
+    let syntax_db = db.upcast();
+    let root = db.file_syntax(db.module_file(item.module_file_id(def_db))?)?;
+    Ok(match item {
+        ModuleItemId::Constant(id) => {
+            ast::ItemConstant::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+        ModuleItemId::Submodule(id) => {
+            ast::ItemModule::from_ptr(syntax_db, &root, id.stable_ptr(def_db)).visibility(syntax_db)
+        }
+        ModuleItemId::Use(id) => {
+            // A use item is split into its path leaves, which all share its visibility.
+            let leaf = ast::UsePathLeaf::from_ptr(syntax_db, &root, id.stable_ptr(def_db));
+            let mut node = leaf.as_syntax_node();
+            while node.kind(syntax_db) != SyntaxKind::ItemUse {
+                node = node.parent().unwrap();
+            }
+            ast::ItemUse::from_syntax_node(syntax_db, node).visibility(syntax_db)
+        }
+        ModuleItemId::FreeFunction(id) => {
+            ast::FunctionWithBody::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+        ModuleItemId::Struct(id) => {
+            ast::ItemStruct::from_ptr(syntax_db, &root, id.stable_ptr(def_db)).visibility(syntax_db)
+        }
+        ModuleItemId::Enum(id) => {
+            ast::ItemEnum::from_ptr(syntax_db, &root, id.stable_ptr(def_db)).visibility(syntax_db)
+        }
+        ModuleItemId::TypeAlias(id) => {
+            ast::ItemTypeAlias::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+        ModuleItemId::ImplAlias(id) => {
+            ast::ItemImplAlias::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+        ModuleItemId::Trait(id) => {
+            ast::ItemTrait::from_ptr(syntax_db, &root, id.stable_ptr(def_db)).visibility(syntax_db)
+        }
+        ModuleItemId::Impl(id) => {
+            ast::ItemImpl::from_ptr(syntax_db, &root, id.stable_ptr(def_db)).visibility(syntax_db)
+        }
+        ModuleItemId::ExternType(id) => {
+            ast::ItemExternType::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+        ModuleItemId::ExternFunction(id) => {
+            ast::ItemExternFunction::from_ptr(syntax_db, &root, id.stable_ptr(def_db))
+                .visibility(syntax_db)
+        }
+    })
+}
+
 pub fn module_item_by_name(
     db: &dyn SemanticGroup,
     module_id: ModuleId,
     name: SmolStr,
 ) -> Maybe<Option<ModuleItemId>> {
     let module_data = db.priv_module_semantic_data(module_id)?;
-    Ok(module_data.items.get(&name).copied())
+    Ok(module_data.items.get(&name).map(|info| info.item_id))
+}
+
+/// Query implementation of [SemanticGroup::module_item_info_by_name].
+pub fn module_item_info_by_name(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    name: SmolStr,
+) -> Maybe<Option<ModuleItemInfo>> {
+    let module_data = db.priv_module_semantic_data(module_id)?;
+    Ok(module_data.items.get(&name).cloned())
 }
 
 /// Query implementation of [crate::db::SemanticGroup::module_scope].
@@ -71,9 +173,9 @@ pub fn module_scope(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<Arc<Sc
     let module_data = db.priv_module_semantic_data(module_id)?;
     let mut generic_items = OrderedHashMap::default();
     // Note: this is done in a separate query, since it contains resolved `use` items.
-    for (name, module_item) in module_data.items.iter() {
+    for (name, info) in module_data.items.iter() {
         generic_items
-            .insert(name.clone(), ResolvedGenericItem::from_module_item(db, *module_item)?);
+            .insert(name.clone(), ResolvedGenericItem::from_module_item(db, info.item_id)?);
     }
     let core_module = core_module(db);
     let parent = match module_id {
@@ -99,3 +201,155 @@ pub fn module_attributes(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<V
         }
     })
 }
+
+/// The items referenced by the lookup items of a module. Used for reporting unused items.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleUsedItems {
+    /// The use items through which paths were resolved, or which brought the trait of a called
+    /// method into scope.
+    pub uses: UnorderedHashSet<UseId>,
+    /// The impls chosen by impl inference.
+    pub impls: UnorderedHashSet<ImplDefId>,
+    /// The free functions referenced from lookup items other than their own definitions.
+    pub free_functions: UnorderedHashSet<FreeFunctionId>,
+}
+impl ModuleUsedItems {
+    /// Adds the items used by a lookup item, given one of its resolver datas.
+    fn add_resolver_data(
+        &mut self,
+        db: &dyn SemanticGroup,
+        resolver_data: &ResolverData,
+        lookup_item: LookupItemId,
+    ) {
+        for item in resolver_data.used_items.iter() {
+            let free_function_id = match item {
+                ModuleItemId::Use(use_id) => {
+                    self.uses.insert(*use_id);
+                    match db.use_resolved_item(*use_id) {
+                        Ok(ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id))) => id,
+                        _ => continue,
+                    }
+                }
+                ModuleItemId::FreeFunction(id) => *id,
+                _ => continue,
+            };
+            // Recursive calls do not count as uses.
+            if lookup_item != LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id))
+            {
+                self.free_functions.insert(free_function_id);
+            }
+        }
+        for impl_id in resolver_data.inference_data.impl_assignment.values() {
+            self.add_impl(db, *impl_id);
+        }
+    }
+
+    /// Adds an inferred impl, and the impls in its generic arguments.
+    fn add_impl(&mut self, db: &dyn SemanticGroup, impl_id: ImplId) {
+        let ImplId::Concrete(concrete_impl_id) = impl_id else {
+            return;
+        };
+        let concrete_impl = db.lookup_intern_concrete_impl(concrete_impl_id);
+        self.impls.insert(concrete_impl.impl_def_id);
+        for generic_arg in concrete_impl.generic_args {
+            if let GenericArgumentId::Impl(impl_id) = generic_arg {
+                self.add_impl(db, impl_id);
+            }
+        }
+    }
+}
+
+/// Query implementation of [SemanticGroup::priv_module_used_items].
+pub fn priv_module_used_items(db: &dyn SemanticGroup, module_id: ModuleId) -> Arc<ModuleUsedItems> {
+    let mut used_items = ModuleUsedItems::default();
+    let Ok(module_items) = db.module_items(module_id) else {
+        return Arc::new(used_items);
+    };
+    let mut lookup_items = vec![];
+    for item in module_items.iter() {
+        lookup_items.push(LookupItemId::ModuleItem(*item));
+        if let ModuleItemId::Impl(impl_def_id) = item {
+            for impl_function_id in db.impl_functions(*impl_def_id).unwrap_or_default().values() {
+                lookup_items.push(LookupItemId::ImplFunction(*impl_function_id));
+            }
+        }
+    }
+    for lookup_item in lookup_items {
+        for resolver_data in get_resolver_datas(lookup_item, db) {
+            used_items.add_resolver_data(db, &resolver_data, lookup_item);
+        }
+    }
+    Arc::new(used_items)
+}
+
+/// Returns true if `is_used` holds for the items used by the module or by one of its descendants,
+/// which are the modules from which the private items of the module are visible.
+///
+/// The modules are checked one by one, so that a used item only depends on the modules up to the
+/// one using it.
+pub fn is_used_in_module_tree(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    is_used: &dyn Fn(&ModuleUsedItems) -> bool,
+) -> bool {
+    is_used(&db.priv_module_used_items(module_id))
+        || db.module_submodules_ids(module_id).unwrap_or_default().into_iter().any(|submodule_id| {
+            is_used_in_module_tree(db, ModuleId::Submodule(submodule_id), is_used)
+        })
+}
+
+/// Returns true if the item is not generated by a plugin, or was copied as is by the plugin from
+/// the code it was generated from. Only such items are reported as unused, as the rest are not
+/// under the user's control.
+pub fn is_user_item(db: &dyn SemanticGroup, module_id: ModuleId, item: ModuleItemId) -> bool {
+    let Ok(name_ptr) = db.module_item_name_stable_ptr(module_id, item) else {
+        return false;
+    };
+    let mut module_file_id = item.module_file_id(db.upcast());
+    let mut span =
+        StableLocation::new(module_file_id, name_ptr).diagnostic_location(db.upcast()).span;
+    loop {
+        let Ok(generated_file_infos) = db.module_generated_file_infos(module_file_id.0) else {
+            return false;
+        };
+        let Some(generated_file_info) = generated_file_infos.get(module_file_id.1 .0) else {
+            return false;
+        };
+        let Some(generated_file_info) = generated_file_info else {
+            return true;
+        };
+        let Some(aux_data) =
+            generated_file_info.aux_data.0.as_any().downcast_ref::<DynPluginAuxData>()
+        else {
+            return false;
+        };
+        let Some(origin_span) = aux_data.map_span(db.elongate(), span) else {
+            return false;
+        };
+        if origin_span.end - origin_span.start != span.end - span.start {
+            // Generated in place of the original code, rather than copied from it.
+            return false;
+        }
+        module_file_id = generated_file_info.origin;
+        span = origin_span;
+    }
+}
+
+/// Maps a span of a module file to the span of the code it originates from, through the plugins
+/// that generated the file. Returns `None` if a plugin does not map the span.
+pub fn origin_span(
+    db: &dyn SemanticGroup,
+    mut module_file_id: ModuleFileId,
+    mut span: TextSpan,
+) -> Option<(ModuleFileId, TextSpan)> {
+    loop {
+        let generated_file_infos = db.module_generated_file_infos(module_file_id.0).ok()?;
+        let Some(generated_file_info) = generated_file_infos.get(module_file_id.1 .0)? else {
+            return Some((module_file_id, span));
+        };
+        let aux_data =
+            generated_file_info.aux_data.0.as_any().downcast_ref::<DynPluginAuxData>()?;
+        span = aux_data.map_span(db.elongate(), span)?;
+        module_file_id = generated_file_info.origin;
+    }
+}
@@ -10,6 +10,7 @@ use cairo_lang_utils::Upcast;
 use smol_str::SmolStr;
 
 use super::generics::semantic_generic_params;
+use super::visibility::Visibility;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::SemanticDiagnostics;
@@ -121,6 +122,8 @@ pub struct StructDefinitionData {
 pub struct Member {
     pub id: MemberId,
     pub ty: semantic::TypeId,
+    #[dont_rewrite]
+    pub visibility: Visibility,
 }
 
 /// Query implementation of [crate::db::SemanticGroup::priv_struct_definition_data].
@@ -155,8 +158,12 @@ pub fn priv_struct_definition_data(
             &mut resolver,
             &member.type_clause(syntax_db).ty(syntax_db),
         );
+        let visibility =
+            Visibility::from_ast(syntax_db, &mut diagnostics, &member.visibility(syntax_db));
         let member_name = member.name(syntax_db).text(syntax_db);
-        if let Some(_other_member) = members.insert(member_name.clone(), Member { id, ty }) {
+        if let Some(_other_member) =
+            members.insert(member_name.clone(), Member { id, ty, visibility })
+        {
             diagnostics.report(&member, StructMemberRedefinition { struct_id, member_name });
         }
     }
@@ -195,7 +202,7 @@ pub fn struct_definition_resolver_data(
     db: &dyn SemanticGroup,
     struct_id: StructId,
 ) -> Maybe<Arc<ResolverData>> {
-    Ok(db.priv_struct_declaration_data(struct_id)?.resolver_data)
+    Ok(db.priv_struct_definition_data(struct_id)?.resolver_data)
 }
 
 pub trait SemanticStructEx<'a>: Upcast<dyn SemanticGroup + 'a> {
@@ -18,13 +18,13 @@ fn test_struct() {
             #[contract(MyImpl1, MyImpl2)]
             struct A {
                 a: felt252,
-                b: (felt252, felt252),
+                pub b: (felt252, felt252),
                 c: (),
                 a: (),
                 a: ()
             }
 
-            fn foo(a: A) {
+            pub fn foo(a: A) {
                 5;
             }
         "},
@@ -43,6 +43,11 @@ fn test_struct() {
             a: ()
             ^***^
 
+        warning: Unused variable. Consider ignoring by prefixing with `_`.
+         --> lib.cairo:10:12
+        pub fn foo(a: A) {
+                   ^
+
         "#}
     );
     let module_id = test_module.module_id;
@@ -61,9 +66,9 @@ fn test_struct() {
     assert_eq!(
         actual,
         indoc! {"
-            a: Member { id: MemberId(test::a), ty: () },
-            b: Member { id: MemberId(test::b), ty: (core::felt252, core::felt252) },
-            c: Member { id: MemberId(test::c), ty: () }"}
+            a: Member { id: MemberId(test::a), ty: (), visibility: Private },
+            b: Member { id: MemberId(test::b), ty: (core::felt252, core::felt252), visibility: Public },
+            c: Member { id: MemberId(test::c), ty: (), visibility: Private }"}
     );
 
     assert_eq!(
@@ -598,6 +598,7 @@ pub fn priv_trait_function_body_data(
         resolver,
         Some(&trait_function_declaration_data.signature),
         environment,
+        None,
     );
     let function_body = match function_syntax.body(db.upcast()) {
         ast::MaybeTraitFunctionBody::Some(expr_block) => expr_block,
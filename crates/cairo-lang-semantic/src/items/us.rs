@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{LanguageElementId, UseId};
+use cairo_lang_defs::ids::{LanguageElementId, ModuleItemId, UseId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -8,6 +8,9 @@ use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
 
+use super::module::{is_used_in_module_tree, is_user_item};
+use super::visibility::Visibility;
+use crate::corelib::core_module;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
@@ -126,6 +129,37 @@ pub fn use_resolver_data(db: &dyn SemanticGroup, use_id: UseId) -> Maybe<Arc<Res
     Ok(db.priv_use_semantic_data(use_id)?.resolver_data)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::use_is_unused].
+pub fn use_is_unused(db: &dyn SemanticGroup, use_id: UseId) -> bool {
+    let module_id = use_id.module_file_id(db.upcast()).0;
+    // The uses of the core crate root are the prelude of every crate, e.g. for impl inference.
+    if module_id == core_module(db) {
+        return false;
+    }
+    let item = ModuleItemId::Use(use_id);
+    let Ok(Some(item_info)) = db.module_item_info_by_name(module_id, use_id.name(db.upcast()))
+    else {
+        return false;
+    };
+    // Public uses are part of the interface of their module.
+    if item_info.item_id != item
+        || item_info.visibility == Visibility::Public
+        || !is_user_item(db, module_id, item)
+    {
+        return false;
+    }
+    // Failing uses are already reported.
+    let Ok(resolved_item) = db.use_resolved_item(use_id) else {
+        return false;
+    };
+    !is_used_in_module_tree(db, module_id, &|used_items| {
+        used_items.uses.contains(&use_id)
+            // Impls are used implicitly, by impl inference.
+            || matches!(resolved_item, ResolvedGenericItem::Impl(impl_def_id)
+                if used_items.impls.contains(&impl_def_id))
+    })
+}
+
 pub trait SemanticUseEx<'a>: Upcast<dyn SemanticGroup + 'a> {
     /// Returns the resolved items.
     ///
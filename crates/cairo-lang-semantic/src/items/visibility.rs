@@ -0,0 +1,77 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::Terminal;
+
+use crate::diagnostic::SemanticDiagnosticKind::*;
+use crate::diagnostic::SemanticDiagnostics;
+
+#[cfg(test)]
+#[path = "visibility_test.rs"]
+mod test;
+
+/// The visibility of a module item or a struct member.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Visibility {
+    /// `pub` - visible from any crate.
+    Public,
+    /// `pub(crate)` - visible only from within the crate of the item.
+    PublicInCrate,
+    /// No visibility modifier - visible only from within the module of the item and its
+    /// submodules.
+    #[default]
+    Private,
+}
+impl Visibility {
+    pub fn from_ast(
+        db: &dyn SyntaxGroup,
+        diagnostics: &mut SemanticDiagnostics,
+        visibility: &ast::Visibility,
+    ) -> Self {
+        match visibility {
+            ast::Visibility::Default(_) => Self::Private,
+            ast::Visibility::Pub(visibility_pub) => match visibility_pub.argument_clause(db) {
+                ast::OptionVisibilityPubArgumentClause::Empty(_) => Self::Public,
+                ast::OptionVisibilityPubArgumentClause::VisibilityPubArgumentClause(clause) => {
+                    let argument = clause.argument(db);
+                    if argument.text(db) != "crate" {
+                        diagnostics.report(&argument, UnsupportedPubArgument);
+                    }
+                    Self::PublicInCrate
+                }
+            },
+        }
+    }
+}
+
+/// Returns whether an item with the given visibility, defined in `containing_module_id`, is
+/// visible from `user_module_id`.
+pub fn peek_visible_in(
+    db: &dyn DefsGroup,
+    visibility: Visibility,
+    containing_module_id: ModuleId,
+    user_module_id: ModuleId,
+) -> bool {
+    match visibility {
+        Visibility::Public => true,
+        Visibility::PublicInCrate => {
+            containing_module_id.owning_crate(db) == user_module_id.owning_crate(db)
+        }
+        Visibility::Private => {
+            // Private items are visible from their module and its descendants.
+            let mut module_id = user_module_id;
+            loop {
+                if module_id == containing_module_id {
+                    return true;
+                }
+                match module_id {
+                    ModuleId::CrateRoot(_) => return false,
+                    ModuleId::Submodule(submodule_id) => {
+                        module_id = submodule_id.parent_module(db);
+                    }
+                }
+            }
+        }
+    }
+}
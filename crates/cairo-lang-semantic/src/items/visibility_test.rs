@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
+use cairo_lang_utils::{extract_matches, Upcast};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use crate::db::SemanticGroup;
+use crate::test_utils::{setup_test_crate, SemanticDatabaseForTesting};
+
+#[test]
+fn test_visibility_across_crates() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+
+    let lib_crate_id = db.intern_crate(CrateLongId("lib".into()));
+    db.set_crate_root(lib_crate_id, Some(Directory("lib_src".into())));
+    let lib_file_id = db.intern_file(FileLongId::OnDisk("lib_src/lib.cairo".into()));
+    db.as_files_group_mut().override_file_content(
+        lib_file_id,
+        Some(Arc::new(
+            indoc! {"
+                pub mod inner {
+                    pub fn public_fn() {}
+                    pub(crate) fn crate_fn() {}
+                    fn private_fn() {}
+                    pub(super) fn bad_argument_fn() {}
+                    pub struct S {
+                        pub a: felt252,
+                        pub(crate) b: felt252,
+                        c: felt252,
+                    }
+                    fn use_parent_item() {
+                        super::root_private_fn();
+                    }
+                }
+                pub use inner::public_fn as reexported_fn;
+                use inner::public_fn as hidden_fn;
+
+                fn root_private_fn() {}
+
+                pub fn use_in_crate(s: inner::S) -> felt252 {
+                    inner::crate_fn();
+                    inner::private_fn();
+                    s.b + s.c
+                }
+            "}
+            .into(),
+        )),
+    );
+
+    let test_crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            pub fn foo(s: lib::inner::S) -> felt252 {
+                lib::inner::public_fn();
+                lib::reexported_fn();
+                lib::inner::crate_fn();
+                lib::inner::private_fn();
+                lib::hidden_fn();
+                let lib::inner::S { a, b: _, c: _ } = lib::inner::S { a: 1, b: 2, c: 3 };
+                a + s.a + s.b + s.c
+            }
+        "},
+    );
+
+    // `pub(crate)` items are visible from within their own crate, while private items are visible
+    // only from within their module and its submodules.
+    let lib_module_id = ModuleId::CrateRoot(lib_crate_id);
+    assert_eq!(
+        db.module_semantic_diagnostics(lib_module_id).unwrap().format(Upcast::upcast(db)),
+        indoc! {r#"
+            warning: Unused import: `lib::hidden_fn`
+             --> lib.cairo:16:12
+            use inner::public_fn as hidden_fn;
+                       ^********************^
+
+            error: Item "private_fn" is not visible in this context.
+             --> lib.cairo:22:12
+                inner::private_fn();
+                       ^********^
+
+            error: Member "c" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:23:13
+                s.b + s.c
+                        ^
+
+        "#}
+    );
+    let inner_module_id = ModuleId::Submodule(extract_matches!(
+        db.module_item_by_name(lib_module_id, "inner".into()).unwrap().unwrap(),
+        ModuleItemId::Submodule
+    ));
+    assert_eq!(
+        db.module_semantic_diagnostics(inner_module_id).unwrap().format(Upcast::upcast(db)),
+        indoc! {r#"
+            warning: Unused function: `lib::inner::private_fn`
+             --> lib.cairo:4:8
+                fn private_fn() {}
+                   ^********^
+
+            warning: Unused function: `lib::inner::use_parent_item`
+             --> lib.cairo:11:8
+                fn use_parent_item() {
+                   ^*************^
+
+            error: Unsupported visibility argument. Only `pub(crate)` is supported.
+             --> lib.cairo:5:9
+                pub(super) fn bad_argument_fn() {}
+                    ^***^
+
+        "#}
+    );
+
+    let test_diagnostics = db
+        .module_semantic_diagnostics(ModuleId::CrateRoot(test_crate_id))
+        .unwrap()
+        .format(Upcast::upcast(db));
+    assert_eq!(
+        test_diagnostics,
+        indoc! {r#"
+            error: Item "crate_fn" is not visible in this context.
+             --> lib.cairo:4:17
+                lib::inner::crate_fn();
+                            ^******^
+
+            error: Item "private_fn" is not visible in this context.
+             --> lib.cairo:5:17
+                lib::inner::private_fn();
+                            ^********^
+
+            error: Item "hidden_fn" is not visible in this context.
+             --> lib.cairo:6:10
+                lib::hidden_fn();
+                     ^*******^
+
+            error: Member "b" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:7:65
+                let lib::inner::S { a, b: _, c: _ } = lib::inner::S { a: 1, b: 2, c: 3 };
+                                                                            ^
+
+            error: Member "c" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:7:71
+                let lib::inner::S { a, b: _, c: _ } = lib::inner::S { a: 1, b: 2, c: 3 };
+                                                                                  ^
+
+            error: Member "b" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:7:9
+                let lib::inner::S { a, b: _, c: _ } = lib::inner::S { a: 1, b: 2, c: 3 };
+                    ^*****************************^
+
+            error: Member "c" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:7:9
+                let lib::inner::S { a, b: _, c: _ } = lib::inner::S { a: 1, b: 2, c: 3 };
+                    ^*****************************^
+
+            error: Member "b" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:8:17
+                a + s.a + s.b + s.c
+                            ^
+
+            error: Member "c" of struct "lib::inner::S" is not visible in this context.
+             --> lib.cairo:8:23
+                a + s.a + s.b + s.c
+                                  ^
+
+        "#}
+    );
+}
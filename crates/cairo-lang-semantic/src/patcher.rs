@@ -1,4 +1,5 @@
 use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::plugin::InlineMacroCodePiece;
 use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
@@ -197,6 +198,10 @@ impl Patches {
     pub fn translate(&self, _db: &dyn DefsGroup, span: TextSpan) -> Option<TextSpan> {
         for Patch { span: patch_span, origin_span } in &self.patches {
             if patch_span.contains(span) {
+                if patch_span.end - patch_span.start != origin_span.end - origin_span.start {
+                    // The patch is generated code, so it can only be mapped as a whole.
+                    return Some(*origin_span);
+                }
                 let start = origin_span.start.add_width(span.start - patch_span.start);
                 return Some(TextSpan { start, end: start.add_width(span.end - span.start) });
             }
@@ -250,6 +255,31 @@ impl<'a> PatchBuilder<'a> {
         self.code += node.get_text(self.db).as_str();
     }
 
+    /// Adds the code of the given pieces, generated in place of the given node. The nodes of the
+    /// pieces are added by `add_node`, e.g. to expand the inline macros nested in them.
+    /// Diagnostics in the nodes of the pieces are mapped to these nodes, and other diagnostics in
+    /// the generated code are mapped to the origin node, without its trivia.
+    pub fn add_inline_macro_code(
+        &mut self,
+        pieces: &[InlineMacroCodePiece],
+        origin: &SyntaxNode,
+        mut add_node: impl FnMut(&mut Self, SyntaxNode),
+    ) {
+        let start = TextOffset::default().add_width(TextWidth::from_str(&self.code));
+        for piece in pieces {
+            match piece {
+                InlineMacroCodePiece::Text(text) => self.add_str(text),
+                InlineMacroCodePiece::Node(node) => add_node(self, node.clone()),
+            }
+        }
+        let end = TextOffset::default().add_width(TextWidth::from_str(&self.code));
+        // Added after the patches of the nodes, so that their translation takes precedence.
+        self.patches.patches.push(Patch {
+            span: TextSpan { start, end },
+            origin_span: origin.span_without_trivia(self.db),
+        });
+    }
+
     fn add_trimmed_node(&mut self, node: SyntaxNode, trim_left: bool, trim_right: bool) {
         let TextSpan { start: trimmed_start, end: trimmed_end } = node.span_without_trivia(self.db);
         let orig_start = if trim_left { trimmed_start } else { node.span(self.db).start };
@@ -27,6 +27,10 @@ pub trait PluginAuxData:
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn Any,
     ) -> Option<PluginMappedDiagnostic>;
+    /// Maps a span of the generated file to the span of the code it was generated from, if known.
+    fn map_span(&self, _db: &(dyn SemanticGroup + 'static), _span: TextSpan) -> Option<TextSpan> {
+        None
+    }
 }
 pub trait AsDynGeneratedFileAuxData {
     fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static);
@@ -124,7 +124,9 @@ impl ResolvedConcreteItem {
                 ImplId::Concrete(concrete_impl_id) => ResolvedGenericItem::Impl(
                     db.lookup_intern_concrete_impl(*concrete_impl_id).impl_def_id,
                 ),
-                ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+                ImplId::GenericParameter(_) | ImplId::ImplVar(_) | ImplId::GeneratedImpl(_) => {
+                    return None;
+                }
             },
         })
     }
@@ -2,7 +2,7 @@ use std::iter::Peekable;
 use std::ops::{Deref, DerefMut, Neg};
 
 use cairo_lang_defs::ids::{
-    GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId, ModuleId, TraitId,
+    GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId, ModuleId, ModuleItemId, TraitId,
 };
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateLongId;
@@ -13,6 +13,7 @@ use cairo_lang_syntax::node::helpers::PathSegmentEx;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 pub use item::{ResolvedConcreteItem, ResolvedGenericItem};
@@ -30,7 +31,9 @@ use crate::expr::inference::{Inference, InferenceData};
 use crate::items::enm::SemanticEnumEx;
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
 use crate::items::imp::{ConcreteImplId, ConcreteImplLongId, ImplId, ImplLookupContext};
+use crate::items::module::ModuleItemInfo;
 use crate::items::trt::{ConcreteTraitGenericFunctionLongId, ConcreteTraitId, ConcreteTraitLongId};
+use crate::items::visibility::peek_visible_in;
 use crate::literals::LiteralLongId;
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::types::resolve_type;
@@ -95,6 +98,8 @@ pub struct ResolverData {
     pub resolved_items: ResolvedItems,
     /// Inference data for the resolver.
     pub inference_data: InferenceData,
+    /// The module items through which paths were resolved. Used for reporting unused items.
+    pub used_items: OrderedHashSet<ModuleItemId>,
 }
 impl ResolverData {
     pub fn new(module_file_id: ModuleFileId) -> Self {
@@ -103,6 +108,7 @@ impl ResolverData {
             generic_params: Default::default(),
             resolved_items: Default::default(),
             inference_data: Default::default(),
+            used_items: Default::default(),
         }
     }
 }
@@ -149,6 +155,7 @@ impl<'db> Resolver<'db> {
                 generic_params: Default::default(),
                 resolved_items: Default::default(),
                 inference_data: Default::default(),
+                used_items: Default::default(),
             },
         }
     }
@@ -406,11 +413,14 @@ impl<'db> Resolver<'db> {
                 if ident == "super" {
                     return Err(diagnostics.report(identifier, InvalidPath));
                 }
-                let module_item = self
+                let item_info = self
                     .db
-                    .module_item_by_name(*module_id, ident)?
+                    .module_item_info_by_name(*module_id, ident)?
                     .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
-                let generic_item = ResolvedGenericItem::from_module_item(self.db, module_item)?;
+                self.validate_item_visibility(diagnostics, *module_id, identifier, &item_info)?;
+                self.data.used_items.insert(item_info.item_id);
+                let generic_item =
+                    ResolvedGenericItem::from_module_item(self.db, item_info.item_id)?;
                 Ok(self.specialize_generic_module_item(
                     diagnostics,
                     identifier,
@@ -598,11 +608,13 @@ impl<'db> Resolver<'db> {
         let ident = identifier.text(syntax_db);
         match item {
             ResolvedGenericItem::Module(module_id) => {
-                let module_item = self
+                let item_info = self
                     .db
-                    .module_item_by_name(*module_id, ident)?
+                    .module_item_info_by_name(*module_id, ident)?
                     .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
-                ResolvedGenericItem::from_module_item(self.db, module_item)
+                self.validate_item_visibility(diagnostics, *module_id, identifier, &item_info)?;
+                self.data.used_items.insert(item_info.item_id);
+                ResolvedGenericItem::from_module_item(self.db, item_info.item_id)
             }
             ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id)) => {
                 let variants = self.db.enum_variants(*enum_id)?;
@@ -619,6 +631,26 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Reports a diagnostic if a module item is not visible from the current module.
+    fn validate_item_visibility(
+        &self,
+        diagnostics: &mut SemanticDiagnostics,
+        containing_module_id: ModuleId,
+        identifier: &ast::TerminalIdentifier,
+        item_info: &ModuleItemInfo,
+    ) -> Maybe<()> {
+        if peek_visible_in(
+            self.db.upcast(),
+            item_info.visibility,
+            containing_module_id,
+            self.module_file_id.0,
+        ) {
+            return Ok(());
+        }
+        let item_name = identifier.text(self.db.upcast());
+        Err(diagnostics.report(identifier, ItemNotVisible { item_name }))
+    }
+
     /// Determines whether the first identifier of a path is a local item.
     fn determine_base_item_in_local_scope(
         &mut self,
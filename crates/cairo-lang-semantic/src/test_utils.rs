@@ -15,6 +15,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{extract_matches, OptionFrom, Upcast};
 
 use crate::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
+use crate::diagnostic::SemanticDiagnosticKind;
 use crate::items::functions::GenericFunctionId;
 use crate::{semantic, ConcreteFunctionWithBodyId, SemanticDiagnostic};
 
@@ -28,6 +29,7 @@ impl Default for SemanticDatabaseForTesting {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         res.set_semantic_plugins(vec![]);
+        res.set_inline_macro_plugins(Arc::new(OrderedHashMap::default()));
         let corelib_path = detect_corelib().expect("Corelib not found in default location.");
         init_dev_corelib(&mut res, corelib_path);
         res
@@ -67,11 +69,14 @@ impl HasMacroPlugins for SemanticDatabaseForTesting {
 pub struct WithStringDiagnostics<T> {
     value: T,
     diagnostics: String,
+    /// The number of diagnostics of [cairo_lang_diagnostics::Severity::Error] in `diagnostics`.
+    error_count: usize,
 }
 impl<T> WithStringDiagnostics<T> {
-    /// Verifies that there are no diagnostics (fails otherwise), and returns the inner value.
+    /// Verifies that there are no errors (fails otherwise), and returns the inner value.
+    /// Warnings are ignored.
     pub fn unwrap(self) -> T {
-        assert!(self.diagnostics.is_empty(), "Unexpected diagnostics:\n{}", self.diagnostics);
+        assert_eq!(self.error_count, 0, "Unexpected diagnostics:\n{}", self.diagnostics);
         self.value
     }
 
@@ -80,6 +85,11 @@ impl<T> WithStringDiagnostics<T> {
         (self.value, self.diagnostics)
     }
 
+    /// Returns the inner value and the diagnostics, keeping the count of errors among them.
+    fn split_with_error_count(self) -> (T, String, usize) {
+        (self.value, self.diagnostics, self.error_count)
+    }
+
     /// Returns the diagnostics (as a string).
     pub fn get_diagnostics(self) -> String {
         self.diagnostics
@@ -106,17 +116,41 @@ pub fn setup_test_crate(db: &mut (dyn SemanticGroup + 'static), content: &str) -
 pub fn setup_test_module(
     db: &mut (dyn SemanticGroup + 'static),
     content: &str,
+) -> WithStringDiagnostics<TestModule> {
+    setup_test_module_with_entry_point(db, content, None)
+}
+
+/// Sets up a module with given content, and returns its module id. The free function named
+/// `entry_point`, if any, is called by the test, so it is not reported as unused.
+fn setup_test_module_with_entry_point(
+    db: &mut (dyn SemanticGroup + 'static),
+    content: &str,
+    entry_point: Option<&str>,
 ) -> WithStringDiagnostics<TestModule> {
     let crate_id = setup_test_crate(db, content);
     let module_id = ModuleId::CrateRoot(crate_id);
     let file_id = db.module_main_file(module_id).unwrap();
 
-    let syntax_diagnostics = db.file_syntax_diagnostics(file_id).format(Upcast::upcast(db));
-    let semantic_diagnostics = db.module_semantic_diagnostics(module_id).unwrap().format(db);
+    let syntax_diagnostics = db.file_syntax_diagnostics(file_id);
+    let mut semantic_diagnostics = DiagnosticsBuilder::default();
+    for diagnostic in db.module_semantic_diagnostics(module_id).unwrap().get_all() {
+        if let SemanticDiagnosticKind::UnusedFunction(free_function_id) = diagnostic.kind {
+            if Some(free_function_id.name(Upcast::upcast(db)).as_str()) == entry_point {
+                continue;
+            }
+        }
+        semantic_diagnostics.add(diagnostic);
+    }
+    let semantic_diagnostics = semantic_diagnostics.build();
 
     WithStringDiagnostics {
         value: TestModule { crate_id, module_id },
-        diagnostics: format!("{syntax_diagnostics}{semantic_diagnostics}"),
+        diagnostics: format!(
+            "{}{}",
+            syntax_diagnostics.format(Upcast::upcast(db)),
+            semantic_diagnostics.format(db)
+        ),
+        error_count: syntax_diagnostics.error_count() + semantic_diagnostics.error_count(),
     }
 }
 
@@ -143,7 +177,9 @@ pub fn setup_test_function(
     } else {
         format!("{module_code}\n{function_code}")
     };
-    let (test_module, diagnostics) = setup_test_module(db, &content).split();
+    let (test_module, diagnostics, error_count) =
+        setup_test_module_with_entry_point(db, &content, Some(function_name))
+            .split_with_error_count();
     let generic_function_id = db
         .module_item_by_name(test_module.module_id, function_name.into())
         .expect("Failed to load module")
@@ -164,6 +200,7 @@ pub fn setup_test_function(
             body: db.function_body_expr(function_id).unwrap(),
         },
         diagnostics,
+        error_count,
     }
 }
 
@@ -186,8 +223,8 @@ pub fn setup_test_expr(
     function_body: &str,
 ) -> WithStringDiagnostics<TestExpr> {
     let function_code = format!("fn test_func() {{ {function_body} {{\n{expr_code}\n}}; }}");
-    let (test_function, diagnostics) =
-        setup_test_function(db, &function_code, "test_func", module_code).split();
+    let (test_function, diagnostics, error_count) =
+        setup_test_function(db, &function_code, "test_func", module_code).split_with_error_count();
     let semantic::ExprBlock { statements, .. } = extract_matches!(
         db.expr_semantic(test_function.function_id, test_function.body),
         semantic::Expr::Block
@@ -213,6 +250,7 @@ pub fn setup_test_expr(
             expr_id: tail.unwrap(),
         },
         diagnostics,
+        error_count,
     }
 }
 
@@ -260,6 +298,18 @@ pub fn test_function_diagnostics(
     )])
 }
 
+/// Tests the diagnostics of all the modules of a crate.
+pub fn test_crate_diagnostics(
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let db = &mut SemanticDatabaseForTesting::default();
+    let crate_id = setup_test_crate(db, inputs["crate_code"].as_str());
+    OrderedHashMap::from([(
+        "expected_diagnostics".into(),
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+    )])
+}
+
 /// Gets the diagnostics for all the modules (including nested) in the given crate.
 pub fn get_crate_semantic_diagnostics(
     db: &dyn SemanticGroup,
@@ -1,4 +1,5 @@
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{EnumId, ExternTypeId, GenericParamId, GenericTypeId, StructId};
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_proc_macros::SemanticObject;
@@ -15,9 +16,10 @@ use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::{InferenceResult, TypeVar};
+use crate::items::functions::ConcreteFunctionWithBodyId;
 use crate::items::imp::{get_impl_at_context, ImplId, ImplLookupContext};
 use crate::resolve::{ResolvedConcreteItem, Resolver};
-use crate::{semantic, semantic_object_for_id};
+use crate::{semantic, semantic_object_for_id, ExprId};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum TypeLongId {
@@ -28,6 +30,7 @@ pub enum TypeLongId {
     Snapshot(TypeId),
     GenericParameter(GenericParamId),
     Var(TypeVar),
+    Closure(ClosureTypeLongId),
     Missing(#[dont_rewrite] DiagnosticAdded),
 }
 impl OptionFrom<TypeLongId> for ConcreteTypeId {
@@ -87,6 +90,7 @@ impl TypeLongId {
                 format!("{}", generic_param.name(db.upcast()))
             }
             TypeLongId::Var(var) => format!("?{}", var.id.0),
+            TypeLongId::Closure(closure) => closure.format(db),
             TypeLongId::Missing(_) => "<missing>".to_string(),
         }
     }
@@ -97,6 +101,7 @@ impl TypeLongId {
             TypeLongId::Concrete(concrete) => TypeHead::Concrete(concrete.generic_type(db)),
             TypeLongId::Tuple(_) => TypeHead::Tuple,
             TypeLongId::Snapshot(inner) => TypeHead::Snapshot(Box::new(inner.head(db)?)),
+            TypeLongId::Closure(_) => TypeHead::Closure,
             TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
                 return None;
             }
@@ -121,6 +126,36 @@ pub enum TypeHead {
     Concrete(GenericTypeId),
     Snapshot(Box<TypeHead>),
     Tuple,
+    Closure,
+}
+
+/// The type of a closure expression. Every closure expression has its own unique type, which is
+/// identified by the function containing it and by its body expression.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ClosureTypeLongId {
+    pub param_tys: Vec<TypeId>,
+    pub ret_ty: TypeId,
+    /// The types of the variables captured by the closure, in the order of their capture.
+    pub captured_types: Vec<TypeId>,
+    /// The function in which the closure is defined.
+    pub parent_function: ConcreteFunctionWithBodyId,
+    /// The body of the closure, an expression of `parent_function`.
+    #[dont_rewrite]
+    pub body: ExprId,
+    #[dont_rewrite]
+    pub wrapper_location: StableLocation,
+}
+impl ClosureTypeLongId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        let location = self.wrapper_location.diagnostic_location(db.upcast());
+        let file_name = location.file_id.file_name(db.upcast());
+        match location.span.start.position_in_file(db.upcast(), location.file_id) {
+            Some(position) => {
+                format!("{{closure@{}:{}:{}}}", file_name, position.line + 1, position.col + 1)
+            }
+            None => format!("{{closure@{file_name}}}"),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
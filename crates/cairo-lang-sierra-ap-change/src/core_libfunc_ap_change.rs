@@ -2,6 +2,7 @@ use cairo_lang_sierra::extensions::ap_tracking::ApTrackingConcreteLibfunc;
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
 use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
 use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc;
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
@@ -15,6 +16,7 @@ use cairo_lang_sierra::extensions::felt252_dict::{
 use cairo_lang_sierra::extensions::gas::{
     BuiltinCostWithdrawGasLibfunc, CostTokenType, GasConcreteLibfunc,
 };
+use cairo_lang_sierra::extensions::int::signed::{Sint128Concrete, SintConcrete, SintMulTraits};
 use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintMulTraits};
 use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
@@ -93,6 +95,14 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             BoxConcreteLibfunc::Into(_) => vec![ApChange::Known(1)],
             BoxConcreteLibfunc::Unbox(_) => vec![ApChange::Known(0)],
         },
+        CoreConcreteLibfunc::Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![ApChange::Known(0)]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+                vec![ApChange::Known(5), ApChange::Known(6)]
+            }
+        },
         CoreConcreteLibfunc::Cast(libfunc) => match libfunc {
             CastConcreteLibfunc::Downcast(_) => vec![ApChange::Known(2), ApChange::Known(2)],
             CastConcreteLibfunc::Upcast(_) => vec![ApChange::Known(0)],
@@ -172,6 +182,22 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
         CoreConcreteLibfunc::Uint512(libfunc) => match libfunc {
             Uint512Concrete::DivModU256(_) => vec![ApChange::Known(47)],
         },
+        CoreConcreteLibfunc::Sint8(libfunc) => sint_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint16(libfunc) => sint_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint32(libfunc) => sint_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint64(libfunc) => sint_ap_change(libfunc),
+        CoreConcreteLibfunc::Sint128(libfunc) => match libfunc {
+            Sint128Concrete::Operation(_) => {
+                vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+            }
+            Sint128Concrete::Diff(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+            Sint128Concrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+            Sint128Concrete::FromFelt252(_) => vec![ApChange::Known(2), ApChange::Known(8)],
+            Sint128Concrete::Const(_) | Sint128Concrete::ToFelt252(_) => {
+                vec![ApChange::Known(0)]
+            }
+            Sint128Concrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+        },
         CoreConcreteLibfunc::Mem(libfunc) => match libfunc {
             MemConcreteLibfunc::StoreTemp(libfunc) => {
                 vec![ApChange::Known(info_provider.type_size(&libfunc.ty))]
@@ -244,6 +270,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             }
             StarkNetConcreteLibfunc::Testing(libfunc) => match libfunc {
                 TestingConcreteLibfunc::PopLog(_) => vec![ApChange::Known(5), ApChange::Known(5)],
+                TestingConcreteLibfunc::Load(_) => vec![ApChange::Known(1)],
                 _ => vec![ApChange::Known(0)],
             },
         },
@@ -263,6 +290,23 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
     }
 }
 
+/// Returns the ap changes for i8/i16/i32/i64 libfuncs.
+fn sint_ap_change<TSintTraits: SintMulTraits + IsZeroTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+) -> Vec<ApChange> {
+    match libfunc {
+        SintConcrete::Const(_) | SintConcrete::ToFelt252(_) => vec![ApChange::Known(0)],
+        SintConcrete::Operation(_) => {
+            vec![ApChange::Known(4), ApChange::Known(6), ApChange::Known(6)]
+        }
+        SintConcrete::Diff(_) => vec![ApChange::Known(2), ApChange::Known(3)],
+        SintConcrete::Equal(_) => vec![ApChange::Known(1), ApChange::Known(1)],
+        SintConcrete::FromFelt252(_) => vec![ApChange::Known(3), ApChange::Known(8)],
+        SintConcrete::IsZero(_) => vec![ApChange::Known(0), ApChange::Known(0)],
+        SintConcrete::WideMul(_) => vec![ApChange::Known(0)],
+    }
+}
+
 /// Returns the ap changes for u8/u16/u32/u64 libfuncs.
 fn uint_ap_change<TUintTraits: UintMulTraits + IsZeroTraits>(
     libfunc: &UintConcrete<TUintTraits>,
@@ -3,6 +3,7 @@ use std::iter;
 use cairo_lang_sierra::extensions::array::ArrayConcreteLibfunc;
 use cairo_lang_sierra::extensions::boolean::BoolConcreteLibfunc;
 use cairo_lang_sierra::extensions::boxing::BoxConcreteLibfunc;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
 use cairo_lang_sierra::extensions::casts::CastConcreteLibfunc;
 use cairo_lang_sierra::extensions::core::CoreConcreteLibfunc::{self, *};
 use cairo_lang_sierra::extensions::ec::EcConcreteLibfunc;
@@ -18,6 +19,7 @@ use cairo_lang_sierra::extensions::gas::GasConcreteLibfunc::{
     BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
 use cairo_lang_sierra::extensions::gas::{BuiltinCostWithdrawGasLibfunc, CostTokenType};
+use cairo_lang_sierra::extensions::int::signed::{Sint128Concrete, SintConcrete, SintMulTraits};
 use cairo_lang_sierra::extensions::int::unsigned::{UintConcrete, UintMulTraits};
 use cairo_lang_sierra::extensions::int::unsigned128::Uint128Concrete;
 use cairo_lang_sierra::extensions::int::unsigned256::Uint256Concrete;
@@ -233,6 +235,11 @@ pub fn core_libfunc_cost(
         Uint128(libfunc) => u128_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint256(libfunc) => u256_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Uint512(libfunc) => u512_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint8(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint16(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint32(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint64(libfunc) => sint_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
+        Sint128(libfunc) => s128_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect(),
         Felt252(libfunc) => {
             felt252_libfunc_cost(libfunc).into_iter().map(BranchCost::from).collect()
         }
@@ -247,6 +254,15 @@ pub fn core_libfunc_cost(
             }
             BoxConcreteLibfunc::Unbox(_) => vec![steps(0).into()],
         },
+        Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(_) | Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                vec![steps(0).into()]
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => vec![
+                ConstCost { steps: 7, holes: 0, range_checks: 3 }.into(),
+                ConstCost { steps: 9, holes: 0, range_checks: 3 }.into(),
+            ],
+        },
         Mem(libfunc) => match libfunc {
             StoreTemp(libfunc) => vec![steps(info_provider.type_size(&libfunc.ty) as i32).into()],
             StoreLocal(libfunc) => {
@@ -490,6 +506,74 @@ fn uint_libfunc_cost<TUintTraits: IsZeroTraits + UintMulTraits>(
     }
 }
 
+/// Returns costs for i8/i16/i32/i64 libfuncs.
+fn sint_libfunc_cost<TSintTraits: IsZeroTraits + SintMulTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+) -> Vec<ConstCost> {
+    let steps = |value| ConstCost { steps: value, ..Default::default() };
+    match libfunc {
+        SintConcrete::Const(_) | SintConcrete::ToFelt252(_) | SintConcrete::WideMul(_) => {
+            vec![steps(0)]
+        }
+        SintConcrete::Operation(_) => {
+            vec![
+                ConstCost { steps: 5, holes: 0, range_checks: 1 },
+                ConstCost { steps: 7, holes: 0, range_checks: 1 },
+                ConstCost { steps: 7, holes: 0, range_checks: 1 },
+            ]
+        }
+        SintConcrete::Diff(_) => {
+            vec![
+                ConstCost { steps: 3, holes: 0, range_checks: 1 },
+                ConstCost { steps: 5, holes: 0, range_checks: 1 },
+            ]
+        }
+        SintConcrete::Equal(_) => {
+            vec![steps(2), steps(3)]
+        }
+        SintConcrete::FromFelt252(_) => {
+            vec![
+                ConstCost { steps: 5, holes: 0, range_checks: 2 },
+                ConstCost { steps: 13, holes: 0, range_checks: 3 },
+            ]
+        }
+        SintConcrete::IsZero(_) => vec![steps(1), steps(1)],
+    }
+}
+
+/// Returns costs for i128 libfuncs.
+fn s128_libfunc_cost(libfunc: &Sint128Concrete) -> Vec<ConstCost> {
+    let steps = |value| ConstCost { steps: value, ..Default::default() };
+    match libfunc {
+        Sint128Concrete::Const(_) | Sint128Concrete::ToFelt252(_) => {
+            vec![steps(0)]
+        }
+        Sint128Concrete::Operation(_) => {
+            vec![
+                ConstCost { steps: 5, holes: 0, range_checks: 1 },
+                ConstCost { steps: 7, holes: 0, range_checks: 1 },
+                ConstCost { steps: 7, holes: 0, range_checks: 1 },
+            ]
+        }
+        Sint128Concrete::Diff(_) => {
+            vec![
+                ConstCost { steps: 3, holes: 0, range_checks: 1 },
+                ConstCost { steps: 5, holes: 0, range_checks: 1 },
+            ]
+        }
+        Sint128Concrete::Equal(_) => {
+            vec![steps(2), steps(3)]
+        }
+        Sint128Concrete::FromFelt252(_) => {
+            vec![
+                ConstCost { steps: 3, holes: 0, range_checks: 1 },
+                ConstCost { steps: 13, holes: 0, range_checks: 3 },
+            ]
+        }
+        Sint128Concrete::IsZero(_) => vec![steps(1), steps(1)],
+    }
+}
+
 /// Returns costs for u128 libfuncs.
 fn u128_libfunc_cost(libfunc: &Uint128Concrete) -> Vec<BranchCost> {
     let steps = |value| ConstCost { steps: value, ..Default::default() };
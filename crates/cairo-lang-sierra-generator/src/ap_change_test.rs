@@ -22,7 +22,7 @@ fn contains_cycles_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
 
     db.module_lowering_diagnostics(test_module.module_id)
         .unwrap()
-        .expect_with_db(db, "Unexpected diagnostics.");
+        .expect_no_errors(db, "Unexpected diagnostics.");
 
     let result = db
         .module_free_functions(test_module.module_id)
@@ -31,7 +31,8 @@ pub fn generate_block_body_code(
     block_id: lowering::BlockId,
     block: &lowering::FlatBlock,
 ) -> Maybe<Vec<pre_sierra::Statement>> {
-    let mut statements: Vec<pre_sierra::Statement> = vec![];
+    // The statements added before the lowering statements have no known location.
+    let mut statements: Vec<pre_sierra::Statement> = vec![pre_sierra::Statement::SetLocation(None)];
 
     if context.should_disable_ap_tracking(&block_id) {
         context.set_ap_tracking(false);
@@ -54,6 +55,7 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        statements.push(pre_sierra::Statement::SetLocation(statement.location().cloned()));
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -104,6 +106,10 @@ pub fn generate_block_code(
     let statement_location: StatementLocation = (block_id, block.statements.len());
 
     let mut statements = generate_block_body_code(context, block_id, block)?;
+    statements.push(pre_sierra::Statement::SetLocation(match &block.end {
+        lowering::FlatBlockEnd::Match { info } => Some(info.location().clone()),
+        _ => None,
+    }));
 
     match &block.end {
         lowering::FlatBlockEnd::Return(returned_variables) => {
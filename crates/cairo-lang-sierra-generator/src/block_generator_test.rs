@@ -17,6 +17,7 @@ use lowering::ids::ConcreteFunctionWithBodyId;
 use super::generate_block_code;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::lifetime::find_variable_lifetime;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -85,6 +86,9 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
 
     let statements = generate_block_code(&mut expr_generator_context, BlockId::root()).unwrap();
     for statement in &statements {
+        if matches!(statement, pre_sierra::Statement::SetLocation(_)) {
+            continue;
+        }
         expected_sierra_code.push_str(&replace_sierra_ids(db, statement).to_string());
         expected_sierra_code.push('\n');
     }
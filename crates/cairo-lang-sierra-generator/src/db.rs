@@ -1,12 +1,15 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::panic::PanicSignatureInfo;
+use cairo_lang_sierra::debug_info::SourceSpan;
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::{ConcreteType, GenericTypeEx};
 use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_utils::Upcast;
 use lowering::ids::ConcreteFunctionWithBodyId;
 use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
@@ -15,6 +18,9 @@ use crate::program_generator::{self};
 use crate::specialization_context::SierraSignatureSpecializationContext;
 use crate::{ap_change, function_generator, pre_sierra};
 
+/// The source spans of Sierra statements, see [cairo_lang_sierra::debug_info::DebugInfo].
+pub type StatementsLocations = HashMap<StatementIdx, Vec<SourceSpan>>;
+
 #[salsa::query_group(SierraGenDatabase)]
 pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
     #[salsa::interned]
@@ -86,6 +92,14 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
 
+    /// Returns the source spans of the statements of the program returned by
+    /// [SierraGenGroup::get_sierra_program_for_functions] for the requested functions.
+    #[salsa::invoke(program_generator::get_sierra_program_statements_locations)]
+    fn get_sierra_program_statements_locations(
+        &self,
+        requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+    ) -> Maybe<Arc<StatementsLocations>>;
+
     /// Returns the [cairo_lang_sierra::program::Program] object of the requested crates.
     #[salsa::invoke(program_generator::get_sierra_program)]
     fn get_sierra_program(
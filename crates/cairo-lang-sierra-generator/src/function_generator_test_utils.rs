@@ -10,6 +10,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::UpcastMut;
 
 use crate::db::SierraGenGroup;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -43,6 +44,7 @@ pub fn test_function_generator(
     let sierra_code: String = function.map_or("None".into(), |func| {
         func.body
             .iter()
+            .filter(|x| !matches!(x, pre_sierra::Statement::SetLocation(_)))
             .map(|x| replace_sierra_ids(db, x).to_string())
             .collect::<Vec<String>>()
             .join("\n")
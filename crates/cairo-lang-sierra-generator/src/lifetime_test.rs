@@ -54,7 +54,7 @@ fn check_variable_lifetime(
 
     db.module_lowering_diagnostics(test_function.module_id)
         .unwrap()
-        .expect_with_db(db, "Unexpected diagnostics.");
+        .expect_no_errors(db, "Unexpected diagnostics.");
 
     let function_id =
         ConcreteFunctionWithBodyId::from_semantic(db, test_function.concrete_function_id);
@@ -28,6 +28,7 @@ impl NextStatementIndexFetch {
                         index += 1;
                     }
                 }
+                Statement::SetLocation(_) => {}
                 Statement::PushValues(_) => panic!(
                     "Unexpected pre_sierra::Statement::PushValues in \
                      NextStatementIndexFetch::new()."
@@ -1,4 +1,5 @@
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_lowering::Location;
 use cairo_lang_sierra as sierra;
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_sierra::program;
@@ -51,6 +52,9 @@ pub enum Statement {
     /// Note that push values does not guarantee that new copies of the values will be pushed.
     /// If a prefix of the values is already on the stack, they will not be re-pushed.
     PushValues(Vec<PushValue>),
+    /// Sets the location of the following statements, until the next `SetLocation` statement.
+    /// `None` means the following statements have no known location.
+    SetLocation(Option<Location>),
 }
 impl std::fmt::Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -72,6 +76,7 @@ impl std::fmt::Display for Statement {
                 )?;
                 write!(f, ")")
             }
+            Statement::SetLocation(_) => write!(f, "SetLocation"),
         }
     }
 }
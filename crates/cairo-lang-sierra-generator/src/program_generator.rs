@@ -1,9 +1,14 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_filesystem::span::{TextOffset, TextPosition};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_lowering::Location;
+use cairo_lang_semantic::items::module::origin_span;
+use cairo_lang_sierra::debug_info::{SourcePosition, SourceSpan};
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
 use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId};
@@ -13,7 +18,7 @@ use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use itertools::chain;
 
-use crate::db::SierraGenGroup;
+use crate::db::{SierraGenGroup, StatementsLocations};
 use crate::pre_sierra::{self};
 use crate::replace_ids::{DebugReplacer, SierraIdReplacer};
 use crate::resolve_labels::{resolve_labels, LabelReplacer};
@@ -49,7 +54,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::SetLocation(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -127,12 +133,13 @@ fn collect_used_types(
         .collect()
 }
 
-pub fn get_sierra_program_for_functions(
+/// Collects the pre-Sierra functions of the given functions and the functions they call, in the
+/// order their bodies appear in the Sierra program.
+fn collect_functions(
     db: &dyn SierraGenGroup,
     requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
-) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+) -> Maybe<Vec<Arc<pre_sierra::Function>>> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
-    let mut statements: Vec<pre_sierra::Statement> = vec![];
     let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
     let mut function_id_queue: VecDeque<ConcreteFunctionWithBodyId> =
         requested_function_ids.into_iter().collect();
@@ -141,15 +148,23 @@ pub fn get_sierra_program_for_functions(
             continue;
         }
         let function: Arc<pre_sierra::Function> = db.function_with_body_sierra(function_id)?;
-        functions.push(function.clone());
-        statements.extend_from_slice(&function.body);
-
         for statement in &function.body {
             if let Some(related_function_id) = try_get_function_with_body_id(db, statement) {
                 function_id_queue.push_back(related_function_id);
             }
         }
+        functions.push(function);
     }
+    Ok(functions)
+}
+
+pub fn get_sierra_program_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    let functions = collect_functions(db, requested_function_ids)?;
+    let statements: Vec<pre_sierra::Statement> =
+        functions.iter().flat_map(|function| function.body.iter().cloned()).collect();
 
     let libfunc_declarations =
         generate_libfunc_declarations(db, collect_used_libfuncs(&statements).iter());
@@ -178,6 +193,74 @@ pub fn get_sierra_program_for_functions(
     }))
 }
 
+/// Query implementation of [SierraGenGroup::get_sierra_program_statements_locations].
+///
+/// Only statements with a known location are included. The spans of a statement start with the
+/// code that generated it, followed by the calls it was inlined into, from the innermost call to
+/// the outermost one.
+pub fn get_sierra_program_statements_locations(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<StatementsLocations>> {
+    let mut statements_locations = StatementsLocations::new();
+    let mut source_spans = HashMap::<StableLocation, Option<SourceSpan>>::new();
+    let mut statement_idx = 0;
+    for function in collect_functions(db, requested_function_ids)? {
+        let mut location: Option<&Location> = None;
+        for statement in &function.body {
+            match statement {
+                pre_sierra::Statement::Sierra(_) => {
+                    if let Some(location) = location {
+                        let spans: Vec<_> = chain!(
+                            [location.stable_location],
+                            location.inline_locations.iter().copied()
+                        )
+                        .filter_map(|stable_location| {
+                            source_spans
+                                .entry(stable_location)
+                                .or_insert_with(|| get_source_span(db, stable_location))
+                                .clone()
+                        })
+                        .collect();
+                        if !spans.is_empty() {
+                            statements_locations
+                                .insert(program::StatementIdx(statement_idx), spans);
+                        }
+                    }
+                    statement_idx += 1;
+                }
+                pre_sierra::Statement::SetLocation(new_location) => {
+                    location = new_location.as_ref();
+                }
+                pre_sierra::Statement::Label(_) => {}
+                pre_sierra::Statement::PushValues(_) => panic!(
+                    "Unexpected pre_sierra::Statement::PushValues in \
+                     get_sierra_program_statements_locations()."
+                ),
+            }
+        }
+    }
+    Ok(Arc::new(statements_locations))
+}
+
+/// Returns the [SourceSpan] of a [StableLocation]. Locations in code generated by plugins are
+/// mapped to the code it was generated from, or `None` if they can not be mapped.
+fn get_source_span(db: &dyn SierraGenGroup, stable_location: StableLocation) -> Option<SourceSpan> {
+    let span = stable_location.diagnostic_location(db.upcast()).span;
+    let (module_file_id, span) = origin_span(db.elongate(), stable_location.module_file_id, span)?;
+    let file_id = db.module_file(module_file_id).ok()?;
+    let position = |offset: TextOffset| {
+        let TextPosition { line, col } =
+            offset.position_in_file(db.upcast(), file_id).expect("File of a location not found.");
+        SourcePosition { line, col }
+    };
+    Some(SourceSpan {
+        file: file_id.full_path(db.upcast()),
+        start: position(span.start),
+        end: position(span.end),
+    })
+}
+
 /// Tries extracting a ConcreteFunctionWithBodyId from a pre-Sierra statement.
 fn try_get_function_with_body_id(
     db: &dyn SierraGenGroup,
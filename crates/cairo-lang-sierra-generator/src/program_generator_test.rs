@@ -1,7 +1,11 @@
+use std::path::Path;
+
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra::program::StatementIdx;
 use cairo_lang_utils::try_extract_matches;
 use indoc::indoc;
 use itertools::Itertools;
@@ -144,3 +148,125 @@ fn test_only_include_dependencies(func_name: &str, sierra_used_funcs: &[&str]) {
         sierra_used_funcs
     );
 }
+
+/// Compiles `foo` of the given crate content, and returns its statements, each followed by its
+/// locations. Files of the corelib are shown relative to it, prefixed by `core/`.
+fn statements_with_locations(content: &str) -> String {
+    let corelib = detect_corelib().unwrap();
+    let (db, crate_id) = setup_db_and_get_crate_id(content);
+    let func_id = ConcreteFunctionWithBodyId::from_no_generics_free(
+        &db,
+        db.crate_modules(crate_id)
+            .iter()
+            .find_map(|module_id| {
+                try_extract_matches!(
+                    db.module_item_by_name(*module_id, "foo".into()).unwrap().unwrap(),
+                    ModuleItemId::FreeFunction
+                )
+            })
+            .unwrap(),
+    )
+    .unwrap();
+    let program = db.get_sierra_program_for_functions(vec![func_id]).unwrap();
+    let statements_locations = db.get_sierra_program_statements_locations(vec![func_id]).unwrap();
+    replace_sierra_ids_in_program(&db, &program)
+        .statements
+        .iter()
+        .enumerate()
+        .map(|(idx, statement)| {
+            let Some(spans) = statements_locations.get(&StatementIdx(idx)) else {
+                return statement.to_string();
+            };
+            let spans = spans
+                .iter()
+                .map(|span| {
+                    let file = match Path::new(&span.file).strip_prefix(&corelib) {
+                        Ok(path) => format!("core/{}", path.display()),
+                        Err(_) => span.file.clone(),
+                    };
+                    format!(
+                        "{file}:{}:{}-{}:{}",
+                        span.start.line, span.start.col, span.end.line, span.end.col
+                    )
+                })
+                .join(" <- ");
+            format!("{statement} // {spans}")
+        })
+        .join("\n")
+}
+
+#[test]
+fn test_statements_locations() {
+    assert_eq!(
+        statements_with_locations(indoc! {"
+            fn foo(a: felt252) -> felt252 {
+                bar(a)
+            }
+
+            #[inline(always)]
+            fn bar(a: felt252) -> felt252 {
+                felt252_add(a, a)
+            }
+        "}),
+        indoc! {"
+            dup<felt252>([0]) -> ([0], [2]) // src/lib.cairo:6:4-6:21 <- src/lib.cairo:1:4-1:10
+            felt252_add([2], [0]) -> ([1]) // src/lib.cairo:6:4-6:21 <- src/lib.cairo:1:4-1:10
+            store_temp<felt252>([1]) -> ([3])
+            return([3])"}
+    );
+}
+
+#[test]
+fn test_statements_locations_of_inline_macros() {
+    // The code generated by `assert!` is located at the call of the macro, and its arguments at
+    // their own location.
+    assert_eq!(
+        statements_with_locations(indoc! {"
+            fn foo(a: felt252) {
+                assert!(a == 0, 'a is not zero');
+            }
+        "}),
+        indoc! {"
+            snapshot_take<felt252>([0]) -> ([1], [2])
+            drop<felt252>([1]) -> ()
+            felt252_const<0>() -> ([3])
+            snapshot_take<felt252>([3]) -> ([4], [5])
+            drop<felt252>([4]) -> ()
+            rename<felt252>([2]) -> ([6])
+            rename<felt252>([5]) -> ([7])
+            felt252_sub([6], [7]) -> ([8]) // core/lib.cairo:129:8-129:29 <- core/lib.cairo:167:14-167:25 <- src/lib.cairo:1:12-1:18
+            store_temp<felt252>([8]) -> ([8]) // core/lib.cairo:167:8-170:9 <- src/lib.cairo:1:12-1:18
+            felt252_is_zero([8]) { fallthrough() 15([9]) } // core/lib.cairo:167:8-170:9 <- src/lib.cairo:1:12-1:18
+            branch_align() -> () // core/lib.cairo:167:8-170:9 <- src/lib.cairo:1:12-1:18
+            struct_construct<Unit>() -> ([10])
+            enum_init<core::bool, 1>([10]) -> ([11])
+            store_temp<core::bool>([11]) -> ([12])
+            jump() { 20() }
+            branch_align() -> ()
+            drop<NonZero<felt252>>([9]) -> ()
+            struct_construct<Unit>() -> ([13])
+            enum_init<core::bool, 0>([13]) -> ([14])
+            store_temp<core::bool>([14]) -> ([12])
+            bool_not_impl([12]) -> ([15]) // core/lib.cairo:53:20-53:36 <- src/lib.cairo:1:4-1:36
+            store_temp<core::bool>([15]) -> ([15]) // src/lib.cairo:1:4-1:36
+            enum_match<core::bool>([15]) { fallthrough([16]) 30([17]) } // src/lib.cairo:1:4-1:36
+            branch_align() -> () // src/lib.cairo:1:4-1:36
+            drop<Unit>([16]) -> ()
+            struct_construct<Unit>() -> ([18])
+            struct_construct<Tuple<Unit>>([18]) -> ([19])
+            enum_init<core::panics::PanicResult::<((),)>, 0>([19]) -> ([20])
+            store_temp<core::panics::PanicResult::<((),)>>([20]) -> ([21])
+            return([21])
+            branch_align() -> ()
+            drop<Unit>([17]) -> ()
+            array_new<felt252>() -> ([22]) // core/array.cairo:26:8-26:19 <- core/array.cairo:70:8-70:25 <- core/lib.cairo:292:35-292:53 <- src/lib.cairo:1:4-1:36
+            felt252_const<7695162765079382371953633751663>() -> ([23])
+            store_temp<felt252>([23]) -> ([23]) // core/array.cairo:30:8-30:37 <- core/lib.cairo:293:4-293:25 <- src/lib.cairo:1:4-1:36
+            array_append<felt252>([22], [23]) -> ([24]) // core/array.cairo:30:8-30:37 <- core/lib.cairo:293:4-293:25 <- src/lib.cairo:1:4-1:36
+            struct_construct<core::panics::Panic>() -> ([25])
+            struct_construct<Tuple<core::panics::Panic, Array<felt252>>>([25], [24]) -> ([26])
+            enum_init<core::panics::PanicResult::<((),)>, 1>([26]) -> ([27])
+            store_temp<core::panics::PanicResult::<((),)>>([27]) -> ([28])
+            return([28])"}
+    );
+}
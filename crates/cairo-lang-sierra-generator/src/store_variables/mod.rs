@@ -175,6 +175,9 @@ impl<'a> AddStoreVariableStatements<'a> {
             pre_sierra::Statement::PushValues(push_values) => {
                 self.push_values(push_values);
             }
+            pre_sierra::Statement::SetLocation(_) => {
+                self.result.push(statement);
+            }
         }
     }
 
@@ -9,7 +9,7 @@ use cairo_lang_filesystem::db::{
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup};
 use cairo_lang_parser::db::ParserDatabase;
-use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_plugins::{get_default_inline_macro_plugins, get_default_plugins};
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use cairo_lang_semantic::test_utils::setup_test_crate;
 use cairo_lang_sierra::ids::{ConcreteLibfuncId, GenericLibfuncId};
@@ -42,6 +42,7 @@ impl Default for SierraGenDatabaseForTesting {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         res.set_semantic_plugins(get_default_plugins());
+        res.set_inline_macro_plugins(Arc::new(get_default_inline_macro_plugins()));
         let corelib_path = detect_corelib().expect("Corelib not found in default location.");
         init_dev_corelib(&mut res, corelib_path);
         res
@@ -106,10 +107,10 @@ pub fn setup_db_and_get_crate_id(
     let module_id = ModuleId::CrateRoot(crate_id);
     db.module_semantic_diagnostics(module_id)
         .unwrap()
-        .expect_with_db(db, "Unexpected semantic diagnostics");
+        .expect_no_errors(db, "Unexpected semantic diagnostics");
     db.module_lowering_diagnostics(module_id)
         .unwrap()
-        .expect_with_db(db, "Unexpected lowering diagnostics.");
+        .expect_no_errors(db, "Unexpected lowering diagnostics.");
     (db_val, crate_id)
 }
 
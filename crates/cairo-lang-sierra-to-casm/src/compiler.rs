@@ -1,14 +1,15 @@
 use std::fmt::Display;
+use std::ops::Range;
 
 use cairo_lang_casm::instructions::{Instruction, InstructionBody, RetInstruction};
 use cairo_lang_sierra::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::ConcreteLibfunc;
-use cairo_lang_sierra::ids::VarId;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
 use cairo_lang_sierra::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
 use cairo_lang_sierra_type_size::get_type_size_map;
-use itertools::zip_eq;
+use itertools::{zip_eq, Itertools};
 use thiserror::Error;
 
 use crate::annotations::{AnnotationError, ProgramAnnotations, StatementAnnotations};
@@ -16,7 +17,7 @@ use crate::invocations::{
     check_references_on_stack, compile_invocation, InvocationError, ProgramInfo,
 };
 use crate::metadata::Metadata;
-use crate::references::{check_types_match, ReferencesError};
+use crate::references::{check_types_match, ReferenceExpression, ReferencesError};
 use crate::relocations::{relocate_instructions, RelocationEntry};
 
 #[cfg(test)]
@@ -68,6 +69,19 @@ impl Display for CairoProgram {
 pub struct SierraStatementDebugInfo {
     /// The offset of the sierra statement within the bytecode.
     pub code_offset: usize,
+    /// The references of the variables at the start of the statement, ordered by their ids, if
+    /// recorded (see [compile_with_var_refs]). Never recorded for the end of the program.
+    pub var_refs: Option<Vec<SierraVarRef>>,
+}
+
+/// The reference of a Sierra variable at the start of a statement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SierraVarRef {
+    pub var_id: VarId,
+    pub ty: ConcreteTypeId,
+    /// The cells of the variable, relative to `ap` and `fp` at the start of the code of the
+    /// statement.
+    pub expression: ReferenceExpression,
 }
 
 /// The debug information of a compilation from Sierra to casm.
@@ -76,6 +90,28 @@ pub struct CairoProgramDebugInfo {
     /// The debug information per Sierra statement.
     pub sierra_statement_info: Vec<SierraStatementDebugInfo>,
 }
+impl CairoProgramDebugInfo {
+    /// Returns the index of the Sierra statement whose code includes the given code offset.
+    pub fn sierra_statement_index_by_code_offset(&self, code_offset: usize) -> StatementIdx {
+        // Statements without code share their offset with the following statement, so the last
+        // statement starting at or before the offset is the one whose code includes it.
+        StatementIdx(
+            self.sierra_statement_info.partition_point(|info| info.code_offset <= code_offset) - 1,
+        )
+    }
+
+    /// Returns the indices of the Sierra statements whose code starts at the given code offset -
+    /// the statement with code starting at the offset, if any, and the statements without code
+    /// before it.
+    pub fn sierra_statements_starting_at(&self, code_offset: usize) -> Range<usize> {
+        let start =
+            self.sierra_statement_info.partition_point(|info| info.code_offset < code_offset);
+        let end =
+            self.sierra_statement_info.partition_point(|info| info.code_offset <= code_offset);
+        // The last entry is the end of the program, not a statement.
+        start..end.min(self.sierra_statement_info.len() - 1)
+    }
+}
 
 /// Ensure the basic structure of the invocation is the same as the library function.
 pub fn check_basic_structure(
@@ -105,6 +141,27 @@ pub fn compile(
     program: &Program,
     metadata: &Metadata,
     gas_usage_check: bool,
+) -> Result<CairoProgram, Box<CompilationError>> {
+    compile_ex(program, metadata, gas_usage_check, false)
+}
+
+/// Compiles a Sierra program as [compile] does, additionally recording the references of the
+/// variables at the start of each statement in the debug information, for debuggers.
+pub fn compile_with_var_refs(
+    program: &Program,
+    metadata: &Metadata,
+    gas_usage_check: bool,
+) -> Result<CairoProgram, Box<CompilationError>> {
+    compile_ex(program, metadata, gas_usage_check, true)
+}
+
+/// Compiles a Sierra program, recording the references of the variables at the start of each
+/// statement if `record_var_refs` is true.
+fn compile_ex(
+    program: &Program,
+    metadata: &Metadata,
+    gas_usage_check: bool,
+    record_var_refs: bool,
 ) -> Result<CairoProgram, Box<CompilationError>> {
     let mut instructions = Vec::new();
     let mut relocations: Vec<RelocationEntry> = Vec::new();
@@ -112,6 +169,8 @@ pub fn compile(
     // Maps statement_idx to program_offset. The last value (for statement_idx=number-of-statements)
     // contains the final offset (the size of the program code segment).
     let mut statement_offsets = Vec::with_capacity(program.statements.len());
+    // The references of the variables at the start of each statement, if recorded.
+    let mut statements_var_refs = Vec::with_capacity(program.statements.len());
 
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new_with_ap_change(
         program,
@@ -134,6 +193,25 @@ pub fn compile(
     for (statement_id, statement) in program.statements.iter().enumerate() {
         let statement_idx = StatementIdx(statement_id);
         statement_offsets.push(program_offset);
+        if record_var_refs {
+            let (annotations, _) = program_annotations
+                .get_annotations_after_take_args(statement_idx, std::iter::empty())
+                .map_err(|err| Box::new(err.into()))?;
+            statements_var_refs.push(Some(
+                annotations
+                    .refs
+                    .into_iter()
+                    .map(|(var_id, value)| SierraVarRef {
+                        var_id,
+                        ty: value.ty,
+                        expression: value.expression,
+                    })
+                    .sorted_by_key(|var_ref| var_ref.var_id.id)
+                    .collect(),
+            ));
+        } else {
+            statements_var_refs.push(None);
+        }
         match statement {
             Statement::Return(ref_ids) => {
                 let (annotations, return_refs) = program_annotations
@@ -255,10 +333,12 @@ pub fn compile(
     Ok(CairoProgram {
         instructions,
         debug_info: CairoProgramDebugInfo {
-            sierra_statement_info: statement_offsets
-                .into_iter()
-                .map(|code_offset| SierraStatementDebugInfo { code_offset })
-                .collect(),
+            sierra_statement_info: zip_eq(
+                statement_offsets,
+                statements_var_refs.into_iter().chain([None]),
+            )
+            .map(|(code_offset, var_refs)| SierraStatementDebugInfo { code_offset, var_refs })
+            .collect(),
         },
     })
 }
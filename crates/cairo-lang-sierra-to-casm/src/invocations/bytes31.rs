@@ -0,0 +1,78 @@
+use cairo_felt::Felt252;
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra::extensions::bytes31::Bytes31ConcreteLibfunc;
+use num_bigint::{BigInt, ToBigInt};
+
+use super::misc::{build_identity, build_single_cell_const, validate_under_limit};
+use super::{
+    add_input_variables, get_non_fallthrough_statement_id, CompiledInvocation,
+    CompiledInvocationBuilder, CostValidationInfo, InvocationError,
+};
+
+/// Builds instructions for Sierra bytes31 operations.
+pub fn build(
+    libfunc: &Bytes31ConcreteLibfunc,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(libfunc) => {
+            build_single_cell_const(builder, libfunc.c.clone())
+        }
+        Bytes31ConcreteLibfunc::ToFelt252(_) => build_identity(builder),
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => build_bytes31_try_from_felt252(builder),
+    }
+}
+
+/// Builds instructions for converting a felt252 into a bytes31, failing if it is not smaller than
+/// 2**248.
+fn build_bytes31_try_from_felt252(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let bytes31_bound: BigInt = BigInt::from(1) << 248;
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    let auxiliary_vars: [_; 4] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    casm_build_extend! {casm_builder,
+        const limit = bytes31_bound.clone();
+        let orig_range_check = range_check;
+        tempvar is_valid_bytes31;
+        hint TestLessThan {lhs: value, rhs: limit} into {dst: is_valid_bytes31};
+        jump IsValidBytes31 if is_valid_bytes31 != 0;
+        tempvar shifted_value = value - limit;
+    }
+    validate_under_limit::<1>(
+        &mut casm_builder,
+        &(Felt252::prime().to_bigint().unwrap() - bytes31_bound.clone()),
+        shifted_value,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        jump Failure;
+        IsValidBytes31:
+    };
+    validate_under_limit::<1>(
+        &mut casm_builder,
+        &bytes31_bound,
+        value,
+        range_check,
+        &auxiliary_vars,
+    );
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Failure", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
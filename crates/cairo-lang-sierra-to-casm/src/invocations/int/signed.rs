@@ -0,0 +1,264 @@
+use cairo_felt::Felt252;
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::cell_expression::CellExpression;
+use cairo_lang_sierra::extensions::int::signed::{
+    Sint128Concrete, SintConcrete, SintConstConcreteLibfunc, SintMulTraits, SintTraits,
+};
+use cairo_lang_sierra::extensions::int::IntOperator;
+use cairo_lang_sierra::extensions::is_zero::IsZeroTraits;
+use cairo_lang_sierra::program::{BranchInfo, BranchTarget};
+use num_bigint::{BigInt, ToBigInt};
+
+use super::unsigned::build_small_wide_mul;
+use crate::invocations::misc::validate_under_limit;
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, misc, CompiledInvocation,
+    CompiledInvocationBuilder, CostValidationInfo, InvocationError,
+};
+use crate::references::ReferenceExpression;
+
+/// Builds invocations for sint const values.
+fn build_const<TSintTraits: SintTraits>(
+    libfunc: &SintConstConcreteLibfunc<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    Ok(builder.build_only_reference_changes(
+        [ReferenceExpression::from_cell(CellExpression::Immediate(libfunc.c.into()))].into_iter(),
+    ))
+}
+
+/// Handles a sint overflowing add or sub operation, for a sint of `K_BITS` bits.
+///
+/// Uses the value of the result shifted by `2**(K_BITS - 1)`, which is in the range
+/// `[0, 2**K_BITS)` iff the result is in range.
+fn build_sint_overflowing_operation<const K_BITS: usize>(
+    builder: CompiledInvocationBuilder<'_>,
+    op: IntOperator,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [underflow_handle_statement_id, overflow_handle_statement_id] =
+        match builder.invocation.branches.as_slice() {
+            [
+                BranchInfo { target: BranchTarget::Fallthrough, results: _ },
+                BranchInfo { target: BranchTarget::Statement(underflow), results: _ },
+                BranchInfo { target: BranchTarget::Statement(overflow), results: _ },
+            ] => [*underflow, *overflow],
+            _ => panic!("malformed invocation"),
+        };
+    let [range_check, a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) range_check;
+        deref a;
+        deref b;
+    };
+    let one = BigInt::from(1);
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const half_limit = (one.clone() << (K_BITS - 1)) as BigInt;
+        const limit = (one.clone() << K_BITS) as BigInt;
+        const double_limit = (one.clone() << (K_BITS + 1)) as BigInt;
+        const u128_limit = (one.clone() << 128) as BigInt;
+        const to_u128_shift = (one << (128 - K_BITS)) as BigInt;
+        // Allocating the helper variables first, so that the result would be the last variable.
+        tempvar rc_value;
+        tempvar shifted_value;
+        tempvar in_range;
+    };
+    let value = match op {
+        IntOperator::OverflowingAdd => {
+            casm_build_extend!(casm_builder, tempvar value = a + b;);
+            value
+        }
+        IntOperator::OverflowingSub => {
+            casm_build_extend!(casm_builder, tempvar value = a - b;);
+            value
+        }
+    };
+    casm_build_extend! {casm_builder,
+            assert shifted_value = value + half_limit;
+            hint TestLessThan {lhs: shifted_value, rhs: limit} into {dst: in_range};
+            jump IsInRange if in_range != 0;
+            tempvar is_overflow;
+            hint TestLessThan {lhs: shifted_value, rhs: double_limit} into {dst: is_overflow};
+            jump IsOverflow if is_overflow != 0;
+            // Underflow:
+            // Here we know that `shifted_value < 0`, as the result is bounded from below by
+            // `-2**K_BITS`.
+            tempvar rc_underflow_value = shifted_value + u128_limit;
+            assert rc_underflow_value = *(range_check++);
+            let underflow_wrapping_value = value + limit;
+            jump Underflow;
+        IsOverflow:
+            // Here we know that `2**K_BITS <= shifted_value`, as the result is bounded from above
+            // by `2**K_BITS`.
+            tempvar rc_overflow_value = shifted_value - limit;
+            assert rc_overflow_value = *(range_check++);
+            let overflow_wrapping_value = value - limit;
+            jump Overflow;
+        IsInRange:
+            // Here `shifted_value` is bounded from both sides, so multiplying it by
+            // `2**(128 - K_BITS)` keeps it in the range check bounds iff
+            // `0 <= shifted_value < 2**K_BITS`.
+            assert rc_value = shifted_value * to_u128_shift;
+            assert rc_value = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            (
+                "Underflow",
+                &[&[range_check], &[underflow_wrapping_value]],
+                Some(underflow_handle_statement_id),
+            ),
+            (
+                "Overflow",
+                &[&[range_check], &[overflow_wrapping_value]],
+                Some(overflow_handle_statement_id),
+            ),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles the difference of two sints of `K_BITS` bits, as an unsigned integer of the same size.
+fn build_sint_diff<const K_BITS: usize>(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let [range_check, a, b] = builder.try_get_single_cells()?;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(0) range_check;
+        deref a;
+        deref b;
+    };
+    let one = BigInt::from(1);
+    casm_build_extend! {casm_builder,
+            let orig_range_check = range_check;
+            const limit = (one.clone() << K_BITS) as BigInt;
+            const u128_limit = (one << 128) as BigInt;
+            tempvar a_ge_b;
+            tempvar a_minus_b = a - b;
+            // `-2**K_BITS < a - b < 2**K_BITS`, so `a - b` is smaller than `2**K_BITS` as a field
+            // element iff `a >= b`.
+            hint TestLessThan {lhs: a_minus_b, rhs: limit} into {dst: a_ge_b};
+            jump NoOverflow if a_ge_b != 0;
+            // Overflow (negative):
+            tempvar fixed_a_minus_b = a_minus_b + u128_limit;
+            assert fixed_a_minus_b = *(range_check++);
+            let wrapping_a_minus_b = a_minus_b + limit;
+            jump Target;
+        NoOverflow:
+            assert a_minus_b = *(range_check++);
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[a_minus_b]], None),
+            ("Target", &[&[range_check], &[wrapping_a_minus_b]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Handles a sint conversion from felt252, for a sint of `K_BITS` bits.
+fn build_sint_from_felt252<const K_BITS: usize>(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [range_check, value] = builder.try_get_single_cells()?;
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let one = BigInt::from(1);
+    let limit_value = one.clone() << K_BITS;
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(2) range_check;
+        deref value;
+    };
+    casm_build_extend! {casm_builder,
+        let orig_range_check = range_check;
+        const half_limit = (one << (K_BITS - 1)) as BigInt;
+        const limit = limit_value.clone();
+        tempvar shifted_value = value + half_limit;
+        tempvar is_in_range;
+        hint TestLessThan {lhs: shifted_value, rhs: limit} into {dst: is_in_range};
+        jump InRange if is_in_range != 0;
+        tempvar shifted_out_of_range_value = shifted_value - limit;
+    }
+    let auxiliary_vars: [_; 5] = std::array::from_fn(|_| casm_builder.alloc_var(false));
+    validate_under_limit::<2>(
+        &mut casm_builder,
+        &(Felt252::prime().to_bigint().unwrap() - limit_value.clone()),
+        shifted_out_of_range_value,
+        range_check,
+        &auxiliary_vars,
+    );
+    casm_build_extend! {casm_builder,
+        jump Done;
+        InRange:
+        assert shifted_value = *(range_check++);
+    };
+    if K_BITS < 128 {
+        casm_build_extend! {casm_builder,
+            // shifted_value + 2**128 - limit < 2**128 ==> shifted_value < limit
+            const fixer_limit = ((BigInt::from(1) << 128) - limit_value) as BigInt;
+            tempvar shifted_value_upper_limit = shifted_value + fixer_limit;
+            assert shifted_value_upper_limit = *(range_check++);
+        };
+    }
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[range_check], &[value]], None),
+            ("Done", &[&[range_check]], Some(failure_handle_statement_id)),
+        ],
+        CostValidationInfo {
+            range_check_info: Some((orig_range_check, range_check)),
+            extra_costs: None,
+        },
+    ))
+}
+
+/// Builds instructions for Sierra i8/i16/i32/i64 operations.
+pub fn build_sint<TSintTraits: SintMulTraits + IsZeroTraits, const K_BITS: usize>(
+    libfunc: &SintConcrete<TSintTraits>,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        SintConcrete::Const(libfunc) => build_const(libfunc, builder),
+        SintConcrete::Equal(_) => misc::build_cell_eq(builder),
+        SintConcrete::ToFelt252(_) => misc::build_identity(builder),
+        SintConcrete::FromFelt252(_) => build_sint_from_felt252::<K_BITS>(builder),
+        SintConcrete::IsZero(_) => misc::build_is_zero(builder),
+        SintConcrete::Operation(libfunc) => {
+            build_sint_overflowing_operation::<K_BITS>(builder, libfunc.operator)
+        }
+        SintConcrete::Diff(_) => build_sint_diff::<K_BITS>(builder),
+        SintConcrete::WideMul(_) => build_small_wide_mul(builder),
+    }
+}
+
+/// Builds instructions for Sierra i128 operations.
+pub fn build_sint128(
+    libfunc: &Sint128Concrete,
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    match libfunc {
+        Sint128Concrete::Const(libfunc) => build_const(libfunc, builder),
+        Sint128Concrete::Equal(_) => misc::build_cell_eq(builder),
+        Sint128Concrete::ToFelt252(_) => misc::build_identity(builder),
+        Sint128Concrete::FromFelt252(_) => build_sint_from_felt252::<128>(builder),
+        Sint128Concrete::IsZero(_) => misc::build_is_zero(builder),
+        Sint128Concrete::Operation(libfunc) => {
+            build_sint_overflowing_operation::<128>(builder, libfunc.operator)
+        }
+        Sint128Concrete::Diff(_) => build_sint_diff::<128>(builder),
+    }
+}
@@ -34,6 +34,7 @@ mod array;
 mod bitwise;
 mod boolean;
 mod boxing;
+mod bytes31;
 mod casts;
 mod debug;
 mod ec;
@@ -592,6 +593,11 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::Uint128(libfunc) => int::unsigned128::build(libfunc, builder),
         CoreConcreteLibfunc::Uint256(libfunc) => int::unsigned256::build(libfunc, builder),
         CoreConcreteLibfunc::Uint512(libfunc) => int::unsigned512::build(libfunc, builder),
+        CoreConcreteLibfunc::Sint8(libfunc) => int::signed::build_sint::<_, 8>(libfunc, builder),
+        CoreConcreteLibfunc::Sint16(libfunc) => int::signed::build_sint::<_, 16>(libfunc, builder),
+        CoreConcreteLibfunc::Sint32(libfunc) => int::signed::build_sint::<_, 32>(libfunc, builder),
+        CoreConcreteLibfunc::Sint64(libfunc) => int::signed::build_sint::<_, 64>(libfunc, builder),
+        CoreConcreteLibfunc::Sint128(libfunc) => int::signed::build_sint128(libfunc, builder),
         CoreConcreteLibfunc::Gas(libfunc) => gas::build(libfunc, builder),
         CoreConcreteLibfunc::BranchAlign(_) => misc::build_branch_align(builder),
         CoreConcreteLibfunc::Array(libfunc) => array::build(libfunc, builder),
@@ -603,6 +609,7 @@ pub fn compile_invocation(
         CoreConcreteLibfunc::UnconditionalJump(_) => misc::build_jump(builder),
         CoreConcreteLibfunc::ApTracking(_) => misc::build_update_ap_tracking(builder),
         CoreConcreteLibfunc::Box(libfunc) => boxing::build(libfunc, builder),
+        CoreConcreteLibfunc::Bytes31(libfunc) => bytes31::build(libfunc, builder),
         CoreConcreteLibfunc::Enum(libfunc) => enm::build(libfunc, builder),
         CoreConcreteLibfunc::Struct(libfunc) => structure::build(libfunc, builder),
         CoreConcreteLibfunc::Felt252Dict(libfunc) => felt252_dict::build_dict(libfunc, builder),
@@ -72,6 +72,13 @@ pub fn build(
                 hint StarknetHint::SetSignature { start: start, end: end };
             };
         }
+        TestingConcreteLibfunc::SetBlockHash(_) => {
+            let [block_number, value] = builder.try_get_single_cells()?;
+            add_input_variables! {casm_builder, deref block_number; deref value;};
+            casm_build_extend! {casm_builder,
+                hint StarknetHint::SetBlockHash { block_number: block_number, value: value };
+            };
+        }
         TestingConcreteLibfunc::PopLog(_) => {
             let address = declare_single_value()?;
 
@@ -102,6 +109,55 @@ pub fn build(
                 CostValidationInfo::default(),
             ));
         }
+        TestingConcreteLibfunc::MockCall(_) => {
+            let [address, selector, ret_data] = builder.try_get_refs()?;
+            let [address] = address.try_unpack()?;
+            let [selector] = selector.try_unpack()?;
+            let [data_start, data_end] = ret_data.try_unpack()?;
+            add_input_variables! {casm_builder,
+                deref address;
+                deref selector;
+                deref data_start;
+                deref data_end;
+            };
+            casm_build_extend! {casm_builder,
+                hint StarknetHint::MockCall {
+                    contract_address: address,
+                    selector: selector,
+                    data_start: data_start,
+                    data_end: data_end
+                };
+            };
+        }
+        TestingConcreteLibfunc::Store(_) => {
+            let [address, key, value] = builder.try_get_single_cells()?;
+            add_input_variables! {casm_builder, deref address; deref key; deref value;};
+            casm_build_extend! {casm_builder,
+                hint StarknetHint::Store { contract_address: address, key: key, value: value };
+            };
+        }
+        TestingConcreteLibfunc::Load(_) => {
+            let [address, key] = builder.try_get_single_cells()?;
+            add_input_variables! {casm_builder, deref address; deref key;};
+            casm_build_extend! {casm_builder,
+                tempvar value;
+                hint StarknetHint::Load { contract_address: address, key: key } into { dst: value };
+                ap += 1;
+            };
+            return Ok(builder.build_from_casm_builder(
+                casm_builder,
+                [("Fallthrough", &[&[value]], None)],
+                CostValidationInfo::default(),
+            ));
+        }
+        TestingConcreteLibfunc::ExpectRevert(_) => {
+            let [error] = builder.try_get_refs()?;
+            let [start, end] = error.try_unpack()?;
+            add_input_variables! {casm_builder, deref start; deref end;};
+            casm_build_extend! {casm_builder,
+                hint StarknetHint::ExpectRevert { start: start, end: end };
+            };
+        }
     }
     casm_build_extend! {casm_builder, ap += 0; };
 
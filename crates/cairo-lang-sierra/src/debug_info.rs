@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
 use crate::ids::{ConcreteLibfuncId, ConcreteTypeId, FunctionId};
-use crate::program::{GenericArg, Program, Statement};
+use crate::program::{GenericArg, Program, Statement, StatementIdx};
 
 #[cfg(test)]
 #[path = "debug_info_test.rs"]
@@ -30,6 +30,16 @@ pub struct DebugInfo {
         deserialize_with = "deserialize_map::<FunctionId, _>"
     )]
     pub user_func_names: HashMap<FunctionId, SmolStr>,
+    /// The source spans each statement originates from, if known.
+    /// The first span of a statement is the code that generated it, followed by the calls it was
+    /// inlined into, from the innermost call to the outermost one.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_statements_locations",
+        deserialize_with = "deserialize_statements_locations"
+    )]
+    pub statements_locations: Option<HashMap<StatementIdx, Vec<SourceSpan>>>,
 }
 impl DebugInfo {
     /// Extracts the existing debug info from a program.
@@ -59,6 +69,7 @@ impl DebugInfo {
                     func.id.debug_name.clone().map(|name| (FunctionId::new(func.id.id), name))
                 })
                 .collect(),
+            statements_locations: None,
         }
     }
 
@@ -128,6 +139,24 @@ impl DebugInfo {
     }
 }
 
+/// A position in a source file.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SourcePosition {
+    /// The line number, starting from 0.
+    pub line: usize,
+    /// The column number, starting from 0.
+    pub col: usize,
+}
+
+/// A span of code in a source file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// The path of the source file.
+    pub file: String,
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
 /// Trait for handling serde for the ids as map keys.
 pub trait IdAsHashKey: Hash + Eq {
     /// Gets the inner id.
@@ -180,3 +209,21 @@ fn deserialize_map<'de, Id: IdAsHashKey, D: serde::Deserializer<'de>>(
         .map(|(id, name)| (Id::new(id), name))
         .collect())
 }
+
+fn serialize_statements_locations<S: serde::Serializer>(
+    m: &Option<HashMap<StatementIdx, Vec<SourceSpan>>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let v: Option<Vec<_>> = m.as_ref().map(|m| {
+        m.iter().map(|(idx, spans)| (idx.0, spans)).sorted_by_key(|(idx, _)| *idx).collect()
+    });
+    v.serialize(serializer)
+}
+
+fn deserialize_statements_locations<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<HashMap<StatementIdx, Vec<SourceSpan>>>, D::Error> {
+    Ok(Option::<Vec<(usize, Vec<SourceSpan>)>>::deserialize(deserializer)?.map(|v| {
+        v.into_iter().map(|(idx, spans)| (StatementIdx(idx), spans)).collect()
+    }))
+}
@@ -0,0 +1,546 @@
+use std::marker::PhantomData;
+
+use num_bigint::BigInt;
+
+use super::unsigned::{Uint16Type, Uint32Type, Uint64Type, Uint8Type};
+use super::unsigned128::Uint128Type;
+use super::IntOperator;
+use crate::define_libfunc_hierarchy;
+use crate::extensions::felt252::Felt252Type;
+use crate::extensions::is_zero::{IsZeroLibfunc, IsZeroTraits};
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext, SpecializationContext,
+};
+use crate::extensions::range_check::RangeCheckType;
+use crate::extensions::try_from_felt252::{TryFromFelt252, TryFromFelt252Libfunc};
+use crate::extensions::{
+    GenericLibfunc, NamedLibfunc, NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType,
+    OutputVarReferenceInfo, SignatureBasedConcreteLibfunc, SpecializationError,
+};
+use crate::ids::{GenericLibfuncId, GenericTypeId};
+use crate::program::GenericArg;
+
+/// Trait for implementing signed integers.
+pub trait SintTraits: Default {
+    /// The rust matching type to this type.
+    type SintType: TryFrom<BigInt> + Into<BigInt> + Copy;
+    /// The generic type id for this type.
+    const GENERIC_TYPE_ID: GenericTypeId;
+    /// The generic type id of the unsigned integer type of the same size.
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId;
+    /// The generic libfunc id for getting a const of this type.
+    const CONST: &'static str;
+    /// The generic libfunc id for comparing equality.
+    const EQUAL: &'static str;
+    /// The generic libfunc id for addition.
+    const OVERFLOWING_ADD: &'static str;
+    /// The generic libfunc id for subtraction.
+    const OVERFLOWING_SUB: &'static str;
+    /// The generic libfunc id for calculating the difference between two integers.
+    const DIFF: &'static str;
+    /// The generic libfunc id for conversion to felt252.
+    const TO_FELT252: &'static str;
+    /// The generic libfunc id for conversion from felt252.
+    const TRY_FROM_FELT252: &'static str;
+}
+
+/// Trait for implementing multiplication for signed integers.
+pub trait SintMulTraits: SintTraits {
+    /// The generic libfunc id that multiplies two integers.
+    const WIDE_MUL: &'static str;
+    /// The generic type id for this type multiplication result.
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId;
+}
+
+#[derive(Default)]
+pub struct SintType<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericType for SintType<TSintTraits> {
+    const ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+    const STORABLE: bool = true;
+    const DUPLICATABLE: bool = true;
+    const DROPPABLE: bool = true;
+    const ZERO_SIZED: bool = false;
+}
+
+/// Libfunc for creating a constant signed integer.
+#[derive(Default)]
+pub struct SintConstLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NamedLibfunc for SintConstLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::CONST;
+    type Concrete = SintConstConcreteLibfunc<TSintTraits>;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        _args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Const),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(Self::Concrete {
+                c: TSintTraits::SintType::try_from(c.clone())
+                    .map_err(|_| SpecializationError::UnsupportedGenericArg)?,
+                signature: <Self as NamedLibfunc>::specialize_signature(
+                    self,
+                    context.upcast(),
+                    args,
+                )?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
+
+pub struct SintConstConcreteLibfunc<TSintTraits: SintTraits> {
+    pub c: TSintTraits::SintType,
+    pub signature: LibfuncSignature,
+}
+impl<TSintTraits: SintTraits> SignatureBasedConcreteLibfunc
+    for SintConstConcreteLibfunc<TSintTraits>
+{
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for comparing sints` equality.
+#[derive(Default)]
+pub struct SintEqualLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintEqualLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::EQUAL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let param_signatures =
+            vec![ParamSignature::new(ty.clone()), ParamSignature::new(ty).with_allow_const()];
+        let branch_signatures = (0..2)
+            .map(|_| BranchSignature {
+                vars: vec![],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            })
+            .collect();
+        Ok(LibfuncSignature { param_signatures, branch_signatures, fallthrough: Some(0) })
+    }
+}
+
+pub struct SintOperationConcreteLibfunc {
+    pub operator: IntOperator,
+    pub signature: LibfuncSignature,
+}
+impl SignatureBasedConcreteLibfunc for SintOperationConcreteLibfunc {
+    fn signature(&self) -> &LibfuncSignature {
+        &self.signature
+    }
+}
+
+/// Libfunc for sints operations.
+///
+/// The libfunc has three branches: the result is in range, the result underflowed, and the result
+/// overflowed. In the last two cases, the wrapped result is returned.
+pub struct SintOperationLibfunc<TSintTraits: SintTraits> {
+    pub operator: IntOperator,
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> SintOperationLibfunc<TSintTraits> {
+    const OVERFLOWING_ADD: &'static str = TSintTraits::OVERFLOWING_ADD;
+    const OVERFLOWING_SUB: &'static str = TSintTraits::OVERFLOWING_SUB;
+    fn new(operator: IntOperator) -> Option<Self> {
+        Some(Self { operator, _phantom: PhantomData })
+    }
+}
+impl<TSintTraits: SintTraits> GenericLibfunc for SintOperationLibfunc<TSintTraits> {
+    type Concrete = SintOperationConcreteLibfunc;
+
+    fn supported_ids() -> Vec<GenericLibfuncId> {
+        vec![
+            GenericLibfuncId::from(Self::OVERFLOWING_ADD),
+            GenericLibfuncId::from(Self::OVERFLOWING_SUB),
+        ]
+    }
+
+    fn by_id(id: &GenericLibfuncId) -> Option<Self> {
+        match id.0.as_str() {
+            id if id == Self::OVERFLOWING_ADD => Self::new(IntOperator::OverflowingAdd),
+            id if id == Self::OVERFLOWING_SUB => Self::new(IntOperator::OverflowingSub),
+            _ => None,
+        }
+    }
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !args.is_empty() {
+            return Err(SpecializationError::WrongNumberOfGenericArgs);
+        }
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        let ty_param = ParamSignature::new(ty.clone());
+        let wrapping_result_info = OutputVarInfo {
+            ty: ty.clone(),
+            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+        };
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                ty_param.clone(),
+                ty_param,
+            ],
+            branch_signatures: vec![
+                // In range.
+                BranchSignature {
+                    vars: vec![
+                        rc_output_info.clone(),
+                        OutputVarInfo {
+                            ty,
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Underflow.
+                BranchSignature {
+                    vars: vec![rc_output_info.clone(), wrapping_result_info.clone()],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Overflow.
+                BranchSignature {
+                    vars: vec![rc_output_info, wrapping_result_info],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        Ok(SintOperationConcreteLibfunc {
+            operator: self.operator,
+            signature: self.specialize_signature(context.upcast(), args)?,
+        })
+    }
+}
+
+/// Libfunc for calculating the difference between two sints, as the unsigned integer of the same
+/// size.
+///
+/// Returns `lhs - rhs` in the first branch if `lhs >= rhs`, and the wrapped difference in the
+/// second branch otherwise.
+#[derive(Default)]
+pub struct SintDiffLibfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintDiffLibfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::DIFF;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?;
+        let unsigned_ty = context.get_concrete_type(TSintTraits::UNSIGNED_GENERIC_TYPE_ID, &[])?;
+        let range_check_type = context.get_concrete_type(RangeCheckType::id(), &[])?;
+
+        let rc_output_info = OutputVarInfo::new_builtin(range_check_type.clone(), 0);
+        let ty_param = ParamSignature::new(ty);
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                ParamSignature::new(range_check_type).with_allow_add_const(),
+                ty_param.clone(),
+                ty_param,
+            ],
+            branch_signatures: vec![
+                BranchSignature {
+                    vars: vec![
+                        rc_output_info.clone(),
+                        OutputVarInfo {
+                            ty: unsigned_ty.clone(),
+                            ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                BranchSignature {
+                    vars: vec![
+                        rc_output_info,
+                        OutputVarInfo {
+                            ty: unsigned_ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for converting a sint into a felt252.
+#[derive(Default)]
+pub struct SintToFelt252Libfunc<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> NoGenericArgsGenericLibfunc for SintToFelt252Libfunc<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TO_FELT252;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(TSintTraits::GENERIC_TYPE_ID, &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(Felt252Type::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for attempting to convert a felt252 into a sint.
+#[derive(Default)]
+pub struct SintFromFelt252Trait<TSintTraits: SintTraits> {
+    _phantom: PhantomData<TSintTraits>,
+}
+impl<TSintTraits: SintTraits> TryFromFelt252 for SintFromFelt252Trait<TSintTraits> {
+    const STR_ID: &'static str = TSintTraits::TRY_FROM_FELT252;
+    const GENERIC_TYPE_ID: GenericTypeId = TSintTraits::GENERIC_TYPE_ID;
+}
+
+pub type SintFromFelt252Libfunc<T> = TryFromFelt252Libfunc<SintFromFelt252Trait<T>>;
+
+/// Libfunc for sint wide multiplication.
+#[derive(Default)]
+pub struct SintWideMulLibfunc<TSintMulTraits: SintMulTraits> {
+    _phantom: PhantomData<TSintMulTraits>,
+}
+impl<TSintMulTraits: SintMulTraits> NoGenericArgsGenericLibfunc
+    for SintWideMulLibfunc<TSintMulTraits>
+{
+    const STR_ID: &'static str = TSintMulTraits::WIDE_MUL;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let ty = context.get_concrete_type(TSintMulTraits::GENERIC_TYPE_ID, &[])?;
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature::new(ty.clone()), ParamSignature::new(ty).with_allow_const()],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(TSintMulTraits::WIDE_MUL_RES_TYPE_ID, &[])?,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+define_libfunc_hierarchy! {
+    pub enum SintLibfunc<TSintTraits: SintMulTraits + IsZeroTraits> {
+        Const(SintConstLibfunc<TSintTraits>),
+        Equal(SintEqualLibfunc<TSintTraits>),
+        ToFelt252(SintToFelt252Libfunc<TSintTraits>),
+        FromFelt252(SintFromFelt252Libfunc<TSintTraits>),
+        IsZero(IsZeroLibfunc<TSintTraits>),
+        Operation(SintOperationLibfunc<TSintTraits>),
+        Diff(SintDiffLibfunc<TSintTraits>),
+        WideMul(SintWideMulLibfunc<TSintTraits>),
+    }, SintConcrete
+}
+
+#[derive(Default)]
+pub struct Sint8Traits;
+
+impl SintTraits for Sint8Traits {
+    type SintType = i8;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i8");
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId = <Uint8Type as NamedType>::ID;
+    const CONST: &'static str = "i8_const";
+    const EQUAL: &'static str = "i8_eq";
+    const OVERFLOWING_ADD: &'static str = "i8_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i8_overflowing_sub_impl";
+    const DIFF: &'static str = "i8_diff";
+    const TO_FELT252: &'static str = "i8_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i8_try_from_felt252";
+}
+
+impl SintMulTraits for Sint8Traits {
+    const WIDE_MUL: &'static str = "i8_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint16Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint8Traits {
+    const IS_ZERO: &'static str = "i8_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint8Type as NamedType>::ID;
+}
+
+/// Type for i8.
+pub type Sint8Type = SintType<Sint8Traits>;
+pub type Sint8Libfunc = SintLibfunc<Sint8Traits>;
+pub type Sint8Concrete = <Sint8Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint16Traits;
+
+impl SintTraits for Sint16Traits {
+    type SintType = i16;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i16");
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId = <Uint16Type as NamedType>::ID;
+    const CONST: &'static str = "i16_const";
+    const EQUAL: &'static str = "i16_eq";
+    const OVERFLOWING_ADD: &'static str = "i16_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i16_overflowing_sub_impl";
+    const DIFF: &'static str = "i16_diff";
+    const TO_FELT252: &'static str = "i16_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i16_try_from_felt252";
+}
+
+impl SintMulTraits for Sint16Traits {
+    const WIDE_MUL: &'static str = "i16_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint32Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint16Traits {
+    const IS_ZERO: &'static str = "i16_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint16Type as NamedType>::ID;
+}
+
+/// Type for i16.
+pub type Sint16Type = SintType<Sint16Traits>;
+pub type Sint16Libfunc = SintLibfunc<Sint16Traits>;
+pub type Sint16Concrete = <Sint16Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint32Traits;
+
+impl SintTraits for Sint32Traits {
+    type SintType = i32;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i32");
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId = <Uint32Type as NamedType>::ID;
+    const CONST: &'static str = "i32_const";
+    const EQUAL: &'static str = "i32_eq";
+    const OVERFLOWING_ADD: &'static str = "i32_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i32_overflowing_sub_impl";
+    const DIFF: &'static str = "i32_diff";
+    const TO_FELT252: &'static str = "i32_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i32_try_from_felt252";
+}
+
+impl SintMulTraits for Sint32Traits {
+    const WIDE_MUL: &'static str = "i32_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint64Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint32Traits {
+    const IS_ZERO: &'static str = "i32_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint32Type as NamedType>::ID;
+}
+
+/// Type for i32.
+pub type Sint32Type = SintType<Sint32Traits>;
+pub type Sint32Libfunc = SintLibfunc<Sint32Traits>;
+pub type Sint32Concrete = <Sint32Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint64Traits;
+
+impl SintTraits for Sint64Traits {
+    type SintType = i64;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i64");
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId = <Uint64Type as NamedType>::ID;
+    const CONST: &'static str = "i64_const";
+    const EQUAL: &'static str = "i64_eq";
+    const OVERFLOWING_ADD: &'static str = "i64_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i64_overflowing_sub_impl";
+    const DIFF: &'static str = "i64_diff";
+    const TO_FELT252: &'static str = "i64_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i64_try_from_felt252";
+}
+
+impl SintMulTraits for Sint64Traits {
+    const WIDE_MUL: &'static str = "i64_wide_mul";
+    const WIDE_MUL_RES_TYPE_ID: GenericTypeId = <Sint128Type as NamedType>::ID;
+}
+
+impl IsZeroTraits for Sint64Traits {
+    const IS_ZERO: &'static str = "i64_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint64Type as NamedType>::ID;
+}
+
+/// Type for i64.
+pub type Sint64Type = SintType<Sint64Traits>;
+pub type Sint64Libfunc = SintLibfunc<Sint64Traits>;
+pub type Sint64Concrete = <Sint64Libfunc as GenericLibfunc>::Concrete;
+
+#[derive(Default)]
+pub struct Sint128Traits;
+
+impl SintTraits for Sint128Traits {
+    type SintType = i128;
+    const GENERIC_TYPE_ID: GenericTypeId = GenericTypeId::new_inline("i128");
+    const UNSIGNED_GENERIC_TYPE_ID: GenericTypeId = <Uint128Type as NamedType>::ID;
+    const CONST: &'static str = "i128_const";
+    const EQUAL: &'static str = "i128_eq";
+    const OVERFLOWING_ADD: &'static str = "i128_overflowing_add_impl";
+    const OVERFLOWING_SUB: &'static str = "i128_overflowing_sub_impl";
+    const DIFF: &'static str = "i128_diff";
+    const TO_FELT252: &'static str = "i128_to_felt252";
+    const TRY_FROM_FELT252: &'static str = "i128_try_from_felt252";
+}
+
+impl IsZeroTraits for Sint128Traits {
+    const IS_ZERO: &'static str = "i128_is_zero";
+    const GENERIC_TYPE_ID: GenericTypeId = <Sint128Type as NamedType>::ID;
+}
+
+/// Type for i128.
+pub type Sint128Type = SintType<Sint128Traits>;
+
+define_libfunc_hierarchy! {
+    pub enum Sint128Libfunc {
+        Const(SintConstLibfunc<Sint128Traits>),
+        Equal(SintEqualLibfunc<Sint128Traits>),
+        ToFelt252(SintToFelt252Libfunc<Sint128Traits>),
+        FromFelt252(SintFromFelt252Libfunc<Sint128Traits>),
+        IsZero(IsZeroLibfunc<Sint128Traits>),
+        Operation(SintOperationLibfunc<Sint128Traits>),
+        Diff(SintDiffLibfunc<Sint128Traits>),
+    }, Sint128Concrete
+}
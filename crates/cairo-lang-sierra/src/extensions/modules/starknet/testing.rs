@@ -153,6 +153,30 @@ impl TestSetterTraits for SetSignatureTrait {
     }
 }
 
+/// Libfunc for setting the hash of a block.
+#[derive(Default)]
+pub struct SetBlockHashLibfunc {}
+
+impl NoGenericArgsGenericLibfunc for SetBlockHashLibfunc {
+    const STR_ID: &'static str = "set_block_hash";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // block_number
+                context.get_concrete_type(Uint64Type::id(), &[])?,
+                // value
+                context.get_concrete_type(Felt252Type::id(), &[])?,
+            ],
+            vec![],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 #[derive(Default)]
 pub struct PopLogLibfunc {}
 
@@ -196,6 +220,99 @@ impl NoGenericArgsGenericLibfunc for PopLogLibfunc {
     }
 }
 
+#[derive(Default)]
+pub struct ExpectRevertTrait {}
+impl TestSetterTraits for ExpectRevertTrait {
+    const STR_ID: &'static str = "expect_revert";
+
+    fn value_type_id(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<ConcreteTypeId, SpecializationError> {
+        felt252_span_ty(context)
+    }
+}
+
+/// Libfunc for mocking the result of calls to a contract's entry point.
+#[derive(Default)]
+pub struct MockCallLibfunc {}
+
+impl NoGenericArgsGenericLibfunc for MockCallLibfunc {
+    const STR_ID: &'static str = "mock_call";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // address
+                context.get_concrete_type(ContractAddressType::id(), &[])?,
+                // selector
+                context.get_concrete_type(Felt252Type::id(), &[])?,
+                // ret_data
+                felt252_span_ty(context)?,
+            ],
+            vec![],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for writing to the storage of a contract.
+#[derive(Default)]
+pub struct StoreLibfunc {}
+
+impl NoGenericArgsGenericLibfunc for StoreLibfunc {
+    const STR_ID: &'static str = "store";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // address
+                context.get_concrete_type(ContractAddressType::id(), &[])?,
+                // key
+                felt252_ty.clone(),
+                // value
+                felt252_ty,
+            ],
+            vec![],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for reading from the storage of a contract.
+#[derive(Default)]
+pub struct LoadLibfunc {}
+
+impl NoGenericArgsGenericLibfunc for LoadLibfunc {
+    const STR_ID: &'static str = "load";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let felt252_ty = context.get_concrete_type(Felt252Type::id(), &[])?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![
+                // address
+                context.get_concrete_type(ContractAddressType::id(), &[])?,
+                // key
+                felt252_ty.clone(),
+            ],
+            vec![OutputVarInfo {
+                ty: felt252_ty,
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
 define_libfunc_hierarchy! {
     pub enum TestingLibfunc {
          SetBlockNumber(TestSetterLibfunc<SetBlockNumberTrait>),
@@ -210,6 +327,11 @@ define_libfunc_hierarchy! {
          SetChainId(TestSetterLibfunc<SetChainIdTrait>),
          SetNonce(TestSetterLibfunc<SetNonceTrait>),
          SetSignature(TestSetterLibfunc<SetSignatureTrait>),
+         SetBlockHash(SetBlockHashLibfunc),
          PopLog(PopLogLibfunc),
+         MockCall(MockCallLibfunc),
+         Store(StoreLibfunc),
+         Load(LoadLibfunc),
+         ExpectRevert(TestSetterLibfunc<ExpectRevertTrait>),
     }, TestingConcreteLibfunc
 }
@@ -3,16 +3,18 @@ use std::str::FromStr;
 
 use cairo_lang_utils::extract_matches;
 use num_bigint::{BigInt, ToBigInt};
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{Signed, ToPrimitive, Zero};
 
 use super::value::CoreValue;
 use super::LibfuncSimulationError;
 use crate::extensions::array::ArrayConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
+use crate::extensions::bytes31::Bytes31ConcreteLibfunc;
+use crate::extensions::consts::SignatureAndConstConcreteLibfunc;
 use crate::extensions::core::CoreConcreteLibfunc::{
     self, ApTracking, Array, Bitwise, Bool, BranchAlign, Drop, Dup, Ec, Enum, Felt252,
-    FunctionCall, Gas, Mem, Struct, Uint128, Uint16, Uint32, Uint64, Uint8, UnconditionalJump,
-    UnwrapNonZero,
+    FunctionCall, Gas, Mem, Sint128, Sint16, Sint32, Sint64, Sint8, Struct, Uint128, Uint16,
+    Uint32, Uint64, Uint8, UnconditionalJump, UnwrapNonZero,
 };
 use crate::extensions::ec::EcConcreteLibfunc;
 use crate::extensions::enm::{EnumConcreteLibfunc, EnumInitConcreteLibfunc};
@@ -25,11 +27,15 @@ use crate::extensions::function_call::FunctionCallConcreteLibfunc;
 use crate::extensions::gas::GasConcreteLibfunc::{
     BuiltinWithdrawGas, GetAvailableGas, GetBuiltinCosts, RedepositGas, WithdrawGas,
 };
+use crate::extensions::int::signed::{
+    Sint128Concrete, SintConcrete, SintConstConcreteLibfunc, SintMulTraits, SintTraits,
+};
 use crate::extensions::int::unsigned::{
     Uint16Concrete, Uint32Concrete, Uint64Concrete, Uint8Concrete, UintConstConcreteLibfunc,
 };
 use crate::extensions::int::unsigned128::Uint128Concrete;
 use crate::extensions::int::IntOperator;
+use crate::extensions::is_zero::IsZeroTraits;
 use crate::extensions::mem::MemConcreteLibfunc::{
     AllocLocal, FinalizeLocals, Rename, StoreLocal, StoreTemp,
 };
@@ -237,6 +243,35 @@ pub fn simulate<
         Uint32(libfunc) => simulate_u32_libfunc(libfunc, &inputs),
         Uint64(libfunc) => simulate_u64_libfunc(libfunc, &inputs),
         Uint128(libfunc) => simulate_u128_libfunc(libfunc, &inputs),
+        Sint8(libfunc) => simulate_sint_libfunc(
+            libfunc,
+            &inputs,
+            |value| CoreValue::Sint8(value.to_i8().unwrap()),
+            |value| CoreValue::Uint8(value.to_u8().unwrap()),
+            |value| CoreValue::Sint16(value.to_i16().unwrap()),
+        ),
+        Sint16(libfunc) => simulate_sint_libfunc(
+            libfunc,
+            &inputs,
+            |value| CoreValue::Sint16(value.to_i16().unwrap()),
+            |value| CoreValue::Uint16(value.to_u16().unwrap()),
+            |value| CoreValue::Sint32(value.to_i32().unwrap()),
+        ),
+        Sint32(libfunc) => simulate_sint_libfunc(
+            libfunc,
+            &inputs,
+            |value| CoreValue::Sint32(value.to_i32().unwrap()),
+            |value| CoreValue::Uint32(value.to_u32().unwrap()),
+            |value| CoreValue::Sint64(value.to_i64().unwrap()),
+        ),
+        Sint64(libfunc) => simulate_sint_libfunc(
+            libfunc,
+            &inputs,
+            |value| CoreValue::Sint64(value.to_i64().unwrap()),
+            |value| CoreValue::Uint64(value.to_u64().unwrap()),
+            |value| CoreValue::Sint128(value.to_i128().unwrap()),
+        ),
+        Sint128(libfunc) => simulate_i128_libfunc(libfunc, &inputs),
         Bool(libfunc) => simulate_bool_libfunc(libfunc, &inputs),
         Felt252(libfunc) => simulate_felt252_libfunc(libfunc, &inputs),
         UnwrapNonZero(_) => match &inputs[..] {
@@ -329,6 +364,7 @@ pub fn simulate<
         CoreConcreteLibfunc::Nullable(_) => {
             unimplemented!("Simulation of nullable is not implemented yet.")
         }
+        CoreConcreteLibfunc::Bytes31(libfunc) => simulate_bytes31_libfunc(libfunc, &inputs),
         CoreConcreteLibfunc::Debug(_) => {
             if inputs.len() == 1 {
                 let arr = extract_matches!(&inputs[0], CoreValue::Array);
@@ -531,6 +567,38 @@ fn simulate_u128_libfunc(
     }
 }
 
+/// Simulate bytes31 library functions. A bytes31 value is represented as a felt252 value.
+fn simulate_bytes31_libfunc(
+    libfunc: &Bytes31ConcreteLibfunc,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Bytes31ConcreteLibfunc::Const(SignatureAndConstConcreteLibfunc { c, .. }) => {
+            if inputs.is_empty() {
+                Ok((vec![CoreValue::Felt252(c.clone())], 0))
+            } else {
+                Err(LibfuncSimulationError::WrongNumberOfArgs)
+            }
+        }
+        Bytes31ConcreteLibfunc::ToFelt252(_) => match inputs {
+            [CoreValue::Felt252(value)] => Ok((vec![CoreValue::Felt252(value.clone())], 0)),
+            [_] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+        Bytes31ConcreteLibfunc::TryFromFelt252(_) => match inputs {
+            [CoreValue::RangeCheck, CoreValue::Felt252(value)] => {
+                Ok(if !value.is_negative() && value.bits() <= 248 {
+                    (vec![CoreValue::RangeCheck, CoreValue::Felt252(value.clone())], 0)
+                } else {
+                    (vec![CoreValue::RangeCheck], 1)
+                })
+            }
+            [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
 /// Simulate u8 library functions.
 fn simulate_u8_libfunc(
     libfunc: &Uint8Concrete,
@@ -803,6 +871,210 @@ fn simulate_u64_libfunc(
     }
 }
 
+/// Returns the value of a signed integer, or None if the value is not a signed integer.
+fn sint_value(value: &CoreValue) -> Option<BigInt> {
+    match value {
+        CoreValue::Sint8(value) => Some(BigInt::from(*value)),
+        CoreValue::Sint16(value) => Some(BigInt::from(*value)),
+        CoreValue::Sint32(value) => Some(BigInt::from(*value)),
+        CoreValue::Sint64(value) => Some(BigInt::from(*value)),
+        CoreValue::Sint128(value) => Some(BigInt::from(*value)),
+        _ => None,
+    }
+}
+
+/// Simulate i8/i16/i32/i64 library functions.
+///
+/// `wrap`, `wrap_unsigned` and `wrap_wide` build the value of the signed type, the unsigned type
+/// of the same size and the signed type of double size, respectively.
+fn simulate_sint_libfunc<TSintTraits: SintMulTraits + IsZeroTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+    inputs: &[CoreValue],
+    wrap: fn(BigInt) -> CoreValue,
+    wrap_unsigned: fn(BigInt) -> CoreValue,
+    wrap_wide: fn(BigInt) -> CoreValue,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let bits = std::mem::size_of::<TSintTraits::SintType>() * 8;
+    match libfunc {
+        SintConcrete::Const(libfunc) => simulate_sint_const(libfunc, inputs, wrap),
+        SintConcrete::Equal(_) => simulate_sint_eq(inputs),
+        SintConcrete::ToFelt252(_) => simulate_sint_to_felt252(inputs),
+        SintConcrete::FromFelt252(_) => simulate_sint_from_felt252(inputs, bits, wrap),
+        SintConcrete::IsZero(_) => simulate_sint_is_zero(inputs),
+        SintConcrete::Operation(libfunc) => {
+            simulate_sint_operation(libfunc.operator, inputs, bits, wrap)
+        }
+        SintConcrete::Diff(_) => simulate_sint_diff(inputs, bits, wrap_unsigned),
+        SintConcrete::WideMul(_) => match inputs {
+            [lhs, rhs] => match (sint_value(lhs), sint_value(rhs)) {
+                (Some(lhs), Some(rhs)) => Ok((vec![wrap_wide(lhs * rhs)], 0)),
+                _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+            },
+            _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+        },
+    }
+}
+
+/// Simulate i128 library functions.
+fn simulate_i128_libfunc(
+    libfunc: &Sint128Concrete,
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let wrap = |value: BigInt| CoreValue::Sint128(value.to_i128().unwrap());
+    match libfunc {
+        Sint128Concrete::Const(libfunc) => simulate_sint_const(libfunc, inputs, wrap),
+        Sint128Concrete::Equal(_) => simulate_sint_eq(inputs),
+        Sint128Concrete::ToFelt252(_) => simulate_sint_to_felt252(inputs),
+        Sint128Concrete::FromFelt252(_) => simulate_sint_from_felt252(inputs, 128, wrap),
+        Sint128Concrete::IsZero(_) => simulate_sint_is_zero(inputs),
+        Sint128Concrete::Operation(libfunc) => {
+            simulate_sint_operation(libfunc.operator, inputs, 128, wrap)
+        }
+        Sint128Concrete::Diff(_) => {
+            simulate_sint_diff(inputs, 128, |value| CoreValue::Uint128(value.to_u128().unwrap()))
+        }
+    }
+}
+
+/// Simulate the sint const libfuncs.
+fn simulate_sint_const<TSintTraits: SintTraits>(
+    libfunc: &SintConstConcreteLibfunc<TSintTraits>,
+    inputs: &[CoreValue],
+    wrap: fn(BigInt) -> CoreValue,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    if inputs.is_empty() {
+        Ok((vec![wrap(libfunc.c.into())], 0))
+    } else {
+        Err(LibfuncSimulationError::WrongNumberOfArgs)
+    }
+}
+
+/// Simulate the sint equality libfuncs.
+fn simulate_sint_eq(
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [a, b] => match (sint_value(a), sint_value(b)) {
+            // "False" branch (branch 0) is the case a != b.
+            // "True" branch (branch 1) is the case a == b.
+            (Some(a), Some(b)) => Ok((vec![], usize::from(a == b))),
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
+/// Simulate the sint to felt252 conversion libfuncs.
+fn simulate_sint_to_felt252(
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [value] => match sint_value(value) {
+            Some(value) => Ok((vec![CoreValue::Felt252(value)], 0)),
+            None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
+/// Simulate the sint from felt252 conversion libfuncs, for a sint of `bits` bits.
+fn simulate_sint_from_felt252(
+    inputs: &[CoreValue],
+    bits: usize,
+    wrap: fn(BigInt) -> CoreValue,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [CoreValue::RangeCheck, CoreValue::Felt252(value)] => {
+            let half_limit = BigInt::from(1) << (bits - 1);
+            Ok(if -half_limit.clone() <= *value && *value < half_limit {
+                (vec![CoreValue::RangeCheck, wrap(value.clone())], 0)
+            } else {
+                (vec![CoreValue::RangeCheck], 1)
+            })
+        }
+        [_, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
+/// Simulate the sint is zero libfuncs.
+fn simulate_sint_is_zero(
+    inputs: &[CoreValue],
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [value] => match sint_value(value) {
+            // Zero - jumping to the failure branch.
+            Some(v) if v.is_zero() => Ok((vec![], 0)),
+            // Non-zero - jumping to the success branch and providing a NonZero wrap to the given
+            // value.
+            Some(_) => Ok((vec![CoreValue::NonZero(Box::new(value.clone()))], 1)),
+            None => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
+/// Simulate the sint overflowing operations libfuncs, for a sint of `bits` bits.
+fn simulate_sint_operation(
+    operator: IntOperator,
+    inputs: &[CoreValue],
+    bits: usize,
+    wrap: fn(BigInt) -> CoreValue,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [CoreValue::RangeCheck, lhs, rhs] => match (sint_value(lhs), sint_value(rhs)) {
+            (Some(lhs), Some(rhs)) => {
+                let value = match operator {
+                    IntOperator::OverflowingAdd => lhs + rhs,
+                    IntOperator::OverflowingSub => lhs - rhs,
+                };
+                let limit = BigInt::from(1) << bits;
+                let half_limit = BigInt::from(1) << (bits - 1);
+                let (value, branch) = if value < -half_limit.clone() {
+                    (value + limit, 1)
+                } else if value >= half_limit {
+                    (value - limit, 2)
+                } else {
+                    (value, 0)
+                };
+                Ok((vec![CoreValue::RangeCheck, wrap(value)], branch))
+            }
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
+/// Simulate the sint diff libfuncs, for a sint of `bits` bits.
+fn simulate_sint_diff(
+    inputs: &[CoreValue],
+    bits: usize,
+    wrap_unsigned: fn(BigInt) -> CoreValue,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match inputs {
+        [CoreValue::RangeCheck, lhs, rhs] => match (sint_value(lhs), sint_value(rhs)) {
+            (Some(lhs), Some(rhs)) => {
+                let value = lhs - rhs;
+                Ok(if value.is_negative() {
+                    (
+                        vec![
+                            CoreValue::RangeCheck,
+                            wrap_unsigned(value + (BigInt::from(1) << bits)),
+                        ],
+                        1,
+                    )
+                } else {
+                    (vec![CoreValue::RangeCheck, wrap_unsigned(value)], 0)
+                })
+            }
+            _ => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        },
+        [_, _, _] => Err(LibfuncSimulationError::MemoryLayoutMismatch),
+        _ => Err(LibfuncSimulationError::WrongNumberOfArgs),
+    }
+}
+
 /// Simulate felt252 library functions.
 fn simulate_felt252_libfunc(
     libfunc: &Felt252Concrete,
@@ -3,7 +3,7 @@ use num_bigint::BigInt;
 use test_case::test_case;
 
 use super::value::CoreValue::{
-    self, Array, GasBuiltin, NonZero, RangeCheck, Uint128, Uint64, Uninitialized,
+    self, Array, Felt252, GasBuiltin, NonZero, RangeCheck, Sint8, Uint128, Uint64, Uninitialized,
 };
 use super::LibfuncSimulationError::{
     self, FunctionSimulationError, MemoryLayoutMismatch, WrongNumberOfArgs,
@@ -157,6 +157,21 @@ fn simulate(
 #[test_case("u128_overflowing_sub", vec![], vec![RangeCheck, Uint128(3), Uint128(5)]
              => Ok((vec![RangeCheck, Uint128(u128::MAX - 1)], 1));
             "u128_overflowing_sub(3, 5)")]
+#[test_case("i8_overflowing_add_impl", vec![], vec![RangeCheck, Sint8(2), Sint8(-3)]
+             => Ok((vec![RangeCheck, Sint8(-1)], 0));
+            "i8_overflowing_add_impl(2, -3)")]
+#[test_case("i8_overflowing_add_impl", vec![], vec![RangeCheck, Sint8(-100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(56)], 1));
+            "i8_overflowing_add_impl(-100, -100)")]
+#[test_case("i8_overflowing_sub_impl", vec![], vec![RangeCheck, Sint8(100), Sint8(-100)]
+             => Ok((vec![RangeCheck, Sint8(-56)], 2));
+            "i8_overflowing_sub_impl(100, -100)")]
+#[test_case("bytes31_try_from_felt252", vec![], vec![RangeCheck, Felt252(5.into())]
+             => Ok((vec![RangeCheck, Felt252(5.into())], 0));
+            "bytes31_try_from_felt252(5)")]
+#[test_case("bytes31_try_from_felt252", vec![], vec![RangeCheck, Felt252(BigInt::from(1) << 248)]
+             => Ok((vec![RangeCheck], 1));
+            "bytes31_try_from_felt252(2**248)")]
 fn simulate_branch(
     id: &str,
     generic_args: Vec<GenericArg>,
@@ -178,6 +193,10 @@ fn simulate_branch(
              => Ok(vec![RangeCheck, Uint128(6), Uint128(2)]); "u128_safe_divmod(32, 5)")]
 #[test_case("u128_const", vec![value_arg(3)], vec![] => Ok(vec![Uint128(3)]);
             "u128_const<3>()")]
+#[test_case("bytes31_const", vec![value_arg(3)], vec![] => Ok(vec![Felt252(3.into())]);
+            "bytes31_const<3>()")]
+#[test_case("bytes31_to_felt252", vec![], vec![Felt252(3.into())] => Ok(vec![Felt252(3.into())]);
+            "bytes31_to_felt252(3)")]
 #[test_case("dup", vec![type_arg("u128")], vec![Uint128(24)]
              => Ok(vec![Uint128(24), Uint128(24)]); "dup<u128>(24)")]
 #[test_case("drop", vec![type_arg("u128")], vec![Uint128(2)] => Ok(vec![]); "drop<u128>(2)")]
@@ -219,6 +238,10 @@ fn simulate_none_branch(
             "u128_safe_divmod(1)")]
 #[test_case("u128_const", vec![value_arg(3)], vec![Uint128(1)] => WrongNumberOfArgs;
             "u128_const<3>(1)")]
+#[test_case("bytes31_const", vec![value_arg(3)], vec![Felt252(1.into())] => WrongNumberOfArgs;
+            "bytes31_const<3>(1)")]
+#[test_case("bytes31_try_from_felt252", vec![], vec![RangeCheck, Uint128(1)] => MemoryLayoutMismatch;
+            "bytes31_try_from_felt252(u128)")]
 #[test_case("dup", vec![type_arg("u128")], vec![] => WrongNumberOfArgs; "dup<u128>()")]
 #[test_case("drop", vec![type_arg("u128")], vec![] => WrongNumberOfArgs; "drop<u128>()")]
 #[test_case("u128_is_zero", vec![], vec![] => WrongNumberOfArgs; "u128_is_zero()")]
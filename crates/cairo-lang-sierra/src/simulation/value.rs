@@ -15,6 +15,11 @@ pub enum CoreValue {
     Uint32(u32),
     Uint64(u64),
     Uint128(u128),
+    Sint8(i8),
+    Sint16(i16),
+    Sint32(i32),
+    Sint64(i64),
+    Sint128(i128),
     NonZero(Box<CoreValue>),
     Ref(Box<CoreValue>),
     Array(Vec<CoreValue>),
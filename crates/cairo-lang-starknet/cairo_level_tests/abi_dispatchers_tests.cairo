@@ -1,7 +1,6 @@
 use array::ArrayTrait;
 use array::SpanTrait;
-use starknet::ContractAddress;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 use super::utils::serialized_element;
 use super::utils::single_deserialize;
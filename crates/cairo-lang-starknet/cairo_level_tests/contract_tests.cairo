@@ -7,8 +7,7 @@ use zeroable::Zeroable;
 use clone::Clone;
 use starknet::Event;
 use starknet::class_hash::Felt252TryIntoClassHash;
-use starknet::StorageAddress;
-use test::test_utils::{assert_eq, assert_ne};
+use test::test_utils::assert_eq;
 
 use super::utils::serialized_element;
 use super::utils::single_deserialize;
@@ -1,8 +1,8 @@
-use core::traits::Into;
 use core::result::ResultTrait;
-use test::test_utils::{assert_eq, assert_ne};
-use starknet::syscalls::{deploy_syscall, get_block_hash_syscall};
+use test::test_utils::assert_eq;
+use starknet::syscalls::{call_contract_syscall, deploy_syscall, get_block_hash_syscall};
 use array::ArrayTrait;
+use array::SpanTrait;
 use traits::TryInto;
 use option::OptionTrait;
 use starknet::SyscallResultTrait;
@@ -35,7 +35,7 @@ mod ContractA {
     }
 
     #[generate_trait]
-    impl MyImpl of MyTrait {
+    pub impl MyImpl of MyTrait {
         fn internal_func(self: @ContractState) -> u128 {
             5
         }
@@ -182,9 +182,113 @@ fn test_entrypoint_failed() {
     contract.foo(300);
 }
 
+// The selector of `foo`.
+const FOO_SELECTOR: felt252 = 0x1b1a0649752af1b28b3dc29a1556eee781e4a4c3a1f7f53f90fa834de098c4d;
+// The storage address of `ContractA::value`.
+const VALUE_ADDRESS: felt252 = 0x1afeeaff0ed5cee7d05a21078399c2f56226b0cd5657062500cef4c4e736f85;
+
+#[test]
+#[available_gas(30000000)]
+fn test_mock_call() {
+    let mut ret_data = Default::default();
+    ret_data.append(7);
+    // Calls to a mocked entry point don't require the contract to be deployed.
+    let mut contract = IContractDispatcher { contract_address: 5.try_into().unwrap() };
+    starknet::testing::mock_call(contract.contract_address, FOO_SELECTOR, ret_data.span());
+    assert_eq(@contract.foo(300), @7, 'contract.foo(300) == 7');
+
+    // A mocked entry point of a deployed contract is not called.
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut contract0 = IContractDispatcher { contract_address: address0 };
+    starknet::testing::mock_call(address0, FOO_SELECTOR, ret_data.span());
+    assert_eq(@contract0.foo(300), @7, 'contract0.foo(300) == 7');
+    assert_eq(@starknet::testing::load(address0, VALUE_ADDRESS), @100, 'contract0.value == 100');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_store_and_load() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut contract0 = IContractDispatcher { contract_address: address0 };
+    assert_eq(@starknet::testing::load(address0, VALUE_ADDRESS), @100, 'contract0.value == 100');
+    starknet::testing::store(address0, VALUE_ADDRESS, 200);
+    assert_eq(@contract0.foo(300), @200, 'contract0.foo(300) == 200');
+    assert_eq(@starknet::testing::load(address0, VALUE_ADDRESS), @300, 'contract0.value == 300');
+    assert_eq(@starknet::testing::load(address0, 1), @0, 'contract0[1] == 0');
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_expect_revert() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractFailedEntrypoint::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut error = Default::default();
+    error.append('Failure');
+    starknet::testing::expect_revert(error.span());
+    let ret_data = call_contract_syscall(address0, FOO_SELECTOR, calldata.span()).unwrap_syscall();
+    assert(ret_data.is_empty(), 'unexpected return data');
+}
+
+#[test]
+#[available_gas(30000000)]
+#[should_panic(expected: ('Failure', 'ENTRYPOINT_FAILED', ))]
+fn test_expect_revert_wrong_error() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractFailedEntrypoint::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut error = Default::default();
+    error.append('Other failure');
+    starknet::testing::expect_revert(error.span());
+    call_contract_syscall(address0, FOO_SELECTOR, calldata.span()).unwrap_syscall();
+}
+
+#[test]
+#[available_gas(30000000)]
+#[should_panic(expected: ('Call did not revert', ))]
+fn test_expect_revert_no_revert() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut contract0 = IContractDispatcher { contract_address: address0 };
+    let mut error = Default::default();
+    error.append('Failure');
+    starknet::testing::expect_revert(error.span());
+    contract0.foo(300);
+}
+
 #[test]
 #[available_gas(30000000)]
-#[should_panic(expected: ('GET_BLOCK_HASH_UNIMPLEMENTED', ))]
 fn test_get_block_hash() {
+    starknet::testing::set_block_hash(5, 0x1234);
+    starknet::testing::set_block_hash(6, 0x5678);
+    assert_eq(@get_block_hash_syscall(5).unwrap_syscall(), @0x1234, 'block_hash(5) == 0x1234');
+    assert_eq(@get_block_hash_syscall(6).unwrap_syscall(), @0x5678, 'block_hash(6) == 0x5678');
+}
+
+#[test]
+#[available_gas(30000000)]
+#[should_panic(expected: ('GET_BLOCK_HASH_NOT_SET', ))]
+fn test_get_block_hash_not_set() {
+    starknet::testing::set_block_hash(5, 0x1234);
     get_block_hash_syscall(0).unwrap_syscall();
 }
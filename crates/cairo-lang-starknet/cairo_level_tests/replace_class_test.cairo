@@ -1,4 +1,3 @@
-use core::debug::PrintTrait;
 use core::result::ResultTrait;
 use core::traits::TryInto;
 use array::ArrayTrait;
@@ -71,3 +70,27 @@ fn test_replace_flow() {
     let mut contract1 = IWithFooDispatcher { contract_address: address0 };
     assert_eq(@contract1.foo(), @100, 'contract1.foo() == 100');
 }
+
+#[test]
+#[available_gas(30000000)]
+#[should_panic(expected: ('ENTRYPOINT_NOT_FOUND', ))]
+fn test_replaced_entry_point_not_found() {
+    let mut calldata = Default::default();
+    calldata.append(100);
+    let (address0, _) = deploy_syscall(
+        ContractA::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), false
+    )
+        .unwrap();
+    let mut contract0 = IWithReplaceDispatcher { contract_address: address0 };
+    contract0.replace();
+
+    // Class B has no `replace` entry point.
+    contract0.replace();
+}
+
+#[test]
+#[available_gas(30000000)]
+fn test_replace_class_hash_not_found() {
+    let mut err = replace_class_syscall(5.try_into().unwrap()).unwrap_err();
+    assert_eq(@err.pop_front().unwrap(), @'CLASS_HASH_NOT_FOUND', 'err == "CLASS_HASH_NOT_FOUND"');
+}
@@ -1,9 +1,9 @@
 use core::option::OptionTrait;
-use core::traits::{Into, TryInto};
+use core::traits::TryInto;
 use array::{ArrayTrait, SpanTrait};
 use starknet::{
-    ClassHash, ContractAddress, EthAddress, StorageAddress, SyscallResult,
-    storage_address_to_felt252, storage_address_try_from_felt252
+    ClassHash, ContractAddress, EthAddress, StorageAddress, storage_address_to_felt252,
+    storage_address_try_from_felt252
 };
 use starknet::eth_address::Felt252TryIntoEthAddress;
 use super::utils::{serialized_element, single_deserialize};
@@ -491,9 +491,10 @@ impl AbiBuilder {
                 Ok(())
             }
             TypeLongId::Snapshot(ty) => self.add_type(db, ty),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
-                Err(ABIError::UnexpectedType)
-            }
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Closure(_)
+            | TypeLongId::Missing(_) => Err(ABIError::UnexpectedType),
         }
     }
 
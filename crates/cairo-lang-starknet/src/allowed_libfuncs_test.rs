@@ -24,7 +24,12 @@ fn experimental_list_includes_all() {
         "set_chain_id",
         "set_nonce",
         "set_signature",
+        "set_block_hash",
         "pop_log",
+        "mock_call",
+        "store",
+        "load",
+        "expect_revert",
         "get_available_gas",
     ];
     pretty_assertions::assert_eq!(
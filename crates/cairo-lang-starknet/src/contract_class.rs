@@ -166,10 +166,9 @@ fn compile_contract_with_prepared_and_checked_db(
 ) -> Result<ContractClass> {
     let SemanticEntryPoints { external, l1_handler, constructor } =
         extract_semantic_entrypoints(db, contract)?;
+    let function_ids: Vec<_> = chain!(&external, &l1_handler, &constructor).cloned().collect();
     let mut sierra_program = db
-        .get_sierra_program_for_functions(
-            chain!(&external, &l1_handler, &constructor).cloned().collect(),
-        )
+        .get_sierra_program_for_functions(function_ids.clone())
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
 
@@ -185,15 +184,21 @@ fn compile_contract_with_prepared_and_checked_db(
         /// TODO(orizi): Validate there is at most one constructor.
         constructor: get_entry_points(db, &constructor, &replacer)?,
     };
+    let mut debug_info = cairo_lang_sierra::debug_info::DebugInfo::extract(&sierra_program);
+    if compiler_config.add_statements_locations {
+        let statements_locations = db
+            .get_sierra_program_statements_locations(function_ids)
+            .to_option()
+            .with_context(|| "Compilation failed without any diagnostics.")?;
+        debug_info.statements_locations = Some((*statements_locations).clone());
+    }
     let contract_class = ContractClass {
         sierra_program: sierra_to_felt252s(
             compiler_version::current_sierra_version_id(),
             compiler_version::current_compiler_version_id(),
             &sierra_program,
         )?,
-        sierra_program_debug_info: Some(cairo_lang_sierra::debug_info::DebugInfo::extract(
-            &sierra_program,
-        )),
+        sierra_program_debug_info: Some(debug_info),
         contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
         entry_points_by_type,
         abi: Some(
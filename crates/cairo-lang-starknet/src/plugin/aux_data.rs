@@ -1,5 +1,6 @@
 use cairo_lang_defs::plugin::GeneratedFileAuxData;
 use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::patcher::Patches;
 use cairo_lang_semantic::plugin::{
@@ -43,6 +44,9 @@ impl PluginAuxData for StarkNetContractAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
 }
 
 /// Contract related auxiliary data of the Starknet plugin.
@@ -76,6 +80,9 @@ impl PluginAuxData for StarkNetABIAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
 }
 
 /// Contract related auxiliary data of the Starknet plugin.
@@ -110,4 +117,7 @@ impl PluginAuxData for StarkNetEventAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
 }
@@ -30,13 +30,13 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
     if module_ast.has_attr(db, DEPRECATED_CONTRACT_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                message: format!(
+            diagnostics: vec![PluginDiagnostic::error(
+                module_ast.stable_ptr().untyped(),
+                format!(
                     "The '{DEPRECATED_CONTRACT_ATTR}' attribute was deprecated, please use \
                      `{CONTRACT_ATTR}` instead.",
                 ),
-                stable_ptr: module_ast.stable_ptr().untyped(),
-            }],
+            )],
             remove_original_item: false,
         };
     }
@@ -47,10 +47,10 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
     let MaybeModuleBody::Some(body) = module_ast.body(db) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                message: "Contracts without body are not supported.".to_string(),
-                stable_ptr: module_ast.stable_ptr().untyped(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                module_ast.stable_ptr().untyped(),
+                "Contracts without body are not supported.".to_string(),
+            )],
             remove_original_item: false,
         };
     };
@@ -59,10 +59,10 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
     }) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                message: "Contracts must define a 'Storage' struct.".to_string(),
-                stable_ptr: module_ast.stable_ptr().untyped(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                module_ast.stable_ptr().untyped(),
+                "Contracts must define a 'Storage' struct.".to_string(),
+            )],
             remove_original_item: false,
         };
     };
@@ -70,10 +70,10 @@ pub fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> Plugi
     if !storage_struct_ast.has_attr(db, STORAGE_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                message: "'Storage' struct must be annotated with #[storage].".to_string(),
-                stable_ptr: module_ast.stable_ptr().untyped(),
-            }],
+            diagnostics: vec![PluginDiagnostic::error(
+                module_ast.stable_ptr().untyped(),
+                "'Storage' struct must be annotated with #[storage].".to_string(),
+            )],
             remove_original_item: false,
         };
     }
@@ -112,10 +112,10 @@ pub fn handle_contract_by_storage(
         MaybeModuleBody::None(empty_body) => {
             return Some(PluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic {
-                    message: "Contracts without body are not supported.".to_string(),
-                    stable_ptr: empty_body.stable_ptr().untyped(),
-                }],
+                diagnostics: vec![PluginDiagnostic::error(
+                    empty_body.stable_ptr().untyped(),
+                    "Contracts without body are not supported.".to_string(),
+                )],
                 remove_original_item: false,
             });
         }
@@ -146,22 +146,22 @@ pub fn handle_contract_by_storage(
         if let Some((has_event_name, stable_ptr)) = event_name_info {
             match (has_event_attr, has_event_name) {
                 (true, false) => {
-                    diagnostics.push(PluginDiagnostic {
-                        message: format!(
+                    diagnostics.push(PluginDiagnostic::error(
+                        stable_ptr,
+                        format!(
                             "Contract type that is marked with #[{EVENT_ATTR}] must be named \
                              `Event`."
                         ),
-                        stable_ptr,
-                    });
+                    ));
                 }
                 (false, true) => {
-                    diagnostics.push(PluginDiagnostic {
-                        message: format!(
+                    diagnostics.push(PluginDiagnostic::error(
+                        stable_ptr,
+                        format!(
                             "Contract type that is named `Event` must be marked with \
                              #[{EVENT_ATTR}]."
                         ),
-                        stable_ptr,
-                    });
+                    ));
                     // The attribute is missing, but we still can't create an empty event.
                     has_event = true;
                 }
@@ -253,10 +253,10 @@ pub fn handle_contract_by_storage(
                 };
                 // TODO(spapini): Check attr args instead.
                 if attr.as_syntax_node().get_text_without_trivia(db) != "#[external(v0)]" {
-                    diagnostics.push(PluginDiagnostic {
-                        message: "Only #[external(v0)] is supported.".to_string(),
-                        stable_ptr: attr.stable_ptr().untyped(),
-                    });
+                    diagnostics.push(PluginDiagnostic::error(
+                        attr.stable_ptr().untyped(),
+                        "Only #[external(v0)] is supported.".to_string(),
+                    ));
                 }
                 let ast::MaybeImplBody::Some(body) = item_impl.body(db) else { continue; };
                 let impl_name = RewriteNode::new_trimmed(item_impl.name(db).as_syntax_node());
@@ -315,7 +315,7 @@ pub fn handle_contract_by_storage(
             use starknet::SyscallResultTraitImpl;
 
             #[cfg(test)]
-            const TEST_CLASS_HASH: felt252 = {test_class_hash};
+            pub(crate) const TEST_CLASS_HASH: felt252 = {test_class_hash};
             $storage_code$
 
             $event_functions$
@@ -325,17 +325,17 @@ pub fn handle_contract_by_storage(
                 $abi_events$
             }}
 
-            mod {EXTERNAL_MODULE} {{$extra_uses$
+            pub mod {EXTERNAL_MODULE} {{$extra_uses$
 
                 $generated_external_functions$
             }}
 
-            mod {L1_HANDLER_MODULE} {{$extra_uses$
+            pub mod {L1_HANDLER_MODULE} {{$extra_uses$
 
                 $generated_l1_handler_functions$
             }}
 
-            mod {CONSTRUCTOR_MODULE} {{$extra_uses$
+            pub mod {CONSTRUCTOR_MODULE} {{$extra_uses$
 
                 $generated_constructor_functions$
             }}
@@ -394,12 +394,10 @@ fn forbid_attribute_in_external_impl(
     attr_name: &str,
 ) {
     if let Some(attr) = impl_item.find_attr(db, attr_name) {
-        diagnostics.push(PluginDiagnostic {
-            message: format!(
-                "The '{attr_name}' attribute is not allowed inside a contract external impl."
-            ),
-            stable_ptr: attr.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            attr.stable_ptr().untyped(),
+            format!("The '{attr_name}' attribute is not allowed inside a contract external impl."),
+        ));
     }
 }
 
@@ -418,10 +416,10 @@ fn handle_entry_point(
     if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
         declaration.generic_params(db)
     {
-        diagnostics.push(PluginDiagnostic {
-            message: "Contract entry points cannot have generic arguments".to_string(),
-            stable_ptr: generic_params.stable_ptr().untyped(),
-        })
+        diagnostics.push(PluginDiagnostic::error(
+            generic_params.stable_ptr().untyped(),
+            "Contract entry points cannot have generic arguments".to_string(),
+        ))
     }
 
     // TODO(ilya): Validate that an account contract has all the required functions.
@@ -475,28 +473,25 @@ fn validate_l1_handler_first_parameter(
     if let Some(first_param) = params.elements(db).get(1) {
         // Validate type
         if !is_felt252(db, &first_param.type_clause(db).ty(db)) {
-            diagnostics.push(PluginDiagnostic {
-                message: "The second parameter of an L1 handler must be of type `felt252`."
-                    .to_string(),
-                stable_ptr: first_param.stable_ptr().untyped(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                first_param.stable_ptr().untyped(),
+                "The second parameter of an L1 handler must be of type `felt252`.".to_string(),
+            ));
         }
 
         // Validate name
         if maybe_strip_underscore(first_param.name(db).text(db).as_str())
             != L1_HANDLER_FIRST_PARAM_NAME
         {
-            diagnostics.push(PluginDiagnostic {
-                message: "The second parameter of an L1 handler must be named 'from_address'."
-                    .to_string(),
-                stable_ptr: first_param.stable_ptr().untyped(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                first_param.stable_ptr().untyped(),
+                "The second parameter of an L1 handler must be named 'from_address'.".to_string(),
+            ));
         }
     } else {
-        diagnostics.push(PluginDiagnostic {
-            message: "An L1 handler must have the 'from_address' as its second parameter."
-                .to_string(),
-            stable_ptr: params.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            params.stable_ptr().untyped(),
+            "An L1 handler must have the 'from_address' as its second parameter.".to_string(),
+        ));
     };
 }
@@ -20,13 +20,13 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     if trait_ast.has_attr(db, DEPRECATED_ABI_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic {
-                message: format!(
+            diagnostics: vec![PluginDiagnostic::error(
+                trait_ast.stable_ptr().untyped(),
+                format!(
                     "The '{DEPRECATED_ABI_ATTR}' attribute was deprecated, please use \
                      `{INTERFACE_ATTR}` instead.",
                 ),
-                stable_ptr: trait_ast.stable_ptr().untyped(),
-            }],
+            )],
             remove_original_item: false,
         };
     }
@@ -38,10 +38,10 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
         MaybeTraitBody::None(empty_body) => {
             return PluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic {
-                    message: "ABIs without body are not supported.".to_string(),
-                    stable_ptr: empty_body.stable_ptr().untyped(),
-                }],
+                diagnostics: vec![PluginDiagnostic::error(
+                    empty_body.stable_ptr().untyped(),
+                    "ABIs without body are not supported.".to_string(),
+                )],
                 remove_original_item: false,
             };
         }
@@ -55,6 +55,8 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     let dispatcher_name = format!("{base_name}DispatcherTrait");
     let contract_caller_name = format!("{base_name}Dispatcher");
     let library_caller_name = format!("{base_name}LibraryDispatcher");
+    // The generated dispatchers have the same visibility as the interface.
+    let visibility = trait_ast.visibility(db).as_item_prefix(db);
     for item_ast in body.items(db).elements(db) {
         match item_ast {
             ast::TraitItem::Function(func) => {
@@ -66,17 +68,17 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                 let mut params = signature.parameters(db).elements(db).into_iter();
                 // The first parameter is the `self` parameter.
                 let Some(self_param) = params.next() else {
-                    diagnostics.push(PluginDiagnostic {
-                        message: "ABI functions must have a `self` parameter.".to_string(),
-                        stable_ptr: declaration.stable_ptr().untyped(),
-                    });
+                    diagnostics.push(PluginDiagnostic::error(
+                        declaration.stable_ptr().untyped(),
+                        "ABI functions must have a `self` parameter.".to_string(),
+                    ));
                     continue;
                 };
                 if self_param.name(db).text(db) != "self" {
-                    diagnostics.push(PluginDiagnostic {
-                        message: "The `self` parameter must be named `self`.".to_string(),
-                        stable_ptr: self_param.stable_ptr().untyped(),
-                    });
+                    diagnostics.push(PluginDiagnostic::error(
+                        self_param.stable_ptr().untyped(),
+                        "The `self` parameter must be named `self`.".to_string(),
+                    ));
                     skip_generation = true;
                 }
 
@@ -84,20 +86,20 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     if is_ref_param(db, &param) {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic {
-                            message: "`ref` parameters are not supported in the ABI of a contract."
+                        diagnostics.push(PluginDiagnostic::error(
+                            param.modifiers(db).stable_ptr().untyped(),
+                            "`ref` parameters are not supported in the ABI of a contract."
                                 .to_string(),
-                            stable_ptr: param.modifiers(db).stable_ptr().untyped(),
-                        })
+                        ))
                     }
 
                     if param.name(db).text(db) == CALLDATA_PARAM_NAME {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic {
-                            message: "Parameter name `__calldata__` cannot be used.".to_string(),
-                            stable_ptr: param.name(db).stable_ptr().untyped(),
-                        })
+                        diagnostics.push(PluginDiagnostic::error(
+                            param.name(db).stable_ptr().untyped(),
+                            "Parameter name `__calldata__` cannot be used.".to_string(),
+                        ))
                     }
 
                     let param_type = param.type_clause(db).ty(db);
@@ -168,13 +170,13 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     let mut builder = PatchBuilder::new(db);
     builder.add_modified(RewriteNode::interpolate_patched(
         &formatdoc!(
-            "trait {dispatcher_name}<T> {{
+            "{visibility}trait {dispatcher_name}<T> {{
             $dispatcher_signatures$
             }}
 
             #[derive(Copy, Drop, storage_access::StorageAccess, Serde)]
-            struct {contract_caller_name} {{
-                contract_address: starknet::ContractAddress,
+            {visibility}struct {contract_caller_name} {{
+                {visibility}contract_address: starknet::ContractAddress,
             }}
 
             impl {contract_caller_name}Impl of {dispatcher_name}<{contract_caller_name}> {{
@@ -182,8 +184,8 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
             }}
 
             #[derive(Copy, Drop, storage_access::StorageAccess, Serde)]
-            struct {library_caller_name} {{
-                class_hash: starknet::ClassHash,
+            {visibility}struct {library_caller_name} {{
+                {visibility}class_hash: starknet::ClassHash,
             }}
 
             impl {library_caller_name}Impl of {dispatcher_name}<{library_caller_name}> {{
@@ -62,16 +62,16 @@ pub fn generate_entry_point_wrapper(
     let raw_output = function.has_attr(db, RAW_OUTPUT_ATTR);
 
     let Some(first_param) = params.next() else {
-        return Err(vec![PluginDiagnostic{
-            message: format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
-            stable_ptr: sig.stable_ptr().untyped(),
-        }]);
+        return Err(vec![PluginDiagnostic::error(
+            sig.stable_ptr().untyped(),
+            format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
+        )]);
     };
     if first_param.name(db).text(db) != "self" {
-        return Err(vec![PluginDiagnostic {
-            message: format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
-            stable_ptr: sig.stable_ptr().untyped(),
-        }]);
+        return Err(vec![PluginDiagnostic::error(
+            sig.stable_ptr().untyped(),
+            format!("`{RAW_OUTPUT_ATTR}` functions must get a 'self' param."),
+        )]);
     };
     let is_snapshot = matches!(first_param.type_clause(db).ty(db), ast::Expr::Unary(_));
     // TODO(spapini): Check modifiers and type.
@@ -84,10 +84,10 @@ pub fn generate_entry_point_wrapper(
 
         let is_ref = is_ref_param(db, &param);
         if raw_output && is_ref {
-            diagnostics.push(PluginDiagnostic {
-                message: format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
-                stable_ptr: param.modifiers(db).stable_ptr().untyped(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                param.modifiers(db).stable_ptr().untyped(),
+                format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
+            ));
         }
 
         let ref_modifier = if is_ref { "ref " } else { "" };
@@ -135,10 +135,10 @@ pub fn generate_entry_point_wrapper(
     };
 
     if raw_output && !return_ty_is_felt252_span {
-        diagnostics.push(PluginDiagnostic {
-            message: format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
-            stable_ptr: ret_type_ptr,
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            ret_type_ptr,
+            format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
+        ));
     }
 
     if !diagnostics.is_empty() {
@@ -175,7 +175,7 @@ pub fn generate_entry_point_wrapper(
 
     Ok(RewriteNode::interpolate_patched(
         "$implicit_precedence$
-        fn $function_name$(mut data: Span::<felt252>) -> Span::<felt252> {
+        pub fn $function_name$(mut data: Span::<felt252>) -> Span::<felt252> {
             internal::revoke_ap_tracking();
             gas::withdraw_gas().expect('Out of gas');
             $arg_definitions$
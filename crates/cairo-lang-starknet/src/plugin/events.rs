@@ -47,10 +47,10 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
     // TODO(spapini): Support generics.
     let generic_params = struct_ast.generic_params(db);
     let OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic {
-            message: "Event structs with generic arguments are unsupported".to_string(),
-            stable_ptr: generic_params.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            generic_params.stable_ptr().untyped(),
+            "Event structs with generic arguments are unsupported".to_string(),
+        ));
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
 
@@ -85,9 +85,10 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
 
     // Add an implementation for `Event<StructName>`.
     let struct_name = RewriteNode::new_trimmed(struct_ast.name(db).as_syntax_node());
+    let visibility = RewriteNode::Text(struct_ast.visibility(db).as_item_prefix(db));
     let event_impl = RewriteNode::interpolate_patched(
         indoc! {"
-            impl $struct_name$IsEvent of starknet::Event<$struct_name$> {
+            $visibility$impl $struct_name$IsEvent of starknet::Event<$struct_name$> {
                 fn append_keys_and_data(
                     self: @$struct_name$, ref keys: Array<felt252>, ref data: Array<felt252>
                 ) {$append_members$
@@ -99,6 +100,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
                 }
             }"},
         [
+            (String::from("visibility"), visibility),
             (String::from("struct_name"), struct_name),
             (String::from("append_members"), append_members),
             (String::from("deserialize_members"), deserialize_members),
@@ -135,17 +137,17 @@ fn get_field_kind(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic {
-            message: "Nested event fields are currently unsupported".to_string(),
-            stable_ptr: member.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            member.stable_ptr().untyped(),
+            "Nested event fields are currently unsupported".to_string(),
+        ));
     }
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic {
-            message: "Serde event fields are currently unsupported".to_string(),
-            stable_ptr: member.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            member.stable_ptr().untyped(),
+            "Serde event fields are currently unsupported".to_string(),
+        ));
     }
 
     if is_key {
@@ -163,14 +165,15 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
     let mut builder = PatchBuilder::new(db);
     let mut diagnostics = vec![];
     let enum_name = RewriteNode::new_trimmed(enum_ast.name(db).as_syntax_node());
+    let visibility = RewriteNode::Text(enum_ast.visibility(db).as_item_prefix(db));
 
     // TODO(spapini): Support generics.
     let generic_params = enum_ast.generic_params(db);
     let OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic {
-            message: "Event enums with generic arguments are unsupported".to_string(),
-            stable_ptr: generic_params.stable_ptr().untyped(),
-        });
+        diagnostics.push(PluginDiagnostic::error(
+            generic_params.stable_ptr().untyped(),
+            "Event enums with generic arguments are unsupported".to_string(),
+        ));
         return PluginResult{ code: None, diagnostics, remove_original_item: false };
     };
 
@@ -222,7 +225,7 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
     // Add an implementation for `Event<StructName>`.
     let event_impl = RewriteNode::interpolate_patched(
         indoc! {"
-            impl $enum_name$IsEvent of starknet::Event<$enum_name$> {
+            $visibility$impl $enum_name$IsEvent of starknet::Event<$enum_name$> {
                 fn append_keys_and_data(
                     self: @$enum_name$, ref keys: Array<felt252>, ref data: Array<felt252>
                 ) {
@@ -239,6 +242,7 @@ pub fn handle_enum(db: &dyn SyntaxGroup, enum_ast: ast::ItemEnum) -> PluginResul
             }
         "},
         [
+            (String::from("visibility"), visibility),
             (String::from("enum_name"), enum_name),
             (String::from("append_variants"), append_variants),
             (String::from("deserialize_variants"), deserialize_variants),
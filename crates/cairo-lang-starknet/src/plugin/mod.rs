@@ -50,6 +50,10 @@ impl MacroPlugin for StarkNetPlugin {
             _ => PluginResult::default(),
         }
     }
+
+    fn executable_attributes(&self) -> Vec<String> {
+        vec![EXTERNAL_ATTR.into(), L1_HANDLER_ATTR.into(), CONSTRUCTOR_ATTR.into()]
+    }
 }
 impl AsDynMacroPlugin for StarkNetPlugin {
     fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
@@ -61,10 +61,10 @@ pub fn handle_storage_struct(
                 ));
             }
             Some((_, _, MappingType::NonLegacy)) => {
-                diagnostics.push(PluginDiagnostic {
-                    message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
-                    stable_ptr: type_ast.stable_ptr().untyped(),
-                });
+                diagnostics.push(PluginDiagnostic::error(
+                    type_ast.stable_ptr().untyped(),
+                    "Non `LegacyMap` mapping is not yet supported.".to_string(),
+                ));
             }
             None => {
                 vars_code.push(RewriteNode::interpolate_patched(
@@ -101,7 +101,7 @@ pub fn handle_storage_struct(
                 }}
                 #[cfg(test)]
                 #[inline(always)]
-                fn contract_state_for_testing() -> ContractState {{
+                pub(crate) fn contract_state_for_testing() -> ContractState {{
                     unsafe_new_contract_state()
                 }}
 
@@ -177,8 +177,8 @@ fn handle_simple_storage_var(address: &str) -> String {
         use super;
 
         #[derive(Copy, Drop)]
-        struct ContractState {{}}
-        trait InternalContractStateTrait {{
+        pub(crate) struct ContractState {{}}
+        pub(crate) trait InternalContractStateTrait {{
             fn address(self: @ContractState) -> starknet::StorageBaseAddress;
             fn read(self: @ContractState) -> $type_name$;
             fn write(ref self: ContractState, value: $type_name$);
@@ -221,8 +221,8 @@ fn handle_legacy_mapping_storage_var(address: &str) -> String {
         use super;
 
         #[derive(Copy, Drop)]
-        struct ContractState {{}}
-        trait InternalContractStateTrait {{
+        pub(crate) struct ContractState {{}}
+        pub(crate) trait InternalContractStateTrait {{
             fn address(self: @ContractState, key: $key_type$) -> starknet::StorageBaseAddress;
             fn read(self: @ContractState, key: $key_type$) -> $value_type$;
             fn write(ref self: ContractState, key: $key_type$, value: $value_type$);
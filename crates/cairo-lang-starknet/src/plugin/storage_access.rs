@@ -108,7 +108,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
 
     let sa_impl = formatdoc!(
         "
-        impl StorageAccess{struct_name} of starknet::StorageAccess::<{struct_name}> {{
+        {visibility}impl StorageAccess{struct_name} of starknet::StorageAccess::<{struct_name}> {{
             fn read(address_domain: u32, base: starknet::StorageBaseAddress) -> \
          starknet::SyscallResult<{struct_name}> {{
                 {reads_values}
@@ -143,6 +143,7 @@ pub fn handle_struct(db: &dyn SyntaxGroup, struct_ast: ast::ItemStruct) -> Plugi
                 {sizes}
             }}
         }}",
+        visibility = struct_ast.visibility(db).as_item_prefix(db),
         struct_name = struct_ast.name(db).as_syntax_node().get_text_without_trivia(db),
         reads_values_at_offset = reads_values_at_offset.join("\n        "),
         reads_values = reads_values.join("\n        "),
@@ -17,6 +17,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("Path")
         .node_with_explicit_kind("Literal", "TerminalLiteralNumber")
         .node_with_explicit_kind("ShortString", "TerminalShortString")
+        .node_with_explicit_kind("String", "TerminalString")
         .node_with_explicit_kind("False", "TerminalFalse")
         .node_with_explicit_kind("True", "TerminalTrue")
         .node("Parenthesized")
@@ -29,10 +30,13 @@ pub fn get_spec() -> Vec<Node> {
         .node("Match")
         .node("If")
         .node("Loop")
+        .node("While")
+        .node("For")
         .node("ErrorPropagate")
         .node("FieldInitShorthand")
         .node("Indexed")
         .node("InlineMacro")
+        .node("Closure")
     )
     .add_separated_list("ExprList", "Expr", "TerminalComma")
     .add_struct(StructBuilder::new("Arg")
@@ -166,6 +170,18 @@ pub fn get_spec() -> Vec<Node> {
         .node("loop_kw", "TerminalLoop")
         .node("body", "ExprBlock")
     )
+    .add_struct(StructBuilder::new("ExprWhile")
+        .node("while_kw", "TerminalWhile")
+        .node("condition", "Expr")
+        .node("body", "ExprBlock")
+    )
+    .add_struct(StructBuilder::new("ExprFor")
+        .node("for_kw", "TerminalFor")
+        .node("pattern", "Pattern")
+        .node("identifier", "TerminalIdentifier")
+        .node("expr", "Expr")
+        .node("body", "ExprBlock")
+    )
     .add_struct(StructBuilder::new("ElseClause")
         .node("else_kw", "TerminalElse")
         .node("else_block_or_if", "BlockOrIf")
@@ -181,8 +197,19 @@ pub fn get_spec() -> Vec<Node> {
     .add_struct(StructBuilder::new("ExprInlineMacro")
         .node("path", "ExprPath")
         .node("bang", "TerminalNot")
-        .node("arguments", "ArgListParenthesized")
+        .node("arguments", "WrappedArgList")
     )
+    .add_enum(EnumBuilder::new("WrappedArgList")
+        .missing("Missing")
+        .node_with_explicit_kind("Parenthesized", "ArgListParenthesized")
+        .node_with_explicit_kind("Bracketed", "ArgListBracketed")
+    )
+    .add_struct(StructBuilder::new("ArgListBracketed")
+        .node("lbrack", "TerminalLBrack")
+        .node("args", "ArgList")
+        .node("rbrack", "TerminalRBrack")
+    )
+    .add_struct(StructBuilder::new("WrappedArgListMissing"))
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
         .node("colon", "TerminalColon")
@@ -218,6 +245,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("Struct")
         .node("Tuple")
         .node("Enum")
+        .node("Range")
         .node_with_explicit_kind("Path", "ExprPath")
     )
     .add_struct(StructBuilder::new("PatternIdentifier")
@@ -255,6 +283,25 @@ pub fn get_spec() -> Vec<Node> {
         .node("pattern", "Pattern")
         .node("rparen", "TerminalRParen")
     )
+    .add_struct(StructBuilder::new("PatternRange")
+        .node("start", "TerminalLiteralNumber")
+        .node("dotdot", "TerminalDotDot")
+        .node("end", "TerminalLiteralNumber")
+    )
+    .add_struct(StructBuilder::new("ExprClosure")
+        .node("wrapper", "ClosureParamWrapper")
+        .node("ret_ty", "OptionReturnTypeClause")
+        .node("expr", "Expr")
+    )
+    .add_enum(EnumBuilder::new("ClosureParamWrapper")
+        .node_with_explicit_kind("Nullary", "TerminalOrOr")
+        .node("NAry")
+    )
+    .add_struct(StructBuilder::new("ClosureParamWrapperNAry")
+        .node("leftor", "TerminalOr")
+        .node("params", "ClosureParamList")
+        .node("rightor", "TerminalOr")
+    )
     // --- Type clauses ---
     // TODO(yuval): support SimpleExpr instead of Expr
     .add_struct(StructBuilder::new("TypeClause").node("colon", "TerminalColon").node("ty", "Expr"))
@@ -311,6 +358,13 @@ pub fn get_spec() -> Vec<Node> {
         .key_node("name", "TerminalIdentifier")
         .node("type_clause", "TypeClause")
     )
+    // A closure parameter, whose type may be omitted and inferred.
+    .add_struct(StructBuilder::new("ClosureParam")
+        .node("modifiers", "ModifierList")
+        .key_node("name", "TerminalIdentifier")
+        .node("type_clause", "OptionTypeClause")
+    )
+    .add_separated_list("ClosureParamList", "ClosureParam", "TerminalComma")
     .add_list("ModifierList", "Modifier")
     .add_enum(EnumBuilder::new("Modifier")
         .node_with_explicit_kind("Ref", "TerminalRef")
@@ -340,6 +394,7 @@ pub fn get_spec() -> Vec<Node> {
     // Struct member and enum variant have the same structure.
     .add_struct(StructBuilder::new("Member")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .key_node("name", "TerminalIdentifier")
         .node("type_clause", "TypeClause")
     )
@@ -370,8 +425,24 @@ pub fn get_spec() -> Vec<Node> {
         .node("rbrack", "TerminalRBrack")
     )
     .add_list("AttributeList", "Attribute")
+    .add_struct(StructBuilder::new("VisibilityDefault"))
+    .add_struct(StructBuilder::new("VisibilityPubArgumentClause")
+        .node("lparen", "TerminalLParen")
+        .node("argument", "TerminalIdentifier")
+        .node("rparen", "TerminalRParen")
+    )
+    .add_option("VisibilityPubArgumentClause")
+    .add_struct(StructBuilder::new("VisibilityPub")
+        .node("pub_kw", "TerminalPub")
+        .node("argument_clause", "OptionVisibilityPubArgumentClause")
+    )
+    .add_enum(EnumBuilder::new("Visibility")
+        .node("Default")
+        .node("Pub")
+    )
     .add_struct(StructBuilder::new("ItemModule")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("module_kw", "TerminalModule")
         .key_node("name", "TerminalIdentifier")
         .node("body", "MaybeModuleBody")
@@ -393,6 +464,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemConstant")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("const_kw", "TerminalConst")
         .key_node("name", "TerminalIdentifier")
         .node("type_clause", "TypeClause")
@@ -402,12 +474,14 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("FunctionWithBody")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
          // TODO(ilya): Use only the name as key node.
         .key_node("declaration", "FunctionDeclaration")
         .node("body", "ExprBlock")
     )
     .add_struct(StructBuilder::new("ItemExternFunction")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("extern_kw", "TerminalExtern")
          // TODO(ilya): Use only the name as key node.
         .key_node("declaration", "FunctionDeclaration")
@@ -415,6 +489,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemExternType")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("extern_kw", "TerminalExtern")
         .node("type_kw", "TerminalType")
         .key_node("name", "TerminalIdentifier")
@@ -424,6 +499,7 @@ pub fn get_spec() -> Vec<Node> {
     // TODO(spapini): consider having specific ItemLists here.
     .add_struct(StructBuilder::new("ItemTrait")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("trait_kw", "TerminalTrait")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -457,6 +533,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemImpl")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("impl_kw", "TerminalImpl")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -494,6 +571,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_struct(StructBuilder::new("ImplItemMissing"))
     .add_struct(StructBuilder::new("ItemImplAlias")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("impl_kw", "TerminalImpl")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -503,6 +581,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemStruct")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("struct_kw", "TerminalStruct")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -512,6 +591,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemEnum")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("enum_kw", "TerminalEnum")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -521,6 +601,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemTypeAlias")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("type_kw", "TerminalType")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
@@ -530,6 +611,7 @@ pub fn get_spec() -> Vec<Node> {
     )
     .add_struct(StructBuilder::new("ItemUse")
         .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
         .node("use_kw", "TerminalUse")
         .key_node("use_path", "UsePath")
         .node("semicolon", "TerminalSemicolon")
@@ -607,6 +689,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
     .add_token_and_terminal("ShortString")
+    .add_token_and_terminal("String")
     .add_keyword_token_and_terminal("As")
     .add_keyword_token_and_terminal("Const")
     .add_keyword_token_and_terminal("Else")
@@ -616,6 +699,8 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("Function")
     .add_keyword_token_and_terminal("If")
     .add_keyword_token_and_terminal("Loop")
+    .add_keyword_token_and_terminal("While")
+    .add_keyword_token_and_terminal("For")
     .add_keyword_token_and_terminal("Impl")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Let")
@@ -624,6 +709,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("Mut")
     .add_keyword_token_and_terminal("NoPanic")
     .add_keyword_token_and_terminal("Of")
+    .add_keyword_token_and_terminal("Pub")
     .add_keyword_token_and_terminal("Ref")
     .add_keyword_token_and_terminal("Continue")
     .add_keyword_token_and_terminal("Return")
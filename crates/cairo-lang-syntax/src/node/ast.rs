@@ -1,4 +1,5 @@
 // Autogenerated file. To regenerate, please run `cargo run --bin generate-syntax`.
+
 #![allow(clippy::match_single_binding)]
 #![allow(clippy::too_many_arguments)]
 #![allow(dead_code)]
@@ -16,6 +17,7 @@ use super::{
     GreenId, GreenNode, SyntaxGroup, SyntaxNode, SyntaxStablePtr, SyntaxStablePtrId, Terminal,
     Token, TypedSyntaxNode,
 };
+
 #[path = "ast_ext.rs"]
 mod ast_ext;
 pub use ast_ext::*;
@@ -23,6 +25,7 @@ pub use ast_ext::*;
 pub struct Trivia(ElementList<Trivium, 1>);
 impl Deref for Trivia {
     type Target = ElementList<Trivium, 1>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -180,6 +183,7 @@ pub enum Expr {
     Path(ExprPath),
     Literal(TerminalLiteralNumber),
     ShortString(TerminalShortString),
+    String(TerminalString),
     False(TerminalFalse),
     True(TerminalTrue),
     Parenthesized(ExprParenthesized),
@@ -192,10 +196,13 @@ pub enum Expr {
     Match(ExprMatch),
     If(ExprIf),
     Loop(ExprLoop),
+    While(ExprWhile),
+    For(ExprFor),
     ErrorPropagate(ExprErrorPropagate),
     FieldInitShorthand(ExprFieldInitShorthand),
     Indexed(ExprIndexed),
     InlineMacro(ExprInlineMacro),
+    Closure(ExprClosure),
     Missing(ExprMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -220,6 +227,11 @@ impl From<TerminalShortStringPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<TerminalStringPtr> for ExprPtr {
+    fn from(value: TerminalStringPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalsePtr> for ExprPtr {
     fn from(value: TerminalFalsePtr) -> Self {
         Self(value.0)
@@ -280,6 +292,16 @@ impl From<ExprLoopPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprWhilePtr> for ExprPtr {
+    fn from(value: ExprWhilePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprForPtr> for ExprPtr {
+    fn from(value: ExprForPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagatePtr> for ExprPtr {
     fn from(value: ExprErrorPropagatePtr) -> Self {
         Self(value.0)
@@ -300,6 +322,11 @@ impl From<ExprInlineMacroPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprClosurePtr> for ExprPtr {
+    fn from(value: ExprClosurePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingPtr> for ExprPtr {
     fn from(value: ExprMissingPtr) -> Self {
         Self(value.0)
@@ -320,6 +347,11 @@ impl From<TerminalShortStringGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<TerminalStringGreen> for ExprGreen {
+    fn from(value: TerminalStringGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalFalseGreen> for ExprGreen {
     fn from(value: TerminalFalseGreen) -> Self {
         Self(value.0)
@@ -380,6 +412,16 @@ impl From<ExprLoopGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprWhileGreen> for ExprGreen {
+    fn from(value: ExprWhileGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprForGreen> for ExprGreen {
+    fn from(value: ExprForGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagateGreen> for ExprGreen {
     fn from(value: ExprErrorPropagateGreen) -> Self {
         Self(value.0)
@@ -400,6 +442,11 @@ impl From<ExprInlineMacroGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprClosureGreen> for ExprGreen {
+    fn from(value: ExprClosureGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingGreen> for ExprGreen {
     fn from(value: ExprMissingGreen) -> Self {
         Self(value.0)
@@ -424,6 +471,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::TerminalShortString => {
                 Expr::ShortString(TerminalShortString::from_syntax_node(db, node))
             }
+            SyntaxKind::TerminalString => Expr::String(TerminalString::from_syntax_node(db, node)),
             SyntaxKind::TerminalFalse => Expr::False(TerminalFalse::from_syntax_node(db, node)),
             SyntaxKind::TerminalTrue => Expr::True(TerminalTrue::from_syntax_node(db, node)),
             SyntaxKind::ExprParenthesized => {
@@ -442,6 +490,8 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprMatch => Expr::Match(ExprMatch::from_syntax_node(db, node)),
             SyntaxKind::ExprIf => Expr::If(ExprIf::from_syntax_node(db, node)),
             SyntaxKind::ExprLoop => Expr::Loop(ExprLoop::from_syntax_node(db, node)),
+            SyntaxKind::ExprWhile => Expr::While(ExprWhile::from_syntax_node(db, node)),
+            SyntaxKind::ExprFor => Expr::For(ExprFor::from_syntax_node(db, node)),
             SyntaxKind::ExprErrorPropagate => {
                 Expr::ErrorPropagate(ExprErrorPropagate::from_syntax_node(db, node))
             }
@@ -452,6 +502,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprInlineMacro => {
                 Expr::InlineMacro(ExprInlineMacro::from_syntax_node(db, node))
             }
+            SyntaxKind::ExprClosure => Expr::Closure(ExprClosure::from_syntax_node(db, node)),
             SyntaxKind::ExprMissing => Expr::Missing(ExprMissing::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Expr"),
         }
@@ -461,6 +512,7 @@ impl TypedSyntaxNode for Expr {
             Expr::Path(x) => x.as_syntax_node(),
             Expr::Literal(x) => x.as_syntax_node(),
             Expr::ShortString(x) => x.as_syntax_node(),
+            Expr::String(x) => x.as_syntax_node(),
             Expr::False(x) => x.as_syntax_node(),
             Expr::True(x) => x.as_syntax_node(),
             Expr::Parenthesized(x) => x.as_syntax_node(),
@@ -473,10 +525,13 @@ impl TypedSyntaxNode for Expr {
             Expr::Match(x) => x.as_syntax_node(),
             Expr::If(x) => x.as_syntax_node(),
             Expr::Loop(x) => x.as_syntax_node(),
+            Expr::While(x) => x.as_syntax_node(),
+            Expr::For(x) => x.as_syntax_node(),
             Expr::ErrorPropagate(x) => x.as_syntax_node(),
             Expr::FieldInitShorthand(x) => x.as_syntax_node(),
             Expr::Indexed(x) => x.as_syntax_node(),
             Expr::InlineMacro(x) => x.as_syntax_node(),
+            Expr::Closure(x) => x.as_syntax_node(),
             Expr::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -494,6 +549,7 @@ impl Expr {
             SyntaxKind::ExprPath => true,
             SyntaxKind::TerminalLiteralNumber => true,
             SyntaxKind::TerminalShortString => true,
+            SyntaxKind::TerminalString => true,
             SyntaxKind::TerminalFalse => true,
             SyntaxKind::TerminalTrue => true,
             SyntaxKind::ExprParenthesized => true,
@@ -506,10 +562,13 @@ impl Expr {
             SyntaxKind::ExprMatch => true,
             SyntaxKind::ExprIf => true,
             SyntaxKind::ExprLoop => true,
+            SyntaxKind::ExprWhile => true,
+            SyntaxKind::ExprFor => true,
             SyntaxKind::ExprErrorPropagate => true,
             SyntaxKind::ExprFieldInitShorthand => true,
             SyntaxKind::ExprIndexed => true,
             SyntaxKind::ExprInlineMacro => true,
+            SyntaxKind::ExprClosure => true,
             SyntaxKind::ExprMissing => true,
             _ => false,
         }
@@ -519,6 +578,7 @@ impl Expr {
 pub struct ExprList(ElementList<Expr, 2>);
 impl Deref for ExprList {
     type Target = ElementList<Expr, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -1050,6 +1110,7 @@ impl TypedSyntaxNode for ExprFieldInitShorthand {
 pub struct ArgList(ElementList<Arg, 2>);
 impl Deref for ArgList {
     type Target = ElementList<Arg, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -1547,6 +1608,7 @@ impl TypedSyntaxNode for PathSegmentWithGenericArgs {
 pub struct ExprPath(ElementList<PathSegment, 2>);
 impl Deref for ExprPath {
     type Target = ElementList<PathSegment, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -3006,6 +3068,7 @@ impl TypedSyntaxNode for ExprMatch {
 pub struct MatchArms(ElementList<MatchArm, 2>);
 impl Deref for MatchArms {
     type Target = ElementList<MatchArm, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -3396,6 +3459,182 @@ impl TypedSyntaxNode for ExprLoop {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprWhile {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprWhile {
+    pub const INDEX_WHILE_KW: usize = 0;
+    pub const INDEX_CONDITION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        while_kw: TerminalWhileGreen,
+        condition: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprWhileGreen {
+        let children: Vec<GreenId> = vec![while_kw.0, condition.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprWhile {
+    pub fn while_kw(&self, db: &dyn SyntaxGroup) -> TerminalWhile {
+        TerminalWhile::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn condition(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhilePtr(pub SyntaxStablePtrId);
+impl ExprWhilePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhileGreen(pub GreenId);
+impl TypedSyntaxNode for ExprWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprWhile);
+    type StablePtr = ExprWhilePtr;
+    type Green = ExprWhileGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalWhile::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprWhile,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprWhile
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprWhilePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprFor {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprFor {
+    pub const INDEX_FOR_KW: usize = 0;
+    pub const INDEX_PATTERN: usize = 1;
+    pub const INDEX_IDENTIFIER: usize = 2;
+    pub const INDEX_EXPR: usize = 3;
+    pub const INDEX_BODY: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        for_kw: TerminalForGreen,
+        pattern: PatternGreen,
+        identifier: TerminalIdentifierGreen,
+        expr: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprForGreen {
+        let children: Vec<GreenId> = vec![for_kw.0, pattern.0, identifier.0, expr.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprForGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprFor,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprFor {
+    pub fn for_kw(&self, db: &dyn SyntaxGroup) -> TerminalFor {
+        TerminalFor::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprForPtr(pub SyntaxStablePtrId);
+impl ExprForPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprForGreen(pub GreenId);
+impl TypedSyntaxNode for ExprFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprFor);
+    type StablePtr = ExprForPtr;
+    type Green = ExprForGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprForGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprFor,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalFor::missing(db).0,
+                    Pattern::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprFor,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprFor
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprForPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ElseClause {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
@@ -3778,7 +4017,7 @@ impl ExprInlineMacro {
         db: &dyn SyntaxGroup,
         path: ExprPathGreen,
         bang: TerminalNotGreen,
-        arguments: ArgListParenthesizedGreen,
+        arguments: WrappedArgListGreen,
     ) -> ExprInlineMacroGreen {
         let children: Vec<GreenId> = vec![path.0, bang.0, arguments.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
@@ -3795,8 +4034,8 @@ impl ExprInlineMacro {
     pub fn bang(&self, db: &dyn SyntaxGroup) -> TerminalNot {
         TerminalNot::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn arguments(&self, db: &dyn SyntaxGroup) -> ArgListParenthesized {
-        ArgListParenthesized::from_syntax_node(db, self.children[2].clone())
+    pub fn arguments(&self, db: &dyn SyntaxGroup) -> WrappedArgList {
+        WrappedArgList::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -3819,7 +4058,7 @@ impl TypedSyntaxNode for ExprInlineMacro {
                 children: vec![
                     ExprPath::missing(db).0,
                     TerminalNot::missing(db).0,
-                    ArgListParenthesized::missing(db).0,
+                    WrappedArgList::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -3848,131 +4087,364 @@ impl TypedSyntaxNode for ExprInlineMacro {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgExpr {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
-}
-impl StructArgExpr {
-    pub const INDEX_COLON: usize = 0;
-    pub const INDEX_EXPR: usize = 1;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        colon: TerminalColonGreen,
-        expr: ExprGreen,
-    ) -> StructArgExprGreen {
-        let children: Vec<GreenId> = vec![colon.0, expr.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl StructArgExpr {
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[1].clone())
-    }
+pub enum WrappedArgList {
+    Parenthesized(ArgListParenthesized),
+    Bracketed(ArgListBracketed),
+    Missing(WrappedArgListMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprPtr(pub SyntaxStablePtrId);
-impl StructArgExprPtr {
+pub struct WrappedArgListPtr(pub SyntaxStablePtrId);
+impl WrappedArgListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
-    type StablePtr = StructArgExprPtr;
-    type Green = StructArgExprGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
-            details: GreenNodeDetails::Node {
-                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
-                width: TextWidth::default(),
-            },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::StructArgExpr,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::StructArgExpr
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgExprPtr(self.node.0.stable_ptr)
+impl From<ArgListParenthesizedPtr> for WrappedArgListPtr {
+    fn from(value: ArgListParenthesizedPtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionStructArgExpr {
-    Empty(OptionStructArgExprEmpty),
-    StructArgExpr(StructArgExpr),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
-impl OptionStructArgExprPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl From<ArgListBracketedPtr> for WrappedArgListPtr {
+    fn from(value: ArgListBracketedPtr) -> Self {
+        Self(value.0)
     }
 }
-impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
-    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
+impl From<WrappedArgListMissingPtr> for WrappedArgListPtr {
+    fn from(value: WrappedArgListMissingPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgExprPtr> for OptionStructArgExprPtr {
-    fn from(value: StructArgExprPtr) -> Self {
+impl From<ArgListParenthesizedGreen> for WrappedArgListGreen {
+    fn from(value: ArgListParenthesizedGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
-    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
+impl From<ArgListBracketedGreen> for WrappedArgListGreen {
+    fn from(value: ArgListBracketedGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgExprGreen> for OptionStructArgExprGreen {
-    fn from(value: StructArgExprGreen) -> Self {
+impl From<WrappedArgListMissingGreen> for WrappedArgListGreen {
+    fn from(value: WrappedArgListMissingGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for OptionStructArgExpr {
+pub struct WrappedArgListGreen(pub GreenId);
+impl TypedSyntaxNode for WrappedArgList {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionStructArgExprPtr;
-    type Green = OptionStructArgExprGreen;
+    type StablePtr = WrappedArgListPtr;
+    type Green = WrappedArgListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        WrappedArgListGreen(WrappedArgListMissing::missing(db).0)
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionStructArgExprEmpty => {
-                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
+            SyntaxKind::ArgListParenthesized => {
+                WrappedArgList::Parenthesized(ArgListParenthesized::from_syntax_node(db, node))
             }
-            SyntaxKind::StructArgExpr => {
-                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
+            SyntaxKind::ArgListBracketed => {
+                WrappedArgList::Bracketed(ArgListBracketed::from_syntax_node(db, node))
             }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionStructArgExpr"
+            SyntaxKind::WrappedArgListMissing => {
+                WrappedArgList::Missing(WrappedArgListMissing::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "WrappedArgList")
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            WrappedArgList::Parenthesized(x) => x.as_syntax_node(),
+            WrappedArgList::Bracketed(x) => x.as_syntax_node(),
+            WrappedArgList::Missing(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        WrappedArgListPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl WrappedArgList {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::ArgListParenthesized => true,
+            SyntaxKind::ArgListBracketed => true,
+            SyntaxKind::WrappedArgListMissing => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ArgListBracketed {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ArgListBracketed {
+    pub const INDEX_LBRACK: usize = 0;
+    pub const INDEX_ARGS: usize = 1;
+    pub const INDEX_RBRACK: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrack: TerminalLBrackGreen,
+        args: ArgListGreen,
+        rbrack: TerminalRBrackGreen,
+    ) -> ArgListBracketedGreen {
+        let children: Vec<GreenId> = vec![lbrack.0, args.0, rbrack.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ArgListBracketedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ArgListBracketed,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ArgListBracketed {
+    pub fn lbrack(&self, db: &dyn SyntaxGroup) -> TerminalLBrack {
+        TerminalLBrack::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn args(&self, db: &dyn SyntaxGroup) -> ArgList {
+        ArgList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrack(&self, db: &dyn SyntaxGroup) -> TerminalRBrack {
+        TerminalRBrack::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ArgListBracketedPtr(pub SyntaxStablePtrId);
+impl ArgListBracketedPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ArgListBracketedGreen(pub GreenId);
+impl TypedSyntaxNode for ArgListBracketed {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ArgListBracketed);
+    type StablePtr = ArgListBracketedPtr;
+    type Green = ArgListBracketedGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ArgListBracketedGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ArgListBracketed,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLBrack::missing(db).0,
+                    ArgList::missing(db).0,
+                    TerminalRBrack::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ArgListBracketed,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ArgListBracketed
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ArgListBracketedPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WrappedArgListMissing {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl WrappedArgListMissing {
+    pub fn new_green(db: &dyn SyntaxGroup) -> WrappedArgListMissingGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        WrappedArgListMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedArgListMissing,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl WrappedArgListMissing {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WrappedArgListMissingPtr(pub SyntaxStablePtrId);
+impl WrappedArgListMissingPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct WrappedArgListMissingGreen(pub GreenId);
+impl TypedSyntaxNode for WrappedArgListMissing {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedArgListMissing);
+    type StablePtr = WrappedArgListMissingPtr;
+    type Green = WrappedArgListMissingGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        WrappedArgListMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedArgListMissing,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::WrappedArgListMissing,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::WrappedArgListMissing
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        WrappedArgListMissingPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructArgExpr {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StructArgExpr {
+    pub const INDEX_COLON: usize = 0;
+    pub const INDEX_EXPR: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        colon: TerminalColonGreen,
+        expr: ExprGreen,
+    ) -> StructArgExprGreen {
+        let children: Vec<GreenId> = vec![colon.0, expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StructArgExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StructArgExpr {
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgExprPtr(pub SyntaxStablePtrId);
+impl StructArgExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
+    type StablePtr = StructArgExprPtr;
+    type Green = StructArgExprGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructArgExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StructArgExpr,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StructArgExpr
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructArgExprPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionStructArgExpr {
+    Empty(OptionStructArgExprEmpty),
+    StructArgExpr(StructArgExpr),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
+impl OptionStructArgExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
+    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructArgExprPtr> for OptionStructArgExprPtr {
+    fn from(value: StructArgExprPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
+    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructArgExprGreen> for OptionStructArgExprGreen {
+    fn from(value: StructArgExprGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for OptionStructArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionStructArgExprPtr;
+    type Green = OptionStructArgExprGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionStructArgExprEmpty => {
+                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::StructArgExpr => {
+                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionStructArgExpr"
             ),
         }
     }
@@ -4096,6 +4568,7 @@ impl StructArgSinglePtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -4293,6 +4766,7 @@ impl StructArg {
 pub struct StructArgList(ElementList<StructArg, 2>);
 impl Deref for StructArgList {
     type Target = ElementList<StructArg, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -4458,6 +4932,7 @@ pub enum Pattern {
     Struct(PatternStruct),
     Tuple(PatternTuple),
     Enum(PatternEnum),
+    Range(PatternRange),
     Path(ExprPath),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -4502,6 +4977,11 @@ impl From<PatternEnumPtr> for PatternPtr {
         Self(value.0)
     }
 }
+impl From<PatternRangePtr> for PatternPtr {
+    fn from(value: PatternRangePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprPathPtr> for PatternPtr {
     fn from(value: ExprPathPtr) -> Self {
         Self(value.0)
@@ -4542,6 +5022,11 @@ impl From<PatternEnumGreen> for PatternGreen {
         Self(value.0)
     }
 }
+impl From<PatternRangeGreen> for PatternGreen {
+    fn from(value: PatternRangeGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprPathGreen> for PatternGreen {
     fn from(value: ExprPathGreen) -> Self {
         Self(value.0)
@@ -4574,6 +5059,7 @@ impl TypedSyntaxNode for Pattern {
             SyntaxKind::PatternStruct => Pattern::Struct(PatternStruct::from_syntax_node(db, node)),
             SyntaxKind::PatternTuple => Pattern::Tuple(PatternTuple::from_syntax_node(db, node)),
             SyntaxKind::PatternEnum => Pattern::Enum(PatternEnum::from_syntax_node(db, node)),
+            SyntaxKind::PatternRange => Pattern::Range(PatternRange::from_syntax_node(db, node)),
             SyntaxKind::ExprPath => Pattern::Path(ExprPath::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Pattern"),
         }
@@ -4587,6 +5073,7 @@ impl TypedSyntaxNode for Pattern {
             Pattern::Struct(x) => x.as_syntax_node(),
             Pattern::Tuple(x) => x.as_syntax_node(),
             Pattern::Enum(x) => x.as_syntax_node(),
+            Pattern::Range(x) => x.as_syntax_node(),
             Pattern::Path(x) => x.as_syntax_node(),
         }
     }
@@ -4608,6 +5095,7 @@ impl Pattern {
             SyntaxKind::PatternStruct => true,
             SyntaxKind::PatternTuple => true,
             SyntaxKind::PatternEnum => true,
+            SyntaxKind::PatternRange => true,
             SyntaxKind::ExprPath => true,
             _ => false,
         }
@@ -4653,6 +5141,7 @@ impl PatternIdentifierPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -4786,6 +5275,7 @@ impl TypedSyntaxNode for PatternStruct {
 pub struct PatternStructParamList(ElementList<PatternStructParam, 2>);
 impl Deref for PatternStructParamList {
     type Target = ElementList<PatternStructParam, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -4946,6 +5436,7 @@ impl TypedSyntaxNode for PatternTuple {
 pub struct PatternList(ElementList<Pattern, 2>);
 impl Deref for PatternList {
     type Target = ElementList<Pattern, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -5292,6 +5783,331 @@ impl TypedSyntaxNode for PatternEnum {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PatternRange {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl PatternRange {
+    pub const INDEX_START: usize = 0;
+    pub const INDEX_DOTDOT: usize = 1;
+    pub const INDEX_END: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        start: TerminalLiteralNumberGreen,
+        dotdot: TerminalDotDotGreen,
+        end: TerminalLiteralNumberGreen,
+    ) -> PatternRangeGreen {
+        let children: Vec<GreenId> = vec![start.0, dotdot.0, end.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        PatternRangeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternRange,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl PatternRange {
+    pub fn start(&self, db: &dyn SyntaxGroup) -> TerminalLiteralNumber {
+        TerminalLiteralNumber::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn dotdot(&self, db: &dyn SyntaxGroup) -> TerminalDotDot {
+        TerminalDotDot::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn end(&self, db: &dyn SyntaxGroup) -> TerminalLiteralNumber {
+        TerminalLiteralNumber::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternRangePtr(pub SyntaxStablePtrId);
+impl PatternRangePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternRangeGreen(pub GreenId);
+impl TypedSyntaxNode for PatternRange {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternRange);
+    type StablePtr = PatternRangePtr;
+    type Green = PatternRangeGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        PatternRangeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::PatternRange,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLiteralNumber::missing(db).0,
+                    TerminalDotDot::missing(db).0,
+                    TerminalLiteralNumber::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::PatternRange,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::PatternRange
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        PatternRangePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprClosure {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprClosure {
+    pub const INDEX_WRAPPER: usize = 0;
+    pub const INDEX_RET_TY: usize = 1;
+    pub const INDEX_EXPR: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        wrapper: ClosureParamWrapperGreen,
+        ret_ty: OptionReturnTypeClauseGreen,
+        expr: ExprGreen,
+    ) -> ExprClosureGreen {
+        let children: Vec<GreenId> = vec![wrapper.0, ret_ty.0, expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprClosureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClosure,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprClosure {
+    pub fn wrapper(&self, db: &dyn SyntaxGroup) -> ClosureParamWrapper {
+        ClosureParamWrapper::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn ret_ty(&self, db: &dyn SyntaxGroup) -> OptionReturnTypeClause {
+        OptionReturnTypeClause::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprClosurePtr(pub SyntaxStablePtrId);
+impl ExprClosurePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprClosureGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClosure {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClosure);
+    type StablePtr = ExprClosurePtr;
+    type Green = ExprClosureGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprClosureGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClosure,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ClosureParamWrapper::missing(db).0,
+                    OptionReturnTypeClause::missing(db).0,
+                    Expr::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprClosure,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprClosure
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprClosurePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ClosureParamWrapper {
+    Nullary(TerminalOrOr),
+    NAry(ClosureParamWrapperNAry),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamWrapperPtr(pub SyntaxStablePtrId);
+impl ClosureParamWrapperPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalOrOrPtr> for ClosureParamWrapperPtr {
+    fn from(value: TerminalOrOrPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ClosureParamWrapperNAryPtr> for ClosureParamWrapperPtr {
+    fn from(value: ClosureParamWrapperNAryPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalOrOrGreen> for ClosureParamWrapperGreen {
+    fn from(value: TerminalOrOrGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ClosureParamWrapperNAryGreen> for ClosureParamWrapperGreen {
+    fn from(value: ClosureParamWrapperNAryGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamWrapperGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamWrapper {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ClosureParamWrapperPtr;
+    type Green = ClosureParamWrapperGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalOrOr => {
+                ClosureParamWrapper::Nullary(TerminalOrOr::from_syntax_node(db, node))
+            }
+            SyntaxKind::ClosureParamWrapperNAry => {
+                ClosureParamWrapper::NAry(ClosureParamWrapperNAry::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "ClosureParamWrapper"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            ClosureParamWrapper::Nullary(x) => x.as_syntax_node(),
+            ClosureParamWrapper::NAry(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamWrapperPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl ClosureParamWrapper {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::TerminalOrOr => true,
+            SyntaxKind::ClosureParamWrapperNAry => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ClosureParamWrapperNAry {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ClosureParamWrapperNAry {
+    pub const INDEX_LEFTOR: usize = 0;
+    pub const INDEX_PARAMS: usize = 1;
+    pub const INDEX_RIGHTOR: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        leftor: TerminalOrGreen,
+        params: ClosureParamListGreen,
+        rightor: TerminalOrGreen,
+    ) -> ClosureParamWrapperNAryGreen {
+        let children: Vec<GreenId> = vec![leftor.0, params.0, rightor.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ClosureParamWrapperNAryGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamWrapperNAry,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ClosureParamWrapperNAry {
+    pub fn leftor(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn params(&self, db: &dyn SyntaxGroup) -> ClosureParamList {
+        ClosureParamList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rightor(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamWrapperNAryPtr(pub SyntaxStablePtrId);
+impl ClosureParamWrapperNAryPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamWrapperNAryGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamWrapperNAry {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParamWrapperNAry);
+    type StablePtr = ClosureParamWrapperNAryPtr;
+    type Green = ClosureParamWrapperNAryGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ClosureParamWrapperNAryGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamWrapperNAry,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalOr::missing(db).0,
+                    ClosureParamList::missing(db).0,
+                    TerminalOr::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ClosureParamWrapperNAry,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ClosureParamWrapperNAry
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamWrapperNAryPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TypeClause {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
@@ -5849,6 +6665,7 @@ impl Statement {
 pub struct StatementList(ElementList<Statement, 1>);
 impl Deref for StatementList {
     type Target = ElementList<Statement, 1>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -6015,6 +6832,7 @@ impl StatementLetPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -6755,6 +7573,7 @@ impl ParamPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -6801,35 +7620,206 @@ impl TypedSyntaxNode for Param {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ModifierList(ElementList<Modifier, 1>);
-impl Deref for ModifierList {
-    type Target = ElementList<Modifier, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct ClosureParam {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl ModifierList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        ModifierListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModifierList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
+impl ClosureParam {
+    pub const INDEX_MODIFIERS: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        modifiers: ModifierListGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: OptionTypeClauseGreen,
+    ) -> ClosureParamGreen {
+        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ClosureParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParam,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListPtr(pub SyntaxStablePtrId);
-impl ModifierListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl ClosureParam {
+    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
+        ModifierList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
+        OptionTypeClause::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListGreen(pub GreenId);
-impl TypedSyntaxNode for ModifierList {
+pub struct ClosureParamPtr(pub SyntaxStablePtrId);
+impl ClosureParamPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParam {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParam);
+    type StablePtr = ClosureParamPtr;
+    type Green = ClosureParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ClosureParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParam,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ModifierList::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionTypeClause::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ClosureParam,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ClosureParam
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ClosureParamList(ElementList<ClosureParam, 2>);
+impl Deref for ClosureParamList {
+    type Target = ElementList<ClosureParam, 2>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ClosureParamList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<ClosureParamListElementOrSeparatorGreen>,
+    ) -> ClosureParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        ClosureParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamListPtr(pub SyntaxStablePtrId);
+impl ClosureParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ClosureParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ClosureParamGreen),
+}
+impl From<TerminalCommaGreen> for ClosureParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        ClosureParamListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<ClosureParamGreen> for ClosureParamListElementOrSeparatorGreen {
+    fn from(value: ClosureParamGreen) -> Self {
+        ClosureParamListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl ClosureParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            ClosureParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            ClosureParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamListGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParamList);
+    type StablePtr = ClosureParamListPtr;
+    type Green = ClosureParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ClosureParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ClosureParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModifierList(ElementList<Modifier, 1>);
+impl Deref for ModifierList {
+    type Target = ElementList<Modifier, 1>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ModifierList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        ModifierListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModifierList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListPtr(pub SyntaxStablePtrId);
+impl ModifierListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListGreen(pub GreenId);
+impl TypedSyntaxNode for ModifierList {
     const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModifierList);
     type StablePtr = ModifierListPtr;
     type Green = ModifierListGreen;
@@ -6928,6 +7918,7 @@ impl Modifier {
 pub struct ParamList(ElementList<Param, 2>);
 impl Deref for ParamList {
     type Target = ElementList<Param, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -7094,6 +8085,7 @@ impl TypedSyntaxNode for ImplicitsClause {
 pub struct ImplicitsList(ElementList<ExprPath, 2>);
 impl Deref for ImplicitsList {
     type Target = ElementList<ExprPath, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -7554,15 +8546,17 @@ pub struct Member {
 }
 impl Member {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_NAME: usize = 2;
+    pub const INDEX_TYPE_CLAUSE: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
         name: TerminalIdentifierGreen,
         type_clause: TypeClauseGreen,
     ) -> MemberGreen {
-        let children: Vec<GreenId> = vec![attributes.0, name.0, type_clause.0];
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, name.0, type_clause.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         MemberGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::Member,
@@ -7574,11 +8568,14 @@ impl Member {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
     }
     pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[2].clone())
+        TypeClause::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -7592,6 +8589,7 @@ impl MemberPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -7608,6 +8606,7 @@ impl TypedSyntaxNode for Member {
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     TypeClause::missing(db).0,
                 ],
@@ -7641,6 +8640,7 @@ impl TypedSyntaxNode for Member {
 pub struct MemberList(ElementList<Member, 2>);
 impl Deref for MemberList {
     type Target = ElementList<Member, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -7951,6 +8951,7 @@ impl Item {
 pub struct ItemList(ElementList<Item, 1>);
 impl Deref for ItemList {
     type Target = ElementList<Item, 1>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -8154,6 +9155,7 @@ impl TypedSyntaxNode for Attribute {
 pub struct AttributeList(ElementList<Attribute, 1>);
 impl Deref for AttributeList {
     type Target = ElementList<Attribute, 1>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -8203,74 +9205,117 @@ impl TypedSyntaxNode for AttributeList {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemModule {
+pub struct VisibilityDefault {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemModule {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_MODULE_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_BODY: usize = 3;
+impl VisibilityDefault {
+    pub fn new_green(db: &dyn SyntaxGroup) -> VisibilityDefaultGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        VisibilityDefaultGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityDefault,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl VisibilityDefault {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityDefaultPtr(pub SyntaxStablePtrId);
+impl VisibilityDefaultPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityDefaultGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityDefault {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityDefault);
+    type StablePtr = VisibilityDefaultPtr;
+    type Green = VisibilityDefaultGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        VisibilityDefaultGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityDefault,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::VisibilityDefault,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::VisibilityDefault
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityDefaultPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VisibilityPubArgumentClause {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl VisibilityPubArgumentClause {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_ARGUMENT: usize = 1;
+    pub const INDEX_RPAREN: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        module_kw: TerminalModuleGreen,
-        name: TerminalIdentifierGreen,
-        body: MaybeModuleBodyGreen,
-    ) -> ItemModuleGreen {
-        let children: Vec<GreenId> = vec![attributes.0, module_kw.0, name.0, body.0];
+        lparen: TerminalLParenGreen,
+        argument: TerminalIdentifierGreen,
+        rparen: TerminalRParenGreen,
+    ) -> VisibilityPubArgumentClauseGreen {
+        let children: Vec<GreenId> = vec![lparen.0, argument.0, rparen.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemModule,
+        VisibilityPubArgumentClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentClause,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemModule {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn module_kw(&self, db: &dyn SyntaxGroup) -> TerminalModule {
-        TerminalModule::from_syntax_node(db, self.children[1].clone())
+impl VisibilityPubArgumentClause {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    pub fn argument(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeModuleBody {
-        MaybeModuleBody::from_syntax_node(db, self.children[3].clone())
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemModulePtr(pub SyntaxStablePtrId);
-impl ItemModulePtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct VisibilityPubArgumentClausePtr(pub SyntaxStablePtrId);
+impl VisibilityPubArgumentClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemModuleGreen(pub GreenId);
-impl TypedSyntaxNode for ItemModule {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemModule);
-    type StablePtr = ItemModulePtr;
-    type Green = ItemModuleGreen;
+pub struct VisibilityPubArgumentClauseGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPubArgumentClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPubArgumentClause);
+    type StablePtr = VisibilityPubArgumentClausePtr;
+    type Green = VisibilityPubArgumentClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemModuleGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemModule,
+        VisibilityPubArgumentClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentClause,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalModule::missing(db).0,
+                    TerminalLParen::missing(db).0,
                     TerminalIdentifier::missing(db).0,
-                    MaybeModuleBody::missing(db).0,
+                    TerminalRParen::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8280,10 +9325,10 @@ impl TypedSyntaxNode for ItemModule {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemModule,
+            SyntaxKind::VisibilityPubArgumentClause,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemModule
+            SyntaxKind::VisibilityPubArgumentClause
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8295,153 +9340,136 @@ impl TypedSyntaxNode for ItemModule {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemModulePtr(self.node.0.stable_ptr)
+        VisibilityPubArgumentClausePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeModuleBody {
-    Some(ModuleBody),
-    None(TerminalSemicolon),
+pub enum OptionVisibilityPubArgumentClause {
+    Empty(OptionVisibilityPubArgumentClauseEmpty),
+    VisibilityPubArgumentClause(VisibilityPubArgumentClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeModuleBodyPtr(pub SyntaxStablePtrId);
-impl MaybeModuleBodyPtr {
+pub struct OptionVisibilityPubArgumentClausePtr(pub SyntaxStablePtrId);
+impl OptionVisibilityPubArgumentClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<ModuleBodyPtr> for MaybeModuleBodyPtr {
-    fn from(value: ModuleBodyPtr) -> Self {
+impl From<OptionVisibilityPubArgumentClauseEmptyPtr> for OptionVisibilityPubArgumentClausePtr {
+    fn from(value: OptionVisibilityPubArgumentClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonPtr> for MaybeModuleBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
+impl From<VisibilityPubArgumentClausePtr> for OptionVisibilityPubArgumentClausePtr {
+    fn from(value: VisibilityPubArgumentClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ModuleBodyGreen> for MaybeModuleBodyGreen {
-    fn from(value: ModuleBodyGreen) -> Self {
+impl From<OptionVisibilityPubArgumentClauseEmptyGreen> for OptionVisibilityPubArgumentClauseGreen {
+    fn from(value: OptionVisibilityPubArgumentClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeModuleBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
+impl From<VisibilityPubArgumentClauseGreen> for OptionVisibilityPubArgumentClauseGreen {
+    fn from(value: VisibilityPubArgumentClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeModuleBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeModuleBody {
+pub struct OptionVisibilityPubArgumentClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionVisibilityPubArgumentClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeModuleBodyPtr;
-    type Green = MaybeModuleBodyGreen;
+    type StablePtr = OptionVisibilityPubArgumentClausePtr;
+    type Green = OptionVisibilityPubArgumentClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::ModuleBody => MaybeModuleBody::Some(ModuleBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeModuleBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            SyntaxKind::OptionVisibilityPubArgumentClauseEmpty => {
+                OptionVisibilityPubArgumentClause::Empty(
+                    OptionVisibilityPubArgumentClauseEmpty::from_syntax_node(db, node),
+                )
             }
-            _ => {
-                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeModuleBody")
+            SyntaxKind::VisibilityPubArgumentClause => {
+                OptionVisibilityPubArgumentClause::VisibilityPubArgumentClause(
+                    VisibilityPubArgumentClause::from_syntax_node(db, node),
+                )
             }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionVisibilityPubArgumentClause"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            MaybeModuleBody::Some(x) => x.as_syntax_node(),
-            MaybeModuleBody::None(x) => x.as_syntax_node(),
+            OptionVisibilityPubArgumentClause::Empty(x) => x.as_syntax_node(),
+            OptionVisibilityPubArgumentClause::VisibilityPubArgumentClause(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeModuleBodyPtr(self.as_syntax_node().0.stable_ptr)
+        OptionVisibilityPubArgumentClausePtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl MaybeModuleBody {
+impl OptionVisibilityPubArgumentClause {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::ModuleBody => true,
-            SyntaxKind::TerminalSemicolon => true,
+            SyntaxKind::OptionVisibilityPubArgumentClauseEmpty => true,
+            SyntaxKind::VisibilityPubArgumentClause => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ModuleBody {
+pub struct OptionVisibilityPubArgumentClauseEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ModuleBody {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ITEMS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: ItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ModuleBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+impl OptionVisibilityPubArgumentClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionVisibilityPubArgumentClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ModuleBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModuleBody,
+        OptionVisibilityPubArgumentClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionVisibilityPubArgumentClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ModuleBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
-        ItemList::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
-    }
-}
+impl OptionVisibilityPubArgumentClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModuleBodyPtr(pub SyntaxStablePtrId);
-impl ModuleBodyPtr {
+pub struct OptionVisibilityPubArgumentClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionVisibilityPubArgumentClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModuleBodyGreen(pub GreenId);
-impl TypedSyntaxNode for ModuleBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModuleBody);
-    type StablePtr = ModuleBodyPtr;
-    type Green = ModuleBodyGreen;
+pub struct OptionVisibilityPubArgumentClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionVisibilityPubArgumentClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> =
+        Some(SyntaxKind::OptionVisibilityPubArgumentClauseEmpty);
+    type StablePtr = OptionVisibilityPubArgumentClauseEmptyPtr;
+    type Green = OptionVisibilityPubArgumentClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ModuleBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModuleBody,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalLBrace::missing(db).0,
-                    ItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
-                ],
-                width: TextWidth::default(),
-            },
+        OptionVisibilityPubArgumentClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionVisibilityPubArgumentClauseEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ModuleBody,
+            SyntaxKind::OptionVisibilityPubArgumentClauseEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ModuleBody
+            SyntaxKind::OptionVisibilityPubArgumentClauseEmpty
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8453,78 +9481,58 @@ impl TypedSyntaxNode for ModuleBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ModuleBodyPtr(self.node.0.stable_ptr)
+        OptionVisibilityPubArgumentClauseEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct FunctionDeclaration {
+pub struct VisibilityPub {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl FunctionDeclaration {
-    pub const INDEX_FUNCTION_KW: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_GENERIC_PARAMS: usize = 2;
-    pub const INDEX_SIGNATURE: usize = 3;
+impl VisibilityPub {
+    pub const INDEX_PUB_KW: usize = 0;
+    pub const INDEX_ARGUMENT_CLAUSE: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        function_kw: TerminalFunctionGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        signature: FunctionSignatureGreen,
-    ) -> FunctionDeclarationGreen {
-        let children: Vec<GreenId> = vec![function_kw.0, name.0, generic_params.0, signature.0];
+        pub_kw: TerminalPubGreen,
+        argument_clause: OptionVisibilityPubArgumentClauseGreen,
+    ) -> VisibilityPubGreen {
+        let children: Vec<GreenId> = vec![pub_kw.0, argument_clause.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        FunctionDeclarationGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionDeclaration,
+        VisibilityPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPub,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl FunctionDeclaration {
-    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
-        TerminalFunction::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[2].clone())
+impl VisibilityPub {
+    pub fn pub_kw(&self, db: &dyn SyntaxGroup) -> TerminalPub {
+        TerminalPub::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
-        FunctionSignature::from_syntax_node(db, self.children[3].clone())
+    pub fn argument_clause(&self, db: &dyn SyntaxGroup) -> OptionVisibilityPubArgumentClause {
+        OptionVisibilityPubArgumentClause::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionDeclarationPtr(pub SyntaxStablePtrId);
-impl FunctionDeclarationPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct VisibilityPubPtr(pub SyntaxStablePtrId);
+impl VisibilityPubPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionDeclarationGreen(pub GreenId);
-impl TypedSyntaxNode for FunctionDeclaration {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionDeclaration);
-    type StablePtr = FunctionDeclarationPtr;
-    type Green = FunctionDeclarationGreen;
+pub struct VisibilityPubGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPub);
+    type StablePtr = VisibilityPubPtr;
+    type Green = VisibilityPubGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        FunctionDeclarationGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionDeclaration,
+        VisibilityPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::VisibilityPub,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalFunction::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    FunctionSignature::missing(db).0,
+                    TerminalPub::missing(db).0,
+                    OptionVisibilityPubArgumentClause::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8534,10 +9542,10 @@ impl TypedSyntaxNode for FunctionDeclaration {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::FunctionDeclaration,
+            SyntaxKind::VisibilityPub,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::FunctionDeclaration
+            SyntaxKind::VisibilityPub
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8549,67 +9557,130 @@ impl TypedSyntaxNode for FunctionDeclaration {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        FunctionDeclarationPtr(self.node.0.stable_ptr)
+        VisibilityPubPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemConstant {
+pub enum Visibility {
+    Default(VisibilityDefault),
+    Pub(VisibilityPub),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPtr(pub SyntaxStablePtrId);
+impl VisibilityPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<VisibilityDefaultPtr> for VisibilityPtr {
+    fn from(value: VisibilityDefaultPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubPtr> for VisibilityPtr {
+    fn from(value: VisibilityPubPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityDefaultGreen> for VisibilityGreen {
+    fn from(value: VisibilityDefaultGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubGreen> for VisibilityGreen {
+    fn from(value: VisibilityPubGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityGreen(pub GreenId);
+impl TypedSyntaxNode for Visibility {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = VisibilityPtr;
+    type Green = VisibilityGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::VisibilityDefault => {
+                Visibility::Default(VisibilityDefault::from_syntax_node(db, node))
+            }
+            SyntaxKind::VisibilityPub => Visibility::Pub(VisibilityPub::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Visibility"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            Visibility::Default(x) => x.as_syntax_node(),
+            Visibility::Pub(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl Visibility {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::VisibilityDefault => true,
+            SyntaxKind::VisibilityPub => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemModule {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemConstant {
+impl ItemModule {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_CONST_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_TYPE_CLAUSE: usize = 3;
-    pub const INDEX_EQ: usize = 4;
-    pub const INDEX_VALUE: usize = 5;
-    pub const INDEX_SEMICOLON: usize = 6;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_MODULE_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_BODY: usize = 4;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        const_kw: TerminalConstGreen,
+        visibility: VisibilityGreen,
+        module_kw: TerminalModuleGreen,
         name: TerminalIdentifierGreen,
-        type_clause: TypeClauseGreen,
-        eq: TerminalEqGreen,
-        value: ExprGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemConstantGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, const_kw.0, name.0, type_clause.0, eq.0, value.0, semicolon.0];
+        body: MaybeModuleBodyGreen,
+    ) -> ItemModuleGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, module_kw.0, name.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemConstantGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemConstant,
+        ItemModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemModule,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemConstant {
+impl ItemModule {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
-        TerminalConst::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[3].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[4].clone())
+    pub fn module_kw(&self, db: &dyn SyntaxGroup) -> TerminalModule {
+        TerminalModule::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn value(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[5].clone())
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeModuleBody {
+        MaybeModuleBody::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemConstantPtr(pub SyntaxStablePtrId);
-impl ItemConstantPtr {
+pub struct ItemModulePtr(pub SyntaxStablePtrId);
+impl ItemModulePtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -8618,28 +9689,27 @@ impl ItemConstantPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemConstantGreen(pub GreenId);
-impl TypedSyntaxNode for ItemConstant {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemConstant);
-    type StablePtr = ItemConstantPtr;
-    type Green = ItemConstantGreen;
+pub struct ItemModuleGreen(pub GreenId);
+impl TypedSyntaxNode for ItemModule {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemModule);
+    type StablePtr = ItemModulePtr;
+    type Green = ItemModuleGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemConstantGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemConstant,
+        ItemModuleGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemModule,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalConst::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalModule::missing(db).0,
                     TerminalIdentifier::missing(db).0,
-                    TypeClause::missing(db).0,
-                    TerminalEq::missing(db).0,
-                    Expr::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    MaybeModuleBody::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8649,10 +9719,10 @@ impl TypedSyntaxNode for ItemConstant {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemConstant,
+            SyntaxKind::ItemModule,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemConstant
+            SyntaxKind::ItemModule
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8664,168 +9734,140 @@ impl TypedSyntaxNode for ItemConstant {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemConstantPtr(self.node.0.stable_ptr)
+        ItemModulePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct FunctionWithBody {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+pub enum MaybeModuleBody {
+    Some(ModuleBody),
+    None(TerminalSemicolon),
 }
-impl FunctionWithBody {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        declaration: FunctionDeclarationGreen,
-        body: ExprBlockGreen,
-    ) -> FunctionWithBodyGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        FunctionWithBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionWithBody,
-            details: GreenNodeDetails::Node { children, width },
-        }))
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeModuleBodyPtr(pub SyntaxStablePtrId);
+impl MaybeModuleBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl FunctionWithBody {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+impl From<ModuleBodyPtr> for MaybeModuleBodyPtr {
+    fn from(value: ModuleBodyPtr) -> Self {
+        Self(value.0)
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
-        ExprBlock::from_syntax_node(db, self.children[2].clone())
+}
+impl From<TerminalSemicolonPtr> for MaybeModuleBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionWithBodyPtr(pub SyntaxStablePtrId);
-impl FunctionWithBodyPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
+impl From<ModuleBodyGreen> for MaybeModuleBodyGreen {
+    fn from(value: ModuleBodyGreen) -> Self {
+        Self(value.0)
     }
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+}
+impl From<TerminalSemicolonGreen> for MaybeModuleBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct FunctionWithBodyGreen(pub GreenId);
-impl TypedSyntaxNode for FunctionWithBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionWithBody);
-    type StablePtr = FunctionWithBodyPtr;
-    type Green = FunctionWithBodyGreen;
+pub struct MaybeModuleBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeModuleBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeModuleBodyPtr;
+    type Green = MaybeModuleBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        FunctionWithBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::FunctionWithBody,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    ExprBlock::missing(db).0,
-                ],
-                width: TextWidth::default(),
-            },
-        }))
+        panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::FunctionWithBody,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::FunctionWithBody
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match kind {
+            SyntaxKind::ModuleBody => MaybeModuleBody::Some(ModuleBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeModuleBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeModuleBody")
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeModuleBody::Some(x) => x.as_syntax_node(),
+            MaybeModuleBody::None(x) => x.as_syntax_node(),
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        FunctionWithBodyPtr(self.node.0.stable_ptr)
+        MaybeModuleBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl MaybeModuleBody {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::ModuleBody => true,
+            SyntaxKind::TerminalSemicolon => true,
+            _ => false,
+        }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternFunction {
+pub struct ModuleBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemExternFunction {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_EXTERN_KW: usize = 1;
-    pub const INDEX_DECLARATION: usize = 2;
-    pub const INDEX_SEMICOLON: usize = 3;
+impl ModuleBody {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ITEMS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        extern_kw: TerminalExternGreen,
-        declaration: FunctionDeclarationGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, extern_kw.0, declaration.0, semicolon.0];
+        lbrace: TerminalLBraceGreen,
+        items: ItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ModuleBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
+        ModuleBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModuleBody,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemExternFunction {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[1].clone())
+impl ModuleBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[2].clone())
+    pub fn items(&self, db: &dyn SyntaxGroup) -> ItemList {
+        ItemList::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
-impl ItemExternFunctionPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct ModuleBodyPtr(pub SyntaxStablePtrId);
+impl ModuleBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
-    type StablePtr = ItemExternFunctionPtr;
-    type Green = ItemExternFunctionGreen;
+pub struct ModuleBodyGreen(pub GreenId);
+impl TypedSyntaxNode for ModuleBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModuleBody);
+    type StablePtr = ModuleBodyPtr;
+    type Green = ModuleBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternFunction,
+        ModuleBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModuleBody,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalExtern::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    ItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8835,10 +9877,10 @@ impl TypedSyntaxNode for ItemExternFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemExternFunction,
+            SyntaxKind::ModuleBody,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemExternFunction
+            SyntaxKind::ModuleBody
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8850,62 +9892,51 @@ impl TypedSyntaxNode for ItemExternFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternFunctionPtr(self.node.0.stable_ptr)
+        ModuleBodyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemExternType {
+pub struct FunctionDeclaration {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemExternType {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_EXTERN_KW: usize = 1;
-    pub const INDEX_TYPE_KW: usize = 2;
-    pub const INDEX_NAME: usize = 3;
-    pub const INDEX_GENERIC_PARAMS: usize = 4;
-    pub const INDEX_SEMICOLON: usize = 5;
+impl FunctionDeclaration {
+    pub const INDEX_FUNCTION_KW: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_GENERIC_PARAMS: usize = 2;
+    pub const INDEX_SIGNATURE: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        extern_kw: TerminalExternGreen,
-        type_kw: TerminalTypeGreen,
+        function_kw: TerminalFunctionGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemExternTypeGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, extern_kw.0, type_kw.0, name.0, generic_params.0, semicolon.0];
+        signature: FunctionSignatureGreen,
+    ) -> FunctionDeclarationGreen {
+        let children: Vec<GreenId> = vec![function_kw.0, name.0, generic_params.0, signature.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        FunctionDeclarationGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionDeclaration,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemExternType {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
-        TerminalExtern::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
-        TerminalType::from_syntax_node(db, self.children[2].clone())
+impl FunctionDeclaration {
+    pub fn function_kw(&self, db: &dyn SyntaxGroup) -> TerminalFunction {
+        TerminalFunction::from_syntax_node(db, self.children[0].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
     }
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[2].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[5].clone())
+    pub fn signature(&self, db: &dyn SyntaxGroup) -> FunctionSignature {
+        FunctionSignature::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
-impl ItemExternTypePtr {
+pub struct FunctionDeclarationPtr(pub SyntaxStablePtrId);
+impl FunctionDeclarationPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -8914,27 +9945,26 @@ impl ItemExternTypePtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemExternTypeGreen(pub GreenId);
-impl TypedSyntaxNode for ItemExternType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
-    type StablePtr = ItemExternTypePtr;
-    type Green = ItemExternTypeGreen;
+pub struct FunctionDeclarationGreen(pub GreenId);
+impl TypedSyntaxNode for FunctionDeclaration {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionDeclaration);
+    type StablePtr = FunctionDeclarationPtr;
+    type Green = FunctionDeclarationGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemExternTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemExternType,
+        FunctionDeclarationGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionDeclaration,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalExtern::missing(db).0,
-                    TerminalType::missing(db).0,
+                    TerminalFunction::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    FunctionSignature::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8944,10 +9974,10 @@ impl TypedSyntaxNode for ItemExternType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemExternType,
+            SyntaxKind::FunctionDeclaration,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemExternType
+            SyntaxKind::FunctionDeclaration
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -8959,57 +9989,80 @@ impl TypedSyntaxNode for ItemExternType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemExternTypePtr(self.node.0.stable_ptr)
+        FunctionDeclarationPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemTrait {
+pub struct ItemConstant {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemTrait {
+impl ItemConstant {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_TRAIT_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_BODY: usize = 4;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_CONST_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_TYPE_CLAUSE: usize = 4;
+    pub const INDEX_EQ: usize = 5;
+    pub const INDEX_VALUE: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        trait_kw: TerminalTraitGreen,
+        visibility: VisibilityGreen,
+        const_kw: TerminalConstGreen,
         name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        body: MaybeTraitBodyGreen,
-    ) -> ItemTraitGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, trait_kw.0, name.0, generic_params.0, body.0];
+        type_clause: TypeClauseGreen,
+        eq: TerminalEqGreen,
+        value: ExprGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemConstantGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            const_kw.0,
+            name.0,
+            type_clause.0,
+            eq.0,
+            value.0,
+            semicolon.0,
+        ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemTrait {
+impl ItemConstant {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
-        TerminalTrait::from_syntax_node(db, self.children[1].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
+        TerminalConst::from_syntax_node(db, self.children[2].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
-        MaybeTraitBody::from_syntax_node(db, self.children[4].clone())
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn value(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitPtr(pub SyntaxStablePtrId);
-impl ItemTraitPtr {
+pub struct ItemConstantPtr(pub SyntaxStablePtrId);
+impl ItemConstantPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -9018,26 +10071,30 @@ impl ItemTraitPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemTraitGreen(pub GreenId);
-impl TypedSyntaxNode for ItemTrait {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
-    type StablePtr = ItemTraitPtr;
-    type Green = ItemTraitGreen;
+pub struct ItemConstantGreen(pub GreenId);
+impl TypedSyntaxNode for ItemConstant {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemConstant);
+    type StablePtr = ItemConstantPtr;
+    type Green = ItemConstantGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemTraitGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemTrait,
+        ItemConstantGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemConstant,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalTrait::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalConst::missing(db).0,
                     TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    MaybeTraitBody::missing(db).0,
+                    TypeClause::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -9047,10 +10104,10 @@ impl TypedSyntaxNode for ItemTrait {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemTrait,
+            SyntaxKind::ItemConstant,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemTrait
+            SyntaxKind::ItemConstant
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9062,140 +10119,79 @@ impl TypedSyntaxNode for ItemTrait {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemTraitPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeTraitBody {
-    Some(TraitBody),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
-impl MaybeTraitBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
-    fn from(value: TraitBodyPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
-    fn from(value: TraitBodyGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeTraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeTraitBodyPtr;
-    type Green = MaybeTraitBodyGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
-            SyntaxKind::TerminalSemicolon => {
-                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
-            }
-            _ => {
-                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
-            }
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeTraitBody::Some(x) => x.as_syntax_node(),
-            MaybeTraitBody::None(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
-    }
-}
-impl MaybeTraitBody {
-    #[allow(clippy::match_like_matches_macro)]
-    pub fn is_variant(kind: SyntaxKind) -> bool {
-        match kind {
-            SyntaxKind::TraitBody => true,
-            SyntaxKind::TerminalSemicolon => true,
-            _ => false,
-        }
+        ItemConstantPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitBody {
+pub struct FunctionWithBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitBody {
-    pub const INDEX_LBRACE: usize = 0;
-    pub const INDEX_ITEMS: usize = 1;
-    pub const INDEX_RBRACE: usize = 2;
+impl FunctionWithBody {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_DECLARATION: usize = 2;
+    pub const INDEX_BODY: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lbrace: TerminalLBraceGreen,
-        items: TraitItemListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> TraitBodyGreen {
-        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        declaration: FunctionDeclarationGreen,
+        body: ExprBlockGreen,
+    ) -> FunctionWithBodyGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, declaration.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        FunctionWithBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionWithBody,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl TraitBody {
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+impl FunctionWithBody {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
-        TraitItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyPtr(pub SyntaxStablePtrId);
-impl TraitBodyPtr {
+pub struct FunctionWithBodyPtr(pub SyntaxStablePtrId);
+impl FunctionWithBodyPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            FunctionDeclarationGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitBodyGreen(pub GreenId);
-impl TypedSyntaxNode for TraitBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
-    type StablePtr = TraitBodyPtr;
-    type Green = TraitBodyGreen;
+pub struct FunctionWithBodyGreen(pub GreenId);
+impl TypedSyntaxNode for FunctionWithBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::FunctionWithBody);
+    type StablePtr = FunctionWithBodyPtr;
+    type Green = FunctionWithBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitBody,
+        FunctionWithBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::FunctionWithBody,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLBrace::missing(db).0,
-                    TraitItemList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    ExprBlock::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -9205,10 +10201,10 @@ impl TypedSyntaxNode for TraitBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitBody,
+            SyntaxKind::FunctionWithBody,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitBody
+            SyntaxKind::FunctionWithBody
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9220,180 +10216,99 @@ impl TypedSyntaxNode for TraitBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitBodyPtr(self.node.0.stable_ptr)
+        FunctionWithBodyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemList(ElementList<TraitItem, 1>);
-impl Deref for TraitItemList {
-    type Target = ElementList<TraitItem, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct ItemExternFunction {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl TraitItemList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
+impl ItemExternFunction {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_EXTERN_KW: usize = 2;
+    pub const INDEX_DECLARATION: usize = 3;
+    pub const INDEX_SEMICOLON: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        extern_kw: TerminalExternGreen,
+        declaration: FunctionDeclarationGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternFunctionGreen {
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, extern_kw.0, declaration.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
+            details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListPtr(pub SyntaxStablePtrId);
-impl TraitItemListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+impl ItemExternFunction {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemListGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
-    type StablePtr = TraitItemListPtr;
-    type Green = TraitItemListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemList,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemListPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum TraitItem {
-    Function(TraitItemFunction),
-    Missing(TraitItemMissing),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemPtr(pub SyntaxStablePtrId);
-impl TraitItemPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<TraitItemFunctionPtr> for TraitItemPtr {
-    fn from(value: TraitItemFunctionPtr) -> Self {
-        Self(value.0)
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-}
-impl From<TraitItemMissingPtr> for TraitItemPtr {
-    fn from(value: TraitItemMissingPtr) -> Self {
-        Self(value.0)
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[2].clone())
     }
-}
-impl From<TraitItemFunctionGreen> for TraitItemGreen {
-    fn from(value: TraitItemFunctionGreen) -> Self {
-        Self(value.0)
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[3].clone())
     }
-}
-impl From<TraitItemMissingGreen> for TraitItemGreen {
-    fn from(value: TraitItemMissingGreen) -> Self {
-        Self(value.0)
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItem {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = TraitItemPtr;
-    type Green = TraitItemGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemGreen(TraitItemMissing::missing(db).0)
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::TraitItemFunction => {
-                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
-            }
-            SyntaxKind::TraitItemMissing => {
-                TraitItem::Missing(TraitItemMissing::from_syntax_node(db, node))
-            }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            TraitItem::Function(x) => x.as_syntax_node(),
-            TraitItem::Missing(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
-    }
-}
-impl TraitItem {
-    #[allow(clippy::match_like_matches_macro)]
-    pub fn is_variant(kind: SyntaxKind) -> bool {
-        match kind {
-            SyntaxKind::TraitItemFunction => true,
-            SyntaxKind::TraitItemMissing => true,
-            _ => false,
+pub struct ItemExternFunctionPtr(pub SyntaxStablePtrId);
+impl ItemExternFunctionPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            FunctionDeclarationGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
         }
     }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemMissing {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
-}
-impl TraitItemMissing {
-    pub fn new_green(db: &dyn SyntaxGroup) -> TraitItemMissingGreen {
-        let children: Vec<GreenId> = vec![];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitItemMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemMissing,
-            details: GreenNodeDetails::Node { children, width },
-        }))
-    }
-}
-impl TraitItemMissing {}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemMissingPtr(pub SyntaxStablePtrId);
-impl TraitItemMissingPtr {
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemMissingGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemMissing {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemMissing);
-    type StablePtr = TraitItemMissingPtr;
-    type Green = TraitItemMissingGreen;
+pub struct ItemExternFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternFunction);
+    type StablePtr = ItemExternFunctionPtr;
+    type Green = ItemExternFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemMissing,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        ItemExternFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternFunction,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitItemMissing,
+            SyntaxKind::ItemExternFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitItemMissing
+            SyntaxKind::ItemExternFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9405,72 +10320,105 @@ impl TypedSyntaxNode for TraitItemMissing {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemMissingPtr(self.node.0.stable_ptr)
+        ItemExternFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TraitItemFunction {
+pub struct ItemExternType {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl TraitItemFunction {
+impl ItemExternType {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_DECLARATION: usize = 1;
-    pub const INDEX_BODY: usize = 2;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_EXTERN_KW: usize = 2;
+    pub const INDEX_TYPE_KW: usize = 3;
+    pub const INDEX_NAME: usize = 4;
+    pub const INDEX_GENERIC_PARAMS: usize = 5;
+    pub const INDEX_SEMICOLON: usize = 6;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        declaration: FunctionDeclarationGreen,
-        body: MaybeTraitFunctionBodyGreen,
-    ) -> TraitItemFunctionGreen {
-        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
+        visibility: VisibilityGreen,
+        extern_kw: TerminalExternGreen,
+        type_kw: TerminalTypeGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemExternTypeGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            extern_kw.0,
+            type_kw.0,
+            name.0,
+            generic_params.0,
+            semicolon.0,
+        ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl TraitItemFunction {
+impl ItemExternType {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
-        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
-        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
+    pub fn extern_kw(&self, db: &dyn SyntaxGroup) -> TerminalExtern {
+        TerminalExtern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
+        TerminalType::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
-impl TraitItemFunctionPtr {
-    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
+pub struct ItemExternTypePtr(pub SyntaxStablePtrId);
+impl ItemExternTypePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            FunctionDeclarationGreen(key_fields[0])
+            TerminalIdentifierGreen(key_fields[0])
         } else {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TraitItemFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TraitItemFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
-    type StablePtr = TraitItemFunctionPtr;
-    type Green = TraitItemFunctionGreen;
+pub struct ItemExternTypeGreen(pub GreenId);
+impl TypedSyntaxNode for ItemExternType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemExternType);
+    type StablePtr = ItemExternTypePtr;
+    type Green = ItemExternTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TraitItemFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TraitItemFunction,
+        ItemExternTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemExternType,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    FunctionDeclaration::missing(db).0,
-                    MaybeTraitFunctionBody::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalExtern::missing(db).0,
+                    TerminalType::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -9480,10 +10428,10 @@ impl TypedSyntaxNode for TraitItemFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TraitItemFunction,
+            SyntaxKind::ItemExternType,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TraitItemFunction
+            SyntaxKind::ItemExternType
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9495,146 +10443,62 @@ impl TypedSyntaxNode for TraitItemFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TraitItemFunctionPtr(self.node.0.stable_ptr)
+        ItemExternTypePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeTraitFunctionBody {
-    Some(ExprBlock),
-    None(TerminalSemicolon),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitFunctionBodyPtr(pub SyntaxStablePtrId);
-impl MaybeTraitFunctionBodyPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<ExprBlockPtr> for MaybeTraitFunctionBodyPtr {
-    fn from(value: ExprBlockPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonPtr> for MaybeTraitFunctionBodyPtr {
-    fn from(value: TerminalSemicolonPtr) -> Self {
-        Self(value.0)
-    }
+pub struct ItemTrait {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
 }
-impl From<ExprBlockGreen> for MaybeTraitFunctionBodyGreen {
-    fn from(value: ExprBlockGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<TerminalSemicolonGreen> for MaybeTraitFunctionBodyGreen {
-    fn from(value: TerminalSemicolonGreen) -> Self {
-        Self(value.0)
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeTraitFunctionBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeTraitFunctionBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeTraitFunctionBodyPtr;
-    type Green = MaybeTraitFunctionBodyGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::ExprBlock => {
-                MaybeTraitFunctionBody::Some(ExprBlock::from_syntax_node(db, node))
-            }
-            SyntaxKind::TerminalSemicolon => {
-                MaybeTraitFunctionBody::None(TerminalSemicolon::from_syntax_node(db, node))
-            }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "MaybeTraitFunctionBody"
-            ),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            MaybeTraitFunctionBody::Some(x) => x.as_syntax_node(),
-            MaybeTraitFunctionBody::None(x) => x.as_syntax_node(),
-        }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeTraitFunctionBodyPtr(self.as_syntax_node().0.stable_ptr)
-    }
-}
-impl MaybeTraitFunctionBody {
-    #[allow(clippy::match_like_matches_macro)]
-    pub fn is_variant(kind: SyntaxKind) -> bool {
-        match kind {
-            SyntaxKind::ExprBlock => true,
-            SyntaxKind::TerminalSemicolon => true,
-            _ => false,
-        }
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemImpl {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
-}
-impl ItemImpl {
+impl ItemTrait {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_IMPL_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_OF_KW: usize = 4;
-    pub const INDEX_TRAIT_PATH: usize = 5;
-    pub const INDEX_BODY: usize = 6;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_TRAIT_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_BODY: usize = 5;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        impl_kw: TerminalImplGreen,
+        visibility: VisibilityGreen,
+        trait_kw: TerminalTraitGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        of_kw: TerminalOfGreen,
-        trait_path: ExprPathGreen,
-        body: MaybeImplBodyGreen,
-    ) -> ItemImplGreen {
+        body: MaybeTraitBodyGreen,
+    ) -> ItemTraitGreen {
         let children: Vec<GreenId> =
-            vec![attributes.0, impl_kw.0, name.0, generic_params.0, of_kw.0, trait_path.0, body.0];
+            vec![attributes.0, visibility.0, trait_kw.0, name.0, generic_params.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemImpl {
+impl ItemTrait {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
-        TerminalImpl::from_syntax_node(db, self.children[1].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trait_kw(&self, db: &dyn SyntaxGroup) -> TerminalTrait {
+        TerminalTrait::from_syntax_node(db, self.children[2].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
-        TerminalOf::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[5].clone())
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
-        MaybeImplBody::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitBody {
+        MaybeTraitBody::from_syntax_node(db, self.children[5].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplPtr(pub SyntaxStablePtrId);
-impl ItemImplPtr {
+pub struct ItemTraitPtr(pub SyntaxStablePtrId);
+impl ItemTraitPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -9643,28 +10507,28 @@ impl ItemImplPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplGreen(pub GreenId);
-impl TypedSyntaxNode for ItemImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
-    type StablePtr = ItemImplPtr;
-    type Green = ItemImplGreen;
+pub struct ItemTraitGreen(pub GreenId);
+impl TypedSyntaxNode for ItemTrait {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemTrait);
+    type StablePtr = ItemTraitPtr;
+    type Green = ItemTraitGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImpl,
+        ItemTraitGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemTrait,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalImpl::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalTrait::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalOf::missing(db).0,
-                    ExprPath::missing(db).0,
-                    MaybeImplBody::missing(db).0,
+                    MaybeTraitBody::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -9674,10 +10538,10 @@ impl TypedSyntaxNode for ItemImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemImpl,
+            SyntaxKind::ItemTrait,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemImpl
+            SyntaxKind::ItemTrait
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9689,137 +10553,139 @@ impl TypedSyntaxNode for ItemImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemImplPtr(self.node.0.stable_ptr)
+        ItemTraitPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum MaybeImplBody {
-    Some(ImplBody),
+pub enum MaybeTraitBody {
+    Some(TraitBody),
     None(TerminalSemicolon),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
-impl MaybeImplBodyPtr {
+pub struct MaybeTraitBodyPtr(pub SyntaxStablePtrId);
+impl MaybeTraitBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<ImplBodyPtr> for MaybeImplBodyPtr {
-    fn from(value: ImplBodyPtr) -> Self {
+impl From<TraitBodyPtr> for MaybeTraitBodyPtr {
+    fn from(value: TraitBodyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
+impl From<TerminalSemicolonPtr> for MaybeTraitBodyPtr {
     fn from(value: TerminalSemicolonPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ImplBodyGreen> for MaybeImplBodyGreen {
-    fn from(value: ImplBodyGreen) -> Self {
+impl From<TraitBodyGreen> for MaybeTraitBodyGreen {
+    fn from(value: TraitBodyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
+impl From<TerminalSemicolonGreen> for MaybeTraitBodyGreen {
     fn from(value: TerminalSemicolonGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MaybeImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for MaybeImplBody {
+pub struct MaybeTraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeTraitBody {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = MaybeImplBodyPtr;
-    type Green = MaybeImplBodyGreen;
+    type StablePtr = MaybeTraitBodyPtr;
+    type Green = MaybeTraitBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
+            SyntaxKind::TraitBody => MaybeTraitBody::Some(TraitBody::from_syntax_node(db, node)),
             SyntaxKind::TerminalSemicolon => {
-                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+                MaybeTraitBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => {
+                panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeTraitBody")
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            MaybeImplBody::Some(x) => x.as_syntax_node(),
-            MaybeImplBody::None(x) => x.as_syntax_node(),
+            MaybeTraitBody::Some(x) => x.as_syntax_node(),
+            MaybeTraitBody::None(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
+        MaybeTraitBodyPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl MaybeImplBody {
+impl MaybeTraitBody {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::ImplBody => true,
+            SyntaxKind::TraitBody => true,
             SyntaxKind::TerminalSemicolon => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplBody {
+pub struct TraitBody {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ImplBody {
+impl TraitBody {
     pub const INDEX_LBRACE: usize = 0;
     pub const INDEX_ITEMS: usize = 1;
     pub const INDEX_RBRACE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         lbrace: TerminalLBraceGreen,
-        items: ImplItemListGreen,
+        items: TraitItemListGreen,
         rbrace: TerminalRBraceGreen,
-    ) -> ImplBodyGreen {
+    ) -> TraitBodyGreen {
         let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ImplBody {
+impl TraitBody {
     pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
         TerminalLBrace::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn items(&self, db: &dyn SyntaxGroup) -> ImplItemList {
-        ImplItemList::from_syntax_node(db, self.children[1].clone())
+    pub fn items(&self, db: &dyn SyntaxGroup) -> TraitItemList {
+        TraitItemList::from_syntax_node(db, self.children[1].clone())
     }
     pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
         TerminalRBrace::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyPtr(pub SyntaxStablePtrId);
-impl ImplBodyPtr {
+pub struct TraitBodyPtr(pub SyntaxStablePtrId);
+impl TraitBodyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplBodyGreen(pub GreenId);
-impl TypedSyntaxNode for ImplBody {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
-    type StablePtr = ImplBodyPtr;
-    type Green = ImplBodyGreen;
+pub struct TraitBodyGreen(pub GreenId);
+impl TypedSyntaxNode for TraitBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitBody);
+    type StablePtr = TraitBodyPtr;
+    type Green = TraitBodyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplBodyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplBody,
+        TraitBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitBody,
             details: GreenNodeDetails::Node {
                 children: vec![
                     TerminalLBrace::missing(db).0,
-                    ImplItemList::missing(db).0,
+                    TraitItemList::missing(db).0,
                     TerminalRBrace::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -9830,10 +10696,10 @@ impl TypedSyntaxNode for ImplBody {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ImplBody,
+            SyntaxKind::TraitBody,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ImplBody
+            SyntaxKind::TraitBody
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -9845,22 +10711,23 @@ impl TypedSyntaxNode for ImplBody {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplBodyPtr(self.node.0.stable_ptr)
+        TraitBodyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplItemList(ElementList<ImplItem, 1>);
-impl Deref for ImplItemList {
-    type Target = ElementList<ImplItem, 1>;
+pub struct TraitItemList(ElementList<TraitItem, 1>);
+impl Deref for TraitItemList {
+    type Target = ElementList<TraitItem, 1>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl ImplItemList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ImplItemGreen>) -> ImplItemListGreen {
+impl TraitItemList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<TraitItemGreen>) -> TraitItemListGreen {
         let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        ImplItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplItemList,
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
             details: GreenNodeDetails::Node {
                 children: children.iter().map(|x| x.0).collect(),
                 width,
@@ -9869,21 +10736,21 @@ impl ImplItemList {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemListPtr(pub SyntaxStablePtrId);
-impl ImplItemListPtr {
+pub struct TraitItemListPtr(pub SyntaxStablePtrId);
+impl TraitItemListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemListGreen(pub GreenId);
-impl TypedSyntaxNode for ImplItemList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplItemList);
-    type StablePtr = ImplItemListPtr;
-    type Green = ImplItemListGreen;
+pub struct TraitItemListGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemList);
+    type StablePtr = TraitItemListPtr;
+    type Green = TraitItemListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplItemListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplItemList,
+        TraitItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemList,
             details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         }))
     }
@@ -9897,281 +10764,117 @@ impl TypedSyntaxNode for ImplItemList {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplItemListPtr(self.node.0.stable_ptr)
+        TraitItemListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum ImplItem {
-    Function(FunctionWithBody),
-    Constant(ItemConstant),
-    Module(ItemModule),
-    Use(ItemUse),
-    ExternFunction(ItemExternFunction),
-    ExternType(ItemExternType),
-    Trait(ItemTrait),
-    Impl(ItemImpl),
-    ImplAlias(ItemImplAlias),
-    Struct(ItemStruct),
-    Enum(ItemEnum),
-    TypeAlias(ItemTypeAlias),
-    Missing(ImplItemMissing),
+pub enum TraitItem {
+    Function(TraitItemFunction),
+    Missing(TraitItemMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemPtr(pub SyntaxStablePtrId);
-impl ImplItemPtr {
+pub struct TraitItemPtr(pub SyntaxStablePtrId);
+impl TraitItemPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<FunctionWithBodyPtr> for ImplItemPtr {
-    fn from(value: FunctionWithBodyPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemConstantPtr> for ImplItemPtr {
-    fn from(value: ItemConstantPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemModulePtr> for ImplItemPtr {
-    fn from(value: ItemModulePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemUsePtr> for ImplItemPtr {
-    fn from(value: ItemUsePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternFunctionPtr> for ImplItemPtr {
-    fn from(value: ItemExternFunctionPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternTypePtr> for ImplItemPtr {
-    fn from(value: ItemExternTypePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemTraitPtr> for ImplItemPtr {
-    fn from(value: ItemTraitPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemImplPtr> for ImplItemPtr {
-    fn from(value: ItemImplPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemImplAliasPtr> for ImplItemPtr {
-    fn from(value: ItemImplAliasPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemStructPtr> for ImplItemPtr {
-    fn from(value: ItemStructPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemEnumPtr> for ImplItemPtr {
-    fn from(value: ItemEnumPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemTypeAliasPtr> for ImplItemPtr {
-    fn from(value: ItemTypeAliasPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ImplItemMissingPtr> for ImplItemPtr {
-    fn from(value: ImplItemMissingPtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<FunctionWithBodyGreen> for ImplItemGreen {
-    fn from(value: FunctionWithBodyGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemConstantGreen> for ImplItemGreen {
-    fn from(value: ItemConstantGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemModuleGreen> for ImplItemGreen {
-    fn from(value: ItemModuleGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemUseGreen> for ImplItemGreen {
-    fn from(value: ItemUseGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternFunctionGreen> for ImplItemGreen {
-    fn from(value: ItemExternFunctionGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemExternTypeGreen> for ImplItemGreen {
-    fn from(value: ItemExternTypeGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemTraitGreen> for ImplItemGreen {
-    fn from(value: ItemTraitGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemImplGreen> for ImplItemGreen {
-    fn from(value: ItemImplGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemImplAliasGreen> for ImplItemGreen {
-    fn from(value: ItemImplAliasGreen) -> Self {
-        Self(value.0)
-    }
-}
-impl From<ItemStructGreen> for ImplItemGreen {
-    fn from(value: ItemStructGreen) -> Self {
+impl From<TraitItemFunctionPtr> for TraitItemPtr {
+    fn from(value: TraitItemFunctionPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ItemEnumGreen> for ImplItemGreen {
-    fn from(value: ItemEnumGreen) -> Self {
+impl From<TraitItemMissingPtr> for TraitItemPtr {
+    fn from(value: TraitItemMissingPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<ItemTypeAliasGreen> for ImplItemGreen {
-    fn from(value: ItemTypeAliasGreen) -> Self {
+impl From<TraitItemFunctionGreen> for TraitItemGreen {
+    fn from(value: TraitItemFunctionGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<ImplItemMissingGreen> for ImplItemGreen {
-    fn from(value: ImplItemMissingGreen) -> Self {
+impl From<TraitItemMissingGreen> for TraitItemGreen {
+    fn from(value: TraitItemMissingGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemGreen(pub GreenId);
-impl TypedSyntaxNode for ImplItem {
+pub struct TraitItemGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItem {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ImplItemPtr;
-    type Green = ImplItemGreen;
+    type StablePtr = TraitItemPtr;
+    type Green = TraitItemGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplItemGreen(ImplItemMissing::missing(db).0)
+        TraitItemGreen(TraitItemMissing::missing(db).0)
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::FunctionWithBody => {
-                ImplItem::Function(FunctionWithBody::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemConstant => {
-                ImplItem::Constant(ItemConstant::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemModule => ImplItem::Module(ItemModule::from_syntax_node(db, node)),
-            SyntaxKind::ItemUse => ImplItem::Use(ItemUse::from_syntax_node(db, node)),
-            SyntaxKind::ItemExternFunction => {
-                ImplItem::ExternFunction(ItemExternFunction::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemExternType => {
-                ImplItem::ExternType(ItemExternType::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemTrait => ImplItem::Trait(ItemTrait::from_syntax_node(db, node)),
-            SyntaxKind::ItemImpl => ImplItem::Impl(ItemImpl::from_syntax_node(db, node)),
-            SyntaxKind::ItemImplAlias => {
-                ImplItem::ImplAlias(ItemImplAlias::from_syntax_node(db, node))
-            }
-            SyntaxKind::ItemStruct => ImplItem::Struct(ItemStruct::from_syntax_node(db, node)),
-            SyntaxKind::ItemEnum => ImplItem::Enum(ItemEnum::from_syntax_node(db, node)),
-            SyntaxKind::ItemTypeAlias => {
-                ImplItem::TypeAlias(ItemTypeAlias::from_syntax_node(db, node))
+            SyntaxKind::TraitItemFunction => {
+                TraitItem::Function(TraitItemFunction::from_syntax_node(db, node))
             }
-            SyntaxKind::ImplItemMissing => {
-                ImplItem::Missing(ImplItemMissing::from_syntax_node(db, node))
+            SyntaxKind::TraitItemMissing => {
+                TraitItem::Missing(TraitItemMissing::from_syntax_node(db, node))
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ImplItem"),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "TraitItem"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            ImplItem::Function(x) => x.as_syntax_node(),
-            ImplItem::Constant(x) => x.as_syntax_node(),
-            ImplItem::Module(x) => x.as_syntax_node(),
-            ImplItem::Use(x) => x.as_syntax_node(),
-            ImplItem::ExternFunction(x) => x.as_syntax_node(),
-            ImplItem::ExternType(x) => x.as_syntax_node(),
-            ImplItem::Trait(x) => x.as_syntax_node(),
-            ImplItem::Impl(x) => x.as_syntax_node(),
-            ImplItem::ImplAlias(x) => x.as_syntax_node(),
-            ImplItem::Struct(x) => x.as_syntax_node(),
-            ImplItem::Enum(x) => x.as_syntax_node(),
-            ImplItem::TypeAlias(x) => x.as_syntax_node(),
-            ImplItem::Missing(x) => x.as_syntax_node(),
+            TraitItem::Function(x) => x.as_syntax_node(),
+            TraitItem::Missing(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplItemPtr(self.as_syntax_node().0.stable_ptr)
+        TraitItemPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl ImplItem {
+impl TraitItem {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::FunctionWithBody => true,
-            SyntaxKind::ItemConstant => true,
-            SyntaxKind::ItemModule => true,
-            SyntaxKind::ItemUse => true,
-            SyntaxKind::ItemExternFunction => true,
-            SyntaxKind::ItemExternType => true,
-            SyntaxKind::ItemTrait => true,
-            SyntaxKind::ItemImpl => true,
-            SyntaxKind::ItemImplAlias => true,
-            SyntaxKind::ItemStruct => true,
-            SyntaxKind::ItemEnum => true,
-            SyntaxKind::ItemTypeAlias => true,
-            SyntaxKind::ImplItemMissing => true,
+            SyntaxKind::TraitItemFunction => true,
+            SyntaxKind::TraitItemMissing => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ImplItemMissing {
+pub struct TraitItemMissing {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ImplItemMissing {
-    pub fn new_green(db: &dyn SyntaxGroup) -> ImplItemMissingGreen {
+impl TraitItemMissing {
+    pub fn new_green(db: &dyn SyntaxGroup) -> TraitItemMissingGreen {
         let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ImplItemMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplItemMissing,
+        TraitItemMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemMissing,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ImplItemMissing {}
+impl TraitItemMissing {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemMissingPtr(pub SyntaxStablePtrId);
-impl ImplItemMissingPtr {
+pub struct TraitItemMissingPtr(pub SyntaxStablePtrId);
+impl TraitItemMissingPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ImplItemMissingGreen(pub GreenId);
-impl TypedSyntaxNode for ImplItemMissing {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplItemMissing);
-    type StablePtr = ImplItemMissingPtr;
-    type Green = ImplItemMissingGreen;
+pub struct TraitItemMissingGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemMissing {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemMissing);
+    type StablePtr = TraitItemMissingPtr;
+    type Green = TraitItemMissingGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ImplItemMissingGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ImplItemMissing,
+        TraitItemMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemMissing,
             details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         }))
     }
@@ -10179,10 +10882,10 @@ impl TypedSyntaxNode for ImplItemMissing {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ImplItemMissing,
+            SyntaxKind::TraitItemMissing,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ImplItemMissing
+            SyntaxKind::TraitItemMissing
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10194,97 +10897,73 @@ impl TypedSyntaxNode for ImplItemMissing {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ImplItemMissingPtr(self.node.0.stable_ptr)
+        TraitItemMissingPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemImplAlias {
+pub struct TraitItemFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemImplAlias {
+impl TraitItemFunction {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_IMPL_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_EQ: usize = 4;
-    pub const INDEX_IMPL_PATH: usize = 5;
-    pub const INDEX_SEMICOLON: usize = 6;
+    pub const INDEX_DECLARATION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        impl_kw: TerminalImplGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        eq: TerminalEqGreen,
-        impl_path: ExprPathGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> ItemImplAliasGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, impl_kw.0, name.0, generic_params.0, eq.0, impl_path.0, semicolon.0];
+        declaration: FunctionDeclarationGreen,
+        body: MaybeTraitFunctionBodyGreen,
+    ) -> TraitItemFunctionGreen {
+        let children: Vec<GreenId> = vec![attributes.0, declaration.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemImplAliasGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImplAlias,
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemImplAlias {
+impl TraitItemFunction {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
-        TerminalImpl::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn impl_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[5].clone())
+    pub fn declaration(&self, db: &dyn SyntaxGroup) -> FunctionDeclaration {
+        FunctionDeclaration::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeTraitFunctionBody {
+        MaybeTraitFunctionBody::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplAliasPtr(pub SyntaxStablePtrId);
-impl ItemImplAliasPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+pub struct TraitItemFunctionPtr(pub SyntaxStablePtrId);
+impl TraitItemFunctionPtr {
+    pub fn declaration_green(self, db: &dyn SyntaxGroup) -> FunctionDeclarationGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
+            FunctionDeclarationGreen(key_fields[0])
         } else {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemImplAliasGreen(pub GreenId);
-impl TypedSyntaxNode for ItemImplAlias {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImplAlias);
-    type StablePtr = ItemImplAliasPtr;
-    type Green = ItemImplAliasGreen;
+pub struct TraitItemFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TraitItemFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TraitItemFunction);
+    type StablePtr = TraitItemFunctionPtr;
+    type Green = TraitItemFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemImplAliasGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemImplAlias,
+        TraitItemFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TraitItemFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalImpl::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    OptionWrappedGenericParamList::missing(db).0,
-                    TerminalEq::missing(db).0,
-                    ExprPath::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
+                    FunctionDeclaration::missing(db).0,
+                    MaybeTraitFunctionBody::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -10294,10 +10973,10 @@ impl TypedSyntaxNode for ItemImplAlias {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemImplAlias,
+            SyntaxKind::TraitItemFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemImplAlias
+            SyntaxKind::TraitItemFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10309,74 +10988,159 @@ impl TypedSyntaxNode for ItemImplAlias {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemImplAliasPtr(self.node.0.stable_ptr)
+        TraitItemFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemStruct {
+pub enum MaybeTraitFunctionBody {
+    Some(ExprBlock),
+    None(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitFunctionBodyPtr(pub SyntaxStablePtrId);
+impl MaybeTraitFunctionBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<ExprBlockPtr> for MaybeTraitFunctionBodyPtr {
+    fn from(value: ExprBlockPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeTraitFunctionBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprBlockGreen> for MaybeTraitFunctionBodyGreen {
+    fn from(value: ExprBlockGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeTraitFunctionBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeTraitFunctionBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeTraitFunctionBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeTraitFunctionBodyPtr;
+    type Green = MaybeTraitFunctionBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ExprBlock => {
+                MaybeTraitFunctionBody::Some(ExprBlock::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalSemicolon => {
+                MaybeTraitFunctionBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "MaybeTraitFunctionBody"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeTraitFunctionBody::Some(x) => x.as_syntax_node(),
+            MaybeTraitFunctionBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeTraitFunctionBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl MaybeTraitFunctionBody {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::ExprBlock => true,
+            SyntaxKind::TerminalSemicolon => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemImpl {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl ItemStruct {
+impl ItemImpl {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_STRUCT_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_LBRACE: usize = 4;
-    pub const INDEX_MEMBERS: usize = 5;
-    pub const INDEX_RBRACE: usize = 6;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_IMPL_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_OF_KW: usize = 5;
+    pub const INDEX_TRAIT_PATH: usize = 6;
+    pub const INDEX_BODY: usize = 7;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
-        struct_kw: TerminalStructGreen,
+        visibility: VisibilityGreen,
+        impl_kw: TerminalImplGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        members: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemStructGreen {
+        of_kw: TerminalOfGreen,
+        trait_path: ExprPathGreen,
+        body: MaybeImplBodyGreen,
+    ) -> ItemImplGreen {
         let children: Vec<GreenId> = vec![
             attributes.0,
-            struct_kw.0,
+            visibility.0,
+            impl_kw.0,
             name.0,
             generic_params.0,
-            lbrace.0,
-            members.0,
-            rbrace.0,
+            of_kw.0,
+            trait_path.0,
+            body.0,
         ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl ItemStruct {
+impl ItemImpl {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
-        TerminalStruct::from_syntax_node(db, self.children[1].clone())
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
+        TerminalImpl::from_syntax_node(db, self.children[2].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+    pub fn of_kw(&self, db: &dyn SyntaxGroup) -> TerminalOf {
+        TerminalOf::from_syntax_node(db, self.children[5].clone())
     }
-    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
+    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[6].clone())
     }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> MaybeImplBody {
+        MaybeImplBody::from_syntax_node(db, self.children[7].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructPtr(pub SyntaxStablePtrId);
-impl ItemStructPtr {
+pub struct ItemImplPtr(pub SyntaxStablePtrId);
+impl ItemImplPtr {
     pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
         let ptr = db.lookup_intern_stable_ptr(self.0);
         if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
@@ -10385,28 +11149,30 @@ impl ItemStructPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ItemStructGreen(pub GreenId);
-impl TypedSyntaxNode for ItemStruct {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
-    type StablePtr = ItemStructPtr;
-    type Green = ItemStructGreen;
+pub struct ItemImplGreen(pub GreenId);
+impl TypedSyntaxNode for ItemImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImpl);
+    type StablePtr = ItemImplPtr;
+    type Green = ItemImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ItemStructGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemStruct,
+        ItemImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImpl,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
-                    TerminalStruct::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalImpl::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
-                    TerminalLBrace::missing(db).0,
-                    MemberList::missing(db).0,
-                    TerminalRBrace::missing(db).0,
+                    TerminalOf::missing(db).0,
+                    ExprPath::missing(db).0,
+                    MaybeImplBody::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -10416,10 +11182,10 @@ impl TypedSyntaxNode for ItemStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemStruct,
+            SyntaxKind::ItemImpl,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemStruct
+            SyntaxKind::ItemImpl
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10431,62 +11197,841 @@ impl TypedSyntaxNode for ItemStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemStructPtr(self.node.0.stable_ptr)
+        ItemImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ItemEnum {
-    node: SyntaxNode,
-    children: Vec<SyntaxNode>,
+pub enum MaybeImplBody {
+    Some(ImplBody),
+    None(TerminalSemicolon),
 }
-impl ItemEnum {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_ENUM_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_LBRACE: usize = 4;
-    pub const INDEX_VARIANTS: usize = 5;
-    pub const INDEX_RBRACE: usize = 6;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        enum_kw: TerminalEnumGreen,
-        name: TerminalIdentifierGreen,
-        generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        variants: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
-    ) -> ItemEnumGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, enum_kw.0, name.0, generic_params.0, lbrace.0, variants.0, rbrace.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ItemEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ItemEnum,
-            details: GreenNodeDetails::Node { children, width },
-        }))
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeImplBodyPtr(pub SyntaxStablePtrId);
+impl MaybeImplBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl ItemEnum {
+impl From<ImplBodyPtr> for MaybeImplBodyPtr {
+    fn from(value: ImplBodyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for MaybeImplBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplBodyGreen> for MaybeImplBodyGreen {
+    fn from(value: ImplBodyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for MaybeImplBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MaybeImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for MaybeImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = MaybeImplBodyPtr;
+    type Green = MaybeImplBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::ImplBody => MaybeImplBody::Some(ImplBody::from_syntax_node(db, node)),
+            SyntaxKind::TerminalSemicolon => {
+                MaybeImplBody::None(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "MaybeImplBody"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            MaybeImplBody::Some(x) => x.as_syntax_node(),
+            MaybeImplBody::None(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MaybeImplBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl MaybeImplBody {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::ImplBody => true,
+            SyntaxKind::TerminalSemicolon => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplBody {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ImplBody {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_ITEMS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrace: TerminalLBraceGreen,
+        items: ImplItemListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ImplBodyGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, items.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ImplBody {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn items(&self, db: &dyn SyntaxGroup) -> ImplItemList {
+        ImplItemList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplBodyPtr(pub SyntaxStablePtrId);
+impl ImplBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplBodyGreen(pub GreenId);
+impl TypedSyntaxNode for ImplBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplBody);
+    type StablePtr = ImplBodyPtr;
+    type Green = ImplBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplBodyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplBody,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLBrace::missing(db).0,
+                    ImplItemList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ImplBody,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ImplBody
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplBodyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplItemList(ElementList<ImplItem, 1>);
+impl Deref for ImplItemList {
+    type Target = ElementList<ImplItem, 1>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ImplItemList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ImplItemGreen>) -> ImplItemListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        ImplItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplItemList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemListPtr(pub SyntaxStablePtrId);
+impl ImplItemListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemListGreen(pub GreenId);
+impl TypedSyntaxNode for ImplItemList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplItemList);
+    type StablePtr = ImplItemListPtr;
+    type Green = ImplItemListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplItemListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplItemList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplItemListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ImplItem {
+    Function(FunctionWithBody),
+    Constant(ItemConstant),
+    Module(ItemModule),
+    Use(ItemUse),
+    ExternFunction(ItemExternFunction),
+    ExternType(ItemExternType),
+    Trait(ItemTrait),
+    Impl(ItemImpl),
+    ImplAlias(ItemImplAlias),
+    Struct(ItemStruct),
+    Enum(ItemEnum),
+    TypeAlias(ItemTypeAlias),
+    Missing(ImplItemMissing),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemPtr(pub SyntaxStablePtrId);
+impl ImplItemPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<FunctionWithBodyPtr> for ImplItemPtr {
+    fn from(value: FunctionWithBodyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemConstantPtr> for ImplItemPtr {
+    fn from(value: ItemConstantPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemModulePtr> for ImplItemPtr {
+    fn from(value: ItemModulePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemUsePtr> for ImplItemPtr {
+    fn from(value: ItemUsePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternFunctionPtr> for ImplItemPtr {
+    fn from(value: ItemExternFunctionPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternTypePtr> for ImplItemPtr {
+    fn from(value: ItemExternTypePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTraitPtr> for ImplItemPtr {
+    fn from(value: ItemTraitPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplPtr> for ImplItemPtr {
+    fn from(value: ItemImplPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplAliasPtr> for ImplItemPtr {
+    fn from(value: ItemImplAliasPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemStructPtr> for ImplItemPtr {
+    fn from(value: ItemStructPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemEnumPtr> for ImplItemPtr {
+    fn from(value: ItemEnumPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTypeAliasPtr> for ImplItemPtr {
+    fn from(value: ItemTypeAliasPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplItemMissingPtr> for ImplItemPtr {
+    fn from(value: ImplItemMissingPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<FunctionWithBodyGreen> for ImplItemGreen {
+    fn from(value: FunctionWithBodyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemConstantGreen> for ImplItemGreen {
+    fn from(value: ItemConstantGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemModuleGreen> for ImplItemGreen {
+    fn from(value: ItemModuleGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemUseGreen> for ImplItemGreen {
+    fn from(value: ItemUseGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternFunctionGreen> for ImplItemGreen {
+    fn from(value: ItemExternFunctionGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemExternTypeGreen> for ImplItemGreen {
+    fn from(value: ItemExternTypeGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTraitGreen> for ImplItemGreen {
+    fn from(value: ItemTraitGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplGreen> for ImplItemGreen {
+    fn from(value: ItemImplGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemImplAliasGreen> for ImplItemGreen {
+    fn from(value: ItemImplAliasGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemStructGreen> for ImplItemGreen {
+    fn from(value: ItemStructGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemEnumGreen> for ImplItemGreen {
+    fn from(value: ItemEnumGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ItemTypeAliasGreen> for ImplItemGreen {
+    fn from(value: ItemTypeAliasGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ImplItemMissingGreen> for ImplItemGreen {
+    fn from(value: ImplItemMissingGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemGreen(pub GreenId);
+impl TypedSyntaxNode for ImplItem {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ImplItemPtr;
+    type Green = ImplItemGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplItemGreen(ImplItemMissing::missing(db).0)
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::FunctionWithBody => {
+                ImplItem::Function(FunctionWithBody::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemConstant => {
+                ImplItem::Constant(ItemConstant::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemModule => ImplItem::Module(ItemModule::from_syntax_node(db, node)),
+            SyntaxKind::ItemUse => ImplItem::Use(ItemUse::from_syntax_node(db, node)),
+            SyntaxKind::ItemExternFunction => {
+                ImplItem::ExternFunction(ItemExternFunction::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemExternType => {
+                ImplItem::ExternType(ItemExternType::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemTrait => ImplItem::Trait(ItemTrait::from_syntax_node(db, node)),
+            SyntaxKind::ItemImpl => ImplItem::Impl(ItemImpl::from_syntax_node(db, node)),
+            SyntaxKind::ItemImplAlias => {
+                ImplItem::ImplAlias(ItemImplAlias::from_syntax_node(db, node))
+            }
+            SyntaxKind::ItemStruct => ImplItem::Struct(ItemStruct::from_syntax_node(db, node)),
+            SyntaxKind::ItemEnum => ImplItem::Enum(ItemEnum::from_syntax_node(db, node)),
+            SyntaxKind::ItemTypeAlias => {
+                ImplItem::TypeAlias(ItemTypeAlias::from_syntax_node(db, node))
+            }
+            SyntaxKind::ImplItemMissing => {
+                ImplItem::Missing(ImplItemMissing::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "ImplItem"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            ImplItem::Function(x) => x.as_syntax_node(),
+            ImplItem::Constant(x) => x.as_syntax_node(),
+            ImplItem::Module(x) => x.as_syntax_node(),
+            ImplItem::Use(x) => x.as_syntax_node(),
+            ImplItem::ExternFunction(x) => x.as_syntax_node(),
+            ImplItem::ExternType(x) => x.as_syntax_node(),
+            ImplItem::Trait(x) => x.as_syntax_node(),
+            ImplItem::Impl(x) => x.as_syntax_node(),
+            ImplItem::ImplAlias(x) => x.as_syntax_node(),
+            ImplItem::Struct(x) => x.as_syntax_node(),
+            ImplItem::Enum(x) => x.as_syntax_node(),
+            ImplItem::TypeAlias(x) => x.as_syntax_node(),
+            ImplItem::Missing(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplItemPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl ImplItem {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::FunctionWithBody => true,
+            SyntaxKind::ItemConstant => true,
+            SyntaxKind::ItemModule => true,
+            SyntaxKind::ItemUse => true,
+            SyntaxKind::ItemExternFunction => true,
+            SyntaxKind::ItemExternType => true,
+            SyntaxKind::ItemTrait => true,
+            SyntaxKind::ItemImpl => true,
+            SyntaxKind::ItemImplAlias => true,
+            SyntaxKind::ItemStruct => true,
+            SyntaxKind::ItemEnum => true,
+            SyntaxKind::ItemTypeAlias => true,
+            SyntaxKind::ImplItemMissing => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImplItemMissing {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ImplItemMissing {
+    pub fn new_green(db: &dyn SyntaxGroup) -> ImplItemMissingGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ImplItemMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplItemMissing,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ImplItemMissing {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemMissingPtr(pub SyntaxStablePtrId);
+impl ImplItemMissingPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ImplItemMissingGreen(pub GreenId);
+impl TypedSyntaxNode for ImplItemMissing {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ImplItemMissing);
+    type StablePtr = ImplItemMissingPtr;
+    type Green = ImplItemMissingGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ImplItemMissingGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ImplItemMissing,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ImplItemMissing,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ImplItemMissing
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ImplItemMissingPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemImplAlias {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemImplAlias {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_IMPL_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_EQ: usize = 5;
+    pub const INDEX_IMPL_PATH: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        impl_kw: TerminalImplGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        eq: TerminalEqGreen,
+        impl_path: ExprPathGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> ItemImplAliasGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            impl_kw.0,
+            name.0,
+            generic_params.0,
+            eq.0,
+            impl_path.0,
+            semicolon.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemImplAliasGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImplAlias,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemImplAlias {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
+        TerminalImpl::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn impl_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemImplAliasPtr(pub SyntaxStablePtrId);
+impl ItemImplAliasPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemImplAliasGreen(pub GreenId);
+impl TypedSyntaxNode for ItemImplAlias {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemImplAlias);
+    type StablePtr = ItemImplAliasPtr;
+    type Green = ItemImplAliasGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemImplAliasGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemImplAlias,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalImpl::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    ExprPath::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemImplAlias,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemImplAlias
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemImplAliasPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemStruct {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemStruct {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_STRUCT_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_LBRACE: usize = 5;
+    pub const INDEX_MEMBERS: usize = 6;
+    pub const INDEX_RBRACE: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        struct_kw: TerminalStructGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        members: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemStructGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            struct_kw.0,
+            name.0,
+            generic_params.0,
+            lbrace.0,
+            members.0,
+            rbrace.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemStruct {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn struct_kw(&self, db: &dyn SyntaxGroup) -> TerminalStruct {
+        TerminalStruct::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructPtr(pub SyntaxStablePtrId);
+impl ItemStructPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ItemStructGreen(pub GreenId);
+impl TypedSyntaxNode for ItemStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ItemStruct);
+    type StablePtr = ItemStructPtr;
+    type Green = ItemStructGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ItemStructGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemStruct,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalStruct::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    OptionWrappedGenericParamList::missing(db).0,
+                    TerminalLBrace::missing(db).0,
+                    MemberList::missing(db).0,
+                    TerminalRBrace::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemStruct,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemStruct
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemStructPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemEnum {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ItemEnum {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_ENUM_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_LBRACE: usize = 5;
+    pub const INDEX_VARIANTS: usize = 6;
+    pub const INDEX_RBRACE: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        enum_kw: TerminalEnumGreen,
+        name: TerminalIdentifierGreen,
+        generic_params: OptionWrappedGenericParamListGreen,
+        lbrace: TerminalLBraceGreen,
+        variants: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> ItemEnumGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            enum_kw.0,
+            name.0,
+            generic_params.0,
+            lbrace.0,
+            variants.0,
+            rbrace.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ItemEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ItemEnum,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ItemEnum {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn enum_kw(&self, db: &dyn SyntaxGroup) -> TerminalEnum {
-        TerminalEnum::from_syntax_node(db, self.children[1].clone())
+        TerminalEnum::from_syntax_node(db, self.children[2].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
     }
     pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[4].clone())
+        TerminalLBrace::from_syntax_node(db, self.children[5].clone())
     }
     pub fn variants(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[5].clone())
+        MemberList::from_syntax_node(db, self.children[6].clone())
     }
     pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[6].clone())
+        TerminalRBrace::from_syntax_node(db, self.children[7].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -10500,6 +12045,7 @@ impl ItemEnumPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -10516,6 +12062,7 @@ impl TypedSyntaxNode for ItemEnum {
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
                     TerminalEnum::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
@@ -10556,15 +12103,17 @@ pub struct ItemTypeAlias {
 }
 impl ItemTypeAlias {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_TYPE_KW: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_GENERIC_PARAMS: usize = 3;
-    pub const INDEX_EQ: usize = 4;
-    pub const INDEX_TY: usize = 5;
-    pub const INDEX_SEMICOLON: usize = 6;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_TYPE_KW: usize = 2;
+    pub const INDEX_NAME: usize = 3;
+    pub const INDEX_GENERIC_PARAMS: usize = 4;
+    pub const INDEX_EQ: usize = 5;
+    pub const INDEX_TY: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
         type_kw: TerminalTypeGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
@@ -10572,8 +12121,16 @@ impl ItemTypeAlias {
         ty: ExprGreen,
         semicolon: TerminalSemicolonGreen,
     ) -> ItemTypeAliasGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, type_kw.0, name.0, generic_params.0, eq.0, ty.0, semicolon.0];
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            visibility.0,
+            type_kw.0,
+            name.0,
+            generic_params.0,
+            eq.0,
+            ty.0,
+            semicolon.0,
+        ];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         ItemTypeAliasGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::ItemTypeAlias,
@@ -10585,23 +12142,26 @@ impl ItemTypeAlias {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn type_kw(&self, db: &dyn SyntaxGroup) -> TerminalType {
-        TerminalType::from_syntax_node(db, self.children[1].clone())
+        TerminalType::from_syntax_node(db, self.children[2].clone())
     }
     pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+        TerminalIdentifier::from_syntax_node(db, self.children[3].clone())
     }
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
-        OptionWrappedGenericParamList::from_syntax_node(db, self.children[3].clone())
+        OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
     }
     pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[4].clone())
+        TerminalEq::from_syntax_node(db, self.children[5].clone())
     }
     pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[5].clone())
+        Expr::from_syntax_node(db, self.children[6].clone())
     }
     pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -10615,6 +12175,7 @@ impl ItemTypeAliasPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -10631,6 +12192,7 @@ impl TypedSyntaxNode for ItemTypeAlias {
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
                     TerminalType::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
@@ -10671,17 +12233,20 @@ pub struct ItemUse {
 }
 impl ItemUse {
     pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_USE_KW: usize = 1;
-    pub const INDEX_USE_PATH: usize = 2;
-    pub const INDEX_SEMICOLON: usize = 3;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_USE_KW: usize = 2;
+    pub const INDEX_USE_PATH: usize = 3;
+    pub const INDEX_SEMICOLON: usize = 4;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
         use_kw: TerminalUseGreen,
         use_path: UsePathGreen,
         semicolon: TerminalSemicolonGreen,
     ) -> ItemUseGreen {
-        let children: Vec<GreenId> = vec![attributes.0, use_kw.0, use_path.0, semicolon.0];
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, use_kw.0, use_path.0, semicolon.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         ItemUseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::ItemUse,
@@ -10693,14 +12258,17 @@ impl ItemUse {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn use_kw(&self, db: &dyn SyntaxGroup) -> TerminalUse {
-        TerminalUse::from_syntax_node(db, self.children[1].clone())
+        TerminalUse::from_syntax_node(db, self.children[2].clone())
     }
     pub fn use_path(&self, db: &dyn SyntaxGroup) -> UsePath {
-        UsePath::from_syntax_node(db, self.children[2].clone())
+        UsePath::from_syntax_node(db, self.children[3].clone())
     }
     pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+        TerminalSemicolon::from_syntax_node(db, self.children[4].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -10714,6 +12282,7 @@ impl ItemUsePtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -10730,6 +12299,7 @@ impl TypedSyntaxNode for ItemUse {
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
                     TerminalUse::missing(db).0,
                     UsePath::missing(db).0,
                     TerminalSemicolon::missing(db).0,
@@ -10894,6 +12464,7 @@ impl UsePathLeafPtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -11103,6 +12674,7 @@ impl TypedSyntaxNode for UsePathMulti {
 pub struct UsePathList(ElementList<UsePath, 2>);
 impl Deref for UsePathList {
     type Target = ElementList<UsePath, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -11217,6 +12789,7 @@ impl AliasClausePtr {
             panic!("Unexpected key field query on root.");
         }
     }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
@@ -11416,179 +12989,478 @@ impl GenericArgExpr {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgExprPtr(pub SyntaxStablePtrId);
-impl GenericArgExprPtr {
+pub struct GenericArgExprPtr(pub SyntaxStablePtrId);
+impl GenericArgExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgExpr);
+    type StablePtr = GenericArgExprPtr;
+    type Green = GenericArgExprGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericArgExprGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgExpr,
+            details: GreenNodeDetails::Node {
+                children: vec![Expr::missing(db).0],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericArgExpr,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericArgExpr
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgExprPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GenericArg {
+    Underscore(TerminalUnderscore),
+    Expr(GenericArgExpr),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgPtr(pub SyntaxStablePtrId);
+impl GenericArgPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalUnderscorePtr> for GenericArgPtr {
+    fn from(value: TerminalUnderscorePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericArgExprPtr> for GenericArgPtr {
+    fn from(value: GenericArgExprPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalUnderscoreGreen> for GenericArgGreen {
+    fn from(value: TerminalUnderscoreGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericArgExprGreen> for GenericArgGreen {
+    fn from(value: GenericArgExprGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArg {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = GenericArgPtr;
+    type Green = GenericArgGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalUnderscore => {
+                GenericArg::Underscore(TerminalUnderscore::from_syntax_node(db, node))
+            }
+            SyntaxKind::GenericArgExpr => {
+                GenericArg::Expr(GenericArgExpr::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "GenericArg"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            GenericArg::Underscore(x) => x.as_syntax_node(),
+            GenericArg::Expr(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl GenericArg {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::TerminalUnderscore => true,
+            SyntaxKind::GenericArgExpr => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericArgs {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl GenericArgs {
+    pub const INDEX_LANGLE: usize = 0;
+    pub const INDEX_GENERIC_ARGS: usize = 1;
+    pub const INDEX_RANGLE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        langle: TerminalLTGreen,
+        generic_args: GenericArgListGreen,
+        rangle: TerminalGTGreen,
+    ) -> GenericArgsGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl GenericArgs {
+    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
+        TerminalLT::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
+        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
+        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgsPtr(pub SyntaxStablePtrId);
+impl GenericArgsPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgsGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
+    type StablePtr = GenericArgsPtr;
+    type Green = GenericArgsGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericArgsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgs,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLT::missing(db).0,
+                    GenericArgList::missing(db).0,
+                    TerminalGT::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericArgs,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericArgs
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericArgsPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericArgList(ElementList<GenericArg, 2>);
+impl Deref for GenericArgList {
+    type Target = ElementList<GenericArg, 2>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl GenericArgList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<GenericArgListElementOrSeparatorGreen>,
+    ) -> GenericArgListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgListPtr(pub SyntaxStablePtrId);
+impl GenericArgListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgExpr);
-    type StablePtr = GenericArgExprPtr;
-    type Green = GenericArgExprGreen;
+pub enum GenericArgListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(GenericArgGreen),
+}
+impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<GenericArgGreen> for GenericArgListElementOrSeparatorGreen {
+    fn from(value: GenericArgGreen) -> Self {
+        GenericArgListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl GenericArgListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericArgListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericArgList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
+    type StablePtr = GenericArgListPtr;
+    type Green = GenericArgListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgExprGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgExpr,
-            details: GreenNodeDetails::Node {
-                children: vec![Expr::missing(db).0],
-                width: TextWidth::default(),
-            },
+        GenericArgListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericArgList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::GenericArgExpr,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::GenericArgExpr
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+        Self(ElementList::new(node))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgExprPtr(self.node.0.stable_ptr)
+        GenericArgListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum GenericArg {
-    Underscore(TerminalUnderscore),
-    Expr(GenericArgExpr),
+pub enum OptionWrappedGenericParamList {
+    Empty(OptionWrappedGenericParamListEmpty),
+    WrappedGenericParamList(WrappedGenericParamList),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgPtr(pub SyntaxStablePtrId);
-impl GenericArgPtr {
+pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TerminalUnderscorePtr> for GenericArgPtr {
-    fn from(value: TerminalUnderscorePtr) -> Self {
+impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<GenericArgExprPtr> for GenericArgPtr {
-    fn from(value: GenericArgExprPtr) -> Self {
+impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
+    fn from(value: WrappedGenericParamListPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalUnderscoreGreen> for GenericArgGreen {
-    fn from(value: TerminalUnderscoreGreen) -> Self {
+impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<GenericArgExprGreen> for GenericArgGreen {
-    fn from(value: GenericArgExprGreen) -> Self {
+impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
+    fn from(value: WrappedGenericParamListGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArg {
+pub struct OptionWrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamList {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = GenericArgPtr;
-    type Green = GenericArgGreen;
+    type StablePtr = OptionWrappedGenericParamListPtr;
+    type Green = OptionWrappedGenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalUnderscore => {
-                GenericArg::Underscore(TerminalUnderscore::from_syntax_node(db, node))
-            }
-            SyntaxKind::GenericArgExpr => {
-                GenericArg::Expr(GenericArgExpr::from_syntax_node(db, node))
+            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
+                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
+            ),
+            SyntaxKind::WrappedGenericParamList => {
+                OptionWrappedGenericParamList::WrappedGenericParamList(
+                    WrappedGenericParamList::from_syntax_node(db, node),
+                )
             }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "GenericArg"),
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionWrappedGenericParamList"
+            ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            GenericArg::Underscore(x) => x.as_syntax_node(),
-            GenericArg::Expr(x) => x.as_syntax_node(),
+            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
+            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgPtr(self.as_syntax_node().0.stable_ptr)
+        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl GenericArg {
+impl OptionWrappedGenericParamList {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::TerminalUnderscore => true,
-            SyntaxKind::GenericArgExpr => true,
+            SyntaxKind::OptionWrappedGenericParamListEmpty => true,
+            SyntaxKind::WrappedGenericParamList => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgs {
+pub struct OptionWrappedGenericParamListEmpty {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericArgs {
+impl OptionWrappedGenericParamListEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionWrappedGenericParamListEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
+impl OptionWrappedGenericParamListEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
+    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
+    type Green = OptionWrappedGenericParamListEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionWrappedGenericParamListEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WrappedGenericParamList {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl WrappedGenericParamList {
     pub const INDEX_LANGLE: usize = 0;
-    pub const INDEX_GENERIC_ARGS: usize = 1;
+    pub const INDEX_GENERIC_PARAMS: usize = 1;
     pub const INDEX_RANGLE: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         langle: TerminalLTGreen,
-        generic_args: GenericArgListGreen,
+        generic_params: GenericParamListGreen,
         rangle: TerminalGTGreen,
-    ) -> GenericArgsGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_args.0, rangle.0];
+    ) -> WrappedGenericParamListGreen {
+        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl GenericArgs {
+impl WrappedGenericParamList {
     pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
         TerminalLT::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_args(&self, db: &dyn SyntaxGroup) -> GenericArgList {
-        GenericArgList::from_syntax_node(db, self.children[1].clone())
+    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
+        GenericParamList::from_syntax_node(db, self.children[1].clone())
     }
     pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
         TerminalGT::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsPtr(pub SyntaxStablePtrId);
-impl GenericArgsPtr {
+pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
+impl WrappedGenericParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgsGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgs);
-    type StablePtr = GenericArgsPtr;
-    type Green = GenericArgsGreen;
+pub struct WrappedGenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for WrappedGenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
+    type StablePtr = WrappedGenericParamListPtr;
+    type Green = WrappedGenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgs,
+        WrappedGenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::WrappedGenericParamList,
             details: GreenNodeDetails::Node {
                 children: vec![
                     TerminalLT::missing(db).0,
-                    GenericArgList::missing(db).0,
+                    GenericParamList::missing(db).0,
                     TerminalGT::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -11599,10 +13471,10 @@ impl TypedSyntaxNode for GenericArgs {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericArgs,
+            SyntaxKind::WrappedGenericParamList,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericArgs
+            SyntaxKind::WrappedGenericParamList
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11614,25 +13486,26 @@ impl TypedSyntaxNode for GenericArgs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgsPtr(self.node.0.stable_ptr)
+        WrappedGenericParamListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericArgList(ElementList<GenericArg, 2>);
-impl Deref for GenericArgList {
-    type Target = ElementList<GenericArg, 2>;
+pub struct GenericParamList(ElementList<GenericParam, 2>);
+impl Deref for GenericParamList {
+    type Target = ElementList<GenericParam, 2>;
+
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl GenericArgList {
+impl GenericParamList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<GenericArgListElementOrSeparatorGreen>,
-    ) -> GenericArgListGreen {
+        children: Vec<GenericParamListElementOrSeparatorGreen>,
+    ) -> GenericParamListGreen {
         let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
             details: GreenNodeDetails::Node {
                 children: children.iter().map(|x| x.id()).collect(),
                 width,
@@ -11640,45 +13513,45 @@ impl GenericArgList {
         }))
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListPtr(pub SyntaxStablePtrId);
-impl GenericArgListPtr {
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamListPtr(pub SyntaxStablePtrId);
+impl GenericParamListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericArgListElementOrSeparatorGreen {
+pub enum GenericParamListElementOrSeparatorGreen {
     Separator(TerminalCommaGreen),
-    Element(GenericArgGreen),
+    Element(GenericParamGreen),
 }
-impl From<TerminalCommaGreen> for GenericArgListElementOrSeparatorGreen {
+impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
     fn from(value: TerminalCommaGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Separator(value)
+        GenericParamListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<GenericArgGreen> for GenericArgListElementOrSeparatorGreen {
-    fn from(value: GenericArgGreen) -> Self {
-        GenericArgListElementOrSeparatorGreen::Element(value)
+impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
+    fn from(value: GenericParamGreen) -> Self {
+        GenericParamListElementOrSeparatorGreen::Element(value)
     }
 }
-impl GenericArgListElementOrSeparatorGreen {
+impl GenericParamListElementOrSeparatorGreen {
     fn id(&self) -> GreenId {
         match self {
-            GenericArgListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericArgListElementOrSeparatorGreen::Element(green) => green.0,
+            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
         }
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericArgListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericArgList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericArgList);
-    type StablePtr = GenericArgListPtr;
-    type Green = GenericArgListGreen;
+pub struct GenericParamListGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
+    type StablePtr = GenericParamListPtr;
+    type Green = GenericParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericArgListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericArgList,
+        GenericParamListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamList,
             details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         }))
     }
@@ -11692,133 +13565,258 @@ impl TypedSyntaxNode for GenericArgList {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericArgListPtr(self.node.0.stable_ptr)
+        GenericParamListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionWrappedGenericParamList {
-    Empty(OptionWrappedGenericParamListEmpty),
-    WrappedGenericParamList(WrappedGenericParamList),
+pub enum GenericParam {
+    Type(GenericParamType),
+    Const(GenericParamConst),
+    Impl(GenericParamImpl),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListPtr {
+pub struct GenericParamPtr(pub SyntaxStablePtrId);
+impl GenericParamPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<OptionWrappedGenericParamListEmptyPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: OptionWrappedGenericParamListEmptyPtr) -> Self {
+impl From<GenericParamTypePtr> for GenericParamPtr {
+    fn from(value: GenericParamTypePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<WrappedGenericParamListPtr> for OptionWrappedGenericParamListPtr {
-    fn from(value: WrappedGenericParamListPtr) -> Self {
+impl From<GenericParamConstPtr> for GenericParamPtr {
+    fn from(value: GenericParamConstPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionWrappedGenericParamListEmptyGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: OptionWrappedGenericParamListEmptyGreen) -> Self {
+impl From<GenericParamImplPtr> for GenericParamPtr {
+    fn from(value: GenericParamImplPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<WrappedGenericParamListGreen> for OptionWrappedGenericParamListGreen {
-    fn from(value: WrappedGenericParamListGreen) -> Self {
+impl From<GenericParamTypeGreen> for GenericParamGreen {
+    fn from(value: GenericParamTypeGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericParamConstGreen> for GenericParamGreen {
+    fn from(value: GenericParamConstGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<GenericParamImplGreen> for GenericParamGreen {
+    fn from(value: GenericParamImplGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamList {
+pub struct GenericParamGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParam {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionWrappedGenericParamListPtr;
-    type Green = OptionWrappedGenericParamListGreen;
+    type StablePtr = GenericParamPtr;
+    type Green = GenericParamGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionWrappedGenericParamListEmpty => OptionWrappedGenericParamList::Empty(
-                OptionWrappedGenericParamListEmpty::from_syntax_node(db, node),
-            ),
-            SyntaxKind::WrappedGenericParamList => {
-                OptionWrappedGenericParamList::WrappedGenericParamList(
-                    WrappedGenericParamList::from_syntax_node(db, node),
-                )
+            SyntaxKind::GenericParamType => {
+                GenericParam::Type(GenericParamType::from_syntax_node(db, node))
             }
-            _ => panic!(
-                "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionWrappedGenericParamList"
-            ),
+            SyntaxKind::GenericParamConst => {
+                GenericParam::Const(GenericParamConst::from_syntax_node(db, node))
+            }
+            SyntaxKind::GenericParamImpl => {
+                GenericParam::Impl(GenericParamImpl::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "GenericParam"),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            OptionWrappedGenericParamList::Empty(x) => x.as_syntax_node(),
-            OptionWrappedGenericParamList::WrappedGenericParamList(x) => x.as_syntax_node(),
+            GenericParam::Type(x) => x.as_syntax_node(),
+            GenericParam::Const(x) => x.as_syntax_node(),
+            GenericParam::Impl(x) => x.as_syntax_node(),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListPtr(self.as_syntax_node().0.stable_ptr)
+        GenericParamPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl OptionWrappedGenericParamList {
+impl GenericParam {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::OptionWrappedGenericParamListEmpty => true,
-            SyntaxKind::WrappedGenericParamList => true,
+            SyntaxKind::GenericParamType => true,
+            SyntaxKind::GenericParamConst => true,
+            SyntaxKind::GenericParamImpl => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionWrappedGenericParamListEmpty {
+pub struct GenericParamType {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl OptionWrappedGenericParamListEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionWrappedGenericParamListEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl GenericParamType {
+    pub const INDEX_NAME: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, name: TerminalIdentifierGreen) -> GenericParamTypeGreen {
+        let children: Vec<GreenId> = vec![name.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
+        GenericParamTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamType,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl OptionWrappedGenericParamListEmpty {}
+impl GenericParamType {
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyPtr(pub SyntaxStablePtrId);
-impl OptionWrappedGenericParamListEmptyPtr {
+pub struct GenericParamTypePtr(pub SyntaxStablePtrId);
+impl GenericParamTypePtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionWrappedGenericParamListEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionWrappedGenericParamListEmpty);
-    type StablePtr = OptionWrappedGenericParamListEmptyPtr;
-    type Green = OptionWrappedGenericParamListEmptyGreen;
+pub struct GenericParamTypeGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamType {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamType);
+    type StablePtr = GenericParamTypePtr;
+    type Green = GenericParamTypeGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionWrappedGenericParamListEmptyGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::OptionWrappedGenericParamListEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        GenericParamTypeGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamType,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalIdentifier::missing(db).0],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::GenericParamType,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::GenericParamType
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        GenericParamTypePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GenericParamConst {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl GenericParamConst {
+    pub const INDEX_CONST_KW: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_COLON: usize = 2;
+    pub const INDEX_TY: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        const_kw: TerminalConstGreen,
+        name: TerminalIdentifierGreen,
+        colon: TerminalColonGreen,
+        ty: ExprGreen,
+    ) -> GenericParamConstGreen {
+        let children: Vec<GreenId> = vec![const_kw.0, name.0, colon.0, ty.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        GenericParamConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamConst,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl GenericParamConst {
+    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
+        TerminalConst::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamConstPtr(pub SyntaxStablePtrId);
+impl GenericParamConstPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericParamConstGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamConst);
+    type StablePtr = GenericParamConstPtr;
+    type Green = GenericParamConstGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        GenericParamConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamConst,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalConst::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TerminalColon::missing(db).0,
+                    Expr::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty,
+            SyntaxKind::GenericParamConst,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionWrappedGenericParamListEmpty
+            SyntaxKind::GenericParamConst
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11830,64 +13828,79 @@ impl TypedSyntaxNode for OptionWrappedGenericParamListEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionWrappedGenericParamListEmptyPtr(self.node.0.stable_ptr)
+        GenericParamConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct WrappedGenericParamList {
+pub struct GenericParamImpl {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl WrappedGenericParamList {
-    pub const INDEX_LANGLE: usize = 0;
-    pub const INDEX_GENERIC_PARAMS: usize = 1;
-    pub const INDEX_RANGLE: usize = 2;
+impl GenericParamImpl {
+    pub const INDEX_IMPL_KW: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_COLON: usize = 2;
+    pub const INDEX_TRAIT_PATH: usize = 3;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        langle: TerminalLTGreen,
-        generic_params: GenericParamListGreen,
-        rangle: TerminalGTGreen,
-    ) -> WrappedGenericParamListGreen {
-        let children: Vec<GreenId> = vec![langle.0, generic_params.0, rangle.0];
+        impl_kw: TerminalImplGreen,
+        name: TerminalIdentifierGreen,
+        colon: TerminalColonGreen,
+        trait_path: ExprPathGreen,
+    ) -> GenericParamImplGreen {
+        let children: Vec<GreenId> = vec![impl_kw.0, name.0, colon.0, trait_path.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        GenericParamImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamImpl,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl WrappedGenericParamList {
-    pub fn langle(&self, db: &dyn SyntaxGroup) -> TerminalLT {
-        TerminalLT::from_syntax_node(db, self.children[0].clone())
+impl GenericParamImpl {
+    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
+        TerminalImpl::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn generic_params(&self, db: &dyn SyntaxGroup) -> GenericParamList {
-        GenericParamList::from_syntax_node(db, self.children[1].clone())
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn rangle(&self, db: &dyn SyntaxGroup) -> TerminalGT {
-        TerminalGT::from_syntax_node(db, self.children[2].clone())
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[3].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListPtr(pub SyntaxStablePtrId);
-impl WrappedGenericParamListPtr {
+pub struct GenericParamImplPtr(pub SyntaxStablePtrId);
+impl GenericParamImplPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct WrappedGenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for WrappedGenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::WrappedGenericParamList);
-    type StablePtr = WrappedGenericParamListPtr;
-    type Green = WrappedGenericParamListGreen;
+pub struct GenericParamImplGreen(pub GreenId);
+impl TypedSyntaxNode for GenericParamImpl {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamImpl);
+    type StablePtr = GenericParamImplPtr;
+    type Green = GenericParamImplGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        WrappedGenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::WrappedGenericParamList,
+        GenericParamImplGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::GenericParamImpl,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalLT::missing(db).0,
-                    GenericParamList::missing(db).0,
-                    TerminalGT::missing(db).0,
+                    TerminalImpl::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TerminalColon::missing(db).0,
+                    ExprPath::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -11897,10 +13910,10 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::WrappedGenericParamList,
+            SyntaxKind::GenericParamImpl,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::WrappedGenericParamList
+            SyntaxKind::GenericParamImpl
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -11912,226 +13925,266 @@ impl TypedSyntaxNode for WrappedGenericParamList {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        WrappedGenericParamListPtr(self.node.0.stable_ptr)
+        GenericParamImplPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamList(ElementList<GenericParam, 2>);
-impl Deref for GenericParamList {
-    type Target = ElementList<GenericParam, 2>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+pub struct TokenIdentifier {
+    node: SyntaxNode,
 }
-impl GenericParamList {
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        children: Vec<GenericParamListElementOrSeparatorGreen>,
-    ) -> GenericParamListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.id()).collect(),
-                width,
-            },
+impl Token for TokenIdentifier {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIdentifier,
+            details: GreenNodeDetails::Token(text),
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListPtr(pub SyntaxStablePtrId);
-impl GenericParamListPtr {
+pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
+impl TokenIdentifierPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum GenericParamListElementOrSeparatorGreen {
-    Separator(TerminalCommaGreen),
-    Element(GenericParamGreen),
+pub struct TokenIdentifierGreen(pub GreenId);
+impl TokenIdentifierGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
 }
-impl From<TerminalCommaGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: TerminalCommaGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Separator(value)
+impl TypedSyntaxNode for TokenIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
+    type StablePtr = TokenIdentifierPtr;
+    type Green = TokenIdentifierGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenIdentifierPtr(self.node.0.stable_ptr)
     }
 }
-impl From<GenericParamGreen> for GenericParamListElementOrSeparatorGreen {
-    fn from(value: GenericParamGreen) -> Self {
-        GenericParamListElementOrSeparatorGreen::Element(value)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalIdentifier {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalIdentifier {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
+    type TokenType = TokenIdentifier;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
 }
-impl GenericParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            GenericParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            GenericParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+impl TerminalIdentifier {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
+        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamListGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamList);
-    type StablePtr = GenericParamListPtr;
-    type Green = GenericParamListGreen;
+pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
+impl TerminalIdentifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalIdentifierGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIdentifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
+    type StablePtr = TerminalIdentifierPtr;
+    type Green = TerminalIdentifierGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamList,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        TerminalIdentifierGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIdentifier,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenIdentifier::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalIdentifier,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalIdentifier
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamListPtr(self.node.0.stable_ptr)
+        TerminalIdentifierPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum GenericParam {
-    Type(GenericParamType),
-    Const(GenericParamConst),
-    Impl(GenericParamImpl),
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamPtr(pub SyntaxStablePtrId);
-impl GenericParamPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-impl From<GenericParamTypePtr> for GenericParamPtr {
-    fn from(value: GenericParamTypePtr) -> Self {
-        Self(value.0)
-    }
-}
-impl From<GenericParamConstPtr> for GenericParamPtr {
-    fn from(value: GenericParamConstPtr) -> Self {
-        Self(value.0)
-    }
+pub struct TokenLiteralNumber {
+    node: SyntaxNode,
 }
-impl From<GenericParamImplPtr> for GenericParamPtr {
-    fn from(value: GenericParamImplPtr) -> Self {
-        Self(value.0)
+impl Token for TokenLiteralNumber {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLiteralNumber,
+            details: GreenNodeDetails::Token(text),
+        }))
     }
-}
-impl From<GenericParamTypeGreen> for GenericParamGreen {
-    fn from(value: GenericParamTypeGreen) -> Self {
-        Self(value.0)
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
-impl From<GenericParamConstGreen> for GenericParamGreen {
-    fn from(value: GenericParamConstGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TokenLiteralNumberPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
 }
-impl From<GenericParamImplGreen> for GenericParamGreen {
-    fn from(value: GenericParamImplGreen) -> Self {
-        Self(value.0)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLiteralNumberGreen(pub GreenId);
+impl TokenLiteralNumberGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParam {
-    const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = GenericParamPtr;
-    type Green = GenericParamGreen;
+impl TypedSyntaxNode for TokenLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
+    type StablePtr = TokenLiteralNumberPtr;
+    type Green = TokenLiteralNumberGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        panic!("No missing variant.");
+        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        match kind {
-            SyntaxKind::GenericParamType => {
-                GenericParam::Type(GenericParamType::from_syntax_node(db, node))
-            }
-            SyntaxKind::GenericParamConst => {
-                GenericParam::Const(GenericParamConst::from_syntax_node(db, node))
-            }
-            SyntaxKind::GenericParamImpl => {
-                GenericParam::Impl(GenericParamImpl::from_syntax_node(db, node))
-            }
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "GenericParam"),
-        }
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        match self {
-            GenericParam::Type(x) => x.as_syntax_node(),
-            GenericParam::Const(x) => x.as_syntax_node(),
-            GenericParam::Impl(x) => x.as_syntax_node(),
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => panic!(
+                "Expected a token {:?}, not an internal node",
+                SyntaxKind::TokenLiteralNumber
+            ),
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
     }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamPtr(self.as_syntax_node().0.stable_ptr)
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
     }
-}
-impl GenericParam {
-    #[allow(clippy::match_like_matches_macro)]
-    pub fn is_variant(kind: SyntaxKind) -> bool {
-        match kind {
-            SyntaxKind::GenericParamType => true,
-            SyntaxKind::GenericParamConst => true,
-            SyntaxKind::GenericParamImpl => true,
-            _ => false,
-        }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenLiteralNumberPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamType {
+pub struct TerminalLiteralNumber {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericParamType {
-    pub const INDEX_NAME: usize = 0;
-    pub fn new_green(db: &dyn SyntaxGroup, name: TerminalIdentifierGreen) -> GenericParamTypeGreen {
-        let children: Vec<GreenId> = vec![name.0];
+impl Terminal for TerminalLiteralNumber {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
+    type TokenType = TokenLiteralNumber;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericParamTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamType,
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
 }
-impl GenericParamType {
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+impl TerminalLiteralNumber {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
+        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamTypePtr(pub SyntaxStablePtrId);
-impl GenericParamTypePtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
+impl TerminalLiteralNumberPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamTypeGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamType {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamType);
-    type StablePtr = GenericParamTypePtr;
-    type Green = GenericParamTypeGreen;
+pub struct TerminalLiteralNumberGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLiteralNumber {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
+    type StablePtr = TerminalLiteralNumberPtr;
+    type Green = TerminalLiteralNumberGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamTypeGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamType,
+        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLiteralNumber,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalIdentifier::missing(db).0],
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenLiteralNumber::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
                 width: TextWidth::default(),
             },
         }))
@@ -12140,10 +14193,10 @@ impl TypedSyntaxNode for GenericParamType {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericParamType,
+            SyntaxKind::TerminalLiteralNumber,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericParamType
+            SyntaxKind::TerminalLiteralNumber
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12155,94 +14208,55 @@ impl TypedSyntaxNode for GenericParamType {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamTypePtr(self.node.0.stable_ptr)
+        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamConst {
+pub struct TokenShortString {
     node: SyntaxNode,
-    children: Vec<SyntaxNode>,
 }
-impl GenericParamConst {
-    pub const INDEX_CONST_KW: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_COLON: usize = 2;
-    pub const INDEX_TY: usize = 3;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        const_kw: TerminalConstGreen,
-        name: TerminalIdentifierGreen,
-        colon: TerminalColonGreen,
-        ty: ExprGreen,
-    ) -> GenericParamConstGreen {
-        let children: Vec<GreenId> = vec![const_kw.0, name.0, colon.0, ty.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericParamConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamConst,
-            details: GreenNodeDetails::Node { children, width },
+impl Token for TokenShortString {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenShortString,
+            details: GreenNodeDetails::Token(text),
         }))
     }
-}
-impl GenericParamConst {
-    pub fn const_kw(&self, db: &dyn SyntaxGroup) -> TerminalConst {
-        TerminalConst::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[3].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamConstPtr(pub SyntaxStablePtrId);
-impl GenericParamConstPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TokenShortStringPtr(pub SyntaxStablePtrId);
+impl TokenShortStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamConstGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamConst {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamConst);
-    type StablePtr = GenericParamConstPtr;
-    type Green = GenericParamConstGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamConst,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalConst::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    TerminalColon::missing(db).0,
-                    Expr::missing(db).0,
-                ],
-                width: TextWidth::default(),
-            },
+pub struct TokenShortStringGreen(pub GreenId);
+impl TokenShortStringGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShortString);
+    type StablePtr = TokenShortStringPtr;
+    type Green = TokenShortStringGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
         }))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::GenericParamConst,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::GenericParamConst
-        );
-        let children = node.children(db).collect();
-        Self { node, children }
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShortString)
+            }
+        }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
         Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
@@ -12251,78 +14265,66 @@ impl TypedSyntaxNode for GenericParamConst {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamConstPtr(self.node.0.stable_ptr)
+        TokenShortStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct GenericParamImpl {
+pub struct TerminalShortString {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl GenericParamImpl {
-    pub const INDEX_IMPL_KW: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_COLON: usize = 2;
-    pub const INDEX_TRAIT_PATH: usize = 3;
-    pub fn new_green(
+impl Terminal for TerminalShortString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalShortString;
+    type TokenType = TokenShortString;
+    fn new_green(
         db: &dyn SyntaxGroup,
-        impl_kw: TerminalImplGreen,
-        name: TerminalIdentifierGreen,
-        colon: TerminalColonGreen,
-        trait_path: ExprPathGreen,
-    ) -> GenericParamImplGreen {
-        let children: Vec<GreenId> = vec![impl_kw.0, name.0, colon.0, trait_path.0];
+        leading_trivia: TriviaGreen,
+        token: <<TerminalShortString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        GenericParamImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamImpl,
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
-}
-impl GenericParamImpl {
-    pub fn impl_kw(&self, db: &dyn SyntaxGroup) -> TerminalImpl {
-        TerminalImpl::from_syntax_node(db, self.children[0].clone())
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+}
+impl TerminalShortString {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[2].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShortString {
+        TokenShortString::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn trait_path(&self, db: &dyn SyntaxGroup) -> ExprPath {
-        ExprPath::from_syntax_node(db, self.children[3].clone())
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamImplPtr(pub SyntaxStablePtrId);
-impl GenericParamImplPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TerminalShortStringPtr(pub SyntaxStablePtrId);
+impl TerminalShortStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct GenericParamImplGreen(pub GreenId);
-impl TypedSyntaxNode for GenericParamImpl {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::GenericParamImpl);
-    type StablePtr = GenericParamImplPtr;
-    type Green = GenericParamImplGreen;
+pub struct TerminalShortStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalShortString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShortString);
+    type StablePtr = TerminalShortStringPtr;
+    type Green = TerminalShortStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        GenericParamImplGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::GenericParamImpl,
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
             details: GreenNodeDetails::Node {
                 children: vec![
-                    TerminalImpl::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    TerminalColon::missing(db).0,
-                    ExprPath::missing(db).0,
+                    Trivia::missing(db).0,
+                    TokenShortString::missing(db).0,
+                    Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -12332,10 +14334,10 @@ impl TypedSyntaxNode for GenericParamImpl {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::GenericParamImpl,
+            SyntaxKind::TerminalShortString,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::GenericParamImpl
+            SyntaxKind::TerminalShortString
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12347,17 +14349,17 @@ impl TypedSyntaxNode for GenericParamImpl {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        GenericParamImplPtr(self.node.0.stable_ptr)
+        TerminalShortStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIdentifier {
+pub struct TokenString {
     node: SyntaxNode,
 }
-impl Token for TokenIdentifier {
+impl Token for TokenString {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIdentifier,
+        TokenStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenString,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12366,25 +14368,25 @@ impl Token for TokenIdentifier {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierPtr(pub SyntaxStablePtrId);
-impl TokenIdentifierPtr {
+pub struct TokenStringPtr(pub SyntaxStablePtrId);
+impl TokenStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIdentifierGreen(pub GreenId);
-impl TokenIdentifierGreen {
+pub struct TokenStringGreen(pub GreenId);
+impl TokenStringGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIdentifier);
-    type StablePtr = TokenIdentifierPtr;
-    type Green = TokenIdentifierGreen;
+impl TypedSyntaxNode for TokenString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenString);
+    type StablePtr = TokenStringPtr;
+    type Green = TokenStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIdentifierGreen(db.intern_green(GreenNode {
+        TokenStringGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12393,7 +14395,7 @@ impl TypedSyntaxNode for TokenIdentifier {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIdentifier)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenString)
             }
         }
     }
@@ -12404,27 +14406,27 @@ impl TypedSyntaxNode for TokenIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIdentifierPtr(self.node.0.stable_ptr)
+        TokenStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIdentifier {
+pub struct TerminalString {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIdentifier {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIdentifier;
-    type TokenType = TokenIdentifier;
+impl Terminal for TerminalString {
+    const KIND: SyntaxKind = SyntaxKind::TerminalString;
+    type TokenType = TokenString;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIdentifier as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalString as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12432,37 +14434,37 @@ impl Terminal for TerminalIdentifier {
         self.token(db).text(db)
     }
 }
-impl TerminalIdentifier {
+impl TerminalString {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIdentifier {
-        TokenIdentifier::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenString {
+        TokenString::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierPtr(pub SyntaxStablePtrId);
-impl TerminalIdentifierPtr {
+pub struct TerminalStringPtr(pub SyntaxStablePtrId);
+impl TerminalStringPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIdentifierGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIdentifier {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIdentifier);
-    type StablePtr = TerminalIdentifierPtr;
-    type Green = TerminalIdentifierGreen;
+pub struct TerminalStringGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalString {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalString);
+    type StablePtr = TerminalStringPtr;
+    type Green = TerminalStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIdentifierGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIdentifier,
+        TerminalStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalString,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIdentifier::missing(db).0,
+                    TokenString::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -12473,10 +14475,10 @@ impl TypedSyntaxNode for TerminalIdentifier {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIdentifier,
+            SyntaxKind::TerminalString,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIdentifier
+            SyntaxKind::TerminalString
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12488,17 +14490,17 @@ impl TypedSyntaxNode for TerminalIdentifier {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIdentifierPtr(self.node.0.stable_ptr)
+        TerminalStringPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLiteralNumber {
+pub struct TokenAs {
     node: SyntaxNode,
 }
-impl Token for TokenLiteralNumber {
+impl Token for TokenAs {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLiteralNumber,
+        TokenAsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenAs,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12507,25 +14509,25 @@ impl Token for TokenLiteralNumber {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TokenLiteralNumberPtr {
+pub struct TokenAsPtr(pub SyntaxStablePtrId);
+impl TokenAsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLiteralNumberGreen(pub GreenId);
-impl TokenLiteralNumberGreen {
+pub struct TokenAsGreen(pub GreenId);
+impl TokenAsGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLiteralNumber);
-    type StablePtr = TokenLiteralNumberPtr;
-    type Green = TokenLiteralNumberGreen;
+impl TypedSyntaxNode for TokenAs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAs);
+    type StablePtr = TokenAsPtr;
+    type Green = TokenAsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLiteralNumberGreen(db.intern_green(GreenNode {
+        TokenAsGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12533,10 +14535,9 @@ impl TypedSyntaxNode for TokenLiteralNumber {
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
-            GreenNodeDetails::Node { .. } => panic!(
-                "Expected a token {:?}, not an internal node",
-                SyntaxKind::TokenLiteralNumber
-            ),
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAs)
+            }
         }
     }
     fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
@@ -12546,27 +14547,27 @@ impl TypedSyntaxNode for TokenLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLiteralNumberPtr(self.node.0.stable_ptr)
+        TokenAsPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLiteralNumber {
+pub struct TerminalAs {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLiteralNumber {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLiteralNumber;
-    type TokenType = TokenLiteralNumber;
+impl Terminal for TerminalAs {
+    const KIND: SyntaxKind = SyntaxKind::TerminalAs;
+    type TokenType = TokenAs;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLiteralNumber as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalAs as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalAsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAs,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12574,37 +14575,37 @@ impl Terminal for TerminalLiteralNumber {
         self.token(db).text(db)
     }
 }
-impl TerminalLiteralNumber {
+impl TerminalAs {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLiteralNumber {
-        TokenLiteralNumber::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAs {
+        TokenAs::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberPtr(pub SyntaxStablePtrId);
-impl TerminalLiteralNumberPtr {
+pub struct TerminalAsPtr(pub SyntaxStablePtrId);
+impl TerminalAsPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLiteralNumberGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLiteralNumber {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLiteralNumber);
-    type StablePtr = TerminalLiteralNumberPtr;
-    type Green = TerminalLiteralNumberGreen;
+pub struct TerminalAsGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalAs {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAs);
+    type StablePtr = TerminalAsPtr;
+    type Green = TerminalAsGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLiteralNumberGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLiteralNumber,
+        TerminalAsGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalAs,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLiteralNumber::missing(db).0,
+                    TokenAs::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -12615,10 +14616,10 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLiteralNumber,
+            SyntaxKind::TerminalAs,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLiteralNumber
+            SyntaxKind::TerminalAs
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12630,17 +14631,17 @@ impl TypedSyntaxNode for TerminalLiteralNumber {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLiteralNumberPtr(self.node.0.stable_ptr)
+        TerminalAsPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenShortString {
+pub struct TokenConst {
     node: SyntaxNode,
 }
-impl Token for TokenShortString {
+impl Token for TokenConst {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenShortString,
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenConst,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12649,25 +14650,25 @@ impl Token for TokenShortString {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenShortStringPtr(pub SyntaxStablePtrId);
-impl TokenShortStringPtr {
+pub struct TokenConstPtr(pub SyntaxStablePtrId);
+impl TokenConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenShortStringGreen(pub GreenId);
-impl TokenShortStringGreen {
+pub struct TokenConstGreen(pub GreenId);
+impl TokenConstGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenShortString {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenShortString);
-    type StablePtr = TokenShortStringPtr;
-    type Green = TokenShortStringGreen;
+impl TypedSyntaxNode for TokenConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
+    type StablePtr = TokenConstPtr;
+    type Green = TokenConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenShortStringGreen(db.intern_green(GreenNode {
+        TokenConstGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12676,7 +14677,7 @@ impl TypedSyntaxNode for TokenShortString {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenShortString)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
             }
         }
     }
@@ -12687,27 +14688,27 @@ impl TypedSyntaxNode for TokenShortString {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenShortStringPtr(self.node.0.stable_ptr)
+        TokenConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalShortString {
+pub struct TerminalConst {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalShortString {
-    const KIND: SyntaxKind = SyntaxKind::TerminalShortString;
-    type TokenType = TokenShortString;
+impl Terminal for TerminalConst {
+    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
+    type TokenType = TokenConst;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalShortString as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalShortString,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12715,37 +14716,37 @@ impl Terminal for TerminalShortString {
         self.token(db).text(db)
     }
 }
-impl TerminalShortString {
+impl TerminalConst {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenShortString {
-        TokenShortString::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
+        TokenConst::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalShortStringPtr(pub SyntaxStablePtrId);
-impl TerminalShortStringPtr {
+pub struct TerminalConstPtr(pub SyntaxStablePtrId);
+impl TerminalConstPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalShortStringGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalShortString {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalShortString);
-    type StablePtr = TerminalShortStringPtr;
-    type Green = TerminalShortStringGreen;
+pub struct TerminalConstGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
+    type StablePtr = TerminalConstPtr;
+    type Green = TerminalConstGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalShortString,
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenShortString::missing(db).0,
+                    TokenConst::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -12756,10 +14757,10 @@ impl TypedSyntaxNode for TerminalShortString {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalShortString,
+            SyntaxKind::TerminalConst,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalShortString
+            SyntaxKind::TerminalConst
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12771,17 +14772,17 @@ impl TypedSyntaxNode for TerminalShortString {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalShortStringPtr(self.node.0.stable_ptr)
+        TerminalConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenAs {
+pub struct TokenElse {
     node: SyntaxNode,
 }
-impl Token for TokenAs {
+impl Token for TokenElse {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenAsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenAs,
+        TokenElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenElse,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12790,25 +14791,25 @@ impl Token for TokenAs {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAsPtr(pub SyntaxStablePtrId);
-impl TokenAsPtr {
+pub struct TokenElsePtr(pub SyntaxStablePtrId);
+impl TokenElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenAsGreen(pub GreenId);
-impl TokenAsGreen {
+pub struct TokenElseGreen(pub GreenId);
+impl TokenElseGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenAs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenAs);
-    type StablePtr = TokenAsPtr;
-    type Green = TokenAsGreen;
+impl TypedSyntaxNode for TokenElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
+    type StablePtr = TokenElsePtr;
+    type Green = TokenElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenAsGreen(db.intern_green(GreenNode {
+        TokenElseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12817,7 +14818,7 @@ impl TypedSyntaxNode for TokenAs {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenAs)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
             }
         }
     }
@@ -12828,27 +14829,27 @@ impl TypedSyntaxNode for TokenAs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenAsPtr(self.node.0.stable_ptr)
+        TokenElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalAs {
+pub struct TerminalElse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalAs {
-    const KIND: SyntaxKind = SyntaxKind::TerminalAs;
-    type TokenType = TokenAs;
+impl Terminal for TerminalElse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
+    type TokenType = TokenElse;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalAs as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalAsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAs,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12856,37 +14857,37 @@ impl Terminal for TerminalAs {
         self.token(db).text(db)
     }
 }
-impl TerminalAs {
+impl TerminalElse {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenAs {
-        TokenAs::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
+        TokenElse::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAsPtr(pub SyntaxStablePtrId);
-impl TerminalAsPtr {
+pub struct TerminalElsePtr(pub SyntaxStablePtrId);
+impl TerminalElsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalAsGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalAs {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalAs);
-    type StablePtr = TerminalAsPtr;
-    type Green = TerminalAsGreen;
+pub struct TerminalElseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalElse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
+    type StablePtr = TerminalElsePtr;
+    type Green = TerminalElseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalAsGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalAs,
+        TerminalElseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalElse,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenAs::missing(db).0,
+                    TokenElse::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -12897,10 +14898,10 @@ impl TypedSyntaxNode for TerminalAs {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalAs,
+            SyntaxKind::TerminalElse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalAs
+            SyntaxKind::TerminalElse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -12912,17 +14913,17 @@ impl TypedSyntaxNode for TerminalAs {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalAsPtr(self.node.0.stable_ptr)
+        TerminalElsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenConst {
+pub struct TokenEnum {
     node: SyntaxNode,
 }
-impl Token for TokenConst {
+impl Token for TokenEnum {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenConst,
+        TokenEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenEnum,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -12931,25 +14932,25 @@ impl Token for TokenConst {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenConstPtr(pub SyntaxStablePtrId);
-impl TokenConstPtr {
+pub struct TokenEnumPtr(pub SyntaxStablePtrId);
+impl TokenEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenConstGreen(pub GreenId);
-impl TokenConstGreen {
+pub struct TokenEnumGreen(pub GreenId);
+impl TokenEnumGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenConst {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
-    type StablePtr = TokenConstPtr;
-    type Green = TokenConstGreen;
+impl TypedSyntaxNode for TokenEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
+    type StablePtr = TokenEnumPtr;
+    type Green = TokenEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenConstGreen(db.intern_green(GreenNode {
+        TokenEnumGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -12958,7 +14959,7 @@ impl TypedSyntaxNode for TokenConst {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
             }
         }
     }
@@ -12969,27 +14970,27 @@ impl TypedSyntaxNode for TokenConst {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenConstPtr(self.node.0.stable_ptr)
+        TokenEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalConst {
+pub struct TerminalEnum {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalConst {
-    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
-    type TokenType = TokenConst;
+impl Terminal for TerminalEnum {
+    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
+    type TokenType = TokenEnum;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalConst,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -12997,37 +14998,37 @@ impl Terminal for TerminalConst {
         self.token(db).text(db)
     }
 }
-impl TerminalConst {
+impl TerminalEnum {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
-        TokenConst::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
+        TokenEnum::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalConstPtr(pub SyntaxStablePtrId);
-impl TerminalConstPtr {
+pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
+impl TerminalEnumPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalConstGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalConst {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
-    type StablePtr = TerminalConstPtr;
-    type Green = TerminalConstGreen;
+pub struct TerminalEnumGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalEnum {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
+    type StablePtr = TerminalEnumPtr;
+    type Green = TerminalEnumGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalConst,
+        TerminalEnumGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalEnum,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenConst::missing(db).0,
+                    TokenEnum::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13038,10 +15039,10 @@ impl TypedSyntaxNode for TerminalConst {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalConst,
+            SyntaxKind::TerminalEnum,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalConst
+            SyntaxKind::TerminalEnum
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13053,17 +15054,17 @@ impl TypedSyntaxNode for TerminalConst {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalConstPtr(self.node.0.stable_ptr)
+        TerminalEnumPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenElse {
+pub struct TokenExtern {
     node: SyntaxNode,
 }
-impl Token for TokenElse {
+impl Token for TokenExtern {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenElse,
+        TokenExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenExtern,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13072,25 +15073,25 @@ impl Token for TokenElse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElsePtr(pub SyntaxStablePtrId);
-impl TokenElsePtr {
+pub struct TokenExternPtr(pub SyntaxStablePtrId);
+impl TokenExternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenElseGreen(pub GreenId);
-impl TokenElseGreen {
+pub struct TokenExternGreen(pub GreenId);
+impl TokenExternGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenElse);
-    type StablePtr = TokenElsePtr;
-    type Green = TokenElseGreen;
+impl TypedSyntaxNode for TokenExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
+    type StablePtr = TokenExternPtr;
+    type Green = TokenExternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenElseGreen(db.intern_green(GreenNode {
+        TokenExternGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13099,7 +15100,7 @@ impl TypedSyntaxNode for TokenElse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenElse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
             }
         }
     }
@@ -13110,27 +15111,27 @@ impl TypedSyntaxNode for TokenElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenElsePtr(self.node.0.stable_ptr)
+        TokenExternPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalElse {
+pub struct TerminalExtern {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalElse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalElse;
-    type TokenType = TokenElse;
+impl Terminal for TerminalExtern {
+    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
+    type TokenType = TokenExtern;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalElse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13138,37 +15139,37 @@ impl Terminal for TerminalElse {
         self.token(db).text(db)
     }
 }
-impl TerminalElse {
+impl TerminalExtern {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenElse {
-        TokenElse::from_syntax_node(db, self.children[1].clone())
-    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
+        TokenExtern::from_syntax_node(db, self.children[1].clone())
+    }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElsePtr(pub SyntaxStablePtrId);
-impl TerminalElsePtr {
+pub struct TerminalExternPtr(pub SyntaxStablePtrId);
+impl TerminalExternPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalElseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalElse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalElse);
-    type StablePtr = TerminalElsePtr;
-    type Green = TerminalElseGreen;
+pub struct TerminalExternGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalExtern {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
+    type StablePtr = TerminalExternPtr;
+    type Green = TerminalExternGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalElseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalElse,
+        TerminalExternGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalExtern,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenElse::missing(db).0,
+                    TokenExtern::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13179,10 +15180,10 @@ impl TypedSyntaxNode for TerminalElse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalElse,
+            SyntaxKind::TerminalExtern,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalElse
+            SyntaxKind::TerminalExtern
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13194,17 +15195,17 @@ impl TypedSyntaxNode for TerminalElse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalElsePtr(self.node.0.stable_ptr)
+        TerminalExternPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenEnum {
+pub struct TokenFalse {
     node: SyntaxNode,
 }
-impl Token for TokenEnum {
+impl Token for TokenFalse {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenEnum,
+        TokenFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFalse,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13213,25 +15214,25 @@ impl Token for TokenEnum {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumPtr(pub SyntaxStablePtrId);
-impl TokenEnumPtr {
+pub struct TokenFalsePtr(pub SyntaxStablePtrId);
+impl TokenFalsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenEnumGreen(pub GreenId);
-impl TokenEnumGreen {
+pub struct TokenFalseGreen(pub GreenId);
+impl TokenFalseGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenEnum);
-    type StablePtr = TokenEnumPtr;
-    type Green = TokenEnumGreen;
+impl TypedSyntaxNode for TokenFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
+    type StablePtr = TokenFalsePtr;
+    type Green = TokenFalseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenEnumGreen(db.intern_green(GreenNode {
+        TokenFalseGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13240,7 +15241,7 @@ impl TypedSyntaxNode for TokenEnum {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenEnum)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
             }
         }
     }
@@ -13251,27 +15252,27 @@ impl TypedSyntaxNode for TokenEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenEnumPtr(self.node.0.stable_ptr)
+        TokenFalsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalEnum {
+pub struct TerminalFalse {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalEnum {
-    const KIND: SyntaxKind = SyntaxKind::TerminalEnum;
-    type TokenType = TokenEnum;
+impl Terminal for TerminalFalse {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
+    type TokenType = TokenFalse;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalEnum as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13279,37 +15280,37 @@ impl Terminal for TerminalEnum {
         self.token(db).text(db)
     }
 }
-impl TerminalEnum {
+impl TerminalFalse {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenEnum {
-        TokenEnum::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
+        TokenFalse::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumPtr(pub SyntaxStablePtrId);
-impl TerminalEnumPtr {
+pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
+impl TerminalFalsePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalEnumGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalEnum {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalEnum);
-    type StablePtr = TerminalEnumPtr;
-    type Green = TerminalEnumGreen;
+pub struct TerminalFalseGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFalse {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
+    type StablePtr = TerminalFalsePtr;
+    type Green = TerminalFalseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalEnumGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalEnum,
+        TerminalFalseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFalse,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenEnum::missing(db).0,
+                    TokenFalse::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13320,10 +15321,10 @@ impl TypedSyntaxNode for TerminalEnum {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalEnum,
+            SyntaxKind::TerminalFalse,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalEnum
+            SyntaxKind::TerminalFalse
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13335,17 +15336,17 @@ impl TypedSyntaxNode for TerminalEnum {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalEnumPtr(self.node.0.stable_ptr)
+        TerminalFalsePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenExtern {
+pub struct TokenFunction {
     node: SyntaxNode,
 }
-impl Token for TokenExtern {
+impl Token for TokenFunction {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenExtern,
+        TokenFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFunction,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13354,25 +15355,25 @@ impl Token for TokenExtern {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternPtr(pub SyntaxStablePtrId);
-impl TokenExternPtr {
+pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
+impl TokenFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenExternGreen(pub GreenId);
-impl TokenExternGreen {
+pub struct TokenFunctionGreen(pub GreenId);
+impl TokenFunctionGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenExtern);
-    type StablePtr = TokenExternPtr;
-    type Green = TokenExternGreen;
+impl TypedSyntaxNode for TokenFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
+    type StablePtr = TokenFunctionPtr;
+    type Green = TokenFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenExternGreen(db.intern_green(GreenNode {
+        TokenFunctionGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13381,7 +15382,7 @@ impl TypedSyntaxNode for TokenExtern {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenExtern)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
             }
         }
     }
@@ -13392,27 +15393,27 @@ impl TypedSyntaxNode for TokenExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenExternPtr(self.node.0.stable_ptr)
+        TokenFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalExtern {
+pub struct TerminalFunction {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalExtern {
-    const KIND: SyntaxKind = SyntaxKind::TerminalExtern;
-    type TokenType = TokenExtern;
+impl Terminal for TerminalFunction {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
+    type TokenType = TokenFunction;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalExtern as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13420,37 +15421,37 @@ impl Terminal for TerminalExtern {
         self.token(db).text(db)
     }
 }
-impl TerminalExtern {
+impl TerminalFunction {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenExtern {
-        TokenExtern::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
+        TokenFunction::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternPtr(pub SyntaxStablePtrId);
-impl TerminalExternPtr {
+pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
+impl TerminalFunctionPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalExternGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalExtern {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalExtern);
-    type StablePtr = TerminalExternPtr;
-    type Green = TerminalExternGreen;
+pub struct TerminalFunctionGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFunction {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
+    type StablePtr = TerminalFunctionPtr;
+    type Green = TerminalFunctionGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalExternGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalExtern,
+        TerminalFunctionGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFunction,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenExtern::missing(db).0,
+                    TokenFunction::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13461,10 +15462,10 @@ impl TypedSyntaxNode for TerminalExtern {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalExtern,
+            SyntaxKind::TerminalFunction,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalExtern
+            SyntaxKind::TerminalFunction
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13476,17 +15477,17 @@ impl TypedSyntaxNode for TerminalExtern {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalExternPtr(self.node.0.stable_ptr)
+        TerminalFunctionPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFalse {
+pub struct TokenIf {
     node: SyntaxNode,
 }
-impl Token for TokenFalse {
+impl Token for TokenIf {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFalse,
+        TokenIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenIf,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13495,25 +15496,25 @@ impl Token for TokenFalse {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalsePtr(pub SyntaxStablePtrId);
-impl TokenFalsePtr {
+pub struct TokenIfPtr(pub SyntaxStablePtrId);
+impl TokenIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFalseGreen(pub GreenId);
-impl TokenFalseGreen {
+pub struct TokenIfGreen(pub GreenId);
+impl TokenIfGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFalse);
-    type StablePtr = TokenFalsePtr;
-    type Green = TokenFalseGreen;
+impl TypedSyntaxNode for TokenIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
+    type StablePtr = TokenIfPtr;
+    type Green = TokenIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFalseGreen(db.intern_green(GreenNode {
+        TokenIfGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13522,7 +15523,7 @@ impl TypedSyntaxNode for TokenFalse {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFalse)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
             }
         }
     }
@@ -13533,27 +15534,27 @@ impl TypedSyntaxNode for TokenFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFalsePtr(self.node.0.stable_ptr)
+        TokenIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFalse {
+pub struct TerminalIf {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFalse {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFalse;
-    type TokenType = TokenFalse;
+impl Terminal for TerminalIf {
+    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
+    type TokenType = TokenIf;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFalse as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13561,37 +15562,37 @@ impl Terminal for TerminalFalse {
         self.token(db).text(db)
     }
 }
-impl TerminalFalse {
+impl TerminalIf {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFalse {
-        TokenFalse::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
+        TokenIf::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalsePtr(pub SyntaxStablePtrId);
-impl TerminalFalsePtr {
+pub struct TerminalIfPtr(pub SyntaxStablePtrId);
+impl TerminalIfPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFalseGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFalse {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFalse);
-    type StablePtr = TerminalFalsePtr;
-    type Green = TerminalFalseGreen;
+pub struct TerminalIfGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalIf {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
+    type StablePtr = TerminalIfPtr;
+    type Green = TerminalIfGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFalseGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFalse,
+        TerminalIfGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalIf,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFalse::missing(db).0,
+                    TokenIf::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13602,10 +15603,10 @@ impl TypedSyntaxNode for TerminalFalse {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFalse,
+            SyntaxKind::TerminalIf,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFalse
+            SyntaxKind::TerminalIf
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13617,17 +15618,17 @@ impl TypedSyntaxNode for TerminalFalse {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFalsePtr(self.node.0.stable_ptr)
+        TerminalIfPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenFunction {
+pub struct TokenLoop {
     node: SyntaxNode,
 }
-impl Token for TokenFunction {
+impl Token for TokenLoop {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenFunction,
+        TokenLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLoop,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13636,25 +15637,25 @@ impl Token for TokenFunction {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionPtr(pub SyntaxStablePtrId);
-impl TokenFunctionPtr {
+pub struct TokenLoopPtr(pub SyntaxStablePtrId);
+impl TokenLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenFunctionGreen(pub GreenId);
-impl TokenFunctionGreen {
+pub struct TokenLoopGreen(pub GreenId);
+impl TokenLoopGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFunction);
-    type StablePtr = TokenFunctionPtr;
-    type Green = TokenFunctionGreen;
+impl TypedSyntaxNode for TokenLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLoop);
+    type StablePtr = TokenLoopPtr;
+    type Green = TokenLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenFunctionGreen(db.intern_green(GreenNode {
+        TokenLoopGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13663,7 +15664,7 @@ impl TypedSyntaxNode for TokenFunction {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFunction)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLoop)
             }
         }
     }
@@ -13674,27 +15675,27 @@ impl TypedSyntaxNode for TokenFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenFunctionPtr(self.node.0.stable_ptr)
+        TokenLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalFunction {
+pub struct TerminalLoop {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalFunction {
-    const KIND: SyntaxKind = SyntaxKind::TerminalFunction;
-    type TokenType = TokenFunction;
+impl Terminal for TerminalLoop {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLoop;
+    type TokenType = TokenLoop;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalFunction as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalLoop as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13702,37 +15703,37 @@ impl Terminal for TerminalFunction {
         self.token(db).text(db)
     }
 }
-impl TerminalFunction {
+impl TerminalLoop {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFunction {
-        TokenFunction::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLoop {
+        TokenLoop::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionPtr(pub SyntaxStablePtrId);
-impl TerminalFunctionPtr {
+pub struct TerminalLoopPtr(pub SyntaxStablePtrId);
+impl TerminalLoopPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalFunctionGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalFunction {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFunction);
-    type StablePtr = TerminalFunctionPtr;
-    type Green = TerminalFunctionGreen;
+pub struct TerminalLoopGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLoop);
+    type StablePtr = TerminalLoopPtr;
+    type Green = TerminalLoopGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalFunctionGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalFunction,
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenFunction::missing(db).0,
+                    TokenLoop::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13743,10 +15744,10 @@ impl TypedSyntaxNode for TerminalFunction {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalFunction,
+            SyntaxKind::TerminalLoop,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalFunction
+            SyntaxKind::TerminalLoop
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13758,17 +15759,17 @@ impl TypedSyntaxNode for TerminalFunction {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalFunctionPtr(self.node.0.stable_ptr)
+        TerminalLoopPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenIf {
+pub struct TokenWhile {
     node: SyntaxNode,
 }
-impl Token for TokenIf {
+impl Token for TokenWhile {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenIf,
+        TokenWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenWhile,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13777,25 +15778,25 @@ impl Token for TokenIf {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfPtr(pub SyntaxStablePtrId);
-impl TokenIfPtr {
+pub struct TokenWhilePtr(pub SyntaxStablePtrId);
+impl TokenWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenIfGreen(pub GreenId);
-impl TokenIfGreen {
+pub struct TokenWhileGreen(pub GreenId);
+impl TokenWhileGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenIf);
-    type StablePtr = TokenIfPtr;
-    type Green = TokenIfGreen;
+impl TypedSyntaxNode for TokenWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenWhile);
+    type StablePtr = TokenWhilePtr;
+    type Green = TokenWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenIfGreen(db.intern_green(GreenNode {
+        TokenWhileGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13804,7 +15805,7 @@ impl TypedSyntaxNode for TokenIf {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenIf)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenWhile)
             }
         }
     }
@@ -13815,27 +15816,27 @@ impl TypedSyntaxNode for TokenIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenIfPtr(self.node.0.stable_ptr)
+        TokenWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalIf {
+pub struct TerminalWhile {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalIf {
-    const KIND: SyntaxKind = SyntaxKind::TerminalIf;
-    type TokenType = TokenIf;
+impl Terminal for TerminalWhile {
+    const KIND: SyntaxKind = SyntaxKind::TerminalWhile;
+    type TokenType = TokenWhile;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalIf as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalWhile as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13843,37 +15844,37 @@ impl Terminal for TerminalIf {
         self.token(db).text(db)
     }
 }
-impl TerminalIf {
+impl TerminalWhile {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenIf {
-        TokenIf::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenWhile {
+        TokenWhile::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfPtr(pub SyntaxStablePtrId);
-impl TerminalIfPtr {
+pub struct TerminalWhilePtr(pub SyntaxStablePtrId);
+impl TerminalWhilePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalIfGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalIf {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalIf);
-    type StablePtr = TerminalIfPtr;
-    type Green = TerminalIfGreen;
+pub struct TerminalWhileGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalWhile);
+    type StablePtr = TerminalWhilePtr;
+    type Green = TerminalWhileGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalIfGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalIf,
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenIf::missing(db).0,
+                    TokenWhile::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -13884,10 +15885,10 @@ impl TypedSyntaxNode for TerminalIf {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalIf,
+            SyntaxKind::TerminalWhile,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalIf
+            SyntaxKind::TerminalWhile
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -13899,17 +15900,17 @@ impl TypedSyntaxNode for TerminalIf {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalIfPtr(self.node.0.stable_ptr)
+        TerminalWhilePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenLoop {
+pub struct TokenFor {
     node: SyntaxNode,
 }
-impl Token for TokenLoop {
+impl Token for TokenFor {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenLoopGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenLoop,
+        TokenForGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenFor,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -13918,25 +15919,25 @@ impl Token for TokenLoop {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLoopPtr(pub SyntaxStablePtrId);
-impl TokenLoopPtr {
+pub struct TokenForPtr(pub SyntaxStablePtrId);
+impl TokenForPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenLoopGreen(pub GreenId);
-impl TokenLoopGreen {
+pub struct TokenForGreen(pub GreenId);
+impl TokenForGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenLoop {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLoop);
-    type StablePtr = TokenLoopPtr;
-    type Green = TokenLoopGreen;
+impl TypedSyntaxNode for TokenFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFor);
+    type StablePtr = TokenForPtr;
+    type Green = TokenForGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenLoopGreen(db.intern_green(GreenNode {
+        TokenForGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -13945,7 +15946,7 @@ impl TypedSyntaxNode for TokenLoop {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLoop)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFor)
             }
         }
     }
@@ -13956,27 +15957,27 @@ impl TypedSyntaxNode for TokenLoop {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenLoopPtr(self.node.0.stable_ptr)
+        TokenForPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalLoop {
+pub struct TerminalFor {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalLoop {
-    const KIND: SyntaxKind = SyntaxKind::TerminalLoop;
-    type TokenType = TokenLoop;
+impl Terminal for TerminalFor {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFor;
+    type TokenType = TokenFor;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalLoop as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalFor as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalLoopGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLoop,
+        TerminalForGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFor,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -13984,37 +15985,37 @@ impl Terminal for TerminalLoop {
         self.token(db).text(db)
     }
 }
-impl TerminalLoop {
+impl TerminalFor {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLoop {
-        TokenLoop::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFor {
+        TokenFor::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLoopPtr(pub SyntaxStablePtrId);
-impl TerminalLoopPtr {
+pub struct TerminalForPtr(pub SyntaxStablePtrId);
+impl TerminalForPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalLoopGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalLoop {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLoop);
-    type StablePtr = TerminalLoopPtr;
-    type Green = TerminalLoopGreen;
+pub struct TerminalForGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFor);
+    type StablePtr = TerminalForPtr;
+    type Green = TerminalForGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalLoopGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalLoop,
+        TerminalForGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalFor,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenLoop::missing(db).0,
+                    TokenFor::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -14025,10 +16026,10 @@ impl TypedSyntaxNode for TerminalLoop {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalLoop,
+            SyntaxKind::TerminalFor,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalLoop
+            SyntaxKind::TerminalFor
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -14040,7 +16041,7 @@ impl TypedSyntaxNode for TerminalLoop {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalLoopPtr(self.node.0.stable_ptr)
+        TerminalForPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -15172,6 +17173,147 @@ impl TypedSyntaxNode for TerminalOf {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenPub {
+    node: SyntaxNode,
+}
+impl Token for TokenPub {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenPub,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenPubPtr(pub SyntaxStablePtrId);
+impl TokenPubPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenPubGreen(pub GreenId);
+impl TokenPubGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenPub);
+    type StablePtr = TokenPubPtr;
+    type Green = TokenPubGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenPub)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenPubPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalPub {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalPub {
+    const KIND: SyntaxKind = SyntaxKind::TerminalPub;
+    type TokenType = TokenPub;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalPub as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPub,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalPub {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenPub {
+        TokenPub::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalPubPtr(pub SyntaxStablePtrId);
+impl TerminalPubPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalPubGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalPub {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalPub);
+    type StablePtr = TerminalPubPtr;
+    type Green = TerminalPubGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalPubGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalPub,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenPub::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalPub,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalPub
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalPubPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenRef {
     node: SyntaxNode,
 }
@@ -7,9 +7,12 @@ use num_traits::Num;
 use smol_str::SmolStr;
 use unescaper::unescape;
 
-use super::{TerminalFalse, TerminalLiteralNumber, TerminalShortString, TerminalTrue};
+use super::{
+    TerminalFalse, TerminalLiteralNumber, TerminalShortString, TerminalString, TerminalTrue,
+    Visibility,
+};
 use crate::node::db::SyntaxGroup;
-use crate::node::Terminal;
+use crate::node::{Terminal, TypedSyntaxNode};
 
 impl TerminalTrue {
     #[inline(always)]
@@ -109,3 +112,36 @@ impl TerminalShortString {
         Some(suffix.into())
     }
 }
+
+impl TerminalString {
+    /// Interpret this token/terminal as a string.
+    pub fn string_value(&self, db: &dyn SyntaxGroup) -> Option<String> {
+        let text = self.text(db);
+        let (prefix, text) = text.split_once('"')?;
+        if !prefix.is_empty() {
+            return None;
+        }
+        let text = text.strip_suffix('"')?;
+
+        let text = unescape(text).ok()?;
+
+        if !text.is_ascii() {
+            return None;
+        }
+
+        Some(text)
+    }
+}
+
+impl Visibility {
+    /// Returns the visibility modifier followed by a space, to be used as a prefix of a generated
+    /// item. Returns an empty string if there is no visibility modifier.
+    pub fn as_item_prefix(&self, db: &dyn SyntaxGroup) -> String {
+        match self {
+            Visibility::Default(_) => "".to_string(),
+            Visibility::Pub(visibility) => {
+                format!("{} ", visibility.as_syntax_node().get_text_without_trivia(db))
+            }
+        }
+    }
+}
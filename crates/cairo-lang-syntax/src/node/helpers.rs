@@ -290,3 +290,18 @@ impl QueryAttrs for Member {
         self.attributes(db).elements(db)
     }
 }
+
+/// Helper trait for ast::WrappedArgList.
+pub trait WrappedArgListHelper {
+    /// Returns the list of arguments, or None if the argument list is missing.
+    fn arg_list(&self, db: &dyn SyntaxGroup) -> Option<ast::ArgList>;
+}
+impl WrappedArgListHelper for ast::WrappedArgList {
+    fn arg_list(&self, db: &dyn SyntaxGroup) -> Option<ast::ArgList> {
+        match self {
+            ast::WrappedArgList::Parenthesized(args) => Some(args.args(db)),
+            ast::WrappedArgList::Bracketed(args) => Some(args.args(db)),
+            ast::WrappedArgList::Missing(_) => None,
+        }
+    }
+}
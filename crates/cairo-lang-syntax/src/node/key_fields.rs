@@ -1,9 +1,14 @@
 // Autogenerated file. To regenerate, please run `cargo run --bin generate-syntax`.
+
 use super::ids::GreenId;
 use super::kind::SyntaxKind;
+
 /// Gets the vector of children ids that are the indexing key for this SyntaxKind.
+
 /// Each SyntaxKind has some children that are defined in the spec to be its indexing key
+
 /// for its stable pointer. See [super::stable_ptr].
+
 pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId> {
     match kind {
         SyntaxKind::Trivia => vec![],
@@ -77,6 +82,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ExprLoop => {
             vec![]
         }
+        SyntaxKind::ExprWhile => {
+            vec![]
+        }
+        SyntaxKind::ExprFor => {
+            vec![]
+        }
         SyntaxKind::ElseClause => {
             vec![]
         }
@@ -92,6 +103,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ExprInlineMacro => {
             vec![]
         }
+        SyntaxKind::ArgListBracketed => {
+            vec![]
+        }
+        SyntaxKind::WrappedArgListMissing => {
+            vec![]
+        }
         SyntaxKind::StructArgExpr => {
             vec![]
         }
@@ -125,6 +142,15 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::PatternEnum => {
             vec![]
         }
+        SyntaxKind::PatternRange => {
+            vec![]
+        }
+        SyntaxKind::ExprClosure => {
+            vec![]
+        }
+        SyntaxKind::ClosureParamWrapperNAry => {
+            vec![]
+        }
         SyntaxKind::TypeClause => {
             vec![]
         }
@@ -168,6 +194,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::Param => {
             vec![/* name */ children[1]]
         }
+        SyntaxKind::ClosureParam => {
+            vec![/* name */ children[1]]
+        }
+        SyntaxKind::ClosureParamList => vec![],
         SyntaxKind::ModifierList => vec![],
         SyntaxKind::ParamList => vec![],
         SyntaxKind::ImplicitsClause => {
@@ -184,7 +214,7 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![]
         }
         SyntaxKind::Member => {
-            vec![/* name */ children[1]]
+            vec![/* name */ children[2]]
         }
         SyntaxKind::MemberList => vec![],
         SyntaxKind::ItemList => vec![],
@@ -195,8 +225,20 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![]
         }
         SyntaxKind::AttributeList => vec![],
+        SyntaxKind::VisibilityDefault => {
+            vec![]
+        }
+        SyntaxKind::VisibilityPubArgumentClause => {
+            vec![]
+        }
+        SyntaxKind::OptionVisibilityPubArgumentClauseEmpty => {
+            vec![]
+        }
+        SyntaxKind::VisibilityPub => {
+            vec![]
+        }
         SyntaxKind::ItemModule => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ModuleBody => {
             vec![]
@@ -205,19 +247,19 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![/* name */ children[1]]
         }
         SyntaxKind::ItemConstant => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::FunctionWithBody => {
-            vec![/* declaration */ children[1]]
+            vec![/* declaration */ children[2]]
         }
         SyntaxKind::ItemExternFunction => {
-            vec![/* declaration */ children[2]]
+            vec![/* declaration */ children[3]]
         }
         SyntaxKind::ItemExternType => {
-            vec![/* name */ children[3]]
+            vec![/* name */ children[4]]
         }
         SyntaxKind::ItemTrait => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::TraitBody => {
             vec![]
@@ -230,7 +272,7 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![/* declaration */ children[1]]
         }
         SyntaxKind::ItemImpl => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ImplBody => {
             vec![]
@@ -240,19 +282,19 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
             vec![]
         }
         SyntaxKind::ItemImplAlias => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ItemStruct => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ItemEnum => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ItemTypeAlias => {
-            vec![/* name */ children[2]]
+            vec![/* name */ children[3]]
         }
         SyntaxKind::ItemUse => {
-            vec![/* use_path */ children[2]]
+            vec![/* use_path */ children[3]]
         }
         SyntaxKind::UsePathLeaf => {
             vec![/* ident */ children[0], /* alias_clause */ children[1]]
@@ -305,6 +347,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalShortString => {
             vec![]
         }
+        SyntaxKind::TokenString => vec![],
+        SyntaxKind::TerminalString => {
+            vec![]
+        }
         SyntaxKind::TokenAs => vec![],
         SyntaxKind::TerminalAs => {
             vec![]
@@ -341,6 +387,14 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalLoop => {
             vec![]
         }
+        SyntaxKind::TokenWhile => vec![],
+        SyntaxKind::TerminalWhile => {
+            vec![]
+        }
+        SyntaxKind::TokenFor => vec![],
+        SyntaxKind::TerminalFor => {
+            vec![]
+        }
         SyntaxKind::TokenImpl => vec![],
         SyntaxKind::TerminalImpl => {
             vec![]
@@ -373,6 +427,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalOf => {
             vec![]
         }
+        SyntaxKind::TokenPub => vec![],
+        SyntaxKind::TerminalPub => {
+            vec![]
+        }
         SyntaxKind::TokenRef => vec![],
         SyntaxKind::TerminalRef => {
             vec![]
@@ -1,4 +1,5 @@
 // Autogenerated file. To regenerate, please run `cargo run --bin generate-syntax`.
+
 use core::fmt;
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum SyntaxKind {
@@ -29,11 +30,15 @@ pub enum SyntaxKind {
     MatchArm,
     ExprIf,
     ExprLoop,
+    ExprWhile,
+    ExprFor,
     ElseClause,
     OptionElseClauseEmpty,
     ExprErrorPropagate,
     ExprIndexed,
     ExprInlineMacro,
+    ArgListBracketed,
+    WrappedArgListMissing,
     StructArgExpr,
     OptionStructArgExprEmpty,
     StructArgSingle,
@@ -47,6 +52,9 @@ pub enum SyntaxKind {
     PatternList,
     PatternStructParamWithExpr,
     PatternEnum,
+    PatternRange,
+    ExprClosure,
+    ClosureParamWrapperNAry,
     TypeClause,
     OptionTypeClauseEmpty,
     ReturnTypeClause,
@@ -62,6 +70,8 @@ pub enum SyntaxKind {
     StatementReturn,
     StatementBreak,
     Param,
+    ClosureParam,
+    ClosureParamList,
     ModifierList,
     ParamList,
     ImplicitsClause,
@@ -75,6 +85,10 @@ pub enum SyntaxKind {
     ItemMissing,
     Attribute,
     AttributeList,
+    VisibilityDefault,
+    VisibilityPubArgumentClause,
+    OptionVisibilityPubArgumentClauseEmpty,
+    VisibilityPub,
     ItemModule,
     ModuleBody,
     FunctionDeclaration,
@@ -117,6 +131,8 @@ pub enum SyntaxKind {
     TerminalLiteralNumber,
     TokenShortString,
     TerminalShortString,
+    TokenString,
+    TerminalString,
     TokenAs,
     TerminalAs,
     TokenConst,
@@ -135,6 +151,10 @@ pub enum SyntaxKind {
     TerminalIf,
     TokenLoop,
     TerminalLoop,
+    TokenWhile,
+    TerminalWhile,
+    TokenFor,
+    TerminalFor,
     TokenImpl,
     TerminalImpl,
     TokenImplicits,
@@ -151,6 +171,8 @@ pub enum SyntaxKind {
     TerminalNoPanic,
     TokenOf,
     TerminalOf,
+    TokenPub,
+    TerminalPub,
     TokenRef,
     TerminalRef,
     TokenContinue,
@@ -271,6 +293,7 @@ impl SyntaxKind {
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
                 | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenString
                 | SyntaxKind::TokenAs
                 | SyntaxKind::TokenConst
                 | SyntaxKind::TokenElse
@@ -280,6 +303,8 @@ impl SyntaxKind {
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
@@ -288,6 +313,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMut
                 | SyntaxKind::TokenNoPanic
                 | SyntaxKind::TokenOf
+                | SyntaxKind::TokenPub
                 | SyntaxKind::TokenRef
                 | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenReturn
@@ -354,6 +380,7 @@ impl SyntaxKind {
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
                 | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalString
                 | SyntaxKind::TerminalAs
                 | SyntaxKind::TerminalConst
                 | SyntaxKind::TerminalElse
@@ -363,6 +390,8 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
@@ -371,6 +400,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMut
                 | SyntaxKind::TerminalNoPanic
                 | SyntaxKind::TerminalOf
+                | SyntaxKind::TerminalPub
                 | SyntaxKind::TerminalRef
                 | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalReturn
@@ -438,6 +468,8 @@ impl SyntaxKind {
                 | SyntaxKind::TokenFunction
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenWhile
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
@@ -446,6 +478,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenMut
                 | SyntaxKind::TokenNoPanic
                 | SyntaxKind::TokenOf
+                | SyntaxKind::TokenPub
                 | SyntaxKind::TokenRef
                 | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenReturn
@@ -469,6 +502,8 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalFunction
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalWhile
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
@@ -477,6 +512,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalMut
                 | SyntaxKind::TerminalNoPanic
                 | SyntaxKind::TerminalOf
+                | SyntaxKind::TerminalPub
                 | SyntaxKind::TerminalRef
                 | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalReturn
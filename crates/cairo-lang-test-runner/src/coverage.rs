@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_runner::{SierraCasmRunner, TraceEntry};
+use cairo_lang_sierra::program::StatementIdx;
+use cairo_lang_sierra_generator::db::StatementsLocations;
+use itertools::Itertools;
+
+#[cfg(test)]
+#[path = "coverage_test.rs"]
+mod test;
+
+/// The default path of the coverage report file.
+pub const COVERAGE_FILE: &str = "lcov.info";
+
+/// Configuration of the coverage mode.
+#[derive(Clone, Debug)]
+pub struct CoverageConfig {
+    /// The path of the LCOV coverage report file.
+    pub path: PathBuf,
+}
+impl Default for CoverageConfig {
+    fn default() -> Self {
+        Self { path: COVERAGE_FILE.into() }
+    }
+}
+
+/// The number of times each Sierra statement was executed.
+pub type StatementsHits = HashMap<StatementIdx, usize>;
+
+/// Adds the Sierra statements executed in the steps of `trace` to `hits`.
+///
+/// A statement is executed whenever the first instruction of its code is executed.
+pub fn add_statements_hits(
+    runner: &SierraCasmRunner,
+    trace: &[TraceEntry],
+    hits: &mut StatementsHits,
+) {
+    for entry in trace {
+        for statement_idx in runner.sierra_statements_starting_at_pc(entry.pc) {
+            *hits.entry(statement_idx).or_default() += 1;
+        }
+    }
+}
+
+/// The number of times each line of each source file was executed, by the file path and the line
+/// number (starting from 1).
+pub type LinesHits = BTreeMap<String, BTreeMap<usize, usize>>;
+
+/// Returns the number of times each line of code of the crates at `crate_roots` was executed.
+///
+/// A line is executed whenever a Sierra statement originating from it is executed, including the
+/// lines of the calls the statement was inlined into. Lines of statements that were never executed
+/// are included with no hits. Lines of other crates, such as the corelib, are excluded.
+pub fn lines_hits(
+    statements_locations: &StatementsLocations,
+    statements_hits: &StatementsHits,
+    crate_roots: &[PathBuf],
+) -> LinesHits {
+    let mut lines = LinesHits::new();
+    for (statement_idx, spans) in statements_locations {
+        let hits = statements_hits.get(statement_idx).copied().unwrap_or_default();
+        for span in spans {
+            if !crate_roots.iter().any(|root| Path::new(&span.file).starts_with(root)) {
+                continue;
+            }
+            let line_hits =
+                lines.entry(span.file.clone()).or_default().entry(span.start.line + 1).or_default();
+            *line_hits = (*line_hits).max(hits);
+        }
+    }
+    lines
+}
+
+/// Formats an LCOV coverage report.
+pub fn format_lcov(lines_hits: &LinesHits) -> String {
+    lines_hits
+        .iter()
+        .map(|(file, lines)| {
+            format!(
+                "TN:\nSF:{file}\n{}LF:{}\nLH:{}\nend_of_record\n",
+                lines.iter().map(|(line, hits)| format!("DA:{line},{hits}\n")).join(""),
+                lines.len(),
+                lines.values().filter(|hits| **hits > 0).count()
+            )
+        })
+        .join("")
+}
+
+/// Writes the LCOV coverage report of a run of the crates at `crate_roots`, and prints the summary
+/// of the coverage.
+pub fn write_coverage_report(
+    config: &CoverageConfig,
+    statements_locations: &StatementsLocations,
+    statements_hits: &StatementsHits,
+    crate_roots: &[PathBuf],
+) -> Result<()> {
+    let lines_hits = lines_hits(statements_locations, statements_hits, crate_roots);
+    fs::write(&config.path, format_lcov(&lines_hits))
+        .with_context(|| format!("Failed to write `{}`.", config.path.display()))?;
+    let lines_count: usize = lines_hits.values().map(|lines| lines.len()).sum();
+    let covered_lines_count: usize =
+        lines_hits.values().map(|lines| lines.values().filter(|hits| **hits > 0).count()).sum();
+    eprintln!(
+        "coverage: {covered_lines_count}/{lines_count} lines covered, written to `{}`.",
+        config.path.display()
+    );
+    Ok(())
+}
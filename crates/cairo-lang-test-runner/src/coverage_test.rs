@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use cairo_felt::Felt252;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_runner::{Arg, SierraCasmRunner};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_sierra_generator::test_utils::setup_db_and_get_crate_id;
+use cairo_lang_utils::try_extract_matches;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{add_statements_hits, format_lcov, lines_hits, StatementsHits};
+
+/// Compiles the given crate content, runs its `main` function with the argument 5, and returns the
+/// LCOV coverage report of the run.
+fn lcov_of_main(content: &str) -> String {
+    let (db, crate_id) = setup_db_and_get_crate_id(content);
+    let free_function_id = db
+        .crate_modules(crate_id)
+        .iter()
+        .find_map(|module_id| {
+            try_extract_matches!(
+                db.module_item_by_name(*module_id, "main".into()).unwrap()?,
+                ModuleItemId::FreeFunction
+            )
+        })
+        .unwrap();
+    let function_id =
+        ConcreteFunctionWithBodyId::from_no_generics_free(&db, free_function_id).unwrap();
+    let program = db.get_sierra_program_for_functions(vec![function_id]).unwrap();
+    let statements_locations =
+        db.get_sierra_program_statements_locations(vec![function_id]).unwrap();
+    let runner = SierraCasmRunner::new(
+        replace_sierra_ids_in_program(&db, &program),
+        None,
+        Default::default(),
+    )
+    .unwrap();
+    let func = runner.find_function_by_path("test::main").unwrap();
+    let result = runner
+        .run_function_with_starknet_context(
+            func,
+            &[Arg::Value(Felt252::from(5))],
+            None,
+            Default::default(),
+        )
+        .unwrap();
+    let mut statements_hits = StatementsHits::default();
+    add_statements_hits(&runner, &result.trace, &mut statements_hits);
+    format_lcov(&lines_hits(&statements_locations, &statements_hits, &[PathBuf::from("src")]))
+}
+
+#[test]
+fn test_lcov_report() {
+    // Running only the `else` branch, so the line of `zero()` is not covered.
+    assert_eq!(
+        lcov_of_main(indoc! {"
+            fn main(x: felt252) -> felt252 {
+                if x == 0 {
+                    zero()
+                } else {
+                    identity(x)
+                }
+            }
+
+            #[inline(never)]
+            fn zero() -> felt252 {
+                0
+            }
+
+            #[inline(never)]
+            fn identity(x: felt252) -> felt252 {
+                x
+            }
+        "}),
+        indoc! {"
+            TN:
+            SF:src/lib.cairo
+            DA:2,1
+            DA:3,0
+            DA:5,1
+            LF:3
+            LH:2
+            end_of_record
+        "}
+    );
+}
+
+#[test]
+fn test_lcov_report_of_inline_macros() {
+    // The lines of the code generated by `assert!` are the lines of its call, and the lines of the
+    // corelib functions it calls are excluded.
+    assert_eq!(
+        lcov_of_main(indoc! {"
+            fn main(x: felt252) -> felt252 {
+                assert!(x != 0, 'x is zero');
+                check(x);
+                x
+            }
+
+            #[inline(never)]
+            fn check(x: felt252) {
+                assert!(
+                    x == 5,
+                    'x is not five'
+                );
+            }
+        "}),
+        indoc! {"
+            TN:
+            SF:src/lib.cairo
+            DA:2,1
+            DA:3,1
+            DA:9,1
+            DA:10,1
+            LF:4
+            LH:4
+            end_of_record
+        "}
+    );
+}
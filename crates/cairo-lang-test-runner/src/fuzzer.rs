@@ -0,0 +1,128 @@
+use cairo_felt::Felt252;
+use cairo_lang_semantic::corelib::{core_felt252_ty, get_core_ty_by_name};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::TypeId;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+#[cfg(test)]
+#[path = "fuzzer_test.rs"]
+mod test;
+
+/// The default number of runs of a fuzz test.
+pub const DEFAULT_FUZZER_RUNS: usize = 256;
+/// The default seed of the arguments generation of a fuzz test.
+pub const DEFAULT_FUZZER_SEED: u64 = 0;
+
+/// The configuration of a fuzz test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuzzerConfig {
+    /// The number of times the test is run, each time with newly generated arguments.
+    pub runs: usize,
+    /// The seed of the arguments generation.
+    pub seed: u64,
+}
+
+/// The type of a parameter of a parameterized or a fuzz test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    Felt252,
+    Unsigned { bits: u32 },
+    Signed { bits: u32 },
+    U256,
+}
+impl ParamType {
+    /// Returns the parameter type of a semantic type, if it is a supported test parameter type.
+    pub fn from_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<Self> {
+        if ty == core_felt252_ty(db) {
+            return Some(ParamType::Felt252);
+        }
+        [
+            ("u8", ParamType::Unsigned { bits: 8 }),
+            ("u16", ParamType::Unsigned { bits: 16 }),
+            ("u32", ParamType::Unsigned { bits: 32 }),
+            ("u64", ParamType::Unsigned { bits: 64 }),
+            ("u128", ParamType::Unsigned { bits: 128 }),
+            ("u256", ParamType::U256),
+            ("i8", ParamType::Signed { bits: 8 }),
+            ("i16", ParamType::Signed { bits: 16 }),
+            ("i32", ParamType::Signed { bits: 32 }),
+            ("i64", ParamType::Signed { bits: 64 }),
+            ("i128", ParamType::Signed { bits: 128 }),
+        ]
+        .into_iter()
+        .find(|(name, _)| ty == get_core_ty_by_name(db, (*name).into(), vec![]))
+        .map(|(_, param_type)| param_type)
+    }
+
+    /// Returns the minimal and maximal values of the type.
+    fn bounds(&self) -> (BigInt, BigInt) {
+        let max_of_bits = |bits: u32| (BigInt::one() << bits) - 1;
+        match self {
+            ParamType::Felt252 => (BigInt::zero(), BigInt::from(Felt252::prime()) - 1),
+            ParamType::Unsigned { bits } => (BigInt::zero(), max_of_bits(*bits)),
+            ParamType::Signed { bits } => (-(BigInt::one() << (bits - 1)), max_of_bits(bits - 1)),
+            ParamType::U256 => (BigInt::zero(), max_of_bits(256)),
+        }
+    }
+
+    /// Returns the values passed to the function for an argument of this type, or `None` if the
+    /// value is out of the range of the type.
+    ///
+    /// Negative felt252 values are taken modulo the prime.
+    pub fn to_args(&self, value: &BigInt) -> Option<Vec<Felt252>> {
+        let (min, max) = self.bounds();
+        match self {
+            ParamType::Felt252 => (-&max <= *value && *value <= max).then(|| vec![value.into()]),
+            ParamType::Unsigned { .. } | ParamType::Signed { .. } => {
+                (min <= *value && *value <= max).then(|| vec![value.into()])
+            }
+            ParamType::U256 => (min <= *value && *value <= max).then(|| {
+                let mask = (BigInt::one() << 128) - 1;
+                vec![(value & &mask).into(), (value >> 128_usize).into()]
+            }),
+        }
+    }
+
+    /// Generates a random value of the type.
+    ///
+    /// Values at the edges of the range of the type are generated more often, as they are more
+    /// likely to reveal bugs.
+    pub fn generate(&self, rng: &mut FuzzerRng) -> BigInt {
+        let (min, max) = self.bounds();
+        // Generating an edge value in a quarter of the runs.
+        if rng.next_u64() < u64::MAX / 4 {
+            let edges = [min.clone(), &min + 1, BigInt::zero(), BigInt::one(), &max - 1, max];
+            return edges[(rng.next_u64() % edges.len() as u64) as usize].clone();
+        }
+        let range_size = (max - &min + BigInt::one()).to_biguint().unwrap();
+        min + BigInt::from(rng.next_below(&range_size))
+    }
+}
+
+/// A deterministic pseudo-random numbers generator, based on SplitMix64.
+pub struct FuzzerRng {
+    state: u64,
+}
+impl FuzzerRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random 64-bit number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in the range `[0, bound)`.
+    fn next_below(&mut self, bound: &BigUint) -> BigUint {
+        // Generating 64 more bits than needed, so that the bias of the modulo is negligible.
+        let words = bound.bits() / 64 + 2;
+        let value = (0..words).fold(BigUint::zero(), |acc, _| (acc << 64) + self.next_u64());
+        value % bound
+    }
+}
@@ -0,0 +1,63 @@
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{FuzzerRng, ParamType, DEFAULT_FUZZER_SEED};
+
+/// The parameter types of a fuzz test, one of each kind.
+const PARAM_TYPES: [ParamType; 4] = [
+    ParamType::Felt252,
+    ParamType::Unsigned { bits: 32 },
+    ParamType::Signed { bits: 8 },
+    ParamType::U256,
+];
+
+/// Generates the inputs of `runs` runs of a fuzz test with the parameters [PARAM_TYPES].
+fn generate_inputs(seed: u64, runs: usize) -> Vec<Vec<BigInt>> {
+    let mut rng = FuzzerRng::new(seed);
+    (0..runs)
+        .map(|_| PARAM_TYPES.iter().map(|param_type| param_type.generate(&mut rng)).collect())
+        .collect()
+}
+
+#[test]
+fn test_same_seed_same_inputs() {
+    assert_eq!(
+        generate_inputs(DEFAULT_FUZZER_SEED, 100),
+        generate_inputs(DEFAULT_FUZZER_SEED, 100)
+    );
+    assert_eq!(generate_inputs(7, 100), generate_inputs(7, 100));
+    assert_ne!(generate_inputs(7, 100), generate_inputs(8, 100));
+}
+
+#[test]
+fn test_bounds() {
+    assert_eq!(ParamType::Unsigned { bits: 8 }.bounds(), (BigInt::zero(), BigInt::from(255)));
+    assert_eq!(ParamType::Signed { bits: 8 }.bounds(), (BigInt::from(-128), BigInt::from(127)));
+    assert_eq!(
+        ParamType::Signed { bits: 128 }.bounds(),
+        (BigInt::from(i128::MIN), BigInt::from(i128::MAX))
+    );
+    assert_eq!(ParamType::U256.bounds(), (BigInt::zero(), (BigInt::one() << 256) - 1));
+}
+
+#[test_case(ParamType::Felt252; "felt252")]
+#[test_case(ParamType::Unsigned { bits: 8 }; "u8")]
+#[test_case(ParamType::Unsigned { bits: 128 }; "u128")]
+#[test_case(ParamType::Signed { bits: 8 }; "i8")]
+#[test_case(ParamType::Signed { bits: 128 }; "i128")]
+#[test_case(ParamType::U256; "u256")]
+fn test_values_within_bounds(param_type: ParamType) {
+    let (min, max) = param_type.bounds();
+    let mut rng = FuzzerRng::new(DEFAULT_FUZZER_SEED);
+    let values = (0..1000).map(|_| param_type.generate(&mut rng)).collect_vec();
+    for value in &values {
+        assert!(min <= *value && *value <= max, "{value} is out of the bounds of {param_type:?}.");
+        assert!(param_type.to_args(value).is_some());
+    }
+    // The edges of the range are generated as well.
+    assert!(values.contains(&min));
+    assert!(values.contains(&max));
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+
+#[cfg(test)]
+#[path = "gas_snapshot_test.rs"]
+mod test;
+
+/// The default path of the gas snapshot file.
+pub const GAS_SNAPSHOT_FILE: &str = ".gas-snapshot";
+
+/// Configuration of the gas snapshot mode.
+#[derive(Clone, Debug)]
+pub struct GasSnapshotConfig {
+    /// The path of the gas snapshot file.
+    pub path: PathBuf,
+    /// The allowed growth of the gas used by a test over its snapshot, in percents.
+    pub threshold_percent: f64,
+}
+impl Default for GasSnapshotConfig {
+    fn default() -> Self {
+        Self { path: GAS_SNAPSHOT_FILE.into(), threshold_percent: 0.0 }
+    }
+}
+
+/// A change in the gas used by a test, relative to the snapshot.
+pub struct GasChange {
+    pub name: String,
+    pub old: usize,
+    pub new: usize,
+}
+impl GasChange {
+    /// Returns the growth of the gas used by the test, in percents.
+    pub fn growth_percent(&self) -> f64 {
+        if self.old == 0 {
+            return if self.new == 0 { 0.0 } else { f64::INFINITY };
+        }
+        (self.new as f64 - self.old as f64) * 100.0 / self.old as f64
+    }
+}
+
+/// Reads a gas snapshot file. Returns `None` if the file does not exist.
+///
+/// Every line of the file contains the full name of a test and the gas it used, separated by a
+/// space.
+pub fn read_gas_snapshot(
+    config: &GasSnapshotConfig,
+) -> Result<Option<OrderedHashMap<String, usize>>> {
+    if !config.path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&config.path)
+        .with_context(|| format!("Failed to read `{}`.", config.path.display()))?;
+    let mut snapshot = OrderedHashMap::default();
+    for (line_index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some((name, gas)) = line.rsplit_once(' ') else {
+            bail!("{}:{}: Expected `<test name> <gas>`.", config.path.display(), line_index + 1);
+        };
+        let gas = gas.parse().with_context(|| {
+            format!("{}:{}: Invalid gas value `{gas}`.", config.path.display(), line_index + 1)
+        })?;
+        snapshot.insert(name.to_string(), gas);
+    }
+    Ok(Some(snapshot))
+}
+
+/// Writes a gas snapshot file, sorted by the test names.
+pub fn write_gas_snapshot(
+    config: &GasSnapshotConfig,
+    snapshot: &OrderedHashMap<String, usize>,
+) -> Result<()> {
+    let content = snapshot
+        .iter()
+        .sorted_by_key(|(name, _)| *name)
+        .map(|(name, gas)| format!("{name} {gas}\n"))
+        .join("");
+    fs::write(&config.path, content)
+        .with_context(|| format!("Failed to write `{}`.", config.path.display()))
+}
+
+/// Returns the changes in the gas used by the tests that appear in both snapshots.
+pub fn gas_changes(
+    old_snapshot: &OrderedHashMap<String, usize>,
+    new_snapshot: &OrderedHashMap<String, usize>,
+) -> Vec<GasChange> {
+    new_snapshot
+        .iter()
+        .filter_map(|(name, new)| {
+            let old = *old_snapshot.get(name)?;
+            (old != *new).then(|| GasChange { name: name.clone(), old, new: *new })
+        })
+        .collect()
+}
+
+/// Compares the gas used by the tests of the current run to the existing snapshot, and writes the
+/// updated snapshot.
+///
+/// Fails without updating the snapshot if the gas used by any test grew past the threshold.
+/// `existing_tests` are the names of all the tests, including those that did not run - their
+/// entries in the existing snapshot are kept.
+pub fn check_and_update_gas_snapshot(
+    config: &GasSnapshotConfig,
+    gas_usage: &OrderedHashMap<String, usize>,
+    existing_tests: &[String],
+) -> Result<()> {
+    let mut new_snapshot = OrderedHashMap::default();
+    if let Some(old_snapshot) = read_gas_snapshot(config)? {
+        let changes = gas_changes(&old_snapshot, gas_usage);
+        let mut regressions = 0;
+        for change in &changes {
+            let growth = change.growth_percent();
+            let regressed = growth > config.threshold_percent;
+            if regressed {
+                regressions += 1;
+            }
+            eprintln!(
+                "gas snapshot: {} {} -> {} ({growth:+.2}%){}",
+                change.name,
+                change.old,
+                change.new,
+                if regressed { " - exceeds the threshold" } else { "" }
+            );
+        }
+        if regressions > 0 {
+            bail!(
+                "gas snapshot check failed: the gas used by {regressions} tests grew by more than \
+                 {}%.",
+                config.threshold_percent
+            );
+        }
+        for (name, gas) in old_snapshot {
+            if !gas_usage.contains_key(&name) && existing_tests.contains(&name) {
+                new_snapshot.insert(name, gas);
+            }
+        }
+    }
+    new_snapshot.extend(gas_usage.iter().map(|(name, gas)| (name.clone(), *gas)));
+    write_gas_snapshot(config, &new_snapshot)
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use pretty_assertions::assert_eq;
+
+use super::{check_and_update_gas_snapshot, gas_changes, GasSnapshotConfig};
+
+/// Returns a snapshot of the gas used by the given tests.
+fn snapshot(entries: &[(&str, usize)]) -> OrderedHashMap<String, usize> {
+    entries.iter().map(|(name, gas)| (name.to_string(), *gas)).collect()
+}
+
+/// Returns a configuration with a 10% threshold, and a snapshot file with the given content, unique
+/// to the calling test.
+fn config_with_snapshot(test_name: &str, content: &str) -> GasSnapshotConfig {
+    let path = std::env::temp_dir()
+        .join(format!("cairo-test-gas-snapshot-{}-{test_name}", std::process::id()));
+    fs::write(&path, content).unwrap();
+    GasSnapshotConfig { path, threshold_percent: 10.0 }
+}
+
+/// Returns the names of the given tests.
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_gas_changes() {
+    let old = snapshot(&[("changed", 100), ("same", 100), ("removed", 100)]);
+    let new = snapshot(&[("changed", 150), ("same", 100), ("added", 100)]);
+    let changes = gas_changes(&old, &new)
+        .into_iter()
+        .map(|change| (change.name.clone(), change.old, change.new, change.growth_percent()))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [("changed".to_string(), 100, 150, 50.0)]);
+}
+
+#[test]
+fn test_new_and_removed_tests() {
+    let config = config_with_snapshot("new_and_removed", "kept 100\nremoved 100\nnot_run 70\n");
+    check_and_update_gas_snapshot(
+        &config,
+        &snapshot(&[("kept", 100), ("added", 30)]),
+        &names(&["kept", "added", "not_run"]),
+    )
+    .unwrap();
+    // The new test is added, the removed test is dropped, and the test that did not run is kept.
+    assert_eq!(fs::read_to_string(&config.path).unwrap(), "added 30\nkept 100\nnot_run 70\n");
+    fs::remove_file(&config.path).unwrap();
+}
+
+#[test]
+fn test_growth_below_threshold() {
+    let config = config_with_snapshot("below_threshold", "test 1000\n");
+    // A growth of 9.9%.
+    check_and_update_gas_snapshot(&config, &snapshot(&[("test", 1099)]), &names(&["test"]))
+        .unwrap();
+    assert_eq!(fs::read_to_string(&config.path).unwrap(), "test 1099\n");
+    fs::remove_file(&config.path).unwrap();
+}
+
+#[test]
+fn test_growth_above_threshold() {
+    let config = config_with_snapshot("above_threshold", "test 1000\n");
+    // A growth of 10.1%.
+    let err =
+        check_and_update_gas_snapshot(&config, &snapshot(&[("test", 1101)]), &names(&["test"]))
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "gas snapshot check failed: the gas used by 1 tests grew by more than 10%."
+    );
+    // The snapshot is not updated.
+    assert_eq!(fs::read_to_string(&config.path).unwrap(), "test 1000\n");
+    fs::remove_file(&config.path).unwrap();
+}
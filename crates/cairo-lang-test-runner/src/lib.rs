@@ -10,10 +10,11 @@ use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
-use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::profiling::{ProfilingConfig, ProfilingInfo};
+use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_semantic::{ConcreteFunction, FunctionLongId};
@@ -30,14 +31,28 @@ use cairo_lang_starknet::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L
 use cairo_lang_starknet::plugin::StarkNetPlugin;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use colored::Colorize;
-use itertools::{chain, Itertools};
+use coverage::{add_statements_hits, write_coverage_report, CoverageConfig, StatementsHits};
+use fuzzer::{FuzzerConfig, FuzzerRng, ParamType};
+use gas_snapshot::{check_and_update_gas_snapshot, GasSnapshotConfig};
+use itertools::{chain, zip_eq, Itertools};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use plugin::TestPlugin;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use reporter::{OutputFormat, TestReporter};
 use test_config::{try_extract_test_config, TestConfig};
 
 use crate::test_config::{PanicExpectation, TestExpectation};
 
+#[cfg(test)]
+#[path = "lib_test.rs"]
+mod test;
+
+pub mod coverage;
+pub mod fuzzer;
+pub mod gas_snapshot;
 pub mod plugin;
+pub mod reporter;
 mod test_config;
 
 pub struct TestRunner {
@@ -47,6 +62,16 @@ pub struct TestRunner {
     pub include_ignored: bool,
     pub ignored: bool,
     pub starknet: bool,
+    /// The format in which the test results are reported.
+    pub format: OutputFormat,
+    /// Whether warnings fail the compilation of the tests, as if they were errors.
+    pub deny_warnings: bool,
+    /// If set, the gas used by the tests is compared to, and recorded in, a gas snapshot file.
+    pub gas_snapshot: Option<GasSnapshotConfig>,
+    /// If set, the lines of code executed by the tests are recorded in a coverage report.
+    pub coverage: Option<CoverageConfig>,
+    /// If set, the tests are profiled by the Sierra functions they call.
+    pub profiling: Option<ProfilingConfig>,
 }
 
 impl TestRunner {
@@ -81,10 +106,6 @@ impl TestRunner {
 
         let main_crate_ids = setup_project(db, Path::new(&path))?;
 
-        if DiagnosticsReporter::stderr().check(db) {
-            bail!("failed to compile: {}", path);
-        }
-
         Ok(Self {
             db: db.snapshot(),
             main_crate_ids,
@@ -92,13 +113,65 @@ impl TestRunner {
             include_ignored,
             ignored,
             starknet,
+            format: OutputFormat::Human,
+            deny_warnings: false,
+            gas_snapshot: None,
+            coverage: None,
+            profiling: None,
         })
     }
 
-    /// Runs the tests and process the results for a summary.
+    /// Sets the format in which the test results are reported.
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether warnings fail the compilation of the tests, as if they were errors.
+    pub fn with_deny_warnings(mut self, deny_warnings: bool) -> Self {
+        self.deny_warnings = deny_warnings;
+        self
+    }
+
+    /// Enables the gas snapshot mode: after all the tests pass, the gas they used is compared to
+    /// the existing snapshot file, and the snapshot file is updated.
+    pub fn with_gas_snapshot(mut self, config: GasSnapshotConfig) -> Self {
+        self.gas_snapshot = Some(config);
+        self
+    }
+
+    /// Enables the coverage mode: after the tests run, the lines of code they executed are
+    /// written to an LCOV coverage report.
+    pub fn with_coverage(mut self, config: CoverageConfig) -> Self {
+        self.coverage = Some(config);
+        self
+    }
+
+    /// Enables the profiling mode: after the tests run, the functions with the most steps are
+    /// printed, and the call stacks of all the tests are written in the collapsed stacks format.
+    pub fn with_profiling(mut self, config: ProfilingConfig) -> Self {
+        self.profiling = Some(config);
+        self
+    }
+
+    /// Runs the tests and process the results for a summary, reporting them in the configured
+    /// format.
     pub fn run(&self) -> Result<Option<TestsSummary>> {
+        self.run_with_reporter(self.format.reporter().as_mut())
+    }
+
+    /// Compiles the tests and fails on their diagnostics, then runs the tests and process the
+    /// results for a summary, reporting them to `reporter`.
+    pub fn run_with_reporter(
+        &self,
+        reporter: &mut dyn TestReporter,
+    ) -> Result<Option<TestsSummary>> {
         let db = &self.db;
 
+        if DiagnosticsReporter::stderr().with_warnings_as_errors(self.deny_warnings).check(db) {
+            bail!("failed to compile the tests.");
+        }
+
         let all_entry_points = if self.starknet {
             find_contracts(db, &self.main_crate_ids)
                 .iter()
@@ -125,23 +198,30 @@ impl TestRunner {
                 })
                 .collect();
         let all_tests = find_all_tests(db, self.main_crate_ids.clone());
+        let function_ids = chain!(
+            all_entry_points.into_iter(),
+            all_tests.iter().flat_map(|(func_id, _cfg)| {
+                ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
+            })
+        )
+        .collect_vec();
         let sierra_program = self
             .db
-            .get_sierra_program_for_functions(
-                chain!(
-                    all_entry_points.into_iter(),
-                    all_tests.iter().flat_map(|(func_id, _cfg)| {
-                        ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
-                    })
-                )
-                .collect(),
-            )
+            .get_sierra_program_for_functions(function_ids.clone())
             .to_option()
             .with_context(|| "Compilation failed without any diagnostics.")?;
+        let statements_locations = match &self.coverage {
+            Some(_) => Some(
+                self.db
+                    .get_sierra_program_statements_locations(function_ids)
+                    .to_option()
+                    .with_context(|| "Compilation failed without any diagnostics.")?,
+            ),
+            None => None,
+        };
         let replacer = DebugReplacer { db };
         let sierra_program = replacer.apply(&sierra_program);
-        let total_tests_count = all_tests.len();
-        let named_tests = all_tests
+        let all_named_tests = all_tests
           .into_iter()
           .map(|(func_id, mut test)| {
               // Un-ignoring all the tests in `include-ignored` mode.
@@ -159,47 +239,59 @@ impl TestRunner {
                       }
                       .debug(db)
                   ),
+                  func_id,
                   test,
               )
           })
+          .map(|(name, func_id, test)| expand_test(db, name, func_id, test))
+          .flatten_ok()
+          .collect::<Result<Vec<_>>>()?;
+        let total_tests_count = all_named_tests.len();
+        let all_test_names = all_named_tests.iter().map(|(name, _)| name.clone()).collect_vec();
+        let named_tests = all_named_tests
+          .into_iter()
           .filter(|(name, _)| name.contains(&self.filter))
           // Filtering unignored tests in `ignored` mode.
-          .filter(|(_, test)| !self.ignored || test.ignored)
+          .filter(|(_, test)| !self.ignored || test.config.ignored)
           .collect_vec();
         let filtered_out = total_tests_count - named_tests.len();
         let contracts_info = get_contracts_info(db, self.main_crate_ids.clone(), &replacer)?;
-        let TestsSummary { passed, failed, ignored, failed_run_results } =
-            run_tests(named_tests, sierra_program, function_set_costs, contracts_info)?;
-        if failed.is_empty() {
-            println!(
-                "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
-                "ok".bright_green(),
-                passed.len(),
-                failed.len(),
-                ignored.len()
+        let summary = run_tests(
+            named_tests,
+            sierra_program,
+            function_set_costs,
+            contracts_info,
+            statements_locations.is_some(),
+            self.profiling.is_some(),
+            reporter,
+        )?;
+        reporter.on_run_finished(&summary, filtered_out);
+        let TestsSummary {
+            passed, failed, ignored, gas_usage, statements_hits, profiling_info, ..
+        } = summary;
+        if let (Some(config), Some(statements_locations)) = (&self.coverage, statements_locations)
+        {
+            let crate_roots = self
+                .main_crate_ids
+                .iter()
+                .filter_map(|crate_id| Some(db.crate_root_dir(*crate_id)?.0))
+                .collect_vec();
+            write_coverage_report(config, &statements_locations, &statements_hits, &crate_roots)?;
+        }
+        if let Some(config) = &self.profiling {
+            profiling_info.write_collapsed_stacks(config)?;
+            eprint!("{}", profiling_info.format_top_functions(config.top_functions));
+            eprintln!(
+                "profile: call stacks written to `{}`.",
+                config.collapsed_stacks_path.display()
             );
+        }
+        if failed.is_empty() {
+            if let Some(config) = &self.gas_snapshot {
+                check_and_update_gas_snapshot(config, &gas_usage, &all_test_names)?;
+            }
             Ok(None)
         } else {
-            println!("failures:");
-            for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
-                print!("   {failure} - ");
-                match run_result {
-                    RunResultValue::Success(_) => {
-                        println!("expected panic but finished successfully.");
-                    }
-                    RunResultValue::Panic(values) => {
-                        print!("panicked with [");
-                        for value in &values {
-                            match as_cairo_short_string(value) {
-                                Some(as_string) => print!("{value} ('{as_string}'), "),
-                                None => print!("{value}, "),
-                            }
-                        }
-                        println!("].")
-                    }
-                }
-            }
-            println!();
             bail!(
                 "test result: {}. {} passed; {} failed; {} ignored",
                 "FAILED".bright_red(),
@@ -211,27 +303,285 @@ impl TestRunner {
     }
 }
 
+/// The arguments a test function is called with.
+pub enum TestArgs {
+    /// The function is called once, with these arguments.
+    Fixed(Vec<Felt252>),
+    /// The function is called several times, with arguments generated by the fuzzer.
+    Fuzzed { config: FuzzerConfig, params: Vec<ParamType> },
+}
+
+/// A test to run - a call of a test function with its arguments.
+pub struct TestEntry {
+    /// The full name of the test function.
+    pub function_name: String,
+    /// The configuration of the test.
+    pub config: TestConfig,
+    /// The arguments the test function is called with.
+    pub args: TestArgs,
+}
+
+/// Expands a test function into the tests to run - one per test case for parameterized tests.
+fn expand_test(
+    db: &dyn SemanticGroup,
+    name: String,
+    func_id: FreeFunctionId,
+    config: TestConfig,
+) -> Result<Vec<(String, TestEntry)>> {
+    let signature = db
+        .free_function_signature(func_id)
+        .to_option()
+        .with_context(|| format!("Failed to get the signature of the test `{name}`."))?;
+    let params = signature
+        .params
+        .iter()
+        .map(|param| {
+            ParamType::from_type(db, param.ty).with_context(|| {
+                format!(
+                    "Parameter `{}` of the test `{name}` is of unsupported type `{}`.",
+                    param.name,
+                    param.ty.format(db)
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(fuzzer_config) = config.fuzzer {
+        if params.is_empty() {
+            bail!("The fuzz test `{name}` must have parameters.");
+        }
+        let entry = TestEntry {
+            function_name: name.clone(),
+            config,
+            args: TestArgs::Fuzzed { config: fuzzer_config, params },
+        };
+        return Ok(vec![(name, entry)]);
+    }
+    if config.cases.is_empty() {
+        if !params.is_empty() {
+            bail!("The test `{name}` has parameters, but no `test_case` or `fuzzer` attributes.");
+        }
+        let entry =
+            TestEntry { function_name: name.clone(), config, args: TestArgs::Fixed(vec![]) };
+        return Ok(vec![(name, entry)]);
+    }
+    config
+        .cases
+        .iter()
+        .map(|case| {
+            let case_name = format!("{name}({})", case.iter().join(", "));
+            if case.len() != params.len() {
+                bail!(
+                    "The test case `{case_name}` has {} arguments, but the test has {} parameters.",
+                    case.len(),
+                    params.len()
+                );
+            }
+            let mut args = vec![];
+            for (param_type, value) in zip_eq(&params, case) {
+                args.extend(param_type.to_args(value).with_context(|| {
+                    format!(
+                        "Argument `{value}` of the test case `{case_name}` is out of the range of \
+                         its parameter type."
+                    )
+                })?);
+            }
+            let entry = TestEntry {
+                function_name: name.clone(),
+                config: config.clone(),
+                args: TestArgs::Fixed(args),
+            };
+            Ok((case_name, entry))
+        })
+        .collect()
+}
+
 /// The status of a ran test.
-enum TestStatus {
+pub enum TestStatus {
     Success,
     Fail(RunResultValue),
     Ignore,
 }
 
+/// The result of a ran test.
+pub struct TestResult {
+    /// The status of the test.
+    pub status: TestStatus,
+    /// The gas used by the test, if it was run with limited available gas.
+    pub gas_used: Option<usize>,
+    /// The number of VM steps of the test, if it was run.
+    pub steps: Option<usize>,
+    /// The result of the fuzzer, for a fuzz test.
+    pub fuzzer: Option<FuzzerResult>,
+    /// The number of times each Sierra statement was executed by the test, if coverage is
+    /// collected.
+    pub statements_hits: Option<StatementsHits>,
+    /// The profile of the test, summed over its runs, if it is profiled.
+    pub profiling_info: Option<ProfilingInfo>,
+}
+
+/// The result of the runs of a fuzz test.
+///
+/// The gas used and the number of steps of a passed fuzz test are averaged over its runs, and
+/// those of a failed fuzz test are of its failing run.
+#[derive(Clone)]
+pub struct FuzzerResult {
+    /// The number of runs of the test, including the failing run.
+    pub runs: usize,
+    /// The seed of the arguments generation.
+    pub seed: u64,
+    /// The arguments of the failing run, if the test failed.
+    pub failing_input: Option<Vec<BigInt>>,
+}
+
 /// Summary data of the ran tests.
 pub struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<RunResultValue>,
+    /// The run result of each failed test, and the fuzzer result for failed fuzz tests.
+    failed_run_results: Vec<(RunResultValue, Option<FuzzerResult>)>,
+    /// The gas used by each passed test that was run with limited available gas.
+    gas_usage: OrderedHashMap<String, usize>,
+    /// The number of times each Sierra statement was executed by all the tests.
+    statements_hits: StatementsHits,
+    /// The profile of all the tests.
+    profiling_info: ProfilingInfo,
+}
+
+/// The result of a single call of a test function.
+struct SingleRunResult {
+    status: TestStatus,
+    gas_used: Option<usize>,
+    steps: usize,
+}
+
+/// Calls a test function once, with the given arguments.
+///
+/// The Sierra statements executed by the call are added to `statements_hits`, and its profile is
+/// added to `profiling_info`, if given.
+fn run_single(
+    runner: &SierraCasmRunner,
+    entry: &TestEntry,
+    args: &[Arg],
+    statements_hits: Option<&mut StatementsHits>,
+    profiling_info: Option<&mut ProfilingInfo>,
+) -> Result<SingleRunResult> {
+    let name = entry.function_name.as_str();
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function(name)?,
+            args,
+            entry.config.available_gas,
+            Default::default(),
+        )
+        .with_context(|| format!("Failed to run the function `{name}`."))?;
+    if let Some(statements_hits) = statements_hits {
+        add_statements_hits(runner, &result.trace, statements_hits);
+    }
+    if let Some(profiling_info) = profiling_info {
+        profiling_info.add(&runner.profile(&result.trace, &result.memory));
+    }
+    // A test that doesn't use the gas builtin doesn't consume any gas.
+    let gas_used = entry.config.available_gas.and_then(|available_gas| match &result.gas_counter {
+        Some(remaining_gas) => (Felt252::from(available_gas) - remaining_gas).to_usize(),
+        None => Some(0),
+    });
+    let status = match &result.value {
+        RunResultValue::Success(_) => match entry.config.expectation {
+            TestExpectation::Success => TestStatus::Success,
+            TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+        },
+        RunResultValue::Panic(value) => match &entry.config.expectation {
+            TestExpectation::Success => TestStatus::Fail(result.value),
+            TestExpectation::Panics(panic_expectation) => match panic_expectation {
+                PanicExpectation::Exact(expected) if value != expected => {
+                    TestStatus::Fail(result.value)
+                }
+                _ => TestStatus::Success,
+            },
+        },
+    };
+    Ok(SingleRunResult { status, gas_used, steps: result.resources.steps })
+}
+
+/// Runs a test - once, or once per fuzzer run for fuzz tests.
+///
+/// If `collect_coverage` is set, the Sierra statements executed by all the runs are recorded, and
+/// if `profile` is set, the runs are profiled.
+fn run_test(
+    runner: &SierraCasmRunner,
+    entry: &TestEntry,
+    collect_coverage: bool,
+    profile: bool,
+) -> Result<TestResult> {
+    let mut statements_hits = collect_coverage.then(StatementsHits::default);
+    let mut profiling_info = profile.then(ProfilingInfo::default);
+    let (config, params) = match &entry.args {
+        TestArgs::Fixed(args) => {
+            let args = args.iter().cloned().map(Arg::Value).collect_vec();
+            let SingleRunResult { status, gas_used, steps } =
+                run_single(runner, entry, &args, statements_hits.as_mut(), profiling_info.as_mut())?;
+            return Ok(TestResult {
+                status,
+                gas_used,
+                steps: Some(steps),
+                fuzzer: None,
+                statements_hits,
+                profiling_info,
+            });
+        }
+        TestArgs::Fuzzed { config, params } => (config, params),
+    };
+    let mut rng = FuzzerRng::new(config.seed);
+    let mut total_gas_used = Some(0);
+    let mut total_steps = 0;
+    for run_index in 0..config.runs {
+        let input = params.iter().map(|param_type| param_type.generate(&mut rng)).collect_vec();
+        let args = zip_eq(params, &input)
+            .flat_map(|(param_type, value)| param_type.to_args(value).unwrap())
+            .map(Arg::Value)
+            .collect_vec();
+        let result =
+            run_single(runner, entry, &args, statements_hits.as_mut(), profiling_info.as_mut())?;
+        if let TestStatus::Fail(_) = result.status {
+            return Ok(TestResult {
+                status: result.status,
+                gas_used: result.gas_used,
+                steps: Some(result.steps),
+                fuzzer: Some(FuzzerResult {
+                    runs: run_index + 1,
+                    seed: config.seed,
+                    failing_input: Some(input),
+                }),
+                statements_hits,
+                profiling_info,
+            });
+        }
+        total_gas_used = total_gas_used.zip(result.gas_used).map(|(total, gas)| total + gas);
+        total_steps += result.steps;
+    }
+    Ok(TestResult {
+        status: TestStatus::Success,
+        gas_used: total_gas_used.map(|total| total / config.runs),
+        steps: Some(total_steps / config.runs),
+        fuzzer: Some(FuzzerResult { runs: config.runs, seed: config.seed, failing_input: None }),
+        statements_hits,
+        profiling_info,
+    })
 }
 
 /// Runs the tests and process the results for a summary.
+///
+/// If `collect_coverage` is set, the Sierra statements executed by the tests are summed up in the
+/// summary, and if `profile` is set, so are the profiles of the tests.
 pub fn run_tests(
-    named_tests: Vec<(String, TestConfig)>,
+    named_tests: Vec<(String, TestEntry)>,
     sierra_program: cairo_lang_sierra::program::Program,
     function_set_costs: OrderedHashMap<FunctionId, OrderedHashMap<CostTokenType, i32>>,
     contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    collect_coverage: bool,
+    profile: bool,
+    reporter: &mut dyn TestReporter,
 ) -> anyhow::Result<TestsSummary> {
     let runner = SierraCasmRunner::new(
         sierra_program,
@@ -239,68 +589,69 @@ pub fn run_tests(
         contracts_info,
     )
     .with_context(|| "Failed setting up runner.")?;
-    println!("running {} tests", named_tests.len());
+    reporter.on_run_started(named_tests.len());
+    let reporter = Mutex::new(reporter);
     let wrapped_summary = Mutex::new(Ok(TestsSummary {
         passed: vec![],
         failed: vec![],
         ignored: vec![],
         failed_run_results: vec![],
+        gas_usage: OrderedHashMap::default(),
+        statements_hits: StatementsHits::default(),
+        profiling_info: ProfilingInfo::default(),
     }));
     named_tests
         .into_par_iter()
-        .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
-            if test.ignored {
-                return Ok((name, TestStatus::Ignore));
-            }
-            let result = runner
-                .run_function_with_starknet_context(
-                    runner.find_function(name.as_str())?,
-                    &[],
-                    test.available_gas,
-                    Default::default(),
-                )
-                .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-            Ok((
-                name,
-                match &result.value {
-                    RunResultValue::Success(_) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Success,
-                        TestExpectation::Panics(_) => TestStatus::Fail(result.value),
+        .map(|(name, entry)| -> anyhow::Result<(String, TestResult)> {
+            if entry.config.ignored {
+                return Ok((
+                    name,
+                    TestResult {
+                        status: TestStatus::Ignore,
+                        gas_used: None,
+                        steps: None,
+                        fuzzer: None,
+                        statements_hits: None,
+                        profiling_info: None,
                     },
-                    RunResultValue::Panic(value) => match test.expectation {
-                        TestExpectation::Success => TestStatus::Fail(result.value),
-                        TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                            PanicExpectation::Exact(expected) if value != &expected => {
-                                TestStatus::Fail(result.value)
-                            }
-                            _ => TestStatus::Success,
-                        },
-                    },
-                },
-            ))
+                ));
+            }
+            reporter.lock().unwrap().on_test_started(&name);
+            Ok((name, run_test(&runner, &entry, collect_coverage, profile)?))
         })
         .for_each(|r| {
             let mut wrapped_summary = wrapped_summary.lock().unwrap();
             if wrapped_summary.is_err() {
                 return;
             }
-            let (name, status) = match r {
-                Ok((name, status)) => (name, status),
+            let (name, result) = match r {
+                Ok((name, result)) => (name, result),
                 Err(err) => {
                     *wrapped_summary = Err(err);
                     return;
                 }
             };
+            reporter.lock().unwrap().on_test_finished(&name, &result);
             let summary = wrapped_summary.as_mut().unwrap();
-            let (res_type, status_str) = match status {
-                TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
+            for (statement_idx, hits) in result.statements_hits.iter().flatten() {
+                *summary.statements_hits.entry(*statement_idx).or_default() += hits;
+            }
+            if let Some(profiling_info) = result.profiling_info {
+                summary.profiling_info.add(&profiling_info);
+            }
+            let res_type = match result.status {
+                TestStatus::Success => {
+                    if let Some(gas_used) = result.gas_used {
+                        summary.gas_usage.insert(name.clone(), gas_used);
+                    }
+                    &mut summary.passed
+                }
                 TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(run_result);
-                    (&mut summary.failed, "fail".bright_red())
+                    summary.failed_run_results.push((run_result, result.fuzzer));
+                    &mut summary.failed
                 }
-                TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
+                TestStatus::Ignore => &mut summary.ignored,
             };
-            println!("test {name} ... {status_str}",);
             res_type.push(name);
         });
     wrapped_summary.into_inner().unwrap()
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{TestResult, TestRunner, TestStatus, TestsSummary};
+use crate::plugin::TestPlugin;
+use crate::reporter::{OutputFormat, TestReporter};
+
+/// Returns a runner of the tests of a crate with the given content.
+fn test_runner(content: &str) -> TestRunner {
+    let mut db = RootDatabase::builder()
+        .detect_corelib()
+        .with_cfg(CfgSet::from_iter([Cfg::name("test")]))
+        .with_semantic_plugin(Arc::new(TestPlugin::default()))
+        .build()
+        .unwrap();
+    let crate_id = db.intern_crate(CrateLongId("fixture".into()));
+    db.set_crate_root(crate_id, Some(Directory("src".into())));
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    db.override_file_content(file_id, Some(Arc::new(content.into())));
+    let mut diagnostics = String::new();
+    let has_errors = DiagnosticsReporter::write_to_string(&mut diagnostics).check(&db);
+    assert!(!has_errors, "Failed to compile the tests:\n{diagnostics}");
+    TestRunner {
+        db,
+        main_crate_ids: vec![crate_id],
+        filter: "".into(),
+        include_ignored: false,
+        ignored: false,
+        starknet: false,
+        format: OutputFormat::Human,
+        deny_warnings: false,
+        gas_snapshot: None,
+        coverage: None,
+        profiling: None,
+    }
+}
+
+/// A reporter recording the results of the tests, as `<name>: <status>`.
+#[derive(Default)]
+struct RecordingReporter {
+    results: Vec<String>,
+}
+impl TestReporter for RecordingReporter {
+    fn on_run_started(&mut self, _tests_count: usize) {}
+
+    fn on_test_started(&mut self, _name: &str) {}
+
+    fn on_test_finished(&mut self, name: &str, result: &TestResult) {
+        let status = match &result.status {
+            TestStatus::Success => "ok".to_string(),
+            TestStatus::Fail(_) => match &result.fuzzer {
+                Some(fuzzer) => format!(
+                    "failed on input ({}) in run {}",
+                    fuzzer.failing_input.iter().flatten().join(", "),
+                    fuzzer.runs
+                ),
+                None => "failed".to_string(),
+            },
+            TestStatus::Ignore => "ignored".to_string(),
+        };
+        self.results.push(format!("{name}: {status}"));
+    }
+
+    fn on_run_finished(&mut self, _summary: &TestsSummary, _filtered_out: usize) {}
+}
+
+/// Runs the tests of a crate with the given content, and returns the result of the run, and the
+/// results of the tests sorted by name.
+fn run(content: &str) -> (anyhow::Result<()>, Vec<String>) {
+    let mut reporter = RecordingReporter::default();
+    let result = test_runner(content).run_with_reporter(&mut reporter).map(|_| ());
+    (result, reporter.results.into_iter().sorted().collect())
+}
+
+#[test]
+fn test_parameterized_tests() {
+    let (result, results) = run(indoc! {"
+        #[test]
+        #[test_case(1, 2, 3)]
+        #[test_case(-1, 1, 0)]
+        #[test_case(1, 1, 3)]
+        fn test_add(a: felt252, b: felt252, expected: felt252) {
+            assert(a + b == expected, 'wrong sum');
+        }
+
+        #[test]
+        #[test_case(255, 'ab')]
+        fn test_types(a: u8, s: felt252) {
+            assert(a == 255_u8, 'wrong u8');
+            assert(s == 0x6162, 'wrong short string');
+        }
+
+        #[test]
+        #[ignore]
+        #[test_case(1)]
+        fn test_ignored(_a: felt252) {}
+
+        #[test]
+        fn test_plain() {}
+    "});
+    // Each test case is a test of its own, named by its arguments.
+    assert_eq!(
+        results,
+        [
+            "fixture::test_add(-1, 1, 0): ok",
+            "fixture::test_add(1, 1, 3): failed",
+            "fixture::test_add(1, 2, 3): ok",
+            "fixture::test_ignored(1): ignored",
+            "fixture::test_plain: ok",
+            "fixture::test_types(255, 24930): ok",
+        ]
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_fuzz_test() {
+    let (result, results) = run(indoc! {"
+        #[test]
+        #[fuzzer(runs: 50, seed: 2)]
+        fn test_not_max(a: u8) {
+            assert(a != 255_u8, 'max');
+        }
+
+        #[test]
+        #[fuzzer(runs: 50)]
+        fn test_any(_a: u128, _b: felt252) {}
+    "});
+    // The fuzzer reports the input of the failing run.
+    assert_eq!(
+        results,
+        ["fixture::test_any: ok", "fixture::test_not_max: failed on input (255) in run 3"]
+    );
+    assert!(result.is_err());
+}
+
+#[test_case(
+    "#[test]\n#[test_case(1)]\nfn test_two(a: felt252, b: felt252) {}",
+    "The test case `fixture::test_two(1)` has 1 arguments, but the test has 2 parameters.";
+    "too few arguments"
+)]
+#[test_case(
+    "#[test]\n#[test_case(1, 2)]\nfn test_one(a: felt252) {}",
+    "The test case `fixture::test_one(1, 2)` has 2 arguments, but the test has 1 parameters.";
+    "too many arguments"
+)]
+#[test_case(
+    "#[test]\n#[test_case(-1)]\nfn test_u8(a: u8) {}",
+    "Argument `-1` of the test case `fixture::test_u8(-1)` is out of the range of its parameter type.";
+    "negative unsigned argument"
+)]
+#[test_case(
+    "#[test]\n#[test_case(256)]\nfn test_u8(a: u8) {}",
+    "Argument `256` of the test case `fixture::test_u8(256)` is out of the range of its parameter \
+     type.";
+    "argument out of range"
+)]
+#[test_case(
+    "#[test]\nfn test_params(a: felt252) {}",
+    "The test `fixture::test_params` has parameters, but no `test_case` or `fuzzer` attributes.";
+    "parameters without test cases"
+)]
+#[test_case(
+    "#[test]\n#[test_case(1)]\nfn test_bool(a: bool) {}",
+    "Parameter `a` of the test `fixture::test_bool` is of unsupported type `core::bool`.";
+    "unsupported parameter type"
+)]
+#[test_case(
+    "#[test]\n#[fuzzer]\nfn test_fuzz() {}",
+    "The fuzz test `fixture::test_fuzz` must have parameters.";
+    "fuzz test without parameters"
+)]
+fn test_invalid_parameterized_tests(content: &str, expected_error: &str) {
+    let (result, results) = run(content);
+    assert_eq!(result.unwrap_err().to_string(), expected_error);
+    assert_eq!(results, Vec::<String>::new());
+}
+
+#[test]
+fn test_deny_warnings() {
+    let content = "#[test]\nfn test_unused() {\n    let x = 1;\n}";
+    let mut reporter = RecordingReporter::default();
+    assert!(test_runner(content).run_with_reporter(&mut reporter).is_ok());
+    assert_eq!(reporter.results, ["fixture::test_unused: ok"]);
+
+    // With warnings denied, the unused variable fails the compilation, and no test is run.
+    let mut reporter = RecordingReporter::default();
+    let result = test_runner(content).with_deny_warnings(true).run_with_reporter(&mut reporter);
+    assert_eq!(result.unwrap_err().to_string(), "failed to compile the tests.");
+    assert_eq!(reporter.results, Vec::<String>::new());
+}
@@ -26,6 +26,10 @@ impl MacroPlugin for TestPlugin {
             remove_original_item: false,
         }
     }
+
+    fn executable_attributes(&self) -> Vec<String> {
+        vec!["test".into()]
+    }
 }
 impl AsDynMacroPlugin for TestPlugin {
     fn as_dyn_macro_plugin<'a>(self: Arc<Self>) -> Arc<dyn MacroPlugin + 'a>
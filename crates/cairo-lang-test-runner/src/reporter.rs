@@ -0,0 +1,315 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use cairo_felt::Felt252;
+use cairo_lang_runner::short_string::format_for_panic;
+use cairo_lang_runner::RunResultValue;
+use colored::Colorize;
+use itertools::{chain, Itertools};
+use serde_json::json;
+
+use crate::{FuzzerResult, TestResult, TestStatus, TestsSummary};
+
+#[cfg(test)]
+#[path = "reporter_test.rs"]
+mod test;
+
+/// The format in which the test results are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored text, meant to be read by humans.
+    #[default]
+    Human,
+    /// A JSON object per line, for each test event.
+    Json,
+    /// A JUnit XML report, printed when all the tests have finished.
+    Junit,
+}
+impl OutputFormat {
+    /// Returns a new reporter for the format.
+    pub fn reporter(self) -> Box<dyn TestReporter> {
+        match self {
+            OutputFormat::Human => Box::<HumanReporter>::default(),
+            OutputFormat::Json => Box::<JsonReporter>::default(),
+            OutputFormat::Junit => Box::<JunitReporter>::default(),
+        }
+    }
+}
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "junit" => Ok(OutputFormat::Junit),
+            _ => bail!("Unknown output format `{s}`. Expected `human`, `json` or `junit`."),
+        }
+    }
+}
+
+/// Receives the events of a tests run and reports them.
+///
+/// Tests are run in parallel, so events of different tests may be interleaved.
+pub trait TestReporter: Send {
+    /// Called before any test starts running.
+    fn on_run_started(&mut self, tests_count: usize);
+    /// Called when a test starts running. Not called for ignored tests.
+    fn on_test_started(&mut self, name: &str);
+    /// Called when a test has finished running, or was ignored.
+    fn on_test_finished(&mut self, name: &str, result: &TestResult);
+    /// Called after all the tests have finished running.
+    fn on_run_finished(&mut self, summary: &TestsSummary, filtered_out: usize);
+}
+
+/// Returns a description of why a test with the given run result failed.
+fn failure_message(run_result: &RunResultValue, fuzzer: Option<&FuzzerResult>) -> String {
+    let message = match run_result {
+        RunResultValue::Success(_) => "expected panic but finished successfully.".into(),
+        RunResultValue::Panic(values) => format!("panicked with {}.", format_for_panic(values)),
+    };
+    match fuzzer.and_then(|fuzzer| Some((fuzzer.failing_input.as_ref()?, fuzzer.seed))) {
+        Some((input, seed)) => {
+            format!("{message} Failing fuzzer input: ({}), seed: {seed}.", input.iter().join(", "))
+        }
+        None => message,
+    }
+}
+
+/// Reports the tests results as colored text.
+#[derive(Default)]
+pub struct HumanReporter;
+impl TestReporter for HumanReporter {
+    fn on_run_started(&mut self, tests_count: usize) {
+        println!("running {tests_count} tests");
+    }
+
+    fn on_test_started(&mut self, _name: &str) {}
+
+    fn on_test_finished(&mut self, name: &str, result: &TestResult) {
+        let status_str = match result.status {
+            TestStatus::Success => "ok".bright_green(),
+            TestStatus::Fail(_) => "fail".bright_red(),
+            TestStatus::Ignore => "ignored".bright_yellow(),
+        };
+        let usage = chain!(
+            result.fuzzer.as_ref().map(|fuzzer| format!("runs: {}", fuzzer.runs)),
+            result.gas_used.map(|gas_used| format!("gas: {gas_used}")),
+            result.steps.map(|steps| format!("steps: {steps}"))
+        )
+        .join(", ");
+        if usage.is_empty() {
+            println!("test {name} ... {status_str}");
+        } else {
+            println!("test {name} ... {status_str} ({usage})");
+        }
+    }
+
+    fn on_run_finished(&mut self, summary: &TestsSummary, filtered_out: usize) {
+        if summary.failed.is_empty() {
+            println!(
+                "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
+                "ok".bright_green(),
+                summary.passed.len(),
+                summary.failed.len(),
+                summary.ignored.len()
+            );
+        } else {
+            println!("failures:");
+            for (failure, (run_result, fuzzer)) in
+                summary.failed.iter().zip_eq(&summary.failed_run_results)
+            {
+                println!("   {failure} - {}", failure_message(run_result, fuzzer.as_ref()));
+            }
+            println!();
+        }
+    }
+}
+
+/// Reports the tests results as JSON objects, one per line.
+#[derive(Default)]
+pub struct JsonReporter;
+impl TestReporter for JsonReporter {
+    fn on_run_started(&mut self, tests_count: usize) {
+        println!("{}", json!({ "event": "run_started", "tests_count": tests_count }));
+    }
+
+    fn on_test_started(&mut self, name: &str) {
+        println!("{}", json!({ "event": "started", "name": name }));
+    }
+
+    fn on_test_finished(&mut self, name: &str, result: &TestResult) {
+        println!("{}", test_finished_event(name, result));
+    }
+
+    fn on_run_finished(&mut self, summary: &TestsSummary, filtered_out: usize) {
+        println!(
+            "{}",
+            json!({
+                "event": "run_finished",
+                "passed": summary.passed.len(),
+                "failed": summary.failed.len(),
+                "ignored": summary.ignored.len(),
+                "filtered_out": filtered_out,
+            })
+        );
+    }
+}
+
+/// Returns the JSON event of a test that has finished running, or was ignored.
+fn test_finished_event(name: &str, result: &TestResult) -> serde_json::Value {
+    match &result.status {
+        TestStatus::Success => {
+            json!({
+                "event": "passed",
+                "name": name,
+                "gas_used": result.gas_used,
+                "steps": result.steps,
+                "fuzzer": result.fuzzer.as_ref().map(fuzzer_to_json),
+            })
+        }
+        TestStatus::Fail(run_result) => {
+            let panic_data = match run_result {
+                RunResultValue::Success(_) => None,
+                RunResultValue::Panic(values) => {
+                    Some(values.iter().map(felt252_to_hex).collect_vec())
+                }
+            };
+            json!({
+                "event": "failed",
+                "name": name,
+                "gas_used": result.gas_used,
+                "steps": result.steps,
+                "fuzzer": result.fuzzer.as_ref().map(fuzzer_to_json),
+                "panic_data": panic_data,
+                "message": failure_message(run_result, result.fuzzer.as_ref()),
+            })
+        }
+        TestStatus::Ignore => json!({ "event": "ignored", "name": name }),
+    }
+}
+
+/// Returns the JSON representation of a fuzzer result.
+fn fuzzer_to_json(fuzzer: &FuzzerResult) -> serde_json::Value {
+    json!({
+        "runs": fuzzer.runs,
+        "seed": fuzzer.seed,
+        "failing_input": fuzzer
+            .failing_input
+            .as_ref()
+            .map(|input| input.iter().map(|value| value.to_string()).collect_vec()),
+    })
+}
+
+/// Formats a felt252 as a hex string.
+fn felt252_to_hex(value: &Felt252) -> String {
+    format!("0x{}", value.to_str_radix(16))
+}
+
+/// A test case of a JUnit report.
+struct JunitTestCase {
+    name: String,
+    gas_used: Option<usize>,
+    steps: Option<usize>,
+    fuzzer_runs: Option<usize>,
+    /// The failure message, if the test failed.
+    failure: Option<String>,
+    ignored: bool,
+}
+
+/// Collects the tests results, and reports them as a JUnit XML document once all the tests have
+/// finished.
+#[derive(Default)]
+pub struct JunitReporter {
+    test_cases: Vec<JunitTestCase>,
+}
+impl TestReporter for JunitReporter {
+    fn on_run_started(&mut self, _tests_count: usize) {}
+
+    fn on_test_started(&mut self, _name: &str) {}
+
+    fn on_test_finished(&mut self, name: &str, result: &TestResult) {
+        self.test_cases.push(JunitTestCase {
+            name: name.to_string(),
+            gas_used: result.gas_used,
+            steps: result.steps,
+            fuzzer_runs: result.fuzzer.as_ref().map(|fuzzer| fuzzer.runs),
+            failure: match &result.status {
+                TestStatus::Fail(run_result) => {
+                    Some(failure_message(run_result, result.fuzzer.as_ref()))
+                }
+                TestStatus::Success | TestStatus::Ignore => None,
+            },
+            ignored: matches!(result.status, TestStatus::Ignore),
+        });
+    }
+
+    fn on_run_finished(&mut self, summary: &TestsSummary, _filtered_out: usize) {
+        for line in self.report(summary) {
+            println!("{line}");
+        }
+    }
+}
+impl JunitReporter {
+    /// Returns the lines of the JUnit XML document of the collected tests results.
+    fn report(&self, summary: &TestsSummary) -> Vec<String> {
+        let mut report = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.into(),
+            "<testsuites>".into(),
+            format!(
+                r#"  <testsuite name="cairo-test" tests="{}" failures="{}" skipped="{}">"#,
+                self.test_cases.len(),
+                summary.failed.len(),
+                summary.ignored.len()
+            ),
+        ];
+        for test_case in &self.test_cases {
+            let (classname, name) =
+                test_case.name.rsplit_once("::").unwrap_or(("", test_case.name.as_str()));
+            report.push(format!(
+                r#"    <testcase name="{}" classname="{}">"#,
+                escape_xml(name),
+                escape_xml(classname)
+            ));
+            let properties = chain!(
+                test_case.gas_used.map(|gas_used| ("gas_used", gas_used)),
+                test_case.steps.map(|steps| ("steps", steps)),
+                test_case.fuzzer_runs.map(|runs| ("fuzzer_runs", runs))
+            )
+            .collect_vec();
+            if !properties.is_empty() {
+                report.push("      <properties>".into());
+                for (name, value) in properties {
+                    report.push(format!(r#"        <property name="{name}" value="{value}"/>"#));
+                }
+                report.push("      </properties>".into());
+            }
+            if let Some(failure) = &test_case.failure {
+                report.push(format!(r#"      <failure message="{}"/>"#, escape_xml(failure)));
+            }
+            if test_case.ignored {
+                report.push("      <skipped/>".into());
+            }
+            report.push("    </testcase>".into());
+        }
+        report.push("  </testsuite>".into());
+        report.push("</testsuites>".into());
+        report
+    }
+}
+
+/// Escapes a string to be used as XML text or attribute value.
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,153 @@
+use cairo_felt::Felt252;
+use cairo_lang_runner::RunResultValue;
+use indoc::indoc;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::{test_finished_event, JunitReporter, TestReporter};
+use crate::{FuzzerResult, TestResult, TestStatus, TestsSummary};
+
+/// Returns the result of a test with the given status, that used 100 gas in 20 steps.
+fn test_result(status: TestStatus) -> TestResult {
+    TestResult {
+        status,
+        gas_used: Some(100),
+        steps: Some(20),
+        fuzzer: None,
+        statements_hits: None,
+        profiling_info: None,
+    }
+}
+
+/// Returns the status of a test that panicked with the short string `a<b&"c"`, which must be
+/// escaped in XML.
+fn panicked_status() -> TestStatus {
+    TestStatus::Fail(RunResultValue::Panic(vec![Felt252::from_bytes_be(b"a<b&\"c\"")]))
+}
+
+#[test]
+fn test_json_passed() {
+    assert_eq!(
+        test_finished_event("test::passing", &test_result(TestStatus::Success)),
+        json!({
+            "event": "passed",
+            "name": "test::passing",
+            "gas_used": 100,
+            "steps": 20,
+            "fuzzer": null,
+        })
+    );
+}
+
+#[test]
+fn test_json_failed() {
+    assert_eq!(
+        test_finished_event("test::failing", &test_result(panicked_status())),
+        json!({
+            "event": "failed",
+            "name": "test::failing",
+            "gas_used": 100,
+            "steps": 20,
+            "fuzzer": null,
+            "panic_data": ["0x613c6226226322"],
+            "message": "panicked with [27369464985183010 ('a<b&\"c\"'), ].",
+        })
+    );
+    assert_eq!(
+        test_finished_event(
+            "test::not_panicking",
+            &test_result(TestStatus::Fail(RunResultValue::Success(vec![])))
+        ),
+        json!({
+            "event": "failed",
+            "name": "test::not_panicking",
+            "gas_used": 100,
+            "steps": 20,
+            "fuzzer": null,
+            "panic_data": null,
+            "message": "expected panic but finished successfully.",
+        })
+    );
+}
+
+#[test]
+fn test_json_failed_fuzzer() {
+    let result = TestResult {
+        fuzzer: Some(FuzzerResult {
+            runs: 3,
+            seed: 7,
+            failing_input: Some(vec![BigInt::from(-1), BigInt::from(2)]),
+        }),
+        ..test_result(panicked_status())
+    };
+    assert_eq!(
+        test_finished_event("test::fuzzed", &result),
+        json!({
+            "event": "failed",
+            "name": "test::fuzzed",
+            "gas_used": 100,
+            "steps": 20,
+            "fuzzer": {"runs": 3, "seed": 7, "failing_input": ["-1", "2"]},
+            "panic_data": ["0x613c6226226322"],
+            "message": "panicked with [27369464985183010 ('a<b&\"c\"'), ]. Failing fuzzer input: \
+                        (-1, 2), seed: 7.",
+        })
+    );
+}
+
+#[test]
+fn test_json_ignored() {
+    let result = TestResult { gas_used: None, steps: None, ..test_result(TestStatus::Ignore) };
+    assert_eq!(
+        test_finished_event("test::ignored", &result),
+        json!({ "event": "ignored", "name": "test::ignored" })
+    );
+}
+
+#[test]
+fn test_junit() {
+    let mut reporter = JunitReporter::default();
+    reporter.on_test_finished("test::passing", &test_result(TestStatus::Success));
+    reporter.on_test_finished("test::failing", &test_result(panicked_status()));
+    reporter.on_test_finished(
+        "ignored",
+        &TestResult { gas_used: None, steps: None, ..test_result(TestStatus::Ignore) },
+    );
+    let summary = TestsSummary {
+        passed: vec!["test::passing".into()],
+        failed: vec!["test::failing".into()],
+        ignored: vec!["ignored".into()],
+        failed_run_results: vec![],
+        gas_usage: Default::default(),
+        statements_hits: Default::default(),
+        profiling_info: Default::default(),
+    };
+    assert_eq!(
+        reporter.report(&summary).into_iter().map(|line| format!("{line}\n")).join(""),
+        indoc! {r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites>
+              <testsuite name="cairo-test" tests="3" failures="1" skipped="1">
+                <testcase name="passing" classname="test">
+                  <properties>
+                    <property name="gas_used" value="100"/>
+                    <property name="steps" value="20"/>
+                  </properties>
+                </testcase>
+                <testcase name="failing" classname="test">
+                  <properties>
+                    <property name="gas_used" value="100"/>
+                    <property name="steps" value="20"/>
+                  </properties>
+                  <failure message="panicked with [27369464985183010 (&apos;a&lt;b&amp;&quot;c&quot;&apos;), ]."/>
+                </testcase>
+                <testcase name="ignored" classname="">
+                  <skipped/>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#}
+    );
+}
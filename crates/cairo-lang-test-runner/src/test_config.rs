@@ -4,9 +4,18 @@ use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, Attribut
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_utils::OptionHelper;
+use itertools::{chain, Itertools};
+use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 
+use crate::fuzzer::{FuzzerConfig, DEFAULT_FUZZER_RUNS, DEFAULT_FUZZER_SEED};
+
+#[cfg(test)]
+#[path = "test_config_test.rs"]
+mod test;
+
 /// Expectation for a panic case.
+#[derive(Clone)]
 pub enum PanicExpectation {
     /// Accept any panic value.
     Any,
@@ -15,6 +24,7 @@ pub enum PanicExpectation {
 }
 
 /// Expectation for a result of a test.
+#[derive(Clone)]
 pub enum TestExpectation {
     /// Running the test should not panic.
     Success,
@@ -23,6 +33,7 @@ pub enum TestExpectation {
 }
 
 /// The configuration for running a single test.
+#[derive(Clone)]
 pub struct TestConfig {
     /// The amount of gas the test requested.
     pub available_gas: Option<usize>,
@@ -30,6 +41,10 @@ pub struct TestConfig {
     pub expectation: TestExpectation,
     /// Should the test be ignored.
     pub ignored: bool,
+    /// The arguments of each of the test cases of a parameterized test.
+    pub cases: Vec<Vec<BigInt>>,
+    /// The configuration of the fuzzer, for a fuzz test.
+    pub fuzzer: Option<FuzzerConfig>,
 }
 
 /// Extracts the configuration of a tests from attributes, or returns the diagnostics if the
@@ -42,28 +57,33 @@ pub fn try_extract_test_config(
     let ignore_attr = attrs.iter().find(|attr| attr.id.as_str() == "ignore");
     let available_gas_attr = attrs.iter().find(|attr| attr.id.as_str() == "available_gas");
     let should_panic_attr = attrs.iter().find(|attr| attr.id.as_str() == "should_panic");
+    let test_case_attrs = attrs.iter().filter(|attr| attr.id.as_str() == "test_case").collect_vec();
+    let fuzzer_attr = attrs.iter().find(|attr| attr.id.as_str() == "fuzzer");
     let mut diagnostics = vec![];
     if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: attr.id_stable_ptr.untyped(),
-                message: "Attribute should not have arguments.".into(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                attr.id_stable_ptr.untyped(),
+                "Attribute should not have arguments.".into(),
+            ));
         }
     } else {
-        for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: attr.id_stable_ptr.untyped(),
-                message: "Attribute should only appear on tests.".into(),
-            });
+        for attr in chain!(
+            [ignore_attr, available_gas_attr, should_panic_attr, fuzzer_attr].into_iter().flatten(),
+            test_case_attrs.iter().copied()
+        ) {
+            diagnostics.push(PluginDiagnostic::error(
+                attr.id_stable_ptr.untyped(),
+                "Attribute should only appear on tests.".into(),
+            ));
         }
     }
     let ignored = if let Some(attr) = ignore_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: attr.id_stable_ptr.untyped(),
-                message: "Attribute should not have arguments.".into(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                attr.id_stable_ptr.untyped(),
+                "Attribute should not have arguments.".into(),
+            ));
         }
         true
     } else {
@@ -79,10 +99,10 @@ pub fn try_extract_test_config(
         {
             literal.numeric_value(db).unwrap_or_default().to_usize()
         } else {
-            diagnostics.push(PluginDiagnostic {
-                stable_ptr: attr.id_stable_ptr.untyped(),
-                message: "Attribute should have a single value argument.".into(),
-            });
+            diagnostics.push(PluginDiagnostic::error(
+                attr.id_stable_ptr.untyped(),
+                "Attribute should have a single value argument.".into(),
+            ));
             None
         }
     } else {
@@ -95,18 +115,42 @@ pub fn try_extract_test_config(
             (
                 true,
                 extract_panic_values(db, attr).on_none(|| {
-                    diagnostics.push(PluginDiagnostic {
-                        stable_ptr: attr.args_stable_ptr.untyped(),
-                        message: "Expected panic must be of the form `expected: <tuple of \
-                                  felt252s>`."
+                    diagnostics.push(PluginDiagnostic::error(
+                        attr.args_stable_ptr.untyped(),
+                        "Expected panic must be of the form `expected: <tuple of felt252s>`."
                             .into(),
-                    });
+                    ));
                 }),
             )
         }
     } else {
         (false, None)
     };
+    let cases = test_case_attrs
+        .iter()
+        .filter_map(|attr| {
+            extract_test_case_args(db, attr).on_none(|| {
+                diagnostics.push(PluginDiagnostic::error(
+                    attr.args_stable_ptr.untyped(),
+                    "Test case arguments must be numeric literals or short strings.".into(),
+                ));
+            })
+        })
+        .collect_vec();
+    let fuzzer = fuzzer_attr.and_then(|attr| {
+        if let Some(test_case_attr) = test_case_attrs.first() {
+            diagnostics.push(PluginDiagnostic::error(
+                test_case_attr.id_stable_ptr.untyped(),
+                "A test can't have both `test_case` and `fuzzer` attributes.".into(),
+            ));
+        }
+        extract_fuzzer_config(db, attr).on_none(|| {
+            diagnostics.push(PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                "Fuzzer arguments must be of the form `runs: <number>, seed: <number>`.".into(),
+            ));
+        })
+    });
     if !diagnostics.is_empty() {
         return Err(diagnostics);
     }
@@ -125,10 +169,53 @@ pub fn try_extract_test_config(
                 TestExpectation::Success
             },
             ignored,
+            cases,
+            fuzzer,
         })
     })
 }
 
+/// Tries to extract the arguments of a test case.
+fn extract_test_case_args(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<Vec<BigInt>> {
+    attr.args
+        .iter()
+        .map(|arg| {
+            let AttributeArgVariant::Unnamed { value, .. } = &arg.variant else {
+                return None;
+            };
+            match value {
+                ast::Expr::Literal(literal) => literal.numeric_value(db),
+                ast::Expr::ShortString(literal) => literal.numeric_value(db),
+                ast::Expr::Unary(unary) => {
+                    let ast::UnaryOperator::Minus(_) = unary.op(db) else { return None };
+                    let ast::Expr::Literal(literal) = unary.expr(db) else { return None };
+                    literal.numeric_value(db).map(|value| -value)
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Tries to extract the configuration of the fuzzer.
+fn extract_fuzzer_config(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<FuzzerConfig> {
+    let mut config = FuzzerConfig { runs: DEFAULT_FUZZER_RUNS, seed: DEFAULT_FUZZER_SEED };
+    for arg in &attr.args {
+        let AttributeArgVariant::Named { name, value: ast::Expr::Literal(literal), .. } =
+            &arg.variant
+        else {
+            return None;
+        };
+        let value = literal.numeric_value(db)?;
+        match name.as_str() {
+            "runs" => config.runs = value.to_usize().filter(|runs| *runs > 0)?,
+            "seed" => config.seed = value.to_u64()?,
+            _ => return None,
+        }
+    }
+    Some(config)
+}
+
 /// Tries to extract the relevant expected panic values.
 fn extract_panic_values(db: &dyn SyntaxGroup, attr: &Attribute) -> Option<Vec<Felt252>> {
     let [
@@ -0,0 +1,108 @@
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
+use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
+use cairo_lang_syntax::node::ast;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{try_extract_test_config, TestConfig};
+use crate::fuzzer::{FuzzerConfig, DEFAULT_FUZZER_RUNS, DEFAULT_FUZZER_SEED};
+
+/// Extracts the test configuration of a function with the given attributes, or returns the
+/// messages of the diagnostics of the attributes.
+fn extract_test_config(attributes: &str) -> Result<Option<TestConfig>, Vec<String>> {
+    let db = SimpleParserDatabase::default();
+    let content = format!("{attributes}\nfn foo(a: u8, b: felt252) {{}}\n");
+    let (syntax_file, diagnostics) =
+        get_syntax_file_and_diagnostics(&db, FileId::new(&db, "test.cairo".into()), &content);
+    assert_eq!(diagnostics.format(&db), "");
+    let [ast::Item::FreeFunction(function)] = &syntax_file.items(&db).elements(&db)[..] else {
+        panic!("Expected a single function.");
+    };
+    try_extract_test_config(&db, function.attributes(&db).structurize(&db)).map_err(|diagnostics| {
+        diagnostics.into_iter().map(|diagnostic| diagnostic.message).collect()
+    })
+}
+
+#[test]
+fn test_test_cases() {
+    let config = extract_test_config(
+        "#[test]\n#[test_case(1, -2)]\n#[test_case(0x10, 'ab')]\n#[test_case(-0, 0)]",
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        config.cases,
+        [[1, -2], [16, 0x6162], [0, 0]]
+            .into_iter()
+            .map(|case| case.into_iter().map(BigInt::from).collect_vec())
+            .collect_vec()
+    );
+    assert_eq!(config.fuzzer, None);
+}
+
+#[test]
+fn test_no_test_cases() {
+    let config = extract_test_config("#[test]").unwrap().unwrap();
+    assert!(config.cases.is_empty());
+    assert_eq!(extract_test_config("").unwrap().map(|config| config.cases), None);
+}
+
+#[test_case("#[test_case(x)]"; "identifier")]
+#[test_case("#[test_case(1 + 2)]"; "binary expression")]
+#[test_case("#[test_case(-x)]"; "negated identifier")]
+#[test_case("#[test_case(--1)]"; "double negation")]
+#[test_case("#[test_case(!1)]"; "not operator")]
+#[test_case("#[test_case(a: 1)]"; "named argument")]
+#[test_case("#[test_case((1, 2))]"; "tuple")]
+fn test_invalid_test_case_args(test_case_attr: &str) {
+    assert_eq!(
+        extract_test_config(&format!("#[test]\n#[test_case(1, 2)]\n{test_case_attr}"))
+            .err()
+            .unwrap(),
+        ["Test case arguments must be numeric literals or short strings."]
+    );
+}
+
+#[test]
+fn test_test_case_with_fuzzer() {
+    assert_eq!(
+        extract_test_config("#[test]\n#[test_case(1, 2)]\n#[fuzzer]").err().unwrap(),
+        ["A test can't have both `test_case` and `fuzzer` attributes."]
+    );
+    assert_eq!(
+        extract_test_config("#[test]\n#[fuzzer(runs: 5)]\n#[test_case(1, 2)]").err().unwrap(),
+        ["A test can't have both `test_case` and `fuzzer` attributes."]
+    );
+}
+
+#[test]
+fn test_test_case_on_non_test() {
+    assert_eq!(
+        extract_test_config("#[test_case(1, 2)]\n#[test_case(3, 4)]").err().unwrap(),
+        ["Attribute should only appear on tests.", "Attribute should only appear on tests."]
+    );
+}
+
+#[test_case("#[fuzzer]", DEFAULT_FUZZER_RUNS, DEFAULT_FUZZER_SEED; "default")]
+#[test_case("#[fuzzer(runs: 5)]", 5, DEFAULT_FUZZER_SEED; "runs")]
+#[test_case("#[fuzzer(seed: 7, runs: 3)]", 3, 7; "runs and seed")]
+fn test_fuzzer_config(fuzzer_attr: &str, runs: usize, seed: u64) {
+    let config = extract_test_config(&format!("#[test]\n{fuzzer_attr}")).unwrap().unwrap();
+    assert_eq!(config.fuzzer, Some(FuzzerConfig { runs, seed }));
+    assert!(config.cases.is_empty());
+}
+
+#[test_case("#[fuzzer(runs: 0)]"; "no runs")]
+#[test_case("#[fuzzer(runs: -1)]"; "negative runs")]
+#[test_case("#[fuzzer(seed: 'a')]"; "short string seed")]
+#[test_case("#[fuzzer(iterations: 5)]"; "unknown argument")]
+#[test_case("#[fuzzer(5)]"; "unnamed argument")]
+fn test_invalid_fuzzer_config(fuzzer_attr: &str) {
+    assert_eq!(
+        extract_test_config(&format!("#[test]\n{fuzzer_attr}")).err().unwrap(),
+        ["Fuzzer arguments must be of the form `runs: <number>, seed: <number>`."]
+    );
+}
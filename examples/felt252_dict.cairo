@@ -0,0 +1,8 @@
+use dict::Felt252DictTrait;
+
+// Stores `n` in a new dictionary and reads it back.
+pub fn felt252_dict(n: felt252) -> felt252 {
+    let mut dict: Felt252Dict<felt252> = Default::default();
+    dict.insert(1, n);
+    dict.get(1)
+}
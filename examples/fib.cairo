@@ -1,5 +1,5 @@
 // Calculates fib...
-fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
+pub fn fib(a: felt252, b: felt252, n: felt252) -> felt252 {
     match n {
         0 => a,
         _ => fib(b, a + b, n - 1),
@@ -1,7 +1,7 @@
 // Test two level of inline module nesting.
 mod inner {
-    mod inner {
-        fn fib_inner(a: felt252, b: felt252, n: felt252) -> felt252 {
+    pub mod inner {
+        pub fn fib_inner(a: felt252, b: felt252, n: felt252) -> felt252 {
             if n != 0 {
                 fib_inner(b, a + b, n - 1)
             } else {
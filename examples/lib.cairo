@@ -1,5 +1,6 @@
 mod corelib_usage;
 mod enum_flow;
+mod felt252_dict;
 mod fib;
 mod fib_array;
 mod fib_box;
@@ -40,12 +40,18 @@ cairo_lang_test_utils::test_file_test_with_runner!(
         bool: "bool",
         box_: "box",
         builtin_costs: "builtin_costs",
+        bytes31: "bytes31",
         casts: "casts",
         ec: "ec",
         enum_: "enum",
         enum_snapshot: "enum_snapshot",
         felt252_dict: "felt252_dict",
         felt252: "felt252",
+        i128: "i128",
+        i16: "i16",
+        i32: "i32",
+        i64: "i64",
+        i8: "i8",
         nullable: "nullable",
         poseidon: "poseidon",
         snapshot: "snapshot",
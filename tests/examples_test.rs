@@ -11,8 +11,11 @@ use cairo_lang_filesystem::db::FilesGroupEx;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_runner::debugging::{RecordedRun, StepKind};
+use cairo_lang_runner::values::parse_args;
 use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
-use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra::debug_info::SourceSpan;
+use cairo_lang_sierra_generator::db::{SierraGenGroup, StatementsLocations};
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
@@ -55,9 +58,19 @@ fn compare_contents_or_fix(name: &str, test_type: &str, content: String) {
 /// Compiles the Cairo code for submodule `name` of the examples crates to a Sierra program.
 fn checked_compile_to_sierra(
     name: &str,
-    (db, crate_ids): &ExampleDirData,
+    example_dir_data: &ExampleDirData,
     auto_add_withdraw_gas: bool,
 ) -> cairo_lang_sierra::program::Program {
+    checked_compile_to_sierra_with_locations(name, example_dir_data, auto_add_withdraw_gas).0
+}
+
+/// Compiles the Cairo code for submodule `name` of the examples crates to a Sierra program, along
+/// with the source spans of its statements.
+fn checked_compile_to_sierra_with_locations(
+    name: &str,
+    (db, crate_ids): &ExampleDirData,
+    auto_add_withdraw_gas: bool,
+) -> (cairo_lang_sierra::program::Program, StatementsLocations) {
     let mut locked_db = db.lock().unwrap();
     let add_withdraw_gas_flag_id = FlagId::new(locked_db.snapshot().upcast(), "add_withdraw_gas");
     locked_db.set_flag(
@@ -80,8 +93,11 @@ fn checked_compile_to_sierra(
             }
         }
     }
-    let sierra_program = db.get_sierra_program_for_functions(requested_function_ids).unwrap();
-    replace_sierra_ids_in_program(&db, &sierra_program)
+    let sierra_program =
+        db.get_sierra_program_for_functions(requested_function_ids.clone()).unwrap();
+    let statements_locations =
+        db.get_sierra_program_statements_locations(requested_function_ids).unwrap();
+    (replace_sierra_ids_in_program(&db, &sierra_program), statements_locations.as_ref().clone())
 }
 
 /// Tests lowering from Cairo to Sierra.
@@ -309,6 +325,70 @@ fn run_function_auto_gas_test(
     );
 }
 
+#[rstest]
+// A Pedersen hash per `n` in 3, 2, 1, with 3 cells per hash.
+#[case::hash_chain(
+    "hash_chain", None, &[("pedersen", 9), ("range_check", 0), ("segment_arena", 0)])]
+// A single dictionary, with 3 cells of the segment arena per dictionary.
+#[case::felt252_dict("felt252_dict", Some(usize::MAX), &[("pedersen", 0), ("segment_arena", 3)])]
+fn hash_chain_resources(
+    #[case] name: &str,
+    #[case] available_gas: Option<usize>,
+    #[case] expected_builtins: &[(&str, usize)],
+    example_dir_data: &ExampleDirData,
+) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra(name, example_dir_data, false),
+        if available_gas.is_some() { Some(Default::default()) } else { None },
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function("").expect("Failed finding the function."),
+            &[Arg::Value(Felt252::from(3))],
+            available_gas,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    let resources = &result.resources;
+    // The steps include the entry code, which is not a part of the trace of the program.
+    assert!(resources.steps > result.trace.len());
+    for (builtin, expected_cells) in expected_builtins {
+        assert_eq!(resources.builtins[*builtin], *expected_cells, "{builtin}");
+    }
+}
+
+#[rstest]
+fn profile_hash_chain(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("hash_chain", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function("").expect("Failed finding the function."),
+            &[Arg::Value(Felt252::from(3))],
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    let profile = runner.profile(&result.trace, &result.memory);
+    let hash_chain = &profile.functions["examples::hash_chain::hash_chain"];
+    // The function is called recursively for n = 3, 2, 1, 0.
+    assert_eq!(hash_chain.calls, 4);
+    assert_eq!(hash_chain.inclusive.steps, result.trace.len());
+    assert_eq!(hash_chain.inclusive.builtins["Pedersen"], 3);
+    assert_eq!(hash_chain.exclusive.builtins["Pedersen"], 3);
+    assert_eq!(profile.stacks.values().sum::<usize>(), result.trace.len());
+    assert_eq!(
+        profile.stacks.keys().map(|stack| stack.split(';').count()).max(),
+        Some(hash_chain.calls)
+    );
+}
+
 #[rstest]
 #[case::size_2(2, 1)]
 #[case::size_3(3, 2)]
@@ -328,3 +408,70 @@ fn run_fib_array_len(#[case] n: usize, #[case] last: usize, example_dir_data: &E
         [_, _, actual_last, actual_len] if actual_last == &Felt252::from(last) && actual_len == &Felt252::from(n)
     );
 }
+
+#[rstest]
+fn run_fib_array_decoded(example_dir_data: &ExampleDirData) {
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("fib_array", example_dir_data, false),
+        None,
+        Default::default(),
+    )
+    .expect("Failed setting up runner.");
+    let func =
+        runner.find_function_by_path("fib_array::fib").expect("Failed finding the function.");
+    let args = runner.prepare_args(func, parse_args("6").unwrap()).unwrap();
+    let result = runner
+        .run_function_with_starknet_context(func, &args, None, Default::default())
+        .expect("Failed running the function.");
+    let values = extract_matches!(result.value, RunResultValue::Success);
+    let decoded = runner.decode_return_value(func, &values, &result.memory).unwrap();
+    assert_eq!(decoded.to_string(), "([1, 1, 2, 3, 5, 8], 8, 6)");
+}
+
+#[rstest]
+fn debug_fib(example_dir_data: &ExampleDirData) {
+    let (sierra_program, statements_locations) =
+        checked_compile_to_sierra_with_locations("fib", example_dir_data, false);
+    let runner = SierraCasmRunner::new(sierra_program, None, Default::default())
+        .expect("Failed setting up runner.");
+    let func = runner.find_function_by_path("fib::fib").expect("Failed finding the function.");
+    let result = runner
+        .run_function_with_starknet_context(
+            func,
+            &[1, 1, 3].map(|value| Arg::Value(Felt252::from(value))),
+            None,
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    let run = RecordedRun::new(&runner, result.trace, result.memory, statements_locations)
+        .expect("Failed recording the run.");
+    let arguments = |frame_idx| {
+        run.arguments(frame_idx).into_iter().map(|(_, value)| value.to_string()).collect_vec()
+    };
+    // The line of `match n {`.
+    let is_breakpoint =
+        |span: &SourceSpan| span.file.ends_with("fib.cairo") && span.start.line == 2;
+
+    // The breakpoint is hit once per call, for `n` in 3, 2, 1, 0.
+    let hits = std::iter::successors(run.find_breakpoint(0, true, is_breakpoint), |step| {
+        run.find_breakpoint(step + 1, true, is_breakpoint)
+    })
+    .collect_vec();
+    assert_eq!(hits.len(), 4);
+    let last_hit = hits[3];
+    let call_stack = run.call_stack(last_hit);
+    assert_eq!(call_stack.len(), 4);
+    assert_eq!(arguments(call_stack[0].0), ["3", "5", "0"]);
+    assert_eq!(arguments(call_stack[3].0), ["1", "1", "3"]);
+    assert_eq!(run.find_breakpoint(last_hit - 1, false, is_breakpoint), Some(hits[2]));
+
+    // Stepping out of the innermost call, in both directions, reaches its caller.
+    for forward in [true, false] {
+        let step = run.step(last_hit, StepKind::Out, forward).unwrap();
+        assert_eq!(run.call_stack(step)[0].0, call_stack[1].0);
+    }
+    // Stepping by statements moves to the next statement, and back.
+    let next = run.step(last_hit, StepKind::Statement, true).unwrap();
+    assert_ne!(run.statement(next), run.statement(last_hit));
+    assert_eq!(run.step(next, StepKind::Statement, false), Some(last_hit));
+}